@@ -25,6 +25,7 @@ fn compile_tonic_server_proto() -> miette::Result<()> {
         PathBuf::from(env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR should be set"));
     let dst_dir = crate_root.join("src").join("generated");
     let proto_dir = crate_root.join("proto");
+    let objects_proto_dir = crate_root.join("..").join("..").join("objects").join("proto");
 
     // Remove `api.rs` if it exists.
     fs::remove_file(dst_dir.join("api.rs")).into_diagnostic().ok();
@@ -33,7 +34,7 @@ fn compile_tonic_server_proto() -> miette::Result<()> {
     let file_descriptor_path = PathBuf::from(out_dir).join("file_descriptor_set.bin");
 
     let protos = &[proto_dir.join("api.proto")];
-    let includes = &[proto_dir];
+    let includes = &[proto_dir, objects_proto_dir];
 
     let file_descriptors = protox::compile(protos, includes)?;
     fs::write(&file_descriptor_path, file_descriptors.encode_to_vec()).into_diagnostic()?;
@@ -77,6 +78,8 @@ fn build_tonic_server(
         .out_dir(out_dir)
         .build_server(!for_no_std)
         .build_transport(!for_no_std)
+        // the messages of the `objects` package are generated by `miden-objects`
+        .extern_path(".objects", "::miden_objects::proto::proto")
         .compile_protos_with_config(prost_build::Config::new(), protos, includes)
         .into_diagnostic()
 }
@@ -1,8 +1,5 @@
 use miden_objects::transaction::TransactionWitness;
-use miden_tx::{
-    utils::{Deserializable, Serializable},
-    LocalTransactionProver, TransactionProver,
-};
+use miden_tx::{LocalTransactionProver, TransactionProver};
 use miden_tx_prover::generated::{
     api_server::{Api as ProverApi, ApiServer},
     ProveTransactionRequest, ProveTransactionResponse,
@@ -48,12 +45,11 @@ impl ProverApi for ProverRpcApi {
             .map_err(|_| Status::resource_exhausted("Server is busy handling another request"))?;
 
         let transaction_witness =
-            TransactionWitness::read_from_bytes(&request.get_ref().transaction_witness)
-                .map_err(invalid_argument)?;
+            TransactionWitness::try_from(request.into_inner()).map_err(invalid_argument)?;
 
         let proof = prover.prove(transaction_witness).map_err(internal_error)?;
 
-        Ok(Response::new(ProveTransactionResponse { proven_transaction: proof.to_bytes() }))
+        Ok(Response::new(ProveTransactionResponse::from(proof)))
     }
 }
 
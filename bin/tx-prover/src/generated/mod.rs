@@ -1,5 +1,7 @@
-use miden_objects::transaction::ProvenTransaction;
-use miden_tx::utils::{Deserializable, DeserializationError, Serializable};
+use miden_objects::{
+    transaction::{ProvenTransaction, TransactionWitness},
+    ProtoConversionError,
+};
 
 #[cfg(all(feature = "std", target_arch = "wasm32"))]
 compile_error!("The `std` feature cannot be used when targeting `wasm32`.");
@@ -17,16 +19,36 @@ pub use nostd::api::*;
 // CONVERSIONS
 // ================================================================================================
 
+impl From<&TransactionWitness> for ProveTransactionRequest {
+    fn from(witness: &TransactionWitness) -> Self {
+        ProveTransactionRequest { transaction_witness: Some(witness.into()) }
+    }
+}
+
+impl TryFrom<ProveTransactionRequest> for TransactionWitness {
+    type Error = ProtoConversionError;
+
+    fn try_from(request: ProveTransactionRequest) -> Result<Self, Self::Error> {
+        request
+            .transaction_witness
+            .ok_or(ProtoConversionError::MissingField("transaction_witness"))?
+            .try_into()
+    }
+}
+
 impl From<ProvenTransaction> for ProveTransactionResponse {
     fn from(value: ProvenTransaction) -> Self {
-        ProveTransactionResponse { proven_transaction: value.to_bytes() }
+        ProveTransactionResponse { proven_transaction: Some((&value).into()) }
     }
 }
 
 impl TryFrom<ProveTransactionResponse> for ProvenTransaction {
-    type Error = DeserializationError;
+    type Error = ProtoConversionError;
 
     fn try_from(response: ProveTransactionResponse) -> Result<Self, Self::Error> {
-        ProvenTransaction::read_from_bytes(&response.proven_transaction)
+        response
+            .proven_transaction
+            .ok_or(ProtoConversionError::MissingField("proven_transaction"))?
+            .try_into()
     }
 }
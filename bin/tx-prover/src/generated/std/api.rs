@@ -1,13 +1,17 @@
 // This file is @generated by prost-build.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ProveTransactionRequest {
-    #[prost(bytes = "vec", tag = "1")]
-    pub transaction_witness: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag = "1")]
+    pub transaction_witness: ::core::option::Option<
+        ::miden_objects::proto::proto::TransactionWitness,
+    >,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ProveTransactionResponse {
-    #[prost(bytes = "vec", tag = "1")]
-    pub proven_transaction: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag = "1")]
+    pub proven_transaction: ::core::option::Option<
+        ::miden_objects::proto::proto::ProvenTransaction,
+    >,
 }
 /// Generated client implementations.
 pub mod api_client {
@@ -25,6 +25,11 @@ pub enum RemoteTransactionProverError {
 
     /// Indicates that the connection to the server failed.
     ConnectionFailed(String),
+
+    /// Indicates that the server could not be reached, e.g., because it refused the connection or
+    /// did not respond in time. Unlike other connection failures, this may be resolved by
+    /// connecting again later.
+    ServerUnreachable(String),
 }
 
 impl std::fmt::Display for RemoteTransactionProverError {
@@ -36,6 +41,9 @@ impl std::fmt::Display for RemoteTransactionProverError {
             RemoteTransactionProverError::ConnectionFailed(endpoint) => {
                 write!(f, "Failed to connect to transaction prover at: {}", endpoint)
             },
+            RemoteTransactionProverError::ServerUnreachable(endpoint) => {
+                write!(f, "Transaction prover at {} is unreachable", endpoint)
+            },
         }
     }
 }
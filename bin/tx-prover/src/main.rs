@@ -32,10 +32,7 @@ mod test {
         testing::account_code::DEFAULT_AUTH_SCRIPT,
         transaction::{ProvenTransaction, TransactionScript, TransactionWitness},
     };
-    use miden_tx::{
-        testing::mock_chain::{Auth, MockChain},
-        utils::Serializable,
-    };
+    use miden_tx::testing::mock_chain::{Auth, MockChain};
     use miden_tx_prover::generated::{
         api_client::ApiClient, api_server::ApiServer, ProveTransactionRequest,
     };
@@ -97,13 +94,9 @@ mod test {
 
         let transaction_witness = TransactionWitness::from(executed_transaction);
 
-        let request_1 = Request::new(ProveTransactionRequest {
-            transaction_witness: transaction_witness.to_bytes(),
-        });
+        let request_1 = Request::new(ProveTransactionRequest::from(&transaction_witness));
 
-        let request_2 = Request::new(ProveTransactionRequest {
-            transaction_witness: transaction_witness.to_bytes(),
-        });
+        let request_2 = Request::new(ProveTransactionRequest::from(&transaction_witness));
 
         // Send both requests concurrently
         let (t1, t2) = (
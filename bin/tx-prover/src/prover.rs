@@ -2,11 +2,25 @@ use alloc::{
     boxed::Box,
     string::{String, ToString},
 };
+use core::time::Duration;
 
-use miden_objects::transaction::{ProvenTransaction, TransactionWitness};
-use miden_tx::{utils::sync::RwLock, TransactionProver, TransactionProverError};
+use futures_timer::Delay;
 
-use crate::{generated::api_client::ApiClient, RemoteTransactionProverError};
+use miden_objects::{
+    transaction::{ProvenTransaction, TransactionWitness},
+    MIN_PROOF_SECURITY_LEVEL,
+};
+use miden_tx::{
+    utils::sync::RwLock, TransactionProver, TransactionProverError, TransactionVerifier,
+};
+
+use crate::{
+    generated::{api_client::ApiClient, ProveTransactionRequest, ProveTransactionResponse},
+    RemoteTransactionProverError,
+};
+
+/// The default delay before the first retry of a failed proving request.
+const DEFAULT_RETRY_DELAY: Duration = Duration::from_millis(500);
 
 // REMOTE TRANSACTION PROVER
 // ================================================================================================
@@ -18,6 +32,9 @@ use crate::{generated::api_client::ApiClient, RemoteTransactionProverError};
 /// transport. Otherwise, it uses the built-in `tonic::transport` for native platforms.
 ///
 /// The transport layer connection is established lazily when the first transaction is proven.
+/// Requests which fail because the server is unavailable, overloaded or too slow are retried up
+/// to the configured number of times, with an exponential backoff between the attempts. The
+/// proven transactions received from the server are verified locally before they are returned.
 pub struct RemoteTransactionProver {
     #[cfg(target_arch = "wasm32")]
     client: RwLock<Option<ApiClient<tonic_web_wasm_client::Client>>>,
@@ -26,18 +43,58 @@ pub struct RemoteTransactionProver {
     client: RwLock<Option<ApiClient<tonic::transport::Channel>>>,
 
     endpoint: String,
+    timeout: Option<Duration>,
+    max_retries: u32,
+    retry_delay: Duration,
+    verifier: TransactionVerifier,
 }
 
 impl RemoteTransactionProver {
     /// Creates a new [RemoteTransactionProver] with the specified gRPC server endpoint. The
     /// endpoint should be in the format `{protocol}://{hostname}:{port}`.
+    ///
+    /// By default, requests have no timeout, failed requests are not retried and the received
+    /// proofs must have at least [MIN_PROOF_SECURITY_LEVEL] bits of security. If retries are
+    /// enabled, the first retry is attempted after 500 ms.
     pub fn new(endpoint: &str) -> Self {
         RemoteTransactionProver {
             endpoint: endpoint.to_string(),
             client: RwLock::new(None),
+            timeout: None,
+            max_retries: 0,
+            retry_delay: DEFAULT_RETRY_DELAY,
+            verifier: TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL),
         }
     }
 
+    /// Sets the timeout of a single proving request to the remote prover.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the number of times a failed proving request is retried.
+    ///
+    /// Only requests which failed because the server could not be reached, was overloaded or did
+    /// not respond in time are retried.
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the delay before the first retry of a failed proving request. The delay is doubled
+    /// for every further retry.
+    pub fn with_retry_delay(mut self, retry_delay: Duration) -> Self {
+        self.retry_delay = retry_delay;
+        self
+    }
+
+    /// Sets the minimum security level the proofs received from the remote prover must have.
+    pub fn with_proof_security_level(mut self, proof_security_level: u32) -> Self {
+        self.verifier = TransactionVerifier::new(proof_security_level);
+        self
+    }
+
     /// Establishes a connection to the remote transaction prover server. The connection is
     /// mantained for the lifetime of the prover. If the connection is already established, this
     /// method does nothing.
@@ -55,15 +112,93 @@ impl RemoteTransactionProver {
 
         #[cfg(not(target_arch = "wasm32"))]
         let new_client = {
-            ApiClient::connect(self.endpoint.clone()).await.map_err(|_| {
-                RemoteTransactionProverError::ConnectionFailed(self.endpoint.to_string())
-            })?
+            let mut endpoint = tonic::transport::Endpoint::from_shared(self.endpoint.clone())
+                .map_err(|_| {
+                    RemoteTransactionProverError::InvalidEndpoint(self.endpoint.clone())
+                })?;
+            if let Some(timeout) = self.timeout {
+                endpoint = endpoint.connect_timeout(timeout);
+            }
+
+            let channel = endpoint.connect().await.map_err(|err| {
+                if is_retriable_connect_error(&err) {
+                    RemoteTransactionProverError::ServerUnreachable(self.endpoint.to_string())
+                } else {
+                    RemoteTransactionProverError::ConnectionFailed(self.endpoint.to_string())
+                }
+            })?;
+            ApiClient::new(channel)
         };
 
         *client = Some(new_client);
 
         Ok(())
     }
+
+    /// Sends the proving request to the remote prover, retrying failed requests up to the
+    /// configured number of times, and returns the response of the remote prover.
+    ///
+    /// Only failures which may be transient are retried, i.e., connection attempts which found the
+    /// server unreachable and requests which failed with a retriable gRPC status. Before the retry
+    /// following the n-th attempt, the prover waits for `retry_delay * 2^(n-1)`.
+    async fn send_witness(
+        &self,
+        request: ProveTransactionRequest,
+    ) -> Result<ProveTransactionResponse, TransactionProverError> {
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            let can_retry = attempt <= self.max_retries;
+
+            match self.connect().await {
+                Ok(()) => (),
+                Err(RemoteTransactionProverError::ServerUnreachable(_)) if can_retry => {
+                    Delay::new(self.backoff(attempt)).await;
+                    continue;
+                },
+                Err(err) => {
+                    return Err(TransactionProverError::InternalError(format!(
+                        "Failed to connect to the remote prover: {}",
+                        err
+                    )))
+                },
+            }
+
+            let mut attempt_request = tonic::Request::new(request.clone());
+            if let Some(timeout) = self.timeout {
+                attempt_request.set_timeout(timeout);
+            }
+
+            let mut client = self.client.write();
+            let result = client
+                .as_mut()
+                .expect("client should be connected")
+                .prove_transaction(attempt_request)
+                .await;
+
+            match result {
+                Ok(response) => return Ok(response.into_inner()),
+                Err(status) if can_retry && is_retriable(&status) => {
+                    // drop the connection so that a new one is established for the next attempt
+                    if status.code() == tonic::Code::Unavailable {
+                        *client = None;
+                    }
+                },
+                Err(status) => {
+                    return Err(TransactionProverError::InternalError(status.to_string()))
+                },
+            }
+
+            // release the client before waiting, so that it is not locked during the backoff
+            drop(client);
+            Delay::new(self.backoff(attempt)).await;
+        }
+    }
+
+    /// Returns the delay before the retry following the specified (1-based) attempt.
+    fn backoff(&self, attempt: u32) -> Duration {
+        self.retry_delay.saturating_mul(2u32.saturating_pow(attempt - 1))
+    }
 }
 
 #[async_trait::async_trait(?Send)]
@@ -72,35 +207,82 @@ impl TransactionProver for RemoteTransactionProver {
         &self,
         tx_witness: TransactionWitness,
     ) -> Result<ProvenTransaction, TransactionProverError> {
-        use miden_objects::utils::Serializable;
-        self.connect().await.map_err(|err| {
+        let response = self.send_witness(ProveTransactionRequest::from(&tx_witness)).await?;
+
+        // Convert the response message back into a ProvenTransaction.
+        let proven_transaction = ProvenTransaction::try_from(response).map_err(|err| {
             TransactionProverError::InternalError(format!(
-                "Failed to connect to the remote prover: {}",
+                "Error converting received response: {}",
                 err
             ))
         })?;
 
-        let mut client = self.client.write();
-
-        let request = tonic::Request::new(crate::generated::ProveTransactionRequest {
-            transaction_witness: tx_witness.to_bytes(),
-        });
-
-        let response = client
-            .as_mut()
-            .expect("client should be connected")
-            .prove_transaction(request)
-            .await
-            .map_err(|err| TransactionProverError::InternalError(err.to_string()))?;
-
-        // Deserialize the response bytes back into a ProvenTransaction.
-        let proven_transaction =
-            ProvenTransaction::try_from(response.into_inner()).map_err(|_| {
-                TransactionProverError::InternalError(
-                    "Error deserializing received response".to_string(),
-                )
-            })?;
+        // Make sure the remote prover proved the requested transaction, and that the proof is
+        // valid.
+        check_proven_transaction(&tx_witness, &proven_transaction)?;
+        self.verifier.verify(proven_transaction.clone()).map_err(|err| {
+            TransactionProverError::InternalError(format!(
+                "Failed to verify the received proof: {}",
+                err
+            ))
+        })?;
 
         Ok(proven_transaction)
     }
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns `true` if a request which failed with the provided status may succeed when retried.
+fn is_retriable(status: &tonic::Status) -> bool {
+    matches!(
+        status.code(),
+        tonic::Code::Unavailable | tonic::Code::DeadlineExceeded | tonic::Code::ResourceExhausted
+    )
+}
+
+/// Returns `true` if a connection attempt which failed with the provided error may succeed when
+/// retried, i.e., if the server refused the connection or did not respond in time.
+#[cfg(not(target_arch = "wasm32"))]
+fn is_retriable_connect_error(err: &tonic::transport::Error) -> bool {
+    use std::{error::Error, io::ErrorKind};
+
+    let mut source = err.source();
+    while let Some(err) = source {
+        if let Some(io_err) = err.downcast_ref::<std::io::Error>() {
+            return matches!(
+                io_err.kind(),
+                ErrorKind::ConnectionRefused
+                    | ErrorKind::ConnectionReset
+                    | ErrorKind::ConnectionAborted
+                    | ErrorKind::NotConnected
+                    | ErrorKind::TimedOut
+            );
+        }
+        source = err.source();
+    }
+
+    false
+}
+
+/// Checks that the provided proven transaction is a transaction against the account, the input
+/// notes and the reference block of the provided transaction witness.
+fn check_proven_transaction(
+    tx_witness: &TransactionWitness,
+    proven_transaction: &ProvenTransaction,
+) -> Result<(), TransactionProverError> {
+    let account = tx_witness.account();
+    let matches_witness = proven_transaction.account_id() == account.id()
+        && proven_transaction.account_update().init_state_hash() == account.init_hash()
+        && proven_transaction.input_notes().commitment() == tx_witness.input_notes().commitment()
+        && proven_transaction.block_ref() == tx_witness.block_header().hash();
+
+    if !matches_witness {
+        return Err(TransactionProverError::InternalError(
+            "Received proven transaction does not match the transaction witness".to_string(),
+        ));
+    }
+
+    Ok(())
+}
@@ -332,22 +332,49 @@ const BASIC_WALLET_CODE: &str = "
     export.::miden::contracts::wallets::basic::move_asset_to_note
 ";
 
+const SPENDING_LIMIT_WALLET_CODE: &str = "
+    export.::miden::contracts::wallets::basic::receive_asset
+    export.::miden::contracts::wallets::basic::create_note
+    export.::miden::contracts::wallets::spending_limit::move_asset_to_note
+";
+
 const RPO_FALCON_AUTH_CODE: &str = "
     export.::miden::contracts::auth::basic::auth_tx_rpo_falcon512
 ";
 
+const RPO_FALCON_MULTISIG_AUTH_CODE: &str = "
+    export.::miden::contracts::auth::multisig::auth_tx_rpo_falcon512_multisig
+";
+
 const BASIC_FUNGIBLE_FAUCET_CODE: &str = "
     export.::miden::contracts::faucets::basic_fungible::distribute
     export.::miden::contracts::faucets::basic_fungible::burn
 ";
 
+const BASIC_NON_FUNGIBLE_FAUCET_CODE: &str = "
+    export.::miden::contracts::faucets::basic_non_fungible::distribute
+    export.::miden::contracts::faucets::basic_non_fungible::burn
+";
+
+const MANAGED_FUNGIBLE_FAUCET_CODE: &str = "
+    export.::miden::contracts::faucets::managed_fungible::auth_tx_rpo_falcon512
+    export.::miden::contracts::faucets::managed_fungible::distribute
+    export.::miden::contracts::faucets::managed_fungible::burn
+    export.::miden::contracts::faucets::managed_fungible::set_paused
+    export.::miden::contracts::faucets::managed_fungible::transfer_ownership
+";
+
 /// Compiles the default account components into a MASL library and stores the complied files in
 /// `target_dir`.
 fn compile_account_components(target_dir: &Path, assembler: Assembler) -> Result<()> {
     for (component_name, component_code) in [
         ("basic_wallet", BASIC_WALLET_CODE),
+        ("spending_limit_wallet", SPENDING_LIMIT_WALLET_CODE),
         ("rpo_falcon_512", RPO_FALCON_AUTH_CODE),
+        ("rpo_falcon_512_multisig", RPO_FALCON_MULTISIG_AUTH_CODE),
         ("basic_fungible_faucet", BASIC_FUNGIBLE_FAUCET_CODE),
+        ("managed_fungible_faucet", MANAGED_FUNGIBLE_FAUCET_CODE),
+        ("basic_non_fungible_faucet", BASIC_NON_FUNGIBLE_FAUCET_CODE),
     ] {
         let component_library = assembler.clone().assemble_library([component_code])?;
         let component_file_path =
@@ -481,6 +508,9 @@ fn is_masm_file(path: &Path) -> io::Result<bool> {
 ///
 /// We also ensure that a constant is not defined twice, except if their error code is the same.
 /// This can happen across multiple files.
+///
+/// In addition, the names of the error constants are recorded, so that assertion failures can be
+/// mapped back to the error constants.
 fn generate_kernel_error_constants(kernel_source_dir: &Path) -> Result<()> {
     // Because the error files will be written to ./src/errors, this should be a no-op if ./src is
     // read-only
@@ -620,6 +650,14 @@ fn generate_kernel_errors(errors: BTreeMap<ErrorName, ExtractedError>) -> Result
         writeln!(output, r#"    (ERR_{error_name}, "{message}"),"#).into_diagnostic()?;
     }
 
+    writeln!(output, "];").into_diagnostic()?;
+    writeln!(output).into_diagnostic()?;
+
+    writeln!(output, "pub const TX_KERNEL_ERROR_NAMES: [(u32, &str); {}] = [", errors.len())
+        .into_diagnostic()?;
+    for error_name in errors.keys() {
+        writeln!(output, r#"    (ERR_{error_name}, "ERR_{error_name}"),"#).into_diagnostic()?;
+    }
     writeln!(output, "];").into_diagnostic()?;
 
     Ok(output)
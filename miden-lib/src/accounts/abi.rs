@@ -0,0 +1,562 @@
+use alloc::{
+    collections::BTreeSet,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::Write;
+
+use miden_objects::{Felt, Word};
+
+use crate::{
+    errors::AccountInterfaceDefinitionError,
+    transaction::{ScriptOperation, TransactionScriptBuilder},
+};
+
+// ABI TYPE
+// ================================================================================================
+
+/// The type of an argument or return value of an account procedure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AbiType {
+    /// A single field element.
+    Felt,
+    /// A word, i.e., four field elements.
+    Word,
+}
+
+impl AbiType {
+    /// Returns the number of stack elements occupied by a value of this type.
+    pub const fn num_elements(&self) -> usize {
+        match self {
+            Self::Felt => 1,
+            Self::Word => 4,
+        }
+    }
+
+    /// Returns the Rust type of values of this type, as used by generated clients.
+    fn rust_type(&self) -> &'static str {
+        match self {
+            Self::Felt => "miden_objects::Felt",
+            Self::Word => "miden_objects::Word",
+        }
+    }
+}
+
+// ABI VALUE
+// ================================================================================================
+
+/// A typed argument of an account procedure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AbiValue {
+    Felt(Felt),
+    Word(Word),
+}
+
+impl AbiValue {
+    /// Returns the type of this value.
+    pub fn abi_type(&self) -> AbiType {
+        match self {
+            Self::Felt(_) => AbiType::Felt,
+            Self::Word(_) => AbiType::Word,
+        }
+    }
+
+    /// Appends the stack elements of this value to `elements`.
+    ///
+    /// Words are laid out as by `push.a.b.c.d`, i.e., with their last element on top of the stack.
+    fn encode_into(&self, elements: &mut Vec<Felt>) {
+        match self {
+            Self::Felt(felt) => elements.push(*felt),
+            Self::Word(word) => elements.extend(word.iter().rev()),
+        }
+    }
+}
+
+impl From<Felt> for AbiValue {
+    fn from(felt: Felt) -> Self {
+        Self::Felt(felt)
+    }
+}
+
+impl From<Word> for AbiValue {
+    fn from(word: Word) -> Self {
+        Self::Word(word)
+    }
+}
+
+// PROCEDURE DEFINITION
+// ================================================================================================
+
+/// The definition of an account procedure, i.e., its name and the names and types of its inputs
+/// and outputs.
+///
+/// Inputs are listed in stack order, i.e., the first input is on top of the stack when the
+/// procedure is called. The same holds for outputs when the procedure returns.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcedureDefinition {
+    name: String,
+    inputs: Vec<(String, AbiType)>,
+    outputs: Vec<(String, AbiType)>,
+}
+
+impl ProcedureDefinition {
+    /// Returns a new [ProcedureDefinition] of a procedure with the specified name which takes no
+    /// inputs and returns no outputs.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            inputs: Vec::new(),
+            outputs: Vec::new(),
+        }
+    }
+
+    /// Appends an input with the specified name and type to this procedure.
+    pub fn with_input(mut self, name: impl Into<String>, abi_type: AbiType) -> Self {
+        self.inputs.push((name.into(), abi_type));
+        self
+    }
+
+    /// Appends an output with the specified name and type to this procedure.
+    pub fn with_output(mut self, name: impl Into<String>, abi_type: AbiType) -> Self {
+        self.outputs.push((name.into(), abi_type));
+        self
+    }
+
+    /// Returns the name of this procedure.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the names and types of the inputs of this procedure.
+    pub fn inputs(&self) -> &[(String, AbiType)] {
+        &self.inputs
+    }
+
+    /// Returns the names and types of the outputs of this procedure.
+    pub fn outputs(&self) -> &[(String, AbiType)] {
+        &self.outputs
+    }
+
+    /// Returns the number of stack elements occupied by the inputs of this procedure.
+    pub fn num_input_elements(&self) -> usize {
+        self.inputs.iter().map(|(_, abi_type)| abi_type.num_elements()).sum()
+    }
+
+    /// Returns the number of stack elements occupied by the outputs of this procedure.
+    pub fn num_output_elements(&self) -> usize {
+        self.outputs.iter().map(|(_, abi_type)| abi_type.num_elements()).sum()
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    fn validate(&self) -> Result<(), AccountInterfaceDefinitionError> {
+        validate_identifier(&self.name)?;
+
+        // inputs and outputs are named independently, so that e.g. `value` can be both read and
+        // returned by a procedure
+        for values in [&self.inputs, &self.outputs] {
+            let mut names = BTreeSet::new();
+            for (name, _) in values {
+                validate_identifier(name)?;
+                if !names.insert(name.as_str()) {
+                    return Err(AccountInterfaceDefinitionError::DuplicateName(name.clone()));
+                }
+            }
+        }
+
+        let num_inputs = self.num_input_elements();
+        let num_outputs = self.num_output_elements();
+        if num_inputs > MAX_STACK_ELEMENTS || num_outputs > MAX_STACK_ELEMENTS {
+            return Err(AccountInterfaceDefinitionError::TooManyStackElements {
+                procedure: self.name.clone(),
+                num_elements: num_inputs.max(num_outputs),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Returns the stack layout of the provided values, padded to the depth of the stack at the
+    /// boundary of a `call`, e.g., `[amount, RECIPIENT, pad(11)]`.
+    fn stack_layout(values: &[(String, AbiType)]) -> String {
+        let mut elements: Vec<String> = values
+            .iter()
+            .map(|(name, abi_type)| match abi_type {
+                AbiType::Felt => name.clone(),
+                AbiType::Word => name.to_uppercase(),
+            })
+            .collect();
+
+        let num_elements: usize = values.iter().map(|(_, abi_type)| abi_type.num_elements()).sum();
+        if num_elements < MAX_STACK_ELEMENTS {
+            elements.push(format!("pad({})", MAX_STACK_ELEMENTS - num_elements));
+        }
+
+        format!("[{}]", elements.join(", "))
+    }
+}
+
+// ACCOUNT INTERFACE DEFINITION
+// ================================================================================================
+
+/// A lightweight ABI of an account component, i.e., the definitions of the procedures exported by
+/// the component under a library path (e.g., `my_app::counter`).
+///
+/// The definition is the single source of truth for the stack layouts of the procedures, from
+/// which:
+/// - [AccountInterfaceDefinition::masm_stubs()] generates the MASM exports of the component, which
+///   document the expected stack layouts and are to be filled in with the procedure bodies.
+/// - [AccountInterfaceDefinition::rust_client()] generates a Rust client with a typed method per
+///   procedure, which encodes the arguments onto the stack in the right order.
+/// - [AccountInterfaceDefinition::encode_call()] encodes a call of a procedure from typed values
+///   at runtime.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountInterfaceDefinition {
+    library_path: String,
+    procedures: Vec<ProcedureDefinition>,
+}
+
+impl AccountInterfaceDefinition {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [AccountInterfaceDefinition] of a component compiled under the specified
+    /// library path (e.g., `my_app::counter`), without any procedures.
+    ///
+    /// # Errors
+    /// Returns an error if a component of the library path is not a valid identifier.
+    pub fn new(library_path: impl Into<String>) -> Result<Self, AccountInterfaceDefinitionError> {
+        let library_path = library_path.into();
+        for component in library_path.split("::") {
+            validate_identifier(component)?;
+        }
+
+        Ok(Self { library_path, procedures: Vec::new() })
+    }
+
+    /// Adds the provided procedure to this interface.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The name of the procedure or of any of its inputs or outputs is not a valid identifier,
+    ///   i.e., does not consist of lowercase ASCII letters, digits and underscores only, or starts
+    ///   with a digit.
+    /// - The interface already contains a procedure with the same name, or two inputs or two
+    ///   outputs of the procedure have the same name.
+    /// - The inputs or outputs of the procedure occupy more than 16 stack elements.
+    pub fn with_procedure(
+        mut self,
+        procedure: ProcedureDefinition,
+    ) -> Result<Self, AccountInterfaceDefinitionError> {
+        procedure.validate()?;
+        if self.get_procedure(procedure.name()).is_some() {
+            return Err(AccountInterfaceDefinitionError::DuplicateName(procedure.name));
+        }
+
+        self.procedures.push(procedure);
+        Ok(self)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the library path of the component.
+    pub fn library_path(&self) -> &str {
+        &self.library_path
+    }
+
+    /// Returns the procedures of this interface, in the order in which they were added.
+    pub fn procedures(&self) -> &[ProcedureDefinition] {
+        &self.procedures
+    }
+
+    /// Returns the procedure with the specified name, if it exists.
+    pub fn get_procedure(&self, name: &str) -> Option<&ProcedureDefinition> {
+        self.procedures.iter().find(|procedure| procedure.name() == name)
+    }
+
+    /// Returns the fully-qualified path of the procedure with the specified name (e.g.,
+    /// `::my_app::counter::increment`).
+    pub fn procedure_path(&self, name: &str) -> String {
+        format!("::{}::{name}", self.library_path)
+    }
+
+    // ENCODING
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a [ScriptOperation] calling the procedure with the specified name with the
+    /// provided arguments, which can be added to a transaction script via
+    /// [TransactionScriptBuilder].
+    ///
+    /// # Errors
+    /// Returns an error if the interface does not contain the procedure, or if the types of the
+    /// provided arguments do not match the inputs of the procedure.
+    pub fn encode_call(
+        &self,
+        name: &str,
+        args: &[AbiValue],
+    ) -> Result<ScriptOperation, AccountInterfaceDefinitionError> {
+        let procedure = self
+            .get_procedure(name)
+            .ok_or_else(|| AccountInterfaceDefinitionError::UnknownProcedure(name.to_string()))?;
+
+        let arg_types: Vec<AbiType> = args.iter().map(AbiValue::abi_type).collect();
+        let input_types: Vec<AbiType> =
+            procedure.inputs().iter().map(|(_, abi_type)| *abi_type).collect();
+        if arg_types != input_types {
+            return Err(AccountInterfaceDefinitionError::ArgumentTypeMismatch {
+                procedure: name.to_string(),
+                expected: input_types,
+                actual: arg_types,
+            });
+        }
+
+        let mut elements = Vec::with_capacity(procedure.num_input_elements());
+        for arg in args {
+            arg.encode_into(&mut elements);
+        }
+
+        Ok(ScriptOperation::CallProcedure {
+            path: self.procedure_path(name),
+            args: elements,
+        })
+    }
+
+    // CODE GENERATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the MASM source code of a module exporting a stub of every procedure of this
+    /// interface.
+    ///
+    /// Each stub documents the stack layout of the procedure's inputs and outputs, and has a
+    /// placeholder body which fails with `ERR_PROCEDURE_NOT_IMPLEMENTED`, so that a component
+    /// compiled before all bodies are filled in cannot be used by mistake. The module is meant to
+    /// be compiled under [Self::library_path()].
+    pub fn masm_stubs(&self) -> String {
+        let mut source = String::new();
+        writeln!(
+            source,
+            "# Account procedures of `{}`.\n\n# Procedure is not implemented yet\n\
+             const.ERR_PROCEDURE_NOT_IMPLEMENTED={ERR_PROCEDURE_NOT_IMPLEMENTED:#010x}",
+            self.library_path
+        )
+        .expect("writing to a string should not fail");
+
+        for (index, procedure) in self.procedures.iter().enumerate() {
+            writeln!(source).expect("writing to a string should not fail");
+            writeln!(
+                source,
+                "#! Inputs:  {}\n#! Outputs: {}",
+                ProcedureDefinition::stack_layout(procedure.inputs()),
+                ProcedureDefinition::stack_layout(procedure.outputs()),
+            )
+            .expect("writing to a string should not fail");
+
+            // the placeholder bodies always fail, since the pushed value is non-zero, and differ
+            // from each other, since the procedures of an account must have distinct MAST roots
+            writeln!(
+                source,
+                "export.{}\n    push.{} assertz.err=ERR_PROCEDURE_NOT_IMPLEMENTED\nend",
+                procedure.name(),
+                index + 1
+            )
+            .expect("writing to a string should not fail");
+        }
+
+        source
+    }
+
+    /// Returns the Rust source code of a client for this interface, i.e., of a unit struct with
+    /// the specified name and an associated function per procedure.
+    ///
+    /// Each function takes a [TransactionScriptBuilder] and the typed inputs of the procedure,
+    /// and adds a call of the procedure with the inputs encoded onto the stack to the builder.
+    pub fn rust_client(&self, struct_name: &str) -> String {
+        let mut source = String::new();
+        writeln!(
+            source,
+            "/// Client for the `{}` account interface.\n///\n/// This code is generated from \
+             an `AccountInterfaceDefinition`.\npub struct {struct_name};\n\nimpl {struct_name} {{",
+            self.library_path
+        )
+        .expect("writing to a string should not fail");
+
+        for (index, procedure) in self.procedures.iter().enumerate() {
+            if index > 0 {
+                writeln!(source).expect("writing to a string should not fail");
+            }
+
+            let params: String = procedure
+                .inputs()
+                .iter()
+                .map(|(name, abi_type)| format!(", {name}: {}", abi_type.rust_type()))
+                .collect();
+            writeln!(
+                source,
+                "    /// Adds a call of `{}` to the provided transaction script builder.\n    \
+                 pub fn {}(\n        builder: miden_lib::transaction::TransactionScriptBuilder{params},\n    \
+                 ) -> Result<\n        miden_lib::transaction::TransactionScriptBuilder,\n        \
+                 miden_lib::transaction::TransactionScriptBuilderError,\n    > {{",
+                self.procedure_path(procedure.name()),
+                procedure.name(),
+            )
+            .expect("writing to a string should not fail");
+
+            writeln!(
+                source,
+                "        let mut args: Vec<miden_objects::Felt> = Vec::with_capacity({});",
+                procedure.num_input_elements()
+            )
+            .expect("writing to a string should not fail");
+            for (name, abi_type) in procedure.inputs() {
+                match abi_type {
+                    AbiType::Felt => writeln!(source, "        args.push({name});"),
+                    AbiType::Word => writeln!(source, "        args.extend({name}.iter().rev());"),
+                }
+                .expect("writing to a string should not fail");
+            }
+
+            writeln!(
+                source,
+                "        builder.call_procedure(\"{}\", args)\n    }}",
+                self.procedure_path(procedure.name())
+            )
+            .expect("writing to a string should not fail");
+        }
+
+        source.push_str("}\n");
+        source
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// The maximum number of stack elements occupied by the inputs or outputs of a procedure.
+pub(crate) const MAX_STACK_ELEMENTS: usize = TransactionScriptBuilder::MAX_PROCEDURE_ARGS;
+
+/// The error code with which the placeholder bodies of generated MASM stubs fail.
+const ERR_PROCEDURE_NOT_IMPLEMENTED: u32 = 0x0003_0001;
+
+/// Checks that the provided name is a valid identifier in both MASM and Rust.
+fn validate_identifier(name: &str) -> Result<(), AccountInterfaceDefinitionError> {
+    let mut chars = name.chars();
+    let is_valid = matches!(chars.next(), Some(c) if c.is_ascii_lowercase() || c == '_')
+        && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+
+    if is_valid {
+        Ok(())
+    } else {
+        Err(AccountInterfaceDefinitionError::InvalidIdentifier(name.to_string()))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use miden_objects::{Felt, ONE, ZERO};
+
+    use super::{AbiType, AbiValue, AccountInterfaceDefinition, ProcedureDefinition};
+    use crate::{errors::AccountInterfaceDefinitionError, transaction::ScriptOperation};
+
+    fn counter_interface() -> AccountInterfaceDefinition {
+        AccountInterfaceDefinition::new("my_app::counter")
+            .unwrap()
+            .with_procedure(
+                ProcedureDefinition::new("increment")
+                    .with_input("amount", AbiType::Felt)
+                    .with_input("key", AbiType::Word)
+                    .with_output("value", AbiType::Felt),
+            )
+            .unwrap()
+            .with_procedure(ProcedureDefinition::new("reset"))
+            .unwrap()
+    }
+
+    #[test]
+    fn calls_are_encoded_in_stack_order() {
+        let interface = counter_interface();
+        let key = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+
+        let operation = interface
+            .encode_call("increment", &[AbiValue::Felt(Felt::new(7)), AbiValue::Word(key)])
+            .unwrap();
+        assert_eq!(
+            operation,
+            ScriptOperation::CallProcedure {
+                path: "::my_app::counter::increment".into(),
+                args: vec![Felt::new(7), Felt::new(4), Felt::new(3), Felt::new(2), Felt::new(1)],
+            }
+        );
+
+        assert!(matches!(
+            interface.encode_call("increment", &[AbiValue::Word(key), AbiValue::Felt(ONE)]),
+            Err(AccountInterfaceDefinitionError::ArgumentTypeMismatch { .. })
+        ));
+        assert!(matches!(
+            interface.encode_call("decrement", &[]),
+            Err(AccountInterfaceDefinitionError::UnknownProcedure(_))
+        ));
+    }
+
+    #[test]
+    fn invalid_definitions_are_rejected() {
+        assert!(AccountInterfaceDefinition::new("my-app::counter").is_err());
+
+        let interface = counter_interface();
+        assert_eq!(
+            interface.clone().with_procedure(ProcedureDefinition::new("reset")),
+            Err(AccountInterfaceDefinitionError::DuplicateName("reset".into()))
+        );
+        assert!(interface.clone().with_procedure(ProcedureDefinition::new("Reset")).is_err());
+        assert!(interface
+            .clone()
+            .with_procedure(
+                ProcedureDefinition::new("set")
+                    .with_input("value", AbiType::Felt)
+                    .with_input("value", AbiType::Felt)
+            )
+            .is_err());
+
+        let too_many_inputs = (0..5).fold(ProcedureDefinition::new("set"), |procedure, i| {
+            procedure.with_input(format!("value_{i}"), AbiType::Word)
+        });
+        assert!(matches!(
+            interface.with_procedure(too_many_inputs),
+            Err(AccountInterfaceDefinitionError::TooManyStackElements { num_elements: 20, .. })
+        ));
+    }
+
+    #[test]
+    fn code_is_generated_from_definition() {
+        let interface = counter_interface();
+
+        let masm = interface.masm_stubs();
+        assert!(masm.contains("#! Inputs:  [amount, KEY, pad(11)]\n#! Outputs: [value, pad(15)]"));
+        assert!(masm.contains("const.ERR_PROCEDURE_NOT_IMPLEMENTED=0x00030001\n"));
+        assert!(masm.contains(
+            "export.increment\n    push.1 assertz.err=ERR_PROCEDURE_NOT_IMPLEMENTED\nend\n"
+        ));
+        assert!(masm.contains("#! Inputs:  [pad(16)]\n#! Outputs: [pad(16)]\nexport.reset\n"));
+        assert!(!masm.contains("TODO"));
+
+        let rust = interface.rust_client("Counter");
+        assert!(rust.contains("pub struct Counter;"));
+        assert!(rust.contains("amount: miden_objects::Felt, key: miden_objects::Word"));
+        assert!(rust.contains("args.push(amount);\n        args.extend(key.iter().rev());"));
+        assert!(rust.contains("builder.call_procedure(\"::my_app::counter::reset\", args)"));
+
+        // encoding of values is consistent with the generated client
+        assert_eq!(
+            interface.encode_call("reset", &[]).unwrap(),
+            ScriptOperation::CallProcedure {
+                path: "::my_app::counter::reset".into(),
+                args: vec![],
+            }
+        );
+        assert_eq!(AbiValue::from(ZERO).abi_type(), AbiType::Felt);
+    }
+}
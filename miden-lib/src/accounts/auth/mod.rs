@@ -1,9 +1,15 @@
+use alloc::vec::Vec;
+
 use miden_objects::{
-    accounts::{AccountComponent, StorageSlot},
+    accounts::{AccountComponent, AccountId, AuthPublicKey, StorageMap, StorageSlot},
     crypto::dsa::rpo_falcon512::PublicKey,
+    AccountError, Digest, Felt, Word, ZERO,
 };
 
-use crate::accounts::components::rpo_falcon_512_library;
+use crate::accounts::components::{rpo_falcon_512_library, rpo_falcon_512_multisig_library};
+
+// RPO FALCON 512
+// ================================================================================================
 
 /// An [`AccountComponent`] implementing the RpoFalcon512 signature scheme for authentication of
 /// transactions.
@@ -11,6 +17,9 @@ use crate::accounts::components::rpo_falcon_512_library;
 /// Its exported procedures are:
 /// - `auth_tx_rpo_falcon512`, which can be used to verify a signature provided via the advice stack
 ///   to authenticate a transaction.
+/// - `rotate_key`, which replaces the public key with a new one, authorized by a signature of the
+///   current key. A transaction script calling it can be built via
+///   [`TransactionScriptBuilder::rotate_key()`](crate::transaction::TransactionScriptBuilder).
 ///
 /// This component supports all account types.
 pub struct RpoFalcon512 {
@@ -22,6 +31,19 @@ impl RpoFalcon512 {
     pub fn new(public_key: PublicKey) -> Self {
         Self { public_key }
     }
+
+    /// Returns the public key of this component.
+    pub fn public_key(&self) -> AuthPublicKey {
+        AuthPublicKey::RpoFalcon512(self.public_key)
+    }
+}
+
+impl From<AuthPublicKey> for RpoFalcon512 {
+    fn from(public_key: AuthPublicKey) -> Self {
+        match public_key {
+            AuthPublicKey::RpoFalcon512(public_key) => Self::new(public_key),
+        }
+    }
 }
 
 impl From<RpoFalcon512> for AccountComponent {
@@ -31,6 +53,139 @@ impl From<RpoFalcon512> for AccountComponent {
             vec![StorageSlot::Value(falcon.public_key.into())],
         )
         .expect("falcon component should satisfy the requirements of a valid account component")
+        .with_name("rpo_falcon_512")
         .with_supports_all_types()
     }
 }
+
+// RPO FALCON 512 MULTISIG
+// ================================================================================================
+
+/// An [`AccountComponent`] implementing m-of-n authentication of transactions using the
+/// RpoFalcon512 signature scheme.
+///
+/// Its exported procedures are:
+/// - `auth_tx_rpo_falcon512_multisig`, which can be used to verify the signatures of `threshold`
+///   distinct signers to authenticate a transaction.
+///
+/// The component's storage layout is:
+/// - Slot 0: the configuration of the component as `[threshold, num_keys, 0, 0]`.
+/// - Slot 1: a map from `[0, 0, 0, key_index]` to the public key at that index.
+///
+/// The indices of the signers of a transaction are not part of the account state. Instead, they
+/// must be provided via the advice map under the key returned by
+/// [`RpoFalcon512Multisig::signers_advice()`]. The signature of each signer is then requested
+/// from the host in the order of the indices, so the transaction authenticator used during
+/// execution must be able to sign with the keys of all signers (e.g., a
+/// `MultiAuthenticator` wrapping the authenticators of the signers).
+///
+/// This component supports all account types.
+pub struct RpoFalcon512Multisig {
+    threshold: u32,
+    public_keys: Vec<PublicKey>,
+}
+
+impl RpoFalcon512Multisig {
+    /// Domain of the advice map key under which the indices of the signers are provided.
+    pub const SIGNERS_DOMAIN: u32 = 0x4d534947;
+
+    /// Creates a new [`RpoFalcon512Multisig`] requiring the signatures of `threshold` out of the
+    /// provided `public_keys`.
+    ///
+    /// # Errors
+    /// Returns an error if the threshold is zero or greater than the number of public keys.
+    pub fn new(threshold: u32, public_keys: Vec<PublicKey>) -> Result<Self, AccountError> {
+        if threshold == 0 || threshold as usize > public_keys.len() {
+            return Err(AccountError::MultisigInvalidThreshold {
+                threshold,
+                num_keys: public_keys.len(),
+            });
+        }
+
+        Ok(Self { threshold, public_keys })
+    }
+
+    /// Returns the number of signatures required to authenticate a transaction.
+    pub fn threshold(&self) -> u32 {
+        self.threshold
+    }
+
+    /// Returns the public keys of the signers.
+    pub fn public_keys(&self) -> &[PublicKey] {
+        &self.public_keys
+    }
+
+    /// Returns the advice map entry specifying the signers of the next transaction of the account
+    /// with the provided ID and current nonce.
+    ///
+    /// `signer_indices` must contain `threshold` indices into the public keys of the component in
+    /// strictly increasing order; otherwise, the transaction will fail to authenticate.
+    pub fn signers_advice(
+        account_id: AccountId,
+        nonce: Felt,
+        signer_indices: &[u32],
+    ) -> (Digest, Vec<Felt>) {
+        let key = Digest::from([nonce, account_id.into(), ZERO, Felt::from(Self::SIGNERS_DOMAIN)]);
+        let values = signer_indices.iter().map(|index| Felt::from(*index)).collect();
+
+        (key, values)
+    }
+}
+
+impl From<RpoFalcon512Multisig> for AccountComponent {
+    fn from(multisig: RpoFalcon512Multisig) -> Self {
+        let config: Word = [
+            Felt::from(multisig.threshold),
+            Felt::new(multisig.public_keys.len() as u64),
+            ZERO,
+            ZERO,
+        ];
+
+        let public_keys = StorageMap::with_entries(
+            multisig.public_keys.into_iter().enumerate().map(|(index, public_key)| {
+                (
+                    Digest::from([ZERO, ZERO, ZERO, Felt::new(index as u64)]),
+                    Word::from(public_key),
+                )
+            }),
+        )
+        .expect("multisig public keys should form a valid storage map");
+
+        AccountComponent::new(
+            rpo_falcon_512_multisig_library(),
+            vec![StorageSlot::Value(config), StorageSlot::Map(public_keys)],
+        )
+        .expect("multisig component should satisfy the requirements of a valid account component")
+        .with_name("rpo_falcon_512_multisig")
+        .with_supports_all_types()
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multisig_threshold() {
+        let public_keys: Vec<PublicKey> =
+            (0..3).map(|i| PublicKey::new([Felt::new(i + 1); 4])).collect();
+
+        assert!(matches!(
+            RpoFalcon512Multisig::new(0, public_keys.clone()),
+            Err(AccountError::MultisigInvalidThreshold { threshold: 0, num_keys: 3 })
+        ));
+        assert!(matches!(
+            RpoFalcon512Multisig::new(4, public_keys.clone()),
+            Err(AccountError::MultisigInvalidThreshold { threshold: 4, num_keys: 3 })
+        ));
+
+        let multisig = RpoFalcon512Multisig::new(2, public_keys).unwrap();
+        assert_eq!(multisig.threshold(), 2);
+
+        let component = AccountComponent::from(multisig);
+        assert_eq!(component.storage_size(), 2);
+    }
+}
@@ -10,6 +10,15 @@ static BASIC_WALLET_LIBRARY: LazyLock<Library> = LazyLock::new(|| {
     Library::read_from_bytes(bytes).expect("Shipped Basic Wallet library is well-formed")
 });
 
+// Initialize the Spending Limit Wallet library only once.
+static SPENDING_LIMIT_WALLET_LIBRARY: LazyLock<Library> = LazyLock::new(|| {
+    let bytes = include_bytes!(concat!(
+        env!("OUT_DIR"),
+        "/assets/account_components/spending_limit_wallet.masl"
+    ));
+    Library::read_from_bytes(bytes).expect("Shipped Spending Limit Wallet library is well-formed")
+});
+
 // Initialize the Rpo Falcon 512 library only once.
 static RPO_FALCON_512_LIBRARY: LazyLock<Library> = LazyLock::new(|| {
     let bytes =
@@ -17,6 +26,15 @@ static RPO_FALCON_512_LIBRARY: LazyLock<Library> = LazyLock::new(|| {
     Library::read_from_bytes(bytes).expect("Shipped Rpo Falcon 512 library is well-formed")
 });
 
+// Initialize the Rpo Falcon 512 Multisig library only once.
+static RPO_FALCON_512_MULTISIG_LIBRARY: LazyLock<Library> = LazyLock::new(|| {
+    let bytes = include_bytes!(concat!(
+        env!("OUT_DIR"),
+        "/assets/account_components/rpo_falcon_512_multisig.masl"
+    ));
+    Library::read_from_bytes(bytes).expect("Shipped Rpo Falcon 512 Multisig library is well-formed")
+});
+
 // Initialize the Basic Fungible Faucet library only once.
 static BASIC_FUNGIBLE_FAUCET_LIBRARY: LazyLock<Library> = LazyLock::new(|| {
     let bytes = include_bytes!(concat!(
@@ -26,17 +44,56 @@ static BASIC_FUNGIBLE_FAUCET_LIBRARY: LazyLock<Library> = LazyLock::new(|| {
     Library::read_from_bytes(bytes).expect("Shipped Basic Fungible Faucet library is well-formed")
 });
 
+// Initialize the Managed Fungible Faucet library only once.
+static MANAGED_FUNGIBLE_FAUCET_LIBRARY: LazyLock<Library> = LazyLock::new(|| {
+    let bytes = include_bytes!(concat!(
+        env!("OUT_DIR"),
+        "/assets/account_components/managed_fungible_faucet.masl"
+    ));
+    Library::read_from_bytes(bytes).expect("Shipped Managed Fungible Faucet library is well-formed")
+});
+
+// Initialize the Basic Non-Fungible Faucet library only once.
+static BASIC_NON_FUNGIBLE_FAUCET_LIBRARY: LazyLock<Library> = LazyLock::new(|| {
+    let bytes = include_bytes!(concat!(
+        env!("OUT_DIR"),
+        "/assets/account_components/basic_non_fungible_faucet.masl"
+    ));
+    Library::read_from_bytes(bytes)
+        .expect("Shipped Basic Non-Fungible Faucet library is well-formed")
+});
+
 /// Returns the Basic Wallet Library.
 pub fn basic_wallet_library() -> Library {
     BASIC_WALLET_LIBRARY.clone()
 }
 
+/// Returns the Spending Limit Wallet Library.
+pub fn spending_limit_wallet_library() -> Library {
+    SPENDING_LIMIT_WALLET_LIBRARY.clone()
+}
+
 /// Returns the Rpo Falcon 512 Library.
 pub fn rpo_falcon_512_library() -> Library {
     RPO_FALCON_512_LIBRARY.clone()
 }
 
+/// Returns the Rpo Falcon 512 Multisig Library.
+pub fn rpo_falcon_512_multisig_library() -> Library {
+    RPO_FALCON_512_MULTISIG_LIBRARY.clone()
+}
+
 /// Returns the Basic Fungible Faucet Library.
 pub fn basic_fungible_faucet_library() -> Library {
     BASIC_FUNGIBLE_FAUCET_LIBRARY.clone()
 }
+
+/// Returns the Managed Fungible Faucet Library.
+pub fn managed_fungible_faucet_library() -> Library {
+    MANAGED_FUNGIBLE_FAUCET_LIBRARY.clone()
+}
+
+/// Returns the Basic Non-Fungible Faucet Library.
+pub fn basic_non_fungible_faucet_library() -> Library {
+    BASIC_NON_FUNGIBLE_FAUCET_LIBRARY.clone()
+}
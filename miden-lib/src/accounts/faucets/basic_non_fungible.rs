@@ -0,0 +1,209 @@
+use alloc::string::ToString;
+
+use miden_objects::{
+    accounts::{
+        Account, AccountBuilder, AccountComponent, AccountStorage, AccountStorageMode, AccountType,
+        StorageSlot,
+    },
+    assets::{NonFungibleAsset, NonFungibleAssetDetails},
+    AccountError, AssetError, Felt, Word, EMPTY_WORD, ZERO,
+};
+
+use super::AuthScheme;
+use crate::accounts::{auth::RpoFalcon512, components::basic_non_fungible_faucet_library};
+
+// BASIC NON-FUNGIBLE FAUCET ACCOUNT COMPONENT
+// ================================================================================================
+
+/// An [`AccountComponent`] implementing a basic non-fungible faucet which keeps track of the
+/// issued assets.
+///
+/// Its exported procedures are:
+/// - `distribute`, which mints a non-fungible asset under the next token id and creates a note for
+///   the provided recipient.
+/// - `burn`, which burns the asset issued under the provided token id.
+///
+/// Every minted asset is assigned a sequential token id starting at 0. Token ids are never reused,
+/// even if the asset issued under a token id is burned. The kernel prevents issuing an asset with
+/// the same data as an outstanding asset.
+///
+/// Both methods require authentication. Thus, this component must be combined with a component
+/// providing authentication.
+///
+/// The component's storage layout is:
+/// - Slot 0: the issuance state as `[next_token_id, issued_count, 0, 0]`, see
+///   [`NonFungibleIssuance`].
+/// - Slot 1: a map from `[0, 0, 0, token_id]` to the asset issued under the token id.
+///
+/// This component supports accounts of type [`AccountType::NonFungibleFaucet`].
+pub struct BasicNonFungibleFaucet;
+
+impl BasicNonFungibleFaucet {
+    /// The index of the slot holding the issuance state, relative to the component's storage.
+    const ISSUANCE_SLOT: u8 = 0;
+
+    /// The index of the slot holding the issued assets, relative to the component's storage.
+    const TOKENS_SLOT: u8 = 1;
+
+    /// Returns the issuance state of a faucet whose [`BasicNonFungibleFaucet`] component starts at
+    /// the provided storage slot.
+    ///
+    /// # Errors
+    /// Returns an error if the slot is out of bounds.
+    pub fn issuance(
+        storage: &AccountStorage,
+        offset: u8,
+    ) -> Result<NonFungibleIssuance, AccountError> {
+        let issuance = Word::from(storage.get_item(offset + Self::ISSUANCE_SLOT)?);
+        Ok(NonFungibleIssuance {
+            next_token_id: issuance[0].as_int(),
+            issued_count: issuance[1].as_int(),
+        })
+    }
+
+    /// Returns the asset issued under the provided token id by a faucet whose
+    /// [`BasicNonFungibleFaucet`] component starts at the provided storage slot, or `None` if no
+    /// asset is issued under the token id.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The slot is out of bounds or is not a map.
+    /// - The stored value is not a valid non-fungible asset.
+    pub fn issued_asset(
+        storage: &AccountStorage,
+        offset: u8,
+        token_id: u64,
+    ) -> Result<Option<NonFungibleAsset>, AccountError> {
+        let value = storage.get_map_item(offset + Self::TOKENS_SLOT, Self::token_key(token_id))?;
+        if value == EMPTY_WORD {
+            return Ok(None);
+        }
+
+        NonFungibleAsset::try_from(value)
+            .map(Some)
+            .map_err(|err| AccountError::StorageSlotValueConversionError(err.to_string()))
+    }
+
+    /// Returns the key under which the asset with the provided token id is stored.
+    fn token_key(token_id: u64) -> Word {
+        [ZERO, ZERO, ZERO, Felt::new(token_id)]
+    }
+}
+
+impl From<BasicNonFungibleFaucet> for AccountComponent {
+    fn from(_: BasicNonFungibleFaucet) -> Self {
+        AccountComponent::new(
+            basic_non_fungible_faucet_library(),
+            vec![StorageSlot::empty_value(), StorageSlot::empty_map()],
+        )
+        .expect("basic non-fungible faucet component should satisfy the requirements of a valid account component")
+        .with_name("basic_non_fungible_faucet")
+        .with_supported_type(AccountType::NonFungibleFaucet)
+    }
+}
+
+// NON-FUNGIBLE ISSUANCE
+// ================================================================================================
+
+/// The issuance state of a [`BasicNonFungibleFaucet`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NonFungibleIssuance {
+    next_token_id: u64,
+    issued_count: u64,
+}
+
+impl NonFungibleIssuance {
+    /// Returns the token id which will be assigned to the next minted asset.
+    pub fn next_token_id(&self) -> u64 {
+        self.next_token_id
+    }
+
+    /// Returns the number of issued assets which have not been burned.
+    pub fn issued_count(&self) -> u64 {
+        self.issued_count
+    }
+
+    /// Returns the token id and the asset which distributing an asset with the provided details
+    /// would issue.
+    ///
+    /// # Errors
+    /// Returns an error if the details do not describe a valid non-fungible asset.
+    pub fn next_issuance(
+        &self,
+        details: &NonFungibleAssetDetails,
+    ) -> Result<(u64, NonFungibleAsset), AssetError> {
+        Ok((self.next_token_id, NonFungibleAsset::new(details)?))
+    }
+}
+
+// BASIC NON-FUNGIBLE FAUCET
+// ================================================================================================
+
+/// Creates a new faucet account with basic non-fungible faucet interface, account storage type and
+/// specified authentication scheme.
+///
+/// The basic non-fungible faucet interface exposes two procedures:
+/// - `distribute`, which mints an asset and creates a note for the provided recipient.
+/// - `burn`, which burns the asset issued under the provided token id.
+///
+/// Both procedures require authentication. The authentication procedure is defined by the
+/// specified authentication scheme.
+///
+/// The storage layout of the faucet account is:
+/// - Slot 0: Reserved slot for faucets.
+/// - Slot 1: Public Key of the authentication component.
+/// - Slot 2: Issuance state of the faucet.
+/// - Slot 3: Issued assets of the faucet.
+pub fn create_basic_non_fungible_faucet(
+    init_seed: [u8; 32],
+    account_storage_mode: AccountStorageMode,
+    auth_scheme: AuthScheme,
+) -> Result<(Account, Word), AccountError> {
+    let auth_component: RpoFalcon512 = match auth_scheme {
+        AuthScheme::RpoFalcon512 { pub_key } => RpoFalcon512::new(pub_key),
+    };
+
+    let (account, account_seed) = AccountBuilder::new()
+        .init_seed(init_seed)
+        .account_type(AccountType::NonFungibleFaucet)
+        .storage_mode(account_storage_mode)
+        .with_component(auth_component)
+        .with_component(BasicNonFungibleFaucet)
+        .build()?;
+
+    Ok((account, account_seed))
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use miden_objects::{crypto::dsa::rpo_falcon512, ONE};
+
+    use super::*;
+
+    #[test]
+    fn non_fungible_faucet_contract_creation() {
+        let pub_key = rpo_falcon512::PublicKey::new([ONE; 4]);
+        let (faucet_account, _) = create_basic_non_fungible_faucet(
+            [3; 32],
+            AccountStorageMode::Private,
+            AuthScheme::RpoFalcon512 { pub_key },
+        )
+        .unwrap();
+
+        let issuance = BasicNonFungibleFaucet::issuance(faucet_account.storage(), 2).unwrap();
+        assert_eq!(issuance.next_token_id(), 0);
+        assert_eq!(issuance.issued_count(), 0);
+        assert_eq!(
+            BasicNonFungibleFaucet::issued_asset(faucet_account.storage(), 2, 0).unwrap(),
+            None
+        );
+
+        let details = NonFungibleAssetDetails::new(faucet_account.id(), vec![1, 2, 3]).unwrap();
+        let (token_id, asset) = issuance.next_issuance(&details).unwrap();
+        assert_eq!(token_id, 0);
+        assert_eq!(asset, NonFungibleAsset::new(&details).unwrap());
+    }
+}
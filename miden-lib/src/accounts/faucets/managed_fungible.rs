@@ -0,0 +1,191 @@
+use alloc::string::ToString;
+
+use miden_objects::{
+    accounts::{
+        Account, AccountBuilder, AccountComponent, AccountStorageMode, AccountType, StorageSlot,
+    },
+    assets::{TokenMetadata, TokenSymbol},
+    crypto::dsa::rpo_falcon512::PublicKey,
+    AccountError, Felt, Word, ONE, ZERO,
+};
+
+use super::AuthScheme;
+use crate::accounts::components::managed_fungible_faucet_library;
+
+// MANAGED FUNGIBLE FAUCET ACCOUNT COMPONENT
+// ================================================================================================
+
+/// An [`AccountComponent`] implementing a fungible faucet which is managed by a single owner.
+///
+/// Its exported procedures are:
+/// - `auth_tx_rpo_falcon512`, which verifies a signature of the owner provided via the advice stack
+///   to authenticate a transaction, and completes a pending ownership transfer.
+/// - `distribute`, which mints an asset and creates a note for the provided recipient. Fails if
+///   this would cause the total issuance to exceed the maximum supply of the token.
+/// - `burn`, which burns the provided asset.
+/// - `set_paused`, which pauses or resumes minting and burning of assets.
+/// - `transfer_ownership`, which initiates the transfer of the ownership of the faucet to the owner
+///   of the provided public key. The transfer is completed when the transaction is authenticated by
+///   the current owner.
+///
+/// `distribute` and `burn` fail while the faucet is paused. In contrast to the
+/// [`BasicFungibleFaucet`](super::BasicFungibleFaucet), all procedures require authentication by
+/// the owner, which is provided by the component itself. Thus, this component must not be
+/// combined with another component providing authentication.
+///
+/// The component's storage layout is:
+/// - Slot 0: Token metadata of the faucet.
+/// - Slot 1: Public key of the owner.
+/// - Slot 2: Public key of the pending owner, or an empty word if no transfer is pending.
+/// - Slot 3: Pause flag of the faucet as `[is_paused, 0, 0, 0]`.
+///
+/// This component supports accounts of type [`AccountType::FungibleFaucet`].
+pub struct ManagedFungibleFaucet {
+    metadata: TokenMetadata,
+    owner: PublicKey,
+    paused: bool,
+}
+
+impl ManagedFungibleFaucet {
+    /// Creates a new, unpaused [`ManagedFungibleFaucet`] component owned by the owner of the
+    /// provided public key from the given pieces of metadata.
+    pub fn new(
+        symbol: TokenSymbol,
+        decimals: u8,
+        max_supply: Felt,
+        owner: PublicKey,
+    ) -> Result<Self, AccountError> {
+        let metadata = TokenMetadata::new(symbol, decimals, max_supply.as_int())
+            .map_err(|err| AccountError::FungibleFaucetInvalidMetadata(err.to_string()))?;
+
+        Ok(Self::from_metadata(metadata, owner))
+    }
+
+    /// Creates a new, unpaused [`ManagedFungibleFaucet`] component owned by the owner of the
+    /// provided public key from the given token metadata.
+    pub fn from_metadata(metadata: TokenMetadata, owner: PublicKey) -> Self {
+        Self { metadata, owner, paused: false }
+    }
+
+    /// Sets whether the faucet is initially paused.
+    pub fn with_paused(mut self, paused: bool) -> Self {
+        self.paused = paused;
+        self
+    }
+
+    /// Returns the token metadata of this faucet.
+    pub fn metadata(&self) -> TokenMetadata {
+        self.metadata
+    }
+
+    /// Returns the public key of the owner of this faucet.
+    pub fn owner(&self) -> PublicKey {
+        self.owner
+    }
+
+    /// Returns true if this faucet is initially paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+}
+
+impl From<ManagedFungibleFaucet> for AccountComponent {
+    fn from(faucet: ManagedFungibleFaucet) -> Self {
+        let metadata: Word = faucet.metadata.into();
+        let paused: Word = [if faucet.paused { ONE } else { ZERO }, ZERO, ZERO, ZERO];
+
+        AccountComponent::new(
+            managed_fungible_faucet_library(),
+            vec![
+                StorageSlot::Value(metadata),
+                StorageSlot::Value(faucet.owner.into()),
+                StorageSlot::empty_value(),
+                StorageSlot::Value(paused),
+            ],
+        )
+        .expect("managed fungible faucet component should satisfy the requirements of a valid account component")
+        .with_name("managed_fungible_faucet")
+        .with_supported_type(AccountType::FungibleFaucet)
+    }
+}
+
+// MANAGED FUNGIBLE FAUCET
+// ================================================================================================
+
+/// Creates a new faucet account with managed fungible faucet interface, account storage type,
+/// owner defined by the specified authentication scheme, and provided meta data (token symbol,
+/// decimals, max supply).
+///
+/// See [`ManagedFungibleFaucet`] for the procedures exposed by the faucet.
+///
+/// The storage layout of the faucet account is:
+/// - Slot 0: Reserved slot for faucets.
+/// - Slot 1: Token metadata of the faucet.
+/// - Slot 2: Public key of the owner.
+/// - Slot 3: Public key of the pending owner.
+/// - Slot 4: Pause flag of the faucet.
+pub fn create_managed_fungible_faucet(
+    init_seed: [u8; 32],
+    symbol: TokenSymbol,
+    decimals: u8,
+    max_supply: Felt,
+    account_storage_mode: AccountStorageMode,
+    auth_scheme: AuthScheme,
+) -> Result<(Account, Word), AccountError> {
+    let owner = match auth_scheme {
+        AuthScheme::RpoFalcon512 { pub_key } => pub_key,
+    };
+
+    let (account, account_seed) = AccountBuilder::new()
+        .init_seed(init_seed)
+        .account_type(AccountType::FungibleFaucet)
+        .storage_mode(account_storage_mode)
+        .with_component(ManagedFungibleFaucet::new(symbol, decimals, max_supply, owner)?)
+        .build()?;
+
+    Ok((account, account_seed))
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use miden_objects::crypto::dsa::rpo_falcon512;
+
+    use super::*;
+
+    #[test]
+    fn managed_faucet_contract_creation() {
+        let pub_key = rpo_falcon512::PublicKey::new([ONE; 4]);
+        let token_symbol = TokenSymbol::try_from("POL").unwrap();
+
+        let (faucet_account, _) = create_managed_fungible_faucet(
+            [7; 32],
+            token_symbol,
+            2,
+            Felt::new(123),
+            AccountStorageMode::Private,
+            AuthScheme::RpoFalcon512 { pub_key },
+        )
+        .unwrap();
+
+        let storage = faucet_account.storage();
+        assert_eq!(
+            storage.get_item(1).unwrap(),
+            [Felt::new(123), Felt::new(2), token_symbol.into(), ZERO].into()
+        );
+        assert_eq!(storage.get_item(2).unwrap(), Word::from(pub_key).into());
+        assert_eq!(storage.get_item(3).unwrap(), Word::default().into());
+        assert_eq!(storage.get_item(4).unwrap(), Word::default().into());
+        assert!(faucet_account.is_faucet());
+
+        // a faucet can be created in the paused state
+        let component: AccountComponent =
+            ManagedFungibleFaucet::new(token_symbol, 2, Felt::new(123), pub_key)
+                .unwrap()
+                .with_paused(true)
+                .into();
+        assert_eq!(component.storage_slots()[3], StorageSlot::Value([ONE, ZERO, ZERO, ZERO]));
+    }
+}
@@ -4,13 +4,21 @@ use miden_objects::{
     accounts::{
         Account, AccountBuilder, AccountComponent, AccountStorageMode, AccountType, StorageSlot,
     },
-    assets::TokenSymbol,
-    AccountError, Felt, FieldElement, Word,
+    assets::{TokenMetadata, TokenSymbol},
+    AccountError, Felt, Word,
 };
 
 use super::AuthScheme;
 use crate::accounts::{auth::RpoFalcon512, components::basic_fungible_faucet_library};
 
+mod basic_non_fungible;
+pub use basic_non_fungible::{
+    create_basic_non_fungible_faucet, BasicNonFungibleFaucet, NonFungibleIssuance,
+};
+
+mod managed_fungible;
+pub use managed_fungible::{create_managed_fungible_faucet, ManagedFungibleFaucet};
+
 // BASIC FUNGIBLE FAUCET ACCOUNT COMPONENT
 // ================================================================================================
 
@@ -26,26 +34,27 @@ use crate::accounts::{auth::RpoFalcon512, components::basic_fungible_faucet_libr
 ///
 /// This component supports accounts of type [`AccountType::FungibleFaucet`].
 pub struct BasicFungibleFaucet {
-    symbol: TokenSymbol,
-    decimals: u8,
-    max_supply: Felt,
+    metadata: TokenMetadata,
 }
 
 impl BasicFungibleFaucet {
     /// Creates a new [`BasicFungibleFaucet`] component from the given pieces of metadata.
     pub fn new(symbol: TokenSymbol, decimals: u8, max_supply: Felt) -> Result<Self, AccountError> {
         // First check that the metadata is valid.
-        if decimals > MAX_DECIMALS {
-            return Err(AccountError::FungibleFaucetInvalidMetadata(
-                "Decimals must be less than 13".to_string(),
-            ));
-        } else if max_supply.as_int() > MAX_MAX_SUPPLY {
-            return Err(AccountError::FungibleFaucetInvalidMetadata(
-                "Max supply must be < 2^63".to_string(),
-            ));
-        }
-
-        Ok(Self { symbol, decimals, max_supply })
+        let metadata = TokenMetadata::new(symbol, decimals, max_supply.as_int())
+            .map_err(|err| AccountError::FungibleFaucetInvalidMetadata(err.to_string()))?;
+
+        Ok(Self::from_metadata(metadata))
+    }
+
+    /// Creates a new [`BasicFungibleFaucet`] component from the given token metadata.
+    pub fn from_metadata(metadata: TokenMetadata) -> Self {
+        Self { metadata }
+    }
+
+    /// Returns the token metadata of this faucet.
+    pub fn metadata(&self) -> TokenMetadata {
+        self.metadata
     }
 }
 
@@ -53,11 +62,11 @@ impl From<BasicFungibleFaucet> for AccountComponent {
     fn from(faucet: BasicFungibleFaucet) -> Self {
         // Note: data is stored as [a0, a1, a2, a3] but loaded onto the stack as
         // [a3, a2, a1, a0, ...]
-        let metadata =
-            [faucet.max_supply, Felt::from(faucet.decimals), faucet.symbol.into(), Felt::ZERO];
+        let metadata: Word = faucet.metadata.into();
 
         AccountComponent::new(basic_fungible_faucet_library(), vec![StorageSlot::Value(metadata)])
             .expect("basic fungible faucet component should satisfy the requirements of a valid account component")
+            .with_name("basic_fungible_faucet")
             .with_supported_type(AccountType::FungibleFaucet)
     }
 }
@@ -65,9 +74,6 @@ impl From<BasicFungibleFaucet> for AccountComponent {
 // FUNGIBLE FAUCET
 // ================================================================================================
 
-const MAX_MAX_SUPPLY: u64 = (1 << 63) - 1;
-const MAX_DECIMALS: u8 = 12;
-
 /// Creates a new faucet account with basic fungible faucet interface,
 /// account storage type, specified authentication scheme, and provided meta data (token symbol,
 /// decimals, max supply).
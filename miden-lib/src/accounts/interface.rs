@@ -0,0 +1,329 @@
+use alloc::vec::Vec;
+
+use miden_objects::{
+    accounts::{Account, AccountCode, AccountId},
+    assembly::Library,
+    notes::Note,
+};
+
+use crate::{
+    accounts::components::{
+        basic_fungible_faucet_library, basic_non_fungible_faucet_library, basic_wallet_library,
+        managed_fungible_faucet_library, rpo_falcon_512_library, rpo_falcon_512_multisig_library,
+        spending_limit_wallet_library,
+    },
+    notes::WellKnownNote,
+};
+
+// ACCOUNT COMPONENT INTERFACE
+// ================================================================================================
+
+/// A well-known account component interface shipped with this crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AccountComponentInterface {
+    /// The interface of the [`BasicWallet`](super::wallets::BasicWallet) component.
+    BasicWallet,
+    /// The interface of the [`SpendingLimitWallet`](super::wallets::SpendingLimitWallet)
+    /// component.
+    SpendingLimitWallet,
+    /// The interface of the [`RpoFalcon512`](super::auth::RpoFalcon512) component.
+    RpoFalcon512,
+    /// The interface of the [`RpoFalcon512Multisig`](super::auth::RpoFalcon512Multisig) component.
+    RpoFalcon512Multisig,
+    /// The interface of the [`BasicFungibleFaucet`](super::faucets::BasicFungibleFaucet)
+    /// component.
+    BasicFungibleFaucet,
+    /// The interface of the [`ManagedFungibleFaucet`](super::faucets::ManagedFungibleFaucet)
+    /// component.
+    ManagedFungibleFaucet,
+    /// The interface of the [`BasicNonFungibleFaucet`](super::faucets::BasicNonFungibleFaucet)
+    /// component.
+    BasicNonFungibleFaucet,
+}
+
+impl AccountComponentInterface {
+    /// All well-known account component interfaces.
+    pub const ALL: [Self; 7] = [
+        Self::BasicWallet,
+        Self::SpendingLimitWallet,
+        Self::RpoFalcon512,
+        Self::RpoFalcon512Multisig,
+        Self::BasicFungibleFaucet,
+        Self::ManagedFungibleFaucet,
+        Self::BasicNonFungibleFaucet,
+    ];
+
+    /// Returns the library of the component implementing this interface.
+    pub fn library(&self) -> Library {
+        match self {
+            Self::BasicWallet => basic_wallet_library(),
+            Self::SpendingLimitWallet => spending_limit_wallet_library(),
+            Self::RpoFalcon512 => rpo_falcon_512_library(),
+            Self::RpoFalcon512Multisig => rpo_falcon_512_multisig_library(),
+            Self::BasicFungibleFaucet => basic_fungible_faucet_library(),
+            Self::ManagedFungibleFaucet => managed_fungible_faucet_library(),
+            Self::BasicNonFungibleFaucet => basic_non_fungible_faucet_library(),
+        }
+    }
+
+    /// Returns true if the provided account code exports all procedures of this interface.
+    pub fn is_implemented_by(&self, code: &AccountCode) -> bool {
+        let library = self.library();
+        library.exports().all(|export| {
+            let root = library.mast_forest()[library.get_export_node_id(export)].digest();
+            code.has_procedure(root)
+        })
+    }
+
+    /// Returns true if this is the interface of a wallet component, i.e., if it exports the
+    /// `receive_asset` procedure of the basic wallet.
+    pub fn is_wallet(&self) -> bool {
+        matches!(self, Self::BasicWallet | Self::SpendingLimitWallet)
+    }
+
+    /// Returns true if this is the interface of an authentication component.
+    pub fn is_auth(&self) -> bool {
+        matches!(
+            self,
+            Self::RpoFalcon512 | Self::RpoFalcon512Multisig | Self::ManagedFungibleFaucet
+        )
+    }
+
+    /// Returns true if this is the interface of a faucet component.
+    pub fn is_faucet(&self) -> bool {
+        matches!(
+            self,
+            Self::BasicFungibleFaucet | Self::ManagedFungibleFaucet | Self::BasicNonFungibleFaucet
+        )
+    }
+}
+
+// NOTE ACCOUNT COMPATIBILITY
+// ================================================================================================
+
+/// Describes whether an account can consume a note.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoteAccountCompatibility {
+    /// The account cannot consume the note, e.g. because the account does not export the
+    /// procedures invoked by the note script or is not allowed to consume the note.
+    No,
+    /// The account might be able to consume the note. Whether it can depends on state which is not
+    /// known in advance, e.g. the vault of the account, the block height or the note script
+    /// itself.
+    Maybe,
+    /// The account can consume the note.
+    Yes,
+}
+
+// ACCOUNT INTERFACE
+// ================================================================================================
+
+/// The well-known interfaces implemented by an account.
+///
+/// An [AccountInterface] is derived from the code of an account by checking which of the
+/// [AccountComponentInterface]s the code exports in full. It can be used to determine whether the
+/// account can consume a note via [AccountInterface::can_consume()] before attempting to execute a
+/// transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountInterface {
+    account_id: AccountId,
+    components: Vec<AccountComponentInterface>,
+}
+
+impl AccountInterface {
+    /// Returns the interface of the account with the provided ID and code.
+    pub fn new(account_id: AccountId, code: &AccountCode) -> Self {
+        let components = AccountComponentInterface::ALL
+            .into_iter()
+            .filter(|component| component.is_implemented_by(code))
+            .collect();
+
+        Self { account_id, components }
+    }
+
+    /// Returns the ID of the account.
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the well-known component interfaces implemented by the account.
+    pub fn components(&self) -> &[AccountComponentInterface] {
+        &self.components
+    }
+
+    /// Returns true if the account implements the provided component interface.
+    pub fn contains(&self, component: AccountComponentInterface) -> bool {
+        self.components.contains(&component)
+    }
+
+    /// Returns true if the account implements a wallet interface.
+    pub fn is_wallet(&self) -> bool {
+        self.components.iter().any(AccountComponentInterface::is_wallet)
+    }
+
+    /// Returns true if the account implements an authentication interface.
+    pub fn is_auth(&self) -> bool {
+        self.components.iter().any(AccountComponentInterface::is_auth)
+    }
+
+    /// Returns true if the account implements a faucet interface.
+    pub fn is_faucet(&self) -> bool {
+        self.components.iter().any(AccountComponentInterface::is_faucet)
+    }
+
+    /// Returns whether the account can consume the provided note.
+    ///
+    /// For the well-known notes, the check is based on the procedures invoked by the note script
+    /// and on the accounts allowed to consume the note:
+    /// - P2ID notes require a wallet and can only be consumed by the target account.
+    /// - P2IDC notes require a wallet and can only be consumed by the target account, if the
+    ///   condition of the note is satisfied.
+    /// - P2IDR notes require a wallet and can be consumed by the target account, or by the sender
+    ///   once the recall height is reached.
+    /// - SWAP and SWAPP notes require the basic wallet and can be consumed if the account's vault
+    ///   holds the requested asset.
+    /// - SPLIT notes require the basic wallet and can be consumed by any account.
+    ///
+    /// Whether notes with other scripts can be consumed cannot be determined in advance.
+    pub fn can_consume(&self, note: &Note) -> NoteAccountCompatibility {
+        let Ok(well_known_note) = WellKnownNote::try_from(note) else {
+            return NoteAccountCompatibility::Maybe;
+        };
+
+        // notes moving assets out of the account invoke `move_asset_to_note` of the basic wallet,
+        // which is not exported by the spending limit wallet
+        let is_basic_wallet = self.contains(AccountComponentInterface::BasicWallet);
+
+        match well_known_note {
+            WellKnownNote::P2id(note) if self.is_wallet() && note.target() == self.account_id => {
+                NoteAccountCompatibility::Yes
+            },
+            WellKnownNote::P2idc(note) if self.is_wallet() && note.target() == self.account_id => {
+                NoteAccountCompatibility::Maybe
+            },
+            WellKnownNote::P2idr(note) if self.is_wallet() && note.target() == self.account_id => {
+                NoteAccountCompatibility::Yes
+            },
+            WellKnownNote::P2idr(note)
+                if self.is_wallet() && note.note().metadata().sender() == self.account_id =>
+            {
+                NoteAccountCompatibility::Maybe
+            },
+            WellKnownNote::Swap(_) | WellKnownNote::Swapp(_) if is_basic_wallet => {
+                NoteAccountCompatibility::Maybe
+            },
+            WellKnownNote::Split(_) if is_basic_wallet => NoteAccountCompatibility::Yes,
+            _ => NoteAccountCompatibility::No,
+        }
+    }
+}
+
+impl From<&Account> for AccountInterface {
+    fn from(account: &Account) -> Self {
+        Self::new(account.id(), account.code())
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use miden_objects::{
+        accounts::{
+            account_id::testing::{ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_SENDER},
+            AccountStorageMode, AccountType,
+        },
+        assets::{Asset, FungibleAsset, TokenSymbol},
+        crypto::{dsa::rpo_falcon512::PublicKey, rand::RpoRandomCoin},
+        notes::NoteType,
+        Felt, Word, ONE, ZERO,
+    };
+
+    use super::*;
+    use crate::{
+        accounts::{faucets::create_basic_fungible_faucet, wallets::create_basic_wallet},
+        notes::{create_p2id_note, create_p2idr_note, create_swap_note},
+        AuthScheme,
+    };
+
+    #[test]
+    fn test_account_interface_can_consume() {
+        let auth_scheme = AuthScheme::RpoFalcon512 { pub_key: PublicKey::new([ONE; 4]) };
+        let (wallet, _) = create_basic_wallet(
+            [1; 32],
+            auth_scheme,
+            AccountType::RegularAccountUpdatableCode,
+            AccountStorageMode::Private,
+        )
+        .unwrap();
+        let (faucet, _) = create_basic_fungible_faucet(
+            [2; 32],
+            TokenSymbol::try_from("POL").unwrap(),
+            2,
+            Felt::new(100),
+            AccountStorageMode::Private,
+            auth_scheme,
+        )
+        .unwrap();
+
+        let wallet_interface = AccountInterface::from(&wallet);
+        assert_eq!(
+            wallet_interface.components(),
+            [AccountComponentInterface::BasicWallet, AccountComponentInterface::RpoFalcon512]
+        );
+        assert!(wallet_interface.is_wallet() && wallet_interface.is_auth());
+        assert!(!wallet_interface.is_faucet());
+
+        let faucet_interface = AccountInterface::from(&faucet);
+        assert_eq!(
+            faucet_interface.components(),
+            [
+                AccountComponentInterface::RpoFalcon512,
+                AccountComponentInterface::BasicFungibleFaucet
+            ]
+        );
+        assert!(faucet_interface.is_faucet() && !faucet_interface.is_wallet());
+
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let asset: Asset = FungibleAsset::new(
+            AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap(),
+            10,
+        )
+        .unwrap()
+        .into();
+        let mut rng = RpoRandomCoin::new(Word::default());
+
+        // P2ID notes can only be consumed by the target wallet
+        let p2id =
+            create_p2id_note(sender, wallet.id(), vec![asset], NoteType::Public, ZERO, &mut rng)
+                .unwrap();
+        assert_eq!(wallet_interface.can_consume(&p2id), NoteAccountCompatibility::Yes);
+        assert_eq!(faucet_interface.can_consume(&p2id), NoteAccountCompatibility::No);
+
+        let p2id =
+            create_p2id_note(sender, faucet.id(), vec![asset], NoteType::Public, ZERO, &mut rng)
+                .unwrap();
+        assert_eq!(wallet_interface.can_consume(&p2id), NoteAccountCompatibility::No);
+        assert_eq!(faucet_interface.can_consume(&p2id), NoteAccountCompatibility::No);
+
+        // P2IDR notes can be reclaimed by the sender once the recall height is reached
+        let p2idr = create_p2idr_note(
+            wallet.id(),
+            sender,
+            vec![asset],
+            NoteType::Public,
+            ZERO,
+            42,
+            &mut rng,
+        )
+        .unwrap();
+        assert_eq!(wallet_interface.can_consume(&p2idr), NoteAccountCompatibility::Maybe);
+
+        // SWAP notes depend on the vault of the consuming account
+        let (swap, _) =
+            create_swap_note(sender, asset, asset, NoteType::Public, ZERO, &mut rng).unwrap();
+        assert_eq!(wallet_interface.can_consume(&swap), NoteAccountCompatibility::Maybe);
+        assert_eq!(faucet_interface.can_consume(&swap), NoteAccountCompatibility::No);
+    }
+}
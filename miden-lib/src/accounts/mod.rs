@@ -1,6 +1,8 @@
 use super::auth::AuthScheme;
 
+pub mod abi;
 pub mod auth;
 pub(super) mod components;
 pub mod faucets;
+pub mod interface;
 pub mod wallets;
@@ -8,6 +8,9 @@ use miden_objects::{
 use super::AuthScheme;
 use crate::accounts::{auth::RpoFalcon512, components::basic_wallet_library};
 
+mod spending_limit;
+pub use spending_limit::{SpendingLimit, SpendingLimitWallet};
+
 // BASIC WALLET
 // ================================================================================================
 
@@ -29,6 +32,7 @@ impl From<BasicWallet> for AccountComponent {
     fn from(_: BasicWallet) -> Self {
         AccountComponent::new(basic_wallet_library(), vec![])
           .expect("basic wallet component should satisfy the requirements of a valid account component")
+          .with_name("basic_wallet")
           .with_supports_all_types()
     }
 }
@@ -0,0 +1,184 @@
+use alloc::{string::ToString, vec::Vec};
+
+use miden_objects::{
+    accounts::{AccountComponent, AccountId, AccountType, StorageMap, StorageSlot},
+    assets::FungibleAsset,
+    AccountError, Digest, Felt, Word, ZERO,
+};
+
+use crate::accounts::components::spending_limit_wallet_library;
+
+// SPENDING LIMIT
+// ================================================================================================
+
+/// A limit on the amount of assets of a fungible faucet that can be moved out of an account per
+/// interval of blocks.
+///
+/// Intervals are aligned to block numbers which are multiples of the interval length. For example,
+/// with an interval of 1000 blocks, at most `max_amount` assets can be spent in blocks `[0, 1000)`,
+/// at most `max_amount` assets in blocks `[1000, 2000)`, and so on. Transactions whose reference
+/// block falls into an interval preceding the last interval in which assets were spent fail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpendingLimit {
+    faucet_id: AccountId,
+    max_amount: u64,
+    interval: u32,
+}
+
+impl SpendingLimit {
+    /// Returns a new [SpendingLimit] allowing at most `max_amount` assets of the specified faucet
+    /// to be spent every `interval` blocks.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The faucet is not a fungible faucet.
+    /// - The maximum amount is greater than [FungibleAsset::MAX_AMOUNT].
+    /// - The interval is zero.
+    pub fn new(faucet_id: AccountId, max_amount: u64, interval: u32) -> Result<Self, AccountError> {
+        if !matches!(faucet_id.account_type(), AccountType::FungibleFaucet) {
+            return Err(AccountError::SpendingLimitInvalidConfig(
+                "spending limits can only be set on fungible faucets".to_string(),
+            ));
+        }
+
+        if max_amount > FungibleAsset::MAX_AMOUNT {
+            return Err(AccountError::SpendingLimitInvalidConfig(format!(
+                "max amount {max_amount} is greater than the maximum fungible asset amount"
+            )));
+        }
+
+        if interval == 0 {
+            return Err(AccountError::SpendingLimitInvalidConfig(
+                "spending limit interval must be greater than zero".to_string(),
+            ));
+        }
+
+        Ok(Self { faucet_id, max_amount, interval })
+    }
+
+    /// Returns the ID of the faucet whose assets are limited.
+    pub fn faucet_id(&self) -> AccountId {
+        self.faucet_id
+    }
+
+    /// Returns the maximum amount of assets that can be spent per interval.
+    pub fn max_amount(&self) -> u64 {
+        self.max_amount
+    }
+
+    /// Returns the length of an interval in blocks.
+    pub fn interval(&self) -> u32 {
+        self.interval
+    }
+
+    /// Returns the key under which this limit is stored in the storage maps of the component.
+    fn storage_key(&self) -> Digest {
+        Digest::from([ZERO, ZERO, ZERO, self.faucet_id.into()])
+    }
+}
+
+// SPENDING LIMIT WALLET
+// ================================================================================================
+
+/// An [`AccountComponent`] implementing a wallet which enforces per-interval spending limits on
+/// fungible assets.
+///
+/// Its exported procedures are:
+/// - `receive_asset`, which can be used to add an asset to the account.
+/// - `create_note`, which can be used to create a new note without any assets attached to it.
+/// - `move_asset_to_note`, which can be used to remove the specified asset from the account and add
+///   it to the output note with the specified index. Fails if this would exceed the spending limit
+///   of the asset's faucet in the current interval.
+///
+/// Non-fungible assets and fungible assets of faucets without a configured limit are not
+/// restricted. `receive_asset` and `create_note` are the same procedures as the ones of the
+/// [`BasicWallet`](super::BasicWallet), so this component cannot be combined with it (and must not
+/// be combined with any other component moving assets out of the account).
+///
+/// The component's storage layout is:
+/// - Slot 0: a map from `[0, 0, 0, faucet_id]` to `[max_amount, interval, 0, 0]`.
+/// - Slot 1: a map from `[0, 0, 0, faucet_id]` to `[interval_start, spent_amount, 0, 0]`, where
+///   `spent_amount` is the amount spent in the interval starting at block `interval_start`.
+///
+/// All methods require authentication. Thus, this component must be combined with a component
+/// providing authentication.
+///
+/// This component supports all account types.
+pub struct SpendingLimitWallet {
+    limits: Vec<SpendingLimit>,
+}
+
+impl SpendingLimitWallet {
+    /// Creates a new [`SpendingLimitWallet`] component enforcing the provided spending limits.
+    ///
+    /// # Errors
+    /// Returns an error if more than one limit is provided for the same faucet.
+    pub fn new(limits: Vec<SpendingLimit>) -> Result<Self, AccountError> {
+        for (i, limit) in limits.iter().enumerate() {
+            if limits[..i].iter().any(|other| other.faucet_id == limit.faucet_id) {
+                return Err(AccountError::SpendingLimitInvalidConfig(format!(
+                    "duplicate spending limit for faucet {}",
+                    limit.faucet_id
+                )));
+            }
+        }
+
+        Ok(Self { limits })
+    }
+
+    /// Returns the spending limits enforced by this component.
+    pub fn limits(&self) -> &[SpendingLimit] {
+        &self.limits
+    }
+}
+
+impl From<SpendingLimitWallet> for AccountComponent {
+    fn from(wallet: SpendingLimitWallet) -> Self {
+        let limits = StorageMap::with_entries(wallet.limits.iter().map(|limit| {
+            let value: Word = [Felt::new(limit.max_amount), Felt::from(limit.interval), ZERO, ZERO];
+            (limit.storage_key(), value)
+        }))
+        .expect("spending limits should form a valid storage map");
+
+        AccountComponent::new(
+            spending_limit_wallet_library(),
+            vec![StorageSlot::Map(limits), StorageSlot::Map(StorageMap::new())],
+        )
+        .expect(
+            "spending limit wallet component should satisfy the requirements of a valid account component",
+        )
+        .with_name("spending_limit_wallet")
+        .with_supports_all_types()
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use miden_objects::accounts::account_id::testing::{
+        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_spending_limit_config() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let nft_faucet_id = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+
+        assert!(SpendingLimit::new(nft_faucet_id, 100, 1000).is_err());
+        assert!(SpendingLimit::new(faucet_id, FungibleAsset::MAX_AMOUNT + 1, 1000).is_err());
+        assert!(SpendingLimit::new(faucet_id, 100, 0).is_err());
+
+        let limit = SpendingLimit::new(faucet_id, 100, 1000).unwrap();
+        assert!(matches!(
+            SpendingLimitWallet::new(vec![limit, limit]),
+            Err(AccountError::SpendingLimitInvalidConfig(_))
+        ));
+
+        let component = AccountComponent::from(SpendingLimitWallet::new(vec![limit]).unwrap());
+        assert_eq!(component.storage_size(), 2);
+    }
+}
@@ -1,2 +1,193 @@
+use alloc::{string::String, vec::Vec};
+use core::{fmt, ops::Range};
+
+use crate::accounts::abi::{AbiType, MAX_STACK_ELEMENTS};
+
 #[rustfmt::skip]
 pub mod tx_kernel_errors;
+use tx_kernel_errors::{TX_KERNEL_ERRORS, TX_KERNEL_ERROR_NAMES};
+
+// KERNEL ERROR LOOKUP
+// ================================================================================================
+
+/// Returns the name of the error constant with the specified error code (e.g.,
+/// `ERR_ACCOUNT_NONCE_INCREASE_MUST_BE_U32`), or `None` if the code is not a kernel error code.
+pub fn kernel_error_name(err_code: u32) -> Option<&'static str> {
+    TX_KERNEL_ERROR_NAMES
+        .iter()
+        .find(|(code, _)| *code == err_code)
+        .map(|(_, name)| *name)
+}
+
+/// Returns the message of the error with the specified error code, or `None` if the code is not
+/// a kernel error code.
+pub fn kernel_error_message(err_code: u32) -> Option<&'static str> {
+    TX_KERNEL_ERRORS
+        .iter()
+        .find(|(code, _)| *code == err_code)
+        .map(|(_, message)| *message)
+}
+
+// KERNEL ERROR LOCATION
+// ================================================================================================
+
+/// The location of a failed assertion in the MASM source, as recorded in the debug info of the
+/// executed code.
+///
+/// Locations are only available for code assembled in debug mode, e.g., for the transaction
+/// kernel and the Miden library if `miden-lib` is built with the `with-debug-info` feature.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KernelErrorLocation {
+    procedure: String,
+    file: String,
+    span: Range<u32>,
+}
+
+impl KernelErrorLocation {
+    /// Returns a new [KernelErrorLocation] for an assertion in the specified procedure which
+    /// covers the specified byte range of the specified source file.
+    pub fn new(procedure: impl Into<String>, file: impl Into<String>, span: Range<u32>) -> Self {
+        Self {
+            procedure: procedure.into(),
+            file: file.into(),
+            span,
+        }
+    }
+
+    /// Returns the fully qualified name of the procedure containing the assertion.
+    pub fn procedure(&self) -> &str {
+        &self.procedure
+    }
+
+    /// Returns the path of the source file containing the assertion, as recorded by the
+    /// assembler.
+    pub fn file(&self) -> &str {
+        &self.file
+    }
+
+    /// Returns the byte range of the assertion in its source file.
+    pub fn span(&self) -> Range<usize> {
+        self.span.start as usize..self.span.end as usize
+    }
+
+    /// Returns the line of the assertion (starting at 1), given the content of its source file.
+    pub fn line(&self, source: &str) -> usize {
+        let end = self.span().start.min(source.len());
+        source.as_bytes()[..end].iter().filter(|byte| **byte == b'\n').count() + 1
+    }
+}
+
+impl fmt::Display for KernelErrorLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({}:{}..{})", self.procedure, self.file, self.span.start, self.span.end)
+    }
+}
+
+// ACCOUNT INTERFACE DEFINITION ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccountInterfaceDefinitionError {
+    ArgumentTypeMismatch {
+        procedure: String,
+        expected: Vec<AbiType>,
+        actual: Vec<AbiType>,
+    },
+    DuplicateName(String),
+    InvalidIdentifier(String),
+    TooManyStackElements {
+        procedure: String,
+        num_elements: usize,
+    },
+    UnknownProcedure(String),
+}
+
+impl fmt::Display for AccountInterfaceDefinitionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ArgumentTypeMismatch { procedure, expected, actual } => {
+                write!(
+                    f,
+                    "procedure {procedure} expects arguments of types {expected:?}, but got \
+                     {actual:?}"
+                )
+            },
+            Self::DuplicateName(name) => write!(f, "name {name} is defined more than once"),
+            Self::InvalidIdentifier(name) => write!(f, "{name} is not a valid identifier"),
+            Self::TooManyStackElements { procedure, num_elements } => {
+                write!(
+                    f,
+                    "procedure {procedure} uses {num_elements} stack elements, but at most \
+                     {MAX_STACK_ELEMENTS} are supported"
+                )
+            },
+            Self::UnknownProcedure(name) => write!(f, "procedure {name} is not defined"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AccountInterfaceDefinitionError {}
+
+// MASM TEMPLATE ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MasmTemplateError {
+    CompilationFailed(String),
+    InvalidPlaceholder(String),
+    MissingValue(String),
+    UnusedValue(String),
+}
+
+impl fmt::Display for MasmTemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CompilationFailed(err) => write!(f, "rendered template failed to compile: {err}"),
+            Self::InvalidPlaceholder(placeholder) => {
+                write!(f, "template contains malformed placeholder `{placeholder}`")
+            },
+            Self::MissingValue(name) => write!(f, "no value bound to placeholder `{name}`"),
+            Self::UnusedValue(name) => {
+                write!(f, "value bound to `{name}` is not used by the template")
+            },
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MasmTemplateError {}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::{tx_kernel_errors::ERR_ACCOUNT_NONCE_INCREASE_MUST_BE_U32, *};
+
+    #[test]
+    fn kernel_error_lookup() {
+        let err_code = ERR_ACCOUNT_NONCE_INCREASE_MUST_BE_U32;
+        assert_eq!(kernel_error_name(err_code), Some("ERR_ACCOUNT_NONCE_INCREASE_MUST_BE_U32"));
+        assert!(kernel_error_message(err_code).is_some());
+
+        assert_eq!(kernel_error_name(0), None);
+        assert_eq!(kernel_error_message(0), None);
+    }
+
+    #[test]
+    fn kernel_error_location_line() {
+        let source = "export.foo\n    push.0\n    assert.err=1\nend\n";
+        let start = source.find("assert").unwrap() as u32;
+        let location = KernelErrorLocation::new("::test::foo", "test.masm", start..start + 12);
+
+        assert_eq!(&source[location.span()], "assert.err=1");
+        assert_eq!(location.line(source), 3);
+        assert_eq!(
+            location.to_string(),
+            format!("::test::foo (test.masm:{start}..{})", start + 12)
+        );
+    }
+}
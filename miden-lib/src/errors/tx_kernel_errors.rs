@@ -17,8 +17,10 @@ pub const ERR_ACCOUNT_INVALID_STORAGE_OFFSET_FOR_SIZE: u32 = 0x00020013;
 pub const ERR_ACCOUNT_IS_NOT_NATIVE: u32 = 0x00020030;
 pub const ERR_ACCOUNT_NONCE_DID_NOT_INCREASE_AFTER_STATE_CHANGE: u32 = 0x00020028;
 pub const ERR_ACCOUNT_NONCE_INCREASE_MUST_BE_U32: u32 = 0x00020004;
+pub const ERR_ACCOUNT_NONCE_NOT_INCREMENTED_BY_ONE: u32 = 0x00020071;
 pub const ERR_ACCOUNT_POW_IS_INSUFFICIENT: u32 = 0x00020008;
 pub const ERR_ACCOUNT_PROC_INDEX_OUT_OF_BOUNDS: u32 = 0x0002000C;
+pub const ERR_ACCOUNT_PROC_IS_READ_ONLY: u32 = 0x00020068;
 pub const ERR_ACCOUNT_PROC_NOT_PART_OF_ACCOUNT_CODE: u32 = 0x0002000B;
 pub const ERR_ACCOUNT_READING_MAP_VALUE_FROM_NON_MAP_SLOT: u32 = 0x00020002;
 pub const ERR_ACCOUNT_SEED_DIGEST_MISMATCH: u32 = 0x00020007;
@@ -29,11 +31,19 @@ pub const ERR_ACCOUNT_TOO_MANY_PROCEDURES: u32 = 0x00020010;
 pub const ERR_ACCOUNT_TOO_MANY_STORAGE_SLOTS: u32 = 0x00020011;
 pub const ERR_ACCOUNT_TOTAL_ISSUANCE_PROC_CAN_ONLY_BE_CALLED_ON_FUNGIBLE_FAUCET: u32 = 0x00020001;
 
+pub const ERR_ARRAY_INDEX_OUT_OF_BOUNDS: u32 = 0x0002006F;
+pub const ERR_ARRAY_SLOT_NOT_ARRAY: u32 = 0x00020070;
+
+pub const ERR_AUTH_NEW_PUBLIC_KEY_IS_EMPTY: u32 = 0x0002006D;
+
 pub const ERR_EPILOGUE_TOTAL_NUMBER_OF_ASSETS_MUST_STAY_THE_SAME: u32 = 0x00020029;
 
 pub const ERR_FAUCET_BURN_CANNOT_EXCEED_EXISTING_TOTAL_SUPPLY: u32 = 0x0002002B;
 pub const ERR_FAUCET_BURN_NON_FUNGIBLE_ASSET_CAN_ONLY_BE_CALLED_ON_NON_FUNGIBLE_FAUCET: u32 = 0x0002002D;
+pub const ERR_FAUCET_INVALID_PAUSE_FLAG: u32 = 0x0002006A;
 pub const ERR_FAUCET_INVALID_STORAGE_OFFSET: u32 = 0x0002000E;
+pub const ERR_FAUCET_IS_PAUSED: u32 = 0x00020069;
+pub const ERR_FAUCET_NEW_OWNER_KEY_IS_EMPTY: u32 = 0x0002006B;
 pub const ERR_FAUCET_NEW_TOTAL_SUPPLY_WOULD_EXCEED_MAX_ASSET_AMOUNT: u32 = 0x0002002A;
 pub const ERR_FAUCET_NON_FUNGIBLE_ASSET_ALREADY_ISSUED: u32 = 0x0002002C;
 pub const ERR_FAUCET_NON_FUNGIBLE_ASSET_TO_BURN_NOT_FOUND: u32 = 0x0002002E;
@@ -55,11 +65,14 @@ pub const ERR_FUNGIBLE_ASSET_PROVIDED_FAUCET_ID_IS_INVALID: u32 = 0x0002004B;
 
 pub const ERR_KERNEL_PROCEDURE_OFFSET_OUT_OF_BOUNDS: u32 = 0x00020003;
 
+pub const ERR_MULTISIG_INVALID_SIGNER_INDEX: u32 = 0x00020057;
+
 pub const ERR_NON_FUNGIBLE_ASSET_ALREADY_EXISTS: u32 = 0x00020047;
 pub const ERR_NON_FUNGIBLE_ASSET_FAUCET_IS_NOT_ORIGIN: u32 = 0x00020027;
 pub const ERR_NON_FUNGIBLE_ASSET_FORMAT_ELEMENT_ONE_MUST_BE_FUNGIBLE_FAUCET_ID: u32 = 0x00020024;
 pub const ERR_NON_FUNGIBLE_ASSET_FORMAT_MOST_SIGNIFICANT_BIT_MUST_BE_ZERO: u32 = 0x00020025;
 pub const ERR_NON_FUNGIBLE_ASSET_PROVIDED_FAUCET_ID_IS_INVALID: u32 = 0x0002004D;
+pub const ERR_NON_FUNGIBLE_FAUCET_UNKNOWN_TOKEN_ID: u32 = 0x0002006C;
 
 pub const ERR_NOTE_ATTEMPT_TO_ACCESS_NOTE_ASSETS_FROM_INCORRECT_CONTEXT: u32 = 0x00020032;
 pub const ERR_NOTE_ATTEMPT_TO_ACCESS_NOTE_INPUTS_FROM_INCORRECT_CONTEXT: u32 = 0x00020033;
@@ -72,6 +85,11 @@ pub const ERR_NOTE_INVALID_TYPE: u32 = 0x00020043;
 pub const ERR_NOTE_NUM_OF_ASSETS_EXCEED_LIMIT: u32 = 0x0002002F;
 pub const ERR_NOTE_TAG_MUST_BE_U32: u32 = 0x00020045;
 
+pub const ERR_P2IDC_CONDITION_NOT_SATISFIED: u32 = 0x0002005B;
+pub const ERR_P2IDC_INVALID_COMPARISON: u32 = 0x0002005C;
+pub const ERR_P2IDC_TARGET_ACCT_MISMATCH: u32 = 0x0002005A;
+pub const ERR_P2IDC_WRONG_NUMBER_OF_INPUTS: u32 = 0x00020059;
+
 pub const ERR_P2IDR_RECLAIM_ACCT_IS_NOT_SENDER: u32 = 0x00020053;
 pub const ERR_P2IDR_RECLAIM_HEIGHT_NOT_REACHED: u32 = 0x00020054;
 pub const ERR_P2IDR_WRONG_NUMBER_OF_INPUTS: u32 = 0x00020052;
@@ -95,8 +113,24 @@ pub const ERR_PROLOGUE_NUMBER_OF_NOTE_INPUTS_EXCEEDED_LIMIT: u32 = 0x0002004F;
 pub const ERR_PROLOGUE_PROVIDED_ACCOUNT_DATA_DOES_NOT_MATCH_ON_CHAIN_COMMITMENT: u32 = 0x0002003A;
 pub const ERR_PROLOGUE_PROVIDED_INPUT_ASSETS_INFO_DOES_NOT_MATCH_ITS_COMMITMENT: u32 = 0x0002003F;
 
+pub const ERR_SPENDING_LIMIT_EXCEEDED: u32 = 0x00020058;
+pub const ERR_SPENDING_LIMIT_INTERVAL_REGRESSED: u32 = 0x0002006E;
+
+pub const ERR_SPLIT_ASSETS_MUST_BE_FUNGIBLE: u32 = 0x00020066;
+pub const ERR_SPLIT_ASSET_AMOUNT_EXCEEDS_LIMIT: u32 = 0x00020067;
+pub const ERR_SPLIT_INVALID_SHARE: u32 = 0x00020063;
+pub const ERR_SPLIT_TOTAL_SHARES_EXCEED_LIMIT: u32 = 0x00020064;
+pub const ERR_SPLIT_WRONG_NUMBER_OF_ASSETS: u32 = 0x00020065;
+pub const ERR_SPLIT_WRONG_NUMBER_OF_INPUTS: u32 = 0x00020062;
+
 pub const ERR_STORAGE_SLOT_INDEX_OUT_OF_BOUNDS: u32 = 0x0002000D;
 
+pub const ERR_SWAPP_ASSETS_MUST_BE_FUNGIBLE: u32 = 0x0002005F;
+pub const ERR_SWAPP_INVALID_FILL_AMOUNT: u32 = 0x00020060;
+pub const ERR_SWAPP_PRICE_NOT_MET: u32 = 0x00020061;
+pub const ERR_SWAPP_WRONG_NUMBER_OF_ASSETS: u32 = 0x0002005E;
+pub const ERR_SWAPP_WRONG_NUMBER_OF_INPUTS: u32 = 0x0002005D;
+
 pub const ERR_SWAP_WRONG_NUMBER_OF_ASSETS: u32 = 0x00020056;
 pub const ERR_SWAP_WRONG_NUMBER_OF_INPUTS: u32 = 0x00020055;
 
@@ -112,7 +146,7 @@ pub const ERR_VAULT_NON_FUNGIBLE_ASSET_ALREADY_EXISTS: u32 = 0x0002001C;
 pub const ERR_VAULT_NON_FUNGIBLE_ASSET_TO_REMOVE_NOT_FOUND: u32 = 0x0002001F;
 pub const ERR_VAULT_REMOVE_FUNGIBLE_ASSET_FAILED_INITIAL_VALUE_INVALID: u32 = 0x0002001E;
 
-pub const TX_KERNEL_ERRORS: [(u32, &str); 87] = [
+pub const TX_KERNEL_ERRORS: [(u32, &str); 114] = [
     (ERR_ACCOUNT_CODE_COMMITMENT_MISMATCH, "Computed account code commitment does not match recorded account code commitment"),
     (ERR_ACCOUNT_CODE_IS_NOT_UPDATABLE, "Account code must be updatable for it to be possible to set new code"),
     (ERR_ACCOUNT_INSUFFICIENT_NUMBER_OF_ONES, "Account ID must contain at least MIN_ACCOUNT_ONES number of ones"),
@@ -120,8 +154,10 @@ pub const TX_KERNEL_ERRORS: [(u32, &str); 87] = [
     (ERR_ACCOUNT_IS_NOT_NATIVE, "The current account is not native"),
     (ERR_ACCOUNT_NONCE_DID_NOT_INCREASE_AFTER_STATE_CHANGE, "Account nonce did not increase after a state changing transaction"),
     (ERR_ACCOUNT_NONCE_INCREASE_MUST_BE_U32, "Account nonce cannot be increased by a greater than u32 value"),
+    (ERR_ACCOUNT_NONCE_NOT_INCREMENTED_BY_ONE, "Account nonce was not incremented by exactly one under the strict nonce policy"),
     (ERR_ACCOUNT_POW_IS_INSUFFICIENT, "Account proof of work is insufficient"),
     (ERR_ACCOUNT_PROC_INDEX_OUT_OF_BOUNDS, "Provided procedure index is out of bounds"),
+    (ERR_ACCOUNT_PROC_IS_READ_ONLY, "Account procedure is read-only and cannot modify the account state"),
     (ERR_ACCOUNT_PROC_NOT_PART_OF_ACCOUNT_CODE, "Account procedure is not part of the account code"),
     (ERR_ACCOUNT_READING_MAP_VALUE_FROM_NON_MAP_SLOT, "Failed to read an account map item from a non-map storage slot"),
     (ERR_ACCOUNT_SEED_DIGEST_MISMATCH, "ID of the new account does not match the ID computed from the seed"),
@@ -132,11 +168,19 @@ pub const TX_KERNEL_ERRORS: [(u32, &str); 87] = [
     (ERR_ACCOUNT_TOO_MANY_STORAGE_SLOTS, "Number of account storage slots exceeds the maximum limit of 255"),
     (ERR_ACCOUNT_TOTAL_ISSUANCE_PROC_CAN_ONLY_BE_CALLED_ON_FUNGIBLE_FAUCET, "The get_fungible_faucet_total_issuance procedure can only be called on a fungible faucet"),
 
+    (ERR_ARRAY_INDEX_OUT_OF_BOUNDS, "The position of the array element is out of bounds"),
+    (ERR_ARRAY_SLOT_NOT_ARRAY, "The storage map does not contain a valid array length entry"),
+
+    (ERR_AUTH_NEW_PUBLIC_KEY_IS_EMPTY, "The new public key of the account must not be empty"),
+
     (ERR_EPILOGUE_TOTAL_NUMBER_OF_ASSETS_MUST_STAY_THE_SAME, "Total number of assets in the account and all involved notes must stay the same"),
 
     (ERR_FAUCET_BURN_CANNOT_EXCEED_EXISTING_TOTAL_SUPPLY, "Asset amount to burn can not exceed the existing total supply"),
     (ERR_FAUCET_BURN_NON_FUNGIBLE_ASSET_CAN_ONLY_BE_CALLED_ON_NON_FUNGIBLE_FAUCET, "The burn_non_fungible_asset procedure can only be called on a non-fungible faucet"),
+    (ERR_FAUCET_INVALID_PAUSE_FLAG, "The pause flag of the faucet must be either 0 or 1"),
     (ERR_FAUCET_INVALID_STORAGE_OFFSET, "Storage offset is invalid for a faucet account (0 is prohibited as it is the reserved data slot for faucets)"),
+    (ERR_FAUCET_IS_PAUSED, "Minting and burning assets is not possible while the faucet is paused"),
+    (ERR_FAUCET_NEW_OWNER_KEY_IS_EMPTY, "The public key of the new owner of the faucet must not be empty"),
     (ERR_FAUCET_NEW_TOTAL_SUPPLY_WOULD_EXCEED_MAX_ASSET_AMOUNT, "Asset mint operation would cause the new total supply to exceed the maximum allowed asset amount"),
     (ERR_FAUCET_NON_FUNGIBLE_ASSET_ALREADY_ISSUED, "Failed to mint new non-fungible asset because it was already issued"),
     (ERR_FAUCET_NON_FUNGIBLE_ASSET_TO_BURN_NOT_FOUND, "Failed to burn non-existent non-fungible asset in the vault"),
@@ -158,11 +202,14 @@ pub const TX_KERNEL_ERRORS: [(u32, &str); 87] = [
 
     (ERR_KERNEL_PROCEDURE_OFFSET_OUT_OF_BOUNDS, "Provided kernel procedure offset is out of bounds"),
 
+    (ERR_MULTISIG_INVALID_SIGNER_INDEX, "Index of a multisig signer is out of bounds or not greater than the index of the previous signer"),
+
     (ERR_NON_FUNGIBLE_ASSET_ALREADY_EXISTS, "Non-fungible asset that already exists in the note cannot be added again"),
     (ERR_NON_FUNGIBLE_ASSET_FAUCET_IS_NOT_ORIGIN, "The origin of the non-fungible asset is not this faucet"),
     (ERR_NON_FUNGIBLE_ASSET_FORMAT_ELEMENT_ONE_MUST_BE_FUNGIBLE_FAUCET_ID, "Malformed non-fungible asset: ASSET[1] is not a valid non-fungible faucet id"),
     (ERR_NON_FUNGIBLE_ASSET_FORMAT_MOST_SIGNIFICANT_BIT_MUST_BE_ZERO, "Malformed non-fungible asset: the most significant bit must be 0"),
     (ERR_NON_FUNGIBLE_ASSET_PROVIDED_FAUCET_ID_IS_INVALID, "Failed to build the non-fungible asset because the provided faucet id is not from a non-fungible faucet"),
+    (ERR_NON_FUNGIBLE_FAUCET_UNKNOWN_TOKEN_ID, "No non-fungible asset is issued under the provided token id"),
 
     (ERR_NOTE_ATTEMPT_TO_ACCESS_NOTE_ASSETS_FROM_INCORRECT_CONTEXT, "Attempted to access note assets from incorrect context"),
     (ERR_NOTE_ATTEMPT_TO_ACCESS_NOTE_INPUTS_FROM_INCORRECT_CONTEXT, "Attempted to access note inputs from incorrect context"),
@@ -175,6 +222,11 @@ pub const TX_KERNEL_ERRORS: [(u32, &str); 87] = [
     (ERR_NOTE_NUM_OF_ASSETS_EXCEED_LIMIT, "Number of assets in a note exceed 255"),
     (ERR_NOTE_TAG_MUST_BE_U32, "The note's tag must fit into a u32 so the 32 most significant bits must be zero."),
 
+    (ERR_P2IDC_CONDITION_NOT_SATISFIED, "P2IDC's condition on the value provided by the oracle account is not satisfied"),
+    (ERR_P2IDC_INVALID_COMPARISON, "P2IDC's comparison operator is not one of the supported operators"),
+    (ERR_P2IDC_TARGET_ACCT_MISMATCH, "P2IDC's target account address and transaction address do not match"),
+    (ERR_P2IDC_WRONG_NUMBER_OF_INPUTS, "P2IDC script expects exactly 9 note inputs"),
+
     (ERR_P2IDR_RECLAIM_ACCT_IS_NOT_SENDER, "P2IDR's reclaimer is not the original sender"),
     (ERR_P2IDR_RECLAIM_HEIGHT_NOT_REACHED, "P2IDR can not be reclaimed as the transaction's reference block is lower than the reclaim height"),
     (ERR_P2IDR_WRONG_NUMBER_OF_INPUTS, "P2IDR scripts expect exactly 2 note inputs"),
@@ -198,8 +250,24 @@ pub const TX_KERNEL_ERRORS: [(u32, &str); 87] = [
     (ERR_PROLOGUE_PROVIDED_ACCOUNT_DATA_DOES_NOT_MATCH_ON_CHAIN_COMMITMENT, "Account data provided does not match the commitment recorded on-chain"),
     (ERR_PROLOGUE_PROVIDED_INPUT_ASSETS_INFO_DOES_NOT_MATCH_ITS_COMMITMENT, "Provided info about assets of an input does not match its commitment"),
 
+    (ERR_SPENDING_LIMIT_EXCEEDED, "Moving the asset out of the account would exceed the spending limit of the current interval"),
+    (ERR_SPENDING_LIMIT_INTERVAL_REGRESSED, "The interval of the transaction precedes the last interval in which the asset was spent"),
+
+    (ERR_SPLIT_ASSETS_MUST_BE_FUNGIBLE, "SPLIT script requires all note assets to be fungible"),
+    (ERR_SPLIT_ASSET_AMOUNT_EXCEEDS_LIMIT, "SPLIT script requires the amounts of all note assets to fit into a u32"),
+    (ERR_SPLIT_INVALID_SHARE, "SPLIT script requires the share of every recipient to be a non-zero u32 value"),
+    (ERR_SPLIT_TOTAL_SHARES_EXCEED_LIMIT, "SPLIT script requires the sum of the shares of all recipients to not exceed 65535"),
+    (ERR_SPLIT_WRONG_NUMBER_OF_ASSETS, "SPLIT script requires at least 1 note asset"),
+    (ERR_SPLIT_WRONG_NUMBER_OF_INPUTS, "SPLIT script expects the P2ID script root followed by one word per recipient as note inputs"),
+
     (ERR_STORAGE_SLOT_INDEX_OUT_OF_BOUNDS, "Provided storage slot index is out of bounds"),
 
+    (ERR_SWAPP_ASSETS_MUST_BE_FUNGIBLE, "SWAPP script requires the offered and the requested assets to be fungible"),
+    (ERR_SWAPP_INVALID_FILL_AMOUNT, "SWAPP fill amounts must be non-zero and must not exceed the amounts of the swap"),
+    (ERR_SWAPP_PRICE_NOT_MET, "SWAPP requested fill amount does not pay the price of the offered fill amount"),
+    (ERR_SWAPP_WRONG_NUMBER_OF_ASSETS, "SWAPP script requires exactly 1 note asset"),
+    (ERR_SWAPP_WRONG_NUMBER_OF_INPUTS, "SWAPP script expects exactly 16 note inputs"),
+
     (ERR_SWAP_WRONG_NUMBER_OF_ASSETS, "SWAP script requires exactly 1 note asset"),
     (ERR_SWAP_WRONG_NUMBER_OF_INPUTS, "SWAP script expects exactly 10 note inputs"),
 
@@ -215,3 +283,120 @@ pub const TX_KERNEL_ERRORS: [(u32, &str); 87] = [
     (ERR_VAULT_NON_FUNGIBLE_ASSET_TO_REMOVE_NOT_FOUND, "Failed to remove non-existent non-fungible asset from the vault"),
     (ERR_VAULT_REMOVE_FUNGIBLE_ASSET_FAILED_INITIAL_VALUE_INVALID, "Failed to remove fungible asset from the asset vault due to the initial value being invalid"),
 ];
+
+pub const TX_KERNEL_ERROR_NAMES: [(u32, &str); 114] = [
+    (ERR_ACCOUNT_CODE_COMMITMENT_MISMATCH, "ERR_ACCOUNT_CODE_COMMITMENT_MISMATCH"),
+    (ERR_ACCOUNT_CODE_IS_NOT_UPDATABLE, "ERR_ACCOUNT_CODE_IS_NOT_UPDATABLE"),
+    (ERR_ACCOUNT_INSUFFICIENT_NUMBER_OF_ONES, "ERR_ACCOUNT_INSUFFICIENT_NUMBER_OF_ONES"),
+    (ERR_ACCOUNT_INVALID_STORAGE_OFFSET_FOR_SIZE, "ERR_ACCOUNT_INVALID_STORAGE_OFFSET_FOR_SIZE"),
+    (ERR_ACCOUNT_IS_NOT_NATIVE, "ERR_ACCOUNT_IS_NOT_NATIVE"),
+    (ERR_ACCOUNT_NONCE_DID_NOT_INCREASE_AFTER_STATE_CHANGE, "ERR_ACCOUNT_NONCE_DID_NOT_INCREASE_AFTER_STATE_CHANGE"),
+    (ERR_ACCOUNT_NONCE_INCREASE_MUST_BE_U32, "ERR_ACCOUNT_NONCE_INCREASE_MUST_BE_U32"),
+    (ERR_ACCOUNT_NONCE_NOT_INCREMENTED_BY_ONE, "ERR_ACCOUNT_NONCE_NOT_INCREMENTED_BY_ONE"),
+    (ERR_ACCOUNT_POW_IS_INSUFFICIENT, "ERR_ACCOUNT_POW_IS_INSUFFICIENT"),
+    (ERR_ACCOUNT_PROC_INDEX_OUT_OF_BOUNDS, "ERR_ACCOUNT_PROC_INDEX_OUT_OF_BOUNDS"),
+    (ERR_ACCOUNT_PROC_IS_READ_ONLY, "ERR_ACCOUNT_PROC_IS_READ_ONLY"),
+    (ERR_ACCOUNT_PROC_NOT_PART_OF_ACCOUNT_CODE, "ERR_ACCOUNT_PROC_NOT_PART_OF_ACCOUNT_CODE"),
+    (ERR_ACCOUNT_READING_MAP_VALUE_FROM_NON_MAP_SLOT, "ERR_ACCOUNT_READING_MAP_VALUE_FROM_NON_MAP_SLOT"),
+    (ERR_ACCOUNT_SEED_DIGEST_MISMATCH, "ERR_ACCOUNT_SEED_DIGEST_MISMATCH"),
+    (ERR_ACCOUNT_SETTING_MAP_ITEM_ON_NON_MAP_SLOT, "ERR_ACCOUNT_SETTING_MAP_ITEM_ON_NON_MAP_SLOT"),
+    (ERR_ACCOUNT_SETTING_VALUE_ITEM_ON_NON_VALUE_SLOT, "ERR_ACCOUNT_SETTING_VALUE_ITEM_ON_NON_VALUE_SLOT"),
+    (ERR_ACCOUNT_STORAGE_COMMITMENT_MISMATCH, "ERR_ACCOUNT_STORAGE_COMMITMENT_MISMATCH"),
+    (ERR_ACCOUNT_TOO_MANY_PROCEDURES, "ERR_ACCOUNT_TOO_MANY_PROCEDURES"),
+    (ERR_ACCOUNT_TOO_MANY_STORAGE_SLOTS, "ERR_ACCOUNT_TOO_MANY_STORAGE_SLOTS"),
+    (ERR_ACCOUNT_TOTAL_ISSUANCE_PROC_CAN_ONLY_BE_CALLED_ON_FUNGIBLE_FAUCET, "ERR_ACCOUNT_TOTAL_ISSUANCE_PROC_CAN_ONLY_BE_CALLED_ON_FUNGIBLE_FAUCET"),
+    (ERR_ARRAY_INDEX_OUT_OF_BOUNDS, "ERR_ARRAY_INDEX_OUT_OF_BOUNDS"),
+    (ERR_ARRAY_SLOT_NOT_ARRAY, "ERR_ARRAY_SLOT_NOT_ARRAY"),
+    (ERR_AUTH_NEW_PUBLIC_KEY_IS_EMPTY, "ERR_AUTH_NEW_PUBLIC_KEY_IS_EMPTY"),
+    (ERR_EPILOGUE_TOTAL_NUMBER_OF_ASSETS_MUST_STAY_THE_SAME, "ERR_EPILOGUE_TOTAL_NUMBER_OF_ASSETS_MUST_STAY_THE_SAME"),
+    (ERR_FAUCET_BURN_CANNOT_EXCEED_EXISTING_TOTAL_SUPPLY, "ERR_FAUCET_BURN_CANNOT_EXCEED_EXISTING_TOTAL_SUPPLY"),
+    (ERR_FAUCET_BURN_NON_FUNGIBLE_ASSET_CAN_ONLY_BE_CALLED_ON_NON_FUNGIBLE_FAUCET, "ERR_FAUCET_BURN_NON_FUNGIBLE_ASSET_CAN_ONLY_BE_CALLED_ON_NON_FUNGIBLE_FAUCET"),
+    (ERR_FAUCET_INVALID_PAUSE_FLAG, "ERR_FAUCET_INVALID_PAUSE_FLAG"),
+    (ERR_FAUCET_INVALID_STORAGE_OFFSET, "ERR_FAUCET_INVALID_STORAGE_OFFSET"),
+    (ERR_FAUCET_IS_PAUSED, "ERR_FAUCET_IS_PAUSED"),
+    (ERR_FAUCET_NEW_OWNER_KEY_IS_EMPTY, "ERR_FAUCET_NEW_OWNER_KEY_IS_EMPTY"),
+    (ERR_FAUCET_NEW_TOTAL_SUPPLY_WOULD_EXCEED_MAX_ASSET_AMOUNT, "ERR_FAUCET_NEW_TOTAL_SUPPLY_WOULD_EXCEED_MAX_ASSET_AMOUNT"),
+    (ERR_FAUCET_NON_FUNGIBLE_ASSET_ALREADY_ISSUED, "ERR_FAUCET_NON_FUNGIBLE_ASSET_ALREADY_ISSUED"),
+    (ERR_FAUCET_NON_FUNGIBLE_ASSET_TO_BURN_NOT_FOUND, "ERR_FAUCET_NON_FUNGIBLE_ASSET_TO_BURN_NOT_FOUND"),
+    (ERR_FAUCET_STORAGE_DATA_SLOT_IS_RESERVED, "ERR_FAUCET_STORAGE_DATA_SLOT_IS_RESERVED"),
+    (ERR_FOREIGN_ACCOUNT_ID_EQUALS_NATIVE_ACCT_ID, "ERR_FOREIGN_ACCOUNT_ID_EQUALS_NATIVE_ACCT_ID"),
+    (ERR_FOREIGN_ACCOUNT_ID_IS_ZERO, "ERR_FOREIGN_ACCOUNT_ID_IS_ZERO"),
+    (ERR_FOREIGN_ACCOUNT_INVALID, "ERR_FOREIGN_ACCOUNT_INVALID"),
+    (ERR_FOREIGN_ACCOUNT_MAX_NUMBER_EXCEEDED, "ERR_FOREIGN_ACCOUNT_MAX_NUMBER_EXCEEDED"),
+    (ERR_FUNGIBLE_ASSET_AMOUNT_EXCEEDS_MAX_ALLOWED_AMOUNT, "ERR_FUNGIBLE_ASSET_AMOUNT_EXCEEDS_MAX_ALLOWED_AMOUNT"),
+    (ERR_FUNGIBLE_ASSET_DISTRIBUTE_WOULD_CAUSE_MAX_SUPPLY_TO_BE_EXCEEDED, "ERR_FUNGIBLE_ASSET_DISTRIBUTE_WOULD_CAUSE_MAX_SUPPLY_TO_BE_EXCEEDED"),
+    (ERR_FUNGIBLE_ASSET_FAUCET_IS_NOT_ORIGIN, "ERR_FUNGIBLE_ASSET_FAUCET_IS_NOT_ORIGIN"),
+    (ERR_FUNGIBLE_ASSET_FORMAT_ELEMENT_ONE_MUST_BE_ZERO, "ERR_FUNGIBLE_ASSET_FORMAT_ELEMENT_ONE_MUST_BE_ZERO"),
+    (ERR_FUNGIBLE_ASSET_FORMAT_ELEMENT_THREE_MUST_BE_FUNGIBLE_FAUCET_ID, "ERR_FUNGIBLE_ASSET_FORMAT_ELEMENT_THREE_MUST_BE_FUNGIBLE_FAUCET_ID"),
+    (ERR_FUNGIBLE_ASSET_FORMAT_ELEMENT_TWO_MUST_BE_ZERO, "ERR_FUNGIBLE_ASSET_FORMAT_ELEMENT_TWO_MUST_BE_ZERO"),
+    (ERR_FUNGIBLE_ASSET_FORMAT_ELEMENT_ZERO_MUST_BE_WITHIN_LIMITS, "ERR_FUNGIBLE_ASSET_FORMAT_ELEMENT_ZERO_MUST_BE_WITHIN_LIMITS"),
+    (ERR_FUNGIBLE_ASSET_PROVIDED_FAUCET_ID_IS_INVALID, "ERR_FUNGIBLE_ASSET_PROVIDED_FAUCET_ID_IS_INVALID"),
+    (ERR_KERNEL_PROCEDURE_OFFSET_OUT_OF_BOUNDS, "ERR_KERNEL_PROCEDURE_OFFSET_OUT_OF_BOUNDS"),
+    (ERR_MULTISIG_INVALID_SIGNER_INDEX, "ERR_MULTISIG_INVALID_SIGNER_INDEX"),
+    (ERR_NON_FUNGIBLE_ASSET_ALREADY_EXISTS, "ERR_NON_FUNGIBLE_ASSET_ALREADY_EXISTS"),
+    (ERR_NON_FUNGIBLE_ASSET_FAUCET_IS_NOT_ORIGIN, "ERR_NON_FUNGIBLE_ASSET_FAUCET_IS_NOT_ORIGIN"),
+    (ERR_NON_FUNGIBLE_ASSET_FORMAT_ELEMENT_ONE_MUST_BE_FUNGIBLE_FAUCET_ID, "ERR_NON_FUNGIBLE_ASSET_FORMAT_ELEMENT_ONE_MUST_BE_FUNGIBLE_FAUCET_ID"),
+    (ERR_NON_FUNGIBLE_ASSET_FORMAT_MOST_SIGNIFICANT_BIT_MUST_BE_ZERO, "ERR_NON_FUNGIBLE_ASSET_FORMAT_MOST_SIGNIFICANT_BIT_MUST_BE_ZERO"),
+    (ERR_NON_FUNGIBLE_ASSET_PROVIDED_FAUCET_ID_IS_INVALID, "ERR_NON_FUNGIBLE_ASSET_PROVIDED_FAUCET_ID_IS_INVALID"),
+    (ERR_NON_FUNGIBLE_FAUCET_UNKNOWN_TOKEN_ID, "ERR_NON_FUNGIBLE_FAUCET_UNKNOWN_TOKEN_ID"),
+    (ERR_NOTE_ATTEMPT_TO_ACCESS_NOTE_ASSETS_FROM_INCORRECT_CONTEXT, "ERR_NOTE_ATTEMPT_TO_ACCESS_NOTE_ASSETS_FROM_INCORRECT_CONTEXT"),
+    (ERR_NOTE_ATTEMPT_TO_ACCESS_NOTE_INPUTS_FROM_INCORRECT_CONTEXT, "ERR_NOTE_ATTEMPT_TO_ACCESS_NOTE_INPUTS_FROM_INCORRECT_CONTEXT"),
+    (ERR_NOTE_ATTEMPT_TO_ACCESS_NOTE_SENDER_FROM_INCORRECT_CONTEXT, "ERR_NOTE_ATTEMPT_TO_ACCESS_NOTE_SENDER_FROM_INCORRECT_CONTEXT"),
+    (ERR_NOTE_DATA_DOES_NOT_MATCH_COMMITMENT, "ERR_NOTE_DATA_DOES_NOT_MATCH_COMMITMENT"),
+    (ERR_NOTE_FUNGIBLE_MAX_AMOUNT_EXCEEDED, "ERR_NOTE_FUNGIBLE_MAX_AMOUNT_EXCEEDED"),
+    (ERR_NOTE_INVALID_INDEX, "ERR_NOTE_INVALID_INDEX"),
+    (ERR_NOTE_INVALID_NOTE_TYPE_FOR_NOTE_TAG_PREFIX, "ERR_NOTE_INVALID_NOTE_TYPE_FOR_NOTE_TAG_PREFIX"),
+    (ERR_NOTE_INVALID_TYPE, "ERR_NOTE_INVALID_TYPE"),
+    (ERR_NOTE_NUM_OF_ASSETS_EXCEED_LIMIT, "ERR_NOTE_NUM_OF_ASSETS_EXCEED_LIMIT"),
+    (ERR_NOTE_TAG_MUST_BE_U32, "ERR_NOTE_TAG_MUST_BE_U32"),
+    (ERR_P2IDC_CONDITION_NOT_SATISFIED, "ERR_P2IDC_CONDITION_NOT_SATISFIED"),
+    (ERR_P2IDC_INVALID_COMPARISON, "ERR_P2IDC_INVALID_COMPARISON"),
+    (ERR_P2IDC_TARGET_ACCT_MISMATCH, "ERR_P2IDC_TARGET_ACCT_MISMATCH"),
+    (ERR_P2IDC_WRONG_NUMBER_OF_INPUTS, "ERR_P2IDC_WRONG_NUMBER_OF_INPUTS"),
+    (ERR_P2IDR_RECLAIM_ACCT_IS_NOT_SENDER, "ERR_P2IDR_RECLAIM_ACCT_IS_NOT_SENDER"),
+    (ERR_P2IDR_RECLAIM_HEIGHT_NOT_REACHED, "ERR_P2IDR_RECLAIM_HEIGHT_NOT_REACHED"),
+    (ERR_P2IDR_WRONG_NUMBER_OF_INPUTS, "ERR_P2IDR_WRONG_NUMBER_OF_INPUTS"),
+    (ERR_P2ID_TARGET_ACCT_MISMATCH, "ERR_P2ID_TARGET_ACCT_MISMATCH"),
+    (ERR_P2ID_WRONG_NUMBER_OF_INPUTS, "ERR_P2ID_WRONG_NUMBER_OF_INPUTS"),
+    (ERR_PROLOGUE_EXISTING_ACCOUNT_MUST_HAVE_NON_ZERO_NONCE, "ERR_PROLOGUE_EXISTING_ACCOUNT_MUST_HAVE_NON_ZERO_NONCE"),
+    (ERR_PROLOGUE_GLOBAL_INPUTS_PROVIDED_DO_NOT_MATCH_BLOCK_HASH_COMMITMENT, "ERR_PROLOGUE_GLOBAL_INPUTS_PROVIDED_DO_NOT_MATCH_BLOCK_HASH_COMMITMENT"),
+    (ERR_PROLOGUE_INPUT_NOTES_COMMITMENT_MISMATCH, "ERR_PROLOGUE_INPUT_NOTES_COMMITMENT_MISMATCH"),
+    (ERR_PROLOGUE_MISMATCH_OF_ACCOUNT_IDS_FROM_GLOBAL_INPUTS_AND_ADVICE_PROVIDER, "ERR_PROLOGUE_MISMATCH_OF_ACCOUNT_IDS_FROM_GLOBAL_INPUTS_AND_ADVICE_PROVIDER"),
+    (ERR_PROLOGUE_MISMATCH_OF_REFERENCE_BLOCK_MMR_AND_NOTE_AUTHENTICATION_MMR, "ERR_PROLOGUE_MISMATCH_OF_REFERENCE_BLOCK_MMR_AND_NOTE_AUTHENTICATION_MMR"),
+    (ERR_PROLOGUE_NEW_ACCOUNT_VAULT_MUST_BE_EMPTY, "ERR_PROLOGUE_NEW_ACCOUNT_VAULT_MUST_BE_EMPTY"),
+    (ERR_PROLOGUE_NEW_FUNGIBLE_FAUCET_RESERVED_SLOT_INVALID_TYPE, "ERR_PROLOGUE_NEW_FUNGIBLE_FAUCET_RESERVED_SLOT_INVALID_TYPE"),
+    (ERR_PROLOGUE_NEW_FUNGIBLE_FAUCET_RESERVED_SLOT_MUST_BE_EMPTY, "ERR_PROLOGUE_NEW_FUNGIBLE_FAUCET_RESERVED_SLOT_MUST_BE_EMPTY"),
+    (ERR_PROLOGUE_NEW_NON_FUNGIBLE_FAUCET_RESERVED_SLOT_INVALID_TYPE, "ERR_PROLOGUE_NEW_NON_FUNGIBLE_FAUCET_RESERVED_SLOT_INVALID_TYPE"),
+    (ERR_PROLOGUE_NEW_NON_FUNGIBLE_FAUCET_RESERVED_SLOT_MUST_BE_VALID_EMPY_SMT, "ERR_PROLOGUE_NEW_NON_FUNGIBLE_FAUCET_RESERVED_SLOT_MUST_BE_VALID_EMPY_SMT"),
+    (ERR_PROLOGUE_NUMBER_OF_INPUT_NOTES_EXCEEDS_LIMIT, "ERR_PROLOGUE_NUMBER_OF_INPUT_NOTES_EXCEEDS_LIMIT"),
+    (ERR_PROLOGUE_NUMBER_OF_NOTE_ASSETS_EXCEEDS_LIMIT, "ERR_PROLOGUE_NUMBER_OF_NOTE_ASSETS_EXCEEDS_LIMIT"),
+    (ERR_PROLOGUE_NUMBER_OF_NOTE_INPUTS_EXCEEDED_LIMIT, "ERR_PROLOGUE_NUMBER_OF_NOTE_INPUTS_EXCEEDED_LIMIT"),
+    (ERR_PROLOGUE_PROVIDED_ACCOUNT_DATA_DOES_NOT_MATCH_ON_CHAIN_COMMITMENT, "ERR_PROLOGUE_PROVIDED_ACCOUNT_DATA_DOES_NOT_MATCH_ON_CHAIN_COMMITMENT"),
+    (ERR_PROLOGUE_PROVIDED_INPUT_ASSETS_INFO_DOES_NOT_MATCH_ITS_COMMITMENT, "ERR_PROLOGUE_PROVIDED_INPUT_ASSETS_INFO_DOES_NOT_MATCH_ITS_COMMITMENT"),
+    (ERR_SPENDING_LIMIT_EXCEEDED, "ERR_SPENDING_LIMIT_EXCEEDED"),
+    (ERR_SPENDING_LIMIT_INTERVAL_REGRESSED, "ERR_SPENDING_LIMIT_INTERVAL_REGRESSED"),
+    (ERR_SPLIT_ASSETS_MUST_BE_FUNGIBLE, "ERR_SPLIT_ASSETS_MUST_BE_FUNGIBLE"),
+    (ERR_SPLIT_ASSET_AMOUNT_EXCEEDS_LIMIT, "ERR_SPLIT_ASSET_AMOUNT_EXCEEDS_LIMIT"),
+    (ERR_SPLIT_INVALID_SHARE, "ERR_SPLIT_INVALID_SHARE"),
+    (ERR_SPLIT_TOTAL_SHARES_EXCEED_LIMIT, "ERR_SPLIT_TOTAL_SHARES_EXCEED_LIMIT"),
+    (ERR_SPLIT_WRONG_NUMBER_OF_ASSETS, "ERR_SPLIT_WRONG_NUMBER_OF_ASSETS"),
+    (ERR_SPLIT_WRONG_NUMBER_OF_INPUTS, "ERR_SPLIT_WRONG_NUMBER_OF_INPUTS"),
+    (ERR_STORAGE_SLOT_INDEX_OUT_OF_BOUNDS, "ERR_STORAGE_SLOT_INDEX_OUT_OF_BOUNDS"),
+    (ERR_SWAPP_ASSETS_MUST_BE_FUNGIBLE, "ERR_SWAPP_ASSETS_MUST_BE_FUNGIBLE"),
+    (ERR_SWAPP_INVALID_FILL_AMOUNT, "ERR_SWAPP_INVALID_FILL_AMOUNT"),
+    (ERR_SWAPP_PRICE_NOT_MET, "ERR_SWAPP_PRICE_NOT_MET"),
+    (ERR_SWAPP_WRONG_NUMBER_OF_ASSETS, "ERR_SWAPP_WRONG_NUMBER_OF_ASSETS"),
+    (ERR_SWAPP_WRONG_NUMBER_OF_INPUTS, "ERR_SWAPP_WRONG_NUMBER_OF_INPUTS"),
+    (ERR_SWAP_WRONG_NUMBER_OF_ASSETS, "ERR_SWAP_WRONG_NUMBER_OF_ASSETS"),
+    (ERR_SWAP_WRONG_NUMBER_OF_INPUTS, "ERR_SWAP_WRONG_NUMBER_OF_INPUTS"),
+    (ERR_TX_INVALID_EXPIRATION_DELTA, "ERR_TX_INVALID_EXPIRATION_DELTA"),
+    (ERR_TX_NUMBER_OF_OUTPUT_NOTES_EXCEEDS_LIMIT, "ERR_TX_NUMBER_OF_OUTPUT_NOTES_EXCEEDS_LIMIT"),
+    (ERR_VAULT_ADD_FUNGIBLE_ASSET_FAILED_INITIAL_VALUE_INVALID, "ERR_VAULT_ADD_FUNGIBLE_ASSET_FAILED_INITIAL_VALUE_INVALID"),
+    (ERR_VAULT_FUNGIBLE_ASSET_AMOUNT_LESS_THAN_AMOUNT_TO_WITHDRAW, "ERR_VAULT_FUNGIBLE_ASSET_AMOUNT_LESS_THAN_AMOUNT_TO_WITHDRAW"),
+    (ERR_VAULT_FUNGIBLE_MAX_AMOUNT_EXCEEDED, "ERR_VAULT_FUNGIBLE_MAX_AMOUNT_EXCEEDED"),
+    (ERR_VAULT_GET_BALANCE_PROC_CAN_ONLY_BE_CALLED_ON_FUNGIBLE_FAUCET, "ERR_VAULT_GET_BALANCE_PROC_CAN_ONLY_BE_CALLED_ON_FUNGIBLE_FAUCET"),
+    (ERR_VAULT_HAS_NON_FUNGIBLE_ASSET_PROC_CAN_BE_CALLED_ONLY_WITH_NON_FUNGIBLE_ASSET, "ERR_VAULT_HAS_NON_FUNGIBLE_ASSET_PROC_CAN_BE_CALLED_ONLY_WITH_NON_FUNGIBLE_ASSET"),
+    (ERR_VAULT_NON_FUNGIBLE_ASSET_ALREADY_EXISTS, "ERR_VAULT_NON_FUNGIBLE_ASSET_ALREADY_EXISTS"),
+    (ERR_VAULT_NON_FUNGIBLE_ASSET_TO_REMOVE_NOT_FOUND, "ERR_VAULT_NON_FUNGIBLE_ASSET_TO_REMOVE_NOT_FOUND"),
+    (ERR_VAULT_REMOVE_FUNGIBLE_ASSET_FAILED_INITIAL_VALUE_INVALID, "ERR_VAULT_REMOVE_FUNGIBLE_ASSET_FAILED_INITIAL_VALUE_INVALID"),
+];
@@ -17,6 +17,7 @@ pub use auth::AuthScheme;
 
 pub mod accounts;
 pub mod errors;
+pub mod masm;
 pub mod notes;
 pub mod transaction;
 
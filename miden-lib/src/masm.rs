@@ -0,0 +1,315 @@
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use miden_objects::{
+    assembly::Assembler, assets::Asset, notes::NoteMetadata, vm::Program, Digest, Felt, Word,
+};
+
+use crate::errors::MasmTemplateError;
+
+// MASM VALUE
+// ================================================================================================
+
+/// A value which can be interpolated into a [MasmTemplate].
+///
+/// Values are encoded as immediate values of MASM instructions, e.g., words are encoded as four
+/// dot-separated elements so that they can be used as `push.{WORD}`. Encoded values never contain
+/// anything but digits and dots, and thus cannot change the structure of the template.
+pub trait MasmValue {
+    /// Returns the MASM encoding of this value.
+    fn to_masm(&self) -> String;
+}
+
+impl MasmValue for Felt {
+    fn to_masm(&self) -> String {
+        self.as_int().to_string()
+    }
+}
+
+impl MasmValue for [Felt] {
+    fn to_masm(&self) -> String {
+        self.iter().map(MasmValue::to_masm).collect::<Vec<_>>().join(".")
+    }
+}
+
+impl MasmValue for Vec<Felt> {
+    fn to_masm(&self) -> String {
+        self.as_slice().to_masm()
+    }
+}
+
+impl MasmValue for Word {
+    fn to_masm(&self) -> String {
+        self.as_slice().to_masm()
+    }
+}
+
+impl MasmValue for Digest {
+    fn to_masm(&self) -> String {
+        Word::from(*self).to_masm()
+    }
+}
+
+impl MasmValue for Asset {
+    fn to_masm(&self) -> String {
+        Word::from(*self).to_masm()
+    }
+}
+
+impl MasmValue for NoteMetadata {
+    fn to_masm(&self) -> String {
+        Word::from(*self).to_masm()
+    }
+}
+
+macro_rules! impl_masm_value_for_int {
+    ($($ty:ty),*) => {
+        $(
+            impl MasmValue for $ty {
+                fn to_masm(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )*
+    };
+}
+
+impl_masm_value_for_int!(u8, u16, u32, u64, usize);
+
+// MASM TEMPLATE
+// ================================================================================================
+
+/// A MASM source template with named placeholders.
+///
+/// Placeholders have the form `{NAME}`, where `NAME` starts with an ASCII letter or an underscore
+/// and consists of ASCII alphanumeric characters and underscores only. Every placeholder must be
+/// bound to a value before the template can be rendered, and every bound value must be used by the
+/// template. Thus, changes to the layout of a template (e.g., a renamed or removed placeholder)
+/// result in an error instead of silently generating wrong code.
+///
+/// Values are bound via [Self::with()], which encodes [MasmValue]s, or via [Self::with_code()],
+/// which inserts a snippet of MASM code as is.
+///
+/// ```
+/// # use miden_lib::masm::MasmTemplate;
+/// # use miden_objects::{Felt, Word};
+/// let word: Word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+/// let source = MasmTemplate::new("begin push.{VALUE} push.{index} dropw drop end")
+///     .with("VALUE", &word)
+///     .with("index", &5u8)
+///     .render()
+///     .unwrap();
+/// assert_eq!(source, "begin push.1.2.3.4 push.5 dropw drop end");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MasmTemplate {
+    source: String,
+    values: BTreeMap<String, String>,
+}
+
+impl MasmTemplate {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [MasmTemplate] with the provided source and without any bound values.
+    pub fn new(source: impl Into<String>) -> Self {
+        Self {
+            source: source.into(),
+            values: BTreeMap::new(),
+        }
+    }
+
+    // BUILDER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Binds the placeholder with the specified name to the MASM encoding of `value`.
+    ///
+    /// Binding the same placeholder again replaces the previously bound value.
+    pub fn with<V: MasmValue + ?Sized>(mut self, name: impl Into<String>, value: &V) -> Self {
+        self.values.insert(name.into(), value.to_masm());
+        self
+    }
+
+    /// Binds the placeholder with the specified name to the provided snippet of MASM code.
+    ///
+    /// The code is inserted as is, and thus should only be used for trusted snippets (e.g.,
+    /// procedures rendered from other templates).
+    pub fn with_code(mut self, name: impl Into<String>, code: impl Into<String>) -> Self {
+        self.values.insert(name.into(), code.into());
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the source of this template.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns the names of all placeholders in this template, in the order of their first
+    /// occurrence.
+    ///
+    /// # Errors
+    /// Returns an error if the template contains a malformed placeholder.
+    pub fn placeholders(&self) -> Result<Vec<&str>, MasmTemplateError> {
+        let mut placeholders = Vec::new();
+        for segment in parse(&self.source)? {
+            if let Segment::Placeholder(name) = segment {
+                if !placeholders.contains(&name) {
+                    placeholders.push(name);
+                }
+            }
+        }
+        Ok(placeholders)
+    }
+
+    // RENDERING
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the source of this template with all placeholders replaced by their values.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The template contains a malformed placeholder.
+    /// - A placeholder is not bound to a value.
+    /// - A bound value is not used by the template.
+    pub fn render(&self) -> Result<String, MasmTemplateError> {
+        let mut rendered = String::with_capacity(self.source.len());
+        let mut used = Vec::new();
+
+        for segment in parse(&self.source)? {
+            match segment {
+                Segment::Text(text) => rendered.push_str(text),
+                Segment::Placeholder(name) => {
+                    let value = self
+                        .values
+                        .get(name)
+                        .ok_or_else(|| MasmTemplateError::MissingValue(name.to_string()))?;
+                    rendered.push_str(value);
+                    used.push(name);
+                },
+            }
+        }
+
+        if let Some(name) = self.values.keys().find(|name| !used.contains(&name.as_str())) {
+            return Err(MasmTemplateError::UnusedValue(name.clone()));
+        }
+
+        Ok(rendered)
+    }
+
+    /// Renders this template and compiles the result into a [Program] using the provided
+    /// assembler.
+    ///
+    /// This is mostly useful for validating templates in tests.
+    ///
+    /// # Errors
+    /// Returns an error if the template cannot be rendered or if the rendered source fails to
+    /// compile.
+    pub fn compile_program(&self, assembler: Assembler) -> Result<Program, MasmTemplateError> {
+        let source = self.render()?;
+        assembler
+            .assemble_program(source)
+            .map_err(|report| MasmTemplateError::CompilationFailed(report.to_string()))
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// A segment of a parsed template.
+enum Segment<'a> {
+    Text(&'a str),
+    Placeholder(&'a str),
+}
+
+/// Splits the provided template source into text segments and placeholders.
+fn parse(source: &str) -> Result<Vec<Segment<'_>>, MasmTemplateError> {
+    let mut segments = Vec::new();
+    let mut rest = source;
+
+    while let Some(start) = rest.find(['{', '}']) {
+        if rest[start..].starts_with('}') {
+            return Err(MasmTemplateError::InvalidPlaceholder(rest[start..].to_string()));
+        }
+        let end = rest[start..]
+            .find('}')
+            .map(|end| start + end)
+            .ok_or_else(|| MasmTemplateError::InvalidPlaceholder(rest[start..].to_string()))?;
+
+        let name = &rest[start + 1..end];
+        if !is_valid_placeholder_name(name) {
+            return Err(MasmTemplateError::InvalidPlaceholder(rest[start..=end].to_string()));
+        }
+
+        segments.push(Segment::Text(&rest[..start]));
+        segments.push(Segment::Placeholder(name));
+        rest = &rest[end + 1..];
+    }
+    segments.push(Segment::Text(rest));
+
+    Ok(segments)
+}
+
+/// Returns true if the provided string is a valid placeholder name.
+fn is_valid_placeholder_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use miden_objects::{Felt, Word, ONE};
+
+    use super::*;
+    use crate::transaction::TransactionKernel;
+
+    #[test]
+    fn template_rendering() {
+        let word: Word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let template =
+            MasmTemplate::new("begin push.{WORD} push.{x} push.{WORD} dropw drop dropw end");
+        assert_eq!(template.placeholders().unwrap(), vec!["WORD", "x"]);
+
+        let rendered = template.clone().with("WORD", &word).with("x", &ONE).render().unwrap();
+        assert_eq!(rendered, "begin push.1.2.3.4 push.1 push.1.2.3.4 dropw drop dropw end");
+
+        // missing and unused values are rejected
+        assert_eq!(
+            template.clone().with("WORD", &word).render(),
+            Err(MasmTemplateError::MissingValue("x".into()))
+        );
+        assert_eq!(
+            template.clone().with("WORD", &word).with("x", &1u32).with("y", &2u32).render(),
+            Err(MasmTemplateError::UnusedValue("y".into()))
+        );
+
+        // the rendered template compiles
+        template
+            .with("WORD", &word)
+            .with("x", &1u32)
+            .compile_program(TransactionKernel::assembler())
+            .unwrap();
+    }
+
+    #[test]
+    fn malformed_placeholders_are_rejected() {
+        for source in ["push.{", "push.}", "push.{1x}", "push.{a b}", "push.{}"] {
+            assert!(
+                matches!(
+                    MasmTemplate::new(source).render(),
+                    Err(MasmTemplateError::InvalidPlaceholder(_))
+                ),
+                "failed for {source}"
+            );
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use miden_objects::{accounts::AccountId, Digest, Felt, NoteError};
+
+// COMPARISON
+// ================================================================================================
+
+/// A comparison operator applied by the P2IDC note script as `oracle_value <op> threshold`.
+///
+/// Values are compared as integers in the range `[0, p)`, where `p` is the field modulus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Comparison {
+    Eq = 0,
+    Lt = 1,
+    Lte = 2,
+    Gt = 3,
+    Gte = 4,
+}
+
+impl Comparison {
+    /// Returns true if `value <op> threshold` holds for this comparison operator.
+    pub fn evaluate(&self, value: Felt, threshold: Felt) -> bool {
+        let (value, threshold) = (value.as_int(), threshold.as_int());
+        match self {
+            Comparison::Eq => value == threshold,
+            Comparison::Lt => value < threshold,
+            Comparison::Lte => value <= threshold,
+            Comparison::Gt => value > threshold,
+            Comparison::Gte => value >= threshold,
+        }
+    }
+}
+
+impl From<Comparison> for Felt {
+    fn from(comparison: Comparison) -> Self {
+        Felt::from(comparison as u8)
+    }
+}
+
+impl TryFrom<Felt> for Comparison {
+    type Error = NoteError;
+
+    fn try_from(value: Felt) -> Result<Self, Self::Error> {
+        match value.as_int() {
+            0 => Ok(Comparison::Eq),
+            1 => Ok(Comparison::Lt),
+            2 => Ok(Comparison::Lte),
+            3 => Ok(Comparison::Gt),
+            4 => Ok(Comparison::Gte),
+            other => {
+                Err(NoteError::InvalidNoteInputs(format!("invalid comparison operator {other}")))
+            },
+        }
+    }
+}
+
+// ORACLE CONDITION
+// ================================================================================================
+
+/// A condition on a value provided by an oracle account, which must be satisfied for a P2IDC note
+/// to be consumed.
+///
+/// The value is read during note execution by invoking the oracle procedure against the oracle
+/// account via foreign procedure invocation, with the oracle procedure input as its only input.
+/// The element on top of the stack returned by the procedure is taken as the oracle value. For
+/// example, the oracle procedure may be a procedure returning the value of the storage slot with
+/// the provided index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OracleCondition {
+    oracle_account_id: AccountId,
+    oracle_proc_root: Digest,
+    oracle_proc_input: Felt,
+    comparison: Comparison,
+    threshold: Felt,
+}
+
+impl OracleCondition {
+    /// Returns a new [OracleCondition] requiring the value returned by the specified oracle
+    /// procedure to satisfy `oracle_value <comparison> threshold`.
+    pub fn new(
+        oracle_account_id: AccountId,
+        oracle_proc_root: Digest,
+        oracle_proc_input: Felt,
+        comparison: Comparison,
+        threshold: Felt,
+    ) -> Self {
+        Self {
+            oracle_account_id,
+            oracle_proc_root,
+            oracle_proc_input,
+            comparison,
+            threshold,
+        }
+    }
+
+    /// Returns the ID of the oracle account.
+    pub fn oracle_account_id(&self) -> AccountId {
+        self.oracle_account_id
+    }
+
+    /// Returns the MAST root of the procedure providing the oracle value.
+    pub fn oracle_proc_root(&self) -> Digest {
+        self.oracle_proc_root
+    }
+
+    /// Returns the input provided to the oracle procedure.
+    pub fn oracle_proc_input(&self) -> Felt {
+        self.oracle_proc_input
+    }
+
+    /// Returns the comparison operator applied to the oracle value.
+    pub fn comparison(&self) -> Comparison {
+        self.comparison
+    }
+
+    /// Returns the value the oracle value is compared against.
+    pub fn threshold(&self) -> Felt {
+        self.threshold
+    }
+
+    /// Returns true if the provided oracle value satisfies this condition.
+    pub fn is_satisfied_by(&self, oracle_value: Felt) -> bool {
+        self.comparison.evaluate(oracle_value, self.threshold)
+    }
+}
@@ -1,4 +1,4 @@
-use alloc::vec::Vec;
+use alloc::{string::ToString, vec::Vec};
 
 use miden_objects::{
     accounts::AccountId,
@@ -15,6 +15,17 @@ use utils::build_swap_tag;
 pub mod scripts;
 pub mod utils;
 
+mod condition;
+pub use condition::{Comparison, OracleCondition};
+
+mod split;
+pub use split::SplitRecipient;
+
+mod well_known;
+pub use well_known::{
+    P2idNote, P2idcNote, P2idrNote, SplitNote, SwapNote, SwappNote, WellKnownNote,
+};
+
 // STANDARDIZED SCRIPTS
 // ================================================================================================
 
@@ -48,6 +59,51 @@ pub fn create_p2id_note<R: FeltRng>(
     Ok(Note::new(vault, metadata, recipient))
 }
 
+/// Generates a P2IDC note - pay to id with a condition on a value provided by an oracle account.
+///
+/// This script enables the transfer of assets from the `sender` account to the `target` account
+/// by specifying the target's account ID, but only while the value provided by the oracle account
+/// satisfies the provided `condition` (e.g., the price of an asset is above a threshold). The
+/// oracle value is read via foreign procedure invocation, so the oracle account must be provided
+/// as a foreign account to the transaction consuming the note.
+///
+/// The passed-in `rng` is used to generate a serial number for the note. The returned note's tag
+/// is set to the target's account ID.
+///
+/// # Errors
+/// Returns an error if deserialization or compilation of the `P2IDC` script fails.
+pub fn create_p2idc_note<R: FeltRng>(
+    sender: AccountId,
+    target: AccountId,
+    assets: Vec<Asset>,
+    condition: OracleCondition,
+    note_type: NoteType,
+    aux: Felt,
+    rng: &mut R,
+) -> Result<Note, NoteError> {
+    let note_script = scripts::p2idc();
+
+    let proc_root = condition.oracle_proc_root();
+    let inputs = NoteInputs::new(vec![
+        target.into(),
+        condition.oracle_account_id().into(),
+        condition.oracle_proc_input(),
+        condition.comparison().into(),
+        proc_root[0],
+        proc_root[1],
+        proc_root[2],
+        proc_root[3],
+        condition.threshold(),
+    ])?;
+    let tag = NoteTag::from_account_id(target, NoteExecutionMode::Local)?;
+    let serial_num = rng.draw_word();
+
+    let metadata = NoteMetadata::new(sender, note_type, tag, NoteExecutionHint::always(), aux)?;
+    let vault = NoteAssets::new(assets)?;
+    let recipient = NoteRecipient::new(serial_num, note_script, inputs);
+    Ok(Note::new(vault, metadata, recipient))
+}
+
 /// Generates a P2IDR note - pay to id with recall after a certain block height.
 ///
 /// This script enables the transfer of assets from the sender `sender` account to the `target`
@@ -136,3 +192,169 @@ pub fn create_swap_note<R: FeltRng>(
 
     Ok((note, payback_note))
 }
+
+/// Generates a SPLIT note - a note distributing its assets to multiple recipients.
+///
+/// This script enables the `sender` to pay out the provided fungible `assets` to a list of
+/// `recipients` in fixed proportions, e.g., for payroll or royalty use cases. The account
+/// consuming the note creates a private P2ID note for each recipient containing its share of
+/// every asset, see [SplitRecipient] for how the shares are computed. Use
+/// [SplitNote::payout_notes()] to compute the notes created when consuming the returned note.
+///
+/// The passed-in `rng` is used to generate a serial number for the note. The returned note's tag
+/// is set to the sender's account ID.
+///
+/// # Errors
+/// Returns an error if:
+/// - No recipients or more than [SplitNote::MAX_RECIPIENTS] recipients are provided.
+/// - The sum of the shares of all recipients exceeds [u16::MAX].
+/// - No assets are provided, any of the assets is not a fungible asset or the amount of any of the
+///   assets does not fit into a u32.
+/// - Deserialization or compilation of the `SPLIT` script fails.
+pub fn create_split_note<R: FeltRng>(
+    sender: AccountId,
+    recipients: Vec<SplitRecipient>,
+    assets: Vec<Asset>,
+    note_type: NoteType,
+    aux: Felt,
+    rng: &mut R,
+) -> Result<Note, NoteError> {
+    if recipients.is_empty() || recipients.len() > SplitNote::MAX_RECIPIENTS {
+        return Err(NoteError::InvalidNoteInputs(format!(
+            "SPLIT note requires between 1 and {} recipients, but {} were provided",
+            SplitNote::MAX_RECIPIENTS,
+            recipients.len()
+        )));
+    }
+
+    let total_shares: u64 = recipients.iter().map(|recipient| recipient.share() as u64).sum();
+    if total_shares > u16::MAX as u64 {
+        return Err(NoteError::InvalidNoteInputs(format!(
+            "sum of the SPLIT shares {total_shares} exceeds {}",
+            u16::MAX
+        )));
+    }
+
+    if assets.is_empty()
+        || !assets.iter().all(|asset| match asset {
+            Asset::Fungible(asset) => asset.amount() <= u32::MAX as u64,
+            Asset::NonFungible(_) => false,
+        })
+    {
+        return Err(NoteError::InvalidNoteInputs(
+            "SPLIT assets must be fungible with amounts fitting into u32".to_string(),
+        ));
+    }
+
+    let note_script = scripts::split();
+
+    let p2id_root: Word = scripts::p2id().hash().into();
+    let mut inputs = p2id_root.to_vec();
+    for recipient in recipients {
+        inputs.extend_from_slice(&Word::from(recipient));
+    }
+    let inputs = NoteInputs::new(inputs)?;
+
+    let tag = NoteTag::from_account_id(sender, NoteExecutionMode::Local)?;
+    let serial_num = rng.draw_word();
+
+    let metadata = NoteMetadata::new(sender, note_type, tag, NoteExecutionHint::always(), aux)?;
+    let vault = NoteAssets::new(assets)?;
+    let recipient = NoteRecipient::new(serial_num, note_script, inputs);
+    Ok(Note::new(vault, metadata, recipient))
+}
+
+/// Generates a SWAPP note - a partially fillable swap of assets between two accounts.
+///
+/// This script enables a swap of 2 fungible assets between the `sender` account and any other
+/// account that is willing to consume the note. The consumer may take only a part of the
+/// `offered_asset`, as long as it pays back at least the proportional part of the
+/// `requested_asset` to the `sender` via a P2ID note. The rest of the `offered_asset` is
+/// re-emitted by the consumer in a new public SWAPP note with the same price. Use
+/// [SwappNote::fill()] to compute the notes created when consuming the returned note.
+///
+/// The amounts of both assets must fit into a u32 so that the price check of the note script
+/// cannot overflow.
+///
+/// # Errors
+/// Returns an error if:
+/// - Either of the assets is not a fungible asset.
+/// - The amount of either of the assets is zero or does not fit into a u32.
+/// - Deserialization or compilation of the `SWAPP` script fails.
+pub fn create_swapp_note<R: FeltRng>(
+    sender: AccountId,
+    offered_asset: Asset,
+    requested_asset: Asset,
+    note_type: NoteType,
+    aux: Felt,
+    rng: &mut R,
+) -> Result<Note, NoteError> {
+    for asset in [offered_asset, requested_asset] {
+        match asset {
+            Asset::Fungible(asset) if asset.amount() > 0 && asset.amount() <= u32::MAX as u64 => {},
+            _ => {
+                return Err(NoteError::InvalidNoteInputs(
+                    "SWAPP assets must be fungible with a non-zero amount fitting into u32"
+                        .to_string(),
+                ))
+            },
+        }
+    }
+
+    let note_script = scripts::swapp();
+
+    // the remainder notes are always public, so their tag must be a public one
+    let payback_tag = NoteTag::from_account_id(sender, NoteExecutionMode::Local)?;
+    let swapp_tag = build_swap_tag(NoteType::Public, &offered_asset, &requested_asset)?;
+    let inputs = build_swapp_inputs(
+        requested_asset,
+        payback_tag,
+        NoteExecutionHint::always(),
+        sender,
+        swapp_tag,
+    )?;
+
+    let tag = build_swap_tag(note_type, &offered_asset, &requested_asset)?;
+    let serial_num = rng.draw_word();
+
+    let metadata = NoteMetadata::new(sender, note_type, tag, NoteExecutionHint::always(), aux)?;
+    let assets = NoteAssets::new(vec![offered_asset])?;
+    let recipient = NoteRecipient::new(serial_num, note_script, inputs);
+    Ok(Note::new(assets, metadata, recipient))
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the inputs of a SWAPP note with the provided parameters.
+fn build_swapp_inputs(
+    requested_asset: Asset,
+    payback_tag: NoteTag,
+    payback_execution_hint: NoteExecutionHint,
+    creator: AccountId,
+    swapp_tag: NoteTag,
+) -> Result<NoteInputs, NoteError> {
+    let requested_asset_word: Word = requested_asset.into();
+    let swapp_root: Word = scripts::swapp().hash().into();
+    let p2id_root: Word = scripts::p2id().hash().into();
+
+    let mut inputs = Vec::with_capacity(16);
+    inputs.extend_from_slice(&requested_asset_word);
+    inputs.extend_from_slice(&swapp_root);
+    inputs.extend_from_slice(&p2id_root);
+    inputs.extend_from_slice(&[
+        payback_tag.inner().into(),
+        payback_execution_hint.into(),
+        creator.into(),
+        swapp_tag.inner().into(),
+    ]);
+
+    NoteInputs::new(inputs)
+}
+
+/// Returns the serial number of a note created by a note with the provided serial number, which is
+/// the provided serial number with its last element incremented by `offset`.
+fn derive_serial_num(serial_num: Word, offset: u64) -> Word {
+    let [s0, s1, s2, s3] = serial_num;
+    [s0, s1, s2, s3 + Felt::new(offset)]
+}
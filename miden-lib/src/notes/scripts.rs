@@ -1,5 +1,5 @@
 use miden_objects::{
-    notes::NoteScript,
+    notes::{NoteInputType, NoteInputsSchema, NoteScript},
     utils::{sync::LazyLock, Deserializable},
     vm::Program,
 };
@@ -11,6 +11,13 @@ static P2ID_SCRIPT: LazyLock<NoteScript> = LazyLock::new(|| {
     NoteScript::new(program)
 });
 
+// Initialize the P2IDC note script only once
+static P2IDC_SCRIPT: LazyLock<NoteScript> = LazyLock::new(|| {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2IDC.masb"));
+    let program = Program::read_from_bytes(bytes).expect("Shipped P2IDC script is well-formed");
+    NoteScript::new(program)
+});
+
 // Initialize the P2IDR note script only once
 static P2IDR_SCRIPT: LazyLock<NoteScript> = LazyLock::new(|| {
     let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2IDR.masb"));
@@ -18,6 +25,13 @@ static P2IDR_SCRIPT: LazyLock<NoteScript> = LazyLock::new(|| {
     NoteScript::new(program)
 });
 
+// Initialize the SPLIT note script only once
+static SPLIT_SCRIPT: LazyLock<NoteScript> = LazyLock::new(|| {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/SPLIT.masb"));
+    let program = Program::read_from_bytes(bytes).expect("Shipped SPLIT script is well-formed");
+    NoteScript::new(program)
+});
+
 // Initialize the SWAP note script only once
 static SWAP_SCRIPT: LazyLock<NoteScript> = LazyLock::new(|| {
     let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/SWAP.masb"));
@@ -25,17 +39,83 @@ static SWAP_SCRIPT: LazyLock<NoteScript> = LazyLock::new(|| {
     NoteScript::new(program)
 });
 
+// Initialize the SWAPP note script only once
+static SWAPP_SCRIPT: LazyLock<NoteScript> = LazyLock::new(|| {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/SWAPP.masb"));
+    let program = Program::read_from_bytes(bytes).expect("Shipped SWAPP script is well-formed");
+    NoteScript::new(program)
+});
+
 /// Returns the P2ID (Pay-to-ID) note script.
 pub fn p2id() -> NoteScript {
     P2ID_SCRIPT.clone()
 }
 
+/// Returns the P2IDC (Pay-to-ID with condition) note script.
+pub fn p2idc() -> NoteScript {
+    P2IDC_SCRIPT.clone()
+}
+
 /// Returns the P2IDR (Pay-to-ID with recall) note script.
 pub fn p2idr() -> NoteScript {
     P2IDR_SCRIPT.clone()
 }
 
+/// Returns the SPLIT (multi-recipient payout) note script.
+pub fn split() -> NoteScript {
+    SPLIT_SCRIPT.clone()
+}
+
 /// Returns the SWAP (Swap note) note script.
 pub fn swap() -> NoteScript {
     SWAP_SCRIPT.clone()
 }
+
+/// Returns the SWAPP (partially fillable Swap note) note script.
+pub fn swapp() -> NoteScript {
+    SWAPP_SCRIPT.clone()
+}
+
+// NOTE INPUTS SCHEMAS
+// ================================================================================================
+
+/// Returns the schema of the inputs of the P2ID note script: `[target]`.
+pub fn p2id_inputs_schema() -> NoteInputsSchema {
+    NoteInputsSchema::new([("target", NoteInputType::AccountId)])
+        .expect("P2ID inputs schema is well-formed")
+}
+
+/// Returns the schema of the inputs of the P2IDC note script:
+/// `[target, oracle_account_id, oracle_proc_input, comparison, ORACLE_PROC_ROOT, threshold]`.
+pub fn p2idc_inputs_schema() -> NoteInputsSchema {
+    NoteInputsSchema::new([
+        ("target", NoteInputType::AccountId),
+        ("oracle_account_id", NoteInputType::AccountId),
+        ("oracle_proc_input", NoteInputType::Felt),
+        ("comparison", NoteInputType::Felt),
+        ("oracle_proc_root", NoteInputType::Word),
+        ("threshold", NoteInputType::Felt),
+    ])
+    .expect("P2IDC inputs schema is well-formed")
+}
+
+/// Returns the schema of the inputs of the P2IDR note script: `[target, recall_height]`.
+pub fn p2idr_inputs_schema() -> NoteInputsSchema {
+    NoteInputsSchema::new([
+        ("target", NoteInputType::AccountId),
+        ("recall_height", NoteInputType::U32),
+    ])
+    .expect("P2IDR inputs schema is well-formed")
+}
+
+/// Returns the schema of the inputs of the SWAP note script:
+/// `[PAYBACK_RECIPIENT, REQUESTED_ASSET, payback_tag, payback_execution_hint]`.
+pub fn swap_inputs_schema() -> NoteInputsSchema {
+    NoteInputsSchema::new([
+        ("payback_recipient", NoteInputType::Word),
+        ("requested_asset", NoteInputType::Word),
+        ("payback_tag", NoteInputType::U32),
+        ("payback_execution_hint", NoteInputType::Felt),
+    ])
+    .expect("SWAP inputs schema is well-formed")
+}
@@ -0,0 +1,139 @@
+use alloc::{string::ToString, vec::Vec};
+
+use miden_objects::{
+    accounts::AccountId,
+    notes::{NoteExecutionHint, NoteExecutionMode, NoteTag},
+    Felt, NoteError, Word,
+};
+
+// SPLIT RECIPIENT
+// ================================================================================================
+
+/// A recipient of a SPLIT note, which receives a P2ID note with its share of the note assets when
+/// the SPLIT note is consumed.
+///
+/// Every recipient receives `amount * share / total_shares` of each asset of the SPLIT note rounded
+/// down, where `total_shares` is the sum of the shares of all recipients of the note. The last
+/// recipient additionally receives the rounding remainder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SplitRecipient {
+    account_id: AccountId,
+    share: u16,
+    tag: NoteTag,
+    execution_hint: NoteExecutionHint,
+}
+
+impl SplitRecipient {
+    /// Returns a new [SplitRecipient] receiving the provided share of the note assets via a P2ID
+    /// note tagged with the recipient's account ID.
+    ///
+    /// # Errors
+    /// Returns an error if the share is zero.
+    pub fn new(account_id: AccountId, share: u16) -> Result<Self, NoteError> {
+        if share == 0 {
+            return Err(NoteError::InvalidNoteInputs(
+                "split recipient share must be non-zero".into(),
+            ));
+        }
+
+        Ok(Self {
+            account_id,
+            share,
+            tag: NoteTag::from_account_id(account_id, NoteExecutionMode::Local)?,
+            execution_hint: NoteExecutionHint::always(),
+        })
+    }
+
+    /// Returns the account receiving the P2ID note.
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the share of the note assets received by this recipient.
+    pub fn share(&self) -> u16 {
+        self.share
+    }
+
+    /// Returns the tag of the P2ID note.
+    pub fn tag(&self) -> NoteTag {
+        self.tag
+    }
+
+    /// Returns the execution hint of the P2ID note.
+    pub fn execution_hint(&self) -> NoteExecutionHint {
+        self.execution_hint
+    }
+}
+
+impl From<SplitRecipient> for Word {
+    fn from(recipient: SplitRecipient) -> Self {
+        [
+            recipient.account_id.into(),
+            Felt::from(recipient.share as u32),
+            recipient.tag.inner().into(),
+            recipient.execution_hint.into(),
+        ]
+    }
+}
+
+impl TryFrom<Word> for SplitRecipient {
+    type Error = NoteError;
+
+    fn try_from(word: Word) -> Result<Self, Self::Error> {
+        let [account_id, share, tag, execution_hint] = word;
+
+        let account_id = AccountId::try_from(account_id)
+            .map_err(|err| NoteError::InvalidNoteInputs(err.to_string()))?;
+        let share = u16::try_from(share.as_int())
+            .ok()
+            .filter(|share| *share != 0)
+            .ok_or_else(|| NoteError::InvalidNoteInputs(format!("invalid split share {share}")))?;
+        let tag = NoteTag::try_from(tag)
+            .map_err(|_| NoteError::InvalidNoteInputs("split tag must fit into u32".into()))?;
+        let execution_hint = NoteExecutionHint::try_from(execution_hint.as_int())?;
+
+        Ok(Self { account_id, share, tag, execution_hint })
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the amounts of an asset with the provided amount received by recipients with the
+/// provided shares, as computed by the SPLIT note script.
+pub(super) fn split_amount(amount: u64, shares: &[u16]) -> Vec<u64> {
+    let total_shares: u64 = shares.iter().map(|share| *share as u64).sum();
+
+    let mut remaining = amount;
+    let mut amounts: Vec<u64> = shares[..shares.len().saturating_sub(1)]
+        .iter()
+        .map(|share| {
+            let amount = amount / total_shares * *share as u64
+                + amount % total_shares * *share as u64 / total_shares;
+            remaining -= amount;
+            amount
+        })
+        .collect();
+    if !shares.is_empty() {
+        amounts.push(remaining);
+    }
+
+    amounts
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::split_amount;
+
+    #[test]
+    fn test_split_amount() {
+        assert_eq!(split_amount(100, &[1, 1, 1]), vec![33, 33, 34]);
+        assert_eq!(split_amount(100, &[3, 7]), vec![30, 70]);
+        assert_eq!(split_amount(5, &[1, 1, 1, 1, 1, 1]), vec![0, 0, 0, 0, 0, 5]);
+        assert_eq!(split_amount(u32::MAX as u64, &[u16::MAX]), vec![u32::MAX as u64]);
+        assert!(split_amount(100, &[]).is_empty());
+    }
+}
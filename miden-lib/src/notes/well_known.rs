@@ -0,0 +1,940 @@
+use alloc::{string::ToString, vec::Vec};
+
+use miden_objects::{
+    accounts::AccountId,
+    assets::{Asset, FungibleAsset},
+    crypto::rand::FeltRng,
+    notes::{
+        Note, NoteArgs, NoteAssets, NoteDetails, NoteExecutionHint, NoteInputValue,
+        NoteInputsSchema, NoteMetadata, NoteRecipient, NoteTag, NoteType,
+    },
+    Digest, Felt, NoteError, Word, ZERO,
+};
+
+use super::{
+    build_swapp_inputs, create_p2id_note, create_p2idc_note, create_p2idr_note, create_split_note,
+    create_swap_note, create_swapp_note, derive_serial_num, scripts, split::split_amount, utils,
+    Comparison, OracleCondition, SplitRecipient,
+};
+
+// WELL-KNOWN NOTE
+// ================================================================================================
+
+/// A note using one of the standard note scripts shipped with this crate.
+///
+/// A [WellKnownNote] can be recognized from an arbitrary [Note] via [WellKnownNote::try_from()],
+/// which matches the root of the note's script against the standard scripts and decodes the note
+/// inputs into structured fields.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WellKnownNote {
+    P2id(P2idNote),
+    P2idc(P2idcNote),
+    P2idr(P2idrNote),
+    Swap(SwapNote),
+    Swapp(SwappNote),
+    Split(SplitNote),
+}
+
+impl WellKnownNote {
+    /// Returns a reference to the underlying note.
+    pub fn note(&self) -> &Note {
+        match self {
+            WellKnownNote::P2id(note) => note.note(),
+            WellKnownNote::P2idc(note) => note.note(),
+            WellKnownNote::P2idr(note) => note.note(),
+            WellKnownNote::Swap(note) => note.note(),
+            WellKnownNote::Swapp(note) => note.note(),
+            WellKnownNote::Split(note) => note.note(),
+        }
+    }
+}
+
+impl TryFrom<&Note> for WellKnownNote {
+    type Error = NoteError;
+
+    /// Recognizes the provided note as one of the well-known notes.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The note script is not one of the standard note scripts.
+    /// - The note inputs cannot be decoded into the inputs expected by the script.
+    fn try_from(note: &Note) -> Result<Self, Self::Error> {
+        let script_root = note.script().hash();
+
+        if script_root == scripts::p2id().hash() {
+            P2idNote::try_from(note.clone()).map(WellKnownNote::P2id)
+        } else if script_root == scripts::p2idc().hash() {
+            P2idcNote::try_from(note.clone()).map(WellKnownNote::P2idc)
+        } else if script_root == scripts::p2idr().hash() {
+            P2idrNote::try_from(note.clone()).map(WellKnownNote::P2idr)
+        } else if script_root == scripts::swap().hash() {
+            SwapNote::try_from(note.clone()).map(WellKnownNote::Swap)
+        } else if script_root == scripts::swapp().hash() {
+            SwappNote::try_from(note.clone()).map(WellKnownNote::Swapp)
+        } else if script_root == scripts::split().hash() {
+            SplitNote::try_from(note.clone()).map(WellKnownNote::Split)
+        } else {
+            Err(NoteError::UnknownNoteScript(script_root))
+        }
+    }
+}
+
+impl From<WellKnownNote> for Note {
+    fn from(note: WellKnownNote) -> Self {
+        match note {
+            WellKnownNote::P2id(note) => note.into(),
+            WellKnownNote::P2idc(note) => note.into(),
+            WellKnownNote::P2idr(note) => note.into(),
+            WellKnownNote::Swap(note) => note.into(),
+            WellKnownNote::Swapp(note) => note.into(),
+            WellKnownNote::Split(note) => note.into(),
+        }
+    }
+}
+
+// P2ID NOTE
+// ================================================================================================
+
+/// A P2ID (pay-to-ID) note, which can only be consumed by the target account.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct P2idNote {
+    note: Note,
+    target: AccountId,
+}
+
+impl P2idNote {
+    /// Returns a new P2ID note transferring the provided assets from the sender to the target
+    /// account.
+    ///
+    /// See [create_p2id_note()] for details.
+    pub fn new<R: FeltRng>(
+        sender: AccountId,
+        target: AccountId,
+        assets: Vec<Asset>,
+        note_type: NoteType,
+        aux: Felt,
+        rng: &mut R,
+    ) -> Result<Self, NoteError> {
+        let note = create_p2id_note(sender, target, assets, note_type, aux, rng)?;
+        Ok(Self { note, target })
+    }
+
+    /// Returns the account which can consume this note.
+    pub fn target(&self) -> AccountId {
+        self.target
+    }
+
+    /// Returns a reference to the underlying note.
+    pub fn note(&self) -> &Note {
+        &self.note
+    }
+}
+
+impl TryFrom<Note> for P2idNote {
+    type Error = NoteError;
+
+    fn try_from(note: Note) -> Result<Self, Self::Error> {
+        check_script(&note, scripts::p2id().hash())?;
+        let [NoteInputValue::AccountId(target)] =
+            decode_inputs(&scripts::p2id_inputs_schema(), &note)?
+        else {
+            unreachable!("P2ID note inputs are decoded according to the P2ID inputs schema");
+        };
+
+        Ok(Self { target, note })
+    }
+}
+
+impl From<P2idNote> for Note {
+    fn from(note: P2idNote) -> Self {
+        note.note
+    }
+}
+
+// P2IDC NOTE
+// ================================================================================================
+
+/// A P2IDC (pay-to-ID with condition) note, which can only be consumed by the target account
+/// while the value provided by an oracle account satisfies the note's condition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct P2idcNote {
+    note: Note,
+    target: AccountId,
+    condition: OracleCondition,
+}
+
+impl P2idcNote {
+    /// Returns a new P2IDC note transferring the provided assets from the sender to the target
+    /// account, subject to the provided condition.
+    ///
+    /// See [create_p2idc_note()] for details.
+    pub fn new<R: FeltRng>(
+        sender: AccountId,
+        target: AccountId,
+        assets: Vec<Asset>,
+        condition: OracleCondition,
+        note_type: NoteType,
+        aux: Felt,
+        rng: &mut R,
+    ) -> Result<Self, NoteError> {
+        let note = create_p2idc_note(sender, target, assets, condition, note_type, aux, rng)?;
+        Ok(Self { note, target, condition })
+    }
+
+    /// Returns the account which can consume this note.
+    pub fn target(&self) -> AccountId {
+        self.target
+    }
+
+    /// Returns the condition which must be satisfied to consume this note.
+    pub fn condition(&self) -> &OracleCondition {
+        &self.condition
+    }
+
+    /// Returns a reference to the underlying note.
+    pub fn note(&self) -> &Note {
+        &self.note
+    }
+}
+
+impl TryFrom<Note> for P2idcNote {
+    type Error = NoteError;
+
+    fn try_from(note: Note) -> Result<Self, Self::Error> {
+        check_script(&note, scripts::p2idc().hash())?;
+        let [target, oracle_id, proc_input, comparison, proc_root, threshold] =
+            decode_inputs(&scripts::p2idc_inputs_schema(), &note)?;
+        let (
+            NoteInputValue::AccountId(target),
+            NoteInputValue::AccountId(oracle_id),
+            NoteInputValue::Felt(proc_input),
+            NoteInputValue::Felt(comparison),
+            NoteInputValue::Word(proc_root),
+            NoteInputValue::Felt(threshold),
+        ) = (target, oracle_id, proc_input, comparison, proc_root, threshold)
+        else {
+            unreachable!("P2IDC note inputs are decoded according to the P2IDC inputs schema");
+        };
+
+        let condition = OracleCondition::new(
+            oracle_id,
+            Digest::from(proc_root),
+            proc_input,
+            Comparison::try_from(comparison)?,
+            threshold,
+        );
+
+        Ok(Self { target, condition, note })
+    }
+}
+
+impl From<P2idcNote> for Note {
+    fn from(note: P2idcNote) -> Self {
+        note.note
+    }
+}
+
+// P2IDR NOTE
+// ================================================================================================
+
+/// A P2IDR (pay-to-ID with recall) note, which can be consumed by the target account, or by the
+/// sender once the recall height has been reached.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct P2idrNote {
+    note: Note,
+    target: AccountId,
+    recall_height: u32,
+}
+
+impl P2idrNote {
+    /// Returns a new P2IDR note transferring the provided assets from the sender to the target
+    /// account, recallable by the sender from the specified block height.
+    ///
+    /// See [create_p2idr_note()] for details.
+    pub fn new<R: FeltRng>(
+        sender: AccountId,
+        target: AccountId,
+        assets: Vec<Asset>,
+        note_type: NoteType,
+        aux: Felt,
+        recall_height: u32,
+        rng: &mut R,
+    ) -> Result<Self, NoteError> {
+        let note = create_p2idr_note(sender, target, assets, note_type, aux, recall_height, rng)?;
+        Ok(Self { note, target, recall_height })
+    }
+
+    /// Returns the account which can consume this note.
+    pub fn target(&self) -> AccountId {
+        self.target
+    }
+
+    /// Returns the block height from which the sender can recall this note.
+    pub fn recall_height(&self) -> u32 {
+        self.recall_height
+    }
+
+    /// Returns a reference to the underlying note.
+    pub fn note(&self) -> &Note {
+        &self.note
+    }
+}
+
+impl TryFrom<Note> for P2idrNote {
+    type Error = NoteError;
+
+    fn try_from(note: Note) -> Result<Self, Self::Error> {
+        check_script(&note, scripts::p2idr().hash())?;
+        let [NoteInputValue::AccountId(target), NoteInputValue::U32(recall_height)] =
+            decode_inputs(&scripts::p2idr_inputs_schema(), &note)?
+        else {
+            unreachable!("P2IDR note inputs are decoded according to the P2IDR inputs schema");
+        };
+
+        Ok(Self { target, recall_height, note })
+    }
+}
+
+impl From<P2idrNote> for Note {
+    fn from(note: P2idrNote) -> Self {
+        note.note
+    }
+}
+
+// SWAP NOTE
+// ================================================================================================
+
+/// A SWAP note, which offers its assets to any account willing to pay the requested asset back
+/// to the sender via a P2ID note.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SwapNote {
+    note: Note,
+    requested_asset: Asset,
+    payback_recipient: Digest,
+    payback_tag: NoteTag,
+    payback_execution_hint: NoteExecutionHint,
+}
+
+impl SwapNote {
+    /// Returns a new SWAP note offering the offered asset in exchange for the requested asset,
+    /// together with the details of the payback note.
+    ///
+    /// See [create_swap_note()] for details.
+    pub fn new<R: FeltRng>(
+        sender: AccountId,
+        offered_asset: Asset,
+        requested_asset: Asset,
+        note_type: NoteType,
+        aux: Felt,
+        rng: &mut R,
+    ) -> Result<(Self, NoteDetails), NoteError> {
+        let (note, payback_note) =
+            create_swap_note(sender, offered_asset, requested_asset, note_type, aux, rng)?;
+
+        Ok((Self::try_from(note)?, payback_note))
+    }
+
+    /// Returns the asset which has to be paid back to the sender to consume this note.
+    pub fn requested_asset(&self) -> Asset {
+        self.requested_asset
+    }
+
+    /// Returns the recipient of the payback note.
+    pub fn payback_recipient(&self) -> Digest {
+        self.payback_recipient
+    }
+
+    /// Returns the tag of the payback note.
+    pub fn payback_tag(&self) -> NoteTag {
+        self.payback_tag
+    }
+
+    /// Returns the execution hint of the payback note.
+    pub fn payback_execution_hint(&self) -> NoteExecutionHint {
+        self.payback_execution_hint
+    }
+
+    /// Returns a reference to the underlying note.
+    pub fn note(&self) -> &Note {
+        &self.note
+    }
+}
+
+impl TryFrom<Note> for SwapNote {
+    type Error = NoteError;
+
+    fn try_from(note: Note) -> Result<Self, Self::Error> {
+        check_script(&note, scripts::swap().hash())?;
+        let [recipient, asset, tag, hint] = decode_inputs(&scripts::swap_inputs_schema(), &note)?;
+        let (
+            NoteInputValue::Word(recipient),
+            NoteInputValue::Word(asset),
+            NoteInputValue::U32(tag),
+            NoteInputValue::Felt(hint),
+        ) = (recipient, asset, tag, hint)
+        else {
+            unreachable!("SWAP note inputs are decoded according to the SWAP inputs schema");
+        };
+
+        let payback_recipient = Digest::from(recipient);
+        let requested_asset = Asset::try_from(asset).map_err(NoteError::InvalidAssetData)?;
+        let payback_tag = NoteTag::from(tag);
+        let payback_execution_hint = NoteExecutionHint::try_from(hint.as_int())?;
+
+        Ok(Self {
+            note,
+            requested_asset,
+            payback_recipient,
+            payback_tag,
+            payback_execution_hint,
+        })
+    }
+}
+
+impl From<SwapNote> for Note {
+    fn from(note: SwapNote) -> Self {
+        note.note
+    }
+}
+
+// SWAPP NOTE
+// ================================================================================================
+
+/// A SWAPP note, which offers its asset to any account willing to pay the requested asset back to
+/// the creator of the note via a P2ID note, either entirely or in part.
+///
+/// When the note is filled partially, the consumer re-emits the remainder of the offered asset in
+/// a new public SWAPP note requesting the remainder of the requested asset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SwappNote {
+    note: Note,
+    offered_asset: FungibleAsset,
+    requested_asset: FungibleAsset,
+    creator: AccountId,
+    payback_tag: NoteTag,
+    payback_execution_hint: NoteExecutionHint,
+    swapp_tag: NoteTag,
+}
+
+impl SwappNote {
+    /// Returns a new SWAPP note offering the offered asset in exchange for the requested asset.
+    ///
+    /// See [create_swapp_note()] for details.
+    pub fn new<R: FeltRng>(
+        sender: AccountId,
+        offered_asset: Asset,
+        requested_asset: Asset,
+        note_type: NoteType,
+        aux: Felt,
+        rng: &mut R,
+    ) -> Result<Self, NoteError> {
+        let note = create_swapp_note(sender, offered_asset, requested_asset, note_type, aux, rng)?;
+        Self::try_from(note)
+    }
+
+    /// Returns the note args with which the note is consumed to take `offered_fill` units of the
+    /// offered asset in exchange for `requested_fill` units of the requested asset.
+    ///
+    /// The args are `[offered_fill, requested_fill, 0, 0]`, such that the note script starts with
+    /// the stack `[0, 0, requested_fill, offered_fill]`. An `offered_fill` of zero fills the note
+    /// entirely, which is equivalent to consuming the note with [NoteArgs::EMPTY].
+    pub fn note_args(offered_fill: u64, requested_fill: u64) -> NoteArgs {
+        NoteArgs::new([Felt::new(offered_fill), Felt::new(requested_fill), ZERO, ZERO])
+    }
+
+    /// Returns the asset offered by this note.
+    pub fn offered_asset(&self) -> FungibleAsset {
+        self.offered_asset
+    }
+
+    /// Returns the asset which has to be paid back to the creator to consume this note entirely.
+    pub fn requested_asset(&self) -> FungibleAsset {
+        self.requested_asset
+    }
+
+    /// Returns the account which receives the payback notes of this note.
+    pub fn creator(&self) -> AccountId {
+        self.creator
+    }
+
+    /// Returns the tag of the payback notes.
+    pub fn payback_tag(&self) -> NoteTag {
+        self.payback_tag
+    }
+
+    /// Returns the execution hint of the payback notes.
+    pub fn payback_execution_hint(&self) -> NoteExecutionHint {
+        self.payback_execution_hint
+    }
+
+    /// Returns the tag of the remainder notes.
+    pub fn swapp_tag(&self) -> NoteTag {
+        self.swapp_tag
+    }
+
+    /// Returns a reference to the underlying note.
+    pub fn note(&self) -> &Note {
+        &self.note
+    }
+
+    /// Returns the notes created by the `consumer` account when consuming this note with the
+    /// note args built from `offered_fill` and `requested_fill`, i.e., the payback note and, if
+    /// the note is filled partially, the remainder note.
+    ///
+    /// # Errors
+    /// Returns an error if the fill amounts would be rejected by the note script, i.e., if:
+    /// - The offered fill amount exceeds the amount of the offered asset.
+    /// - The requested fill amount exceeds the amount of the requested asset, or is equal to it for
+    ///   a partial fill.
+    /// - The requested fill amount does not pay the price of the offered fill amount.
+    pub fn fill(
+        &self,
+        consumer: AccountId,
+        offered_fill: u64,
+        requested_fill: u64,
+    ) -> Result<(Note, Option<SwappNote>), NoteError> {
+        let offered_amount = self.offered_asset.amount();
+        let requested_amount = self.requested_asset.amount();
+
+        let (offered_fill, requested_fill) = if offered_fill == 0 {
+            (offered_amount, requested_amount)
+        } else {
+            (offered_fill, requested_fill)
+        };
+
+        let is_partial_fill = offered_fill < offered_amount;
+        if offered_fill > offered_amount
+            || requested_fill > requested_amount
+            || (is_partial_fill && requested_fill == requested_amount)
+            || (offered_fill as u128) * (requested_amount as u128)
+                > (requested_fill as u128) * (offered_amount as u128)
+        {
+            return Err(NoteError::InvalidSwapFill { offered_fill, requested_fill });
+        }
+
+        let serial_num = self.note.serial_num();
+
+        // build the payback note
+        let payback_recipient =
+            utils::build_p2id_recipient(self.creator, derive_serial_num(serial_num, 1))?;
+        let payback_metadata = NoteMetadata::new(
+            consumer,
+            NoteType::Private,
+            self.payback_tag,
+            self.payback_execution_hint,
+            ZERO,
+        )?;
+        let payback_asset = FungibleAsset::new(self.requested_asset.faucet_id(), requested_fill)
+            .map_err(NoteError::InvalidAssetData)?;
+        let payback_assets = NoteAssets::new(vec![payback_asset.into()])?;
+        let payback_note = Note::new(payback_assets, payback_metadata, payback_recipient);
+
+        if !is_partial_fill {
+            return Ok((payback_note, None));
+        }
+
+        // build the remainder note
+        let remaining_requested_asset =
+            FungibleAsset::new(self.requested_asset.faucet_id(), requested_amount - requested_fill)
+                .map_err(NoteError::InvalidAssetData)?;
+        let inputs = build_swapp_inputs(
+            remaining_requested_asset.into(),
+            self.payback_tag,
+            self.payback_execution_hint,
+            self.creator,
+            self.swapp_tag,
+        )?;
+        let recipient =
+            NoteRecipient::new(derive_serial_num(serial_num, 2), scripts::swapp(), inputs);
+        let metadata = NoteMetadata::new(
+            consumer,
+            NoteType::Public,
+            self.swapp_tag,
+            NoteExecutionHint::always(),
+            ZERO,
+        )?;
+        let remaining_offered_asset =
+            FungibleAsset::new(self.offered_asset.faucet_id(), offered_amount - offered_fill)
+                .map_err(NoteError::InvalidAssetData)?;
+        let assets = NoteAssets::new(vec![remaining_offered_asset.into()])?;
+        let remainder_note = Self::try_from(Note::new(assets, metadata, recipient))?;
+
+        Ok((payback_note, Some(remainder_note)))
+    }
+}
+
+impl TryFrom<Note> for SwappNote {
+    type Error = NoteError;
+
+    fn try_from(note: Note) -> Result<Self, Self::Error> {
+        check_script(&note, scripts::swapp().hash())?;
+        let inputs = note_inputs::<16>(&note)?;
+
+        let requested_asset = decode_fungible_asset([inputs[0], inputs[1], inputs[2], inputs[3]])?;
+        if Digest::from([inputs[4], inputs[5], inputs[6], inputs[7]]) != scripts::swapp().hash()
+            || Digest::from([inputs[8], inputs[9], inputs[10], inputs[11]])
+                != scripts::p2id().hash()
+        {
+            return Err(NoteError::InvalidNoteInputs(
+                "SWAPP note inputs must contain the SWAPP and P2ID script roots".to_string(),
+            ));
+        }
+        let payback_tag = NoteTag::try_from(inputs[12]).map_err(|_| {
+            NoteError::InvalidNoteInputs("payback tag must fit into u32".to_string())
+        })?;
+        let payback_execution_hint = NoteExecutionHint::try_from(inputs[13].as_int())?;
+        let creator = decode_account_id(inputs[14])?;
+        let swapp_tag = NoteTag::try_from(inputs[15])
+            .map_err(|_| NoteError::InvalidNoteInputs("SWAPP tag must fit into u32".to_string()))?;
+
+        let offered_asset = match note.assets().iter().as_slice() {
+            [asset] => decode_fungible_asset((*asset).into())?,
+            _ => {
+                return Err(NoteError::InvalidNoteInputs(
+                    "SWAPP note must contain exactly one asset".to_string(),
+                ))
+            },
+        };
+
+        Ok(Self {
+            note,
+            offered_asset,
+            requested_asset,
+            creator,
+            payback_tag,
+            payback_execution_hint,
+            swapp_tag,
+        })
+    }
+}
+
+impl From<SwappNote> for Note {
+    fn from(note: SwappNote) -> Self {
+        note.note
+    }
+}
+
+// SPLIT NOTE
+// ================================================================================================
+
+/// A SPLIT note, which distributes its assets to multiple recipients in fixed proportions via
+/// P2ID notes created by the consuming account.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SplitNote {
+    note: Note,
+    recipients: Vec<SplitRecipient>,
+}
+
+impl SplitNote {
+    /// The maximum number of recipients of a SPLIT note, limited by the maximum number of note
+    /// inputs.
+    pub const MAX_RECIPIENTS: usize = 31;
+
+    /// Returns a new SPLIT note distributing the provided assets to the provided recipients.
+    ///
+    /// See [create_split_note()] for details.
+    pub fn new<R: FeltRng>(
+        sender: AccountId,
+        recipients: Vec<SplitRecipient>,
+        assets: Vec<Asset>,
+        note_type: NoteType,
+        aux: Felt,
+        rng: &mut R,
+    ) -> Result<Self, NoteError> {
+        let note = create_split_note(sender, recipients.clone(), assets, note_type, aux, rng)?;
+        Ok(Self { note, recipients })
+    }
+
+    /// Returns the recipients of this note.
+    pub fn recipients(&self) -> &[SplitRecipient] {
+        &self.recipients
+    }
+
+    /// Returns a reference to the underlying note.
+    pub fn note(&self) -> &Note {
+        &self.note
+    }
+
+    /// Returns the P2ID notes created by the `consumer` account when consuming this note, in the
+    /// order of the recipients.
+    ///
+    /// The note of the recipient at index `i` has the serial number of this note with its last
+    /// element incremented by `i + 1`.
+    pub fn payout_notes(&self, consumer: AccountId) -> Result<Vec<Note>, NoteError> {
+        let shares: Vec<u16> = self.recipients.iter().map(SplitRecipient::share).collect();
+        let amounts: Vec<Vec<u64>> = self
+            .note
+            .assets()
+            .iter()
+            .map(|asset| split_amount(asset_amount(asset), &shares))
+            .collect();
+
+        self.recipients
+            .iter()
+            .enumerate()
+            .map(|(idx, recipient)| {
+                let serial_num = derive_serial_num(self.note.serial_num(), idx as u64 + 1);
+                let note_recipient =
+                    utils::build_p2id_recipient(recipient.account_id(), serial_num)?;
+                let metadata = NoteMetadata::new(
+                    consumer,
+                    NoteType::Private,
+                    recipient.tag(),
+                    recipient.execution_hint(),
+                    ZERO,
+                )?;
+
+                let mut assets = Vec::new();
+                for (asset, amounts) in self.note.assets().iter().zip(amounts.iter()) {
+                    if amounts[idx] > 0 {
+                        let asset = FungibleAsset::new(asset.faucet_id(), amounts[idx])
+                            .map_err(NoteError::InvalidAssetData)?;
+                        assets.push(asset.into());
+                    }
+                }
+
+                Ok(Note::new(NoteAssets::new(assets)?, metadata, note_recipient))
+            })
+            .collect()
+    }
+}
+
+impl TryFrom<Note> for SplitNote {
+    type Error = NoteError;
+
+    fn try_from(note: Note) -> Result<Self, Self::Error> {
+        check_script(&note, scripts::split().hash())?;
+
+        let inputs = note.inputs().values();
+        if inputs.len() % 4 != 0 || inputs.len() < 8 {
+            return Err(NoteError::InvalidNoteInputs(format!(
+                "expected a P2ID script root and recipient words, but found {} note inputs",
+                inputs.len()
+            )));
+        }
+        if Digest::from([inputs[0], inputs[1], inputs[2], inputs[3]]) != scripts::p2id().hash() {
+            return Err(NoteError::InvalidNoteInputs(
+                "SPLIT note inputs must start with the P2ID script root".to_string(),
+            ));
+        }
+
+        let recipients = inputs[4..]
+            .chunks_exact(4)
+            .map(|word| SplitRecipient::try_from([word[0], word[1], word[2], word[3]]))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { note, recipients })
+    }
+}
+
+impl From<SplitNote> for Note {
+    fn from(note: SplitNote) -> Self {
+        note.note
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Checks that the script of the provided note has the expected root.
+fn check_script(note: &Note, expected_root: Digest) -> Result<(), NoteError> {
+    let script_root = note.script().hash();
+    if script_root != expected_root {
+        return Err(NoteError::UnknownNoteScript(script_root));
+    }
+
+    Ok(())
+}
+
+/// Returns the inputs of the provided note, checking that there are exactly `N` of them.
+fn note_inputs<const N: usize>(note: &Note) -> Result<[Felt; N], NoteError> {
+    note.inputs().values().try_into().map_err(|_| {
+        NoteError::InvalidNoteInputs(format!(
+            "expected {N} note inputs, but found {}",
+            note.inputs().num_values()
+        ))
+    })
+}
+
+/// Decodes the inputs of the provided note according to the provided schema.
+fn decode_inputs<const N: usize>(
+    schema: &NoteInputsSchema,
+    note: &Note,
+) -> Result<[NoteInputValue; N], NoteError> {
+    let values = schema.decode(note.inputs())?;
+    Ok(values.try_into().expect("schema should have exactly N fields"))
+}
+
+/// Decodes an account ID from a note input.
+fn decode_account_id(input: Felt) -> Result<AccountId, NoteError> {
+    AccountId::try_from(input).map_err(|err| NoteError::InvalidNoteInputs(err.to_string()))
+}
+
+/// Returns the amount of the provided asset, or zero for a non-fungible asset.
+fn asset_amount(asset: &Asset) -> u64 {
+    match asset {
+        Asset::Fungible(asset) => asset.amount(),
+        Asset::NonFungible(_) => 0,
+    }
+}
+
+/// Decodes a fungible asset from a word.
+fn decode_fungible_asset(word: Word) -> Result<FungibleAsset, NoteError> {
+    match Asset::try_from(word).map_err(NoteError::InvalidAssetData)? {
+        Asset::Fungible(asset) => Ok(asset),
+        Asset::NonFungible(_) => {
+            Err(NoteError::InvalidNoteInputs("SWAPP assets must be fungible".to_string()))
+        },
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use miden_objects::{
+        accounts::account_id::testing::{
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2,
+            ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN, ACCOUNT_ID_SENDER,
+        },
+        assets::FungibleAsset,
+        crypto::rand::RpoRandomCoin,
+        ONE, ZERO,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_well_known_notes() {
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let target =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let offered: Asset = FungibleAsset::new(
+            AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap(),
+            10,
+        )
+        .unwrap()
+        .into();
+        let requested: Asset = FungibleAsset::new(
+            AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2).unwrap(),
+            20,
+        )
+        .unwrap()
+        .into();
+        let mut rng = RpoRandomCoin::new(Word::default());
+
+        let p2id =
+            P2idNote::new(sender, target, vec![offered], NoteType::Public, ZERO, &mut rng).unwrap();
+        match WellKnownNote::try_from(p2id.note()).unwrap() {
+            WellKnownNote::P2id(note) => assert_eq!(note.target(), target),
+            other => panic!("expected a P2ID note, got {other:?}"),
+        }
+
+        let p2idr =
+            P2idrNote::new(sender, target, vec![offered], NoteType::Public, ZERO, 42, &mut rng)
+                .unwrap();
+        match WellKnownNote::try_from(p2idr.note()).unwrap() {
+            WellKnownNote::P2idr(note) => {
+                assert_eq!(note.target(), target);
+                assert_eq!(note.recall_height(), 42);
+            },
+            other => panic!("expected a P2IDR note, got {other:?}"),
+        }
+
+        let condition = OracleCondition::new(
+            AccountId::try_from(ACCOUNT_ID_SENDER).unwrap(),
+            Digest::from([ZERO, ONE, ZERO, ONE]),
+            ZERO,
+            Comparison::Gte,
+            Felt::new(100),
+        );
+        assert!(condition.is_satisfied_by(Felt::new(100)));
+        assert!(!condition.is_satisfied_by(Felt::new(99)));
+
+        let p2idc = P2idcNote::new(
+            sender,
+            target,
+            vec![offered],
+            condition,
+            NoteType::Public,
+            ZERO,
+            &mut rng,
+        )
+        .unwrap();
+        match WellKnownNote::try_from(p2idc.note()).unwrap() {
+            WellKnownNote::P2idc(note) => {
+                assert_eq!(note.target(), target);
+                assert_eq!(note.condition(), &condition);
+            },
+            other => panic!("expected a P2IDC note, got {other:?}"),
+        }
+
+        let (swap, payback_note) =
+            SwapNote::new(sender, offered, requested, NoteType::Public, ZERO, &mut rng).unwrap();
+        assert_eq!(swap.requested_asset(), requested);
+        assert_eq!(swap.payback_recipient(), payback_note.recipient().digest());
+        assert!(matches!(WellKnownNote::try_from(swap.note()), Ok(WellKnownNote::Swap(_))));
+
+        let swapp =
+            SwappNote::new(sender, offered, requested, NoteType::Public, ZERO, &mut rng).unwrap();
+        assert_eq!(swapp.creator(), sender);
+        assert!(matches!(WellKnownNote::try_from(swapp.note()), Ok(WellKnownNote::Swapp(_))));
+
+        // taking 4 out of 10 offered units requires paying back at least 8 out of 20 units
+        assert!(matches!(swapp.fill(target, 4, 7), Err(NoteError::InvalidSwapFill { .. })));
+        let (payback, remainder) = swapp.fill(target, 4, 8).unwrap();
+        assert_eq!(payback.metadata().sender(), target);
+        let expected_payback = FungibleAsset::new(requested.faucet_id(), 8).unwrap();
+        assert_eq!(payback.assets().iter().next(), Some(&Asset::from(expected_payback)));
+        let remainder = remainder.unwrap();
+        assert_eq!(remainder.offered_asset().amount(), 6);
+        assert_eq!(remainder.requested_asset().amount(), 12);
+        assert_eq!(remainder.creator(), sender);
+
+        // filling the note entirely does not create a remainder note
+        let (payback, remainder) = swapp.fill(target, 0, 0).unwrap();
+        assert_eq!(payback.assets().iter().next(), Some(&requested));
+        assert!(remainder.is_none());
+
+        let recipients =
+            vec![SplitRecipient::new(target, 1).unwrap(), SplitRecipient::new(sender, 2).unwrap()];
+        let split = SplitNote::new(
+            sender,
+            recipients.clone(),
+            vec![offered, requested],
+            NoteType::Public,
+            ZERO,
+            &mut rng,
+        )
+        .unwrap();
+        match WellKnownNote::try_from(split.note()).unwrap() {
+            WellKnownNote::Split(note) => assert_eq!(note.recipients(), recipients.as_slice()),
+            other => panic!("expected a SPLIT note, got {other:?}"),
+        }
+
+        // 10 and 20 units are split 1:2, with the rounding remainder going to the last recipient
+        let payout_notes = split.payout_notes(target).unwrap();
+        let payout_amounts: Vec<Vec<u64>> = payout_notes
+            .iter()
+            .map(|note| {
+                note.assets()
+                    .iter()
+                    .map(|asset| asset.as_fungible().unwrap().amount())
+                    .collect()
+            })
+            .collect();
+        assert_eq!(payout_amounts, vec![vec![3, 6], vec![7, 14]]);
+        assert_eq!(
+            payout_notes[1].recipient(),
+            &utils::build_p2id_recipient(sender, derive_serial_num(split.note().serial_num(), 2))
+                .unwrap()
+        );
+
+        // a P2ID note cannot be decoded as a P2IDR note
+        assert!(matches!(
+            P2idrNote::try_from(Note::from(p2id)),
+            Err(NoteError::UnknownNoteScript(_))
+        ));
+    }
+}
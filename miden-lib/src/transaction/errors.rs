@@ -2,15 +2,26 @@ use alloc::{string::String, vec::Vec};
 use core::fmt;
 
 use miden_objects::{
-    notes::NoteMetadata, AccountDeltaError, AccountError, AssetError, Digest, Felt, NoteError,
+    notes::{NoteId, NoteMetadata},
+    vm::RowIndex,
+    AccountDeltaError, AccountError, AssetError, Digest, Felt, NoteError, TransactionScriptError,
 };
 
+use crate::errors::{kernel_error_message, kernel_error_name, KernelErrorLocation};
+
 // TRANSACTION KERNEL ERROR
 // ================================================================================================
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum TransactionKernelError {
     AccountDeltaError(AccountDeltaError),
+    FailedAssertion {
+        clk: RowIndex,
+        err_code: u32,
+        name: &'static str,
+        message: &'static str,
+        location: Option<KernelErrorLocation>,
+    },
     FailedToAddAssetToNote(NoteError),
     InvalidNoteInputs {
         expected: Digest,
@@ -40,9 +51,53 @@ pub enum TransactionKernelError {
     MissingMemoryValue(u32),
 }
 
+impl TransactionKernelError {
+    /// Returns a [TransactionKernelError::FailedAssertion] for an assertion which failed with the
+    /// specified error code in the specified clock cycle, or `None` if the code is not a kernel
+    /// error code.
+    ///
+    /// The error code is mapped back to the name of the error constant and its message. The error
+    /// code alone does not identify the failed assertion, so the returned error has no location;
+    /// it can be resolved from the debug info of the executed code and set via
+    /// [Self::with_location()].
+    pub fn from_failed_assertion(clk: RowIndex, err_code: u32) -> Option<Self> {
+        let name = kernel_error_name(err_code)?;
+        let message = kernel_error_message(err_code)?;
+
+        Some(Self::FailedAssertion {
+            clk,
+            err_code,
+            name,
+            message,
+            location: None,
+        })
+    }
+
+    /// Sets the MASM source location of the failed assertion if this is a
+    /// [TransactionKernelError::FailedAssertion], and returns the error unchanged otherwise.
+    pub fn with_location(mut self, new_location: KernelErrorLocation) -> Self {
+        if let Self::FailedAssertion { location, .. } = &mut self {
+            *location = Some(new_location);
+        }
+
+        self
+    }
+}
+
 impl fmt::Display for TransactionKernelError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            TransactionKernelError::FailedAssertion { clk, err_code, name, message, location } => {
+                write!(
+                    f,
+                    "{message} ({name}, error code {err_code:#010x}) at clock cycle {}",
+                    u32::from(*clk)
+                )?;
+                if let Some(location) = location {
+                    write!(f, " in {location}")?;
+                }
+                Ok(())
+            },
             TransactionKernelError::FailedToAddAssetToNote(err) => {
                 write!(f, "Failed to add asset to note: {err}")
             },
@@ -169,3 +224,38 @@ impl fmt::Display for TransactionTraceParsingError {
 
 #[cfg(feature = "std")]
 impl std::error::Error for TransactionTraceParsingError {}
+
+// TRANSACTION SCRIPT BUILDER ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum TransactionScriptBuilderError {
+    InvalidExpirationDelta(u16),
+    InvalidMintNote(NoteId),
+    NoteCreationFailed(NoteError),
+    ScriptCompilationFailed(TransactionScriptError),
+    TooManyProcedureArguments(usize),
+}
+
+impl fmt::Display for TransactionScriptBuilderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidExpirationDelta(delta) => {
+                write!(f, "transaction expiration delta must be at least 1, but was {delta}")
+            },
+            Self::InvalidMintNote(note_id) => {
+                write!(f, "note {note_id} must contain exactly one fungible asset to be minted")
+            },
+            Self::NoteCreationFailed(err) => write!(f, "failed to create note: {err}"),
+            Self::ScriptCompilationFailed(err) => {
+                write!(f, "failed to compile transaction script: {err}")
+            },
+            Self::TooManyProcedureArguments(num_args) => {
+                write!(f, "procedure call has {num_args} arguments, but at most 16 are supported")
+            },
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TransactionScriptBuilderError {}
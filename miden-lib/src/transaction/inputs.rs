@@ -2,9 +2,9 @@ use alloc::vec::Vec;
 
 use miden_objects::{
     accounts::{Account, StorageSlot},
-    transaction::{ChainMmr, InputNote, TransactionArgs, TransactionInputs, TransactionScript},
+    transaction::{ChainMmr, InputNote, TransactionArgs, TransactionInputs},
     vm::AdviceInputs,
-    Digest, Felt, FieldElement, Word, EMPTY_WORD, WORD_SIZE, ZERO,
+    Digest, Felt, FieldElement, Word, TX_KERNEL_VERSION, WORD_SIZE, ZERO,
 };
 
 use super::TransactionKernel;
@@ -24,9 +24,9 @@ pub(super) fn extend_advice_inputs(
     advice_inputs: &mut AdviceInputs,
 ) {
     // TODO: remove this value and use a user input instead
-    let kernel_version = 0;
+    let kernel_version = TX_KERNEL_VERSION;
 
-    build_advice_stack(tx_inputs, tx_args.tx_script(), advice_inputs, kernel_version);
+    build_advice_stack(tx_inputs, tx_args, advice_inputs, kernel_version);
 
     // build the advice map and Merkle store for relevant components
     add_kernel_hashes_to_advice_inputs(advice_inputs, kernel_version);
@@ -60,10 +60,11 @@ pub(super) fn extend_advice_inputs(
 ///     ACCOUNT_CODE_COMMITMENT,
 ///     number_of_input_notes,
 ///     TX_SCRIPT_ROOT,
+///     FEE_ASSET,
 /// ]
 fn build_advice_stack(
     tx_inputs: &TransactionInputs,
-    tx_script: Option<&TransactionScript>,
+    tx_args: &TransactionArgs,
     inputs: &mut AdviceInputs,
     kernel_version: u8,
 ) {
@@ -102,7 +103,10 @@ fn build_advice_stack(
     inputs.extend_stack([Felt::from(tx_inputs.input_notes().num_notes() as u32)]);
 
     // push tx_script root onto the stack
-    inputs.extend_stack(tx_script.map_or(Word::default(), |script| *script.hash()));
+    inputs.extend_stack(tx_args.tx_script().map_or(Word::default(), |script| *script.hash()));
+
+    // push the fee asset onto the stack
+    inputs.extend_stack(tx_args.fee().map_or(Word::default(), Word::from));
 }
 
 // CHAIN MMR INJECTOR
@@ -149,6 +153,9 @@ fn add_chain_mmr_to_advice_inputs(mmr: &ChainMmr, inputs: &mut AdviceInputs) {
 /// - The node |-> (key, value), for all leaf nodes of the asset vault SMT.
 /// - [account_id, 0, 0, 0] |-> account_seed, when account seed is provided.
 /// - If present, the Merkle leaves associated with the account storage maps.
+///
+/// Storage maps held by a storage backend are not added; their nodes are loaded on demand by the
+/// transaction host.
 fn add_account_to_advice_inputs(
     account: &Account,
     account_seed: Option<Word>,
@@ -229,7 +236,7 @@ fn add_input_notes_to_advice_inputs(
         let note = input_note.note();
         let assets = note.assets();
         let recipient = note.recipient();
-        let note_arg = tx_args.get_note_args(note.id()).unwrap_or(&EMPTY_WORD);
+        let note_args = tx_args.note_args(note.id());
 
         // NOTE: keep map in sync with the `note::get_inputs` API procedure
         inputs.extend_map([(
@@ -246,7 +253,7 @@ fn add_input_notes_to_advice_inputs(
         note_data.extend(*assets.commitment());
 
         // NOTE: keep in sync with the `prologue::process_note_args_and_metadata` kernel procedure
-        note_data.extend(Word::from(*note_arg));
+        note_data.extend(note_args.as_word());
         note_data.extend(Word::from(note.metadata()));
 
         // NOTE: keep in sync with the `prologue::process_note_assets` kernel procedure
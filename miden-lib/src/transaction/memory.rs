@@ -76,6 +76,9 @@ pub const NEW_CODE_ROOT_PTR: MemoryAddress = 6;
 /// The memory address at which the transaction expiration block number is stored.
 pub const TX_EXPIRATION_BLOCK_NUM_PTR: MemoryAddress = 7;
 
+/// The memory address at which the fee asset paid by the transaction is stored.
+pub const TX_FEE_ASSET_PTR: MemoryAddress = 8;
+
 // GLOBAL INPUTS
 // ------------------------------------------------------------------------------------------------
 
@@ -1,26 +1,28 @@
-use alloc::{string::ToString, sync::Arc, vec::Vec};
+use alloc::{format, string::ToString, sync::Arc, vec::Vec};
 
 #[cfg(any(feature = "testing", test))]
 use miden_objects::accounts::AccountCode;
 use miden_objects::{
     accounts::AccountId,
     assembly::{Assembler, DefaultSourceManager, KernelLibrary},
+    assets::FungibleAsset,
     transaction::{
         OutputNote, OutputNotes, TransactionArgs, TransactionInputs, TransactionOutputs,
     },
-    utils::{group_slice_elements, serde::Deserializable},
+    utils::{group_slice_elements, serde::Deserializable, sync::LazyLock},
     vm::{AdviceInputs, AdviceMap, Program, ProgramInfo, StackInputs, StackOutputs},
-    Digest, Felt, TransactionOutputError, Word, EMPTY_WORD,
+    Digest, Felt, TransactionOutputError, Word, EMPTY_WORD, MAX_INPUT_NOTES_PER_TX,
+    MAX_OUTPUT_NOTES_PER_TX, ZERO,
 };
 use miden_stdlib::StdLibrary;
-use outputs::EXPIRATION_BLOCK_ELEMENT_IDX;
+use outputs::{EXPIRATION_BLOCK_ELEMENT_IDX, FEE_ASSET_WORD_IDX};
 
 use super::MidenLib;
 
 pub mod memory;
 
 mod events;
-pub use events::{TransactionEvent, TransactionTrace};
+pub use events::{TransactionEvent, TransactionTrace, EVENT_ID_PREFIX};
 
 mod inputs;
 
@@ -31,7 +33,15 @@ pub use outputs::{
 
 mod errors;
 pub use errors::{
-    TransactionEventParsingError, TransactionKernelError, TransactionTraceParsingError,
+    TransactionEventParsingError, TransactionKernelError, TransactionScriptBuilderError,
+    TransactionTraceParsingError,
+};
+
+mod script_builder;
+pub use script_builder::{
+    ScriptOperation, TransactionScriptBuilder, AUTH_TX_RPO_FALCON512_PROC, CREATE_NOTE_PROC,
+    DISTRIBUTE_PROC, MOVE_ASSET_TO_NOTE_PROC, ROTATE_KEY_RPO_FALCON512_PROC,
+    UPDATE_EXPIRATION_BLOCK_DELTA_PROC,
 };
 
 mod procedures;
@@ -44,33 +54,61 @@ const KERNEL_LIB_BYTES: &[u8] =
 const KERNEL_MAIN_BYTES: &[u8] =
     include_bytes!(concat!(env!("OUT_DIR"), "/assets/kernels/tx_kernel.masb"));
 
+// Deserialize the transaction kernel library only once.
+static KERNEL_LIB: LazyLock<KernelLibrary> = LazyLock::new(|| {
+    KernelLibrary::read_from_bytes(KERNEL_LIB_BYTES)
+        .expect("failed to deserialize transaction kernel library")
+});
+
+// Deserialize the transaction kernel program only once.
+static KERNEL_MAIN: LazyLock<Program> = LazyLock::new(|| {
+    Program::read_from_bytes(KERNEL_MAIN_BYTES)
+        .expect("failed to deserialize transaction kernel runtime")
+});
+
+// Compute the program info of the transaction kernel only once.
+static KERNEL_PROGRAM_INFO: LazyLock<ProgramInfo> =
+    LazyLock::new(|| ProgramInfo::new(KERNEL_MAIN.hash(), KERNEL_LIB.kernel().clone()));
+
 // TRANSACTION KERNEL
 // ================================================================================================
 
 pub struct TransactionKernel;
 
 impl TransactionKernel {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The maximum number of notes which can be consumed by a single transaction, as enforced by
+    /// the transaction kernel.
+    pub const MAX_INPUT_NOTES: usize = MAX_INPUT_NOTES_PER_TX;
+
+    /// The maximum number of notes which can be created by a single transaction, as enforced by
+    /// the transaction kernel.
+    pub const MAX_OUTPUT_NOTES: usize = MAX_OUTPUT_NOTES_PER_TX;
+
     // KERNEL SOURCE CODE
     // --------------------------------------------------------------------------------------------
 
     /// Returns a library with the transaction kernel system procedures.
     ///
+    /// The library is assembled at build time and deserialized on first use only; subsequent
+    /// calls return a clone of the cached library, which shares its MAST forest with the cache.
+    ///
     /// # Panics
     /// Panics if the transaction kernel source is not well-formed.
     pub fn kernel() -> KernelLibrary {
-        // TODO: make this static
-        KernelLibrary::read_from_bytes(KERNEL_LIB_BYTES)
-            .expect("failed to deserialize transaction kernel library")
+        KERNEL_LIB.clone()
     }
 
     /// Returns an AST of the transaction kernel executable program.
     ///
+    /// Like [Self::kernel()], the program is deserialized on first use only.
+    ///
     /// # Panics
     /// Panics if the transaction kernel source is not well-formed.
     pub fn main() -> Program {
-        // TODO: make static
-        Program::read_from_bytes(KERNEL_MAIN_BYTES)
-            .expect("failed to deserialize transaction kernel runtime")
+        KERNEL_MAIN.clone()
     }
 
     /// Returns [ProgramInfo] for the transaction kernel executable program.
@@ -78,11 +116,7 @@ impl TransactionKernel {
     /// # Panics
     /// Panics if the transaction kernel source is not well-formed.
     pub fn program_info() -> ProgramInfo {
-        // TODO: make static
-        let program_hash = Self::main().hash();
-        let kernel = Self::kernel().kernel().clone();
-
-        ProgramInfo::new(program_hash, kernel)
+        KERNEL_PROGRAM_INFO.clone()
     }
 
     /// Transforms the provided [TransactionInputs] and [TransactionArgs] into stack and advice
@@ -166,6 +200,8 @@ impl TransactionKernel {
     ///
     /// ```text
     /// [
+    ///     FEE_ASSET,
+    ///     0, 0, 0,
     ///     expiration_block_num,
     ///     OUTPUT_NOTES_COMMITMENT,
     ///     FINAL_ACCOUNT_HASH,
@@ -176,12 +212,16 @@ impl TransactionKernel {
     /// - OUTPUT_NOTES_COMMITMENT is a commitment to the output notes.
     /// - FINAL_ACCOUNT_HASH is a hash of the account's final state.
     /// - expiration_block_num is the block number at which the transaction will expire.
+    /// - FEE_ASSET is the fee paid by the transaction, or [EMPTY_WORD] if no fee was paid.
     pub fn build_output_stack(
         final_acct_hash: Digest,
         output_notes_hash: Digest,
         expiration_block_num: u32,
+        fee: Option<FungibleAsset>,
     ) -> StackOutputs {
-        let mut outputs: Vec<Felt> = Vec::with_capacity(9);
+        let mut outputs: Vec<Felt> = Vec::with_capacity(16);
+        outputs.extend(fee.map_or(EMPTY_WORD, Word::from));
+        outputs.extend([ZERO; 3]);
         outputs.push(Felt::from(expiration_block_num));
         outputs.extend(final_acct_hash);
         outputs.extend(output_notes_hash);
@@ -195,7 +235,7 @@ impl TransactionKernel {
     ///
     /// The data on the stack is expected to be arranged as follows:
     ///
-    /// Stack: [CNC, FAH, tx_expiration_block_num]
+    /// Stack: [CNC, FAH, tx_expiration_block_num, 0, 0, 0, FEE_ASSET]
     ///
     /// Where:
     /// - CNC is the commitment to the notes created by the transaction.
@@ -204,14 +244,15 @@ impl TransactionKernel {
     /// - tx_expiration_block_num is the block height at which the transaction will become expired,
     ///   defined by the sum of the execution block ref and the transaction's block expiration delta
     ///   (if set during transaction execution).
+    /// - FEE_ASSET is the fee paid by the transaction, or [EMPTY_WORD] if no fee was paid.
     ///
     /// # Errors
     /// Returns an error if:
-    /// - Words 3 and 4 on the stack are not 0.
-    /// - Overflow addresses are not empty.
+    /// - The expiration block number is not a valid u32.
+    /// - The fee asset is neither [EMPTY_WORD] nor a valid fungible asset.
     pub fn parse_output_stack(
         stack: &StackOutputs,
-    ) -> Result<(Digest, Digest, u32), TransactionOutputError> {
+    ) -> Result<(Digest, Digest, u32, Option<FungibleAsset>), TransactionOutputError> {
         let output_notes_hash = stack
             .get_stack_word(OUTPUT_NOTES_COMMITMENT_WORD_IDX * 4)
             .expect("first word missing")
@@ -232,13 +273,17 @@ impl TransactionKernel {
             )
         })?;
 
-        if stack.get_stack_word(12).expect("fourth word missing") != EMPTY_WORD {
-            return Err(TransactionOutputError::OutputStackInvalid(
-                "Fourth word on output stack should consist only of ZEROs".into(),
-            ));
-        }
-
-        Ok((final_account_hash, output_notes_hash, expiration_block_num))
+        let fee_asset = stack.get_stack_word(FEE_ASSET_WORD_IDX * 4).expect("fourth word missing");
+        let fee = if fee_asset == EMPTY_WORD {
+            None
+        } else {
+            let fee = FungibleAsset::try_from(fee_asset).map_err(|err| {
+                TransactionOutputError::OutputStackInvalid(format!("Invalid fee asset: {err}"))
+            })?;
+            Some(fee)
+        };
+
+        Ok((final_account_hash, output_notes_hash, expiration_block_num, fee))
     }
 
     // TRANSACTION OUTPUT PARSER
@@ -248,7 +293,7 @@ impl TransactionKernel {
     ///
     /// The output stack is expected to be arrange as follows:
     ///
-    /// Stack: [CNC, FAH, tx_expiration_block_num]
+    /// Stack: [CNC, FAH, tx_expiration_block_num, 0, 0, 0, FEE_ASSET]
     ///
     /// Where:
     /// - CNC is the commitment to the notes created by the transaction.
@@ -257,6 +302,7 @@ impl TransactionKernel {
     /// - tx_expiration_block_num is the block height at which the transaction will become expired,
     ///   defined by the sum of the execution block ref and the transaction's block expiration delta
     ///   (if set during transaction execution).
+    /// - FEE_ASSET is the fee paid by the transaction, or [EMPTY_WORD] if no fee was paid.
     ///
     /// The actual data describing the new account state and output notes is expected to be located
     /// in the provided advice map under keys CNC and FAH.
@@ -265,7 +311,7 @@ impl TransactionKernel {
         adv_map: &AdviceMap,
         output_notes: Vec<OutputNote>,
     ) -> Result<TransactionOutputs, TransactionOutputError> {
-        let (final_acct_hash, output_notes_hash, expiration_block_num) =
+        let (final_acct_hash, output_notes_hash, expiration_block_num, fee) =
             Self::parse_output_stack(stack)?;
 
         // parse final account state
@@ -290,6 +336,7 @@ impl TransactionKernel {
             account,
             output_notes,
             expiration_block_num,
+            fee,
         })
     }
 }
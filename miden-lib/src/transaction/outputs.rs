@@ -20,6 +20,10 @@ pub const FINAL_ACCOUNT_HASH_WORD_IDX: usize = 1;
 /// The index of the item at which the expiration block height is stored on the output stack.
 pub const EXPIRATION_BLOCK_ELEMENT_IDX: usize = 8;
 
+/// The index of the word at which the fee asset paid by the transaction is stored on the output
+/// stack.
+pub const FEE_ASSET_WORD_IDX: usize = 3;
+
 // ACCOUNT HEADER EXTRACTOR
 // ================================================================================================
 
@@ -0,0 +1,503 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::Write;
+
+use miden_objects::{
+    accounts::{AccountId, AuthPublicKey},
+    assembly::Assembler,
+    assets::Asset,
+    crypto::rand::FeltRng,
+    notes::{Note, NoteMetadata, NoteType},
+    transaction::{TransactionArgs, TransactionScript},
+    vm::AdviceMap,
+    Digest, Felt, Word,
+};
+
+use super::TransactionScriptBuilderError;
+use crate::notes::create_p2id_note;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Path of the basic wallet procedure used to create output notes.
+pub const CREATE_NOTE_PROC: &str = "::miden::contracts::wallets::basic::create_note";
+
+/// Path of the basic wallet procedure used to move assets into output notes.
+pub const MOVE_ASSET_TO_NOTE_PROC: &str = "::miden::contracts::wallets::basic::move_asset_to_note";
+
+/// Path of the basic fungible faucet procedure used to mint assets into output notes.
+pub const DISTRIBUTE_PROC: &str = "::miden::contracts::faucets::basic_fungible::distribute";
+
+/// Path of the procedure authenticating transactions with the RPO Falcon 512 signature scheme.
+pub const AUTH_TX_RPO_FALCON512_PROC: &str =
+    "::miden::contracts::auth::basic::auth_tx_rpo_falcon512";
+
+/// Path of the procedure rotating the public key of the RPO Falcon 512 authentication component.
+pub const ROTATE_KEY_RPO_FALCON512_PROC: &str = "::miden::contracts::auth::basic::rotate_key";
+
+/// Path of the kernel procedure used to set the expiration delta of the transaction.
+pub const UPDATE_EXPIRATION_BLOCK_DELTA_PROC: &str = "::miden::tx::update_expiration_block_delta";
+
+// SCRIPT OPERATION
+// ================================================================================================
+
+/// An operation performed by a transaction script built with [TransactionScriptBuilder].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScriptOperation {
+    /// Creates an output note via [CREATE_NOTE_PROC] and moves the assets from the account vault
+    /// into it via [MOVE_ASSET_TO_NOTE_PROC].
+    CreateNote {
+        recipient: Digest,
+        metadata: NoteMetadata,
+        assets: Vec<Asset>,
+    },
+    /// Mints the specified amount of the faucet's fungible asset into a new output note via
+    /// [DISTRIBUTE_PROC].
+    Mint {
+        amount: u64,
+        recipient: Digest,
+        metadata: NoteMetadata,
+    },
+    /// Calls the account procedure with the specified path and arguments, with the first argument
+    /// on top of the stack.
+    CallProcedure { path: String, args: Vec<Felt> },
+    /// Sets the value of a storage slot via the account procedure with the specified path, which
+    /// is expected to take `[index, VALUE]` as inputs (as `miden::account::set_item` does).
+    SetStorageItem { path: String, index: u8, value: Word },
+}
+
+// TRANSACTION SCRIPT BUILDER
+// ================================================================================================
+
+/// Builds transaction scripts from a sequence of [ScriptOperation]s.
+///
+/// The builder takes care of encoding the arguments of the called account procedures onto the
+/// stack and of restoring the stack depth after every call. The authentication procedure, if set,
+/// is always called at the end of the script, after all other operations, since it increments the
+/// nonce of the account and signs over the created notes.
+///
+/// Notes created via [Self::send_note()] and [Self::send_p2id()] are recorded by the builder, so
+/// that their details can be added to the advice map of the transaction (see
+/// [Self::build_transaction_args()]).
+///
+/// If an expiration delta is set via [Self::with_expiration_delta()], the script sets it before
+/// performing any other operation, so that the transaction expires `delta` blocks after its
+/// reference block.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TransactionScriptBuilder {
+    operations: Vec<ScriptOperation>,
+    auth_procedure: Option<String>,
+    expiration_delta: Option<u16>,
+    output_notes: Vec<Note>,
+}
+
+impl TransactionScriptBuilder {
+    /// The maximum number of arguments which can be passed to an account procedure.
+    pub const MAX_PROCEDURE_ARGS: usize = 16;
+
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [TransactionScriptBuilder] without any operations.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // BUILDER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Adds an operation creating an output note with the specified recipient, metadata and assets.
+    ///
+    /// The account must export the procedures of the
+    /// [BasicWallet](crate::accounts::wallets::BasicWallet).
+    pub fn create_note(
+        mut self,
+        recipient: Digest,
+        metadata: NoteMetadata,
+        assets: impl IntoIterator<Item = Asset>,
+    ) -> Self {
+        self.operations.push(ScriptOperation::CreateNote {
+            recipient,
+            metadata,
+            assets: assets.into_iter().collect(),
+        });
+        self
+    }
+
+    /// Adds an operation creating the specified note and records the note as an output note of
+    /// the transaction.
+    pub fn send_note(self, note: Note) -> Self {
+        let mut builder = self.create_note(
+            note.recipient().digest(),
+            *note.metadata(),
+            note.assets().iter().copied(),
+        );
+        builder.output_notes.push(note);
+        builder
+    }
+
+    /// Adds an operation sending the specified assets from the `sender` account to the `target`
+    /// account via a P2ID note.
+    ///
+    /// The serial number of the note is drawn from the provided `rng`.
+    ///
+    /// # Errors
+    /// Returns an error if the P2ID note cannot be created.
+    pub fn send_p2id<R: FeltRng>(
+        self,
+        sender: AccountId,
+        target: AccountId,
+        assets: Vec<Asset>,
+        note_type: NoteType,
+        aux: Felt,
+        rng: &mut R,
+    ) -> Result<Self, TransactionScriptBuilderError> {
+        let note = create_p2id_note(sender, target, assets, note_type, aux, rng)
+            .map_err(TransactionScriptBuilderError::NoteCreationFailed)?;
+        Ok(self.send_note(note))
+    }
+
+    /// Adds an operation minting the single fungible asset of the specified note and records the
+    /// note as an output note of the transaction.
+    ///
+    /// The account must be a fungible faucet exporting the procedures of the
+    /// [BasicFungibleFaucet](crate::accounts::faucets::BasicFungibleFaucet).
+    ///
+    /// # Errors
+    /// Returns an error if the note does not contain exactly one fungible asset.
+    pub fn mint(self, note: Note) -> Result<Self, TransactionScriptBuilderError> {
+        let amount = match note.assets().iter().collect::<Vec<_>>().as_slice() {
+            [Asset::Fungible(asset)] => asset.amount(),
+            _ => return Err(TransactionScriptBuilderError::InvalidMintNote(note.id())),
+        };
+
+        let mut builder = self.mint_to(amount, note.recipient().digest(), *note.metadata());
+        builder.output_notes.push(note);
+        Ok(builder)
+    }
+
+    /// Adds an operation minting the specified amount of the faucet's fungible asset into a new
+    /// output note with the specified recipient and metadata.
+    ///
+    /// The account must be a fungible faucet exporting the procedures of the
+    /// [BasicFungibleFaucet](crate::accounts::faucets::BasicFungibleFaucet).
+    pub fn mint_to(mut self, amount: u64, recipient: Digest, metadata: NoteMetadata) -> Self {
+        self.operations.push(ScriptOperation::Mint { amount, recipient, metadata });
+        self
+    }
+
+    /// Adds an operation calling the account procedure with the specified fully-qualified path
+    /// (e.g., `::miden::contracts::wallets::basic::receive_asset`) and arguments.
+    ///
+    /// The arguments are placed onto the stack right before the procedure is called, such that the
+    /// first argument ends up on top of the stack. Any values returned by the procedure are
+    /// dropped.
+    ///
+    /// # Errors
+    /// Returns an error if more than [Self::MAX_PROCEDURE_ARGS] arguments are provided.
+    pub fn call_procedure(
+        mut self,
+        path: impl Into<String>,
+        args: impl IntoIterator<Item = Felt>,
+    ) -> Result<Self, TransactionScriptBuilderError> {
+        let args: Vec<Felt> = args.into_iter().collect();
+        if args.len() > Self::MAX_PROCEDURE_ARGS {
+            return Err(TransactionScriptBuilderError::TooManyProcedureArguments(args.len()));
+        }
+
+        self.operations.push(ScriptOperation::CallProcedure { path: path.into(), args });
+        Ok(self)
+    }
+
+    /// Adds an operation setting the storage slot with the specified index to `value` via the
+    /// account procedure with the specified fully-qualified path.
+    pub fn set_storage_item(mut self, path: impl Into<String>, index: u8, value: Word) -> Self {
+        self.operations
+            .push(ScriptOperation::SetStorageItem { path: path.into(), index, value });
+        self
+    }
+
+    /// Adds an operation rotating the public key of the account to `new_public_key` via
+    /// [ROTATE_KEY_RPO_FALCON512_PROC].
+    ///
+    /// The account must export the procedures of the
+    /// [RpoFalcon512](crate::accounts::auth::RpoFalcon512) component. The rotation is authorized
+    /// by a signature of the current key, while the transaction itself is authenticated at the
+    /// end of the script with the new key. Thus, the authenticator of the transaction must hold
+    /// both secret keys.
+    pub fn rotate_key(mut self, new_public_key: AuthPublicKey) -> Self {
+        let key = Word::from(new_public_key);
+        self.operations.push(ScriptOperation::CallProcedure {
+            path: ROTATE_KEY_RPO_FALCON512_PROC.to_string(),
+            args: key.iter().rev().copied().collect(),
+        });
+        self
+    }
+
+    /// Sets the account procedure used to authenticate the transaction.
+    ///
+    /// The procedure is called at the end of the script, after all other operations.
+    pub fn with_auth_procedure(mut self, path: impl Into<String>) -> Self {
+        self.auth_procedure = Some(path.into());
+        self
+    }
+
+    /// Authenticates the transaction with [AUTH_TX_RPO_FALCON512_PROC].
+    pub fn with_rpo_falcon512_auth(self) -> Self {
+        self.with_auth_procedure(AUTH_TX_RPO_FALCON512_PROC)
+    }
+
+    /// Sets the expiration delta of the transaction via [UPDATE_EXPIRATION_BLOCK_DELTA_PROC], such
+    /// that the transaction expires `delta` blocks after its reference block.
+    ///
+    /// # Errors
+    /// Returns an error if the delta is 0.
+    pub fn with_expiration_delta(
+        mut self,
+        delta: u16,
+    ) -> Result<Self, TransactionScriptBuilderError> {
+        if delta == 0 {
+            return Err(TransactionScriptBuilderError::InvalidExpirationDelta(delta));
+        }
+
+        self.expiration_delta = Some(delta);
+        Ok(self)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the operations of the script in the order in which they are executed.
+    pub fn operations(&self) -> &[ScriptOperation] {
+        &self.operations
+    }
+
+    /// Returns the path of the authentication procedure, if set.
+    pub fn auth_procedure(&self) -> Option<&str> {
+        self.auth_procedure.as_deref()
+    }
+
+    /// Returns the expiration delta of the transaction, if set.
+    pub fn expiration_delta(&self) -> Option<u16> {
+        self.expiration_delta
+    }
+
+    /// Returns the notes recorded as output notes of the transaction.
+    pub fn output_notes(&self) -> &[Note] {
+        &self.output_notes
+    }
+
+    /// Returns true if the script neither performs any operation, nor authenticates the
+    /// transaction, nor sets its expiration delta.
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+            && self.auth_procedure.is_none()
+            && self.expiration_delta.is_none()
+    }
+
+    // BUILDING
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the MASM source code of the transaction script.
+    pub fn source(&self) -> String {
+        let mut source = String::from("begin\n");
+
+        if let Some(delta) = self.expiration_delta {
+            writeln!(source, "    push.{delta} exec.{UPDATE_EXPIRATION_BLOCK_DELTA_PROC}")
+                .expect("writing to a string should not fail");
+        }
+
+        for operation in self.operations.iter() {
+            match operation {
+                ScriptOperation::CreateNote { recipient, metadata, assets } => {
+                    // create the note; the stack is padded so that the call does not modify the
+                    // deeper stack elements
+                    writeln!(
+                        source,
+                        "    padw padw push.{} push.{} push.{} push.{} push.{}",
+                        format_word(&(*recipient).into()),
+                        Felt::from(metadata.execution_hint()),
+                        metadata.note_type() as u8,
+                        metadata.aux(),
+                        metadata.tag(),
+                    )
+                    .expect("writing to a string should not fail");
+                    writeln!(source, "    call.{CREATE_NOTE_PROC}")
+                        .expect("writing to a string should not fail");
+                    // => [note_idx, PAD(15)]
+
+                    for asset in assets.iter() {
+                        writeln!(
+                            source,
+                            "    push.{} call.{MOVE_ASSET_TO_NOTE_PROC} dropw",
+                            format_word(&Word::from(*asset))
+                        )
+                        .expect("writing to a string should not fail");
+                    }
+                    source.push_str("    dropw dropw dropw dropw\n");
+                },
+                ScriptOperation::Mint { amount, recipient, metadata } => {
+                    writeln!(
+                        source,
+                        "    push.{} push.{} push.{} push.{} push.{} push.{amount}",
+                        format_word(&(*recipient).into()),
+                        Felt::from(metadata.execution_hint()),
+                        metadata.note_type() as u8,
+                        metadata.aux(),
+                        metadata.tag(),
+                    )
+                    .expect("writing to a string should not fail");
+                    // => [amount, tag, aux, note_type, execution_hint, RECIPIENT]
+
+                    writeln!(source, "    call.{DISTRIBUTE_PROC} dropw dropw drop")
+                        .expect("writing to a string should not fail");
+                },
+                ScriptOperation::CallProcedure { path, args } => {
+                    write_procedure_call(&mut source, path, args);
+                },
+                ScriptOperation::SetStorageItem { path, index, value } => {
+                    let mut args = vec![Felt::from(*index)];
+                    args.extend(value.iter().rev());
+                    write_procedure_call(&mut source, path, &args);
+                },
+            }
+        }
+
+        if let Some(auth_procedure) = &self.auth_procedure {
+            writeln!(source, "    call.{auth_procedure}")
+                .expect("writing to a string should not fail");
+        }
+
+        source.push_str("end\n");
+        source
+    }
+
+    /// Compiles the transaction script using the provided assembler.
+    ///
+    /// # Errors
+    /// Returns an error if the script fails to compile, e.g., because a called procedure is not
+    /// available to the assembler.
+    pub fn build(
+        &self,
+        assembler: Assembler,
+    ) -> Result<TransactionScript, TransactionScriptBuilderError> {
+        TransactionScript::compile(self.source(), [], assembler)
+            .map_err(TransactionScriptBuilderError::ScriptCompilationFailed)
+    }
+
+    /// Compiles the transaction script using the provided assembler into [TransactionArgs], with
+    /// the recorded output notes added as expected output notes.
+    ///
+    /// # Errors
+    /// Returns an error if the script fails to compile.
+    pub fn build_transaction_args(
+        &self,
+        assembler: Assembler,
+    ) -> Result<TransactionArgs, TransactionScriptBuilderError> {
+        let tx_script = self.build(assembler)?;
+        let mut tx_args = TransactionArgs::new(Some(tx_script), None, AdviceMap::default());
+        for note in self.output_notes.iter() {
+            tx_args.add_expected_output_note(note);
+        }
+
+        Ok(tx_args)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Writes a call of the procedure with the specified path into `source`, placing the arguments
+/// onto the stack before the call and dropping them after it.
+fn write_procedure_call(source: &mut String, path: &str, args: &[Felt]) {
+    source.push_str("   ");
+    if !args.is_empty() {
+        let args: Vec<String> = args.iter().rev().map(|arg| arg.as_int().to_string()).collect();
+        write!(source, " push.{}", args.join(".")).expect("writing to a string should not fail");
+    }
+    write!(source, " call.{path}").expect("writing to a string should not fail");
+    // the depth of the stack is not changed by the call, so only the arguments are dropped
+    source.push_str(&" dropw".repeat(args.len() / 4));
+    source.push_str(&" drop".repeat(args.len() % 4));
+    source.push('\n');
+}
+
+/// Formats the provided word as an argument of a `push` instruction.
+fn format_word(word: &Word) -> String {
+    word.iter()
+        .map(|element| element.as_int().to_string())
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use miden_objects::{
+        accounts::account_id::testing::ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN, ONE,
+    };
+
+    use super::*;
+
+    #[test]
+    fn procedure_calls_restore_stack_depth() {
+        let builder = TransactionScriptBuilder::new()
+            .call_procedure("::foo::bar", [ONE; 5])
+            .unwrap()
+            .set_storage_item(
+                "::foo::set_item",
+                3,
+                [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)],
+            )
+            .with_rpo_falcon512_auth();
+
+        assert_eq!(
+            builder.source(),
+            format!(
+                "begin\n    push.1.1.1.1.1 call.::foo::bar dropw drop\n    push.1.2.3.4.3 call.::foo::set_item dropw drop\n    call.{AUTH_TX_RPO_FALCON512_PROC}\nend\n"
+            )
+        );
+
+        let err = TransactionScriptBuilder::new()
+            .call_procedure("::foo::bar", [ONE; TransactionScriptBuilder::MAX_PROCEDURE_ARGS + 1])
+            .unwrap_err();
+        assert_eq!(err, TransactionScriptBuilderError::TooManyProcedureArguments(17));
+    }
+
+    #[test]
+    fn expiration_delta_is_set_first() {
+        let builder = TransactionScriptBuilder::new()
+            .call_procedure("::foo::bar", [])
+            .unwrap()
+            .with_expiration_delta(10)
+            .unwrap();
+
+        assert!(!TransactionScriptBuilder::new().with_expiration_delta(10).unwrap().is_empty());
+        assert_eq!(
+            builder.source(),
+            format!(
+                "begin\n    push.10 exec.{UPDATE_EXPIRATION_BLOCK_DELTA_PROC}\n    call.::foo::bar\nend\n"
+            )
+        );
+
+        let err = TransactionScriptBuilder::new().with_expiration_delta(0).unwrap_err();
+        assert_eq!(err, TransactionScriptBuilderError::InvalidExpirationDelta(0));
+    }
+
+    #[test]
+    fn mint_requires_single_fungible_asset() {
+        let account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+        let mut rng = miden_objects::crypto::rand::RpoRandomCoin::new(Default::default());
+        let note =
+            create_p2id_note(account_id, account_id, vec![], NoteType::Public, ONE, &mut rng)
+                .unwrap();
+
+        let err = TransactionScriptBuilder::new().mint(note.clone()).unwrap_err();
+        assert_eq!(err, TransactionScriptBuilderError::InvalidMintNote(note.id()));
+    }
+}
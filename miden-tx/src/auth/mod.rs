@@ -1,4 +1,6 @@
 mod tx_authenticator;
-pub use tx_authenticator::{BasicAuthenticator, TransactionAuthenticator};
+pub use tx_authenticator::{
+    BasicAuthenticator, MultiAuthenticator, SignatureRequest, TransactionAuthenticator,
+};
 
 pub mod signatures;
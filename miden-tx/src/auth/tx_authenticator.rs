@@ -1,7 +1,11 @@
 use alloc::{collections::BTreeMap, string::ToString, sync::Arc, vec::Vec};
 
 use miden_lib::utils::sync::RwLock;
-use miden_objects::accounts::{AccountDelta, AuthSecretKey};
+use miden_objects::{
+    accounts::{AccountDelta, AccountId, AuthSecretKey},
+    crypto::rand::RpoRandomCoin,
+    Hasher,
+};
 use rand::Rng;
 use vm_processor::{Digest, Felt, Word};
 
@@ -18,6 +22,13 @@ use crate::errors::AuthenticationError;
 /// private key pairs, and can be requested to generate signatures against any of the managed keys.
 ///
 /// The public keys are defined by [Digest]'s which are the hashes of the actual public keys.
+///
+/// The trait is object-safe, so that the transaction host can hold any authenticator behind an
+/// `Arc<dyn TransactionAuthenticator>`. This allows backing the authenticator by in-memory keys
+/// (see [BasicAuthenticator]), by several other authenticators (see [MultiAuthenticator]), or by
+/// a hardware or remote signer. Since signatures are requested synchronously during transaction
+/// execution, authenticators backed by asynchronous signers need to block on the signer, or the
+/// signatures need to be provided in advance via the advice map of the transaction arguments.
 pub trait TransactionAuthenticator {
     /// Retrieves a signature for a specific message as a list of [Felt].
     ///
@@ -36,6 +47,118 @@ pub trait TransactionAuthenticator {
         message: Word,
         account_delta: &AccountDelta,
     ) -> Result<Vec<Felt>, AuthenticationError>;
+
+    /// Retrieves a signature for the provided [SignatureRequest] as a list of [Felt].
+    ///
+    /// This is the method invoked by the transaction host. In addition to the parameters of
+    /// [Self::get_signature()], the request describes the account and the account procedure which
+    /// requested the signature. Authenticators enforcing signing policies can override this method
+    /// to veto a request by returning [AuthenticationError::RejectedSignature].
+    ///
+    /// The default implementation ignores the context of the request and delegates to
+    /// [Self::get_signature()].
+    fn sign_request(&self, request: &SignatureRequest) -> Result<Vec<Felt>, AuthenticationError> {
+        self.get_signature(request.pub_key(), request.message(), request.account_delta())
+    }
+}
+
+// SIGNATURE REQUEST
+// ================================================================================================
+
+/// A request for a signature issued by the transaction host during transaction execution.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignatureRequest {
+    pub_key: Word,
+    message: Word,
+    account_id: AccountId,
+    procedure_root: Option<Digest>,
+    account_delta: AccountDelta,
+    seed: Option<Word>,
+    timestamp: Option<u32>,
+}
+
+impl SignatureRequest {
+    /// Returns a new [SignatureRequest] instantiated from the provided data.
+    pub fn new(
+        pub_key: Word,
+        message: Word,
+        account_id: AccountId,
+        procedure_root: Option<Digest>,
+        account_delta: AccountDelta,
+    ) -> Self {
+        Self {
+            pub_key,
+            message,
+            account_id,
+            procedure_root,
+            account_delta,
+            seed: None,
+            timestamp: None,
+        }
+    }
+
+    /// Sets the seed from which the authenticator should derive the randomness used to generate
+    /// the signature.
+    ///
+    /// The transaction host sets the seed when the transaction is executed with a
+    /// [TransactionRng](crate::TransactionRng), so that signatures are reproducible.
+    pub fn with_seed(mut self, seed: Word) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Sets the current time of the transaction requesting the signature.
+    pub fn with_timestamp(mut self, timestamp: u32) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+
+    /// Returns the public key against which the signature is requested.
+    pub fn pub_key(&self) -> Word {
+        self.pub_key
+    }
+
+    /// Returns the message to sign.
+    pub fn message(&self) -> Word {
+        self.message
+    }
+
+    /// Returns the ID of the account executing the transaction.
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the MAST root of the account procedure which last invoked the transaction kernel
+    /// before the signature was requested, if any.
+    ///
+    /// For the standard authentication procedures, this is the root of the authentication
+    /// procedure itself, since it reads the public key from the account storage before requesting
+    /// the signature.
+    pub fn procedure_root(&self) -> Option<Digest> {
+        self.procedure_root
+    }
+
+    /// Returns the changes made to the account up to the point of requesting the signature.
+    pub fn account_delta(&self) -> &AccountDelta {
+        &self.account_delta
+    }
+
+    /// Returns the seed from which the randomness used to generate the signature should be
+    /// derived, if any.
+    ///
+    /// If the seed is set, authenticators should generate signatures deterministically from it.
+    pub fn seed(&self) -> Option<Word> {
+        self.seed
+    }
+
+    /// Returns the current time (as a UNIX timestamp in seconds) of the transaction requesting
+    /// the signature, if known.
+    ///
+    /// The time is derived from the reference block of the transaction via the
+    /// [TransactionClock](crate::TransactionClock) of the transaction executor.
+    pub fn timestamp(&self) -> Option<u32> {
+        self.timestamp
+    }
 }
 
 // BASIC AUTHENTICATOR
@@ -69,6 +192,30 @@ impl<R: Rng> BasicAuthenticator<R> {
             rng: Arc::new(RwLock::new(rng)),
         }
     }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Signs the message with the secret key corresponding to the provided public key using the
+    /// provided RNG.
+    fn sign<G: Rng>(
+        &self,
+        pub_key: Word,
+        message: Word,
+        rng: &mut G,
+    ) -> Result<Vec<Felt>, AuthenticationError> {
+        match self.keys.get(&pub_key.into()) {
+            Some(key) => match key {
+                AuthSecretKey::RpoFalcon512(falcon_key) => {
+                    get_falcon_signature(falcon_key, message, rng)
+                },
+            },
+            None => Err(AuthenticationError::UnknownKey(format!(
+                "Public key {} is not contained in the authenticator's keys",
+                Digest::from(pub_key)
+            ))),
+        }
+    }
 }
 
 impl<R: Rng> TransactionAuthenticator for BasicAuthenticator<R> {
@@ -89,21 +236,97 @@ impl<R: Rng> TransactionAuthenticator for BasicAuthenticator<R> {
     ) -> Result<Vec<Felt>, AuthenticationError> {
         let _ = account_delta;
         let mut rng = self.rng.write();
+        self.sign(pub_key, message, &mut *rng)
+    }
 
-        match self.keys.get(&pub_key.into()) {
-            Some(key) => match key {
-                AuthSecretKey::RpoFalcon512(falcon_key) => {
-                    get_falcon_signature(falcon_key, message, &mut *rng)
-                },
+    /// Gets a signature for the provided [SignatureRequest].
+    ///
+    /// If the request carries a seed, the signature is generated using randomness derived from
+    /// this seed and the message instead of the authenticator's RNG, and thus is reproducible.
+    /// Binding the randomness to the message ensures that requests which reuse a seed for
+    /// distinct messages are not signed with the same randomness.
+    fn sign_request(&self, request: &SignatureRequest) -> Result<Vec<Felt>, AuthenticationError> {
+        match request.seed() {
+            Some(seed) => {
+                let seed = Hasher::merge(&[seed.into(), request.message().into()]);
+                let mut rng = RpoRandomCoin::new(seed.into());
+                self.sign(request.pub_key(), request.message(), &mut rng)
+            },
+            None => {
+                self.get_signature(request.pub_key(), request.message(), request.account_delta())
             },
-            None => Err(AuthenticationError::UnknownKey(format!(
-                "Public key {} is not contained in the authenticator's keys",
-                Digest::from(pub_key)
-            ))),
         }
     }
 }
 
+// MULTI AUTHENTICATOR
+// ================================================================================================
+
+/// An authenticator which delegates signature requests to a list of authenticators.
+///
+/// Requests are forwarded to the authenticators in order, until one of them does not fail with
+/// [AuthenticationError::UnknownKey]. This allows combining keys managed by different backends
+/// (e.g., in-memory keys and a hardware signer) into a single authenticator.
+#[derive(Clone, Default)]
+pub struct MultiAuthenticator {
+    authenticators: Vec<Arc<dyn TransactionAuthenticator>>,
+}
+
+impl MultiAuthenticator {
+    /// Returns a new [MultiAuthenticator] delegating to the provided authenticators.
+    pub fn new(authenticators: Vec<Arc<dyn TransactionAuthenticator>>) -> Self {
+        Self { authenticators }
+    }
+
+    /// Appends the provided authenticator to the list of authenticators.
+    pub fn with_authenticator(mut self, authenticator: Arc<dyn TransactionAuthenticator>) -> Self {
+        self.authenticators.push(authenticator);
+        self
+    }
+
+    /// Returns the number of authenticators this authenticator delegates to.
+    pub fn num_authenticators(&self) -> usize {
+        self.authenticators.len()
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the result of the first authenticator which knows the requested key.
+    fn first_known_key(
+        &self,
+        sign: impl Fn(&dyn TransactionAuthenticator) -> Result<Vec<Felt>, AuthenticationError>,
+    ) -> Result<Vec<Felt>, AuthenticationError> {
+        for authenticator in self.authenticators.iter() {
+            match sign(authenticator.as_ref()) {
+                Err(AuthenticationError::UnknownKey(_)) => continue,
+                result => return result,
+            }
+        }
+
+        Err(AuthenticationError::UnknownKey(
+            "Public key is not known to any of the authenticators".to_string(),
+        ))
+    }
+}
+
+impl TransactionAuthenticator for MultiAuthenticator {
+    fn get_signature(
+        &self,
+        pub_key: Word,
+        message: Word,
+        account_delta: &AccountDelta,
+    ) -> Result<Vec<Felt>, AuthenticationError> {
+        self.first_known_key(|authenticator| {
+            authenticator.get_signature(pub_key, message, account_delta)
+        })
+    }
+
+    fn sign_request(&self, request: &SignatureRequest) -> Result<Vec<Felt>, AuthenticationError> {
+        self.first_known_key(|authenticator| authenticator.sign_request(request))
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -123,7 +346,126 @@ impl TransactionAuthenticator for () {
 #[cfg(test)]
 mod test {
     use miden_lib::utils::{Deserializable, Serializable};
-    use miden_objects::{accounts::AuthSecretKey, crypto::dsa::rpo_falcon512::SecretKey};
+    use miden_objects::{
+        accounts::{
+            account_id::testing::ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN, AuthSecretKey,
+        },
+        crypto::dsa::rpo_falcon512::SecretKey,
+    };
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    use super::*;
+
+    /// An authenticator which rejects all requests not issued by the allowed procedure.
+    struct ProcedurePolicy {
+        allowed_procedure: Digest,
+        inner: Arc<dyn TransactionAuthenticator>,
+    }
+
+    impl TransactionAuthenticator for ProcedurePolicy {
+        fn get_signature(
+            &self,
+            pub_key: Word,
+            message: Word,
+            account_delta: &AccountDelta,
+        ) -> Result<Vec<Felt>, AuthenticationError> {
+            self.inner.get_signature(pub_key, message, account_delta)
+        }
+
+        fn sign_request(
+            &self,
+            request: &SignatureRequest,
+        ) -> Result<Vec<Felt>, AuthenticationError> {
+            if request.procedure_root() != Some(self.allowed_procedure) {
+                return Err(AuthenticationError::RejectedSignature(
+                    "procedure is not allowed to request signatures".to_string(),
+                ));
+            }
+            self.inner.sign_request(request)
+        }
+    }
+
+    fn authenticator(secret_key: &SecretKey) -> Arc<dyn TransactionAuthenticator> {
+        let pub_key: Word = secret_key.public_key().into();
+        Arc::new(BasicAuthenticator::<ChaCha20Rng>::new_with_rng(
+            &[(pub_key, AuthSecretKey::RpoFalcon512(secret_key.clone()))],
+            ChaCha20Rng::from_seed([0; 32]),
+        ))
+    }
+
+    #[test]
+    fn multi_authenticator_and_policies() {
+        let key_1 = SecretKey::new();
+        let key_2 = SecretKey::new();
+        let allowed_procedure = Digest::from([Felt::new(1); 4]);
+
+        let multi = MultiAuthenticator::new(vec![authenticator(&key_1)]).with_authenticator(
+            Arc::new(ProcedurePolicy {
+                allowed_procedure,
+                inner: authenticator(&key_2),
+            }),
+        );
+        assert_eq!(multi.num_authenticators(), 2);
+
+        let account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let request = |pub_key: Word, procedure_root| {
+            SignatureRequest::new(
+                pub_key,
+                [Felt::new(7); 4],
+                account_id,
+                procedure_root,
+                AccountDelta::default(),
+            )
+        };
+
+        // the key of the first authenticator can be used from any procedure
+        multi.sign_request(&request(key_1.public_key().into(), None)).unwrap();
+
+        // the key of the second authenticator can only be used from the allowed procedure
+        multi
+            .sign_request(&request(key_2.public_key().into(), Some(allowed_procedure)))
+            .unwrap();
+        assert!(matches!(
+            multi.sign_request(&request(key_2.public_key().into(), None)),
+            Err(AuthenticationError::RejectedSignature(_))
+        ));
+
+        // unknown keys are reported as such
+        let key_3 = SecretKey::new();
+        assert!(matches!(
+            multi.sign_request(&request(key_3.public_key().into(), None)),
+            Err(AuthenticationError::UnknownKey(_))
+        ));
+    }
+
+    #[test]
+    fn seeded_signature_requests_are_reproducible() {
+        let secret_key = SecretKey::new();
+        let pub_key: Word = secret_key.public_key().into();
+        let keys = [(pub_key, AuthSecretKey::RpoFalcon512(secret_key))];
+        let auth_1 = BasicAuthenticator::new_with_rng(&keys, ChaCha20Rng::from_seed([1; 32]));
+        let auth_2 = BasicAuthenticator::new_with_rng(&keys, ChaCha20Rng::from_seed([2; 32]));
+
+        let account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let request = SignatureRequest::new(
+            pub_key,
+            [Felt::new(7); 4],
+            account_id,
+            None,
+            AccountDelta::default(),
+        );
+
+        // without a seed, the signatures depend on the randomness of the authenticators
+        assert_ne!(auth_1.sign_request(&request).unwrap(), auth_2.sign_request(&request).unwrap());
+
+        // with a seed, the signatures are the same regardless of the authenticator's RNG
+        let request = request.with_seed([Felt::new(42); 4]).with_timestamp(1_700_000_000);
+        assert_eq!(request.timestamp(), Some(1_700_000_000));
+        assert_eq!(auth_1.sign_request(&request).unwrap(), auth_2.sign_request(&request).unwrap());
+    }
 
     #[test]
     fn serialize_auth_key() {
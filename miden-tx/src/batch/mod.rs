@@ -0,0 +1,41 @@
+use miden_objects::batches::{ProposedBatch, ProvenBatch};
+
+use super::{BatchProverError, TransactionVerifier};
+
+// LOCAL BATCH PROVER
+// ================================================================================================
+
+/// Local batch prover is a stateless component which is responsible for proving batches of
+/// transactions.
+///
+/// The prover verifies the proofs of all transactions in a [ProposedBatch] and produces a
+/// [ProvenBatch] describing the batch-level state changes of the transactions.
+///
+/// The prover does not produce an aggregated batch proof yet; instead, the proven batch keeps the
+/// proven transactions, so that consumers of the batch can verify their proofs (see [ProvenBatch]).
+pub struct LocalBatchProver {
+    tx_verifier: TransactionVerifier,
+}
+
+impl LocalBatchProver {
+    /// Creates a new [LocalBatchProver] instance which requires the proofs of the transactions to
+    /// have at least the specified security level.
+    pub fn new(proof_security_level: u32) -> Self {
+        Self {
+            tx_verifier: TransactionVerifier::new(proof_security_level),
+        }
+    }
+
+    /// Proves the provided batch and returns a [ProvenBatch].
+    ///
+    /// # Errors
+    /// Returns an error if the proof of any transaction in the batch cannot be verified.
+    pub fn prove(&self, batch: ProposedBatch) -> Result<ProvenBatch, BatchProverError> {
+        let results = self.tx_verifier.verify_batch(batch.transactions());
+        for (tx, result) in batch.transactions().iter().zip(results) {
+            result.map_err(|err| BatchProverError::TransactionVerificationFailed(tx.id(), err))?;
+        }
+
+        Ok(ProvenBatch::new(batch))
+    }
+}
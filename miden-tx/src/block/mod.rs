@@ -0,0 +1,215 @@
+use alloc::collections::BTreeMap;
+
+use miden_lib::transaction::TransactionKernel;
+use miden_objects::{
+    block::{AccountTree, Block, NullifierTree, ProposedBlock},
+    crypto::merkle::Mmr,
+    notes::{NoteHeader, NoteId, NoteMetadata},
+    BlockHeader, Digest, NullifierTreeError,
+};
+
+use super::BlockProverError;
+
+// LOCAL BLOCK PROVER
+// ================================================================================================
+
+/// Local block prover is responsible for building blocks from [ProposedBlock]s against the state
+/// of the chain.
+///
+/// The prover keeps track of the chain state, i.e., the chain MMR, the account tree and the
+/// nullifier tree, as well as of the header of the last block. Proving a block checks the proposed
+/// block against the chain state, computes the header of the new block and applies the block to
+/// the chain state.
+///
+/// Unauthenticated input notes of the proposed block which were not created within the block are
+/// authenticated against the notes created in the blocks proven by this prover, and against the
+/// notes provided via [LocalBlockProver::with_notes()].
+///
+/// TODO: produce a block proof once the block kernel is available. Until then, the proof hash of
+/// the block header is set to the default digest, which makes this prover suitable for testing
+/// only.
+#[derive(Debug, Clone)]
+pub struct LocalBlockProver {
+    chain: Mmr,
+    accounts: AccountTree,
+    nullifiers: NullifierTree,
+    notes: BTreeMap<NoteId, NoteMetadata>,
+    last_header: Option<BlockHeader>,
+}
+
+impl LocalBlockProver {
+    /// Creates a new [LocalBlockProver] for an empty chain. The first block proven by the prover
+    /// is the genesis block.
+    pub fn new() -> Self {
+        Self {
+            chain: Mmr::default(),
+            accounts: AccountTree::new(),
+            nullifiers: NullifierTree::new(),
+            notes: BTreeMap::new(),
+            last_header: None,
+        }
+    }
+
+    /// Creates a new [LocalBlockProver] for the chain with the provided state.
+    ///
+    /// The last block header must be the header of the last block added to the chain MMR. Notes
+    /// created in the chain can be provided via [LocalBlockProver::with_notes()] so that they can
+    /// be consumed as unauthenticated notes.
+    pub fn with_state(
+        chain: Mmr,
+        accounts: AccountTree,
+        nullifiers: NullifierTree,
+        last_header: BlockHeader,
+    ) -> Self {
+        Self {
+            chain,
+            accounts,
+            nullifiers,
+            notes: BTreeMap::new(),
+            last_header: Some(last_header),
+        }
+    }
+
+    /// Adds the provided notes to the notes created in the chain, against which unauthenticated
+    /// input notes are authenticated.
+    pub fn with_notes(mut self, notes: impl IntoIterator<Item = NoteHeader>) -> Self {
+        self.notes.extend(notes.into_iter().map(|note| (note.id(), *note.metadata())));
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the MMR of all blocks of the chain.
+    pub fn chain(&self) -> &Mmr {
+        &self.chain
+    }
+
+    /// Returns the tree containing the latest state hash of each account.
+    pub fn accounts(&self) -> &AccountTree {
+        &self.accounts
+    }
+
+    /// Returns the tree containing the nullifiers of all consumed notes.
+    pub fn nullifiers(&self) -> &NullifierTree {
+        &self.nullifiers
+    }
+
+    /// Returns the header of the last block of the chain, or `None` if the chain is empty.
+    pub fn last_header(&self) -> Option<&BlockHeader> {
+        self.last_header.as_ref()
+    }
+
+    // PROVER
+    // --------------------------------------------------------------------------------------------
+
+    /// Proves the provided block against the current chain state, applies it to the chain state
+    /// and returns the resulting [Block].
+    ///
+    /// The chain state is updated only if the block is proven successfully, i.e., if an error is
+    /// returned, the chain state is left unchanged.
+    ///
+    /// No block proof is produced yet: the proof hash of the returned block header is a
+    /// placeholder set to [Digest::default()].
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - A batch of the block expires at or before the number of the new block.
+    /// - The initial state of an updated account does not match the account state in the chain.
+    ///   Accounts which are not yet in the chain must be new accounts, i.e., their initial state
+    ///   hash must be [Digest::default()].
+    /// - An unauthenticated input note which is not created within the block was not created in
+    ///   the chain either.
+    /// - A note consumed by the block has already been consumed.
+    /// - The resulting block is invalid.
+    pub fn prove(
+        &mut self,
+        block: ProposedBlock,
+        timestamp: u32,
+    ) -> Result<Block, BlockProverError> {
+        let block_num = self.last_header.map_or(0, |header| header.block_num() + 1);
+        let prev_hash = self.last_header.map_or(Digest::default(), |header| header.hash());
+
+        // validate the block against the chain state
+        if block.expiration_block_num() <= block_num {
+            return Err(BlockProverError::ExpiredBlock {
+                expiration_block_num: block.expiration_block_num(),
+                block_num,
+            });
+        }
+
+        // accounts which are not in the chain yet are expected to be new accounts
+        for update in block.account_updates() {
+            let current_state = self.accounts.get(update.account_id()).unwrap_or_default();
+            if current_state != update.init_state_hash() {
+                return Err(BlockProverError::InconsistentAccountState {
+                    account_id: update.account_id(),
+                    expected: current_state,
+                    actual: update.init_state_hash(),
+                });
+            }
+        }
+
+        for note in block.unauthenticated_notes() {
+            if self.notes.get(&note.id()) != Some(note.metadata()) {
+                return Err(BlockProverError::UnauthenticatedNoteNotFound(note.id()));
+            }
+        }
+
+        let account_mutations = self
+            .accounts
+            .compute_mutations(
+                block
+                    .account_updates()
+                    .map(|update| (update.account_id(), update.final_state_hash())),
+            )
+            .map_err(BlockProverError::InvalidAccountUpdates)?;
+
+        // apply the nullifiers to a copy of the nullifier tree, so that the chain state is left
+        // unchanged if the block turns out to be invalid
+        let mut nullifiers = self.nullifiers.clone();
+        nullifiers
+            .insert_batch(block.nullifiers().iter().copied(), block_num)
+            .map_err(|err| match err {
+                NullifierTreeError::NullifierAlreadySpent { nullifier, .. } => {
+                    BlockProverError::NullifierAlreadySpent(nullifier)
+                },
+                err => BlockProverError::InvalidNullifiers(err),
+            })?;
+
+        // TODO: Set `proof_hash` to the hash of the block proof once the block kernel is available;
+        // until then, the default digest is used as a placeholder.
+        let header = BlockHeader::new(
+            0,
+            prev_hash,
+            block_num,
+            self.chain.peaks().hash_peaks(),
+            account_mutations.root(),
+            nullifiers.root(),
+            block.build_note_tree().root(),
+            block.compute_tx_hash(),
+            TransactionKernel::kernel_root(),
+            Digest::default(),
+            timestamp,
+        );
+
+        let block = block.into_block(header).map_err(BlockProverError::InvalidBlock)?;
+
+        // the block is valid, apply it to the chain state
+        self.accounts
+            .apply_mutations(account_mutations)
+            .expect("mutations were computed against the current account tree");
+        self.nullifiers = nullifiers;
+        self.notes.extend(block.notes().map(|(_, note)| (note.id(), *note.metadata())));
+        self.chain.add(header.hash());
+        self.last_header = Some(header);
+
+        Ok(block)
+    }
+}
+
+impl Default for LocalBlockProver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,333 @@
+use alloc::string::String;
+use core::fmt::{self, Display};
+
+use miden_lib::transaction::TransactionKernelError;
+use miden_objects::{
+    accounts::AccountId,
+    assets::Asset,
+    notes::{NoteId, Nullifier},
+    transaction::TransactionId,
+    AccountDeltaError, AccountError, AccountTreeError, AssetError, BlockError, Digest, Felt,
+    NullifierTreeError, ProvenTransactionError, TransactionInputError, TransactionOutputError,
+    TransactionScriptError,
+};
+use vm_processor::ExecutionError;
+
+use super::TransactionVerifierError;
+
+// TRANSACTION EXECUTOR ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransactionExecutorError {
+    ExecuteTransactionProgramFailed(ExecutionError),
+    ExpirationBlockNumOverflow {
+        block_num: u32,
+        expiration_delta: u16,
+    },
+    ExpirationDeltaExceeded {
+        expiration_block_num: u32,
+        max_expiration_block_num: u32,
+    },
+    FeeEstimationFailed(AssetError),
+    FetchForeignAccountInputsFailed(DataStoreError),
+    FetchTransactionInputsFailed(DataStoreError),
+    InconsistentAccountId {
+        input_id: AccountId,
+        output_id: AccountId,
+    },
+    InconsistentAccountNonceDelta {
+        expected: Option<Felt>,
+        actual: Option<Felt>,
+    },
+    InvalidExpirationDelta(u16),
+    InvalidForeignAccountInputs(TransactionInputError),
+    InvalidTransactionChain(TransactionChainError),
+    InvalidTransactionOutput(TransactionOutputError),
+    KernelAssertionFailed(TransactionKernelError),
+    NoncePolicyViolation(AccountDeltaError),
+    TransactionHostCreationFailed(TransactionHostError),
+}
+
+impl TransactionExecutorError {
+    /// Returns the error for a failed execution of the transaction program.
+    ///
+    /// Assertion failures with a known kernel error code are mapped to
+    /// [TransactionExecutorError::KernelAssertionFailed], which describes the failed assertion,
+    /// the clock cycle in which it failed and its location in the MASM source. All other errors
+    /// are wrapped in [TransactionExecutorError::ExecuteTransactionProgramFailed].
+    pub fn from_execution_error(err: ExecutionError) -> Self {
+        if let ExecutionError::FailedAssertion { clk, err_code, .. } = err {
+            if let Some(kernel_err) = TransactionKernelError::from_failed_assertion(clk, err_code) {
+                return Self::KernelAssertionFailed(kernel_err);
+            }
+        }
+
+        Self::ExecuteTransactionProgramFailed(err)
+    }
+}
+
+impl fmt::Display for TransactionExecutorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TransactionExecutorError {}
+
+// TRANSACTION CHAIN ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransactionChainError {
+    AccountMismatch {
+        expected: AccountId,
+        actual: AccountId,
+    },
+    InconsistentFinalAccountState {
+        expected: Digest,
+        actual: Digest,
+    },
+    InconsistentInitialAccountState {
+        expected: Digest,
+        actual: Digest,
+    },
+    InvalidAccountDelta(AccountError),
+    InvalidTransactionInputs(TransactionInputError),
+    OutOfOrderSubmission {
+        expected: Option<TransactionId>,
+        actual: TransactionId,
+    },
+}
+
+impl fmt::Display for TransactionChainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TransactionChainError {}
+
+// TRANSACTION PROVER ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransactionProverError {
+    InternalError(String),
+    InvalidAccountDelta(AccountError),
+    InvalidCompactAccountDelta(AccountDeltaError),
+    InvalidProvingOptions(String),
+    InvalidTransactionOutput(TransactionOutputError),
+    ProvenTransactionError(ProvenTransactionError),
+    TransactionProgramExecutionFailed(ExecutionError),
+    TransactionHostCreationFailed(TransactionHostError),
+}
+
+impl Display for TransactionProverError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TransactionProverError::InternalError(inner) => {
+                write!(f, "Internal transaction prover error: {}", inner)
+            },
+            TransactionProverError::InvalidAccountDelta(account_error) => {
+                write!(f, "Applying account delta failed: {}", account_error)
+            },
+            TransactionProverError::InvalidCompactAccountDelta(inner) => {
+                write!(f, "Building compact account delta failed: {}", inner)
+            },
+            TransactionProverError::InvalidProvingOptions(msg) => {
+                write!(f, "Invalid proving options: {}", msg)
+            },
+            TransactionProverError::InvalidTransactionOutput(inner) => {
+                write!(f, "Transaction output invalid: {}", inner)
+            },
+            TransactionProverError::ProvenTransactionError(inner) => {
+                write!(f, "Building proven transaction error: {}", inner)
+            },
+            TransactionProverError::TransactionProgramExecutionFailed(inner) => {
+                write!(f, "Proving transaction failed: {}", inner)
+            },
+            TransactionProverError::TransactionHostCreationFailed(inner) => {
+                write!(f, "Failed to create the transaction host: {}", inner)
+            },
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TransactionProverError {}
+
+// BATCH PROVER ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BatchProverError {
+    TransactionVerificationFailed(TransactionId, TransactionVerifierError),
+}
+
+impl fmt::Display for BatchProverError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BatchProverError {}
+
+// BLOCK PROVER ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BlockProverError {
+    ExpiredBlock {
+        expiration_block_num: u32,
+        block_num: u32,
+    },
+    InconsistentAccountState {
+        account_id: AccountId,
+        expected: Digest,
+        actual: Digest,
+    },
+    InvalidAccountUpdates(AccountTreeError),
+    InvalidBlock(BlockError),
+    InvalidNullifiers(NullifierTreeError),
+    NullifierAlreadySpent(Nullifier),
+    UnauthenticatedNoteNotFound(NoteId),
+}
+
+impl fmt::Display for BlockProverError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BlockProverError {}
+
+// TRANSACTION REQUEST ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransactionRequestError {
+    AccountMismatch {
+        expected: AccountId,
+        actual: AccountId,
+    },
+    DuplicateInputNote(NoteId),
+    DuplicateOutputNote(NoteId),
+    ExpectedOutputAssetMissing(Asset),
+    InvalidExpirationDelta(u16),
+    InvalidMaxAssetsPerTransaction(usize),
+    InvalidMaxNotesPerTransaction(usize),
+    InvalidOutputNote(NoteId),
+    MissingOutputNote(NoteId),
+    NoteExceedsAssetLimit {
+        note_id: NoteId,
+        num_assets: usize,
+        max: usize,
+    },
+    ScriptCompilationFailed(TransactionScriptError),
+    TooManyProcedureArguments(usize),
+}
+
+impl fmt::Display for TransactionRequestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TransactionRequestError {}
+
+// TRANSACTION HOST ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransactionHostError {
+    AccountProcedureIndexMapError(String),
+}
+
+impl fmt::Display for TransactionHostError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TransactionHostError {}
+
+// HOST EXTENSION ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HostExtensionError {
+    InternalError(String),
+    InvalidEventData { event_id: u32, reason: String },
+}
+
+impl fmt::Display for HostExtensionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HostExtensionError::InternalError(error) => {
+                write!(f, "host extension internal error: {error}")
+            },
+            HostExtensionError::InvalidEventData { event_id, reason } => {
+                write!(f, "invalid data for event {event_id}: {reason}")
+            },
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for HostExtensionError {}
+
+// DATA STORE ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DataStoreError {
+    AccountNotFound(AccountId),
+    BlockNotFound(u32),
+    InvalidTransactionInput(TransactionInputError),
+    InternalError(String),
+    NoteAlreadyConsumed(NoteId),
+    NoteNotFound(NoteId),
+}
+
+impl fmt::Display for DataStoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DataStoreError {}
+
+// AUTHENTICATION ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum AuthenticationError {
+    InternalError(String),
+    RejectedSignature(String),
+    UnknownKey(String),
+}
+
+impl fmt::Display for AuthenticationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AuthenticationError::InternalError(error) => {
+                write!(f, "authentication internal error: {error}")
+            },
+            AuthenticationError::RejectedSignature(reason) => {
+                write!(f, "signature was rejected: {reason}")
+            },
+            AuthenticationError::UnknownKey(error) => write!(f, "unknown key error: {error}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AuthenticationError {}
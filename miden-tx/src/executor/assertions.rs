@@ -0,0 +1,122 @@
+use alloc::{sync::Arc, vec::Vec};
+
+use miden_lib::errors::KernelErrorLocation;
+use miden_objects::vm::RowIndex;
+use vm_core::{
+    mast::{MastForest, MastNode, OperationOrDecorator},
+    AssemblyOp, Decorator, Operation,
+};
+use vm_processor::VmStateIterator;
+
+// FAILED ASSERTION LOCATOR
+// ================================================================================================
+
+/// Returns the MASM source location of the assertion which failed with the specified error code
+/// in the specified clock cycle, or `None` if it cannot be resolved from the debug info of the
+/// executed code.
+///
+/// The location is resolved as follows:
+/// - The instructions asserting with the error code are collected from the assembly op
+///   decorators of the provided MAST forests.
+/// - The provided states of the failed execution are replayed up to the failed clock cycle to
+///   find the last instruction executed before the failure.
+/// - If this instruction had not completed before the failed cycle, it is the failed assertion.
+///   Otherwise, the failed assertion is the first instruction asserting with the error code
+///   which follows it in the same procedure.
+///
+/// If neither applies (e.g., because the assertion is the first instruction of a procedure), the
+/// location is only resolved if a single instruction asserts with the error code.
+pub(super) fn locate_failed_assertion(
+    forests: &[Arc<MastForest>],
+    states: VmStateIterator,
+    clk: RowIndex,
+    err_code: u32,
+) -> Option<KernelErrorLocation> {
+    let mut sites = Vec::new();
+    for forest in forests {
+        collect_assertion_sites(forest, err_code, &mut sites);
+    }
+    if sites.is_empty() {
+        return None;
+    }
+
+    // find the last instruction executed before the failed cycle; the state of a cycle describes
+    // the instruction executed in the preceding cycle
+    let mut last_asmop = None;
+    for state in states {
+        let Ok(state) = state else { break };
+        if state.clk > clk {
+            break;
+        }
+        if let Some(asmop) = state.asmop {
+            last_asmop = Some((state.clk, asmop));
+        }
+    }
+
+    let site = match last_asmop {
+        Some((state_clk, asmop)) if state_clk == clk && asmop.cycle_idx() < asmop.num_cycles() => {
+            Some(asmop.as_ref().clone())
+        },
+        Some((_, asmop)) => next_site(&sites, asmop.as_ref()).cloned(),
+        None => None,
+    }
+    .or_else(|| if sites.len() == 1 { sites.pop() } else { None })?;
+
+    let location = site.location()?;
+    Some(KernelErrorLocation::new(
+        site.context_name(),
+        location.path.as_ref(),
+        location.start.to_u32()..location.end.to_u32(),
+    ))
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Adds the assembly ops of all instructions in the provided forest which assert with the
+/// specified error code to `sites`.
+fn collect_assertion_sites(forest: &MastForest, err_code: u32, sites: &mut Vec<AssemblyOp>) {
+    for node in forest.nodes() {
+        let MastNode::Block(block) = node else { continue };
+
+        // the assembly op decorator of an instruction precedes the first operation of the
+        // instruction
+        let mut current_asmop = None;
+        for item in block.iter() {
+            match item {
+                OperationOrDecorator::Decorator(decorator_id) => {
+                    if let Some(Decorator::AsmOp(asmop)) = forest.get_decorator_by_id(*decorator_id)
+                    {
+                        current_asmop = Some(asmop);
+                    }
+                },
+                OperationOrDecorator::Operation(
+                    Operation::Assert(code)
+                    | Operation::MpVerify(code)
+                    | Operation::U32assert2(code),
+                ) if *code == err_code => {
+                    if let Some(asmop) = current_asmop.filter(|asmop| !sites.contains(asmop)) {
+                        sites.push(asmop.clone());
+                    }
+                },
+                OperationOrDecorator::Operation(_) => (),
+            }
+        }
+    }
+}
+
+/// Returns the first of the provided assertion sites which follows the provided instruction in
+/// the same procedure.
+fn next_site<'a>(sites: &'a [AssemblyOp], asmop: &AssemblyOp) -> Option<&'a AssemblyOp> {
+    let location = asmop.location()?;
+
+    sites
+        .iter()
+        .filter(|site| site.context_name() == asmop.context_name())
+        .filter(|site| {
+            site.location().is_some_and(|site_location| {
+                site_location.path == location.path && site_location.start > location.start
+            })
+        })
+        .min_by_key(|site| site.location().map(|site_location| site_location.start))
+}
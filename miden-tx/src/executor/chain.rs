@@ -0,0 +1,213 @@
+use alloc::{
+    collections::{BTreeMap, VecDeque},
+    vec::Vec,
+};
+
+use miden_objects::{
+    accounts::{Account, AccountId},
+    notes::{Note, NoteId},
+    transaction::{
+        ExecutedTransaction, InputNote, InputNotes, OutputNote, TransactionId, TransactionInputs,
+    },
+};
+
+use crate::errors::TransactionChainError;
+
+// LOCAL TRANSACTION CHAIN
+// ================================================================================================
+
+/// A sequence of transactions executed locally against the same account, where every transaction
+/// is executed against the state of the account produced by the previous transaction.
+///
+/// Chaining allows executing a transaction before the previous transactions against the account
+/// have been proven and included in a block. The chain keeps track of the in-flight (i.e., not yet
+/// committed) state of the account and of the full output notes of the chained transactions. These
+/// notes can be consumed by subsequent transactions of the chain as unauthenticated notes (see
+/// [TransactionExecutor::execute_chained_transaction()](super::TransactionExecutor::execute_chained_transaction)).
+///
+/// Since every transaction of the chain builds on the account state produced by its predecessor,
+/// the transactions must be submitted in the order in which they were executed. The chain tracks
+/// the transactions which have not been submitted yet and rejects out-of-order submissions via
+/// [Self::mark_submitted()].
+#[derive(Debug, Clone)]
+pub struct LocalTransactionChain {
+    account: Account,
+    output_notes: BTreeMap<NoteId, Note>,
+    pending_transactions: VecDeque<TransactionId>,
+}
+
+impl LocalTransactionChain {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new empty [LocalTransactionChain] starting at the provided account state.
+    ///
+    /// The provided state is usually the latest committed state of the account.
+    pub fn new(account: Account) -> Self {
+        Self {
+            account,
+            output_notes: BTreeMap::new(),
+            pending_transactions: VecDeque::new(),
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of the account against which the transactions of this chain are executed.
+    pub fn account_id(&self) -> AccountId {
+        self.account.id()
+    }
+
+    /// Returns the in-flight state of the account, i.e., the state produced by the last
+    /// transaction of this chain.
+    pub fn account(&self) -> &Account {
+        &self.account
+    }
+
+    /// Returns the full output notes of the chained transactions which have not been consumed by a
+    /// later transaction of this chain.
+    pub fn output_notes(&self) -> impl Iterator<Item = &Note> {
+        self.output_notes.values()
+    }
+
+    /// Returns the output note with the specified ID, if it was created by a transaction of this
+    /// chain and has not been consumed by a later transaction of this chain.
+    pub fn get_output_note(&self, note_id: NoteId) -> Option<&Note> {
+        self.output_notes.get(&note_id)
+    }
+
+    /// Returns the IDs of the transactions which have not been submitted yet, in the order in
+    /// which they must be submitted.
+    pub fn pending_transactions(&self) -> impl Iterator<Item = &TransactionId> {
+        self.pending_transactions.iter()
+    }
+
+    /// Returns the ID of the transaction which must be submitted next, if any.
+    pub fn next_submission(&self) -> Option<TransactionId> {
+        self.pending_transactions.front().copied()
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Appends the provided transaction to this chain.
+    ///
+    /// The in-flight account state is updated with the account delta of the transaction, the
+    /// notes consumed by the transaction are removed from the tracked output notes, and the full
+    /// output notes of the transaction are added to them.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The transaction was executed against a different account.
+    /// - The transaction was not executed against the in-flight state of the account.
+    /// - The account delta of the transaction cannot be applied to the in-flight state, or the
+    ///   resulting state does not match the final account state of the transaction.
+    pub fn push(&mut self, transaction: &ExecutedTransaction) -> Result<(), TransactionChainError> {
+        if transaction.account_id() != self.account.id() {
+            return Err(TransactionChainError::AccountMismatch {
+                expected: self.account.id(),
+                actual: transaction.account_id(),
+            });
+        }
+
+        if transaction.initial_account().hash() != self.account.hash() {
+            return Err(TransactionChainError::InconsistentInitialAccountState {
+                expected: self.account.hash(),
+                actual: transaction.initial_account().hash(),
+            });
+        }
+
+        let mut account = self.account.clone();
+        account
+            .apply_delta(transaction.account_delta())
+            .map_err(TransactionChainError::InvalidAccountDelta)?;
+        if account.hash() != transaction.final_account().hash() {
+            return Err(TransactionChainError::InconsistentFinalAccountState {
+                expected: transaction.final_account().hash(),
+                actual: account.hash(),
+            });
+        }
+
+        self.account = account;
+        for input_note in transaction.input_notes().iter() {
+            self.output_notes.remove(&input_note.id());
+        }
+        for output_note in transaction.output_notes().iter() {
+            if let OutputNote::Full(note) = output_note {
+                self.output_notes.insert(note.id(), note.clone());
+            }
+        }
+        self.pending_transactions.push_back(transaction.id());
+
+        Ok(())
+    }
+
+    /// Records the submission of the transaction with the specified ID.
+    ///
+    /// # Errors
+    /// Returns an error if the transaction is not the next pending transaction of this chain.
+    pub fn mark_submitted(
+        &mut self,
+        transaction_id: TransactionId,
+    ) -> Result<(), TransactionChainError> {
+        if self.next_submission() != Some(transaction_id) {
+            return Err(TransactionChainError::OutOfOrderSubmission {
+                expected: self.next_submission(),
+                actual: transaction_id,
+            });
+        }
+
+        self.pending_transactions.pop_front();
+        Ok(())
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the inputs of a transaction which is executed against the in-flight account state
+    /// and consumes the specified notes.
+    ///
+    /// The account of the provided inputs is replaced by the in-flight account state. The notes
+    /// which are output notes of this chain are appended to the input notes as unauthenticated
+    /// notes, in the order in which they are specified.
+    pub(crate) fn transaction_inputs(
+        &self,
+        tx_inputs: TransactionInputs,
+        notes: &[NoteId],
+    ) -> Result<TransactionInputs, TransactionChainError> {
+        let (_, account_seed, block_header, block_chain, input_notes) = tx_inputs.into_parts();
+
+        // the seed is required only as long as the account has not been updated by the chain
+        let account_seed = account_seed.filter(|_| self.account.is_new());
+
+        let mut input_notes = input_notes.into_vec();
+        input_notes.extend(
+            notes
+                .iter()
+                .filter_map(|note_id| self.get_output_note(*note_id))
+                .map(|note| InputNote::unauthenticated(note.clone())),
+        );
+        let input_notes: InputNotes<InputNote> = InputNotes::new(input_notes)
+            .map_err(TransactionChainError::InvalidTransactionInputs)?;
+
+        TransactionInputs::new(
+            self.account.clone(),
+            account_seed,
+            block_header,
+            block_chain,
+            input_notes,
+        )
+        .map_err(TransactionChainError::InvalidTransactionInputs)
+    }
+
+    /// Returns the IDs of the specified notes which are not output notes of this chain, i.e.,
+    /// which must be fetched from the data store.
+    pub(crate) fn authenticated_notes(&self, notes: &[NoteId]) -> Vec<NoteId> {
+        notes
+            .iter()
+            .filter(|note_id| !self.output_notes.contains_key(note_id))
+            .copied()
+            .collect()
+    }
+}
@@ -0,0 +1,31 @@
+use miden_objects::BlockHeader;
+
+// TRANSACTION CLOCK
+// ================================================================================================
+
+/// Defines the notion of "current time" used by the transaction executor.
+///
+/// Transactions are executed against a reference block, and thus the wall-clock time of the
+/// machine executing a transaction has no bearing on its validity. To keep execution
+/// reproducible, the current time is derived from the reference block of the transaction rather
+/// than read from the system clock.
+pub trait TransactionClock {
+    /// Returns the current time (as a UNIX timestamp in seconds) for a transaction executed
+    /// against the provided reference block.
+    fn current_time(&self, block_header: &BlockHeader) -> u32;
+}
+
+// BLOCK CLOCK
+// ================================================================================================
+
+/// A [TransactionClock] which reports the timestamp of the reference block as the current time.
+///
+/// This is the clock used by the transaction executor by default.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct BlockClock;
+
+impl TransactionClock for BlockClock {
+    fn current_time(&self, block_header: &BlockHeader) -> u32 {
+        block_header.timestamp()
+    }
+}
@@ -0,0 +1,140 @@
+use alloc::vec::Vec;
+
+use miden_lib::transaction::TransactionKernel;
+use miden_objects::{accounts::AccountId, notes::NoteId, transaction::TransactionArgs};
+use vm_processor::{ExecutionError, MemAdviceProvider, Process};
+use winter_maybe_async::{maybe_async, maybe_await};
+
+use super::{TransactionExecutor, TransactionExecutorError};
+
+// NOTE CONSUMABILITY
+// ================================================================================================
+
+/// Describes whether a note can be consumed by an account, as determined by a
+/// [NoteConsumptionChecker].
+#[derive(Debug)]
+pub enum NoteConsumability {
+    /// The note script executed successfully against the account.
+    Consumable,
+    /// The note script requested a signature, and thus the note can only be consumed if the
+    /// transaction is authenticated.
+    NeedsAuthentication,
+    /// The execution of the note script failed with the contained error.
+    NotConsumable(TransactionExecutorError),
+}
+
+impl NoteConsumability {
+    /// Returns true if the note can be consumed without authentication.
+    pub fn is_consumable(&self) -> bool {
+        matches!(self, Self::Consumable)
+    }
+}
+
+// NOTE CONSUMPTION CHECKER
+// ================================================================================================
+
+/// Checks whether notes can be consumed by an account without executing full transactions.
+///
+/// Every note is checked in isolation by executing the prologue and the script of the note against
+/// the account. The execution stops as soon as the note is processed, and it is performed without
+/// building the execution trace or recording the advice data. Thus, the transaction script and the
+/// epilogue are not executed, and no signatures are generated: a note whose script requests a
+/// signature is reported as [NoteConsumability::NeedsAuthentication] instead.
+///
+/// This makes the check considerably cheaper than executing a transaction, so that it can be used
+/// to triage a large number of discovered notes before consuming them.
+pub struct NoteConsumptionChecker<'a> {
+    tx_executor: &'a TransactionExecutor,
+}
+
+impl<'a> NoteConsumptionChecker<'a> {
+    /// Returns a new [NoteConsumptionChecker] which uses the data store, the loaded code and the
+    /// extensions of the provided transaction executor.
+    pub fn new(tx_executor: &'a TransactionExecutor) -> Self {
+        Self { tx_executor }
+    }
+
+    /// Checks whether each of the specified notes can be consumed by the specified account, and
+    /// returns the result of every check in the order of the notes.
+    ///
+    /// The note arguments and advice inputs of the provided transaction arguments are used for
+    /// every check, while the transaction script is ignored.
+    ///
+    /// # Errors:
+    /// Returns an error if any of the checks fails, see [Self::check_note()].
+    #[maybe_async]
+    pub fn check_notes(
+        &self,
+        account_id: AccountId,
+        block_ref: u32,
+        notes: &[NoteId],
+        tx_args: TransactionArgs,
+    ) -> Result<Vec<(NoteId, NoteConsumability)>, TransactionExecutorError> {
+        let mut results = Vec::with_capacity(notes.len());
+        for note_id in notes {
+            let consumability =
+                maybe_await!(self.check_note(account_id, block_ref, *note_id, tx_args.clone()))?;
+            results.push((*note_id, consumability));
+        }
+
+        Ok(results)
+    }
+
+    /// Checks whether the specified note can be consumed by the specified account.
+    ///
+    /// A failure to execute the note script is not an error, but is reported as
+    /// [NoteConsumability::NotConsumable].
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If required data can not be fetched from the [DataStore](super::DataStore).
+    /// - If the transaction host could not be created.
+    #[maybe_async]
+    pub fn check_note(
+        &self,
+        account_id: AccountId,
+        block_ref: u32,
+        note_id: NoteId,
+        mut tx_args: TransactionArgs,
+    ) -> Result<NoteConsumability, TransactionExecutorError> {
+        let (tx_inputs, account_codes, tx_rng) = maybe_await!(self
+            .tx_executor
+            .prepare_transaction(account_id, block_ref, &[note_id], &[], &mut tx_args))?;
+
+        let (stack_inputs, advice_inputs) =
+            TransactionKernel::prepare_inputs(&tx_inputs, &tx_args, None);
+        let advice_provider: MemAdviceProvider = advice_inputs.into();
+
+        // load note script MAST into the MAST store
+        self.tx_executor.mast_store.load_transaction_code(&tx_inputs, &tx_args);
+
+        // the host is not provided with an authenticator, so that signature requests fail
+        let mut host = self
+            .tx_executor
+            .create_host(&tx_inputs, advice_provider, &account_codes, None, tx_rng)?
+            .with_stop_after_notes();
+
+        // execute the transaction kernel with tracing enabled, so that the host can stop the
+        // execution once the note is processed
+        let program = TransactionKernel::main();
+        let exec_options = self.tx_executor.exec_options.with_tracing();
+        let result = Process::new(program.kernel().clone(), stack_inputs, &mut host, exec_options)
+            .execute(&program);
+
+        // the execution fails when it is stopped by the host, thus the end of the notes processing
+        // is what indicates that the note was consumed successfully
+        if host.tx_progress().notes_processing().end().is_some() {
+            return Ok(NoteConsumability::Consumable);
+        }
+
+        Ok(match result {
+            Ok(_) => NoteConsumability::Consumable,
+            Err(ExecutionError::FailedSignatureGeneration(_)) => {
+                NoteConsumability::NeedsAuthentication
+            },
+            Err(err) => NoteConsumability::NotConsumable(
+                TransactionExecutorError::from_execution_error(err),
+            ),
+        })
+    }
+}
@@ -1,7 +1,11 @@
 #[cfg(feature = "async")]
 use alloc::boxed::Box;
 
-use miden_objects::{accounts::AccountId, notes::NoteId, transaction::TransactionInputs};
+use miden_objects::{
+    accounts::AccountId,
+    notes::NoteId,
+    transaction::{ForeignAccountInputs, TransactionInputs},
+};
 use winter_maybe_async::*;
 
 use crate::DataStoreError;
@@ -11,6 +15,9 @@ use crate::DataStoreError;
 
 /// The [DataStore] trait defines the interface that transaction objects use to fetch data
 /// required for transaction execution.
+///
+/// When the `async` feature is enabled, all methods of this trait are `async`, so that data stores
+/// backed by remote or persistent storage can fetch the data without blocking.
 #[maybe_async_trait]
 pub trait DataStore {
     /// Returns account, chain, and input note data required to execute a transaction against
@@ -20,6 +27,11 @@ pub trait DataStore {
     /// recorded in the chain. In general, it is recommended that bock_ref corresponds to the
     /// latest block available in the data store.
     ///
+    /// Notes which have not been recorded in the chain yet (e.g., notes created by another
+    /// transaction of the same batch) may be returned as unauthenticated input notes. Their
+    /// existence is then verified by the batch or block producer rather than by the transaction
+    /// kernel.
+    ///
     /// # Errors
     /// Returns an error if:
     /// - The account with the specified ID could not be found in the data store.
@@ -35,4 +47,26 @@ pub trait DataStore {
         block_ref: u32,
         notes: &[NoteId],
     ) -> Result<TransactionInputs, DataStoreError>;
+
+    /// Returns the data required to read the state of the foreign account with the specified ID
+    /// during execution of a transaction referencing the block with the specified number.
+    ///
+    /// The returned inputs must be valid against the header of the block specified by block_ref,
+    /// i.e., the Merkle path of the account must open to the account root of that block.
+    ///
+    /// The default implementation does not support foreign accounts and always returns an error.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The account with the specified ID could not be found in the data store.
+    /// - The block with the specified number could not be found in the data store.
+    /// - The data store encountered some internal error
+    #[maybe_async]
+    fn get_foreign_account_inputs(
+        &self,
+        account_id: AccountId,
+        _block_ref: u32,
+    ) -> Result<ForeignAccountInputs, DataStoreError> {
+        Err(DataStoreError::AccountNotFound(account_id))
+    }
 }
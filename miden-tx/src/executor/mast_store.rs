@@ -1,4 +1,4 @@
-use alloc::{collections::BTreeMap, sync::Arc};
+use alloc::{collections::BTreeMap, sync::Arc, vec::Vec};
 
 use miden_lib::{transaction::TransactionKernel, utils::sync::RwLock, MidenLib, StdLibrary};
 use miden_objects::{
@@ -77,6 +77,18 @@ impl TransactionMastStore {
         }
     }
 
+    /// Returns all [MastForest]s loaded into this store.
+    pub fn forests(&self) -> Vec<Arc<MastForest>> {
+        let mut forests: Vec<Arc<MastForest>> = Vec::new();
+        for forest in self.mast_forests.read().values() {
+            if !forests.iter().any(|known_forest| Arc::ptr_eq(known_forest, forest)) {
+                forests.push(forest.clone());
+            }
+        }
+
+        forests
+    }
+
     /// Registers all procedures of the provided [MastForest] with this store.
     pub fn insert(&self, mast_forest: Arc<MastForest>) {
         let mut mast_forests = self.mast_forests.write();
@@ -1,26 +1,86 @@
 use alloc::{collections::BTreeSet, sync::Arc, vec::Vec};
 
-use miden_lib::transaction::TransactionKernel;
+use miden_lib::transaction::{TransactionKernel, TransactionKernelError, TransactionScriptBuilder};
 use miden_objects::{
-    accounts::{AccountCode, AccountId},
+    accounts::{AccountCode, AccountId, NoncePolicy, StorageSlot},
     assembly::Library,
+    assets::FungibleAsset,
     notes::NoteId,
-    transaction::{ExecutedTransaction, TransactionArgs, TransactionInputs},
+    transaction::{ExecutedTransaction, FeeModel, TransactionArgs, TransactionInputs},
     vm::StackOutputs,
-    MAX_TX_EXECUTION_CYCLES, MIN_TX_EXECUTION_CYCLES, ZERO,
+    Word, MAX_TX_EXECUTION_CYCLES, MIN_TX_EXECUTION_CYCLES, ZERO,
+};
+use vm_processor::{
+    AdviceProvider, ExecutionOptions, MemAdviceProvider, Process, RecAdviceProvider,
 };
-use vm_processor::{ExecutionOptions, RecAdviceProvider};
 use winter_maybe_async::{maybe_async, maybe_await};
 
-use super::{TransactionExecutorError, TransactionHost};
+use super::{ExecutionDiagnostics, HostExtension, TransactionExecutorError, TransactionHost};
 use crate::auth::TransactionAuthenticator;
 
+mod assertions;
+
 mod data_store;
 pub use data_store::DataStore;
 
 mod mast_store;
 pub use mast_store::TransactionMastStore;
 
+mod simulation;
+pub use simulation::TransactionSimulation;
+
+mod rng;
+pub use rng::TransactionRng;
+
+mod clock;
+pub use clock::{BlockClock, TransactionClock};
+
+mod consumption;
+pub use consumption::{NoteConsumability, NoteConsumptionChecker};
+
+mod script_cache;
+pub use script_cache::{ScriptCache, ScriptCacheStats};
+
+mod chain;
+pub use chain::LocalTransactionChain;
+
+// EXECUTOR OPTIONS
+// ================================================================================================
+
+/// Options which configure a [TransactionExecutor].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExecutorOptions {
+    /// The maximum number of note scripts and transaction scripts each kept in the [ScriptCache]
+    /// of the executor. A capacity of 0 disables caching.
+    pub script_cache_capacity: usize,
+    /// The policy the nonce update of every executed transaction must satisfy. Transactions
+    /// violating the policy are rejected by the executor.
+    ///
+    /// This policy is applied in addition to the nonce policy of the account, which is part of
+    /// the account code and enforced by the transaction kernel.
+    pub nonce_policy: NoncePolicy,
+    /// The expiration delta of every executed transaction, i.e., the number of blocks after its
+    /// reference block at which a transaction expires.
+    ///
+    /// Transactions without a transaction script are executed with a script setting this delta.
+    /// Transactions with a script must set an expiration block number no later than the one
+    /// implied by this delta themselves (e.g., via
+    /// [TransactionScriptBuilder::with_expiration_delta()]), and are rejected by the executor
+    /// otherwise. Transactions whose reference block number plus this delta exceeds `u32::MAX`
+    /// are rejected as well.
+    pub expiration_delta: Option<u16>,
+}
+
+impl Default for ExecutorOptions {
+    fn default() -> Self {
+        Self {
+            script_cache_capacity: ScriptCache::DEFAULT_CAPACITY,
+            nonce_policy: NoncePolicy::default(),
+            expiration_delta: None,
+        }
+    }
+}
+
 // TRANSACTION EXECUTOR
 // ================================================================================================
 
@@ -31,12 +91,28 @@ pub use mast_store::TransactionMastStore;
 /// - Load the code associated with the transaction into the [TransactionMastStore].
 /// - Execute the transaction program and create an [ExecutedTransaction].
 ///
-/// The transaction executor uses dynamic dispatch with trait objects for the [DataStore] and
-/// [TransactionAuthenticator], allowing it to be used with different backend implementations.
+/// The transaction executor uses dynamic dispatch with trait objects for the [DataStore],
+/// [TransactionAuthenticator] and [TransactionClock], allowing it to be used with different
+/// backend implementations.
+///
+/// By default, transaction execution is not reproducible since signatures are generated using
+/// the randomness of the authenticator. Setting a seed via [Self::with_rng_seed()] makes all
+/// randomness provided to a transaction derive from the seed (see [TransactionRng]).
+///
+/// Scripts compiled by the executor (e.g., the script setting the expiration delta of
+/// transactions without a transaction script) are kept in the [ScriptCache] of the executor, so
+/// that repeated executions do not recompile them. The cache can also be used to compile the note
+/// and transaction scripts of the executed transactions (see [Self::script_cache()]).
 pub struct TransactionExecutor {
     data_store: Arc<dyn DataStore>,
     mast_store: Arc<TransactionMastStore>,
+    script_cache: Arc<ScriptCache>,
+    nonce_policy: NoncePolicy,
+    expiration_delta: Option<u16>,
     authenticator: Option<Arc<dyn TransactionAuthenticator>>,
+    clock: Arc<dyn TransactionClock>,
+    rng_seed: Option<Word>,
+    extensions: Vec<Arc<dyn HostExtension>>,
     /// Holds the code of all accounts loaded into this transaction executor via the
     /// [Self::load_account_code()] method.
     account_codes: BTreeSet<AccountCode>,
@@ -58,7 +134,16 @@ impl TransactionExecutor {
         Self {
             data_store,
             mast_store: Arc::new(TransactionMastStore::new()),
+            script_cache: Arc::new(ScriptCache::new(
+                ScriptCache::DEFAULT_CAPACITY,
+                TransactionKernel::assembler(),
+            )),
+            nonce_policy: NoncePolicy::default(),
+            expiration_delta: None,
             authenticator,
+            clock: Arc::new(BlockClock),
+            rng_seed: None,
+            extensions: Vec::new(),
             exec_options: ExecutionOptions::new(
                 Some(MAX_TX_EXECUTION_CYCLES),
                 MIN_TX_EXECUTION_CYCLES,
@@ -75,7 +160,14 @@ impl TransactionExecutor {
     /// When transaction executor is in debug mode, all transaction-related code (note scripts,
     /// account code) will be compiled and executed in debug mode. This will ensure that all debug
     /// instructions present in the original source code are executed.
+    ///
+    /// This replaces the [ScriptCache] of the executor with an empty cache whose assembler is in
+    /// the requested mode.
     pub fn with_debug_mode(mut self, in_debug_mode: bool) -> Self {
+        self.script_cache = Arc::new(ScriptCache::new(
+            self.script_cache.capacity(),
+            TransactionKernel::assembler().with_debug_mode(in_debug_mode),
+        ));
         self.exec_options = ExecutionOptions::new(
             Some(self.exec_options.max_cycles()),
             self.exec_options.expected_cycles(),
@@ -97,6 +189,68 @@ impl TransactionExecutor {
         self
     }
 
+    /// Makes transaction execution deterministic by deriving all randomness provided to the
+    /// executed transactions from the specified seed.
+    ///
+    /// For every transaction, a [TransactionRng] is derived from the seed, the executing account
+    /// and its nonce, the reference block, the input notes and the transaction script (see
+    /// [TransactionRng::for_transaction()]). The RNG is used to:
+    /// - Place a seed for the transaction and note scripts into the advice map of the transaction
+    ///   arguments under [TransactionRng::script_seed_key()].
+    /// - Attach a seed bound to the message to sign to every signature request sent to the
+    ///   [TransactionAuthenticator].
+    pub fn with_rng_seed(mut self, seed: Word) -> Self {
+        self.rng_seed = Some(seed);
+        self
+    }
+
+    /// Sets the [TransactionClock] which defines the current time of the executed transactions.
+    ///
+    /// By default, the current time of a transaction is the timestamp of its reference block (see
+    /// [BlockClock]).
+    pub fn with_clock(mut self, clock: Arc<dyn TransactionClock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Configures the [TransactionExecutor] with the provided [ExecutorOptions].
+    ///
+    /// This replaces the [ScriptCache] of the executor with an empty cache of the configured
+    /// capacity, and sets the [NoncePolicy] which executed transactions must satisfy and the
+    /// expiration delta of the executed transactions.
+    pub fn with_options(mut self, options: ExecutorOptions) -> Self {
+        self.script_cache = Arc::new(ScriptCache::new(
+            options.script_cache_capacity,
+            self.script_cache.assembler().clone(),
+        ));
+        self.nonce_policy = options.nonce_policy;
+        self.expiration_delta = options.expiration_delta;
+        self
+    }
+
+    /// Adds a [HostExtension] to the hosts of the executed transactions.
+    ///
+    /// Extensions handle custom events emitted by transaction code and provide
+    /// application-specific advice. Extensions are consulted in the order in which they were
+    /// added.
+    pub fn with_extension(mut self, extension: Arc<dyn HostExtension>) -> Self {
+        self.extensions.push(extension);
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the cache of compiled scripts of this executor.
+    ///
+    /// The scripts compiled by the executor during execution are kept in this cache. Compiling the
+    /// note scripts and transaction scripts of executed transactions via the cache as well (e.g.,
+    /// via [crate::TransactionRequest::build_transaction_args_with_cache()]) avoids recompiling
+    /// scripts which are executed repeatedly, such as the scripts of standard notes.
+    pub fn script_cache(&self) -> &ScriptCache {
+        &self.script_cache
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -139,25 +293,376 @@ impl TransactionExecutor {
         notes: &[NoteId],
         tx_args: TransactionArgs,
     ) -> Result<ExecutedTransaction, TransactionExecutorError> {
+        maybe_await!(self.execute_transaction_with_foreign_accounts(
+            account_id,
+            block_ref,
+            notes,
+            &[],
+            tx_args
+        ))
+    }
+
+    /// Prepares and executes a transaction which may read the state of the specified foreign
+    /// accounts, and returns an [ExecutedTransaction].
+    ///
+    /// In addition to the data fetched by [Self::execute_transaction()], the method fetches the
+    /// inputs of every foreign account from the [DataStore] and validates them against the
+    /// reference block of the transaction. The state and code of the foreign accounts are then
+    /// provided to the transaction kernel, so that procedures of these accounts can be invoked
+    /// during execution.
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If required data can not be fetched from the [DataStore].
+    /// - If the inputs of any of the foreign accounts are not valid against the reference block.
+    #[maybe_async]
+    pub fn execute_transaction_with_foreign_accounts(
+        &self,
+        account_id: AccountId,
+        block_ref: u32,
+        notes: &[NoteId],
+        foreign_account_ids: &[AccountId],
+        mut tx_args: TransactionArgs,
+    ) -> Result<ExecutedTransaction, TransactionExecutorError> {
+        let (tx_inputs, account_codes, tx_rng) = maybe_await!(self.prepare_transaction(
+            account_id,
+            block_ref,
+            notes,
+            foreign_account_ids,
+            &mut tx_args
+        ))?;
+
+        self.execute_prepared_transaction(tx_inputs, account_codes, tx_rng, tx_args)
+    }
+
+    /// Prepares and executes a transaction against the in-flight account state tracked by the
+    /// provided [LocalTransactionChain], appends it to the chain and returns it.
+    ///
+    /// This allows executing a transaction before the previous transactions against the account
+    /// have been proven and included in a block. The state of the account returned by the
+    /// [DataStore] is replaced by the in-flight state of the chain. The specified notes which are
+    /// full output notes of earlier transactions of the chain are consumed as unauthenticated
+    /// notes after all other notes, which are fetched from the [DataStore].
+    ///
+    /// The resulting transactions must be submitted in the order in which they were executed (see
+    /// [LocalTransactionChain::mark_submitted()]).
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If required data can not be fetched from the [DataStore].
+    /// - If the transaction inputs can not be built from the in-flight state of the chain.
+    /// - If the transaction program fails to execute.
+    #[maybe_async]
+    pub fn execute_chained_transaction(
+        &self,
+        chain: &mut LocalTransactionChain,
+        block_ref: u32,
+        notes: &[NoteId],
+        mut tx_args: TransactionArgs,
+    ) -> Result<ExecutedTransaction, TransactionExecutorError> {
+        let authenticated_notes = chain.authenticated_notes(notes);
+        let (tx_inputs, account_codes, tx_rng) = maybe_await!(self.prepare_transaction(
+            chain.account_id(),
+            block_ref,
+            &authenticated_notes,
+            &[],
+            &mut tx_args
+        ))?;
+        let tx_inputs = chain
+            .transaction_inputs(tx_inputs, notes)
+            .map_err(TransactionExecutorError::InvalidTransactionChain)?;
+
+        let executed_transaction =
+            self.execute_prepared_transaction(tx_inputs, account_codes, tx_rng, tx_args)?;
+        chain
+            .push(&executed_transaction)
+            .map_err(TransactionExecutorError::InvalidTransactionChain)?;
+
+        Ok(executed_transaction)
+    }
+
+    /// Executes a transaction specified by the provided arguments in dry-run mode and returns a
+    /// [TransactionSimulation].
+    ///
+    /// Unlike [Self::execute_transaction()], this method neither records the advice data nor
+    /// builds the execution trace, both of which are only needed to prove the transaction. It can
+    /// be used to preview the effects and the cost (in cycles) of a transaction before executing
+    /// and proving it.
+    ///
+    /// The result of a simulation is not binding: the checks [Self::execute_transaction()]
+    /// performs on the outputs of a transaction (e.g., against the [NoncePolicy] and the
+    /// expiration delta of the executor) are not applied, and a later execution may have
+    /// different effects if the data provided by the [DataStore] changes in the meantime.
+    /// Signatures requested by the transaction are still produced by the authenticator.
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If required data can not be fetched from the [DataStore].
+    /// - If the transaction program fails to execute.
+    #[maybe_async]
+    pub fn simulate(
+        &self,
+        account_id: AccountId,
+        block_ref: u32,
+        notes: &[NoteId],
+        mut tx_args: TransactionArgs,
+    ) -> Result<TransactionSimulation, TransactionExecutorError> {
+        let (tx_inputs, account_codes, tx_rng) = maybe_await!(self.prepare_transaction(
+            account_id,
+            block_ref,
+            notes,
+            &[],
+            &mut tx_args
+        ))?;
+
+        let (stack_inputs, advice_inputs) =
+            TransactionKernel::prepare_inputs(&tx_inputs, &tx_args, None);
+        let advice_provider: MemAdviceProvider = advice_inputs.into();
+
+        // load note script MAST into the MAST store
+        self.mast_store.load_transaction_code(&tx_inputs, &tx_args);
+
+        let mut host = self.create_host(
+            &tx_inputs,
+            advice_provider,
+            &account_codes,
+            self.authenticator.clone(),
+            tx_rng.clone(),
+        )?;
+
+        // execute the transaction kernel without building the execution trace
+        let program = TransactionKernel::main();
+        let stack_outputs =
+            Process::new(program.kernel().clone(), stack_inputs, &mut host, self.exec_options)
+                .execute(&program)
+                .map_err(|err| {
+                    self.locate_failed_assertion(
+                        TransactionExecutorError::from_execution_error(err),
+                        &tx_inputs,
+                        &tx_args,
+                        &account_codes,
+                        tx_rng,
+                    )
+                })?;
+
+        let events = host.events().to_vec();
+        let (advice_provider, account_delta, output_notes, _, tx_progress) = host.into_parts();
+        let (_, map, _) = advice_provider.into_parts();
+
+        let tx_outputs =
+            TransactionKernel::from_transaction_parts(&stack_outputs, &map.into(), output_notes)
+                .map_err(TransactionExecutorError::InvalidTransactionOutput)?;
+
+        Ok(TransactionSimulation::new(
+            account_delta,
+            tx_outputs.account,
+            tx_outputs.output_notes,
+            events,
+            tx_progress.into(),
+        ))
+    }
+
+    /// Executes a transaction specified by the provided arguments in dry-run mode and returns the
+    /// [ExecutionDiagnostics] collected during execution.
+    ///
+    /// The transaction is executed with tracing enabled, and a snapshot of the operand stack and
+    /// the kernel memory is recorded at the end of the prologue, of every input note, of the
+    /// transaction script and of the epilogue. If the transaction program fails, the error is
+    /// recorded in the returned diagnostics rather than returned, so that the snapshots preceding
+    /// the failure can be inspected.
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If required data can not be fetched from the [DataStore].
+    /// - If the transaction host could not be created.
+    #[maybe_async]
+    pub fn diagnose(
+        &self,
+        account_id: AccountId,
+        block_ref: u32,
+        notes: &[NoteId],
+        mut tx_args: TransactionArgs,
+    ) -> Result<ExecutionDiagnostics, TransactionExecutorError> {
+        let (tx_inputs, account_codes, tx_rng) = maybe_await!(self.prepare_transaction(
+            account_id,
+            block_ref,
+            notes,
+            &[],
+            &mut tx_args
+        ))?;
+
+        let (stack_inputs, advice_inputs) =
+            TransactionKernel::prepare_inputs(&tx_inputs, &tx_args, None);
+        let advice_provider: MemAdviceProvider = advice_inputs.into();
+
+        // load note script MAST into the MAST store
+        self.mast_store.load_transaction_code(&tx_inputs, &tx_args);
+
+        let mut host = self
+            .create_host(
+                &tx_inputs,
+                advice_provider,
+                &account_codes,
+                self.authenticator.clone(),
+                tx_rng,
+            )?
+            .with_diagnostics();
+
+        // execute the transaction kernel with tracing enabled, so that the host receives the
+        // trace events marking the ends of the kernel stages
+        let program = TransactionKernel::main();
+        let exec_options = self.exec_options.with_tracing();
+        let result = Process::new(program.kernel().clone(), stack_inputs, &mut host, exec_options)
+            .execute(&program);
+
+        let mut diagnostics = host.take_diagnostics().expect("diagnostics must be enabled");
+        if let Err(err) = result {
+            diagnostics.set_failure(err);
+        }
+
+        Ok(diagnostics)
+    }
+
+    /// Estimates the fee of a transaction specified by the provided arguments using the provided
+    /// [FeeModel].
+    ///
+    /// The transaction is executed in dry-run mode via [Self::simulate()], and the fee is computed
+    /// from the resulting
+    /// [TransactionMeasurements](miden_objects::transaction::TransactionMeasurements).
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If the transaction simulation fails.
+    /// - If the fee model fails to compute the fee from the transaction measurements.
+    #[maybe_async]
+    pub fn estimate_fee(
+        &self,
+        account_id: AccountId,
+        block_ref: u32,
+        notes: &[NoteId],
+        tx_args: TransactionArgs,
+        fee_model: &dyn FeeModel,
+    ) -> Result<FungibleAsset, TransactionExecutorError> {
+        let simulation = maybe_await!(self.simulate(account_id, block_ref, notes, tx_args))?;
+
+        fee_model
+            .compute_fee(simulation.measurements())
+            .map_err(TransactionExecutorError::FeeEstimationFailed)
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Fetches the data required to execute a transaction from the [DataStore], and returns the
+    /// transaction inputs together with the code of all accounts which may be invoked during
+    /// execution and the [TransactionRng] of the transaction (if a seed was set).
+    ///
+    /// The inputs of the specified foreign accounts are validated against the reference block of
+    /// the transaction and loaded into the provided transaction arguments. If an expiration delta
+    /// was set and the transaction has no transaction script, a script setting the delta is added
+    /// to the transaction arguments. If a seed was set, the seed for the transaction and note
+    /// scripts is loaded into the transaction arguments as well.
+    #[maybe_async]
+    fn prepare_transaction(
+        &self,
+        account_id: AccountId,
+        block_ref: u32,
+        notes: &[NoteId],
+        foreign_account_ids: &[AccountId],
+        tx_args: &mut TransactionArgs,
+    ) -> Result<
+        (TransactionInputs, BTreeSet<AccountCode>, Option<TransactionRng>),
+        TransactionExecutorError,
+    > {
         let tx_inputs =
             maybe_await!(self.data_store.get_transaction_inputs(account_id, block_ref, notes))
                 .map_err(TransactionExecutorError::FetchTransactionInputsFailed)?;
 
+        // fetch the foreign account inputs, make sure they are valid against the reference block
+        // and load them into the transaction arguments
+        let mut account_codes = self.account_codes.clone();
+        for foreign_account_id in foreign_account_ids {
+            let foreign_inputs = maybe_await!(self
+                .data_store
+                .get_foreign_account_inputs(*foreign_account_id, block_ref))
+            .map_err(TransactionExecutorError::FetchForeignAccountInputsFailed)?;
+
+            foreign_inputs
+                .validate(tx_inputs.block_header())
+                .map_err(TransactionExecutorError::InvalidForeignAccountInputs)?;
+
+            tx_args.add_foreign_account(&foreign_inputs);
+            self.mast_store.load_account_code(foreign_inputs.account_code());
+            account_codes.insert(foreign_inputs.account_code().clone());
+        }
+
+        // set the expiration delta of the executor for transactions without a script; the
+        // expiration of transactions with a script is checked after execution
+        if let Some(delta) = self.expiration_delta {
+            if delta == 0 {
+                return Err(TransactionExecutorError::InvalidExpirationDelta(delta));
+            }
+
+            if tx_args.tx_script().is_none() {
+                let source = TransactionScriptBuilder::new()
+                    .with_expiration_delta(delta)
+                    .expect("expiration delta should be valid")
+                    .source();
+                let tx_script = self
+                    .script_cache
+                    .compile_tx_script(&source, Vec::new())
+                    .expect("expiration delta script should compile");
+                tx_args.set_tx_script(tx_script);
+            }
+        }
+
+        // derive the RNG of the transaction and provide the scripts with a seed
+        let tx_rng = self.rng_seed.map(|seed| {
+            let tx_script_root = tx_args.tx_script().map(|tx_script| tx_script.hash());
+            let mut tx_rng = TransactionRng::for_transaction(seed, &tx_inputs, tx_script_root);
+            let script_seed = tx_rng.draw_seed();
+            tx_args.extend_advice_map([(TransactionRng::script_seed_key(), script_seed.to_vec())]);
+            tx_rng
+        });
+
+        Ok((tx_inputs, account_codes, tx_rng))
+    }
+
+    /// Executes the transaction described by the provided inputs and arguments, which were
+    /// prepared via [Self::prepare_transaction()], and returns an [ExecutedTransaction].
+    fn execute_prepared_transaction(
+        &self,
+        tx_inputs: TransactionInputs,
+        account_codes: BTreeSet<AccountCode>,
+        tx_rng: Option<TransactionRng>,
+        tx_args: TransactionArgs,
+    ) -> Result<ExecutedTransaction, TransactionExecutorError> {
         let (stack_inputs, advice_inputs) =
             TransactionKernel::prepare_inputs(&tx_inputs, &tx_args, None);
         let advice_recorder: RecAdviceProvider = advice_inputs.into();
+        let max_expiration_block_num = self
+            .expiration_delta
+            .map(|delta| {
+                let block_num = tx_inputs.block_header().block_num();
+                block_num.checked_add(u32::from(delta)).ok_or(
+                    TransactionExecutorError::ExpirationBlockNumOverflow {
+                        block_num,
+                        expiration_delta: delta,
+                    },
+                )
+            })
+            .transpose()?;
 
         // load note script MAST into the MAST store
         self.mast_store.load_transaction_code(&tx_inputs, &tx_args);
 
-        let mut host = TransactionHost::new(
-            tx_inputs.account().into(),
+        let mut host = self.create_host(
+            &tx_inputs,
             advice_recorder,
-            self.mast_store.clone(),
+            &account_codes,
             self.authenticator.clone(),
-            self.account_codes.iter().map(|code| code.commitment()).collect(),
-        )
-        .map_err(TransactionExecutorError::TransactionHostCreationFailed)?;
+            tx_rng.clone(),
+        )?;
 
         // execute the transaction kernel
         let result = vm_processor::execute(
@@ -166,18 +671,20 @@ impl TransactionExecutor {
             &mut host,
             self.exec_options,
         )
-        .map_err(TransactionExecutorError::ExecuteTransactionProgramFailed)?;
+        .map_err(|err| {
+            self.locate_failed_assertion(
+                TransactionExecutorError::from_execution_error(err),
+                &tx_inputs,
+                &tx_args,
+                &account_codes,
+                tx_rng,
+            )
+        })?;
 
         // Attempt to retrieve used account codes based on the advice map
-        let account_codes = self
-            .account_codes
-            .iter()
-            .filter_map(|code| {
-                tx_args
-                    .advice_inputs()
-                    .mapped_values(&code.commitment())
-                    .and(Some(code.clone()))
-            })
+        let account_codes = account_codes
+            .into_iter()
+            .filter(|code| tx_args.advice_inputs().mapped_values(&code.commitment()).is_some())
             .collect();
 
         build_executed_transaction(
@@ -186,7 +693,99 @@ impl TransactionExecutor {
             result.stack_outputs().clone(),
             host,
             account_codes,
+            self.nonce_policy,
+            max_expiration_block_num,
+        )
+    }
+
+    /// Returns the provided error with the MASM source location of the failed assertion set, if
+    /// the error is a failed kernel assertion and the executor is in debug mode.
+    ///
+    /// The location is resolved from the debug info of the executed code by re-executing the
+    /// transaction described by the provided inputs and arguments up to the failed assertion, so
+    /// the authenticator may be asked for the same signatures again. Locations of assertions in
+    /// the transaction kernel and the Miden library are only available if `miden-lib` is built
+    /// with the `with-debug-info` feature.
+    fn locate_failed_assertion(
+        &self,
+        err: TransactionExecutorError,
+        tx_inputs: &TransactionInputs,
+        tx_args: &TransactionArgs,
+        account_codes: &BTreeSet<AccountCode>,
+        tx_rng: Option<TransactionRng>,
+    ) -> TransactionExecutorError {
+        let TransactionExecutorError::KernelAssertionFailed(kernel_err) = err else {
+            return err;
+        };
+        let TransactionKernelError::FailedAssertion { clk, err_code, .. } = kernel_err else {
+            return TransactionExecutorError::KernelAssertionFailed(kernel_err);
+        };
+        if !self.exec_options.enable_debugging() {
+            return TransactionExecutorError::KernelAssertionFailed(kernel_err);
+        }
+
+        let (stack_inputs, advice_inputs) =
+            TransactionKernel::prepare_inputs(tx_inputs, tx_args, None);
+        let advice_provider: MemAdviceProvider = advice_inputs.into();
+        let location = self
+            .create_host(
+                tx_inputs,
+                advice_provider,
+                account_codes,
+                self.authenticator.clone(),
+                tx_rng,
+            )
+            .ok()
+            .and_then(|host| {
+                let program = TransactionKernel::main();
+                let mut forests = self.mast_store.forests();
+                forests.push(program.mast_forest().clone());
+
+                let states = vm_processor::execute_iter(&program, stack_inputs, host);
+                assertions::locate_failed_assertion(&forests, states, clk, err_code)
+            });
+
+        match location {
+            Some(location) => {
+                TransactionExecutorError::KernelAssertionFailed(kernel_err.with_location(location))
+            },
+            None => TransactionExecutorError::KernelAssertionFailed(kernel_err),
+        }
+    }
+
+    /// Creates a [TransactionHost] for executing the transaction described by the provided inputs
+    /// which serves signature requests using the provided authenticator.
+    fn create_host<A: AdviceProvider>(
+        &self,
+        tx_inputs: &TransactionInputs,
+        adv_provider: A,
+        account_codes: &BTreeSet<AccountCode>,
+        authenticator: Option<Arc<dyn TransactionAuthenticator>>,
+        tx_rng: Option<TransactionRng>,
+    ) -> Result<TransactionHost<A>, TransactionExecutorError> {
+        let current_time = self.clock.current_time(tx_inputs.block_header());
+
+        let host = TransactionHost::new(
+            tx_inputs.account().into(),
+            adv_provider,
+            self.mast_store.clone(),
+            authenticator,
+            account_codes.iter().map(|code| code.commitment()).collect(),
         )
+        .map_err(TransactionExecutorError::TransactionHostCreationFailed)?
+        .with_current_time(current_time)
+        .with_extensions(self.extensions.iter().cloned())
+        .with_lazy_storage_maps(tx_inputs.account().storage().slots().iter().filter_map(|slot| {
+            match slot {
+                StorageSlot::LazyMap(map) => Some(map.clone()),
+                _ => None,
+            }
+        }));
+
+        Ok(match tx_rng {
+            Some(tx_rng) => host.with_rng(tx_rng),
+            None => host,
+        })
     }
 }
 
@@ -194,13 +793,20 @@ impl TransactionExecutor {
 // ================================================================================================
 
 /// Creates a new [ExecutedTransaction] from the provided data.
+///
+/// The nonce update of the resulting account delta is checked against the provided
+/// [NoncePolicy], and the expiration block number of the transaction against the provided
+/// maximum expiration block number (if any).
 fn build_executed_transaction(
     tx_args: TransactionArgs,
     tx_inputs: TransactionInputs,
     stack_outputs: StackOutputs,
     host: TransactionHost<RecAdviceProvider>,
     account_codes: Vec<AccountCode>,
+    nonce_policy: NoncePolicy,
+    max_expiration_block_num: Option<u32>,
 ) -> Result<ExecutedTransaction, TransactionExecutorError> {
+    let kernel_events = host.kernel_events().to_vec();
     let (advice_recorder, account_delta, output_notes, generated_signatures, tx_progress) =
         host.into_parts();
 
@@ -237,6 +843,21 @@ fn build_executed_transaction(
         });
     }
 
+    // make sure the nonce was updated as required by the nonce policy of the executor
+    account_delta
+        .validate_nonce_update(initial_account.nonce(), nonce_policy)
+        .map_err(TransactionExecutorError::NoncePolicyViolation)?;
+
+    // make sure the transaction expires no later than required by the executor
+    if let Some(max_expiration_block_num) = max_expiration_block_num {
+        if tx_outputs.expiration_block_num > max_expiration_block_num {
+            return Err(TransactionExecutorError::ExpirationDeltaExceeded {
+                expiration_block_num: tx_outputs.expiration_block_num,
+                max_expiration_block_num,
+            });
+        }
+    }
+
     // introduce generated signatures into the witness inputs
     advice_witness.extend_map(generated_signatures);
 
@@ -248,5 +869,6 @@ fn build_executed_transaction(
         tx_args,
         advice_witness,
         tx_progress.into(),
-    ))
+    )
+    .with_events(kernel_events))
 }
@@ -0,0 +1,191 @@
+use alloc::vec::Vec;
+
+use miden_objects::{
+    crypto::rand::{FeltRng, RpoRandomCoin},
+    transaction::TransactionInputs,
+    Digest, Felt, Hasher, Word, ZERO,
+};
+use rand::RngCore;
+
+// TRANSACTION RNG
+// ================================================================================================
+
+/// A deterministic source of randomness used during transaction execution.
+///
+/// The RNG is seeded with a user-provided seed which, via [TransactionRng::for_transaction()], is
+/// bound to the executing account, its initial nonce, the reference block, the input notes and the
+/// transaction script of the transaction. Thus, executing the same transaction with the same seed
+/// always produces the same random values, while distinct transactions executed with the same seed
+/// do not share their randomness. This makes it
+/// possible to replay transactions, e.g., when auditing them or when comparing the outputs of
+/// different executor implementations.
+///
+/// The transaction executor uses the RNG to:
+/// - Provide a seed to the transaction and note scripts via the advice map (see
+///   [TransactionRng::script_seed_key()]). Scripts should derive serial numbers and any other
+///   random values from this seed.
+/// - Provide a seed to the transaction authenticator with every signature request, so that the
+///   generated signatures are reproducible as well. The seed is bound to the message to sign, so
+///   that the randomness used to sign distinct messages is never reused.
+#[derive(Debug, Clone)]
+pub struct TransactionRng {
+    coin: RpoRandomCoin,
+}
+
+impl TransactionRng {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [TransactionRng] instantiated from the provided seed.
+    pub fn new(seed: Word) -> Self {
+        Self { coin: RpoRandomCoin::new(seed) }
+    }
+
+    /// Returns a new [TransactionRng] for the transaction described by the provided inputs and
+    /// transaction script root.
+    ///
+    /// The seed of the returned RNG is computed as:
+    ///
+    /// > hash(SEED || account_id, block_ref, nonce, 0 || INPUT_NOTES_COMMITMENT || TX_SCRIPT_ROOT)
+    ///
+    /// where the input notes commitment commits to the nullifiers of all input notes, and the
+    /// transaction script root is [ZERO; 4] for transactions without a script. Thus, different
+    /// transactions executed with the same user-provided seed do not share their randomness.
+    pub fn for_transaction(
+        seed: Word,
+        tx_inputs: &TransactionInputs,
+        tx_script_root: Option<Digest>,
+    ) -> Self {
+        let account = tx_inputs.account();
+        let tx_context: Word = [
+            account.id().into(),
+            Felt::from(tx_inputs.block_header().block_num()),
+            account.nonce(),
+            ZERO,
+        ];
+
+        let mut elements = Vec::with_capacity(16);
+        elements.extend_from_slice(&seed);
+        elements.extend_from_slice(&tx_context);
+        elements.extend_from_slice(tx_inputs.input_notes().commitment().as_elements());
+        elements.extend_from_slice(tx_script_root.unwrap_or_default().as_elements());
+
+        Self::new(Hasher::hash_elements(&elements).into())
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the advice map key under which the transaction executor places the seed for the
+    /// transaction and note scripts.
+    ///
+    /// The seed can be loaded onto the operand stack using `push.KEY adv.push_mapval adv_loadw`.
+    pub fn script_seed_key() -> Digest {
+        Hasher::hash(b"miden::tx::script_seed")
+    }
+
+    // RANDOMNESS
+    // --------------------------------------------------------------------------------------------
+
+    /// Draws a random word which can be used to seed randomness outside of this RNG (e.g., by a
+    /// transaction script or by the transaction authenticator).
+    pub fn draw_seed(&mut self) -> Word {
+        self.coin.draw_word()
+    }
+
+    /// Draws a random word which can be used as the serial number of a note.
+    pub fn draw_serial_num(&mut self) -> Word {
+        self.coin.draw_word()
+    }
+
+    /// Draws a random word bound to the provided message, which can be used to seed the randomness
+    /// of a signature over the message.
+    ///
+    /// The seed is computed as hash(SEED || MESSAGE) for a freshly drawn SEED, so that the
+    /// randomness used to sign a message never depends on the seed alone.
+    pub fn draw_signature_seed(&mut self, message: Word) -> Word {
+        Hasher::merge(&[self.draw_seed().into(), message.into()]).into()
+    }
+}
+
+impl FeltRng for TransactionRng {
+    fn draw_element(&mut self) -> Felt {
+        self.coin.draw_element()
+    }
+
+    fn draw_word(&mut self) -> Word {
+        self.coin.draw_word()
+    }
+}
+
+impl RngCore for TransactionRng {
+    fn next_u32(&mut self) -> u32 {
+        self.coin.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.coin.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.coin.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.coin.try_fill_bytes(dest)
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TransactionContextBuilder;
+
+    #[test]
+    fn transaction_rng_is_deterministic() {
+        let seed = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let tx_context = TransactionContextBuilder::with_standard_account(Felt::new(1))
+            .with_mock_notes_preserved()
+            .build();
+        let tx_inputs = tx_context.tx_inputs();
+
+        let mut rng_a = TransactionRng::for_transaction(seed, tx_inputs, None);
+        let mut rng_b = TransactionRng::for_transaction(seed, tx_inputs, None);
+        assert_eq!(rng_a.draw_seed(), rng_b.draw_seed());
+        assert_eq!(rng_a.draw_serial_num(), rng_b.draw_serial_num());
+        assert_eq!(rng_a.next_u64(), rng_b.next_u64());
+
+        // a different transaction script, nonce or set of input notes results in different
+        // randomness
+        let first = TransactionRng::for_transaction(seed, tx_inputs, None).draw_seed();
+        let script_root = Some(Digest::from([Felt::new(5); 4]));
+        assert_ne!(
+            first,
+            TransactionRng::for_transaction(seed, tx_inputs, script_root).draw_seed()
+        );
+
+        let other_nonce = TransactionContextBuilder::with_standard_account(Felt::new(2))
+            .with_mock_notes_preserved()
+            .build();
+        assert_ne!(
+            first,
+            TransactionRng::for_transaction(seed, other_nonce.tx_inputs(), None).draw_seed()
+        );
+
+        let no_notes = TransactionContextBuilder::with_standard_account(Felt::new(1)).build();
+        assert_ne!(
+            first,
+            TransactionRng::for_transaction(seed, no_notes.tx_inputs(), None).draw_seed()
+        );
+
+        // signature seeds are bound to the message
+        let message_1 = [Felt::new(7); 4];
+        let message_2 = [Felt::new(8); 4];
+        let mut rng_c = TransactionRng::for_transaction(seed, tx_inputs, None);
+        let mut rng_d = TransactionRng::for_transaction(seed, tx_inputs, None);
+        assert_ne!(rng_c.draw_signature_seed(message_1), rng_d.draw_signature_seed(message_2));
+    }
+}
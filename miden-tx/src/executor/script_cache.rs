@@ -0,0 +1,247 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use miden_lib::utils::sync::RwLock;
+use miden_objects::{
+    assembly::Assembler, notes::NoteScript, transaction::TransactionScript, Digest, Felt, Hasher,
+    NoteError, TransactionScriptError, Word,
+};
+
+// SCRIPT CACHE
+// ================================================================================================
+
+/// A cache of compiled note scripts and transaction scripts, keyed by the hash of their source
+/// code.
+///
+/// Compiling the same script repeatedly (e.g., the transaction script of every payment sent by a
+/// wallet) is wasteful, since the result only depends on the source code and the assembler. The
+/// cache keeps up to a configured number of scripts of each kind and evicts the least recently used
+/// script when it is full.
+///
+/// Every cache is bound to the assembler it was created with, and compiles all scripts with this
+/// assembler. Thus, scripts compiled against different libraries never share cache entries;
+/// compiling scripts with a different assembler requires a separate cache.
+pub struct ScriptCache {
+    capacity: usize,
+    assembler: Assembler,
+    /// Lookups mutate the state as well, since they record when an entry was last used in order to
+    /// determine the entry to evict, and update the statistics of the cache. Thus, both lookups
+    /// and insertions take the write lock.
+    state: RwLock<CacheState>,
+}
+
+impl ScriptCache {
+    /// The default maximum number of scripts of each kind kept in a cache.
+    pub const DEFAULT_CAPACITY: usize = 64;
+
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new, empty [ScriptCache] which keeps up to `capacity` scripts of each kind, and
+    /// compiles scripts with the provided assembler. A capacity of 0 disables caching.
+    pub fn new(capacity: usize, assembler: Assembler) -> Self {
+        Self {
+            capacity,
+            assembler,
+            state: RwLock::new(CacheState::default()),
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the maximum number of scripts of each kind kept in this cache.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the assembler with which the scripts of this cache are compiled.
+    pub fn assembler(&self) -> &Assembler {
+        &self.assembler
+    }
+
+    /// Returns statistics about the usage of this cache.
+    pub fn stats(&self) -> ScriptCacheStats {
+        let state = self.state.read();
+        ScriptCacheStats {
+            num_note_scripts: state.note_scripts.len(),
+            num_tx_scripts: state.tx_scripts.len(),
+            hits: state.hits,
+            misses: state.misses,
+        }
+    }
+
+    /// Returns true if a note script compiled from the provided source code is cached.
+    pub fn contains_note_script(&self, source: &str) -> bool {
+        self.state.read().note_scripts.contains_key(&note_script_key(source))
+    }
+
+    /// Returns true if a transaction script compiled from the provided source code and inputs is
+    /// cached.
+    pub fn contains_tx_script(&self, source: &str, inputs: &[(Word, Vec<Felt>)]) -> bool {
+        self.state.read().tx_scripts.contains_key(&tx_script_key(source, inputs))
+    }
+
+    // COMPILATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the note script compiled from the provided source code, compiling it with the
+    /// assembler of this cache only if it is not cached yet.
+    ///
+    /// # Errors
+    /// Returns an error if the note script fails to compile.
+    pub fn compile_note_script(&self, source: &str) -> Result<NoteScript, NoteError> {
+        let key = note_script_key(source);
+        if let Some(script) = self.state.write().get_note_script(&key) {
+            return Ok(script);
+        }
+
+        let script = NoteScript::compile(source, self.assembler.clone())?;
+        if self.capacity > 0 {
+            self.state.write().insert_note_script(key, script.clone(), self.capacity);
+        }
+
+        Ok(script)
+    }
+
+    /// Returns the transaction script compiled from the provided source code and inputs,
+    /// compiling it with the assembler of this cache only if it is not cached yet.
+    ///
+    /// # Errors
+    /// Returns an error if the transaction script fails to compile.
+    pub fn compile_tx_script(
+        &self,
+        source: &str,
+        inputs: Vec<(Word, Vec<Felt>)>,
+    ) -> Result<TransactionScript, TransactionScriptError> {
+        let key = tx_script_key(source, &inputs);
+        if let Some(script) = self.state.write().get_tx_script(&key) {
+            return Ok(script);
+        }
+
+        let script = TransactionScript::compile(source, inputs, self.assembler.clone())?;
+        if self.capacity > 0 {
+            self.state.write().insert_tx_script(key, script.clone(), self.capacity);
+        }
+
+        Ok(script)
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Removes all scripts from this cache and resets its statistics.
+    pub fn clear(&self) {
+        *self.state.write() = CacheState::default();
+    }
+}
+
+// SCRIPT CACHE STATS
+// ================================================================================================
+
+/// Statistics about the usage of a [ScriptCache].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScriptCacheStats {
+    /// The number of cached note scripts.
+    pub num_note_scripts: usize,
+    /// The number of cached transaction scripts.
+    pub num_tx_scripts: usize,
+    /// The number of compilations which were served from the cache.
+    pub hits: u64,
+    /// The number of compilations which were not served from the cache.
+    pub misses: u64,
+}
+
+// CACHE STATE
+// ================================================================================================
+
+#[derive(Default)]
+struct CacheState {
+    note_scripts: BTreeMap<Digest, (u64, NoteScript)>,
+    tx_scripts: BTreeMap<Digest, (u64, TransactionScript)>,
+    /// A counter which is incremented on every access and used to determine the least recently
+    /// used entries.
+    clock: u64,
+    hits: u64,
+    misses: u64,
+}
+
+impl CacheState {
+    fn get_note_script(&mut self, key: &Digest) -> Option<NoteScript> {
+        self.clock += 1;
+        let script = touch(&mut self.note_scripts, key, self.clock);
+        self.record_access(script.is_some());
+        script
+    }
+
+    fn get_tx_script(&mut self, key: &Digest) -> Option<TransactionScript> {
+        self.clock += 1;
+        let script = touch(&mut self.tx_scripts, key, self.clock);
+        self.record_access(script.is_some());
+        script
+    }
+
+    fn insert_note_script(&mut self, key: Digest, script: NoteScript, capacity: usize) {
+        insert_evicting(&mut self.note_scripts, key, (self.clock, script), capacity);
+    }
+
+    fn insert_tx_script(&mut self, key: Digest, script: TransactionScript, capacity: usize) {
+        insert_evicting(&mut self.tx_scripts, key, (self.clock, script), capacity);
+    }
+
+    fn record_access(&mut self, hit: bool) {
+        if hit {
+            self.hits += 1;
+        } else {
+            self.misses += 1;
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn note_script_key(source: &str) -> Digest {
+    Hasher::hash(source.as_bytes())
+}
+
+fn tx_script_key(source: &str, inputs: &[(Word, Vec<Felt>)]) -> Digest {
+    // the inputs are part of the compiled transaction script, and thus part of the key
+    let mut elements = Vec::new();
+    for (key, values) in inputs {
+        elements.extend_from_slice(key);
+        elements.push(Felt::from(values.len() as u32));
+        elements.extend_from_slice(values);
+    }
+
+    Hasher::merge(&[Hasher::hash(source.as_bytes()), Hasher::hash_elements(&elements)])
+}
+
+/// Returns a clone of the entry under the specified key, marking it as used at `clock`.
+fn touch<T: Clone>(
+    entries: &mut BTreeMap<Digest, (u64, T)>,
+    key: &Digest,
+    clock: u64,
+) -> Option<T> {
+    entries.get_mut(key).map(|(last_used, value)| {
+        *last_used = clock;
+        value.clone()
+    })
+}
+
+/// Inserts the provided entry, evicting the least recently used entry if the map is full.
+fn insert_evicting<T>(
+    entries: &mut BTreeMap<Digest, (u64, T)>,
+    key: Digest,
+    entry: (u64, T),
+    capacity: usize,
+) {
+    if entries.len() >= capacity && !entries.contains_key(&key) {
+        let lru_key =
+            entries.iter().min_by_key(|(_, (last_used, _))| *last_used).map(|(key, _)| *key);
+        if let Some(lru_key) = lru_key {
+            entries.remove(&lru_key);
+        }
+    }
+
+    entries.insert(key, entry);
+}
@@ -0,0 +1,81 @@
+use alloc::vec::Vec;
+
+use miden_lib::transaction::TransactionEvent;
+use miden_objects::{
+    accounts::{AccountDelta, AccountHeader},
+    transaction::{OutputNotes, TransactionMeasurements},
+};
+
+// TRANSACTION SIMULATION
+// ================================================================================================
+
+/// The result of a transaction executed in dry-run mode via
+/// [TransactionExecutor::simulate()](super::TransactionExecutor::simulate).
+///
+/// A simulation contains the effects the transaction had when it was executed in dry-run mode,
+/// but none of the data required to prove it. Thus, it can be used to preview the outcome and the
+/// cost of a transaction, but it cannot be turned into a proven transaction.
+#[derive(Debug, Clone)]
+pub struct TransactionSimulation {
+    account_delta: AccountDelta,
+    final_account: AccountHeader,
+    output_notes: OutputNotes,
+    events: Vec<TransactionEvent>,
+    measurements: TransactionMeasurements,
+}
+
+impl TransactionSimulation {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [TransactionSimulation] instantiated from the provided data.
+    pub fn new(
+        account_delta: AccountDelta,
+        final_account: AccountHeader,
+        output_notes: OutputNotes,
+        events: Vec<TransactionEvent>,
+        measurements: TransactionMeasurements,
+    ) -> Self {
+        Self {
+            account_delta,
+            final_account,
+            output_notes,
+            events,
+            measurements,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the changes the transaction would make to the state of the account.
+    pub fn account_delta(&self) -> &AccountDelta {
+        &self.account_delta
+    }
+
+    /// Returns the header of the account as it would be after the transaction.
+    pub fn final_account(&self) -> &AccountHeader {
+        &self.final_account
+    }
+
+    /// Returns the notes the transaction would create.
+    pub fn output_notes(&self) -> &OutputNotes {
+        &self.output_notes
+    }
+
+    /// Returns the events emitted by the transaction kernel, in the order in which they were
+    /// emitted.
+    pub fn events(&self) -> &[TransactionEvent] {
+        &self.events
+    }
+
+    /// Returns the number of cycles spent in each of the transaction execution stages.
+    pub fn measurements(&self) -> &TransactionMeasurements {
+        &self.measurements
+    }
+
+    /// Returns the total number of cycles spent executing the transaction.
+    pub fn total_cycles(&self) -> usize {
+        self.measurements.total_cycles()
+    }
+}
@@ -0,0 +1,294 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::Write;
+
+use miden_lib::transaction::memory::{
+    ACCT_ID_PTR, BLK_HASH_PTR, CURRENT_ACCOUNT_DATA_PTR, CURRENT_INPUT_NOTE_PTR,
+    INIT_ACCT_HASH_PTR, INIT_NONCE_PTR, INPUT_NOTES_COMMITMENT_PTR, INPUT_VAULT_ROOT_PTR,
+    NATIVE_ACCT_CODE_COMMITMENT_PTR, NATIVE_ACCT_ID_AND_NONCE_PTR,
+    NATIVE_ACCT_STORAGE_COMMITMENT_PTR, NATIVE_ACCT_VAULT_ROOT_PTR, NUM_INPUT_NOTES_PTR,
+    NUM_OUTPUT_NOTES_PTR, OUTPUT_VAULT_ROOT_PTR, TX_EXPIRATION_BLOCK_NUM_PTR, TX_SCRIPT_ROOT_PTR,
+    TX_VAULT_ROOT_PTR,
+};
+use miden_objects::{notes::NoteId, Digest, Felt, Word};
+use vm_processor::{ContextId, ProcessState};
+
+// CONSTANTS
+// ================================================================================================
+
+/// The memory locations of the transaction kernel which are recorded in every snapshot.
+const SNAPSHOT_MEMORY_LAYOUT: [(&str, u32); 18] = [
+    ("TX_VAULT_ROOT", TX_VAULT_ROOT_PTR),
+    ("CURRENT_INPUT_NOTE", CURRENT_INPUT_NOTE_PTR),
+    ("NUM_OUTPUT_NOTES", NUM_OUTPUT_NOTES_PTR),
+    ("INPUT_VAULT_ROOT", INPUT_VAULT_ROOT_PTR),
+    ("OUTPUT_VAULT_ROOT", OUTPUT_VAULT_ROOT_PTR),
+    ("CURRENT_ACCOUNT_DATA", CURRENT_ACCOUNT_DATA_PTR),
+    ("TX_EXPIRATION_BLOCK_NUM", TX_EXPIRATION_BLOCK_NUM_PTR),
+    ("BLK_HASH", BLK_HASH_PTR),
+    ("ACCT_ID", ACCT_ID_PTR),
+    ("INIT_ACCT_HASH", INIT_ACCT_HASH_PTR),
+    ("INPUT_NOTES_COMMITMENT", INPUT_NOTES_COMMITMENT_PTR),
+    ("INIT_NONCE", INIT_NONCE_PTR),
+    ("TX_SCRIPT_ROOT", TX_SCRIPT_ROOT_PTR),
+    ("NATIVE_ACCT_ID_AND_NONCE", NATIVE_ACCT_ID_AND_NONCE_PTR),
+    ("NATIVE_ACCT_VAULT_ROOT", NATIVE_ACCT_VAULT_ROOT_PTR),
+    ("NATIVE_ACCT_STORAGE_COMMITMENT", NATIVE_ACCT_STORAGE_COMMITMENT_PTR),
+    ("NATIVE_ACCT_CODE_COMMITMENT", NATIVE_ACCT_CODE_COMMITMENT_PTR),
+    ("NUM_INPUT_NOTES", NUM_INPUT_NOTES_PTR),
+];
+
+/// The number of operand stack elements recorded in every snapshot.
+const SNAPSHOT_STACK_DEPTH: usize = 16;
+
+// EXECUTION STAGE
+// ================================================================================================
+
+/// A stage of the transaction kernel after which a [StageSnapshot] is recorded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExecutionStage {
+    /// The prologue has been executed.
+    Prologue,
+    /// The specified input note has been executed.
+    InputNote(NoteId),
+    /// The transaction script has been executed.
+    TransactionScript,
+    /// The epilogue has been executed.
+    Epilogue,
+}
+
+impl ExecutionStage {
+    /// Returns the name of this stage.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Prologue => "prologue",
+            Self::InputNote(_) => "input_note",
+            Self::TransactionScript => "tx_script",
+            Self::Epilogue => "epilogue",
+        }
+    }
+}
+
+// STAGE SNAPSHOT
+// ================================================================================================
+
+/// The state of the VM at the end of an [ExecutionStage].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StageSnapshot {
+    stage: ExecutionStage,
+    cycle: u32,
+    stack: Vec<Felt>,
+    memory: Vec<(&'static str, u32, Word)>,
+}
+
+impl StageSnapshot {
+    /// Returns a new [StageSnapshot] of the provided process state.
+    ///
+    /// Only the top [SNAPSHOT_STACK_DEPTH] elements of the operand stack and the initialized
+    /// locations of the kernel memory listed in [SNAPSHOT_MEMORY_LAYOUT] are recorded.
+    fn new<S: ProcessState>(stage: ExecutionStage, process: &S) -> Self {
+        let mut stack = process.get_stack_state();
+        stack.truncate(SNAPSHOT_STACK_DEPTH);
+
+        let memory = SNAPSHOT_MEMORY_LAYOUT
+            .iter()
+            .filter_map(|&(name, address)| {
+                process
+                    .get_mem_value(ContextId::root(), address)
+                    .map(|value| (name, address, value))
+            })
+            .collect();
+
+        Self {
+            stage,
+            cycle: u32::from(process.clk()),
+            stack,
+            memory,
+        }
+    }
+
+    /// Returns the stage at the end of which this snapshot was recorded.
+    pub fn stage(&self) -> ExecutionStage {
+        self.stage
+    }
+
+    /// Returns the VM cycle at which this snapshot was recorded.
+    pub fn cycle(&self) -> u32 {
+        self.cycle
+    }
+
+    /// Returns the top of the operand stack, with the top element first.
+    pub fn stack(&self) -> &[Felt] {
+        &self.stack
+    }
+
+    /// Returns the recorded kernel memory as (name, address, value) tuples.
+    ///
+    /// The names are the names of the corresponding memory layout constants in
+    /// [miden_lib::transaction::memory] without the `_PTR` suffix.
+    pub fn memory(&self) -> &[(&'static str, u32, Word)] {
+        &self.memory
+    }
+
+    /// Returns the value of the kernel memory location with the specified name, if recorded.
+    pub fn memory_value(&self, name: &str) -> Option<Word> {
+        self.memory.iter().find(|(n, ..)| *n == name).map(|(_, _, value)| *value)
+    }
+
+    /// Writes this snapshot into the provided string as a JSON object.
+    fn write_json(&self, out: &mut String) {
+        out.push_str("{\"stage\":\"");
+        out.push_str(self.stage.name());
+        out.push('"');
+        if let ExecutionStage::InputNote(note_id) = self.stage {
+            let _ = write!(out, ",\"note_id\":\"{}\"", note_id.to_hex());
+        }
+        let _ = write!(out, ",\"cycle\":{},\"stack\":[", self.cycle);
+        for (i, felt) in self.stack.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            let _ = write!(out, "{}", felt.as_int());
+        }
+        out.push_str("],\"memory\":{");
+        for (i, (name, address, value)) in self.memory.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            let _ = write!(
+                out,
+                "\"{name}\":{{\"address\":{address},\"value\":\"{}\"}}",
+                Digest::from(*value).to_hex()
+            );
+        }
+        out.push_str("}}");
+    }
+}
+
+// EXECUTION DIAGNOSTICS
+// ================================================================================================
+
+/// Collects snapshots of the VM state at the end of each stage of the transaction kernel.
+///
+/// Snapshots are recorded by the [TransactionHost](super::TransactionHost) in response to the
+/// trace events emitted by the kernel, and thus are only collected if tracing is enabled. If the
+/// transaction fails, the snapshots recorded so far together with the error allow locating the
+/// failure without adding `debug` instructions to the MASM code.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExecutionDiagnostics {
+    snapshots: Vec<StageSnapshot>,
+    current_note: Option<NoteId>,
+    failure: Option<String>,
+}
+
+impl ExecutionDiagnostics {
+    /// Returns a new, empty [ExecutionDiagnostics].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the recorded snapshots in the order in which the stages were completed.
+    pub fn snapshots(&self) -> &[StageSnapshot] {
+        &self.snapshots
+    }
+
+    /// Returns the snapshot of the last completed stage, if any.
+    pub fn last_snapshot(&self) -> Option<&StageSnapshot> {
+        self.snapshots.last()
+    }
+
+    /// Returns the error which caused the transaction to fail, if any.
+    pub fn failure(&self) -> Option<&str> {
+        self.failure.as_deref()
+    }
+
+    /// Renders the diagnostics as a JSON document.
+    ///
+    /// Stack elements are rendered as integers, while memory words are rendered as hex strings.
+    pub fn to_json(&self) -> String {
+        let mut out = String::from("{\"snapshots\":[");
+        for (i, snapshot) in self.snapshots.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            snapshot.write_json(&mut out);
+        }
+        out.push_str("],\"failure\":");
+        match &self.failure {
+            Some(failure) => write_json_string(&mut out, failure),
+            None => out.push_str("null"),
+        }
+        out.push('}');
+        out
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Records that execution of the specified input note has started.
+    pub(crate) fn start_note_execution(&mut self, note_id: NoteId) {
+        self.current_note = Some(note_id);
+    }
+
+    /// Records a snapshot of the provided process state at the end of the specified stage.
+    ///
+    /// For the [ExecutionStage::InputNote] stage, the ID of the note is the one provided to the
+    /// last [Self::start_note_execution()] call.
+    pub(crate) fn end_stage<S: ProcessState>(&mut self, stage: ExecutionStage, process: &S) {
+        self.snapshots.push(StageSnapshot::new(stage, process));
+    }
+
+    /// Records a snapshot at the end of the execution of the current input note.
+    pub(crate) fn end_note_execution<S: ProcessState>(&mut self, process: &S) {
+        if let Some(note_id) = self.current_note.take() {
+            self.end_stage(ExecutionStage::InputNote(note_id), process);
+        }
+    }
+
+    /// Records the error which caused the transaction to fail.
+    pub(crate) fn set_failure(&mut self, failure: impl ToString) {
+        self.failure = Some(failure.to_string());
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Writes the provided string into `out` as an escaped JSON string.
+fn write_json_string(out: &mut String, value: &str) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            },
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_strings_are_escaped() {
+        let mut out = String::new();
+        write_json_string(&mut out, "assertion \"failed\"\n\tat\\clk\u{1}");
+        assert_eq!(out, r#""assertion \"failed\"\n\tat\\clk\u0001""#);
+    }
+}
@@ -0,0 +1,58 @@
+use alloc::vec::Vec;
+
+use miden_objects::{Digest, Felt};
+use vm_processor::ProcessState;
+
+use crate::errors::HostExtensionError;
+
+// HOST EXTENSION
+// ================================================================================================
+
+/// Defines an extension of the [TransactionHost](super::TransactionHost) which allows
+/// applications to handle custom events and to provide application-specific advice without
+/// forking the host.
+///
+/// Extensions can be used to:
+/// - Handle events emitted by note scripts, transaction scripts or account code via
+///   `emit.<event_id>`, where the upper 16 bits of the event ID differ from the ones reserved for
+///   the transaction kernel (see [EVENT_ID_PREFIX](miden_lib::transaction::EVENT_ID_PREFIX)). The
+///   handler can inspect the state of the VM and respond with entries for the advice map, e.g., to
+///   inject oracle data or the output of a custom signature scheme.
+/// - Answer `adv.push_mapval` requests for keys which are not present in the advice map.
+///
+/// All data is provided via the advice map, so that it is recorded in the advice witness of the
+/// executed transaction. Thus, the extensions do not need to be available when proving the
+/// transaction.
+///
+/// All methods have default implementations which do not handle anything, so implementors only
+/// need to override the methods relevant to them.
+pub trait HostExtension {
+    /// Returns true if this extension handles events with the specified ID.
+    fn handles_event(&self, event_id: u32) -> bool {
+        let _ = event_id;
+        false
+    }
+
+    /// Handles the event with the specified ID and returns the entries to be inserted into the
+    /// advice map before execution resumes.
+    ///
+    /// This method is invoked only for events for which [Self::handles_event()] returns true.
+    fn on_event(
+        &self,
+        event_id: u32,
+        process: &dyn ProcessState,
+    ) -> Result<Vec<(Digest, Vec<Felt>)>, HostExtensionError> {
+        let _ = (event_id, process);
+        Ok(Vec::new())
+    }
+
+    /// Returns the values for the specified advice map key, or `None` if this extension does not
+    /// provide values for the key.
+    ///
+    /// This method is invoked when the key requested by `adv.push_mapval` is not present in the
+    /// advice map.
+    fn get_mapped_values(&self, key: &Digest) -> Option<Vec<Felt>> {
+        let _ = key;
+        None
+    }
+}
@@ -5,20 +5,17 @@ use alloc::{
     vec::Vec,
 };
 
-use miden_lib::{
-    errors::tx_kernel_errors::TX_KERNEL_ERRORS,
-    transaction::{
-        memory::{CURRENT_INPUT_NOTE_PTR, NATIVE_NUM_ACCT_STORAGE_SLOTS_PTR},
-        TransactionEvent, TransactionKernelError, TransactionTrace,
-    },
+use miden_lib::transaction::{
+    memory::{CURRENT_INPUT_NOTE_PTR, NATIVE_NUM_ACCT_STORAGE_SLOTS_PTR},
+    TransactionEvent, TransactionKernelError, TransactionTrace, EVENT_ID_PREFIX,
 };
 use miden_objects::{
-    accounts::{AccountDelta, AccountHeader},
+    accounts::{AccountDelta, AccountHeader, AccountId, LazyMapSlot},
     assets::Asset,
     notes::NoteId,
-    transaction::{OutputNote, TransactionMeasurements},
+    transaction::{KernelEvent, OutputNote, TransactionMeasurements},
     vm::RowIndex,
-    Digest, Hasher,
+    Digest, Hasher, Word,
 };
 use vm_processor::{
     AdviceExtractor, AdviceInjector, AdviceProvider, AdviceSource, ContextId, ExecutionError, Felt,
@@ -37,8 +34,16 @@ use note_builder::OutputNoteBuilder;
 mod tx_progress;
 pub use tx_progress::TransactionProgress;
 
+mod diagnostics;
+pub use diagnostics::{ExecutionDiagnostics, ExecutionStage, StageSnapshot};
+
+mod extension;
+pub use extension::HostExtension;
+
 use crate::{
-    auth::TransactionAuthenticator, errors::TransactionHostError, executor::TransactionMastStore,
+    auth::{SignatureRequest, TransactionAuthenticator},
+    errors::TransactionHostError,
+    executor::{TransactionMastStore, TransactionRng},
 };
 
 // TRANSACTION HOST
@@ -74,6 +79,15 @@ pub struct TransactionHost<A> {
     /// not present in the `generated_signatures` field.
     authenticator: Option<Arc<dyn TransactionAuthenticator>>,
 
+    /// The ID of the account against which the transaction is executed.
+    account_id: AccountId,
+
+    /// The MAST root of the account procedure which last invoked the transaction kernel.
+    ///
+    /// This field is updated by the [TransactionHost::on_event()] handler, and is provided to the
+    /// authenticator as part of signature requests.
+    last_account_procedure: Option<Digest>,
+
     /// Contains previously generated signatures (as a message |-> signature map) required for
     /// transaction execution.
     ///
@@ -86,10 +100,53 @@ pub struct TransactionHost<A> {
     /// This field is updated by the [TransactionHost::on_trace()] handler.
     tx_progress: TransactionProgress,
 
-    /// Contains mappings from error codes to the related error messages.
+    /// The list of events emitted by the transaction kernel during execution, in the order in
+    /// which they were emitted.
+    ///
+    /// This field is updated by the [TransactionHost::on_event()] handler.
+    events: Vec<TransactionEvent>,
+
+    /// The typed log of the actions performed by the transaction kernel during execution (e.g.,
+    /// assets added to the account vault or notes created), in the order in which they occurred.
+    ///
+    /// This field is updated by the [TransactionHost::on_event()] handler and by the signature
+    /// request handler.
+    kernel_events: Vec<KernelEvent>,
+
+    /// Deterministic source of randomness for the transaction, if any.
+    ///
+    /// When set, a seed drawn from this RNG is attached to every signature request, so that the
+    /// generated signatures are reproducible.
+    rng: Option<TransactionRng>,
+
+    /// The current time of the transaction as defined by the
+    /// [TransactionClock](crate::TransactionClock) of the transaction executor, if known.
+    current_time: Option<u32>,
+
+    /// Snapshots of the VM state at the end of each kernel stage, if diagnostics are enabled.
     ///
-    /// This map is initialized at construction time from the [KERNEL_ERRORS] array.
-    error_messages: BTreeMap<u32, &'static str>,
+    /// This field is updated by the [TransactionHost::on_trace()] handler.
+    diagnostics: Option<ExecutionDiagnostics>,
+
+    /// Extensions handling custom events and providing application-specific advice, in the
+    /// order in which they are consulted.
+    extensions: Vec<Arc<dyn HostExtension>>,
+
+    /// If true, custom events which are not handled by any of the extensions are ignored instead
+    /// of failing the execution.
+    skip_unhandled_events: bool,
+
+    /// If true, the execution is stopped once all input notes are processed.
+    ///
+    /// This field is consulted by the [TransactionHost::on_trace()] handler.
+    stop_after_notes: bool,
+
+    /// Storage maps of the account which are held by a storage backend, and whose leaves and
+    /// Merkle paths are loaded into the advice provider when they are accessed.
+    ///
+    /// The maps are updated together with the corresponding maps of the transaction kernel, so
+    /// that their roots can be matched against the roots accessed by the kernel.
+    lazy_maps: Vec<LazyMapSlot>,
 }
 
 impl<A: AdviceProvider> TransactionHost<A> {
@@ -108,7 +165,6 @@ impl<A: AdviceProvider> TransactionHost<A> {
         let proc_index_map =
             AccountProcedureIndexMap::new(account_code_commitments, &adv_provider)?;
 
-        let kernel_assertion_errors = BTreeMap::from(TX_KERNEL_ERRORS);
         Ok(Self {
             adv_provider,
             mast_store,
@@ -116,12 +172,87 @@ impl<A: AdviceProvider> TransactionHost<A> {
             acct_procedure_index_map: proc_index_map,
             output_notes: BTreeMap::default(),
             authenticator,
+            account_id: account.id(),
+            last_account_procedure: None,
             tx_progress: TransactionProgress::default(),
+            events: Vec::new(),
+            kernel_events: Vec::new(),
             generated_signatures: BTreeMap::new(),
-            error_messages: kernel_assertion_errors,
+            rng: None,
+            current_time: None,
+            diagnostics: None,
+            extensions: Vec::new(),
+            skip_unhandled_events: false,
+            stop_after_notes: false,
+            lazy_maps: Vec::new(),
         })
     }
 
+    /// Sets the deterministic source of randomness used by this host to the provided RNG.
+    pub fn with_rng(mut self, rng: TransactionRng) -> Self {
+        self.rng = Some(rng);
+        self
+    }
+
+    /// Sets the current time of the transaction executed by this host.
+    pub fn with_current_time(mut self, current_time: u32) -> Self {
+        self.current_time = Some(current_time);
+        self
+    }
+
+    /// Adds the provided extensions to this host.
+    ///
+    /// Extensions are consulted in the order in which they were added.
+    pub fn with_extensions(
+        mut self,
+        extensions: impl IntoIterator<Item = Arc<dyn HostExtension>>,
+    ) -> Self {
+        self.extensions.extend(extensions);
+        self
+    }
+
+    /// Adds the provided storage maps held by a storage backend to this host.
+    ///
+    /// The nodes of these maps are not included in the advice inputs of the transaction; instead,
+    /// the leaf and the Merkle path of an entry are loaded into the advice provider when the entry
+    /// is accessed by the transaction kernel. The loaded nodes are thus recorded in the witness of
+    /// the transaction.
+    pub fn with_lazy_storage_maps(mut self, maps: impl IntoIterator<Item = LazyMapSlot>) -> Self {
+        self.lazy_maps.extend(maps);
+        self
+    }
+
+    /// Makes this host ignore custom events which are not handled by any of its extensions.
+    ///
+    /// This is used when re-executing a transaction from its witness (e.g., when proving it),
+    /// since the advice provided by the extensions during execution is already recorded in the
+    /// witness.
+    pub fn with_unhandled_events_skipped(mut self) -> Self {
+        self.skip_unhandled_events = true;
+        self
+    }
+
+    /// Makes this host stop the execution once all input notes are processed, i.e., before the
+    /// transaction script and the epilogue are executed.
+    ///
+    /// The execution is stopped by failing it with an [ExecutionError::EventError], and the end
+    /// of the notes processing is recorded in the [TransactionProgress] of this host. The stop is
+    /// triggered by a trace event, and thus the transaction needs to be executed with tracing
+    /// enabled.
+    pub fn with_stop_after_notes(mut self) -> Self {
+        self.stop_after_notes = true;
+        self
+    }
+
+    /// Enables collection of [ExecutionDiagnostics] by this host.
+    ///
+    /// Snapshots are recorded in response to trace events, and thus the transaction needs to be
+    /// executed with tracing enabled.
+    pub fn with_diagnostics(mut self) -> Self {
+        self.diagnostics = Some(ExecutionDiagnostics::new());
+        self
+    }
+
     /// Consumes `self` and returns the advice provider, account delta, output notes, generated
     /// signatures, and transaction progress.
     pub fn into_parts(
@@ -149,6 +280,34 @@ impl<A: AdviceProvider> TransactionHost<A> {
         &self.tx_progress
     }
 
+    /// Returns the current time of the transaction executed by this host, if known.
+    pub fn current_time(&self) -> Option<u32> {
+        self.current_time
+    }
+
+    /// Returns the typed log of the actions performed by the transaction kernel so far, in the
+    /// order in which they occurred.
+    pub fn kernel_events(&self) -> &[KernelEvent] {
+        &self.kernel_events
+    }
+
+    /// Returns the diagnostics collected so far, if diagnostics are enabled.
+    pub fn diagnostics(&self) -> Option<&ExecutionDiagnostics> {
+        self.diagnostics.as_ref()
+    }
+
+    /// Removes the collected diagnostics from this host and returns them, if diagnostics are
+    /// enabled.
+    pub fn take_diagnostics(&mut self) -> Option<ExecutionDiagnostics> {
+        self.diagnostics.take()
+    }
+
+    /// Returns the events emitted by the transaction kernel so far, in the order in which they
+    /// were emitted.
+    pub fn events(&self) -> &[TransactionEvent] {
+        &self.events
+    }
+
     // EVENT HANDLERS
     // --------------------------------------------------------------------------------------------
 
@@ -169,6 +328,11 @@ impl<A: AdviceProvider> TransactionHost<A> {
 
         let note_builder = OutputNoteBuilder::new(stack, &self.adv_provider)?;
 
+        self.kernel_events.push(KernelEvent::NoteCreated {
+            note_idx,
+            metadata: *note_builder.metadata(),
+            recipient: note_builder.recipient_digest(),
+        });
         self.output_notes.insert(note_idx, note_builder);
 
         Ok(())
@@ -197,6 +361,8 @@ impl<A: AdviceProvider> TransactionHost<A> {
             .ok_or_else(|| TransactionKernelError::MissingNote(format!("{:?}", &note_idx)))?;
 
         note_builder.add_asset(asset)?;
+        self.kernel_events
+            .push(KernelEvent::AssetAddedToNote { note_idx: node_idx, asset });
 
         Ok(())
     }
@@ -209,6 +375,9 @@ impl<A: AdviceProvider> TransactionHost<A> {
         process: &S,
     ) -> Result<(), TransactionKernelError> {
         let proc_idx = self.acct_procedure_index_map.get_proc_index(process)?;
+        let proc_root = process.get_stack_word(0).into();
+        self.last_account_procedure = Some(proc_root);
+        self.kernel_events.push(KernelEvent::AccountProcedureInvoked(proc_root));
         self.adv_provider
             .push_stack(AdviceSource::Value(proc_idx.into()))
             .expect("failed to push value onto advice stack");
@@ -224,6 +393,7 @@ impl<A: AdviceProvider> TransactionHost<A> {
     ) -> Result<(), TransactionKernelError> {
         let value = process.get_stack_item(0);
         self.account_delta.increment_nonce(value);
+        self.kernel_events.push(KernelEvent::NonceIncremented(value));
         Ok(())
     }
 
@@ -268,10 +438,12 @@ impl<A: AdviceProvider> TransactionHost<A> {
         ];
 
         // update the delta tracker only if the current and new values are different
+        let slot_index = slot_index.as_int() as u8;
         if current_slot_value != new_slot_value {
-            let slot_index = slot_index.as_int() as u8;
             self.account_delta.storage_delta().set_item(slot_index, new_slot_value);
         }
+        self.kernel_events
+            .push(KernelEvent::StorageItemUpdated { slot_index, value: new_slot_value });
 
         Ok(())
     }
@@ -319,6 +491,11 @@ impl<A: AdviceProvider> TransactionHost<A> {
             new_map_key.into(),
             new_map_value,
         );
+        self.kernel_events.push(KernelEvent::StorageMapItemUpdated {
+            slot_index,
+            key: new_map_key.into(),
+            value: new_map_value,
+        });
 
         Ok(())
     }
@@ -343,6 +520,7 @@ impl<A: AdviceProvider> TransactionHost<A> {
             .vault_delta()
             .add_asset(asset)
             .map_err(TransactionKernelError::AccountDeltaError)?;
+        self.kernel_events.push(KernelEvent::AssetAddedToVault(asset));
         Ok(())
     }
 
@@ -363,6 +541,7 @@ impl<A: AdviceProvider> TransactionHost<A> {
             .vault_delta()
             .remove_asset(asset)
             .map_err(TransactionKernelError::AccountDeltaError)?;
+        self.kernel_events.push(KernelEvent::AssetRemovedFromVault(asset));
         Ok(())
     }
 
@@ -381,12 +560,26 @@ impl<A: AdviceProvider> TransactionHost<A> {
         let pub_key = process.get_stack_word(0);
         let msg = process.get_stack_word(1);
         let signature_key = Hasher::merge(&[pub_key.into(), msg.into()]);
+        self.kernel_events
+            .push(KernelEvent::SignatureRequested { pub_key, message: msg });
 
         let signature = if let Some(signature) = self.adv_provider.get_mapped_values(&signature_key)
         {
             signature.to_vec()
         } else {
-            let account_delta = self.account_delta.clone().into_delta();
+            let mut request = SignatureRequest::new(
+                pub_key,
+                msg,
+                self.account_id,
+                self.last_account_procedure,
+                self.account_delta.clone().into_delta(),
+            );
+            if let Some(rng) = self.rng.as_mut() {
+                request = request.with_seed(rng.draw_signature_seed(msg));
+            }
+            if let Some(current_time) = self.current_time {
+                request = request.with_timestamp(current_time);
+            }
 
             let signature: Vec<Felt> = match &self.authenticator {
                 None => {
@@ -394,11 +587,9 @@ impl<A: AdviceProvider> TransactionHost<A> {
                         "No authenticator assigned to transaction host",
                     ))
                 },
-                Some(authenticator) => {
-                    authenticator.get_signature(pub_key, msg, &account_delta).map_err(|_| {
-                        ExecutionError::FailedSignatureGeneration("Error generating signature")
-                    })
-                },
+                Some(authenticator) => authenticator.sign_request(&request).map_err(|_| {
+                    ExecutionError::FailedSignatureGeneration("Error generating signature")
+                }),
             }?;
 
             self.generated_signatures.insert(signature_key, signature.clone());
@@ -412,6 +603,105 @@ impl<A: AdviceProvider> TransactionHost<A> {
         Ok(HostResponse::None)
     }
 
+    // EXTENSION HANDLERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Dispatches a custom (i.e., non-kernel) event to the first extension handling it and
+    /// inserts the advice map entries returned by the extension into the advice provider.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - No extension handles the event and unhandled events are not skipped.
+    /// - The extension fails to handle the event.
+    fn on_custom_event<S: ProcessState>(
+        &mut self,
+        process: &S,
+        event_id: u32,
+    ) -> Result<HostResponse, ExecutionError> {
+        let extension = match self.extensions.iter().find(|ext| ext.handles_event(event_id)) {
+            Some(extension) => extension,
+            None if self.skip_unhandled_events => return Ok(HostResponse::None),
+            None => {
+                return Err(ExecutionError::EventError(format!(
+                    "event {event_id} is not handled by any of the host extensions"
+                )))
+            },
+        };
+
+        let entries = extension
+            .on_event(event_id, process)
+            .map_err(|err| ExecutionError::EventError(err.to_string()))?;
+        for (key, values) in entries {
+            self.adv_provider.insert_into_map(key.into(), values);
+        }
+
+        Ok(HostResponse::None)
+    }
+
+    /// Inserts the values provided by the first extension which knows the key requested by
+    /// `adv.push_mapval` into the advice provider, unless the key is already in the advice map.
+    ///
+    /// Expected stack state: [..., KEY, ...], where KEY starts at `key_offset`
+    fn on_map_value_requested<S: ProcessState>(&mut self, process: &S, key_offset: usize) {
+        let key = Digest::new([
+            process.get_stack_item(key_offset + 3),
+            process.get_stack_item(key_offset + 2),
+            process.get_stack_item(key_offset + 1),
+            process.get_stack_item(key_offset),
+        ]);
+
+        if self.adv_provider.get_mapped_values(&key).is_some() {
+            return;
+        }
+
+        if let Some(values) = self.extensions.iter().find_map(|ext| ext.get_mapped_values(&key)) {
+            self.adv_provider.insert_into_map(key.into(), values);
+        }
+    }
+
+    /// Loads the leaf and the Merkle path of the key at the provided stack offset into the advice
+    /// provider, if the root following the key on the stack is the root of one of the storage
+    /// maps held by a storage backend.
+    ///
+    /// Returns the index of the matching map in `lazy_maps`, or None if the root does not belong
+    /// to any of these maps.
+    fn on_smt_leaf_requested<S: ProcessState>(
+        &mut self,
+        process: &S,
+        key_offset: usize,
+    ) -> Result<Option<usize>, ExecutionError> {
+        let key = Digest::from(read_word(process, key_offset));
+        let root = Digest::from(read_word(process, key_offset + 4));
+
+        let Some(map_index) = self.lazy_maps.iter().position(|map| map.root() == root) else {
+            return Ok(None);
+        };
+        let proof = self.lazy_maps[map_index]
+            .open(&key)
+            .map_err(|err| ExecutionError::EventError(err.to_string()))?;
+
+        // the advice provider expects non-empty leaves to be in the advice map
+        let leaf = proof.leaf();
+        if !leaf.is_empty() {
+            self.adv_provider.insert_into_map(leaf.hash().into(), leaf.to_elements());
+        }
+
+        // add the nodes on the path from the leaf to the root to the Merkle store
+        let mut index = leaf.index().value();
+        let mut node = leaf.hash();
+        for sibling in proof.path().iter() {
+            let (lhs, rhs) = if index & 1 == 1 {
+                (*sibling, node)
+            } else {
+                (node, *sibling)
+            };
+            node = self.adv_provider.merge_roots(lhs.into(), rhs.into())?.into();
+            index >>= 1;
+        }
+
+        Ok(Some(map_index))
+    }
+
     // HELPER FUNCTIONS
     // --------------------------------------------------------------------------------------------
 
@@ -475,6 +765,28 @@ impl<A: AdviceProvider> Host for TransactionHost<A> {
     ) -> Result<HostResponse, ExecutionError> {
         match injector {
             AdviceInjector::SigToStack { .. } => self.on_signature_requested(process),
+            AdviceInjector::MapValueToStack { key_offset, .. } if !self.extensions.is_empty() => {
+                self.on_map_value_requested(process, key_offset);
+                self.adv_provider.set_advice(process, &injector)
+            },
+            // the operand stack is expected to be [KEY, ROOT, ...]
+            AdviceInjector::SmtGet | AdviceInjector::SmtPeek if !self.lazy_maps.is_empty() => {
+                self.on_smt_leaf_requested(process, 0)?;
+                self.adv_provider.set_advice(process, &injector)
+            },
+            // the operand stack is expected to be [VALUE, KEY, ROOT, ...]
+            AdviceInjector::SmtSet if !self.lazy_maps.is_empty() => {
+                let map_index = self.on_smt_leaf_requested(process, 4)?;
+                let response = self.adv_provider.set_advice(process, &injector)?;
+                if let Some(map_index) = map_index {
+                    let value = read_word(process, 0);
+                    let key = Digest::from(read_word(process, 4));
+                    self.lazy_maps[map_index]
+                        .insert(key, value)
+                        .map_err(|err| ExecutionError::EventError(err.to_string()))?;
+                }
+                Ok(response)
+            },
             injector => self.adv_provider.set_advice(process, &injector),
         }
     }
@@ -488,6 +800,12 @@ impl<A: AdviceProvider> Host for TransactionHost<A> {
         process: &S,
         event_id: u32,
     ) -> Result<HostResponse, ExecutionError> {
+        // events outside of the range reserved for the transaction kernel are handled by the
+        // extensions of this host
+        if event_id >> 16 != EVENT_ID_PREFIX {
+            return self.on_custom_event(process, event_id);
+        }
+
         let event = TransactionEvent::try_from(event_id)
             .map_err(|err| ExecutionError::EventError(err.to_string()))?;
 
@@ -497,6 +815,8 @@ impl<A: AdviceProvider> Host for TransactionHost<A> {
             )));
         }
 
+        self.events.push(event.clone());
+
         match event {
             TransactionEvent::AccountVaultBeforeAddAsset => Ok(()),
             TransactionEvent::AccountVaultAfterAddAsset => {
@@ -557,6 +877,9 @@ impl<A: AdviceProvider> Host for TransactionHost<A> {
                     "Note execution interval measurement is incorrect: check the placement of the start and the end of the interval",
                 );
                 self.tx_progress.start_note_execution(process.clk(), note_id);
+                if let Some(diagnostics) = self.diagnostics.as_mut() {
+                    diagnostics.start_note_execution(note_id);
+                }
             },
             NoteExecutionEnd => self.tx_progress.end_note_execution(process.clk()),
             TxScriptProcessingStart => self.tx_progress.start_tx_script_processing(process.clk()),
@@ -565,14 +888,30 @@ impl<A: AdviceProvider> Host for TransactionHost<A> {
             EpilogueEnd => self.tx_progress.end_epilogue(process.clk()),
         }
 
+        if self.stop_after_notes && event == NotesProcessingEnd {
+            return Err(ExecutionError::EventError(
+                "execution stopped after processing the input notes".to_string(),
+            ));
+        }
+
+        if let Some(diagnostics) = self.diagnostics.as_mut() {
+            match event {
+                PrologueEnd => diagnostics.end_stage(ExecutionStage::Prologue, process),
+                NoteExecutionEnd => diagnostics.end_note_execution(process),
+                TxScriptProcessingEnd => {
+                    diagnostics.end_stage(ExecutionStage::TransactionScript, process)
+                },
+                EpilogueEnd => diagnostics.end_stage(ExecutionStage::Epilogue, process),
+                _ => (),
+            }
+        }
+
         Ok(HostResponse::None)
     }
 
     fn on_assert_failed<S: ProcessState>(&mut self, process: &S, err_code: u32) -> ExecutionError {
-        let err_msg = self
-            .error_messages
-            .get(&err_code)
-            .map_or("Unknown error".to_string(), |msg| msg.to_string());
+        let err_msg = TransactionKernelError::from_failed_assertion(process.clk(), err_code)
+            .map_or("Unknown error".to_string(), |err| err.to_string());
         ExecutionError::FailedAssertion {
             clk: process.clk(),
             err_code,
@@ -580,3 +919,17 @@ impl<A: AdviceProvider> Host for TransactionHost<A> {
         }
     }
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the word located on the operand stack at the provided offset, where the first element
+/// of the word is the deepest one.
+fn read_word<S: ProcessState>(process: &S, offset: usize) -> Word {
+    [
+        process.get_stack_item(offset + 3),
+        process.get_stack_item(offset + 2),
+        process.get_stack_item(offset + 1),
+        process.get_stack_item(offset),
+    ]
+}
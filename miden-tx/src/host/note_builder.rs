@@ -113,6 +113,19 @@ impl OutputNoteBuilder {
         })
     }
 
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the metadata of the note.
+    pub fn metadata(&self) -> &NoteMetadata {
+        &self.metadata
+    }
+
+    /// Returns the digest of the note's recipient.
+    pub fn recipient_digest(&self) -> Digest {
+        self.recipient_digest
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -132,6 +132,11 @@ impl CycleInterval {
         self.end = Some(e);
     }
 
+    /// Returns the cycle at which the interval ended, or None if it has not ended yet.
+    pub fn end(&self) -> Option<RowIndex> {
+        self.end
+    }
+
     /// Calculate the length of the interval
     pub fn len(&self) -> usize {
         if let Some(start) = self.start {
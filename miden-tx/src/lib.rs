@@ -6,32 +6,93 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+// Rayon-backed proving and batch verification spawn worker threads, which are only available on
+// `wasm32` when the module is built with shared memory and the `wasm-bindgen-rayon` feature.
+#[cfg(all(feature = "concurrent", target_family = "wasm", not(target_feature = "atomics")))]
+compile_error!(
+    "the `concurrent` feature requires the `atomics` target feature when targeting wasm; \
+     build without it or enable thread support via the `wasm-bindgen-rayon` feature"
+);
+
+#[cfg(all(
+    feature = "concurrent",
+    target_family = "wasm",
+    not(feature = "wasm-bindgen-rayon")
+))]
+compile_error!(
+    "the `concurrent` feature requires the `wasm-bindgen-rayon` feature when targeting wasm, \
+     which provides the thread pool used by Rayon"
+);
+
+/// Initializes the Rayon thread pool backed by Web Workers.
+///
+/// This is exported to JavaScript as `initThreadPool`, which must be awaited before proving a
+/// transaction or verifying transactions in parallel.
+#[cfg(all(feature = "wasm-bindgen-rayon", target_family = "wasm"))]
+pub use wasm_bindgen_rayon::init_thread_pool;
+
+#[cfg(feature = "executor")]
 pub use miden_objects::transaction::TransactionInputs;
 
+#[cfg(feature = "executor")]
 mod executor;
-pub use executor::{DataStore, TransactionExecutor, TransactionMastStore};
+#[cfg(feature = "executor")]
+pub use executor::{
+    BlockClock, DataStore, ExecutorOptions, LocalTransactionChain, NoteConsumability,
+    NoteConsumptionChecker, ScriptCache, ScriptCacheStats, TransactionClock, TransactionExecutor,
+    TransactionMastStore, TransactionRng, TransactionSimulation,
+};
 
+#[cfg(feature = "executor")]
 pub mod host;
-pub use host::{TransactionHost, TransactionProgress};
+#[cfg(feature = "executor")]
+pub use host::{
+    ExecutionDiagnostics, ExecutionStage, HostExtension, StageSnapshot, TransactionHost,
+    TransactionProgress,
+};
 
+#[cfg(feature = "executor")]
 mod prover;
-pub use prover::{LocalTransactionProver, ProvingOptions, TransactionProver};
+#[cfg(feature = "executor")]
+pub use prover::{
+    HashFunction, LocalTransactionProver, ProofSecurityLevel, ProvingOptions, ProvingProgress,
+    ProvingStage, TransactionProver,
+};
 
 mod verifier;
 pub use verifier::TransactionVerifier;
 
+#[cfg(feature = "executor")]
+mod batch;
+#[cfg(feature = "executor")]
+pub use batch::LocalBatchProver;
+
+#[cfg(feature = "executor")]
+mod block;
+#[cfg(feature = "executor")]
+pub use block::LocalBlockProver;
+
+#[cfg(feature = "executor")]
+mod request;
+#[cfg(feature = "executor")]
+pub use request::{ConsumptionPlanner, ProcedureCall, TransactionRequest};
+
 mod errors;
+pub use errors::TransactionVerifierError;
+#[cfg(feature = "executor")]
 pub use errors::{
-    AuthenticationError, DataStoreError, TransactionExecutorError, TransactionProverError,
-    TransactionVerifierError,
+    AuthenticationError, BatchProverError, BlockProverError, DataStoreError, HostExtensionError,
+    TransactionChainError, TransactionExecutorError, TransactionProverError,
+    TransactionRequestError,
 };
 
+#[cfg(feature = "executor")]
 pub mod auth;
 
-#[cfg(any(feature = "testing", test))]
+#[cfg(all(feature = "executor", any(feature = "testing", test)))]
 pub mod testing;
 
-#[cfg(test)]
+#[cfg(all(feature = "executor", test))]
 mod tests;
 
 // RE-EXPORTS
@@ -4,18 +4,24 @@ use alloc::{sync::Arc, vec::Vec};
 
 use miden_lib::transaction::TransactionKernel;
 use miden_objects::{
-    accounts::delta::AccountUpdateDetails,
+    accounts::delta::{AccountUpdateDetails, CompactAccountDelta},
     assembly::Library,
     transaction::{OutputNote, ProvenTransaction, ProvenTransactionBuilder, TransactionWitness},
 };
 use miden_prover::prove;
-pub use miden_prover::ProvingOptions;
+pub use miden_prover::{HashFunction, ProvingOptions};
 use vm_processor::MemAdviceProvider;
 use winter_maybe_async::*;
 
 use super::{TransactionHost, TransactionProverError};
 use crate::executor::TransactionMastStore;
 
+mod options;
+pub use options::ProofSecurityLevel;
+
+mod progress;
+pub use progress::{ProvingProgress, ProvingStage};
+
 // TRANSACTION PROVER TRAIT
 // ================================================================================================
 
@@ -45,6 +51,8 @@ pub trait TransactionProver {
 pub struct LocalTransactionProver {
     mast_store: Arc<TransactionMastStore>,
     proof_options: ProvingOptions,
+    compact_account_updates: bool,
+    progress: Option<Arc<dyn ProvingProgress>>,
 }
 
 impl LocalTransactionProver {
@@ -53,9 +61,47 @@ impl LocalTransactionProver {
         Self {
             mast_store: Arc::new(TransactionMastStore::new()),
             proof_options,
+            compact_account_updates: false,
+            progress: None,
         }
     }
 
+    /// Creates a new [LocalTransactionProver] instance which generates proofs with the specified
+    /// security level, using the default parameters of this level and the specified hash function.
+    ///
+    /// # Errors
+    /// Returns an error if the hash function does not support the specified security level.
+    pub fn with_security_level(
+        security_level: ProofSecurityLevel,
+        hash_fn: HashFunction,
+    ) -> Result<Self, TransactionProverError> {
+        Ok(Self::new(security_level.proving_options(hash_fn)?))
+    }
+
+    /// Configures the prover to describe updates of existing public accounts via
+    /// [AccountUpdateDetails::CompactDelta] rather than [AccountUpdateDetails::Delta].
+    ///
+    /// Compact deltas additionally contain openings of the changed account state, which allow
+    /// verifying the update against the initial account state commitment alone.
+    pub fn with_compact_account_updates(mut self) -> Self {
+        self.compact_account_updates = true;
+        self
+    }
+
+    /// Configures the prover to report the progress of proving each transaction to the provided
+    /// callback.
+    ///
+    /// This allows, e.g., showing a progress bar while a transaction is being proven.
+    pub fn with_progress(mut self, progress: impl ProvingProgress + 'static) -> Self {
+        self.progress = Some(Arc::new(progress));
+        self
+    }
+
+    /// Returns the options used by this prover to generate proofs.
+    pub fn proof_options(&self) -> &ProvingOptions {
+        &self.proof_options
+    }
+
     /// Loads the provided library code into the internal MAST forest store.
     ///
     /// TODO: this is a work-around to support accounts which were complied with user-defined
@@ -63,24 +109,26 @@ impl LocalTransactionProver {
     pub fn load_library(&mut self, library: &Library) {
         self.mast_store.insert(library.mast_forest().clone());
     }
-}
 
-impl Default for LocalTransactionProver {
-    fn default() -> Self {
-        Self {
-            mast_store: Arc::new(TransactionMastStore::new()),
-            proof_options: Default::default(),
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Reports the start of the specified stage to the progress callback, if one is configured.
+    fn report_progress(&self, stage: ProvingStage) {
+        if let Some(progress) = &self.progress {
+            progress.on_progress(stage);
         }
     }
-}
 
-#[maybe_async_trait]
-impl TransactionProver for LocalTransactionProver {
+    /// Proves the transaction of the provided witness, reporting the start of each stage except
+    /// the final one to the progress callback.
     #[maybe_async]
-    fn prove(
+    fn prove_witness(
         &self,
         tx_witness: TransactionWitness,
     ) -> Result<ProvenTransaction, TransactionProverError> {
+        self.report_progress(ProvingStage::PreparingInputs);
+
         let TransactionWitness {
             tx_inputs,
             tx_args,
@@ -112,8 +160,11 @@ impl TransactionProver for LocalTransactionProver {
             None,
             account_codes.iter().map(|c| c.commitment()).collect(),
         )
-        .map_err(TransactionProverError::TransactionHostCreationFailed)?;
+        .map_err(TransactionProverError::TransactionHostCreationFailed)?
+        // advice provided by host extensions during execution is recorded in the witness
+        .with_unhandled_events_skipped();
 
+        self.report_progress(ProvingStage::GeneratingProof);
         let (stack_outputs, proof) = maybe_await!(prove(
             &TransactionKernel::main(),
             stack_inputs,
@@ -122,6 +173,8 @@ impl TransactionProver for LocalTransactionProver {
         ))
         .map_err(TransactionProverError::TransactionProgramExecutionFailed)?;
 
+        self.report_progress(ProvingStage::BuildingProvenTransaction);
+
         // extract transaction outputs and process transaction data
         let (advice_provider, account_delta, output_notes, _signatures, _tx_progress) =
             host.into_parts();
@@ -141,6 +194,7 @@ impl TransactionProver for LocalTransactionProver {
             tx_outputs.expiration_block_num,
             proof,
         )
+        .fee(tx_outputs.fee)
         .add_input_notes(input_notes)
         .add_output_notes(output_notes);
 
@@ -153,6 +207,11 @@ impl TransactionProver for LocalTransactionProver {
                         .map_err(TransactionProverError::InvalidAccountDelta)?;
 
                     AccountUpdateDetails::New(account)
+                } else if self.compact_account_updates {
+                    let delta = CompactAccountDelta::new(account, account_delta)
+                        .map_err(TransactionProverError::InvalidCompactAccountDelta)?;
+
+                    AccountUpdateDetails::CompactDelta(delta)
                 } else {
                     AccountUpdateDetails::Delta(account_delta)
                 };
@@ -165,3 +224,31 @@ impl TransactionProver for LocalTransactionProver {
         builder.build().map_err(TransactionProverError::ProvenTransactionError)
     }
 }
+
+impl Default for LocalTransactionProver {
+    fn default() -> Self {
+        Self {
+            mast_store: Arc::new(TransactionMastStore::new()),
+            proof_options: Default::default(),
+            compact_account_updates: false,
+            progress: None,
+        }
+    }
+}
+
+#[maybe_async_trait]
+impl TransactionProver for LocalTransactionProver {
+    #[maybe_async]
+    fn prove(
+        &self,
+        tx_witness: TransactionWitness,
+    ) -> Result<ProvenTransaction, TransactionProverError> {
+        let result = maybe_await!(self.prove_witness(tx_witness));
+        match result {
+            Ok(_) => self.report_progress(ProvingStage::Finished),
+            Err(_) => self.report_progress(ProvingStage::Failed),
+        }
+
+        result
+    }
+}
@@ -0,0 +1,129 @@
+use alloc::string::ToString;
+use core::fmt;
+
+use miden_prover::{HashFunction, ProvingOptions};
+
+use crate::TransactionProverError;
+
+// PROOF SECURITY LEVEL
+// ================================================================================================
+
+/// The conjectured security level targeted by transaction proofs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ProofSecurityLevel {
+    /// 96 bits of conjectured security, which is the minimum accepted by the protocol.
+    Bits96,
+    /// 128 bits of conjectured security.
+    Bits128,
+}
+
+impl ProofSecurityLevel {
+    /// The smallest supported blowup factor.
+    pub const MIN_BLOWUP_FACTOR: usize = 8;
+
+    /// The largest supported blowup factor.
+    pub const MAX_BLOWUP_FACTOR: usize = 128;
+
+    /// The grinding factor used for proofs with 96 bits of security.
+    const GRINDING_FACTOR_96_BITS: u32 = 16;
+
+    /// The blowup factor used for proofs with 128 bits of security.
+    const BLOWUP_FACTOR_128_BITS: usize = 16;
+
+    /// Returns the number of bits of conjectured security of this level.
+    pub const fn bits(&self) -> u32 {
+        match self {
+            Self::Bits96 => 96,
+            Self::Bits128 => 128,
+        }
+    }
+
+    /// Returns the default [ProvingOptions] for this security level, using the specified hash
+    /// function.
+    ///
+    /// # Errors
+    /// Returns an error if the hash function cannot be used for proofs with this security level
+    /// (see [ProofSecurityLevel::proving_options_with_blowup()]).
+    pub fn proving_options(
+        &self,
+        hash_fn: HashFunction,
+    ) -> Result<ProvingOptions, TransactionProverError> {
+        let blowup_factor = match self {
+            Self::Bits96 => Self::MIN_BLOWUP_FACTOR,
+            Self::Bits128 => Self::BLOWUP_FACTOR_128_BITS,
+        };
+        self.proving_options_with_blowup(blowup_factor, hash_fn)
+    }
+
+    /// Returns [ProvingOptions] for this security level, using the specified blowup factor and
+    /// hash function.
+    ///
+    /// For 96-bit security, a larger blowup factor reduces the number of FRI queries (and thus the
+    /// proof size) at the cost of a longer proving time.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The blowup factor is not a power of two between [Self::MIN_BLOWUP_FACTOR] and
+    ///   [Self::MAX_BLOWUP_FACTOR].
+    /// - 128-bit security is requested with a blowup factor other than 16, or with a hash function
+    ///   other than BLAKE3-256 or RPO, which are the only configurations supported by the prover.
+    pub fn proving_options_with_blowup(
+        &self,
+        blowup_factor: usize,
+        hash_fn: HashFunction,
+    ) -> Result<ProvingOptions, TransactionProverError> {
+        if !blowup_factor.is_power_of_two()
+            || !(Self::MIN_BLOWUP_FACTOR..=Self::MAX_BLOWUP_FACTOR).contains(&blowup_factor)
+        {
+            return Err(invalid_options(format!(
+                "blowup factor must be a power of two between {} and {}, but was {blowup_factor}",
+                Self::MIN_BLOWUP_FACTOR,
+                Self::MAX_BLOWUP_FACTOR
+            )));
+        }
+
+        match self {
+            Self::Bits96 => {
+                // each FRI query contributes log2(blowup_factor) bits of conjectured security
+                let query_security = Self::Bits96.bits() - Self::GRINDING_FACTOR_96_BITS;
+                let num_queries = query_security.div_ceil(blowup_factor.ilog2()) as usize;
+
+                Ok(ProvingOptions::new(
+                    num_queries,
+                    blowup_factor,
+                    Self::GRINDING_FACTOR_96_BITS,
+                    hash_fn,
+                ))
+            },
+            Self::Bits128 => {
+                if blowup_factor != Self::BLOWUP_FACTOR_128_BITS {
+                    return Err(invalid_options(format!(
+                        "128-bit security requires a blowup factor of {}, but was {blowup_factor}",
+                        Self::BLOWUP_FACTOR_128_BITS
+                    )));
+                }
+
+                match hash_fn {
+                    HashFunction::Blake3_256 => Ok(ProvingOptions::with_128_bit_security(false)),
+                    HashFunction::Rpo256 => Ok(ProvingOptions::with_128_bit_security(true)),
+                    _ => Err(invalid_options(format!(
+                        "hash function {hash_fn:?} does not support 128-bit security"
+                    ))),
+                }
+            },
+        }
+    }
+}
+
+impl fmt::Display for ProofSecurityLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-bit", self.bits())
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn invalid_options(msg: impl ToString) -> TransactionProverError {
+    TransactionProverError::InvalidProvingOptions(msg.to_string())
+}
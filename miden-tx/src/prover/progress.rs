@@ -0,0 +1,63 @@
+use core::fmt;
+
+// PROVING STAGE
+// ================================================================================================
+
+/// A stage of proving a transaction, as reported to a [ProvingProgress] callback.
+///
+/// The underlying STARK prover does not expose its internal phases (i.e., trace generation, trace
+/// low-degree extension, constraint evaluation and FRI), and thus, all of them are covered by
+/// [ProvingStage::GeneratingProof]. Since this stage dominates the proving time and its progress
+/// cannot be observed, no completion percentage is reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProvingStage {
+    /// The inputs of the transaction kernel are being prepared from the transaction witness.
+    PreparingInputs,
+    /// The transaction kernel is being executed and its execution is being proven.
+    GeneratingProof,
+    /// The outputs of the transaction are being extracted and the proven transaction is being
+    /// built.
+    BuildingProvenTransaction,
+    /// The transaction has been proven.
+    Finished,
+    /// Proving the transaction failed. No further stages are reported.
+    Failed,
+}
+
+impl fmt::Display for ProvingStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PreparingInputs => write!(f, "preparing inputs"),
+            Self::GeneratingProof => write!(f, "generating proof"),
+            Self::BuildingProvenTransaction => write!(f, "building proven transaction"),
+            Self::Finished => write!(f, "finished"),
+            Self::Failed => write!(f, "failed"),
+        }
+    }
+}
+
+// PROVING PROGRESS
+// ================================================================================================
+
+/// A callback which is notified by a [LocalTransactionProver](super::LocalTransactionProver) when
+/// it enters a new [ProvingStage].
+///
+/// The callback is invoked on the thread which proves the transaction, and thus should return
+/// quickly (e.g., by forwarding the progress to a UI thread).
+///
+/// Every proving run ends with either [ProvingStage::Finished] or [ProvingStage::Failed].
+///
+/// This trait is implemented for all closures accepting the stage.
+pub trait ProvingProgress: Send + Sync {
+    /// Reports that proving has entered the specified stage.
+    fn on_progress(&self, stage: ProvingStage);
+}
+
+impl<F> ProvingProgress for F
+where
+    F: Fn(ProvingStage) + Send + Sync,
+{
+    fn on_progress(&self, stage: ProvingStage) {
+        self(stage)
+    }
+}
@@ -0,0 +1,393 @@
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::String,
+    vec::Vec,
+};
+
+use miden_lib::transaction::TransactionScriptBuilder;
+use miden_objects::{
+    accounts::AccountId,
+    assembly::Assembler,
+    assets::Asset,
+    notes::{NoteArgs, NoteId},
+    transaction::{ExecutedTransaction, OutputNote, TransactionArgs, TransactionScript},
+    vm::AdviceMap,
+    Digest, Felt, TransactionScriptError,
+};
+
+use crate::{ScriptCache, TransactionRequestError};
+
+mod planner;
+pub use planner::ConsumptionPlanner;
+
+// PROCEDURE CALL
+// ================================================================================================
+
+/// A call to an account procedure with the specified arguments.
+///
+/// The arguments are placed onto the stack right before the procedure is called, such that the
+/// first argument ends up on top of the stack. Any values returned by the procedure are dropped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcedureCall {
+    path: String,
+    args: Vec<Felt>,
+}
+
+impl ProcedureCall {
+    /// The maximum number of arguments which can be passed to a procedure.
+    pub const MAX_ARGS: usize = TransactionScriptBuilder::MAX_PROCEDURE_ARGS;
+
+    /// Returns a new [ProcedureCall] of the procedure with the specified fully-qualified path
+    /// (e.g., `::miden::contracts::wallets::basic::receive_asset`).
+    ///
+    /// # Errors
+    /// Returns an error if more than [Self::MAX_ARGS] arguments are provided.
+    pub fn new(
+        path: impl Into<String>,
+        args: impl IntoIterator<Item = Felt>,
+    ) -> Result<Self, TransactionRequestError> {
+        let args: Vec<Felt> = args.into_iter().collect();
+        if args.len() > Self::MAX_ARGS {
+            return Err(TransactionRequestError::TooManyProcedureArguments(args.len()));
+        }
+
+        Ok(Self { path: path.into(), args })
+    }
+
+    /// Returns the path of the called procedure.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Returns the arguments of the call.
+    pub fn args(&self) -> &[Felt] {
+        &self.args
+    }
+}
+
+// TRANSACTION REQUEST
+// ================================================================================================
+
+/// A declarative description of a transaction to be executed against a single account.
+///
+/// A transaction request specifies:
+/// - The notes to be consumed by the transaction, optionally with [NoteArgs] which are placed onto
+///   the stack right before the script of the note is executed.
+/// - The notes to be created by the transaction. Output notes are created using the procedures of
+///   the basic wallet, and thus the account must export `create_note` and `move_asset_to_note`
+///   procedures of [miden_lib::accounts::wallets::BasicWallet]. For [OutputNote::Full] notes, the
+///   note details are added to the advice map, while for [OutputNote::Partial] notes only the
+///   recipient digest is needed.
+/// - Calls to account procedures with their arguments.
+/// - Assets which are expected to be sent out via the output notes of the transaction.
+/// - Optionally, an expiration delta, i.e., the number of blocks after the reference block of the
+///   transaction at which it expires.
+///
+/// The request is compiled into [TransactionArgs] via [Self::build_transaction_args()]. The
+/// compiled transaction script first creates all output notes and then executes the procedure
+/// calls in the order in which they were added. Thus, procedures which must be executed last
+/// (e.g., authentication procedures which increment the account nonce) should be added last.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionRequest {
+    account_id: AccountId,
+    input_notes: Vec<(NoteId, Option<NoteArgs>)>,
+    output_notes: Vec<OutputNote>,
+    procedure_calls: Vec<ProcedureCall>,
+    expected_output_assets: Vec<Asset>,
+    advice_map: BTreeMap<Digest, Vec<Felt>>,
+    expiration_delta: Option<u16>,
+}
+
+impl TransactionRequest {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new, empty [TransactionRequest] for the specified account.
+    pub fn new(account_id: AccountId) -> Self {
+        Self {
+            account_id,
+            input_notes: Vec::new(),
+            output_notes: Vec::new(),
+            procedure_calls: Vec::new(),
+            expected_output_assets: Vec::new(),
+            advice_map: BTreeMap::new(),
+            expiration_delta: None,
+        }
+    }
+
+    // BUILDER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Adds a note to be consumed by the transaction, optionally with note arguments.
+    ///
+    /// Notes consumed without note arguments receive [NoteArgs::EMPTY].
+    pub fn with_input_note(mut self, note_id: NoteId, note_args: Option<NoteArgs>) -> Self {
+        self.input_notes.push((note_id, note_args));
+        self
+    }
+
+    /// Adds a note to be created by the transaction.
+    pub fn with_output_note(mut self, note: OutputNote) -> Self {
+        self.output_notes.push(note);
+        self
+    }
+
+    /// Adds a call to an account procedure.
+    pub fn with_procedure_call(mut self, call: ProcedureCall) -> Self {
+        self.procedure_calls.push(call);
+        self
+    }
+
+    /// Adds an asset which is expected to be sent out via the output notes of the transaction.
+    pub fn with_expected_output_asset(mut self, asset: Asset) -> Self {
+        self.expected_output_assets.push(asset);
+        self
+    }
+
+    /// Adds the provided entries to the advice map of the transaction.
+    pub fn with_advice_map(
+        mut self,
+        entries: impl IntoIterator<Item = (Digest, Vec<Felt>)>,
+    ) -> Self {
+        self.advice_map.extend(entries);
+        self
+    }
+
+    /// Sets the expiration delta of the transaction, such that it expires `delta` blocks after its
+    /// reference block and can only be included into blocks before that.
+    ///
+    /// # Errors
+    /// Returns an error if the delta is 0.
+    pub fn with_expiration_delta(mut self, delta: u16) -> Result<Self, TransactionRequestError> {
+        if delta == 0 {
+            return Err(TransactionRequestError::InvalidExpirationDelta(delta));
+        }
+
+        self.expiration_delta = Some(delta);
+        Ok(self)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of the account against which the transaction is executed.
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the IDs of the notes to be consumed by the transaction.
+    pub fn input_note_ids(&self) -> Vec<NoteId> {
+        self.input_notes.iter().map(|(note_id, _)| *note_id).collect()
+    }
+
+    /// Returns the args with which the specified note is consumed, if any were provided.
+    pub fn note_args(&self, note_id: NoteId) -> Option<NoteArgs> {
+        self.input_notes
+            .iter()
+            .find(|(id, _)| *id == note_id)
+            .and_then(|(_, note_args)| *note_args)
+    }
+
+    /// Returns the notes to be created by the transaction.
+    pub fn output_notes(&self) -> &[OutputNote] {
+        &self.output_notes
+    }
+
+    /// Returns the account procedure calls of the transaction.
+    pub fn procedure_calls(&self) -> &[ProcedureCall] {
+        &self.procedure_calls
+    }
+
+    /// Returns the assets which are expected to be sent out via the output notes.
+    pub fn expected_output_assets(&self) -> &[Asset] {
+        &self.expected_output_assets
+    }
+
+    /// Returns the expiration delta of the transaction, if set.
+    pub fn expiration_delta(&self) -> Option<u16> {
+        self.expiration_delta
+    }
+
+    // COMPILATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the source code of the transaction script for this request, or `None` if the
+    /// request neither creates notes, nor calls account procedures, nor sets an expiration delta.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The request contains duplicate output notes.
+    /// - The request contains [OutputNote::Header] notes, which do not contain enough details to be
+    ///   created.
+    pub fn script_source(&self) -> Result<Option<String>, TransactionRequestError> {
+        if self.output_notes.is_empty()
+            && self.procedure_calls.is_empty()
+            && self.expiration_delta.is_none()
+        {
+            return Ok(None);
+        }
+
+        let mut seen_notes = BTreeSet::new();
+        let mut builder = TransactionScriptBuilder::new();
+        if let Some(delta) = self.expiration_delta {
+            builder = builder
+                .with_expiration_delta(delta)
+                .expect("expiration delta should be validated on construction");
+        }
+
+        for note in self.output_notes.iter() {
+            if !seen_notes.insert(note.id()) {
+                return Err(TransactionRequestError::DuplicateOutputNote(note.id()));
+            }
+            let (recipient, assets) = match note {
+                OutputNote::Full(note) => (note.recipient().digest(), note.assets()),
+                OutputNote::Partial(note) => (note.recipient_digest(), note.assets()),
+                OutputNote::Header(note) => {
+                    return Err(TransactionRequestError::InvalidOutputNote(note.id()))
+                },
+            };
+            builder = builder.create_note(recipient, *note.metadata(), assets.iter().copied());
+        }
+
+        for call in self.procedure_calls.iter() {
+            builder = builder
+                .call_procedure(call.path.clone(), call.args.iter().copied())
+                .expect("number of procedure call arguments should be validated on construction");
+        }
+
+        Ok(Some(builder.source()))
+    }
+
+    /// Compiles this request into [TransactionArgs] using the provided assembler.
+    ///
+    /// The resulting arguments can be passed to [crate::TransactionExecutor::execute_transaction()]
+    /// together with [Self::account_id()] and [Self::input_note_ids()].
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The request contains duplicate input or output notes.
+    /// - The request contains [OutputNote::Header] notes.
+    /// - The transaction script fails to compile.
+    pub fn build_transaction_args(
+        &self,
+        assembler: Assembler,
+    ) -> Result<TransactionArgs, TransactionRequestError> {
+        self.build_transaction_args_inner(|source| {
+            TransactionScript::compile(source, [], assembler)
+        })
+    }
+
+    /// Compiles this request into [TransactionArgs] like [Self::build_transaction_args()], but
+    /// takes the transaction script from the provided [ScriptCache] if a script with the same
+    /// source was compiled before. Scripts are compiled with the assembler of the cache.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The request contains duplicate input or output notes.
+    /// - The request contains [OutputNote::Header] notes.
+    /// - The transaction script fails to compile.
+    pub fn build_transaction_args_with_cache(
+        &self,
+        script_cache: &ScriptCache,
+    ) -> Result<TransactionArgs, TransactionRequestError> {
+        self.build_transaction_args_inner(|source| {
+            script_cache.compile_tx_script(&source, Vec::new())
+        })
+    }
+
+    // VALIDATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Verifies that the provided executed transaction fulfills this request.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The transaction was executed against another account.
+    /// - Any of the requested output notes was not created by the transaction.
+    /// - The output notes of the transaction do not contain all expected output assets. For
+    ///   fungible assets, the total amount sent out across all output notes must be at least the
+    ///   expected amount.
+    pub fn verify_executed_transaction(
+        &self,
+        executed_tx: &ExecutedTransaction,
+    ) -> Result<(), TransactionRequestError> {
+        if executed_tx.account_id() != self.account_id {
+            return Err(TransactionRequestError::AccountMismatch {
+                expected: self.account_id,
+                actual: executed_tx.account_id(),
+            });
+        }
+
+        let output_notes = executed_tx.output_notes();
+        for note in self.output_notes.iter() {
+            if !output_notes.iter().any(|output_note| output_note.id() == note.id()) {
+                return Err(TransactionRequestError::MissingOutputNote(note.id()));
+            }
+        }
+
+        let output_assets: Vec<&Asset> = output_notes
+            .iter()
+            .filter_map(|note| note.assets())
+            .flat_map(|a| a.iter())
+            .collect();
+        for expected_asset in self.expected_output_assets.iter() {
+            let is_sent = match expected_asset {
+                Asset::Fungible(expected) => {
+                    let total_amount: u64 = output_assets
+                        .iter()
+                        .filter_map(|asset| match asset {
+                            Asset::Fungible(asset) if asset.is_from_same_faucet(expected) => {
+                                Some(asset.amount())
+                            },
+                            _ => None,
+                        })
+                        .sum();
+                    total_amount >= expected.amount()
+                },
+                Asset::NonFungible(_) => output_assets.contains(&expected_asset),
+            };
+
+            if !is_sent {
+                return Err(TransactionRequestError::ExpectedOutputAssetMissing(*expected_asset));
+            }
+        }
+
+        Ok(())
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    fn build_transaction_args_inner(
+        &self,
+        compile: impl FnOnce(String) -> Result<TransactionScript, TransactionScriptError>,
+    ) -> Result<TransactionArgs, TransactionRequestError> {
+        let mut seen_notes = BTreeSet::new();
+        for (note_id, _) in self.input_notes.iter() {
+            if !seen_notes.insert(*note_id) {
+                return Err(TransactionRequestError::DuplicateInputNote(*note_id));
+            }
+        }
+
+        let tx_script = self
+            .script_source()?
+            .map(compile)
+            .transpose()
+            .map_err(TransactionRequestError::ScriptCompilationFailed)?;
+
+        let mut tx_args = TransactionArgs::new(tx_script, None, AdviceMap::default());
+        for (note_id, note_args) in self.input_notes.iter() {
+            if let Some(note_args) = note_args {
+                tx_args.add_note_args(*note_id, *note_args);
+            }
+        }
+        tx_args.extend_advice_map(self.advice_map.clone());
+        for note in self.output_notes.iter() {
+            if let OutputNote::Full(note) = note {
+                tx_args.add_expected_output_note(note);
+            }
+        }
+
+        Ok(tx_args)
+    }
+}
@@ -0,0 +1,211 @@
+use alloc::{collections::BTreeSet, vec::Vec};
+
+use miden_lib::transaction::TransactionKernel;
+use miden_objects::{
+    accounts::AccountId,
+    notes::{Note, NoteId},
+    Digest,
+};
+
+use super::{ProcedureCall, TransactionRequest};
+use crate::TransactionRequestError;
+
+// CONSUMPTION PLANNER
+// ================================================================================================
+
+/// Splits a set of notes to be consumed by a single account into a sequence of transactions which
+/// respect the limits of a transaction.
+///
+/// Every planned transaction consumes at most [Self::max_notes_per_tx()] notes, and adds at most
+/// [Self::max_assets_per_tx()] distinct assets to the account vault. Fungible assets issued by the
+/// same faucet count as a single asset, since they are merged in the vault.
+///
+/// Notes are assigned to the first planned transaction which can accommodate them, such that the
+/// number of transactions is minimal if only the note limit is binding. Every planned transaction
+/// ends with the procedure calls added via [Self::with_procedure_call()], e.g., to authenticate
+/// the transaction.
+#[derive(Debug, Clone)]
+pub struct ConsumptionPlanner {
+    account_id: AccountId,
+    max_notes_per_tx: usize,
+    max_assets_per_tx: usize,
+    procedure_calls: Vec<ProcedureCall>,
+}
+
+impl ConsumptionPlanner {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The default maximum number of distinct assets added to the account vault by a single
+    /// planned transaction.
+    ///
+    /// A serialized entry of the vault delta takes at most 33 bytes, thus this limit keeps the
+    /// vault delta below half of [miden_objects::ACCOUNT_UPDATE_MAX_SIZE], which leaves room for
+    /// the remaining account updates.
+    pub const DEFAULT_MAX_ASSETS_PER_TX: usize = 512;
+
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [ConsumptionPlanner] for the specified account which plans transactions
+    /// consuming up to [TransactionKernel::MAX_INPUT_NOTES] notes and adding up to
+    /// [Self::DEFAULT_MAX_ASSETS_PER_TX] assets each.
+    pub fn new(account_id: AccountId) -> Self {
+        Self {
+            account_id,
+            max_notes_per_tx: TransactionKernel::MAX_INPUT_NOTES,
+            max_assets_per_tx: Self::DEFAULT_MAX_ASSETS_PER_TX,
+            procedure_calls: Vec::new(),
+        }
+    }
+
+    // BUILDER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Sets the maximum number of notes consumed by a single planned transaction.
+    ///
+    /// # Errors
+    /// Returns an error if the limit is 0 or greater than [TransactionKernel::MAX_INPUT_NOTES].
+    pub fn with_max_notes_per_tx(
+        mut self,
+        max_notes_per_tx: usize,
+    ) -> Result<Self, TransactionRequestError> {
+        if max_notes_per_tx == 0 || max_notes_per_tx > TransactionKernel::MAX_INPUT_NOTES {
+            return Err(TransactionRequestError::InvalidMaxNotesPerTransaction(max_notes_per_tx));
+        }
+
+        self.max_notes_per_tx = max_notes_per_tx;
+        Ok(self)
+    }
+
+    /// Sets the maximum number of distinct assets added to the account vault by a single planned
+    /// transaction.
+    ///
+    /// # Errors
+    /// Returns an error if the limit is 0.
+    pub fn with_max_assets_per_tx(
+        mut self,
+        max_assets_per_tx: usize,
+    ) -> Result<Self, TransactionRequestError> {
+        if max_assets_per_tx == 0 {
+            return Err(TransactionRequestError::InvalidMaxAssetsPerTransaction(max_assets_per_tx));
+        }
+
+        self.max_assets_per_tx = max_assets_per_tx;
+        Ok(self)
+    }
+
+    /// Adds a call to an account procedure which is executed at the end of every planned
+    /// transaction.
+    pub fn with_procedure_call(mut self, call: ProcedureCall) -> Self {
+        self.procedure_calls.push(call);
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of the account which consumes the notes.
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the maximum number of notes consumed by a single planned transaction.
+    pub fn max_notes_per_tx(&self) -> usize {
+        self.max_notes_per_tx
+    }
+
+    /// Returns the maximum number of distinct assets added to the account vault by a single
+    /// planned transaction.
+    pub fn max_assets_per_tx(&self) -> usize {
+        self.max_assets_per_tx
+    }
+
+    // PLANNING
+    // --------------------------------------------------------------------------------------------
+
+    /// Splits the provided notes into a sequence of [TransactionRequest]s which consume all of the
+    /// notes and respect the limits of this planner.
+    ///
+    /// The requests are returned in the order in which they should be executed, and the notes of
+    /// every request are in the order in which they were provided.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The same note is provided more than once.
+    /// - A note contains more distinct assets than [Self::max_assets_per_tx()].
+    pub fn plan(&self, notes: &[Note]) -> Result<Vec<TransactionRequest>, TransactionRequestError> {
+        let mut seen_notes = BTreeSet::new();
+        let mut planned_txs: Vec<PlannedTransaction> = Vec::new();
+
+        for note in notes {
+            if !seen_notes.insert(note.id()) {
+                return Err(TransactionRequestError::DuplicateInputNote(note.id()));
+            }
+
+            let vault_keys: BTreeSet<Digest> =
+                note.assets().iter().map(|asset| asset.vault_key().into()).collect();
+            if vault_keys.len() > self.max_assets_per_tx {
+                return Err(TransactionRequestError::NoteExceedsAssetLimit {
+                    note_id: note.id(),
+                    num_assets: vault_keys.len(),
+                    max: self.max_assets_per_tx,
+                });
+            }
+
+            match planned_txs.iter_mut().find(|planned_tx| self.fits(planned_tx, &vault_keys)) {
+                Some(planned_tx) => planned_tx.add_note(note.id(), vault_keys),
+                None => {
+                    let mut planned_tx = PlannedTransaction::default();
+                    planned_tx.add_note(note.id(), vault_keys);
+                    planned_txs.push(planned_tx);
+                },
+            }
+        }
+
+        Ok(planned_txs
+            .into_iter()
+            .map(|planned_tx| {
+                let request = planned_tx
+                    .note_ids
+                    .into_iter()
+                    .fold(TransactionRequest::new(self.account_id), |request, note_id| {
+                        request.with_input_note(note_id, None)
+                    });
+
+                self.procedure_calls
+                    .iter()
+                    .cloned()
+                    .fold(request, |request, call| request.with_procedure_call(call))
+            })
+            .collect())
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns true if a note adding the provided assets to the vault can be added to the provided
+    /// planned transaction without exceeding the limits of this planner.
+    fn fits(&self, planned_tx: &PlannedTransaction, vault_keys: &BTreeSet<Digest>) -> bool {
+        planned_tx.note_ids.len() < self.max_notes_per_tx
+            && planned_tx.vault_keys.union(vault_keys).count() <= self.max_assets_per_tx
+    }
+}
+
+// PLANNED TRANSACTION
+// ================================================================================================
+
+/// The notes assigned to a single transaction by the [ConsumptionPlanner], together with the vault
+/// keys of the assets they add to the account vault.
+#[derive(Default)]
+struct PlannedTransaction {
+    note_ids: Vec<NoteId>,
+    vault_keys: BTreeSet<Digest>,
+}
+
+impl PlannedTransaction {
+    fn add_note(&mut self, note_id: NoteId, vault_keys: BTreeSet<Digest>) {
+        self.note_ids.push(note_id);
+        self.vault_keys.extend(vault_keys);
+    }
+}
@@ -1,3 +1,39 @@
+//! A mock chain which can be used to test transactions against a realistic chain state.
+//!
+//! [MockChain] keeps track of the accounts, notes, and nullifiers of a local chain, and produces
+//! blocks with the same structure as the blocks produced by the network: each sealed block commits
+//! to the account, nullifier, and note trees of the chain, and is appended to the chain MMR. This
+//! makes it possible to build [TransactionInputs] for any account known to the chain, with
+//! authenticated input notes and a valid [ChainMmr], and to execute transactions against them.
+//!
+//! A typical test creates accounts and notes, seals a block to make them available, builds a
+//! transaction context for one of the accounts, and applies the executed transaction back to the
+//! chain:
+//!
+//! ```ignore
+//! let mut chain = MockChain::new();
+//! let faucet = chain.add_existing_faucet(Auth::NoAuth, "POL", 1000);
+//! let sender = chain.add_existing_wallet(Auth::BasicAuth, vec![faucet.mint(100)]);
+//! let target = chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+//! let note = chain
+//!     .add_p2id_note(sender.id(), target.id(), &[faucet.mint(10)], NoteType::Public)
+//!     .unwrap();
+//! chain.seal_block(None);
+//! assert!(chain.available_notes().iter().any(|input_note| input_note.id() == note.id()));
+//!
+//! // all notes available on the chain are consumed by the transaction
+//! let executed_tx = chain
+//!     .build_tx_context(target.id())
+//!     .build()
+//!     .execute()
+//!     .unwrap();
+//! chain.add_executed_transaction(executed_tx);
+//! chain.seal_block(None);
+//! ```
+//!
+//! Blocks can also be sealed at a specific block number via [MockChain::seal_block()], e.g., to
+//! test notes and accounts which depend on the current block number.
+
 use alloc::{collections::BTreeMap, vec::Vec};
 use core::fmt;
 
@@ -12,15 +48,18 @@ use miden_objects::{
         AuthSecretKey,
     },
     assets::{Asset, FungibleAsset, TokenSymbol},
-    block::{compute_tx_hash, Block, BlockAccountUpdate, BlockNoteIndex, BlockNoteTree, NoteBatch},
+    block::{
+        compute_tx_hash, Block, BlockAccountUpdate, BlockNoteIndex, BlockNoteTree, NoteBatch,
+        NullifierTree,
+    },
     crypto::{
         dsa::rpo_falcon512::SecretKey,
-        merkle::{Mmr, MmrError, PartialMmr, Smt},
+        merkle::{Mmr, MmrError, PartialMmr},
     },
     notes::{Note, NoteId, NoteInclusionProof, NoteType, Nullifier},
     transaction::{
-        ChainMmr, ExecutedTransaction, InputNote, InputNotes, OutputNote, ToInputNoteCommitments,
-        TransactionId, TransactionInputs,
+        ChainMmr, ExecutedTransaction, ForeignAccountInputs, InputNote, InputNotes, OutputNote,
+        ToInputNoteCommitments, TransactionId, TransactionInputs,
     },
     AccountError, BlockHeader, FieldElement, NoteError, ACCOUNT_TREE_DEPTH,
 };
@@ -28,7 +67,7 @@ use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha20Rng;
 use vm_processor::{
     crypto::{RpoRandomCoin, SimpleSmt},
-    Digest, Felt, Word, ZERO,
+    Digest, Felt, Word,
 };
 
 use super::TransactionContextBuilder;
@@ -42,6 +81,7 @@ const TIMESTAMP_START: u32 = 1693348223;
 /// Timestamp of timestamp on each new block
 const TIMESTAMP_STEP: u32 = 10;
 
+/// The authenticator used by accounts created with [Auth::BasicAuth].
 pub type MockAuthenticator = BasicAuthenticator<ChaCha20Rng>;
 
 // MOCK FUNGIBLE FAUCET
@@ -51,10 +91,12 @@ pub type MockAuthenticator = BasicAuthenticator<ChaCha20Rng>;
 pub struct MockFungibleFaucet(Account);
 
 impl MockFungibleFaucet {
+    /// Returns the account of the faucet.
     pub fn account(&self) -> &Account {
         &self.0
     }
 
+    /// Returns a fungible asset of the specified amount issued by this faucet.
     pub fn mint(&self, amount: u64) -> Asset {
         FungibleAsset::new(self.0.id(), amount).unwrap().into()
     }
@@ -150,9 +192,12 @@ impl PendingObjects {
     }
 }
 
+/// Errors which can occur when modifying a [MockChain].
 #[derive(Debug)]
 pub enum MockError {
+    /// The same nullifier was added to the chain more than once.
     DuplicatedNullifier,
+    /// The same note was added to the chain more than once.
     DuplicatedNote,
 }
 
@@ -182,6 +227,14 @@ impl std::error::Error for MockError {}
 // ================================================================================================
 
 /// Structure chain data, used to build necessary openings and to construct [BlockHeader].
+///
+/// Objects added to the chain (accounts, notes, nullifiers, and executed transactions) are kept
+/// pending until the next block is sealed via [MockChain::seal_block()]. Sealing a block makes
+/// them available for use in subsequent transactions, e.g., via [MockChain::build_tx_context()]
+/// or [MockChain::get_transaction_inputs()].
+///
+/// Block numbers always match the positions of the blocks in the chain MMR, i.e., the chain
+/// contains a block for every block number up to the latest one.
 #[derive(Debug, Clone)]
 pub struct MockChain {
     /// An append-only structure used to represent the history of blocks produced for this chain.
@@ -191,7 +244,7 @@ pub struct MockChain {
     blocks: Vec<Block>,
 
     /// Tree containing the latest `Nullifier`'s tree.
-    nullifiers: Smt,
+    nullifiers: NullifierTree,
 
     /// Tree containing the latest hash of each account.
     accounts: SimpleSmt<ACCOUNT_TREE_DEPTH>,
@@ -223,11 +276,12 @@ impl MockChain {
     // CONSTRUCTORS
     // ----------------------------------------------------------------------------------------
 
+    /// Returns a new empty [MockChain] without any blocks.
     pub fn new() -> Self {
         Self {
             chain: Mmr::default(),
             blocks: vec![],
-            nullifiers: Smt::default(),
+            nullifiers: NullifierTree::new(),
             accounts: SimpleSmt::<ACCOUNT_TREE_DEPTH>::new().expect("depth too big for SimpleSmt"),
             pending_objects: PendingObjects::new(),
             available_notes: BTreeMap::new(),
@@ -236,6 +290,11 @@ impl MockChain {
         }
     }
 
+    /// Adds the effects of an [ExecutedTransaction] to the pending objects.
+    ///
+    /// This updates the state of the executing account, marks the input notes of the transaction
+    /// as consumed, and adds its output notes as a new batch of notes. A block has to be created
+    /// to finalize the new entities.
     pub fn add_executed_transaction(&mut self, transaction: ExecutedTransaction) {
         let mut account = transaction.initial_account().clone();
         account.apply_delta(transaction.account_delta()).unwrap();
@@ -305,6 +364,11 @@ impl MockChain {
     // OTHER IMPLEMENTATIONS
     // ================================================================================================
 
+    /// Adds a new [BasicWallet] account holding the specified assets to the pending objects, and
+    /// returns it.
+    ///
+    /// The account is new, i.e., its nonce is zero and it has to be deployed by its first
+    /// transaction. A block has to be created to finalize the new entity.
     pub fn add_new_wallet(&mut self, auth_method: Auth, assets: Vec<Asset>) -> Account {
         let account_builder = AccountBuilder::new()
             .init_seed(ChaCha20Rng::from_seed(Default::default()).gen())
@@ -314,6 +378,10 @@ impl MockChain {
         self.add_from_account_builder(auth_method, account_builder)
     }
 
+    /// Adds an existing [BasicWallet] account holding the specified assets to the pending
+    /// objects, and returns it.
+    ///
+    /// A block has to be created to finalize the new entity.
     pub fn add_existing_wallet(&mut self, auth_method: Auth, assets: Vec<Asset>) -> Account {
         let account_builder = AccountBuilder::new()
             .init_seed(ChaCha20Rng::from_seed(Default::default()).gen())
@@ -323,6 +391,10 @@ impl MockChain {
         self.add_from_account_builder(auth_method, account_builder)
     }
 
+    /// Adds a new [BasicFungibleFaucet] account to the pending objects, and returns it.
+    ///
+    /// The faucet is new, i.e., its nonce is zero and it has to be deployed by its first
+    /// transaction. A block has to be created to finalize the new entity.
     pub fn add_new_faucet(
         &mut self,
         auth_method: Auth,
@@ -347,6 +419,9 @@ impl MockChain {
         MockFungibleFaucet(account)
     }
 
+    /// Adds an existing [BasicFungibleFaucet] account to the pending objects, and returns it.
+    ///
+    /// A block has to be created to finalize the new entity.
     pub fn add_existing_faucet(
         &mut self,
         auth_method: Auth,
@@ -418,6 +493,13 @@ impl MockChain {
         ));
     }
 
+    /// Returns a [TransactionContextBuilder] for a transaction executed by the specified account
+    /// against the current state of this chain.
+    ///
+    /// All notes available on the chain are used as the input notes of the transaction.
+    ///
+    /// # Panics
+    /// Panics if the account was not created via this chain.
     pub fn build_tx_context(&self, account_id: AccountId) -> TransactionContextBuilder {
         let mock_account = self.available_accounts.get(&account_id).unwrap();
 
@@ -427,23 +509,58 @@ impl MockChain {
             .mock_chain(self.clone())
     }
 
+    /// Returns the [TransactionInputs] for a transaction executed by the provided account against
+    /// the latest block of this chain, consuming the specified notes.
+    ///
+    /// The returned chain MMR contains the headers of all blocks in which the input notes were
+    /// created, except for the latest block which is provided as the reference block.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// - No block has been sealed yet.
+    /// - Any of the notes is not available on the chain.
     pub fn get_transaction_inputs(
         &self,
         account: Account,
         account_seed: Option<Word>,
         notes: &[NoteId],
     ) -> TransactionInputs {
-        let block_header = self.blocks.last().unwrap().header();
+        self.get_transaction_inputs_with_unauthenticated_notes(account, account_seed, notes, &[])
+    }
+
+    /// Returns the [TransactionInputs] for a transaction executed by the provided account against
+    /// the latest block of this chain, consuming the specified notes followed by the provided
+    /// unauthenticated notes.
+    ///
+    /// The returned chain MMR contains the headers of all blocks in which the authenticated input
+    /// notes were created, except for the latest block which is provided as the reference block.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// - No block has been sealed yet.
+    /// - Any of the authenticated notes is not available on the chain.
+    pub fn get_transaction_inputs_with_unauthenticated_notes(
+        &self,
+        account: Account,
+        account_seed: Option<Word>,
+        notes: &[NoteId],
+        unauthenticated_notes: &[Note],
+    ) -> TransactionInputs {
+        let block_header = self.latest_block_header();
 
         let mut input_notes = vec![];
         let mut block_headers_map: BTreeMap<u32, BlockHeader> = BTreeMap::new();
         for note in notes {
             let input_note = self.available_notes.get(note).unwrap().clone();
             let note_block_num = input_note.location().unwrap().block_num();
-            let block_header = self.blocks.get(note_block_num as usize).unwrap().header();
-            block_headers_map.insert(note_block_num, block_header);
+            // notes created in the reference block are authenticated against its header directly
+            if note_block_num != block_header.block_num() {
+                block_headers_map
+                    .insert(note_block_num, self.block_header(note_block_num as usize));
+            }
             input_notes.push(input_note);
         }
+        input_notes.extend(unauthenticated_notes.iter().cloned().map(InputNote::unauthenticated));
 
         let block_headers: Vec<BlockHeader> = block_headers_map.values().cloned().collect();
         let mmr = mmr_to_chain_mmr(&self.chain, &block_headers).unwrap();
@@ -461,20 +578,40 @@ impl MockChain {
     // MODIFIERS
     // =========================================================================================
 
-    /// Creates the next block.
+    /// Creates the next block and returns it.
     ///
     /// This will also make all the objects currently pending available for use.
-    /// If `block_num` is `Some(number)`, `number` will be used as the new block's number
+    ///
+    /// If `block_num` is `Some(number)`, `number` will be used as the new block's number. Empty
+    /// blocks are sealed for all block numbers between the latest block and `number`, so that the
+    /// chain can be moved forward in time without breaking the chain MMR.
+    ///
+    /// # Panics
+    /// Panics if `block_num` is not greater than the number of the latest block.
     pub fn seal_block(&mut self, block_num: Option<u32>) -> Block {
-        let next_block_num = self.blocks.last().map_or(0, |b| b.header().block_num() + 1);
-        let block_num: u32 = if let Some(input_block_num) = block_num {
-            if input_block_num < next_block_num {
-                panic!("Input block number should be higher than the last block number");
+        let next_block_num = self.next_block_num();
+        let block_num = block_num.unwrap_or(next_block_num);
+        if block_num < next_block_num {
+            panic!("Input block number should be higher than the last block number");
+        }
+
+        if block_num > next_block_num {
+            let pending_objects =
+                core::mem::replace(&mut self.pending_objects, PendingObjects::new());
+            let removed_notes = core::mem::take(&mut self.removed_notes);
+            for _ in next_block_num..block_num {
+                self.seal_next_block();
             }
-            input_block_num
-        } else {
-            next_block_num
-        };
+            self.pending_objects = pending_objects;
+            self.removed_notes = removed_notes;
+        }
+
+        self.seal_next_block()
+    }
+
+    /// Creates the block following the latest block, finalizing all pending objects.
+    fn seal_next_block(&mut self) -> Block {
+        let block_num = self.next_block_num();
 
         for update in self.pending_objects.updated_accounts.iter() {
             self.accounts.insert(update.account_id().into(), *update.new_state_hash());
@@ -499,9 +636,9 @@ impl MockChain {
         // - resetting the nullifier tree once defined at the protocol level.
         // - inserting only nullifier from transactions included in the batches, once the batch
         // kernel has been implemented.
-        for nullifier in self.pending_objects.created_nullifiers.iter() {
-            self.nullifiers.insert(nullifier.inner(), [block_num.into(), ZERO, ZERO, ZERO]);
-        }
+        self.nullifiers
+            .insert_batch(self.pending_objects.created_nullifiers.iter().copied(), block_num)
+            .unwrap();
         let notes_tree = self.pending_objects.build_note_tree();
 
         let version = 0;
@@ -587,6 +724,12 @@ impl MockChain {
         self.removed_notes = vec![];
     }
 
+    /// Returns the number of the block which will be created by the next call to
+    /// [MockChain::seal_block()].
+    fn next_block_num(&self) -> u32 {
+        self.blocks.last().map_or(0, |block| block.header().block_num() + 1)
+    }
+
     // ACCESSORS
     // =========================================================================================
 
@@ -601,8 +744,21 @@ impl MockChain {
         self.blocks[block_number].header()
     }
 
+    /// Returns the [BlockHeader] of the latest block.
+    ///
+    /// # Panics
+    /// Panics if no block has been sealed yet.
+    pub fn latest_block_header(&self) -> BlockHeader {
+        self.blocks.last().expect("no block has been sealed yet").header()
+    }
+
+    /// Returns all blocks of this chain, in ascending order of block numbers.
+    pub fn blocks(&self) -> &[Block] {
+        &self.blocks
+    }
+
     /// Get a reference to the nullifier tree.
-    pub fn nullifiers(&self) -> &Smt {
+    pub fn nullifiers(&self) -> &NullifierTree {
         &self.nullifiers
     }
 
@@ -615,11 +771,39 @@ impl MockChain {
     pub fn accounts(&self) -> &SimpleSmt<ACCOUNT_TREE_DEPTH> {
         &self.accounts
     }
+
+    /// Get the [ForeignAccountInputs] of the account with the specified ID as of the latest block.
+    ///
+    /// Returns `None` if the full state of the account is not known to the chain.
+    pub fn get_foreign_account_inputs(
+        &self,
+        account_id: AccountId,
+    ) -> Option<ForeignAccountInputs> {
+        let account = match self.available_accounts.get(&account_id) {
+            Some(mock_account) => mock_account.account().clone(),
+            None => self
+                .blocks
+                .iter()
+                .rev()
+                .flat_map(|block| block.updated_accounts())
+                .find(|update| update.account_id() == account_id)
+                .and_then(|update| match update.details() {
+                    AccountUpdateDetails::New(account) => Some(account.clone()),
+                    _ => None,
+                })?,
+        };
+
+        let account_path = self.accounts.open(&account_id.into()).path;
+
+        Some(ForeignAccountInputs::from_account(&account, account_path))
+    }
 }
 
 // MOCK CHAIN BUILDER
 // ================================================================================================
 
+/// A builder of a [MockChain] which contains a single non-empty block with the provided accounts
+/// and notes.
 #[derive(Default)]
 pub struct MockChainBuilder {
     accounts: Vec<Account>,
@@ -628,22 +812,27 @@ pub struct MockChainBuilder {
 }
 
 impl MockChainBuilder {
+    /// Sets the accounts which exist on the chain.
     pub fn accounts(mut self, accounts: Vec<Account>) -> Self {
         self.accounts = accounts;
         self
     }
 
+    /// Sets the notes which exist on the chain.
     pub fn notes(mut self, notes: Vec<Note>) -> Self {
         self.notes = notes;
         self
     }
 
+    /// Sets the number of the block containing the accounts and notes of the chain.
+    ///
+    /// Empty blocks are created for all preceding block numbers.
     pub fn starting_block_num(mut self, block_num: u32) -> Self {
         self.starting_block_num = block_num;
         self
     }
 
-    /// Returns a [MockChain] with a single block
+    /// Returns a [MockChain] whose latest block contains the accounts and notes of this builder.
     pub fn build(self) -> MockChain {
         let mut chain = MockChain::new();
         for account in self.accounts {
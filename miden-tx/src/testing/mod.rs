@@ -4,8 +4,11 @@ pub use mock_host::MockHost;
 mod mock_host;
 
 pub mod mock_chain;
-
+pub use mock_chain::{Auth, MockChain, MockChainBuilder, MockFungibleFaucet};
 pub use tx_context::{TransactionContext, TransactionContextBuilder};
 mod tx_context;
 
+pub use note_script::{NoteScriptTestContext, NoteScriptTestError, NoteScriptTestResult};
+mod note_script;
+
 pub mod utils;
@@ -0,0 +1,342 @@
+// NOTE SCRIPT TEST CONTEXT
+// ================================================================================================
+
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+use miden_lib::transaction::TransactionKernel;
+use miden_objects::{
+    accounts::{
+        account_id::testing::{
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN, ACCOUNT_ID_SENDER,
+        },
+        Account, AccountId,
+    },
+    assembly::Assembler,
+    assets::Asset,
+    notes::{
+        Note, NoteAssets, NoteExecutionHint, NoteInputs, NoteMetadata, NoteRecipient, NoteScript,
+        NoteTag, NoteType,
+    },
+    transaction::{ExecutedTransaction, OutputNote, OutputNotes, TransactionScript},
+    NoteError,
+};
+use vm_processor::{Digest, Felt, Word, ONE, ZERO};
+use winter_maybe_async::*;
+
+use super::{mock_chain::MockAuthenticator, TransactionContextBuilder};
+use crate::TransactionExecutorError;
+
+/// A context for testing a custom note script.
+///
+/// The context compiles the provided MASM source of a note script, creates a note with this script
+/// and the configured inputs and assets, and consumes the note by the configured account in a
+/// single transaction. The outcome of the transaction can be inspected via the returned
+/// [NoteScriptTestResult].
+///
+/// By default, the note is consumed by a mock account which exposes the procedures of the
+/// `test::account` module (e.g., `set_item` and `incr_nonce`), and the note script is compiled with
+/// an assembler which can link against this module as well as the Miden standard library. Note
+/// that the transaction kernel requires the nonce of the account to be incremented whenever its
+/// state changes, so the note script (or the transaction script) must take care of this.
+///
+/// ```ignore
+/// let result = NoteScriptTestContext::new(
+///     "
+///     use.test::account
+///
+///     begin
+///         push.1.2.3.4 push.0 call.account::set_item dropw dropw
+///         push.1 call.account::incr_nonce drop
+///     end
+///     ",
+/// )
+/// .execute()
+/// .unwrap();
+///
+/// result.assert_storage_item(0, [ONE, Felt::new(2), Felt::new(3), Felt::new(4)]);
+/// result.assert_num_output_notes(0);
+/// ```
+pub struct NoteScriptTestContext {
+    assembler: Assembler,
+    account: Account,
+    authenticator: Option<MockAuthenticator>,
+    note_code: String,
+    note_inputs: Vec<Felt>,
+    note_assets: Vec<Asset>,
+    note_sender: AccountId,
+    note_serial_num: Word,
+    tx_script: Option<TransactionScript>,
+}
+
+impl NoteScriptTestContext {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [NoteScriptTestContext] for a note with the provided script source code.
+    ///
+    /// The note has no inputs and no assets, and is consumed by a mock account.
+    pub fn new(note_code: impl Into<String>) -> Self {
+        Self {
+            assembler: TransactionKernel::testing_assembler_with_mock_account(),
+            account: Account::mock(
+                ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
+                ONE,
+                TransactionKernel::testing_assembler(),
+            ),
+            authenticator: None,
+            note_code: note_code.into(),
+            note_inputs: Vec::new(),
+            note_assets: Vec::new(),
+            note_sender: AccountId::try_from(ACCOUNT_ID_SENDER).expect("valid account ID"),
+            note_serial_num: [ONE, ZERO, ZERO, ZERO],
+            tx_script: None,
+        }
+    }
+
+    // BUILDER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Sets the account consuming the note, together with the authenticator used to sign the
+    /// transaction on behalf of the account (if any).
+    ///
+    /// The account must not be new, i.e., its nonce must be greater than zero.
+    pub fn account(mut self, account: Account, authenticator: Option<MockAuthenticator>) -> Self {
+        self.account = account;
+        self.authenticator = authenticator;
+        self
+    }
+
+    /// Sets the assembler used to compile the note script.
+    pub fn assembler(mut self, assembler: Assembler) -> Self {
+        self.assembler = assembler;
+        self
+    }
+
+    /// Sets the inputs of the note.
+    pub fn note_inputs(mut self, inputs: impl IntoIterator<Item = Felt>) -> Self {
+        self.note_inputs = inputs.into_iter().collect();
+        self
+    }
+
+    /// Sets the assets of the note.
+    pub fn note_assets(mut self, assets: impl IntoIterator<Item = Asset>) -> Self {
+        self.note_assets = assets.into_iter().collect();
+        self
+    }
+
+    /// Sets the ID of the account which created the note.
+    pub fn note_sender(mut self, sender: AccountId) -> Self {
+        self.note_sender = sender;
+        self
+    }
+
+    /// Sets the serial number of the note.
+    pub fn note_serial_num(mut self, serial_num: Word) -> Self {
+        self.note_serial_num = serial_num;
+        self
+    }
+
+    /// Sets the transaction script executed after the note is consumed.
+    pub fn tx_script(mut self, tx_script: TransactionScript) -> Self {
+        self.tx_script = Some(tx_script);
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the note consumed by the transaction of this context.
+    ///
+    /// # Errors
+    /// Returns an error if the note script cannot be compiled, or the inputs or assets of the
+    /// note are invalid.
+    pub fn note(&self) -> Result<Note, NoteError> {
+        let script = NoteScript::compile(self.note_code.as_str(), self.assembler.clone())?;
+        let inputs = NoteInputs::new(self.note_inputs.clone())?;
+        let assets = NoteAssets::new(self.note_assets.clone())?;
+        let metadata = NoteMetadata::new(
+            self.note_sender,
+            NoteType::Public,
+            NoteTag::from(0),
+            NoteExecutionHint::always(),
+            ZERO,
+        )?;
+        let recipient = NoteRecipient::new(self.note_serial_num, script, inputs);
+
+        Ok(Note::new(assets, metadata, recipient))
+    }
+
+    // EXECUTION
+    // --------------------------------------------------------------------------------------------
+
+    /// Consumes the note of this context by the configured account and returns the outcome of
+    /// the transaction.
+    ///
+    /// # Errors
+    /// Returns an error if the note cannot be created or the transaction fails to execute, e.g.,
+    /// because the note script fails.
+    #[maybe_async]
+    pub fn execute(self) -> Result<NoteScriptTestResult, NoteScriptTestError> {
+        let note = self.note().map_err(NoteScriptTestError::InvalidNote)?;
+
+        let mut tx_context = TransactionContextBuilder::new(self.account)
+            .authenticator(self.authenticator)
+            .input_notes(vec![note.clone()]);
+        if let Some(tx_script) = self.tx_script {
+            tx_context = tx_context.tx_script(tx_script);
+        }
+
+        let executed_transaction = maybe_await!(tx_context.build().execute())
+            .map_err(NoteScriptTestError::ExecutionFailed)?;
+
+        Ok(NoteScriptTestResult::new(note, executed_transaction))
+    }
+}
+
+// NOTE SCRIPT TEST RESULT
+// ================================================================================================
+
+/// The outcome of consuming a note via a [NoteScriptTestContext].
+///
+/// The assertion helpers of this struct panic with a descriptive message if the assertion does
+/// not hold, so that they can be used directly in tests.
+#[derive(Debug, Clone)]
+pub struct NoteScriptTestResult {
+    note: Note,
+    final_account: Account,
+    executed_transaction: ExecutedTransaction,
+}
+
+impl NoteScriptTestResult {
+    fn new(note: Note, executed_transaction: ExecutedTransaction) -> Self {
+        let mut final_account = executed_transaction.initial_account().clone();
+        final_account
+            .apply_delta(executed_transaction.account_delta())
+            .expect("account delta of an executed transaction must be valid");
+
+        Self {
+            note,
+            final_account,
+            executed_transaction,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the note consumed by the transaction.
+    pub fn note(&self) -> &Note {
+        &self.note
+    }
+
+    /// Returns the state of the consuming account after the transaction.
+    pub fn final_account(&self) -> &Account {
+        &self.final_account
+    }
+
+    /// Returns the notes created by the transaction.
+    pub fn output_notes(&self) -> &OutputNotes {
+        self.executed_transaction.output_notes()
+    }
+
+    /// Returns the executed transaction.
+    pub fn executed_transaction(&self) -> &ExecutedTransaction {
+        &self.executed_transaction
+    }
+
+    /// Consumes this result and returns the executed transaction.
+    pub fn into_executed_transaction(self) -> ExecutedTransaction {
+        self.executed_transaction
+    }
+
+    // ASSERTIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Asserts that the storage slot with the specified index holds the expected value after the
+    /// transaction.
+    #[track_caller]
+    pub fn assert_storage_item(&self, index: u8, expected: Word) {
+        let value = self
+            .final_account
+            .storage()
+            .get_item(index)
+            .unwrap_or_else(|err| panic!("failed to read storage slot {index}: {err}"));
+        assert_eq!(value, Digest::from(expected), "unexpected value in storage slot {index}");
+    }
+
+    /// Asserts that the storage map in the slot with the specified index maps the provided key
+    /// to the expected value after the transaction.
+    #[track_caller]
+    pub fn assert_storage_map_item(&self, index: u8, key: Word, expected: Word) {
+        let value = self
+            .final_account
+            .storage()
+            .get_map_item(index, key)
+            .unwrap_or_else(|err| panic!("failed to read storage map in slot {index}: {err}"));
+        assert_eq!(value, expected, "unexpected value for key {key:?} in storage map {index}");
+    }
+
+    /// Asserts that the vault of the account holds the expected balance of the fungible asset
+    /// issued by the specified faucet after the transaction.
+    #[track_caller]
+    pub fn assert_fungible_balance(&self, faucet_id: AccountId, expected: u64) {
+        let balance =
+            self.final_account.vault().get_balance(faucet_id).unwrap_or_else(|err| {
+                panic!("failed to read balance of faucet {faucet_id}: {err}")
+            });
+        assert_eq!(balance, expected, "unexpected balance of faucet {faucet_id}");
+    }
+
+    /// Asserts that the vault of the account contains the specified asset after the transaction.
+    ///
+    /// For fungible assets, the vault must hold at least the amount of the asset.
+    #[track_caller]
+    pub fn assert_vault_contains(&self, asset: Asset) {
+        let vault = self.final_account.vault();
+        let contains = match asset {
+            Asset::Fungible(asset) => vault
+                .get_balance(asset.faucet_id())
+                .is_ok_and(|balance| balance >= asset.amount()),
+            Asset::NonFungible(_) => vault.has_non_fungible_asset(asset).unwrap_or(false),
+        };
+        assert!(contains, "account vault does not contain asset {asset:?}");
+    }
+
+    /// Asserts that the transaction created the expected number of notes.
+    #[track_caller]
+    pub fn assert_num_output_notes(&self, expected: usize) {
+        assert_eq!(self.output_notes().num_notes(), expected, "unexpected number of output notes");
+    }
+
+    /// Asserts that the transaction created the specified note.
+    #[track_caller]
+    pub fn assert_output_note(&self, note: &Note) {
+        let created = self.output_notes().iter().any(|output_note| match output_note {
+            OutputNote::Full(output_note) => output_note == note,
+            _ => output_note.id() == note.id() && output_note.metadata() == note.metadata(),
+        });
+        assert!(created, "note {} was not created by the transaction", note.id());
+    }
+}
+
+// NOTE SCRIPT TEST ERROR
+// ================================================================================================
+
+/// Errors which can occur when executing a [NoteScriptTestContext].
+#[derive(Debug)]
+pub enum NoteScriptTestError {
+    /// The note could not be created, e.g., because its script failed to compile.
+    InvalidNote(NoteError),
+    /// The transaction consuming the note failed to execute.
+    ExecutionFailed(TransactionExecutorError),
+}
+
+impl fmt::Display for NoteScriptTestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NoteScriptTestError {}
@@ -15,13 +15,13 @@ use miden_objects::{
     },
     assembly::Assembler,
     assets::{Asset, FungibleAsset, NonFungibleAsset},
-    notes::{Note, NoteExecutionHint, NoteId, NoteType},
+    notes::{Note, NoteArgs, NoteExecutionHint, NoteId, NoteType},
     testing::{
         constants::{
             CONSUMED_ASSET_1_AMOUNT, CONSUMED_ASSET_2_AMOUNT, CONSUMED_ASSET_3_AMOUNT,
             NON_FUNGIBLE_ASSET_DATA_2,
         },
-        notes::NoteBuilder,
+        notes::MockNoteBuilder,
         prepare_word,
         storage::prepare_assets,
     },
@@ -42,9 +42,10 @@ pub struct TransactionContextBuilder {
     advice_inputs: AdviceInputs,
     authenticator: Option<MockAuthenticator>,
     input_notes: Vec<Note>,
+    unauthenticated_notes: Vec<Note>,
     expected_output_notes: Vec<Note>,
     tx_script: Option<TransactionScript>,
-    note_args: BTreeMap<NoteId, Word>,
+    note_args: BTreeMap<NoteId, NoteArgs>,
     rng: ChaCha20Rng,
     mock_chain: Option<MockChain>,
 }
@@ -56,6 +57,7 @@ impl TransactionContextBuilder {
             account,
             account_seed: None,
             input_notes: Vec::new(),
+            unauthenticated_notes: Vec::new(),
             expected_output_notes: Vec::new(),
             advice_map: None,
             rng: ChaCha20Rng::from_seed([0_u8; 32]),
@@ -83,6 +85,7 @@ impl TransactionContextBuilder {
             account_seed: None,
             authenticator: None,
             input_notes: Vec::new(),
+            unauthenticated_notes: Vec::new(),
             expected_output_notes: Vec::new(),
             advice_map: None,
             advice_inputs: Default::default(),
@@ -108,6 +111,7 @@ impl TransactionContextBuilder {
             account_seed: None,
             authenticator: None,
             input_notes: Vec::new(),
+            unauthenticated_notes: Vec::new(),
             expected_output_notes: Vec::new(),
             advice_inputs: Default::default(),
             advice_map: None,
@@ -133,6 +137,7 @@ impl TransactionContextBuilder {
             account_seed: None,
             authenticator: None,
             input_notes: Vec::new(),
+            unauthenticated_notes: Vec::new(),
             expected_output_notes: Vec::new(),
             advice_map: None,
             advice_inputs: Default::default(),
@@ -163,11 +168,23 @@ impl TransactionContextBuilder {
         self
     }
 
+    /// Adds notes which are consumed by the transaction without being recorded in the mock chain,
+    /// i.e., as unauthenticated input notes.
+    pub fn unauthenticated_notes(mut self, notes: Vec<Note>) -> Self {
+        self.unauthenticated_notes.extend(notes);
+        self
+    }
+
     pub fn tx_script(mut self, tx_script: TransactionScript) -> Self {
         self.tx_script = Some(tx_script);
         self
     }
 
+    pub fn note_args(mut self, note_args: BTreeMap<NoteId, NoteArgs>) -> Self {
+        self.note_args.extend(note_args);
+        self
+    }
+
     pub fn expected_notes(mut self, output_notes: Vec<OutputNote>) -> Self {
         let output_notes = output_notes.into_iter().filter_map(|n| match n {
             OutputNote::Full(note) => Some(note),
@@ -185,7 +202,7 @@ impl TransactionContextBuilder {
         inputs: impl IntoIterator<Item = Felt>,
         assets: impl IntoIterator<Item = Asset>,
     ) -> Note {
-        let note = NoteBuilder::new(self.account.id(), &mut self.rng)
+        let note = MockNoteBuilder::new(self.account.id(), &mut self.rng)
             .note_inputs(inputs)
             .expect("The inputs should be valid")
             .add_assets(assets)
@@ -202,7 +219,7 @@ impl TransactionContextBuilder {
         assets: impl IntoIterator<Item = Asset>,
         inputs: impl IntoIterator<Item = Felt>,
     ) -> Note {
-        NoteBuilder::new(sender, ChaCha20Rng::from_seed(self.rng.gen()))
+        MockNoteBuilder::new(sender, ChaCha20Rng::from_seed(self.rng.gen()))
             .note_inputs(inputs)
             .unwrap()
             .add_assets(assets)
@@ -246,7 +263,7 @@ impl TransactionContextBuilder {
         );
         let code = var_name;
 
-        NoteBuilder::new(sender, ChaCha20Rng::from_seed(self.rng.gen()))
+        MockNoteBuilder::new(sender, ChaCha20Rng::from_seed(self.rng.gen()))
             .note_inputs(inputs)
             .unwrap()
             .add_assets(assets)
@@ -311,7 +328,7 @@ impl TransactionContextBuilder {
             execution_hint_always = Felt::from(NoteExecutionHint::always())
         );
 
-        NoteBuilder::new(sender, ChaCha20Rng::from_seed(self.rng.gen()))
+        MockNoteBuilder::new(sender, ChaCha20Rng::from_seed(self.rng.gen()))
             .note_inputs(inputs)
             .unwrap()
             .add_assets([asset])
@@ -352,7 +369,7 @@ impl TransactionContextBuilder {
             end
         ";
 
-        NoteBuilder::new(sender, ChaCha20Rng::from_seed(self.rng.gen()))
+        MockNoteBuilder::new(sender, ChaCha20Rng::from_seed(self.rng.gen()))
             .add_assets(assets)
             .code(code)
             .build(&self.assembler)
@@ -580,10 +597,11 @@ impl TransactionContextBuilder {
         let input_note_ids: Vec<NoteId> =
             mock_chain.available_notes().iter().map(|n| n.id()).collect();
 
-        let tx_inputs = mock_chain.get_transaction_inputs(
+        let tx_inputs = mock_chain.get_transaction_inputs_with_unauthenticated_notes(
             self.account.clone(),
             self.account_seed,
             &input_note_ids,
+            &self.unauthenticated_notes,
         );
 
         tx_args.extend_expected_output_notes(self.expected_output_notes.clone());
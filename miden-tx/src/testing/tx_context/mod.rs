@@ -7,7 +7,10 @@ use miden_objects::{
     accounts::{Account, AccountId},
     assembly::Assembler,
     notes::{Note, NoteId},
-    transaction::{ExecutedTransaction, InputNote, InputNotes, TransactionArgs, TransactionInputs},
+    transaction::{
+        ExecutedTransaction, ForeignAccountInputs, InputNote, InputNotes, TransactionArgs,
+        TransactionInputs,
+    },
 };
 use vm_processor::{AdviceInputs, ExecutionError, Process};
 use winter_maybe_async::*;
@@ -139,4 +142,17 @@ impl DataStore for TransactionContext {
 
         Ok(self.tx_inputs.clone())
     }
+
+    #[maybe_async]
+    fn get_foreign_account_inputs(
+        &self,
+        account_id: AccountId,
+        block_ref: u32,
+    ) -> Result<ForeignAccountInputs, DataStoreError> {
+        assert_eq!(block_ref, self.tx_inputs.block_header().block_num());
+
+        self.mock_chain
+            .get_foreign_account_inputs(account_id)
+            .ok_or(DataStoreError::AccountNotFound(account_id))
+    }
 }
@@ -1,24 +1,27 @@
 use alloc::string::String;
 
-use miden_lib::transaction::memory::{
-    NOTE_MEM_SIZE, NUM_OUTPUT_NOTES_PTR, OUTPUT_NOTE_ASSETS_OFFSET, OUTPUT_NOTE_METADATA_OFFSET,
-    OUTPUT_NOTE_NUM_ASSETS_OFFSET, OUTPUT_NOTE_RECIPIENT_OFFSET, OUTPUT_NOTE_SECTION_OFFSET,
-};
-use miden_objects::{
-    notes::Note,
-    testing::{prepare_word, storage::prepare_assets},
-    vm::StackInputs,
-    Felt, Hasher, Word, ONE, ZERO,
+use miden_lib::{
+    masm::MasmTemplate,
+    transaction::memory::{
+        NOTE_MEM_SIZE, NUM_OUTPUT_NOTES_PTR, OUTPUT_NOTE_ASSETS_OFFSET,
+        OUTPUT_NOTE_METADATA_OFFSET, OUTPUT_NOTE_NUM_ASSETS_OFFSET, OUTPUT_NOTE_RECIPIENT_OFFSET,
+        OUTPUT_NOTE_SECTION_OFFSET,
+    },
 };
+use miden_objects::{notes::Note, vm::StackInputs, Felt, Hasher, Word, ONE, ZERO};
 use vm_processor::{ContextId, Host, Process, ProcessState};
 
 mod test_account;
 mod test_asset;
 mod test_asset_vault;
+mod test_auth;
 mod test_epilogue;
 mod test_faucet;
+mod test_managed_faucet;
+mod test_non_fungible_faucet;
 mod test_note;
 mod test_prologue;
+mod test_spending_limit;
 mod test_tx;
 
 // HELPER MACROS
@@ -53,76 +56,56 @@ pub fn try_read_root_mem_value<H: Host>(process: &Process<H>, addr: u32) -> Opti
 }
 
 pub fn output_notes_data_procedure(notes: &[Note]) -> String {
-    let note_0_metadata = prepare_word(&notes[0].metadata().into());
-    let note_0_recipient = prepare_word(&notes[0].recipient().digest());
-    let note_0_assets = prepare_assets(notes[0].assets());
-    let note_0_num_assets = 1;
+    let populate_notes = notes[..3]
+        .iter()
+        .enumerate()
+        .map(|(i, note)| {
+            MasmTemplate::new(POPULATE_OUTPUT_NOTE_TEMPLATE)
+                .with("NOTE_IDX", &i)
+                .with("NOTE_METADATA", note.metadata())
+                .with("NOTE_RECIPIENT", &note.recipient().digest())
+                .with("NOTE_ASSET", &Word::from(*note.assets().iter().next().unwrap()))
+                .with("OUTPUT_NOTE_SECTION_OFFSET", &OUTPUT_NOTE_SECTION_OFFSET)
+                .with("NOTE_OFFSET", &(NOTE_MEM_SIZE * i as u32))
+                .with("OUTPUT_NOTE_METADATA_OFFSET", &OUTPUT_NOTE_METADATA_OFFSET)
+                .with("OUTPUT_NOTE_RECIPIENT_OFFSET", &OUTPUT_NOTE_RECIPIENT_OFFSET)
+                .with("OUTPUT_NOTE_NUM_ASSETS_OFFSET", &OUTPUT_NOTE_NUM_ASSETS_OFFSET)
+                .with("OUTPUT_NOTE_ASSETS_OFFSET", &OUTPUT_NOTE_ASSETS_OFFSET)
+                .render()
+                .expect("output note template should be well formed")
+        })
+        .collect::<String>();
+
+    MasmTemplate::new(CREATE_MOCK_NOTES_TEMPLATE)
+        .with_code("POPULATE_NOTES", populate_notes)
+        .with("NUM_NOTES", &notes.len())
+        .with("NUM_OUTPUT_NOTES_PTR", &NUM_OUTPUT_NOTES_PTR)
+        .render()
+        .expect("mock notes template should be well formed")
+}
+
+/// Populates the kernel memory of the output note with index NOTE_IDX, which has a single asset.
+const POPULATE_OUTPUT_NOTE_TEMPLATE: &str = "
+            # populate note {NOTE_IDX}
+            push.{NOTE_METADATA}
+            push.{OUTPUT_NOTE_SECTION_OFFSET}.{NOTE_OFFSET}.{OUTPUT_NOTE_METADATA_OFFSET} add add mem_storew dropw
 
-    let note_1_metadata = prepare_word(&notes[1].metadata().into());
-    let note_1_recipient = prepare_word(&notes[1].recipient().digest());
-    let note_1_assets = prepare_assets(notes[1].assets());
-    let note_1_num_assets = 1;
+            push.{NOTE_RECIPIENT}
+            push.{OUTPUT_NOTE_SECTION_OFFSET}.{NOTE_OFFSET}.{OUTPUT_NOTE_RECIPIENT_OFFSET} add add mem_storew dropw
 
-    let note_2_metadata = prepare_word(&notes[2].metadata().into());
-    let note_2_recipient = prepare_word(&notes[2].recipient().digest());
-    let note_2_assets = prepare_assets(notes[2].assets());
-    let note_2_num_assets = 1;
+            push.1
+            push.{OUTPUT_NOTE_SECTION_OFFSET}.{NOTE_OFFSET}.{OUTPUT_NOTE_NUM_ASSETS_OFFSET} add add mem_store
 
-    const NOTE_1_OFFSET: u32 = NOTE_MEM_SIZE;
-    const NOTE_2_OFFSET: u32 = NOTE_MEM_SIZE * 2;
+            push.{NOTE_ASSET}
+            push.{OUTPUT_NOTE_SECTION_OFFSET}.{NOTE_OFFSET}.{OUTPUT_NOTE_ASSETS_OFFSET} add add mem_storew dropw
+";
 
-    format!(
-        "
+const CREATE_MOCK_NOTES_TEMPLATE: &str = "
         proc.create_mock_notes
             # remove padding from prologue
             dropw dropw dropw dropw
-
-            # populate note 0
-            push.{note_0_metadata}
-            push.{OUTPUT_NOTE_SECTION_OFFSET}.{OUTPUT_NOTE_METADATA_OFFSET} add mem_storew dropw
-
-            push.{note_0_recipient}
-            push.{OUTPUT_NOTE_SECTION_OFFSET}.{OUTPUT_NOTE_RECIPIENT_OFFSET} add mem_storew dropw
-
-            push.{note_0_num_assets}
-            push.{OUTPUT_NOTE_SECTION_OFFSET}.{OUTPUT_NOTE_NUM_ASSETS_OFFSET} add mem_store
-
-            push.{}
-            push.{OUTPUT_NOTE_SECTION_OFFSET}.{OUTPUT_NOTE_ASSETS_OFFSET} add mem_storew dropw
-
-            # populate note 1
-            push.{note_1_metadata}
-            push.{OUTPUT_NOTE_SECTION_OFFSET}.{NOTE_1_OFFSET}.{OUTPUT_NOTE_METADATA_OFFSET} add add mem_storew dropw
-
-            push.{note_1_recipient}
-            push.{OUTPUT_NOTE_SECTION_OFFSET}.{NOTE_1_OFFSET}.{OUTPUT_NOTE_RECIPIENT_OFFSET} add add mem_storew dropw
-
-            push.{note_1_num_assets}
-            push.{OUTPUT_NOTE_SECTION_OFFSET}.{NOTE_1_OFFSET}.{OUTPUT_NOTE_NUM_ASSETS_OFFSET} add add mem_store
-
-            push.{}
-            push.{OUTPUT_NOTE_SECTION_OFFSET}.{NOTE_1_OFFSET}.{OUTPUT_NOTE_ASSETS_OFFSET} add add mem_storew dropw
-
-            # populate note 2
-            push.{note_2_metadata}
-            push.{OUTPUT_NOTE_SECTION_OFFSET}.{NOTE_2_OFFSET}.{OUTPUT_NOTE_METADATA_OFFSET} add add mem_storew dropw
-
-            push.{note_2_recipient}
-            push.{OUTPUT_NOTE_SECTION_OFFSET}.{NOTE_2_OFFSET}.{OUTPUT_NOTE_RECIPIENT_OFFSET} add add mem_storew dropw
-
-            push.{note_2_num_assets}
-            push.{OUTPUT_NOTE_SECTION_OFFSET}.{NOTE_2_OFFSET}.{OUTPUT_NOTE_NUM_ASSETS_OFFSET} add add mem_store
-
-            push.{}
-            push.{OUTPUT_NOTE_SECTION_OFFSET}.{NOTE_2_OFFSET}.{OUTPUT_NOTE_ASSETS_OFFSET} add add mem_storew dropw
-
+{POPULATE_NOTES}
             # set num output notes
-            push.{}.{NUM_OUTPUT_NOTES_PTR} mem_store
+            push.{NUM_NOTES}.{NUM_OUTPUT_NOTES_PTR} mem_store
         end
-        ",
-        note_0_assets[0],
-        note_1_assets[0],
-        note_2_assets[0],
-        notes.len()
-    )
-}
+        ";
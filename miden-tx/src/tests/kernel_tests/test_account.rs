@@ -1,6 +1,11 @@
-use miden_lib::transaction::{
-    memory::{NATIVE_ACCT_CODE_COMMITMENT_PTR, NEW_CODE_ROOT_PTR},
-    TransactionKernel,
+use miden_lib::{
+    errors::tx_kernel_errors::{
+        ERR_ACCOUNT_PROC_IS_READ_ONLY, ERR_ARRAY_INDEX_OUT_OF_BOUNDS, ERR_ARRAY_SLOT_NOT_ARRAY,
+    },
+    transaction::{
+        memory::{NATIVE_ACCT_CODE_COMMITMENT_PTR, NEW_CODE_ROOT_PTR},
+        TransactionKernel, TransactionKernelError,
+    },
 };
 use miden_objects::{
     accounts::{
@@ -11,7 +16,7 @@ use miden_objects::{
             ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
         },
         AccountBuilder, AccountCode, AccountComponent, AccountId, AccountStorage, AccountType,
-        StorageSlot,
+        StorageArray, StorageMap, StorageSlot,
     },
     assembly::Library,
     testing::{account_component::AccountMockComponent, prepare_word, storage::STORAGE_LEAVES_2},
@@ -25,6 +30,7 @@ use super::{Felt, StackInputs, Word, ONE, ZERO};
 use crate::{
     testing::{executor::CodeExecutor, TransactionContextBuilder},
     tests::kernel_tests::{output_notes_data_procedure, read_root_mem_value},
+    TransactionExecutorError,
 };
 
 // ACCOUNT CODE TESTS
@@ -614,6 +620,266 @@ fn test_account_component_storage_offset() {
     );
 }
 
+#[test]
+fn test_account_component_read_only_procedures() {
+    let assembler = TransactionKernel::testing_assembler();
+
+    let source_code = "
+        use.miden::account
+        use.miden::faucet
+        use.miden::tx
+
+        export.foo_write
+            push.1.2.3.4.0
+            exec.account::set_item
+
+            push.1 exec.account::incr_nonce
+            dropw dropw
+        end
+
+        export.foo_read
+            push.0
+            exec.account::get_item
+            push.1.2.3.4 eqw assert
+
+            dropw dropw
+        end
+
+        export.foo_read_only_write
+            push.5.6.7.8.0
+            exec.account::set_item
+
+            dropw dropw
+        end
+
+        export.foo_read_only_incr_nonce
+            push.1 exec.account::incr_nonce
+        end
+
+        export.foo_read_only_set_code
+            push.1.2.3.4 exec.account::set_code
+        end
+
+        export.foo_read_only_add_asset
+            push.1.2.3.4 exec.account::add_asset dropw
+        end
+
+        export.foo_read_only_remove_asset
+            push.1.2.3.4 exec.account::remove_asset dropw
+        end
+
+        export.foo_read_only_create_note
+            push.1.2.3.4 push.0.1.0.0 exec.tx::create_note drop
+        end
+
+        export.foo_read_only_add_asset_to_note
+            push.0 push.1.2.3.4 exec.tx::add_asset_to_note dropw drop
+        end
+
+        export.foo_read_only_mint
+            push.1.2.3.4 exec.faucet::mint dropw
+        end
+
+        export.foo_read_only_burn
+            push.1.2.3.4 exec.faucet::burn dropw
+        end
+    ";
+
+    let code = assembler.clone().assemble_library([source_code]).unwrap();
+    let find_procedure_digest_by_name = |name: &str| {
+        code.exports().find_map(|export| {
+            if export.name.as_str() == name {
+                Some(code.mast_forest()[code.get_export_node_id(export)].digest())
+            } else {
+                None
+            }
+        })
+    };
+    let foo_write = find_procedure_digest_by_name("foo_write").unwrap();
+    let foo_read = find_procedure_digest_by_name("foo_read").unwrap();
+    let foo_read_only_write = find_procedure_digest_by_name("foo_read_only_write").unwrap();
+    let read_only_state_updates = [
+        "foo_read_only_incr_nonce",
+        "foo_read_only_set_code",
+        "foo_read_only_add_asset",
+        "foo_read_only_remove_asset",
+        "foo_read_only_create_note",
+        "foo_read_only_add_asset_to_note",
+        "foo_read_only_mint",
+        "foo_read_only_burn",
+    ]
+    .map(|name| (name, find_procedure_digest_by_name(name).unwrap()));
+
+    let component = AccountComponent::compile(
+        source_code,
+        assembler.clone(),
+        vec![StorageSlot::Value(Word::default())],
+    )
+    .unwrap()
+    .with_supported_type(AccountType::RegularAccountUpdatableCode)
+    .with_read_only_procedures([foo_read, foo_read_only_write])
+    .with_read_only_procedures(read_only_state_updates.map(|(_, digest)| digest));
+
+    let (account, _) = AccountBuilder::new()
+        .init_seed(ChaCha20Rng::from_entropy().gen())
+        .with_component(component)
+        .nonce(ONE)
+        .build_testing()
+        .unwrap();
+
+    // Assert that the read-only flags have been set correctly.
+    for (procedure_digest, expected_read_only) in
+        [(foo_write, false), (foo_read, true), (foo_read_only_write, true)]
+    {
+        let procedure_index = account.code().get_procedure_index_by_root(procedure_digest).unwrap();
+        assert_eq!(
+            account.code().get_procedure_by_index(procedure_index).is_read_only(),
+            expected_read_only,
+            "failed for procedure {procedure_digest}"
+        );
+    }
+
+    // read-only procedures can read the storage written by other procedures of the component
+    let tx_script_program = assembler
+        .clone()
+        .assemble_program(format!("begin call.{foo_write} call.{foo_read} end"))
+        .unwrap();
+    let tx_script = TransactionScript::new(tx_script_program, vec![]);
+    let tx_context = TransactionContextBuilder::new(account.clone()).tx_script(tx_script).build();
+    tx_context.execute().unwrap();
+
+    // read-only procedures cannot write to the storage or modify the account state otherwise
+    for (name, procedure_digest) in [("foo_read_only_write", foo_read_only_write)]
+        .into_iter()
+        .chain(read_only_state_updates)
+    {
+        let tx_script_program = assembler
+            .clone()
+            .assemble_program(format!("begin call.{procedure_digest} end"))
+            .unwrap();
+        let tx_script = TransactionScript::new(tx_script_program, vec![]);
+        let tx_context =
+            TransactionContextBuilder::new(account.clone()).tx_script(tx_script).build();
+        assert!(
+            matches!(
+                tx_context.execute(),
+                Err(TransactionExecutorError::KernelAssertionFailed(
+                    TransactionKernelError::FailedAssertion {
+                        err_code: ERR_ACCOUNT_PROC_IS_READ_ONLY,
+                        ..
+                    }
+                ))
+            ),
+            "failed for procedure {name}"
+        );
+    }
+}
+
+#[test]
+fn test_storage_array_is_bounds_checked() {
+    let assembler = TransactionKernel::testing_assembler();
+    let items: [Word; 3] = [[ONE; 4], [Felt::new(2); 4], [Felt::new(3); 4]];
+    let new_item: Word = [Felt::new(4); 4];
+
+    let source_code = format!(
+        "
+        use.miden::account
+        use.miden::array
+
+        export.array_read
+            push.1.0 exec.array::get_length push.3 assert_eq
+            push.1.0 exec.array::get_item push.{item_1} assert_eqw
+        end
+
+        export.array_write
+            push.{new_item} push.2.0 exec.array::set_item
+            dropw push.{item_2} assert_eqw
+            push.2.0 exec.array::get_item push.{new_item} assert_eqw
+
+            push.1 exec.account::incr_nonce
+        end
+
+        export.array_read_out_of_bounds
+            push.3.0 exec.array::get_item dropw
+        end
+
+        export.array_write_out_of_bounds
+            push.{new_item} push.3.0 exec.array::set_item dropw dropw
+        end
+
+        export.map_read_as_array
+            push.0.1 exec.array::get_item dropw
+        end
+        ",
+        item_1 = prepare_word(&items[1]),
+        item_2 = prepare_word(&items[2]),
+        new_item = prepare_word(&new_item),
+    );
+
+    let code = assembler.clone().assemble_library([source_code.as_str()]).unwrap();
+    let find_procedure_digest_by_name = |name: &str| {
+        code.exports().find_map(|export| {
+            if export.name.as_str() == name {
+                Some(code.mast_forest()[code.get_export_node_id(export)].digest())
+            } else {
+                None
+            }
+        })
+    };
+
+    let component = AccountComponent::compile(
+        source_code.as_str(),
+        assembler.clone(),
+        vec![
+            StorageArray::with_items(items).unwrap().into(),
+            StorageSlot::Map(StorageMap::new()),
+        ],
+    )
+    .unwrap()
+    .with_supported_type(AccountType::RegularAccountUpdatableCode);
+
+    let (account, _) = AccountBuilder::new()
+        .init_seed(ChaCha20Rng::from_entropy().gen())
+        .with_component(component)
+        .nonce(ONE)
+        .build_testing()
+        .unwrap();
+
+    let execute_procedure = |name: &str| {
+        let procedure_digest = find_procedure_digest_by_name(name).unwrap();
+        let tx_script_program = assembler
+            .clone()
+            .assemble_program(format!("begin call.{procedure_digest} end"))
+            .unwrap();
+        let tx_script = TransactionScript::new(tx_script_program, vec![]);
+        TransactionContextBuilder::new(account.clone())
+            .tx_script(tx_script)
+            .build()
+            .execute()
+    };
+
+    // elements within the bounds of the array can be read and written
+    execute_procedure("array_read").unwrap();
+    execute_procedure("array_write").unwrap();
+
+    // elements outside the bounds of the array cannot be accessed
+    for (name, expected_err_code) in [
+        ("array_read_out_of_bounds", ERR_ARRAY_INDEX_OUT_OF_BOUNDS),
+        ("array_write_out_of_bounds", ERR_ARRAY_INDEX_OUT_OF_BOUNDS),
+        ("map_read_as_array", ERR_ARRAY_SLOT_NOT_ARRAY),
+    ] {
+        assert!(
+            matches!(
+                execute_procedure(name),
+                Err(TransactionExecutorError::KernelAssertionFailed(
+                    TransactionKernelError::FailedAssertion { err_code, .. }
+                )) if err_code == expected_err_code
+            ),
+            "failed for procedure {name}"
+        );
+    }
+}
+
 // ACCOUNT VAULT TESTS
 // ================================================================================================
 
@@ -0,0 +1,103 @@
+use miden_lib::{
+    accounts::auth::RpoFalcon512,
+    errors::tx_kernel_errors::ERR_AUTH_NEW_PUBLIC_KEY_IS_EMPTY,
+    transaction::{TransactionKernel, TransactionScriptBuilder},
+};
+use miden_objects::{
+    accounts::{Account, AccountBuilder, AccountType, AuthPublicKey, AuthSecretKey},
+    crypto::dsa::rpo_falcon512::SecretKey,
+    transaction::TransactionScript,
+};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+
+use super::{Word, ONE};
+use crate::{
+    assert_execution_error,
+    testing::{mock_chain::MockAuthenticator, TransactionContextBuilder},
+};
+
+// The storage slot of the public key of the RpoFalcon512 component.
+const PUBLIC_KEY_SLOT: u8 = 0;
+
+// KEY ROTATION TESTS
+// ================================================================================================
+
+#[test]
+fn test_rotate_key_is_authorized_by_current_key() {
+    let mut rng = ChaCha20Rng::from_seed(Default::default());
+    let current_key = AuthSecretKey::RpoFalcon512(SecretKey::with_rng(&mut rng));
+    let new_key = AuthSecretKey::RpoFalcon512(SecretKey::with_rng(&mut rng));
+    let tx_script = rotate_key_script(new_key.public_key());
+
+    // the rotation is signed with the current key and the transaction with the new one
+    let mut account = falcon_account(current_key.public_key());
+    let authenticator = MockAuthenticator::new_with_rng(
+        &[current_key.to_authenticator_entry(), new_key.to_authenticator_entry()],
+        rng.clone(),
+    );
+    let executed_transaction = TransactionContextBuilder::new(account.clone())
+        .authenticator(Some(authenticator))
+        .tx_script(tx_script.clone())
+        .build()
+        .execute()
+        .unwrap();
+
+    account.apply_delta(executed_transaction.account_delta()).unwrap();
+    assert_eq!(
+        account.storage().get_item(PUBLIC_KEY_SLOT).unwrap(),
+        Word::from(new_key.public_key()).into()
+    );
+
+    // the holder of the new key alone cannot rotate the key of an account
+    let account = falcon_account(current_key.public_key());
+    let authenticator = MockAuthenticator::new_with_rng(&[new_key.to_authenticator_entry()], rng);
+    let result = TransactionContextBuilder::new(account)
+        .authenticator(Some(authenticator))
+        .tx_script(tx_script)
+        .build()
+        .execute();
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_rotate_key_rejects_empty_key() {
+    let current_key = AuthSecretKey::RpoFalcon512(SecretKey::new());
+    let tx_context =
+        TransactionContextBuilder::new(falcon_account(current_key.public_key())).build();
+
+    let code = "
+        use.kernel::prologue
+        use.miden::contracts::auth::basic->auth
+
+        begin
+            exec.prologue::prepare_transaction
+            padw call.auth::rotate_key dropw
+        end
+        ";
+    let process = tx_context.execute_code(code);
+    assert_execution_error!(process, ERR_AUTH_NEW_PUBLIC_KEY_IS_EMPTY);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn falcon_account(public_key: AuthPublicKey) -> Account {
+    let (account, _) = AccountBuilder::new()
+        .init_seed(ChaCha20Rng::from_entropy().gen())
+        .account_type(AccountType::RegularAccountUpdatableCode)
+        .with_component(RpoFalcon512::from(public_key))
+        .nonce(ONE)
+        .build_testing()
+        .unwrap();
+
+    account
+}
+
+fn rotate_key_script(new_public_key: AuthPublicKey) -> TransactionScript {
+    TransactionScriptBuilder::new()
+        .rotate_key(new_public_key)
+        .with_rpo_falcon512_auth()
+        .build(TransactionKernel::testing_assembler())
+        .unwrap()
+}
@@ -3,6 +3,7 @@ use alloc::{string::ToString, vec::Vec};
 use miden_lib::{
     errors::tx_kernel_errors::{
         ERR_ACCOUNT_NONCE_DID_NOT_INCREASE_AFTER_STATE_CHANGE,
+        ERR_ACCOUNT_NONCE_NOT_INCREMENTED_BY_ONE,
         ERR_EPILOGUE_TOTAL_NUMBER_OF_ASSETS_MUST_STAY_THE_SAME, ERR_TX_INVALID_EXPIRATION_DELTA,
     },
     transaction::{
@@ -11,7 +12,12 @@ use miden_lib::{
     },
 };
 use miden_objects::{
-    accounts::Account,
+    accounts::{
+        account_id::testing::ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN, Account,
+        AccountComponent, AccountId, AccountStorage, NoncePolicy,
+    },
+    assets::AssetVault,
+    testing::account_component::AccountMockComponent,
     transaction::{OutputNote, OutputNotes},
 };
 use vm_processor::{Felt, ProcessState, ONE};
@@ -354,3 +360,52 @@ fn test_epilogue_increment_nonce_violation() {
     let process = tx_context.execute_code(&code);
     assert_execution_error!(process, ERR_ACCOUNT_NONCE_DID_NOT_INCREASE_AFTER_STATE_CHANGE)
 }
+
+#[test]
+fn test_epilogue_strict_nonce_policy() {
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let mock_component = AccountMockComponent::new_with_slots(
+        TransactionKernel::testing_assembler(),
+        AccountStorage::mock_storage_slots(),
+    )
+    .unwrap();
+    let component = AccountComponent::from(mock_component).with_nonce_policy(NoncePolicy::Strict);
+    let (account_code, account_storage) =
+        Account::initialize_from_components(account_id.account_type(), &[component]).unwrap();
+    assert_eq!(account_code.nonce_policy(), NoncePolicy::Strict);
+    let account =
+        Account::from_parts(account_id, AssetVault::mock(), account_storage, account_code, ONE);
+
+    let tx_context = TransactionContextBuilder::new(account).build();
+    let code = |nonce_increment: u64| {
+        format!(
+            "
+            use.kernel::prologue
+            use.test::account
+            use.kernel::epilogue
+
+            begin
+                exec.prologue::prepare_transaction
+
+                push.{nonce_increment}
+                call.account::incr_nonce
+
+                exec.epilogue::finalize_transaction
+
+                # truncate the stack
+                drop drop
+            end
+            "
+        )
+    };
+
+    // the nonce must be incremented by exactly one, even if the account state did not change
+    tx_context.execute_code(&code(1)).unwrap();
+
+    let process = tx_context.execute_code(&code(0));
+    assert_execution_error!(process, ERR_ACCOUNT_NONCE_NOT_INCREMENTED_BY_ONE);
+
+    let process = tx_context.execute_code(&code(2));
+    assert_execution_error!(process, ERR_ACCOUNT_NONCE_NOT_INCREMENTED_BY_ONE);
+}
@@ -156,8 +156,6 @@ fn test_mint_fungible_asset_fails_saturate_max_amount() {
 // NON-FUNGIBLE FAUCET MINT TESTS
 // ================================================================================================
 
-// TODO: reenable once storage map support is implemented
-#[ignore]
 #[test]
 fn test_mint_non_fungible_asset_succeeds() {
     let tx_context = TransactionContextBuilder::with_non_fungible_faucet(
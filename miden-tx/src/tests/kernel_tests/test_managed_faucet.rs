@@ -0,0 +1,244 @@
+use alloc::string::String;
+
+use miden_lib::{
+    accounts::faucets::ManagedFungibleFaucet,
+    errors::tx_kernel_errors::{
+        ERR_FAUCET_INVALID_PAUSE_FLAG, ERR_FAUCET_IS_PAUSED, ERR_FAUCET_NEW_OWNER_KEY_IS_EMPTY,
+        ERR_FUNGIBLE_ASSET_DISTRIBUTE_WOULD_CAUSE_MAX_SUPPLY_TO_BE_EXCEEDED,
+    },
+    transaction::{memory::NATIVE_ACCT_STORAGE_SLOTS_SECTION_PTR, TransactionKernel},
+};
+use miden_objects::{
+    accounts::{Account, AccountBuilder, AccountType, AuthSecretKey},
+    assets::{Asset, FungibleAsset, TokenSymbol},
+    crypto::dsa::rpo_falcon512::{PublicKey, SecretKey},
+    notes::{NoteExecutionHint, NoteType},
+    testing::{prepare_word, storage::FAUCET_STORAGE_DATA_SLOT},
+    transaction::TransactionScript,
+    EMPTY_WORD,
+};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use vm_processor::{Host, Process};
+
+use super::{Felt, Word, ONE, ZERO};
+use crate::{
+    assert_execution_error,
+    testing::{mock_chain::MockAuthenticator, TransactionContextBuilder},
+    tests::kernel_tests::read_root_mem_value,
+};
+
+const MAX_SUPPLY: u64 = 1000;
+
+// The storage slots of the component, after the reserved faucet data slot.
+const OWNER_KEY_SLOT: u8 = 2;
+const PENDING_OWNER_KEY_SLOT: u8 = 3;
+const PAUSED_SLOT: u8 = 4;
+
+// MANAGED FAUCET TESTS
+// ================================================================================================
+
+#[test]
+fn test_managed_faucet_distribute_respects_max_supply() {
+    let account = managed_faucet_account(PublicKey::new([ONE; 4]), false);
+    let tx_context = TransactionContextBuilder::new(account).build();
+
+    // the whole supply can be distributed
+    let process = tx_context.execute_code(&tx_code(&distribute_code(MAX_SUPPLY))).unwrap();
+    let issuance = read_root_mem_value(
+        &process,
+        NATIVE_ACCT_STORAGE_SLOTS_SECTION_PTR + FAUCET_STORAGE_DATA_SLOT as u32,
+    );
+    assert_eq!(issuance[3].as_int(), MAX_SUPPLY);
+
+    // but not more than that
+    let process = tx_context.execute_code(&tx_code(&distribute_code(MAX_SUPPLY + 1)));
+    assert_execution_error!(
+        process,
+        ERR_FUNGIBLE_ASSET_DISTRIBUTE_WOULD_CAUSE_MAX_SUPPLY_TO_BE_EXCEEDED
+    );
+}
+
+#[test]
+fn test_managed_faucet_paused() {
+    let account = managed_faucet_account(PublicKey::new([ONE; 4]), true);
+    let asset: Asset = FungibleAsset::new(account.id(), 10).unwrap().into();
+    let tx_context = TransactionContextBuilder::new(account).build();
+
+    let process = tx_context.execute_code(&tx_code(&distribute_code(10)));
+    assert_execution_error!(process, ERR_FAUCET_IS_PAUSED);
+
+    let burn =
+        format!("push.{asset} call.faucet::burn dropw", asset = prepare_word(&Word::from(asset)));
+    let process = tx_context.execute_code(&tx_code(&burn));
+    assert_execution_error!(process, ERR_FAUCET_IS_PAUSED);
+
+    // resuming the faucet makes it possible to distribute assets again
+    let resume_and_distribute =
+        format!("push.0 call.faucet::set_paused drop {}", distribute_code(10));
+    let process = tx_context.execute_code(&tx_code(&resume_and_distribute)).unwrap();
+    assert_eq!(read_slot(&process, PAUSED_SLOT), EMPTY_WORD);
+}
+
+#[test]
+fn test_managed_faucet_set_paused() {
+    let account = managed_faucet_account(PublicKey::new([ONE; 4]), false);
+    let tx_context = TransactionContextBuilder::new(account).build();
+
+    let process = tx_context
+        .execute_code(&tx_code("push.1 call.faucet::set_paused drop"))
+        .unwrap();
+    assert_eq!(read_slot(&process, PAUSED_SLOT), [ONE, ZERO, ZERO, ZERO]);
+
+    // pausing the faucet prevents distributing assets within the same transaction
+    let pause_and_distribute =
+        format!("push.1 call.faucet::set_paused drop {}", distribute_code(10));
+    let process = tx_context.execute_code(&tx_code(&pause_and_distribute));
+    assert_execution_error!(process, ERR_FAUCET_IS_PAUSED);
+
+    let process = tx_context.execute_code(&tx_code("push.2 call.faucet::set_paused drop"));
+    assert_execution_error!(process, ERR_FAUCET_INVALID_PAUSE_FLAG);
+}
+
+#[test]
+fn test_managed_faucet_transfer_ownership_sets_pending_owner() {
+    let new_owner: Word = [ONE, Felt::new(2), Felt::new(3), Felt::new(4)];
+    let account = managed_faucet_account(PublicKey::new([ONE; 4]), false);
+    let tx_context = TransactionContextBuilder::new(account).build();
+
+    let transfer = format!(
+        "push.{new_owner} call.faucet::transfer_ownership dropw",
+        new_owner = prepare_word(&new_owner)
+    );
+    let process = tx_context.execute_code(&tx_code(&transfer)).unwrap();
+    assert_eq!(read_slot(&process, OWNER_KEY_SLOT), [ONE; 4]);
+    assert_eq!(read_slot(&process, PENDING_OWNER_KEY_SLOT), new_owner);
+
+    let process = tx_context.execute_code(&tx_code("padw call.faucet::transfer_ownership dropw"));
+    assert_execution_error!(process, ERR_FAUCET_NEW_OWNER_KEY_IS_EMPTY);
+}
+
+#[test]
+fn test_managed_faucet_ownership_transfer_is_authenticated_by_previous_owner() {
+    let mut rng = ChaCha20Rng::from_seed(Default::default());
+    let owner_key = SecretKey::with_rng(&mut rng);
+    let new_owner_key = SecretKey::with_rng(&mut rng);
+    let new_owner: Word = new_owner_key.public_key().into();
+
+    let tx_script = TransactionScript::compile(
+        format!(
+            "
+            use.miden::contracts::faucets::managed_fungible->faucet
+
+            begin
+                push.{new_owner}
+                call.faucet::transfer_ownership
+                dropw
+
+                call.faucet::auth_tx_rpo_falcon512
+            end
+            ",
+            new_owner = prepare_word(&new_owner),
+        ),
+        [],
+        TransactionKernel::testing_assembler(),
+    )
+    .unwrap();
+
+    // the transfer is completed once the current owner authenticates the transaction
+    let mut account = managed_faucet_account(owner_key.public_key(), false);
+    let executed_transaction = TransactionContextBuilder::new(account.clone())
+        .authenticator(Some(authenticator(owner_key, rng.clone())))
+        .tx_script(tx_script.clone())
+        .build()
+        .execute()
+        .unwrap();
+
+    account.apply_delta(executed_transaction.account_delta()).unwrap();
+    assert_eq!(account.storage().get_item(OWNER_KEY_SLOT).unwrap(), new_owner.into());
+    assert_eq!(account.storage().get_item(PENDING_OWNER_KEY_SLOT).unwrap(), EMPTY_WORD.into());
+
+    // the new owner cannot authenticate its own ownership transfer
+    let account = managed_faucet_account(SecretKey::with_rng(&mut rng).public_key(), false);
+    let result = TransactionContextBuilder::new(account)
+        .authenticator(Some(authenticator(new_owner_key, rng)))
+        .tx_script(tx_script)
+        .build()
+        .execute();
+    assert!(result.is_err());
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn managed_faucet_account(owner: PublicKey, paused: bool) -> Account {
+    let faucet = ManagedFungibleFaucet::new(
+        TokenSymbol::try_from("MAN").unwrap(),
+        2,
+        Felt::new(MAX_SUPPLY),
+        owner,
+    )
+    .unwrap()
+    .with_paused(paused);
+
+    let (account, _) = AccountBuilder::new()
+        .init_seed(ChaCha20Rng::from_entropy().gen())
+        .account_type(AccountType::FungibleFaucet)
+        .with_component(faucet)
+        .nonce(ONE)
+        .build_testing()
+        .unwrap();
+
+    account
+}
+
+fn authenticator(key: SecretKey, rng: ChaCha20Rng) -> MockAuthenticator {
+    let pub_key: Word = key.public_key().into();
+    MockAuthenticator::new_with_rng(&[(pub_key, AuthSecretKey::RpoFalcon512(key))], rng)
+}
+
+fn read_slot<H: Host>(process: &Process<H>, slot: u8) -> Word {
+    read_root_mem_value(process, NATIVE_ACCT_STORAGE_SLOTS_SECTION_PTR + slot as u32 * 2)
+}
+
+/// Returns the code distributing the provided amount of assets to a mock recipient.
+fn distribute_code(amount: u64) -> String {
+    format!(
+        "
+        push.{recipient}
+        push.{execution_hint_always}
+        push.{PUBLIC_NOTE}
+        push.{aux}
+        push.{tag}
+        push.{amount}
+        call.faucet::distribute
+        dropw dropw dropw dropw
+        ",
+        recipient = prepare_word(&[ZERO, ONE, Felt::new(2), Felt::new(3)]),
+        execution_hint_always = Felt::from(NoteExecutionHint::always()),
+        PUBLIC_NOTE = NoteType::Public as u8,
+        aux = ZERO,
+        tag = Felt::new(4),
+    )
+}
+
+/// Returns the code executing the provided operations against the managed faucet.
+fn tx_code(operations: &str) -> String {
+    format!(
+        "
+        use.std::sys
+
+        use.kernel::prologue
+        use.miden::contracts::faucets::managed_fungible->faucet
+
+        begin
+            exec.prologue::prepare_transaction
+
+            {operations}
+
+            # truncate the stack
+            exec.sys::truncate_stack
+        end
+        "
+    )
+}
@@ -0,0 +1,159 @@
+use alloc::{string::String, vec::Vec};
+
+use miden_lib::{
+    accounts::{auth::RpoFalcon512, faucets::BasicNonFungibleFaucet},
+    errors::tx_kernel_errors::{
+        ERR_FAUCET_NON_FUNGIBLE_ASSET_ALREADY_ISSUED, ERR_NON_FUNGIBLE_FAUCET_UNKNOWN_TOKEN_ID,
+    },
+    transaction::memory::NATIVE_ACCT_STORAGE_SLOTS_SECTION_PTR,
+};
+use miden_objects::{
+    accounts::{Account, AccountBuilder, AccountType, StorageMap},
+    assets::{NonFungibleAsset, NonFungibleAssetDetails},
+    crypto::{dsa::rpo_falcon512::PublicKey, hash::rpo::Rpo256 as Hasher},
+    notes::{NoteExecutionHint, NoteType},
+    testing::prepare_word,
+    Digest,
+};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use vm_processor::{Host, Process};
+
+use super::{Felt, Word, ONE, ZERO};
+use crate::{
+    assert_execution_error, testing::TransactionContextBuilder,
+    tests::kernel_tests::read_root_mem_value,
+};
+
+// The storage slots of the component, after the reserved faucet data slot and the slot of the
+// authentication component.
+const ISSUANCE_SLOT: u32 = 2;
+const TOKENS_SLOT: u32 = 3;
+
+// NON-FUNGIBLE FAUCET TESTS
+// ================================================================================================
+
+#[test]
+fn test_non_fungible_faucet_distribute_assigns_token_ids() {
+    let account = non_fungible_faucet_account();
+    let assets = [asset(&account, &[1, 2, 3]), asset(&account, &[4, 5, 6])];
+    let tx_context = TransactionContextBuilder::new(account).build();
+
+    let code = tx_code(&[distribute_code(&[1, 2, 3]), distribute_code(&[4, 5, 6])]);
+    let process = tx_context.execute_code(&code).unwrap();
+
+    assert_eq!(read_slot(&process, ISSUANCE_SLOT), [Felt::new(2), Felt::new(2), ZERO, ZERO]);
+    assert_eq!(read_slot(&process, TOKENS_SLOT), tokens_root(&[(0, assets[0]), (1, assets[1])]));
+}
+
+#[test]
+fn test_non_fungible_faucet_prevents_double_issuance() {
+    let account = non_fungible_faucet_account();
+    let tx_context = TransactionContextBuilder::new(account).build();
+
+    let code = tx_code(&[distribute_code(&[1, 2, 3]), distribute_code(&[1, 2, 3])]);
+    let process = tx_context.execute_code(&code);
+
+    assert_execution_error!(process, ERR_FAUCET_NON_FUNGIBLE_ASSET_ALREADY_ISSUED);
+}
+
+#[test]
+fn test_non_fungible_faucet_burn() {
+    let account = non_fungible_faucet_account();
+    let remaining = asset(&account, &[4, 5, 6]);
+    let tx_context = TransactionContextBuilder::new(account).build();
+
+    // the asset minted in the same transaction is part of the input vault and can thus be burned
+    let code = tx_code(&[distribute_code(&[1, 2, 3]), distribute_code(&[4, 5, 6]), burn_code(0)]);
+    let process = tx_context.execute_code(&code).unwrap();
+
+    // token ids are not reused, but the burned asset is no longer counted as issued
+    assert_eq!(read_slot(&process, ISSUANCE_SLOT), [Felt::new(2), ONE, ZERO, ZERO]);
+    assert_eq!(read_slot(&process, TOKENS_SLOT), tokens_root(&[(1, remaining)]));
+
+    let process = tx_context.execute_code(&tx_code(&[burn_code(0)]));
+    assert_execution_error!(process, ERR_NON_FUNGIBLE_FAUCET_UNKNOWN_TOKEN_ID);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn non_fungible_faucet_account() -> Account {
+    let (account, _) = AccountBuilder::new()
+        .init_seed(ChaCha20Rng::from_entropy().gen())
+        .account_type(AccountType::NonFungibleFaucet)
+        .with_component(RpoFalcon512::new(PublicKey::new([ONE; 4])))
+        .with_component(BasicNonFungibleFaucet)
+        .nonce(ONE)
+        .build_testing()
+        .unwrap();
+
+    account
+}
+
+fn asset(account: &Account, data: &[u8]) -> NonFungibleAsset {
+    let details = NonFungibleAssetDetails::new(account.id(), data.to_vec()).unwrap();
+    NonFungibleAsset::new(&details).unwrap()
+}
+
+fn tokens_root(tokens: &[(u64, NonFungibleAsset)]) -> Word {
+    let map = StorageMap::with_entries(tokens.iter().map(|(token_id, asset)| {
+        (Digest::from([ZERO, ZERO, ZERO, Felt::new(*token_id)]), Word::from(*asset))
+    }))
+    .unwrap();
+
+    map.root().into()
+}
+
+fn read_slot<H: Host>(process: &Process<H>, slot: u32) -> Word {
+    read_root_mem_value(process, NATIVE_ACCT_STORAGE_SLOTS_SECTION_PTR + slot * 2)
+}
+
+/// Returns the code distributing an asset with the provided data to a mock recipient.
+fn distribute_code(data: &[u8]) -> String {
+    format!(
+        "
+        push.{recipient}
+        push.{execution_hint_always}
+        push.{PUBLIC_NOTE}
+        push.{aux}
+        push.{tag}
+        push.{data_hash}
+        call.faucet::distribute
+        dropw dropw dropw dropw
+        ",
+        recipient = prepare_word(&[ZERO, ONE, Felt::new(2), Felt::new(3)]),
+        execution_hint_always = Felt::from(NoteExecutionHint::always()),
+        PUBLIC_NOTE = NoteType::Public as u8,
+        aux = ZERO,
+        tag = Felt::new(4),
+        data_hash = prepare_word(&Word::from(Hasher::hash(data))),
+    )
+}
+
+/// Returns the code burning the asset issued under the provided token id.
+fn burn_code(token_id: u64) -> String {
+    format!("push.{token_id} call.faucet::burn drop")
+}
+
+/// Returns the code executing the provided operations against the non-fungible faucet.
+fn tx_code(operations: &[String]) -> String {
+    format!(
+        "
+        use.std::sys
+
+        use.kernel::prologue
+        use.miden::contracts::faucets::basic_non_fungible->faucet
+
+        begin
+            exec.prologue::prepare_transaction
+
+            {operations}
+
+            # truncate the stack
+            exec.sys::truncate_stack
+        end
+        ",
+        operations = operations.iter().map(String::as_str).collect::<Vec<_>>().join("\n"),
+    )
+}
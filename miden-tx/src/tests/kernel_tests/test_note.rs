@@ -5,7 +5,13 @@ use miden_lib::{
     transaction::memory::CURRENT_INPUT_NOTE_PTR,
 };
 use miden_objects::{
-    notes::Note, testing::prepare_word, transaction::TransactionArgs, Hasher, WORD_SIZE,
+    accounts::{
+        account_id::testing::ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN, AccountId,
+    },
+    notes::{Note, NoteExecutionHint, NoteExecutionMode, NoteMetadata, NoteTag, NoteType},
+    testing::prepare_word,
+    transaction::TransactionArgs,
+    Hasher, WORD_SIZE,
 };
 use vm_processor::{ProcessState, EMPTY_WORD, ONE};
 
@@ -358,8 +364,8 @@ fn test_note_script_and_note_args() {
         ";
 
     let note_args_map = BTreeMap::from([
-        (tx_context.input_notes().get_note(0).note().id(), note_args[1]),
-        (tx_context.input_notes().get_note(1).note().id(), note_args[0]),
+        (tx_context.input_notes().get_note(0).note().id(), note_args[1].into()),
+        (tx_context.input_notes().get_note(1).note().id(), note_args[0].into()),
     ]);
 
     let tx_args = TransactionArgs::new(
@@ -522,3 +528,45 @@ fn test_get_inputs_hash() {
 
     assert_eq!(process.get_stack_state()[0..16], expected_stack);
 }
+
+#[test]
+fn test_is_network_note() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+    let network_tag = NoteTag::from_account_id(account_id, NoteExecutionMode::Network).unwrap();
+    let local_tag = NoteTag::from_account_id(account_id, NoteExecutionMode::Local).unwrap();
+
+    for (tag, note_type) in [
+        (network_tag, NoteType::Public),
+        (network_tag, NoteType::Private),
+        (local_tag, NoteType::Public),
+        (local_tag, NoteType::Private),
+    ] {
+        let code = format!(
+            "
+            use.miden::note
+
+            begin
+                push.{note_type} push.{tag}
+                exec.note::is_network_note
+
+                push.{tag}
+                exec.note::get_tag_execution_mode
+            end
+            ",
+            note_type = note_type as u8,
+            tag = tag.inner(),
+        );
+
+        let process = tx_context.execute_code(&code).unwrap();
+
+        let metadata =
+            NoteMetadata::new(account_id, note_type, tag, NoteExecutionHint::always(), ZERO);
+        let is_network_note = metadata.is_ok_and(|metadata| metadata.is_network_note());
+
+        assert_eq!(process.stack.get(0), Felt::from(u8::from(tag.execution_mode())));
+        assert_eq!(process.stack.get(1), Felt::from(is_network_note as u8));
+    }
+}
@@ -85,8 +85,8 @@ fn test_transaction_prologue() {
     ];
 
     let note_args_map = BTreeMap::from([
-        (tx_context.input_notes().get_note(0).note().id(), note_args[0]),
-        (tx_context.input_notes().get_note(1).note().id(), note_args[1]),
+        (tx_context.input_notes().get_note(0).note().id(), note_args[0].into()),
+        (tx_context.input_notes().get_note(1).note().id(), note_args[1].into()),
     ]);
 
     let tx_args = TransactionArgs::new(
@@ -0,0 +1,189 @@
+use alloc::string::String;
+
+use miden_lib::{
+    accounts::{
+        auth::RpoFalcon512,
+        wallets::{SpendingLimit, SpendingLimitWallet},
+    },
+    errors::tx_kernel_errors::{
+        ERR_SPENDING_LIMIT_EXCEEDED, ERR_SPENDING_LIMIT_INTERVAL_REGRESSED,
+    },
+    transaction::memory::NATIVE_ACCT_STORAGE_SLOTS_SECTION_PTR,
+};
+use miden_objects::{
+    accounts::{
+        account_id::testing::{
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1,
+        },
+        Account, AccountBuilder, AccountId, StorageMap,
+    },
+    assets::{Asset, FungibleAsset},
+    crypto::dsa::rpo_falcon512::PublicKey,
+    notes::{NoteExecutionHint, NoteType},
+    testing::prepare_word,
+    Digest,
+};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+
+use super::{Felt, Word, ONE, ZERO};
+use crate::{
+    assert_execution_error, testing::TransactionContextBuilder,
+    tests::kernel_tests::read_root_mem_value,
+};
+
+const MAX_AMOUNT: u64 = 150;
+const INTERVAL: u32 = 10;
+
+// The storage slot holding the spent amounts, after the slot of the authentication component.
+const SPENT_SLOT: u32 = 2;
+
+// SPENDING LIMIT TESTS
+// ================================================================================================
+
+#[test]
+fn test_spending_limit_within_limit() {
+    let account = spending_limit_account();
+    let tx_context = TransactionContextBuilder::new(account).build();
+
+    // assets of the limited faucet can be spent up to the limit, while assets of other faucets are
+    // not restricted
+    let code = move_assets_code(&[limited_asset(100), limited_asset(50), unlimited_asset(500)]);
+    let process = tx_context.execute_code(&code).unwrap();
+
+    let block_num = tx_context.tx_inputs().block_header().block_num();
+    let interval_start = block_num - block_num % INTERVAL;
+    let spent_amounts = StorageMap::with_entries([(
+        Digest::from([ZERO, ZERO, ZERO, limited_faucet_id().into()]),
+        [Felt::from(interval_start), Felt::new(MAX_AMOUNT), ZERO, ZERO],
+    )])
+    .unwrap();
+
+    assert_eq!(
+        read_root_mem_value(&process, NATIVE_ACCT_STORAGE_SLOTS_SECTION_PTR + SPENT_SLOT * 2),
+        Word::from(spent_amounts.root()),
+        "the amount spent in the current interval must be updated",
+    );
+}
+
+#[test]
+fn test_spending_limit_exceeded() {
+    let account = spending_limit_account();
+    let tx_context = TransactionContextBuilder::new(account).build();
+
+    let code = move_assets_code(&[limited_asset(100), limited_asset(51)]);
+    let process = tx_context.execute_code(&code);
+
+    assert_execution_error!(process, ERR_SPENDING_LIMIT_EXCEEDED);
+}
+
+#[test]
+fn test_spending_limit_cannot_be_reset_by_earlier_reference_block() {
+    // the limit was exhausted by a transaction referencing a block of a later interval than the
+    // reference block of this transaction
+    let later_interval_start = 1000;
+    let account = spending_limit_account();
+    let mut storage = account.storage().clone();
+    storage
+        .set_map_item(
+            SPENT_SLOT as u8,
+            [ZERO, ZERO, ZERO, limited_faucet_id().into()],
+            [Felt::from(later_interval_start), Felt::new(MAX_AMOUNT), ZERO, ZERO],
+        )
+        .unwrap();
+    let account = Account::from_parts(
+        account.id(),
+        account.vault().clone(),
+        storage,
+        account.code().clone(),
+        account.nonce(),
+    );
+
+    let tx_context = TransactionContextBuilder::new(account).build();
+    let block_num = tx_context.tx_inputs().block_header().block_num();
+    assert!(block_num < later_interval_start);
+
+    // switching back to the earlier interval must not reset the spent amount
+    let code = move_assets_code(&[limited_asset(1)]);
+    let process = tx_context.execute_code(&code);
+
+    assert_execution_error!(process, ERR_SPENDING_LIMIT_INTERVAL_REGRESSED);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn limited_faucet_id() -> AccountId {
+    AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap()
+}
+
+fn limited_asset(amount: u64) -> Asset {
+    FungibleAsset::new(limited_faucet_id(), amount).unwrap().into()
+}
+
+fn unlimited_asset(amount: u64) -> Asset {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1).unwrap();
+    FungibleAsset::new(faucet_id, amount).unwrap().into()
+}
+
+fn spending_limit_account() -> Account {
+    let limit = SpendingLimit::new(limited_faucet_id(), MAX_AMOUNT, INTERVAL).unwrap();
+
+    let (account, _) = AccountBuilder::new()
+        .init_seed(ChaCha20Rng::from_entropy().gen())
+        .with_component(RpoFalcon512::new(PublicKey::new([ONE; 4])))
+        .with_component(SpendingLimitWallet::new(vec![limit]).unwrap())
+        .with_assets([limited_asset(1000), unlimited_asset(1000)])
+        .nonce(ONE)
+        .build_testing()
+        .unwrap();
+
+    account
+}
+
+/// Returns the code creating a note and moving the provided assets from the account to the note.
+fn move_assets_code(assets: &[Asset]) -> String {
+    let mut move_assets = String::new();
+    for asset in assets {
+        move_assets.push_str(&format!(
+            "
+            push.{asset}
+            call.wallet::move_asset_to_note
+            dropw
+            ",
+            asset = prepare_word(&Word::from(*asset)),
+        ));
+    }
+
+    format!(
+        "
+        use.std::sys
+
+        use.kernel::prologue
+        use.miden::contracts::wallets::basic->basic_wallet
+        use.miden::contracts::wallets::spending_limit->wallet
+
+        begin
+            exec.prologue::prepare_transaction
+
+            push.{recipient}
+            push.{execution_hint_always}
+            push.{PUBLIC_NOTE}
+            push.{aux}
+            push.{tag}
+            call.basic_wallet::create_note
+            # => [note_idx]
+
+            {move_assets}
+
+            # truncate the stack
+            exec.sys::truncate_stack
+        end
+        ",
+        recipient = prepare_word(&[ZERO, ONE, Felt::new(2), Felt::new(3)]),
+        execution_hint_always = Felt::from(NoteExecutionHint::always()),
+        PUBLIC_NOTE = NoteType::Public as u8,
+        aux = ZERO,
+        tag = Felt::new(4),
+    )
+}
@@ -3,8 +3,10 @@ use std::string::String;
 
 use miden_lib::{
     errors::tx_kernel_errors::{
-        ERR_NON_FUNGIBLE_ASSET_ALREADY_EXISTS, ERR_TX_NUMBER_OF_OUTPUT_NOTES_EXCEEDS_LIMIT,
+        ERR_NON_FUNGIBLE_ASSET_ALREADY_EXISTS, ERR_P2IDC_CONDITION_NOT_SATISFIED,
+        ERR_TX_NUMBER_OF_OUTPUT_NOTES_EXCEEDS_LIMIT,
     },
+    notes::{create_p2idc_note, Comparison, OracleCondition},
     transaction::{
         memory::{
             ACCOUNT_DATA_LENGTH, ACCT_CODE_COMMITMENT_OFFSET, ACCT_ID_AND_NONCE_OFFSET,
@@ -14,7 +16,7 @@ use miden_lib::{
             NUM_OUTPUT_NOTES_PTR, OUTPUT_NOTE_ASSETS_OFFSET, OUTPUT_NOTE_METADATA_OFFSET,
             OUTPUT_NOTE_RECIPIENT_OFFSET, OUTPUT_NOTE_SECTION_OFFSET,
         },
-        TransactionKernel,
+        TransactionKernel, TransactionKernelError,
     },
 };
 use miden_objects::{
@@ -22,12 +24,13 @@ use miden_objects::{
         account_id::testing::{
             ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2,
             ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
-            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN, ACCOUNT_ID_SENDER,
         },
         Account, AccountBuilder, AccountCode, AccountComponent, AccountId, AccountProcedureInfo,
         AccountStorage, AccountType, StorageSlot,
     },
-    assets::{AssetVault, NonFungibleAsset},
+    assets::{AssetVault, FungibleAsset, NonFungibleAsset},
+    crypto::rand::RpoRandomCoin,
     notes::{
         Note, NoteAssets, NoteExecutionHint, NoteInputs, NoteMetadata, NoteRecipient, NoteTag,
         NoteType,
@@ -36,7 +39,7 @@ use miden_objects::{
         account_component::AccountMockComponent, constants::NON_FUNGIBLE_ASSET_DATA_2,
         prepare_word, storage::STORAGE_LEAVES_2,
     },
-    transaction::{OutputNote, OutputNotes},
+    transaction::{OutputNote, OutputNotes, TransactionScript},
     Digest, FieldElement,
 };
 use rand::{Rng, SeedableRng};
@@ -46,9 +49,10 @@ use vm_processor::AdviceInputs;
 use super::{Felt, Process, ProcessState, Word, ONE, ZERO};
 use crate::{
     assert_execution_error,
+    errors::TransactionExecutorError,
     testing::{
         mock_chain::{MockChain, MockChainBuilder},
-        MockHost, TransactionContextBuilder,
+        MockHost, TransactionContext, TransactionContextBuilder,
     },
     tests::kernel_tests::{read_root_mem_value, try_read_root_mem_value},
 };
@@ -208,6 +212,25 @@ fn test_create_note_too_many_notes() {
     assert_execution_error!(process, ERR_TX_NUMBER_OF_OUTPUT_NOTES_EXCEEDS_LIMIT);
 }
 
+#[test]
+fn test_note_limits_match_kernel_constants() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+
+    let code = "
+        use.kernel::constants
+
+        begin
+            exec.constants::get_max_num_output_notes
+            exec.constants::get_max_num_input_notes
+        end
+        ";
+
+    let process = tx_context.execute_code(code).unwrap();
+
+    assert_eq!(process.stack.get(0).as_int() as usize, TransactionKernel::MAX_INPUT_NOTES);
+    assert_eq!(process.stack.get(1).as_int() as usize, TransactionKernel::MAX_OUTPUT_NOTES);
+}
+
 #[test]
 fn test_get_output_notes_hash() {
     let tx_context = TransactionContextBuilder::with_standard_account(ONE)
@@ -923,9 +946,107 @@ fn test_load_foreign_account_twice() {
     );
 }
 
+#[test]
+fn test_p2idc_note_oracle_condition() {
+    let price = 150;
+
+    // the oracle provides the price as the last element of the storage slot at index 0
+    let storage_slot = StorageSlot::Value([ZERO, ZERO, ZERO, Felt::new(price)]);
+    let (oracle_account, _) = AccountBuilder::new()
+        .init_seed(ChaCha20Rng::from_entropy().gen())
+        .with_component(
+            AccountMockComponent::new_with_slots(
+                TransactionKernel::testing_assembler(),
+                vec![storage_slot],
+            )
+            .unwrap(),
+        )
+        .nonce(ONE)
+        .build_testing()
+        .unwrap();
+
+    // consuming the note succeeds while the price satisfies the condition, and fails otherwise
+    let tx_context = p2idc_tx_context(&oracle_account, Comparison::Gte, price);
+    tx_context.execute().unwrap();
+
+    let tx_context = p2idc_tx_context(&oracle_account, Comparison::Lt, price);
+    match tx_context.execute() {
+        Err(TransactionExecutorError::KernelAssertionFailed(
+            TransactionKernelError::FailedAssertion { clk, err_code, name, .. },
+        )) => {
+            // the error code is mapped back to the error constant
+            assert_eq!(err_code, ERR_P2IDC_CONDITION_NOT_SATISFIED);
+            assert_eq!(name, "ERR_P2IDC_CONDITION_NOT_SATISFIED");
+            assert!(u32::from(clk) > 0);
+        },
+        other => panic!("expected the P2IDC condition to fail, got {other:?}"),
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Returns a context for a transaction consuming a P2IDC note whose condition requires the value
+/// in the first storage slot of the oracle account to satisfy `value <comparison> threshold`.
+fn p2idc_tx_context(
+    oracle_account: &Account,
+    comparison: Comparison,
+    threshold: u64,
+) -> TransactionContext {
+    // TODO: Temporary fix: Build a native account that has the same code commitment as the foreign
+    // account, see `test_load_foreign_account_twice`.
+    let native_account = Account::from_parts(
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap(),
+        AssetVault::mock(),
+        oracle_account.storage().clone(),
+        oracle_account.code().clone(),
+        ONE,
+    );
+
+    let condition = OracleCondition::new(
+        oracle_account.id(),
+        get_root_of_get_item_procedure(),
+        ZERO,
+        comparison,
+        Felt::new(threshold),
+    );
+    let note = create_p2idc_note(
+        AccountId::try_from(ACCOUNT_ID_SENDER).unwrap(),
+        native_account.id(),
+        vec![FungibleAsset::mock(100)],
+        condition,
+        NoteType::Public,
+        ZERO,
+        &mut RpoRandomCoin::new(Word::default()),
+    )
+    .unwrap();
+
+    let mock_chain = MockChainBuilder::default()
+        .accounts(vec![oracle_account.clone()])
+        .notes(vec![note])
+        .build();
+    let advice_inputs = get_mock_advice_inputs(oracle_account, &mock_chain);
+
+    let tx_script = TransactionScript::compile(
+        "
+        use.test::account
+
+        begin
+            push.1 call.account::incr_nonce drop
+        end
+        ",
+        [],
+        TransactionKernel::testing_assembler(),
+    )
+    .unwrap();
+
+    TransactionContextBuilder::new(native_account)
+        .mock_chain(mock_chain)
+        .advice_inputs(advice_inputs)
+        .tx_script(tx_script)
+        .build()
+}
+
 fn get_mock_advice_inputs(foreign_account: &Account, mock_chain: &MockChain) -> AdviceInputs {
     let foreign_id_root = Digest::from([foreign_account.id().into(), ZERO, ZERO, ZERO]);
     let foreign_id_and_nonce = [foreign_account.id().into(), ZERO, ZERO, foreign_account.nonce()];
@@ -9,45 +9,68 @@ use ::assembly::{
     ast::{Module, ModuleKind},
     LibraryPath,
 };
-use miden_lib::transaction::TransactionKernel;
+use miden_lib::{
+    accounts::{auth::RpoFalcon512, wallets::BasicWallet},
+    errors::tx_kernel_errors::ERR_ACCOUNT_NONCE_INCREASE_MUST_BE_U32,
+    notes::create_p2id_note,
+    transaction::{TransactionKernel, TransactionKernelError},
+};
 use miden_objects::{
     accounts::{
         account_id::testing::{
             ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2,
             ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
             ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
         },
-        AccountBuilder, AccountCode, AccountComponent, AccountStorage, AccountType, StorageSlot,
+        Account, AccountBuilder, AccountCode, AccountComponent, AccountDelta, AccountId,
+        AccountStorage, AccountStorageDelta, AccountType, LazyMapSlot, LazyStorageMap,
+        MemoryStorageBackend, NoncePolicy, StorageSlot,
     },
     assembly::DefaultSourceManager,
     assets::{Asset, AssetVault, FungibleAsset, NonFungibleAsset},
+    crypto::{dsa::rpo_falcon512::PublicKey, rand::RpoRandomCoin},
     notes::{
-        Note, NoteAssets, NoteExecutionHint, NoteExecutionMode, NoteHeader, NoteId, NoteInputs,
-        NoteMetadata, NoteRecipient, NoteScript, NoteTag, NoteType,
+        Note, NoteAssets, NoteBuilder, NoteExecutionHint, NoteExecutionMode, NoteHeader, NoteId,
+        NoteInputs, NoteMetadata, NoteRecipient, NoteScript, NoteTag, NoteType,
     },
     testing::{
         account_component::AccountMockComponent,
         constants::{FUNGIBLE_ASSET_AMOUNT, NON_FUNGIBLE_ASSET_DATA},
         notes::DEFAULT_NOTE_CODE,
         prepare_word,
-        storage::{STORAGE_INDEX_0, STORAGE_INDEX_2},
+        storage::{STORAGE_INDEX_0, STORAGE_INDEX_2, STORAGE_LEAVES_2},
+    },
+    transaction::{
+        CycleFeeModel, ExecutedTransaction, FeeModel, InputNote, KernelEvent, OutputNote,
+        ProvenTransaction, TransactionArgs, TransactionScript, TransactionWitness,
     },
-    transaction::{ProvenTransaction, TransactionArgs, TransactionScript},
-    Felt, Word, MIN_PROOF_SECURITY_LEVEL,
+    AccountDeltaError, ExecutedTransactionError, Felt, ProtocolLimits, ProvenTransactionError,
+    Word, MIN_PROOF_SECURITY_LEVEL, ZERO,
 };
 use miden_prover::ProvingOptions;
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha20Rng;
 use vm_processor::{
     utils::{Deserializable, Serializable},
-    Digest, MemAdviceProvider, ONE,
+    Digest, MemAdviceProvider, ProcessState, ONE,
 };
 
 use super::{
-    LocalTransactionProver, TransactionExecutor, TransactionHost, TransactionProver,
-    TransactionVerifier,
+    ConsumptionPlanner, DataStoreError, ExecutionStage, HashFunction, HostExtension,
+    HostExtensionError, LocalTransactionChain, LocalTransactionProver, NoteConsumability,
+    NoteConsumptionChecker, ProcedureCall, ProofSecurityLevel, ProvingStage, TransactionChainError,
+    TransactionExecutor, TransactionExecutorError, TransactionHost, TransactionProver,
+    TransactionRequest, TransactionRequestError, TransactionRng, TransactionVerifier,
+    TransactionVerifierError,
+};
+use crate::{
+    testing::{
+        mock_chain::{Auth, MockChain, MockChainBuilder},
+        NoteScriptTestContext, NoteScriptTestError, TransactionContextBuilder,
+    },
+    ExecutorOptions, ScriptCache, ScriptCacheStats, TransactionMastStore,
 };
-use crate::{testing::TransactionContextBuilder, TransactionMastStore};
 
 mod kernel_tests;
 
@@ -120,6 +143,156 @@ fn transaction_executor_witness() {
     assert_eq!(executed_transaction.output_notes(), &tx_outputs.output_notes);
 }
 
+#[test]
+fn transaction_executor_simulate() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+
+    let account_id = tx_context.account().id();
+
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let note_ids = tx_context
+        .tx_inputs()
+        .input_notes()
+        .iter()
+        .map(|note| note.id())
+        .collect::<Vec<_>>();
+
+    let simulation = executor
+        .simulate(account_id, block_ref, &note_ids, tx_context.tx_args().clone())
+        .unwrap();
+    let executed_transaction = executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_context.tx_args().clone())
+        .unwrap();
+
+    // the simulation previews the effects of the executed transaction
+    assert_eq!(simulation.account_delta(), executed_transaction.account_delta());
+    assert_eq!(simulation.final_account(), executed_transaction.final_account());
+    assert_eq!(simulation.output_notes(), executed_transaction.output_notes());
+    assert_eq!(simulation.total_cycles(), executed_transaction.measurements().total_cycles());
+    assert!(!simulation.events().is_empty());
+
+    // fees can be quoted from the simulation of the transaction
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fee_model = CycleFeeModel::new(faucet_id, 10, 1);
+    let fee = executor
+        .estimate_fee(account_id, block_ref, &note_ids, tx_context.tx_args().clone(), &fee_model)
+        .unwrap();
+    assert_eq!(fee, fee_model.compute_fee(executed_transaction.measurements()).unwrap());
+}
+
+#[test]
+fn transaction_executor_diagnostics() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let note_ids = tx_context
+        .tx_inputs()
+        .input_notes()
+        .iter()
+        .map(|note| note.id())
+        .collect::<Vec<_>>();
+
+    let diagnostics = executor
+        .diagnose(account_id, block_ref, &note_ids, tx_context.tx_args().clone())
+        .unwrap();
+    assert!(diagnostics.failure().is_none());
+
+    // a snapshot is recorded at the end of the prologue, of every note, of the tx script and of
+    // the epilogue
+    let mut expected_stages = vec![ExecutionStage::Prologue];
+    expected_stages.extend(note_ids.iter().map(|note_id| ExecutionStage::InputNote(*note_id)));
+    expected_stages.extend([ExecutionStage::TransactionScript, ExecutionStage::Epilogue]);
+    let stages: Vec<_> = diagnostics.snapshots().iter().map(|s| s.stage()).collect();
+    assert_eq!(stages, expected_stages);
+
+    // the snapshots capture the kernel memory
+    let prologue = &diagnostics.snapshots()[0];
+    assert_eq!(
+        prologue.memory_value("BLK_HASH").unwrap(),
+        Word::from(tx_context.tx_inputs().block_header().hash())
+    );
+    assert_eq!(prologue.memory_value("ACCT_ID").unwrap()[0], Felt::from(account_id));
+    assert!(diagnostics
+        .snapshots()
+        .windows(2)
+        .all(|snapshots| snapshots[0].cycle() <= snapshots[1].cycle()));
+
+    let json = diagnostics.to_json();
+    assert!(json.starts_with("{\"snapshots\":[{\"stage\":\"prologue\""));
+    assert!(json.ends_with("\"failure\":null}"));
+
+    // if the transaction fails, the snapshots preceding the failure are still returned
+    let tx_script = TransactionScript::compile(
+        "begin push.1 push.2 assert_eq end",
+        [],
+        TransactionKernel::testing_assembler(),
+    )
+    .unwrap();
+    let tx_args = TransactionArgs::new(
+        Some(tx_script),
+        None,
+        tx_context.tx_args().advice_inputs().clone().map,
+    );
+    let diagnostics = executor.diagnose(account_id, block_ref, &note_ids, tx_args).unwrap();
+    assert!(diagnostics.failure().is_some());
+    assert_eq!(
+        diagnostics.last_snapshot().unwrap().stage(),
+        ExecutionStage::InputNote(*note_ids.last().unwrap())
+    );
+}
+
+#[test]
+fn note_consumption_checker() {
+    let (account, _) = AccountBuilder::new()
+        .init_seed(ChaCha20Rng::from_seed([1; 32]).gen())
+        .with_component(RpoFalcon512::new(PublicKey::new([ONE; 4])))
+        .with_component(BasicWallet)
+        .nonce(ONE)
+        .build_testing()
+        .unwrap();
+    let other_id = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+    let asset = FungibleAsset::mock(FUNGIBLE_ASSET_AMOUNT);
+    let mut rng = RpoRandomCoin::new([ONE; 4]);
+
+    let p2id_to_account =
+        create_p2id_note(other_id, account.id(), vec![asset], NoteType::Public, ZERO, &mut rng)
+            .unwrap();
+    let p2id_to_other =
+        create_p2id_note(account.id(), other_id, vec![asset], NoteType::Public, ZERO, &mut rng)
+            .unwrap();
+    let auth_note_script = NoteScript::compile(
+        "begin call.::miden::contracts::auth::basic::auth_tx_rpo_falcon512 end",
+        TransactionKernel::testing_assembler(),
+    )
+    .unwrap();
+    let auth_note = NoteBuilder::new(other_id, auth_note_script).build(&mut rng).unwrap();
+
+    let check = |note: &Note| {
+        let tx_context = TransactionContextBuilder::new(account.clone())
+            .input_notes(vec![note.clone()])
+            .build();
+        let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+        let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+        NoteConsumptionChecker::new(&executor)
+            .check_note(account.id(), block_ref, note.id(), TransactionArgs::default())
+            .unwrap()
+    };
+
+    assert!(check(&p2id_to_account).is_consumable());
+    assert!(matches!(check(&p2id_to_other), NoteConsumability::NotConsumable(_)));
+    assert!(matches!(check(&auth_note), NoteConsumability::NeedsAuthentication));
+}
+
 #[test]
 fn executed_transaction_account_delta_new() {
     let account_assets = AssetVault::mock().assets().collect::<Vec<Asset>>();
@@ -380,6 +553,41 @@ fn executed_transaction_account_delta_new() {
         removed_assets.len(),
         executed_transaction.account_delta().vault().removed_assets().count()
     );
+
+    // event log
+    // --------------------------------------------------------------------------------------------
+    // assert that the individual actions of the transaction are recorded in the event log
+    let events = executed_transaction.events();
+    assert!(events.contains(&KernelEvent::StorageItemUpdated {
+        slot_index: STORAGE_INDEX_0,
+        value: updated_slot_value
+    }));
+    assert!(events.contains(&KernelEvent::StorageMapItemUpdated {
+        slot_index: STORAGE_INDEX_2,
+        key: updated_map_key.into(),
+        value: updated_map_value
+    }));
+    assert!(added_assets
+        .iter()
+        .all(|asset| events.contains(&KernelEvent::AssetAddedToVault(*asset))));
+    assert!(removed_assets
+        .iter()
+        .all(|asset| events.contains(&KernelEvent::AssetRemovedFromVault(*asset))));
+
+    let nonce_increment = events
+        .iter()
+        .filter_map(|event| match event {
+            KernelEvent::NonceIncremented(value) => Some(*value),
+            _ => None,
+        })
+        .fold(Felt::new(0), |acc, value| acc + value);
+    assert_eq!(nonce_increment, Felt::new(2));
+
+    let num_created_notes = events
+        .iter()
+        .filter(|event| matches!(event, KernelEvent::NoteCreated { .. }))
+        .count();
+    assert_eq!(num_created_notes, executed_transaction.output_notes().num_notes());
 }
 
 #[test]
@@ -841,10 +1049,233 @@ fn prove_witness_and_verify() {
 
     let serialized_transaction = proven_transaction.to_bytes();
     let proven_transaction = ProvenTransaction::read_from_bytes(&serialized_transaction).unwrap();
+
+    // the transaction is well-formed under the protocol limits, but not under stricter ones
+    proven_transaction.validate(&ProtocolLimits::default()).unwrap();
+    let num_input_notes = proven_transaction.input_notes().num_notes();
+    let limits = ProtocolLimits {
+        max_input_notes_per_tx: num_input_notes - 1,
+        ..Default::default()
+    };
+    assert_eq!(
+        proven_transaction.validate(&limits),
+        Err(ProvenTransactionError::TooManyInputNotes(num_input_notes))
+    );
+
     let verifier = TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL);
     assert!(verifier.verify(proven_transaction).is_ok());
 }
 
+#[test]
+fn transaction_expiration_delta_is_proven_and_verified() {
+    let mut tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+    let account_id = tx_context.account().id();
+    let block_num = tx_context.tx_inputs().block_header().block_num();
+
+    let request = TransactionRequest::new(account_id)
+        .with_procedure_call(ProcedureCall::new("::test::account::incr_nonce", [ONE]).unwrap())
+        .with_expiration_delta(10)
+        .unwrap();
+    assert!(matches!(
+        TransactionRequest::new(account_id).with_expiration_delta(0),
+        Err(TransactionRequestError::InvalidExpirationDelta(0))
+    ));
+
+    let tx_args = request
+        .build_transaction_args(TransactionKernel::testing_assembler_with_mock_account())
+        .unwrap()
+        .with_advice_inputs(tx_context.tx_args().advice_inputs().clone());
+    tx_context.set_tx_args(tx_args);
+
+    let executed_transaction = tx_context.execute().unwrap();
+    assert_eq!(executed_transaction.expiration_block_num(), block_num + 10);
+
+    let prover = LocalTransactionProver::new(ProvingOptions::default());
+    let proven_transaction = prover.prove(executed_transaction.into()).unwrap();
+    assert_eq!(proven_transaction.expiration_block_num(), block_num + 10);
+
+    let verifier = TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL);
+    verifier.verify_at(proven_transaction.clone(), block_num + 9).unwrap();
+    assert_eq!(
+        verifier.verify_at(proven_transaction, block_num + 10),
+        Err(TransactionVerifierError::TransactionExpired {
+            expiration_block_num: block_num + 10,
+            block_num: block_num + 10,
+        })
+    );
+}
+
+#[test]
+fn executor_applies_expiration_delta() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+    let account_id = tx_context.account().id();
+    let block_num = tx_context.tx_inputs().block_header().block_num();
+    let executor = |expiration_delta| {
+        TransactionExecutor::new(Arc::new(tx_context.clone()), None)
+            .with_options(ExecutorOptions { expiration_delta, ..Default::default() })
+    };
+    let incr_nonce_args = |expiration_delta: Option<u16>| {
+        let mut request = TransactionRequest::new(account_id)
+            .with_procedure_call(ProcedureCall::new("::test::account::incr_nonce", [ONE]).unwrap());
+        if let Some(delta) = expiration_delta {
+            request = request.with_expiration_delta(delta).unwrap();
+        }
+
+        request
+            .build_transaction_args(TransactionKernel::testing_assembler_with_mock_account())
+            .unwrap()
+            .with_advice_inputs(tx_context.tx_args().advice_inputs().clone())
+    };
+
+    // transactions without a script are executed with a script setting the delta
+    let tx_args =
+        TransactionArgs::default().with_advice_inputs(tx_context.tx_args().advice_inputs().clone());
+    let executor_with_delta = executor(Some(10));
+    let executed_transaction = executor_with_delta
+        .execute_transaction(account_id, block_num, &[], tx_args.clone())
+        .unwrap();
+    assert!(executed_transaction.tx_args().tx_script().is_some());
+    assert_eq!(executed_transaction.expiration_block_num(), block_num + 10);
+
+    // the script setting the delta is compiled only once and then taken from the script cache
+    executor_with_delta
+        .execute_transaction(account_id, block_num, &[], tx_args.clone())
+        .unwrap();
+    assert_eq!(
+        executor_with_delta.script_cache().stats(),
+        ScriptCacheStats {
+            num_note_scripts: 0,
+            num_tx_scripts: 1,
+            hits: 1,
+            misses: 1
+        }
+    );
+
+    // transactions with a script must expire no later than implied by the delta
+    let executed_transaction = executor(Some(10))
+        .execute_transaction(account_id, block_num, &[], incr_nonce_args(Some(5)))
+        .unwrap();
+    assert_eq!(executed_transaction.expiration_block_num(), block_num + 5);
+    assert_eq!(
+        executor(Some(10))
+            .execute_transaction(account_id, block_num, &[], incr_nonce_args(Some(20)))
+            .unwrap_err(),
+        TransactionExecutorError::ExpirationDeltaExceeded {
+            expiration_block_num: block_num + 20,
+            max_expiration_block_num: block_num + 10,
+        }
+    );
+    assert!(matches!(
+        executor(Some(10)).execute_transaction(account_id, block_num, &[], incr_nonce_args(None)),
+        Err(TransactionExecutorError::ExpirationDeltaExceeded { .. })
+    ));
+
+    // without an expiration delta, the expiration of a transaction is not restricted
+    let executed_transaction = executor(None)
+        .execute_transaction(account_id, block_num, &[], tx_args.clone())
+        .unwrap();
+    assert!(executed_transaction.tx_args().tx_script().is_none());
+    assert_eq!(executed_transaction.expiration_block_num(), u32::MAX);
+
+    assert!(matches!(
+        executor(Some(0)).execute_transaction(account_id, block_num, &[], tx_args),
+        Err(TransactionExecutorError::InvalidExpirationDelta(0))
+    ));
+}
+
+#[test]
+fn executor_locates_failed_kernel_assertions() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+    let account_id = tx_context.account().id();
+    let block_num = tx_context.tx_inputs().block_header().block_num();
+
+    // a transaction script compiled with debug info which fails with a kernel error code
+    let source = format!(
+        "begin\n    push.0\n    assert.err={ERR_ACCOUNT_NONCE_INCREASE_MUST_BE_U32}\nend\n"
+    );
+    let tx_script = TransactionScript::compile_with_debug_info(
+        source.clone(),
+        vec![],
+        TransactionKernel::assembler(),
+    )
+    .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script)
+        .with_advice_inputs(tx_context.tx_args().advice_inputs().clone());
+    let execute = |in_debug_mode| {
+        TransactionExecutor::new(Arc::new(tx_context.clone()), None)
+            .with_debug_mode(in_debug_mode)
+            .execute_transaction(account_id, block_num, &[], tx_args.clone())
+    };
+
+    // in debug mode, the failed assertion is located via the debug info of the executed code
+    match execute(true) {
+        Err(TransactionExecutorError::KernelAssertionFailed(
+            TransactionKernelError::FailedAssertion { err_code, location, .. },
+        )) => {
+            assert_eq!(err_code, ERR_ACCOUNT_NONCE_INCREASE_MUST_BE_U32);
+            let location = location.expect("the failed assertion should be located");
+            assert!(source[location.span()].starts_with("assert.err"));
+            assert_eq!(location.line(&source), 3);
+        },
+        other => panic!("expected a failed kernel assertion, got {other:?}"),
+    }
+
+    assert!(matches!(
+        execute(false),
+        Err(TransactionExecutorError::KernelAssertionFailed(
+            TransactionKernelError::FailedAssertion { location: None, .. }
+        ))
+    ));
+}
+
+#[test]
+fn transaction_fee_is_deducted_and_proven() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+    let account_id = tx_context.account().id();
+    let fee = FungibleAsset::try_from(FungibleAsset::mock(10)).unwrap();
+
+    let request = TransactionRequest::new(account_id)
+        .with_procedure_call(ProcedureCall::new("::test::account::incr_nonce", [ONE]).unwrap());
+    let tx_args = request
+        .build_transaction_args(TransactionKernel::testing_assembler_with_mock_account())
+        .unwrap()
+        .with_advice_inputs(tx_context.tx_args().advice_inputs().clone());
+
+    // the fee is deducted from the account vault
+    let mut fee_context = tx_context.clone();
+    fee_context.set_tx_args(tx_args.clone().with_fee(fee));
+    let executed_transaction = fee_context.execute().unwrap();
+
+    assert_eq!(executed_transaction.fee(), Some(fee));
+    assert_eq!(
+        executed_transaction
+            .account_delta()
+            .vault()
+            .removed_assets()
+            .collect::<Vec<_>>(),
+        vec![Asset::from(fee)]
+    );
+
+    // the fee is a public output of the proven transaction
+    let prover = LocalTransactionProver::new(ProvingOptions::default());
+    let proven_transaction = prover.prove(executed_transaction.into()).unwrap();
+    assert_eq!(proven_transaction.fee(), Some(fee));
+
+    let proven_transaction =
+        ProvenTransaction::read_from_bytes(&proven_transaction.to_bytes()).unwrap();
+    let verifier = TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL);
+    verifier.verify(proven_transaction).unwrap();
+
+    // a fee exceeding the balance of the account can not be paid
+    let mut fee_context = tx_context.clone();
+    let excessive_fee = FungibleAsset::try_from(FungibleAsset::mock(FUNGIBLE_ASSET_AMOUNT + 1));
+    fee_context.set_tx_args(tx_args.with_fee(excessive_fee.unwrap()));
+    assert!(matches!(
+        fee_context.execute(),
+        Err(TransactionExecutorError::TransactionProgramExecutionFailed(_))
+    ));
+}
+
 // TEST TRANSACTION SCRIPT
 // ================================================================================================
 
@@ -907,6 +1338,247 @@ fn test_tx_script() {
     );
 }
 
+#[test]
+fn transaction_executor_with_rng_seed() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+    let seed = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None).with_rng_seed(seed);
+
+    let account_id = tx_context.tx_inputs().account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let note_ids = tx_context
+        .tx_inputs()
+        .input_notes()
+        .iter()
+        .map(|note| note.id())
+        .collect::<Vec<_>>();
+
+    let tx_script_src = format!(
+        "
+    begin
+        # load the script seed from the advice map
+        push.{key}
+        adv.push_mapval push.16073 drop         # TODO: remove line, see miden-vm/#1122
+        adv_loadw dropw
+    end
+",
+        key = prepare_word(&Word::from(TransactionRng::script_seed_key())),
+    );
+    let tx_script =
+        TransactionScript::compile(tx_script_src, [], TransactionKernel::testing_assembler())
+            .unwrap();
+
+    // the script seed is derived from the executor seed, the transaction inputs and the script
+    let script_seed =
+        TransactionRng::for_transaction(seed, tx_context.tx_inputs(), Some(tx_script.hash()))
+            .draw_seed();
+
+    let tx_args = TransactionArgs::new(
+        Some(tx_script),
+        None,
+        tx_context.tx_args().advice_inputs().clone().map,
+    );
+
+    // executing the same transaction twice with the same seed produces the same result
+    let executed_1 = executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_args.clone())
+        .unwrap();
+    let executed_2 = executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_args.clone())
+        .unwrap();
+    assert_eq!(executed_1.advice_witness(), executed_2.advice_witness());
+    assert_eq!(executed_1.tx_args().advice_inputs(), executed_2.tx_args().advice_inputs());
+    assert_eq!(
+        executed_1
+            .tx_args()
+            .advice_inputs()
+            .mapped_values(&TransactionRng::script_seed_key()),
+        Some(script_seed.as_slice())
+    );
+
+    // without a seed, the script seed is not provided to the transaction
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+    assert!(executor.execute_transaction(account_id, block_ref, &note_ids, tx_args).is_err());
+}
+
+/// A host extension which responds to [ORACLE_EVENT] with the doubled element at the top of the
+/// stack and provides a fixed value for [ORACLE_KEY].
+struct OracleExtension;
+
+const ORACLE_EVENT: u32 = 0x0001_0001;
+const ORACLE_KEY: Word = [Felt::new(9), Felt::new(9), Felt::new(9), Felt::new(9)];
+
+impl OracleExtension {
+    fn event_key(value: Felt) -> Digest {
+        Digest::new([value, Felt::new(0), Felt::new(0), Felt::new(0)])
+    }
+}
+
+impl HostExtension for OracleExtension {
+    fn handles_event(&self, event_id: u32) -> bool {
+        event_id == ORACLE_EVENT
+    }
+
+    fn on_event(
+        &self,
+        _event_id: u32,
+        process: &dyn ProcessState,
+    ) -> Result<Vec<(Digest, Vec<Felt>)>, HostExtensionError> {
+        let value = process.get_stack_item(0);
+        Ok(vec![(Self::event_key(value), vec![value + value; 4])])
+    }
+
+    fn get_mapped_values(&self, key: &Digest) -> Option<Vec<Felt>> {
+        (Word::from(*key) == ORACLE_KEY).then(|| vec![Felt::new(42); 4])
+    }
+}
+
+#[test]
+fn transaction_executor_with_host_extension() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+    let account_id = tx_context.tx_inputs().account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let note_ids = tx_context
+        .tx_inputs()
+        .input_notes()
+        .iter()
+        .map(|note| note.id())
+        .collect::<Vec<_>>();
+
+    let tx_script_src = format!(
+        "
+    begin
+        # request the doubled value of 7 from the extension via a custom event
+        push.7 emit.{ORACLE_EVENT} drop
+        push.{event_key}
+        adv.push_mapval push.16073 drop         # TODO: remove line, see miden-vm/#1122
+        adv_loadw
+        push.14.14.14.14 assert_eqw
+
+        # request a value for a key which is only known to the extension
+        push.{oracle_key}
+        adv.push_mapval push.16073 drop         # TODO: remove line, see miden-vm/#1122
+        adv_loadw
+        push.42.42.42.42 assert_eqw
+    end
+",
+        event_key = prepare_word(&Word::from(OracleExtension::event_key(Felt::new(7)))),
+        oracle_key = prepare_word(&ORACLE_KEY),
+    );
+    let tx_script =
+        TransactionScript::compile(tx_script_src, [], TransactionKernel::testing_assembler())
+            .unwrap();
+    let tx_args = TransactionArgs::new(
+        Some(tx_script),
+        None,
+        tx_context.tx_args().advice_inputs().clone().map,
+    );
+
+    // without the extension, the custom event is rejected by the host
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+    assert!(executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_args.clone())
+        .is_err());
+
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None)
+        .with_extension(Arc::new(OracleExtension));
+    let executed_transaction =
+        executor.execute_transaction(account_id, block_ref, &note_ids, tx_args).unwrap();
+
+    // the advice provided by the extension is part of the witness, so the transaction can be
+    // proven without the extension
+    let executed_transaction_id = executed_transaction.id();
+    let prover = LocalTransactionProver::new(ProvingOptions::default());
+    let proven_transaction = prover.prove(executed_transaction.into()).unwrap();
+    assert_eq!(proven_transaction.id(), executed_transaction_id);
+}
+
+/// Tests that the entries of a storage map held by a storage backend are loaded by the host when
+/// they are accessed, and that executing a transaction against such a map has the same effect as
+/// executing it against an in-memory map.
+#[test]
+fn transaction_executor_loads_lazy_storage_maps() {
+    let (account, _) = AccountBuilder::new()
+        .init_seed(ChaCha20Rng::from_entropy().gen())
+        .with_component(
+            AccountMockComponent::new_with_slots(
+                TransactionKernel::testing_assembler(),
+                AccountStorage::mock_storage_slots(),
+            )
+            .unwrap(),
+        )
+        .nonce(ONE)
+        .build_testing()
+        .unwrap();
+
+    let lazy_map =
+        LazyStorageMap::from_storage_map(&AccountStorage::mock_map(), MemoryStorageBackend::new())
+            .unwrap();
+    let mut lazy_slots = account.storage().slots().to_vec();
+    lazy_slots[STORAGE_INDEX_2 as usize] = StorageSlot::LazyMap(LazyMapSlot::new(lazy_map));
+    let lazy_account = Account::from_parts(
+        account.id(),
+        account.vault().clone(),
+        AccountStorage::new(lazy_slots).unwrap(),
+        account.code().clone(),
+        account.nonce(),
+    );
+    assert_eq!(lazy_account.hash(), account.hash());
+
+    let (existing_key, existing_value) = STORAGE_LEAVES_2[0];
+    let updated_key = [Felt::new(40), Felt::new(41), Felt::new(42), Felt::new(43)];
+    let updated_value = [Felt::new(44), Felt::new(45), Felt::new(46), Felt::new(47)];
+
+    let tx_script_src = format!(
+        "\
+        use.test::account
+
+        begin
+            # read an entry of the map
+            push.{EXISTING_KEY} push.{STORAGE_INDEX_2}
+            call.account::get_map_item
+            push.{EXISTING_VALUE} assert_eqw
+
+            # insert a new entry into the map and read it back
+            push.{UPDATED_VALUE} push.{UPDATED_KEY} push.{STORAGE_INDEX_2}
+            call.account::set_map_item dropw dropw dropw
+
+            push.{UPDATED_KEY} push.{STORAGE_INDEX_2}
+            call.account::get_map_item
+            push.{UPDATED_VALUE} assert_eqw
+
+            push.1 call.account::incr_nonce drop
+            dropw dropw dropw dropw
+        end
+    ",
+        EXISTING_KEY = prepare_word(&Word::from(existing_key)),
+        EXISTING_VALUE = prepare_word(&existing_value),
+        UPDATED_KEY = prepare_word(&updated_key),
+        UPDATED_VALUE = prepare_word(&updated_value),
+    );
+
+    let execute = |account: Account| {
+        let mut tx_context = TransactionContextBuilder::new(account).build();
+        let tx_script = TransactionScript::compile(
+            tx_script_src.clone(),
+            [],
+            TransactionKernel::testing_assembler_with_mock_account(),
+        )
+        .unwrap();
+        tx_context.set_tx_args(TransactionArgs::with_tx_script(tx_script));
+        tx_context.execute().unwrap()
+    };
+
+    let executed_transaction = execute(account);
+    let lazy_executed_transaction = execute(lazy_account);
+    assert_eq!(lazy_executed_transaction.account_delta(), executed_transaction.account_delta());
+    assert_eq!(lazy_executed_transaction.final_account(), executed_transaction.final_account());
+}
+
 /// Tests that an account can call code in a custom library when loading that library into the
 /// executor.
 ///
@@ -1003,3 +1675,681 @@ fn transaction_executor_account_code_using_custom_library() {
     // Account nonce should have been incremented by 4.
     assert_eq!(executed_tx.account_delta().nonce().unwrap(), Felt::new(4));
 }
+
+#[test]
+fn transaction_request_is_compiled_and_executed() {
+    let mut tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+    let account_id = tx_context.account().id();
+
+    let asset = FungibleAsset::mock(FUNGIBLE_ASSET_AMOUNT / 2);
+    let note_script =
+        NoteScript::compile(DEFAULT_NOTE_CODE, TransactionKernel::testing_assembler()).unwrap();
+    let note = NoteBuilder::new(account_id, note_script)
+        .add_asset(asset)
+        .tag(NoteTag::for_local_use_case(0, 0).unwrap())
+        .build(&mut RpoRandomCoin::new([ONE; 4]))
+        .unwrap();
+
+    let request = TransactionRequest::new(account_id)
+        .with_output_note(OutputNote::Full(note.clone()))
+        .with_procedure_call(ProcedureCall::new("::test::account::incr_nonce", [ONE]).unwrap())
+        .with_expected_output_asset(asset);
+
+    let tx_args = request
+        .build_transaction_args(TransactionKernel::testing_assembler_with_mock_account())
+        .unwrap()
+        .with_advice_inputs(tx_context.tx_args().advice_inputs().clone());
+    tx_context.set_tx_args(tx_args);
+
+    let executed_transaction = tx_context.execute().unwrap();
+
+    assert_eq!(executed_transaction.account_delta().nonce(), Some(Felt::new(2)));
+    assert!(executed_transaction
+        .output_notes()
+        .iter()
+        .any(|output_note| output_note == &OutputNote::Full(note.clone())));
+    request.verify_executed_transaction(&executed_transaction).unwrap();
+
+    // expecting more assets than were sent out is detected
+    let request = request.with_expected_output_asset(FungibleAsset::mock(FUNGIBLE_ASSET_AMOUNT));
+    assert!(matches!(
+        request.verify_executed_transaction(&executed_transaction),
+        Err(TransactionRequestError::ExpectedOutputAssetMissing(_))
+    ));
+}
+
+#[test]
+fn chained_transactions_are_executed_against_in_flight_state() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+    let account_id = tx_context.account().id();
+    let block_num = tx_context.tx_inputs().block_header().block_num();
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+    let mut chain = LocalTransactionChain::new(tx_context.account().clone());
+    let tx_args = |request: TransactionRequest| {
+        request
+            .with_procedure_call(ProcedureCall::new("::test::account::incr_nonce", [ONE]).unwrap())
+            .build_transaction_args(TransactionKernel::testing_assembler_with_mock_account())
+            .unwrap()
+            .with_advice_inputs(tx_context.tx_args().advice_inputs().clone())
+    };
+
+    // the first transaction creates a note which is consumed by the second transaction
+    let note_script =
+        NoteScript::compile(DEFAULT_NOTE_CODE, TransactionKernel::testing_assembler()).unwrap();
+    let note = NoteBuilder::new(account_id, note_script)
+        .tag(NoteTag::for_local_use_case(0, 0).unwrap())
+        .build(&mut RpoRandomCoin::new([ONE; 4]))
+        .unwrap();
+    let request =
+        TransactionRequest::new(account_id).with_output_note(OutputNote::Full(note.clone()));
+    let first_tx = executor
+        .execute_chained_transaction(&mut chain, block_num, &[], tx_args(request))
+        .unwrap();
+    assert_eq!(chain.account().hash(), first_tx.final_account().hash());
+    assert_eq!(chain.get_output_note(note.id()), Some(&note));
+
+    let second_tx = executor
+        .execute_chained_transaction(
+            &mut chain,
+            block_num,
+            &[note.id()],
+            tx_args(TransactionRequest::new(account_id)),
+        )
+        .unwrap();
+    assert_eq!(second_tx.initial_account().hash(), first_tx.final_account().hash());
+    assert_eq!(second_tx.account_delta().nonce(), Some(Felt::new(3)));
+    assert_eq!(second_tx.input_notes().get_note(0), &InputNote::unauthenticated(note));
+    assert_eq!(chain.output_notes().count(), 0);
+
+    // a transaction executed against the committed state cannot be appended to the chain
+    let stale_tx = executor
+        .execute_transaction(
+            account_id,
+            block_num,
+            &[],
+            tx_args(TransactionRequest::new(account_id)),
+        )
+        .unwrap();
+    assert!(matches!(
+        chain.push(&stale_tx),
+        Err(TransactionChainError::InconsistentInitialAccountState { .. })
+    ));
+
+    // the chained transactions can only be submitted in the order of their execution
+    assert_eq!(chain.next_submission(), Some(first_tx.id()));
+    assert!(matches!(
+        chain.mark_submitted(second_tx.id()),
+        Err(TransactionChainError::OutOfOrderSubmission { .. })
+    ));
+    chain.mark_submitted(first_tx.id()).unwrap();
+    chain.mark_submitted(second_tx.id()).unwrap();
+    assert_eq!(chain.next_submission(), None);
+}
+
+#[test]
+fn unauthenticated_input_notes_are_listed_in_proven_transaction() {
+    let sender = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+    let note_script =
+        NoteScript::compile(DEFAULT_NOTE_CODE, TransactionKernel::testing_assembler()).unwrap();
+    let note = NoteBuilder::new(sender, note_script)
+        .build(&mut RpoRandomCoin::new([ONE; 4]))
+        .unwrap();
+
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .unauthenticated_notes(vec![note.clone()])
+        .build();
+    assert!(!tx_context.tx_inputs().input_notes().get_note(0).is_authenticated());
+
+    let executed_transaction = tx_context.execute().unwrap();
+    let prover = LocalTransactionProver::new(ProvingOptions::default());
+    let proven_transaction = prover.prove(executed_transaction.into()).unwrap();
+
+    // the batch producer matches unauthenticated notes against created notes via their headers
+    assert_eq!(
+        proven_transaction.get_unauthenticated_notes().collect::<Vec<_>>(),
+        vec![note.header()]
+    );
+    assert_eq!(proven_transaction.get_nullifiers().collect::<Vec<_>>(), vec![note.nullifier()]);
+
+    let verifier = TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL);
+    assert!(verifier.verify(proven_transaction).is_ok());
+}
+
+#[test]
+fn consumption_planner_respects_transaction_limits() {
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let faucet_id_2 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2).unwrap();
+    let fungible = FungibleAsset::mock(10);
+    let fungible_2: Asset = FungibleAsset::new(faucet_id_2, 10).unwrap().into();
+    let non_fungible =
+        NonFungibleAsset::mock(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, &NON_FUNGIBLE_ASSET_DATA);
+
+    let note_script =
+        NoteScript::compile(DEFAULT_NOTE_CODE, TransactionKernel::testing_assembler()).unwrap();
+    let mut rng = RpoRandomCoin::new([ONE; 4]);
+    let notes: Vec<Note> = [fungible, fungible, fungible_2, non_fungible, fungible]
+        .into_iter()
+        .map(|asset| {
+            NoteBuilder::new(account_id, note_script.clone())
+                .add_asset(asset)
+                .build(&mut rng)
+                .unwrap()
+        })
+        .collect();
+
+    let auth_call = ProcedureCall::new("::test::account::incr_nonce", [ONE]).unwrap();
+    let planner = ConsumptionPlanner::new(account_id)
+        .with_max_notes_per_tx(3)
+        .unwrap()
+        .with_max_assets_per_tx(1)
+        .unwrap()
+        .with_procedure_call(auth_call.clone());
+
+    // notes are assigned to the first transaction with room for both the note and its assets
+    let requests = planner.plan(&notes).unwrap();
+    let planned_notes: Vec<Vec<NoteId>> =
+        requests.iter().map(TransactionRequest::input_note_ids).collect();
+    assert_eq!(
+        planned_notes,
+        vec![
+            vec![notes[0].id(), notes[1].id(), notes[4].id()],
+            vec![notes[2].id()],
+            vec![notes[3].id()],
+        ]
+    );
+    assert!(requests.iter().all(|request| request.procedure_calls() == [auth_call.clone()]));
+
+    // without the asset limit, only the note limit splits the notes
+    let requests = ConsumptionPlanner::new(account_id)
+        .with_max_notes_per_tx(3)
+        .unwrap()
+        .plan(&notes)
+        .unwrap();
+    assert_eq!(requests.len(), 2);
+
+    // invalid plans and limits are rejected
+    assert!(matches!(
+        planner.plan(&[notes[0].clone(), notes[0].clone()]),
+        Err(TransactionRequestError::DuplicateInputNote(_))
+    ));
+    let multi_asset_note = NoteBuilder::new(account_id, note_script)
+        .add_asset(fungible)
+        .add_asset(fungible_2)
+        .build(&mut rng)
+        .unwrap();
+    assert!(matches!(
+        planner.plan(&[multi_asset_note]),
+        Err(TransactionRequestError::NoteExceedsAssetLimit { num_assets: 2, max: 1, .. })
+    ));
+    assert!(matches!(
+        ConsumptionPlanner::new(account_id)
+            .with_max_notes_per_tx(TransactionKernel::MAX_INPUT_NOTES + 1),
+        Err(TransactionRequestError::InvalidMaxNotesPerTransaction(_))
+    ));
+}
+
+#[test]
+fn transaction_executor_foreign_account() {
+    let storage_slot = AccountStorage::mock_item_0().slot;
+    let (foreign_account, _) = AccountBuilder::new()
+        .init_seed(ChaCha20Rng::from_entropy().gen())
+        .with_component(
+            AccountMockComponent::new_with_slots(
+                TransactionKernel::testing_assembler(),
+                vec![storage_slot.clone()],
+            )
+            .unwrap(),
+        )
+        .nonce(ONE)
+        .build_testing()
+        .unwrap();
+    let foreign_account_id = foreign_account.id();
+    let mock_chain = MockChainBuilder::default().accounts(vec![foreign_account.clone()]).build();
+
+    // the foreign account inputs provided by the mock chain are valid against the latest block
+    let foreign_inputs = mock_chain.get_foreign_account_inputs(foreign_account_id).unwrap();
+    foreign_inputs.validate(&mock_chain.block_header(0)).unwrap();
+
+    // TODO: Temporary fix: Build a native account that has the same code commitment as the foreign
+    // account, see `test_load_foreign_account_twice`.
+    let native_account = Account::from_parts(
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap(),
+        AssetVault::mock(),
+        foreign_account.storage().clone(),
+        foreign_account.code().clone(),
+        ONE,
+    );
+    let tx_context = TransactionContextBuilder::new(native_account).mock_chain(mock_chain).build();
+
+    // keep in sync with actual index of the `get_item` procedure
+    let get_item_root = *foreign_account.code().procedures()[6].mast_root();
+    let tx_script_src = format!(
+        "
+        use.std::sys
+        use.miden::tx
+
+        begin
+            # pad the stack for the `execute_foreign_procedure` execution
+            padw padw padw push.0.0
+            # => [pad(14)]
+
+            # push the index of desired storage item
+            push.0
+
+            # get the hash of the `get_item` account procedure
+            push.{get_item_root}
+
+            # push the foreign account id
+            push.{foreign_account_id}
+            # => [foreign_account_id, FOREIGN_PROC_ROOT, storage_item_index, pad(14)]
+
+            exec.tx::execute_foreign_procedure
+            # => [STORAGE_VALUE]
+
+            push.{storage_value} assert_eqw
+
+            # truncate the stack
+            exec.sys::truncate_stack
+        end
+        ",
+        storage_value = prepare_word(&storage_slot.value()),
+    );
+    let tx_script =
+        TransactionScript::compile(tx_script_src, [], TransactionKernel::testing_assembler())
+            .unwrap();
+    let tx_args = TransactionArgs::new(
+        Some(tx_script),
+        None,
+        tx_context.tx_args().advice_inputs().clone().map,
+    );
+
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    executor
+        .execute_transaction_with_foreign_accounts(
+            account_id,
+            block_ref,
+            &[],
+            &[foreign_account_id],
+            tx_args.clone(),
+        )
+        .unwrap();
+
+    // foreign accounts unknown to the data store are rejected
+    let unknown_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+    assert!(matches!(
+        executor.execute_transaction_with_foreign_accounts(
+            account_id,
+            block_ref,
+            &[],
+            &[unknown_account_id],
+            tx_args,
+        ),
+        Err(TransactionExecutorError::FetchForeignAccountInputsFailed(
+            DataStoreError::AccountNotFound(_)
+        ))
+    ));
+}
+
+#[test]
+fn mock_chain_seals_blocks_at_requested_block_num() {
+    let mut mock_chain = MockChain::new();
+    let account = mock_chain.add_existing_wallet(Auth::NoAuth, vec![]);
+    let note = mock_chain
+        .add_p2id_note(account.id(), account.id(), &[], NoteType::Public)
+        .unwrap();
+    mock_chain.seal_block(None);
+
+    // empty blocks are sealed up to the requested block number
+    let block = mock_chain.seal_block(Some(5));
+    assert_eq!(block.header().block_num(), 5);
+    assert_eq!(mock_chain.blocks().len(), 6);
+    assert_eq!(mock_chain.latest_block_header(), mock_chain.block_header(5));
+    assert_eq!(mock_chain.block_header(3).block_num(), 3);
+    assert_eq!(mock_chain.chain().chain_length(), 5);
+
+    // the note created in the first block can be consumed against the latest block
+    let tx_inputs = mock_chain.get_transaction_inputs(account, None, &[note.id()]);
+    assert_eq!(tx_inputs.block_header().block_num(), 5);
+    assert_eq!(tx_inputs.block_chain().chain_length(), 5);
+    assert!(tx_inputs.block_chain().contains_block(0));
+}
+
+#[test]
+fn note_script_test_context_executes_custom_note_script() {
+    let note_code = format!(
+        "
+        use.miden::note
+        use.test::account
+
+        begin
+            # store the first note input in the first storage slot
+            push.0 exec.note::get_inputs
+            # => [num_inputs, ptr]
+            drop mem_loadw
+            # => [INPUT]
+            push.{STORAGE_INDEX_0} call.account::set_item dropw dropw
+            # => []
+
+            push.1 call.account::incr_nonce drop
+        end
+        "
+    );
+    let input = [Felt::new(9), Felt::new(8), Felt::new(7), Felt::new(6)];
+
+    let result = NoteScriptTestContext::new(note_code).note_inputs(input).execute().unwrap();
+    result.assert_storage_item(STORAGE_INDEX_0, input);
+    result.assert_fungible_balance(
+        AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap(),
+        FUNGIBLE_ASSET_AMOUNT,
+    );
+    result.assert_num_output_notes(0);
+
+    // failing note scripts are reported as execution errors
+    assert!(matches!(
+        NoteScriptTestContext::new("begin push.0 assert end").execute(),
+        Err(NoteScriptTestError::ExecutionFailed(_))
+    ));
+    assert!(matches!(
+        NoteScriptTestContext::new("begin not_an_instruction end").execute(),
+        Err(NoteScriptTestError::InvalidNote(_))
+    ));
+}
+
+#[test]
+fn script_cache_reuses_compiled_scripts() {
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let cache = ScriptCache::new(1, TransactionKernel::testing_assembler_with_mock_account());
+
+    let request = TransactionRequest::new(account_id)
+        .with_procedure_call(ProcedureCall::new("::test::account::incr_nonce", [ONE]).unwrap());
+    let tx_args = request.build_transaction_args_with_cache(&cache).unwrap();
+    let cached_tx_args = request.build_transaction_args_with_cache(&cache).unwrap();
+    assert_eq!(tx_args.tx_script().unwrap().hash(), cached_tx_args.tx_script().unwrap().hash());
+    assert_eq!(
+        cache.stats(),
+        ScriptCacheStats {
+            num_note_scripts: 0,
+            num_tx_scripts: 1,
+            hits: 1,
+            misses: 1
+        }
+    );
+
+    // the least recently used script is evicted once the cache is full
+    let note_script = cache.compile_note_script(DEFAULT_NOTE_CODE).unwrap();
+    assert!(cache.contains_note_script(DEFAULT_NOTE_CODE));
+    let other_code = "begin push.1 drop end";
+    cache.compile_note_script(other_code).unwrap();
+    assert!(!cache.contains_note_script(DEFAULT_NOTE_CODE));
+    assert!(cache.contains_note_script(other_code));
+    assert_eq!(cache.compile_note_script(DEFAULT_NOTE_CODE).unwrap(), note_script);
+
+    // scripts are compiled with the assembler of the cache, so a cache whose assembler does not
+    // provide the mock account library cannot compile the request
+    let other_cache = ScriptCache::new(1, TransactionKernel::testing_assembler());
+    assert!(request.build_transaction_args_with_cache(&other_cache).is_err());
+
+    // a cache with a capacity of 0 does not keep any scripts
+    let executor = TransactionExecutor::new(
+        Arc::new(TransactionContextBuilder::with_standard_account(ONE).build()),
+        None,
+    )
+    .with_options(ExecutorOptions {
+        script_cache_capacity: 0,
+        ..Default::default()
+    });
+    executor.script_cache().compile_note_script(DEFAULT_NOTE_CODE).unwrap();
+    assert_eq!(executor.script_cache().stats().num_note_scripts, 0);
+}
+
+#[test]
+fn executor_enforces_nonce_policy() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+    let account_id = tx_context.account().id();
+    let block_num = tx_context.tx_inputs().block_header().block_num();
+    let executor = |nonce_policy| {
+        TransactionExecutor::new(Arc::new(tx_context.clone()), None)
+            .with_options(ExecutorOptions { nonce_policy, ..Default::default() })
+    };
+    let incr_nonce_args = |value: u64| {
+        TransactionRequest::new(account_id)
+            .with_procedure_call(
+                ProcedureCall::new("::test::account::incr_nonce", [Felt::new(value)]).unwrap(),
+            )
+            .build_transaction_args(TransactionKernel::testing_assembler_with_mock_account())
+            .unwrap()
+            .with_advice_inputs(tx_context.tx_args().advice_inputs().clone())
+    };
+
+    // a transaction which leaves the nonce unchanged is only accepted under the relaxed policy
+    let relaxed = executor(NoncePolicy::Relaxed);
+    let strict = executor(NoncePolicy::Strict);
+    relaxed
+        .execute_transaction(account_id, block_num, &[], tx_context.tx_args().clone())
+        .unwrap();
+    assert!(matches!(
+        strict.execute_transaction(account_id, block_num, &[], tx_context.tx_args().clone()),
+        Err(TransactionExecutorError::NoncePolicyViolation(_))
+    ));
+
+    // incrementing the nonce by one satisfies both policies
+    relaxed
+        .execute_transaction(account_id, block_num, &[], incr_nonce_args(1))
+        .unwrap();
+    strict
+        .execute_transaction(account_id, block_num, &[], incr_nonce_args(1))
+        .unwrap();
+
+    // larger increments are only accepted under the relaxed policy
+    relaxed
+        .execute_transaction(account_id, block_num, &[], incr_nonce_args(2))
+        .unwrap();
+    assert!(matches!(
+        strict.execute_transaction(account_id, block_num, &[], incr_nonce_args(2)),
+        Err(TransactionExecutorError::NoncePolicyViolation(_))
+    ));
+}
+
+#[test]
+fn prover_reports_progress() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+    let executed_transaction = tx_context.execute().unwrap();
+
+    let stages = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let recorded_stages = stages.clone();
+    let prover = LocalTransactionProver::new(ProvingOptions::default()).with_progress(
+        move |stage: ProvingStage| {
+            recorded_stages.lock().unwrap().push(stage);
+        },
+    );
+    let tx_witness = TransactionWitness::from(executed_transaction);
+    prover.prove(tx_witness.clone()).unwrap();
+
+    assert_eq!(
+        *stages.lock().unwrap(),
+        vec![
+            ProvingStage::PreparingInputs,
+            ProvingStage::GeneratingProof,
+            ProvingStage::BuildingProvenTransaction,
+            ProvingStage::Finished,
+        ]
+    );
+
+    // the failure of a proving run is reported as well
+    stages.lock().unwrap().clear();
+    let failing_script = TransactionScript::compile(
+        "begin push.0 assert end",
+        vec![],
+        TransactionKernel::assembler(),
+    )
+    .unwrap();
+    let invalid_witness = TransactionWitness {
+        tx_args: TransactionArgs::with_tx_script(failing_script),
+        ..tx_witness
+    };
+    assert!(prover.prove(invalid_witness).is_err());
+
+    assert_eq!(
+        *stages.lock().unwrap(),
+        vec![
+            ProvingStage::PreparingInputs,
+            ProvingStage::GeneratingProof,
+            ProvingStage::Failed,
+        ]
+    );
+}
+
+#[test]
+fn prover_security_level_is_enforced_by_verifier() {
+    // invalid proving options are rejected
+    assert!(ProofSecurityLevel::Bits96
+        .proving_options_with_blowup(12, HashFunction::Rpo256)
+        .is_err());
+    assert!(ProofSecurityLevel::Bits96
+        .proving_options_with_blowup(4, HashFunction::Rpo256)
+        .is_err());
+    assert!(ProofSecurityLevel::Bits128.proving_options(HashFunction::Blake3_192).is_err());
+    assert!(ProofSecurityLevel::Bits128
+        .proving_options_with_blowup(32, HashFunction::Rpo256)
+        .is_err());
+
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+    let executed_transaction = tx_context.execute().unwrap();
+
+    let prover = LocalTransactionProver::with_security_level(
+        ProofSecurityLevel::Bits96,
+        HashFunction::Blake3_192,
+    )
+    .unwrap();
+    let proven_transaction = prover.prove(executed_transaction.into()).unwrap();
+    let security_level = proven_transaction.proof_security_level();
+    assert!(security_level >= ProofSecurityLevel::Bits96.bits());
+    assert!(security_level < ProofSecurityLevel::Bits128.bits());
+
+    // a verifier requiring a higher security level rejects the proof
+    TransactionVerifier::new(ProofSecurityLevel::Bits96.bits())
+        .verify(proven_transaction.clone())
+        .unwrap();
+    assert_eq!(
+        TransactionVerifier::new(ProofSecurityLevel::Bits128.bits()).verify(proven_transaction),
+        Err(TransactionVerifierError::InsufficientProofSecurityLevel(
+            security_level,
+            ProofSecurityLevel::Bits128.bits()
+        ))
+    );
+}
+
+#[test]
+fn executed_transaction_validation() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+    let executed_transaction = tx_context.execute().unwrap();
+    executed_transaction.validate().unwrap();
+
+    // an account delta which does not lead to the final account state is detected
+    let (account_delta, tx_outputs, tx_witness, tx_measurements) =
+        executed_transaction.into_parts();
+    let invalid_delta = AccountDelta::new(
+        account_delta.storage().clone(),
+        account_delta.vault().clone(),
+        Some(Felt::new(1000)),
+    )
+    .unwrap();
+    let invalid_transaction = ExecutedTransaction::new(
+        tx_witness.tx_inputs.clone(),
+        tx_outputs.clone(),
+        tx_witness.account_codes.clone(),
+        invalid_delta,
+        tx_witness.tx_args.clone(),
+        tx_witness.advice_witness.clone(),
+        tx_measurements.clone(),
+    );
+    assert!(matches!(
+        invalid_transaction.validate(),
+        Err(ExecutedTransactionError::AccountFinalHashMismatch(..))
+    ));
+
+    // a storage delta which does not lead to the final storage commitment is detected
+    let invalid_storage_delta = AccountStorageDelta::new(
+        BTreeMap::from([(STORAGE_INDEX_0, [Felt::new(42); 4])]),
+        account_delta.storage().maps().clone(),
+    )
+    .unwrap();
+    let invalid_delta = AccountDelta::new(
+        invalid_storage_delta,
+        account_delta.vault().clone(),
+        account_delta.nonce(),
+    )
+    .unwrap();
+    let invalid_transaction = ExecutedTransaction::new(
+        tx_witness.tx_inputs.clone(),
+        tx_outputs.clone(),
+        tx_witness.account_codes.clone(),
+        invalid_delta,
+        tx_witness.tx_args.clone(),
+        tx_witness.advice_witness.clone(),
+        tx_measurements.clone(),
+    );
+    assert!(matches!(
+        invalid_transaction.validate(),
+        Err(ExecutedTransactionError::AccountStorageDeltaMismatch(
+            AccountDeltaError::StorageCommitmentMismatch { .. }
+        ))
+    ));
+
+    // an account delta which cannot be applied to the initial account is detected
+    let invalid_delta = AccountDelta::new(
+        account_delta.storage().clone(),
+        account_delta.vault().clone(),
+        Some(tx_witness.tx_inputs.account().nonce()),
+    )
+    .unwrap();
+    let invalid_transaction = ExecutedTransaction::new(
+        tx_witness.tx_inputs,
+        tx_outputs,
+        tx_witness.account_codes,
+        invalid_delta,
+        tx_witness.tx_args,
+        tx_witness.advice_witness,
+        tx_measurements,
+    );
+    assert!(matches!(
+        invalid_transaction.validate(),
+        Err(ExecutedTransactionError::AccountDeltaApplicationFailed(..))
+    ));
+}
+
+#[test]
+fn persisted_witness_can_be_proven_later() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+    let executed_transaction = tx_context.execute().unwrap();
+    let executed_transaction_id = executed_transaction.id();
+
+    let filepath = std::env::temp_dir().join(format!("{executed_transaction_id}.mtxw"));
+    let tx_witness = TransactionWitness::from(executed_transaction);
+    tx_witness.write(&filepath).unwrap();
+
+    // resume proving from the persisted witness
+    let persisted_witness = TransactionWitness::read(&filepath).unwrap();
+    std::fs::remove_file(&filepath).unwrap();
+    assert_eq!(persisted_witness, tx_witness);
+
+    let prover = LocalTransactionProver::new(ProvingOptions::default());
+    let proven_transaction = prover.prove(persisted_witness).unwrap();
+    assert_eq!(proven_transaction.id(), executed_transaction_id);
+
+    // files which do not contain a witness are rejected
+    std::fs::write(&filepath, b"not a witness").unwrap();
+    let err = TransactionWitness::read(&filepath).unwrap_err();
+    std::fs::remove_file(&filepath).unwrap();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+}
@@ -1,3 +1,5 @@
+use alloc::vec::Vec;
+
 use miden_lib::transaction::TransactionKernel;
 use miden_objects::{transaction::ProvenTransaction, vm::ProgramInfo};
 use miden_verifier::verify;
@@ -31,6 +33,69 @@ impl TransactionVerifier {
     /// - Transaction verification fails.
     /// - The security level of the verified proof is insufficient.
     pub fn verify(&self, transaction: ProvenTransaction) -> Result<(), TransactionVerifierError> {
+        self.verify_transaction(&transaction)
+    }
+
+    /// Verifies the provided [ProvenTransaction] against the transaction kernel, and checks that
+    /// the transaction has not expired at the specified block.
+    ///
+    /// As in the `LocalBlockProver`, a transaction is considered expired at
+    /// all blocks with a number greater than or equal to its expiration block number.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The transaction has expired at `block_num`.
+    /// - Transaction verification fails.
+    /// - The security level of the verified proof is insufficient.
+    pub fn verify_at(
+        &self,
+        transaction: ProvenTransaction,
+        block_num: u32,
+    ) -> Result<(), TransactionVerifierError> {
+        // the expiration block number is part of the public outputs of the proof, thus it can be
+        // checked before the (more expensive) proof verification
+        if transaction.expiration_block_num() <= block_num {
+            return Err(TransactionVerifierError::TransactionExpired {
+                expiration_block_num: transaction.expiration_block_num(),
+                block_num,
+            });
+        }
+
+        self.verify_transaction(&transaction)
+    }
+
+    /// Verifies the provided [ProvenTransaction]s against the transaction kernel and returns the
+    /// verification result of each transaction, in the order of the provided transactions.
+    ///
+    /// When the `concurrent` feature is enabled, the transactions are verified in parallel.
+    pub fn verify_batch(
+        &self,
+        transactions: &[ProvenTransaction],
+    ) -> Vec<Result<(), TransactionVerifierError>> {
+        let mut results = Vec::with_capacity(transactions.len());
+
+        #[cfg(not(feature = "concurrent"))]
+        results.extend(transactions.iter().map(|tx| self.verify_transaction(tx)));
+
+        #[cfg(feature = "concurrent")]
+        {
+            use rayon::prelude::*;
+            transactions
+                .par_iter()
+                .map(|tx| self.verify_transaction(tx))
+                .collect_into_vec(&mut results);
+        }
+
+        results
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    fn verify_transaction(
+        &self,
+        transaction: &ProvenTransaction,
+    ) -> Result<(), TransactionVerifierError> {
         // build stack inputs and outputs
         let stack_inputs = TransactionKernel::build_input_stack(
             transaction.account_id(),
@@ -42,8 +107,18 @@ impl TransactionVerifier {
             transaction.account_update().final_state_hash(),
             transaction.output_notes().commitment(),
             transaction.expiration_block_num(),
+            transaction.fee(),
         );
 
+        // the security level of the proof is determined by the options recorded in it, thus, it
+        // can be checked before the (more expensive) proof verification
+        if transaction.proof_security_level() < self.proof_security_level {
+            return Err(TransactionVerifierError::InsufficientProofSecurityLevel(
+                transaction.proof_security_level(),
+                self.proof_security_level,
+            ));
+        }
+
         // verify transaction proof
         let proof_security_level = verify(
             self.tx_program_info.clone(),
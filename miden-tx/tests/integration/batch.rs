@@ -0,0 +1,249 @@
+use miden_lib::{
+    notes::create_p2id_note,
+    transaction::{TransactionKernel, TransactionScriptBuilder},
+};
+use miden_objects::{
+    accounts::{account_id::testing::ACCOUNT_ID_SENDER, AccountId},
+    batches::{BatchId, ProposedBatch, ProvenBatch},
+    crypto::rand::RpoRandomCoin,
+    notes::{Note, NoteType, Nullifier},
+    transaction::{
+        ExecutedTransaction, InputNoteCommitment, OutputNote, ProvenTransaction,
+        ProvenTransactionBuilder,
+    },
+    utils::serde::{Deserializable, Serializable},
+    BatchError, Felt, MIN_PROOF_SECURITY_LEVEL, ONE, ZERO,
+};
+use miden_prover::ProvingOptions;
+use miden_tx::{
+    testing::mock_chain::{Auth, MockChain},
+    LocalBatchProver, LocalTransactionProver, TransactionProver, TransactionVerifier,
+    TransactionVerifierError,
+};
+
+use crate::build_default_auth_script;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Executes transactions against two different wallets, and a transaction against the first
+/// wallet which starts from the same account state as the first transaction.
+pub fn execute_transactions() -> (ExecutedTransaction, ExecutedTransaction, ExecutedTransaction) {
+    let mut chain = MockChain::new();
+    let faucet = chain.add_existing_faucet(Auth::NoAuth, "POL", 100000u64);
+    let wallet_1 = chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let wallet_2 = chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let sender_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+
+    let mut execute = |target: AccountId, amount: u64| {
+        chain
+            .add_p2id_note(sender_id, target, &[faucet.mint(amount)], NoteType::Public)
+            .unwrap();
+        chain.seal_block(None);
+
+        let executed_transaction = chain
+            .build_tx_context(target)
+            .tx_script(build_default_auth_script())
+            .build()
+            .execute()
+            .unwrap();
+
+        // consume the note so that it is not available to the next transaction
+        chain.add_executed_transaction(executed_transaction.clone());
+        chain.seal_block(None);
+
+        executed_transaction
+    };
+
+    let tx_1 = execute(wallet_1.id(), 100);
+    let tx_2 = execute(wallet_2.id(), 200);
+    let tx_3 = execute(wallet_1.id(), 300);
+
+    (tx_1, tx_2, tx_3)
+}
+
+/// Executes a transaction creating a P2ID note, and a transaction against the target wallet which
+/// consumes the note as an unauthenticated note before it is recorded in the chain.
+pub fn execute_note_transfer() -> (ExecutedTransaction, ExecutedTransaction, Note) {
+    let mut chain = MockChain::new();
+    let faucet = chain.add_existing_faucet(Auth::NoAuth, "POL", 100000u64);
+    let sender = chain.add_existing_wallet(Auth::BasicAuth, vec![faucet.mint(100)]);
+    let target = chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    chain.seal_block(None);
+
+    let note = create_p2id_note(
+        sender.id(),
+        target.id(),
+        vec![faucet.mint(100)],
+        NoteType::Public,
+        ZERO,
+        &mut RpoRandomCoin::new([ONE; 4]),
+    )
+    .unwrap();
+    let tx_script = TransactionScriptBuilder::new()
+        .send_note(note.clone())
+        .with_rpo_falcon512_auth()
+        .build(TransactionKernel::assembler())
+        .unwrap();
+
+    let create_tx = chain
+        .build_tx_context(sender.id())
+        .tx_script(tx_script)
+        .expected_notes(vec![OutputNote::Full(note.clone())])
+        .build()
+        .execute()
+        .unwrap();
+    let consume_tx = chain
+        .build_tx_context(target.id())
+        .unauthenticated_notes(vec![note.clone()])
+        .tx_script(build_default_auth_script())
+        .build()
+        .execute()
+        .unwrap();
+
+    (create_tx, consume_tx, note)
+}
+
+pub fn prove_transaction(executed_transaction: ExecutedTransaction) -> ProvenTransaction {
+    let prover = LocalTransactionProver::new(ProvingOptions::default());
+    prover.prove(executed_transaction.into()).unwrap()
+}
+
+// BATCH TESTS
+// ================================================================================================
+
+#[test]
+fn prove_batch_of_transactions() {
+    let (tx_1, tx_2, _) = execute_transactions();
+    let tx_1 = prove_transaction(tx_1);
+    let tx_2 = prove_transaction(tx_2);
+
+    let batch = ProposedBatch::new(vec![tx_1.clone(), tx_2.clone()]).unwrap();
+
+    assert_eq!(batch.id(), BatchId::compute([tx_1.id(), tx_2.id()].iter()));
+    assert_eq!(batch.account_updates().count(), 2);
+    assert_eq!(batch.input_notes().len(), 2);
+    assert!(batch.output_notes().is_empty());
+    assert_eq!(
+        batch.expiration_block_num(),
+        tx_1.expiration_block_num().min(tx_2.expiration_block_num())
+    );
+
+    for (update, tx) in batch.account_updates().zip([&tx_1, &tx_2]) {
+        assert_eq!(update.account_id(), tx.account_id());
+        assert_eq!(update.init_state_hash(), tx.account_update().init_state_hash());
+        assert_eq!(update.final_state_hash(), tx.account_update().final_state_hash());
+        assert_eq!(update.transactions(), &[tx.id()]);
+    }
+
+    let output_notes_root = batch.output_notes_root();
+    let prover = LocalBatchProver::new(MIN_PROOF_SECURITY_LEVEL);
+    let proven_batch = prover.prove(batch).unwrap();
+
+    assert_eq!(proven_batch.transactions(), &[tx_1, tx_2]);
+    assert_eq!(proven_batch.account_updates().len(), 2);
+    assert_eq!(proven_batch.output_notes_root(), output_notes_root);
+
+    // The transaction proofs kept in the batch can be verified by consumers of the batch
+    let verifier = TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL);
+    assert!(verifier
+        .verify_batch(proven_batch.transactions())
+        .into_iter()
+        .all(|r| r.is_ok()));
+
+    // Serialize & deserialize the ProvenBatch
+    let serialized_batch = proven_batch.to_bytes();
+    assert_eq!(ProvenBatch::read_from_bytes(&serialized_batch).unwrap(), proven_batch);
+}
+
+#[test]
+fn proposed_batch_validation() {
+    let (tx_1, _, tx_3) = execute_transactions();
+    let tx_1 = prove_transaction(tx_1);
+    let tx_3 = prove_transaction(tx_3);
+
+    assert_eq!(ProposedBatch::new(vec![]).unwrap_err(), BatchError::EmptyBatch);
+
+    assert_eq!(
+        ProposedBatch::new(vec![tx_1.clone(), tx_1.clone()]).unwrap_err(),
+        BatchError::DuplicateTransaction(tx_1.id())
+    );
+
+    // both transactions against the first wallet start from the same account state
+    assert_eq!(
+        ProposedBatch::new(vec![tx_1.clone(), tx_3.clone()]).unwrap_err(),
+        BatchError::InconsistentAccountStateTransition {
+            account_id: tx_1.account_id(),
+            expected: tx_1.account_update().final_state_hash(),
+            actual: tx_3.account_update().init_state_hash(),
+        }
+    );
+}
+
+#[test]
+fn unauthenticated_notes_are_erased_within_batch() {
+    let (create_tx, consume_tx, note) = execute_note_transfer();
+    let create_tx = prove_transaction(create_tx);
+    let consume_tx = prove_transaction(consume_tx);
+
+    // the note is created and consumed within the batch, so it is erased from the batch
+    let batch = ProposedBatch::new(vec![create_tx.clone(), consume_tx.clone()]).unwrap();
+    assert!(batch.input_notes().is_empty());
+    assert!(batch.output_notes().is_empty());
+    batch.validate_note_erasure().unwrap();
+
+    // without the creating transaction, the note must be authenticated by the block producer
+    let batch = ProposedBatch::new(vec![consume_tx.clone()]).unwrap();
+    assert_eq!(batch.unauthenticated_input_notes().collect::<Vec<_>>(), vec![note.header()]);
+    assert_eq!(
+        batch.validate_note_erasure().unwrap_err(),
+        BatchError::UnauthenticatedNoteNotFound(note.id())
+    );
+
+    // a note cannot be consumed within the batch before it is created
+    assert_eq!(
+        ProposedBatch::new(vec![consume_tx.clone(), create_tx.clone()]).unwrap_err(),
+        BatchError::NoteConsumedBeforeCreation(note.id())
+    );
+
+    // a transaction consuming two unauthenticated notes with the same ID but different nullifiers
+    // is rejected
+    let mut forged_note = Nullifier::from([ONE, ONE, ONE, Felt::new(2)]).to_bytes();
+    forged_note.extend(Some(*note.header()).to_bytes());
+    let forged_note = InputNoteCommitment::read_from_bytes(&forged_note).unwrap();
+    let forged_tx = ProvenTransactionBuilder::new(
+        consume_tx.account_id(),
+        consume_tx.account_update().init_state_hash(),
+        consume_tx.account_update().final_state_hash(),
+        consume_tx.block_ref(),
+        consume_tx.expiration_block_num(),
+        consume_tx.proof().clone(),
+    )
+    .account_update_details(consume_tx.account_update().details().clone())
+    .add_input_notes(consume_tx.input_notes().iter().cloned().chain([forged_note]))
+    .build()
+    .unwrap();
+    assert_eq!(
+        ProposedBatch::new(vec![create_tx, forged_tx]).unwrap_err(),
+        BatchError::DuplicateUnauthenticatedNote(note.id())
+    );
+}
+
+#[test]
+fn verify_batch_of_transactions() {
+    let (tx_1, tx_2, _) = execute_transactions();
+    let transactions = [prove_transaction(tx_1), prove_transaction(tx_2)];
+
+    let verifier = TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL);
+    let results = verifier.verify_batch(&transactions);
+    assert_eq!(results, vec![Ok(()), Ok(())]);
+
+    // a verifier requiring a higher security level rejects every transaction
+    let verifier = TransactionVerifier::new(1000);
+    let results = verifier.verify_batch(&transactions);
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(|result| matches!(
+        result,
+        Err(TransactionVerifierError::InsufficientProofSecurityLevel(_, 1000))
+    )));
+}
@@ -0,0 +1,223 @@
+use miden_objects::{
+    batches::ProposedBatch,
+    block::{AccountTree, Block, NullifierTree, ProposedBlock},
+    transaction::ProvenTransaction,
+    BatchError, BlockError, Digest, MIN_PROOF_SECURITY_LEVEL,
+};
+use miden_tx::{BlockProverError, LocalBatchProver, LocalBlockProver};
+
+use crate::batch::{execute_note_transfer, execute_transactions, prove_transaction};
+
+// BLOCK TESTS
+// ================================================================================================
+
+#[test]
+fn prove_blocks_from_batches() {
+    let (tx_1, tx_2, _) = execute_transactions();
+    let tx_1 = prove_transaction(tx_1);
+    let tx_2 = prove_transaction(tx_2);
+
+    let batch_prover = LocalBatchProver::new(MIN_PROOF_SECURITY_LEVEL);
+    let batch_1 = batch_prover.prove(ProposedBatch::new(vec![tx_1.clone()]).unwrap()).unwrap();
+    let batch_2 = batch_prover.prove(ProposedBatch::new(vec![tx_2.clone()]).unwrap()).unwrap();
+
+    let proposed_block = ProposedBlock::new(vec![batch_1.clone(), batch_2.clone()]).unwrap();
+    assert_eq!(proposed_block.batch_ids(), &[batch_1.id(), batch_2.id()]);
+    assert_eq!(proposed_block.account_updates().count(), 2);
+    assert_eq!(proposed_block.nullifiers().len(), 2);
+    assert_eq!(proposed_block.output_note_batches().len(), 2);
+
+    let note_root = proposed_block.build_note_tree().root();
+    let tx_hash = proposed_block.compute_tx_hash();
+
+    // the accounts must be in the chain, since they are not new accounts
+    assert_eq!(
+        LocalBlockProver::new()
+            .prove(ProposedBlock::new(vec![batch_1.clone()]).unwrap(), 10)
+            .unwrap_err(),
+        BlockProverError::InconsistentAccountState {
+            account_id: tx_1.account_id(),
+            expected: Digest::default(),
+            actual: tx_1.account_update().init_state_hash(),
+        }
+    );
+
+    let (mut block_prover, genesis) = prover_with_accounts(&[&tx_1, &tx_2], NullifierTree::new());
+    let chain_root = block_prover.chain().peaks().hash_peaks();
+    let block = block_prover.prove(proposed_block, 10).unwrap();
+    let header = block.header();
+
+    assert_eq!(header.block_num(), 1);
+    assert_eq!(header.prev_hash(), genesis.hash());
+    assert_eq!(header.chain_root(), chain_root);
+    assert_eq!(header.account_root(), block_prover.accounts().root());
+    assert_eq!(header.nullifier_root(), block_prover.nullifiers().root());
+    assert_eq!(header.note_root(), note_root);
+    assert_eq!(header.tx_hash(), tx_hash);
+    assert_eq!(header.tx_hash(), block.compute_tx_hash());
+    assert_eq!(header.proof_hash(), Digest::default());
+    assert_eq!(header.timestamp(), 10);
+    assert_eq!(block.updated_accounts().len(), 2);
+    assert_eq!(block.nullifiers().len(), 2);
+
+    for tx in [&tx_1, &tx_2] {
+        let update = block
+            .updated_accounts()
+            .iter()
+            .find(|update| update.account_id() == tx.account_id())
+            .unwrap();
+        assert_eq!(update.new_state_hash(), tx.account_update().final_state_hash());
+        assert_eq!(update.transactions(), &[tx.id()]);
+    }
+
+    // an empty block extends the chain
+    let chain_root = block_prover.chain().peaks().hash_peaks();
+    let empty_block = block_prover.prove(ProposedBlock::new(vec![]).unwrap(), 20).unwrap();
+
+    assert_eq!(empty_block.header().block_num(), 2);
+    assert_eq!(empty_block.header().prev_hash(), block.hash());
+    assert_eq!(empty_block.header().chain_root(), chain_root);
+    assert_eq!(empty_block.header().account_root(), header.account_root());
+    assert_eq!(empty_block.header().nullifier_root(), header.nullifier_root());
+    assert_eq!(block_prover.last_header(), Some(&empty_block.header()));
+
+    // the batches cannot be included in the chain a second time
+    assert_eq!(
+        block_prover.prove(ProposedBlock::new(vec![batch_1]).unwrap(), 30).unwrap_err(),
+        BlockProverError::InconsistentAccountState {
+            account_id: tx_1.account_id(),
+            expected: tx_1.account_update().final_state_hash(),
+            actual: tx_1.account_update().init_state_hash(),
+        }
+    );
+}
+
+#[test]
+fn failed_block_leaves_chain_state_unchanged() {
+    let (tx_1, tx_2, _) = execute_transactions();
+    let tx_1 = prove_transaction(tx_1);
+    let tx_2 = prove_transaction(tx_2);
+    let spent_nullifier = tx_2.input_notes().get_note(0).nullifier();
+
+    let batch_prover = LocalBatchProver::new(MIN_PROOF_SECURITY_LEVEL);
+    let batch_1 = batch_prover.prove(ProposedBatch::new(vec![tx_1.clone()]).unwrap()).unwrap();
+    let batch_2 = batch_prover.prove(ProposedBatch::new(vec![tx_2.clone()]).unwrap()).unwrap();
+
+    // set up a chain in which the note consumed by the second transaction is already spent
+    let (mut block_prover, genesis) = prover_with_accounts(
+        &[&tx_1, &tx_2],
+        NullifierTree::with_entries([(spent_nullifier, 0)]).unwrap(),
+    );
+    let chain_root = block_prover.chain().peaks().hash_peaks();
+    let account_root = block_prover.accounts().root();
+    let nullifier_root = block_prover.nullifiers().root();
+
+    // the account updates of the first batch are valid, but the block as a whole is not
+    assert_eq!(
+        block_prover
+            .prove(ProposedBlock::new(vec![batch_1.clone(), batch_2]).unwrap(), 20)
+            .unwrap_err(),
+        BlockProverError::NullifierAlreadySpent(spent_nullifier)
+    );
+    assert_eq!(block_prover.chain().peaks().hash_peaks(), chain_root);
+    assert_eq!(block_prover.accounts().root(), account_root);
+    assert_eq!(block_prover.nullifiers().root(), nullifier_root);
+    assert_eq!(block_prover.last_header(), Some(&genesis.header()));
+
+    // the chain state can still be extended by a valid block
+    let block = block_prover.prove(ProposedBlock::new(vec![batch_1]).unwrap(), 20).unwrap();
+    assert_eq!(block.header().block_num(), 1);
+    assert_eq!(block.header().account_root(), block_prover.accounts().root());
+    assert_eq!(block.header().nullifier_root(), block_prover.nullifiers().root());
+}
+
+#[test]
+fn proposed_block_validation() {
+    let (tx_1, _, tx_3) = execute_transactions();
+    let tx_1 = prove_transaction(tx_1);
+    let tx_3 = prove_transaction(tx_3);
+
+    let batch_prover = LocalBatchProver::new(MIN_PROOF_SECURITY_LEVEL);
+    let batch_1 = batch_prover.prove(ProposedBatch::new(vec![tx_1.clone()]).unwrap()).unwrap();
+    let batch_3 = batch_prover.prove(ProposedBatch::new(vec![tx_3.clone()]).unwrap()).unwrap();
+
+    assert_eq!(
+        ProposedBlock::new(vec![batch_1.clone(), batch_1.clone()]).unwrap_err(),
+        BlockError::DuplicateBatch(batch_1.id())
+    );
+
+    // both batches update the first wallet starting from the same account state
+    assert_eq!(
+        ProposedBlock::new(vec![batch_1, batch_3]).unwrap_err(),
+        BlockError::InvalidAccountUpdate(BatchError::InconsistentAccountStateTransition {
+            account_id: tx_1.account_id(),
+            expected: tx_1.account_update().final_state_hash(),
+            actual: tx_3.account_update().init_state_hash(),
+        })
+    );
+}
+
+#[test]
+fn unauthenticated_notes_are_authenticated_against_the_chain() {
+    let (create_tx, consume_tx, note) = execute_note_transfer();
+    let create_tx = prove_transaction(create_tx);
+    let consume_tx = prove_transaction(consume_tx);
+
+    let batch_prover = LocalBatchProver::new(MIN_PROOF_SECURITY_LEVEL);
+    let create_batch = batch_prover
+        .prove(ProposedBatch::new(vec![create_tx.clone()]).unwrap())
+        .unwrap();
+    let consume_batch = batch_prover
+        .prove(ProposedBatch::new(vec![consume_tx.clone()]).unwrap())
+        .unwrap();
+    let (mut block_prover, _) =
+        prover_with_accounts(&[&create_tx, &consume_tx], NullifierTree::new());
+
+    // a note created by an earlier batch in the block is erased from the block
+    let proposed_block =
+        ProposedBlock::new(vec![create_batch.clone(), consume_batch.clone()]).unwrap();
+    assert!(proposed_block.unauthenticated_notes().is_empty());
+    assert!(!proposed_block.nullifiers().contains(&note.nullifier()));
+    block_prover.clone().prove(proposed_block, 10).unwrap();
+
+    // otherwise, the note must have been created in the chain
+    let proposed_block = ProposedBlock::new(vec![consume_batch.clone()]).unwrap();
+    assert_eq!(proposed_block.unauthenticated_notes(), &[*note.header()]);
+    assert_eq!(
+        block_prover.prove(proposed_block, 10).unwrap_err(),
+        BlockProverError::UnauthenticatedNoteNotFound(note.id())
+    );
+
+    block_prover.prove(ProposedBlock::new(vec![create_batch]).unwrap(), 10).unwrap();
+    let block = block_prover
+        .prove(ProposedBlock::new(vec![consume_batch]).unwrap(), 20)
+        .unwrap();
+    assert_eq!(block.nullifiers(), &[note.nullifier()]);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a block prover for a chain consisting of an empty genesis block, in which the accounts
+/// updated by the provided transactions are in their initial states, together with the genesis
+/// block.
+fn prover_with_accounts(
+    txs: &[&ProvenTransaction],
+    nullifiers: NullifierTree,
+) -> (LocalBlockProver, Block) {
+    let mut genesis_prover = LocalBlockProver::new();
+    let genesis = genesis_prover.prove(ProposedBlock::new(vec![]).unwrap(), 0).unwrap();
+    let accounts = AccountTree::with_entries(
+        txs.iter().map(|tx| (tx.account_id(), tx.account_update().init_state_hash())),
+    )
+    .unwrap();
+
+    let block_prover = LocalBlockProver::with_state(
+        genesis_prover.chain().clone(),
+        accounts,
+        nullifiers,
+        genesis.header(),
+    );
+
+    (block_prover, genesis)
+}
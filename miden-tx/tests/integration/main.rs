@@ -1,5 +1,8 @@
 extern crate alloc;
 
+mod batch;
+mod block;
+mod multisig;
 mod scripts;
 mod wallet;
 
@@ -0,0 +1,159 @@
+use alloc::sync::Arc;
+
+use miden_lib::{
+    accounts::{auth::RpoFalcon512Multisig, wallets::BasicWallet},
+    errors::tx_kernel_errors::ERR_MULTISIG_INVALID_SIGNER_INDEX,
+    transaction::{TransactionKernel, TransactionKernelError},
+};
+use miden_objects::{
+    accounts::{
+        account_id::testing::ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN, Account,
+        AccountId, AuthSecretKey,
+    },
+    assets::AssetVault,
+    crypto::dsa::rpo_falcon512::{PublicKey, SecretKey},
+    transaction::{ExecutedTransaction, TransactionArgs, TransactionScript},
+    Felt, Word,
+};
+use miden_tx::{
+    auth::{BasicAuthenticator, MultiAuthenticator, TransactionAuthenticator},
+    testing::{TransactionContext, TransactionContextBuilder},
+    TransactionExecutor, TransactionExecutorError,
+};
+use rand::rngs::StdRng;
+use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+
+use crate::prove_and_verify_transaction;
+
+const MULTISIG_AUTH_SCRIPT: &str = "
+    begin
+        call.::miden::contracts::auth::multisig::auth_tx_rpo_falcon512_multisig
+    end
+";
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the secret keys of the signers of the multisig account.
+fn signer_keys(num_signers: u8) -> Vec<SecretKey> {
+    (0..num_signers)
+        .map(|signer| SecretKey::with_rng(&mut ChaCha20Rng::from_seed([signer; 32])))
+        .collect()
+}
+
+/// Returns an authenticator which can sign with the provided keys only, with every key managed by
+/// a separate authenticator.
+fn authenticator_for(keys: &[&SecretKey]) -> Arc<dyn TransactionAuthenticator> {
+    let authenticators = keys
+        .iter()
+        .map(|key| {
+            let pub_key: Word = key.public_key().into();
+            Arc::new(BasicAuthenticator::<StdRng>::new(&[(
+                pub_key,
+                AuthSecretKey::RpoFalcon512((*key).clone()),
+            )])) as Arc<dyn TransactionAuthenticator>
+        })
+        .collect();
+
+    Arc::new(MultiAuthenticator::new(authenticators))
+}
+
+/// Returns a wallet account requiring `threshold` signatures of the provided keys.
+fn multisig_account(threshold: u32, keys: &[SecretKey]) -> Account {
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let public_keys: Vec<PublicKey> = keys.iter().map(SecretKey::public_key).collect();
+
+    let (account_code, account_storage) = Account::initialize_from_components(
+        account_id.account_type(),
+        &[
+            RpoFalcon512Multisig::new(threshold, public_keys).unwrap().into(),
+            BasicWallet.into(),
+        ],
+    )
+    .unwrap();
+
+    Account::from_parts(
+        account_id,
+        AssetVault::default(),
+        account_storage,
+        account_code,
+        Felt::new(1),
+    )
+}
+
+/// Executes a transaction against the provided account which is authenticated by the signers
+/// with the provided indices, using an authenticator knowing the provided keys.
+fn execute_multisig_transaction(
+    tx_context: &TransactionContext,
+    signer_indices: &[u32],
+    authenticator_keys: &[&SecretKey],
+) -> Result<ExecutedTransaction, TransactionExecutorError> {
+    let account = tx_context.account();
+    let executor = TransactionExecutor::new(
+        Arc::new(tx_context.clone()),
+        Some(authenticator_for(authenticator_keys)),
+    );
+
+    let tx_script =
+        TransactionScript::compile(MULTISIG_AUTH_SCRIPT, vec![], TransactionKernel::assembler())
+            .unwrap();
+    let mut tx_args = TransactionArgs::with_tx_script(tx_script);
+    tx_args.extend_advice_map([RpoFalcon512Multisig::signers_advice(
+        account.id(),
+        account.nonce(),
+        signer_indices,
+    )]);
+
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    executor.execute_transaction(account.id(), block_ref, &[], tx_args)
+}
+
+// TESTS
+// ================================================================================================
+
+/// Tests a 2-of-3 multisig wallet end to end, with the signatures produced by the keys of the
+/// individual signers.
+#[test]
+fn prove_multisig_wallet_transaction() {
+    let keys = signer_keys(3);
+    let account = multisig_account(2, &keys);
+    let tx_context = TransactionContextBuilder::new(account.clone()).build();
+
+    // any two signers can authenticate a transaction
+    for signers in [[0, 1], [0, 2], [1, 2]] {
+        let signer_keys: Vec<&SecretKey> =
+            signers.iter().map(|signer| &keys[*signer as usize]).collect();
+        let executed_transaction =
+            execute_multisig_transaction(&tx_context, &signers, &signer_keys).unwrap();
+        assert_eq!(executed_transaction.account_delta().nonce(), Some(Felt::new(2)));
+
+        prove_and_verify_transaction(executed_transaction).unwrap();
+    }
+
+    // a single signer is below the threshold
+    let result = execute_multisig_transaction(&tx_context, &[0], &[&keys[0]]);
+    assert!(result.is_err());
+
+    // the signature of a signer cannot be provided without its key
+    let result = execute_multisig_transaction(&tx_context, &[0, 1], &[&keys[0], &keys[2]]);
+    assert!(result.is_err());
+
+    // a signer cannot sign twice to reach the threshold
+    let result = execute_multisig_transaction(&tx_context, &[0, 0], &[&keys[0]]);
+    assert!(matches!(
+        result,
+        Err(TransactionExecutorError::KernelAssertionFailed(
+            TransactionKernelError::FailedAssertion { err_code, .. }
+        )) if err_code == ERR_MULTISIG_INVALID_SIGNER_INDEX
+    ));
+
+    // signer indices must be within bounds
+    let result = execute_multisig_transaction(&tx_context, &[0, 3], &[&keys[0]]);
+    assert!(matches!(
+        result,
+        Err(TransactionExecutorError::KernelAssertionFailed(
+            TransactionKernelError::FailedAssertion { err_code, .. }
+        )) if err_code == ERR_MULTISIG_INVALID_SIGNER_INDEX
+    ));
+}
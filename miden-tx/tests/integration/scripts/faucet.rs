@@ -2,7 +2,10 @@ extern crate alloc;
 
 use alloc::sync::Arc;
 
-use miden_lib::accounts::{auth::RpoFalcon512, faucets::BasicFungibleFaucet};
+use miden_lib::{
+    accounts::{auth::RpoFalcon512, faucets::BasicFungibleFaucet},
+    transaction::TransactionScriptBuilder,
+};
 use miden_objects::{
     accounts::{account_id::testing::ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN, Account, AccountId},
     assets::{Asset, AssetVault, FungibleAsset, TokenSymbol},
@@ -51,29 +54,12 @@ fn prove_faucet_contract_mint_fungible_asset_succeeds() {
 
     assert_eq!(tag.validate(note_type), Ok(tag));
 
-    let tx_script_code = format!(
-        "
-            begin
-
-                push.{recipient}
-                push.{note_execution_hint}
-                push.{note_type}
-                push.{aux}
-                push.{tag}
-                push.{amount}
-                call.::miden::contracts::faucets::basic_fungible::distribute
-
-                call.::miden::contracts::auth::basic::auth_tx_rpo_falcon512
-                dropw dropw drop
-
-            end
-            ",
-        note_type = note_type as u8,
-        recipient = prepare_word(&recipient),
-        aux = aux,
-        tag = u32::from(tag),
-        note_execution_hint = Felt::from(note_execution_hint)
-    );
+    let metadata =
+        NoteMetadata::new(faucet_account.id(), note_type, tag, note_execution_hint, aux).unwrap();
+    let tx_script_code = TransactionScriptBuilder::new()
+        .mint_to(amount.as_int(), recipient.into(), metadata)
+        .with_rpo_falcon512_auth()
+        .source();
 
     let tx_args = build_tx_args_from_script(&tx_script_code);
 
@@ -92,11 +78,7 @@ fn prove_faucet_contract_mint_fungible_asset_succeeds() {
     let id = NoteId::new(recipient.into(), assets.commitment());
 
     assert_eq!(output_note.id(), id);
-    assert_eq!(
-        output_note.metadata(),
-        &NoteMetadata::new(faucet_account.id(), NoteType::Private, tag, note_execution_hint, aux)
-            .unwrap()
-    );
+    assert_eq!(output_note.metadata(), &metadata);
 }
 
 #[test]
@@ -1,4 +1,6 @@
 mod faucet;
 mod p2id;
 mod p2idr;
+mod split;
 mod swap;
+mod swapp;
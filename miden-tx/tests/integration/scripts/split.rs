@@ -0,0 +1,170 @@
+use miden_lib::{
+    errors::tx_kernel_errors::ERR_SPLIT_INVALID_SHARE,
+    notes::{scripts, SplitNote, SplitRecipient},
+    transaction::{TransactionKernel, TransactionKernelError},
+};
+use miden_objects::{
+    accounts::{
+        account_id::testing::{
+            ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN, ACCOUNT_ID_SENDER,
+        },
+        Account, AccountId,
+    },
+    assets::AssetVault,
+    crypto::rand::RpoRandomCoin,
+    notes::{Note, NoteInputs, NoteRecipient, NoteType},
+    testing::account_code::DEFAULT_AUTH_SCRIPT,
+    transaction::TransactionScript,
+    Felt, ONE,
+};
+use miden_tx::{
+    testing::mock_chain::{Auth, MockChain},
+    TransactionExecutorError,
+};
+
+use crate::prove_and_verify_transaction;
+
+#[test]
+fn prove_split_script() {
+    // Create assets
+    let mut chain = MockChain::new();
+    let faucet_1 = chain.add_existing_faucet(Auth::NoAuth, "POL", 100000u64);
+    let faucet_2 = chain.add_existing_faucet(Auth::NoAuth, "ETH", 100000u64);
+
+    // Create the recipients, which do not need to exist on chain, and the consuming account
+    let sender_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let recipients = vec![
+        SplitRecipient::new(
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap(),
+            50,
+        )
+        .unwrap(),
+        SplitRecipient::new(
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap(),
+            30,
+        )
+        .unwrap(),
+        SplitRecipient::new(sender_id, 20).unwrap(),
+    ];
+    let executor_account = chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+
+    // Create the note containing the SPLIT script
+    let split = SplitNote::new(
+        sender_id,
+        recipients,
+        vec![faucet_1.mint(1001), faucet_2.mint(3)],
+        NoteType::Public,
+        Felt::new(27),
+        &mut RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+
+    chain.add_note(split.note().clone());
+    chain.seal_block(None);
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    let transaction_script =
+        TransactionScript::compile(DEFAULT_AUTH_SCRIPT, vec![], TransactionKernel::assembler())
+            .unwrap();
+
+    let executed_transaction = chain
+        .build_tx_context(executor_account.id())
+        .tx_script(transaction_script)
+        .build()
+        .execute()
+        .unwrap();
+
+    // the assets pass through the vault of the executing account, which is left unchanged
+    let executor_account_after: Account = Account::from_parts(
+        executor_account.id(),
+        AssetVault::default(),
+        executor_account.storage().clone(),
+        executor_account.code().clone(),
+        Felt::new(2),
+    );
+    assert_eq!(executed_transaction.final_account().hash(), executor_account_after.hash());
+
+    // Check that a P2ID note has been created for every recipient
+    let payout_notes = split.payout_notes(executor_account.id()).unwrap();
+    assert_eq!(executed_transaction.output_notes().num_notes(), payout_notes.len());
+
+    for (idx, payout_note) in payout_notes.iter().enumerate() {
+        let output_note = executed_transaction.output_notes().get_note(idx);
+        assert_eq!(output_note.id(), payout_note.id());
+        assert_eq!(output_note.metadata(), payout_note.metadata());
+    }
+
+    // 1001 units are split into 500, 300 and 201 units, 3 units into 1, 0 and 2 units
+    assert_eq!(payout_notes[0].assets().num_assets(), 2);
+    assert_eq!(payout_notes[1].assets().iter().collect::<Vec<_>>(), vec![&faucet_1.mint(300)]);
+    assert_eq!(
+        payout_notes[2].assets().iter().collect::<Vec<_>>(),
+        vec![&faucet_1.mint(201), &faucet_2.mint(2)]
+    );
+
+    // Prove, serialize/deserialize and verify the transaction
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+}
+
+#[test]
+fn split_script_rejects_shares_exceeding_u32() {
+    let mut chain = MockChain::new();
+    let faucet = chain.add_existing_faucet(Auth::NoAuth, "POL", 100000u64);
+    let executor_account = chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+
+    let sender_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let recipients = vec![
+        SplitRecipient::new(
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap(),
+            2,
+        )
+        .unwrap(),
+        SplitRecipient::new(sender_id, 1).unwrap(),
+    ];
+    let split = SplitNote::new(
+        sender_id,
+        recipients,
+        vec![faucet.mint(1000)],
+        NoteType::Public,
+        Felt::new(27),
+        &mut RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+
+    // replace the share of the last recipient with -1, such that the shares would sum up to 1 in
+    // the field
+    let mut inputs = split.note().inputs().values().to_vec();
+    let last_share = inputs.len() - 3;
+    inputs[last_share] = -ONE;
+
+    let note = Note::new(
+        split.note().assets().clone(),
+        *split.note().metadata(),
+        NoteRecipient::new(
+            split.note().serial_num(),
+            scripts::split(),
+            NoteInputs::new(inputs).unwrap(),
+        ),
+    );
+    chain.add_note(note);
+    chain.seal_block(None);
+
+    let transaction_script =
+        TransactionScript::compile(DEFAULT_AUTH_SCRIPT, vec![], TransactionKernel::assembler())
+            .unwrap();
+
+    let result = chain
+        .build_tx_context(executor_account.id())
+        .tx_script(transaction_script)
+        .build()
+        .execute();
+
+    assert!(matches!(
+        result,
+        Err(TransactionExecutorError::KernelAssertionFailed(
+            TransactionKernelError::FailedAssertion { err_code, .. }
+        )) if err_code == ERR_SPLIT_INVALID_SHARE
+    ));
+}
@@ -0,0 +1,207 @@
+use std::collections::BTreeMap;
+
+use miden_lib::{
+    notes::SwappNote,
+    transaction::{TransactionKernel, AUTH_TX_RPO_FALCON512_PROC},
+};
+use miden_objects::{
+    accounts::{account_id::testing::ACCOUNT_ID_SENDER, Account, AccountId},
+    assets::AssetVault,
+    crypto::rand::RpoRandomCoin,
+    notes::NoteType,
+    testing::account_code::DEFAULT_AUTH_SCRIPT,
+    transaction::{OutputNote, TransactionScript},
+    Felt, ZERO,
+};
+use miden_tx::{
+    testing::mock_chain::{Auth, MockChain},
+    ProcedureCall, TransactionRequest,
+};
+
+use crate::prove_and_verify_transaction;
+
+#[test]
+fn prove_swapp_script_partial_fill() {
+    // Create assets
+    let mut chain = MockChain::new();
+    let offered_faucet = chain.add_existing_faucet(Auth::NoAuth, "POL", 100000u64);
+    let requested_faucet = chain.add_existing_faucet(Auth::NoAuth, "ETH", 100000u64);
+    let offered_asset = offered_faucet.mint(100);
+    let requested_asset = requested_faucet.mint(50);
+
+    // Create the consumer account, the creator of the note does not need to exist on chain
+    let sender_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let target_account =
+        chain.add_existing_wallet(Auth::BasicAuth, vec![requested_faucet.mint(50)]);
+
+    // Create the note containing the SWAPP script
+    let swapp = SwappNote::new(
+        sender_id,
+        offered_asset,
+        requested_asset,
+        NoteType::Public,
+        Felt::new(27),
+        &mut RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+
+    chain.add_note(swapp.note().clone());
+    chain.seal_block(None);
+
+    // take 40 out of the 100 offered units for 20 out of the 50 requested units
+    let (payback_note, remainder) = swapp.fill(target_account.id(), 40, 20).unwrap();
+    let remainder = remainder.expect("partial fill creates a remainder note");
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    let transaction_script =
+        TransactionScript::compile(DEFAULT_AUTH_SCRIPT, vec![], TransactionKernel::assembler())
+            .unwrap();
+
+    let executed_transaction = chain
+        .build_tx_context(target_account.id())
+        .tx_script(transaction_script)
+        .note_args(BTreeMap::from([(swapp.note().id(), SwappNote::note_args(40, 20))]))
+        .expected_notes(vec![OutputNote::Full(remainder.note().clone())])
+        .build()
+        .execute()
+        .unwrap();
+
+    // target account vault delta
+    let target_account_after: Account = Account::from_parts(
+        target_account.id(),
+        AssetVault::new(&[offered_faucet.mint(40), requested_faucet.mint(30)]).unwrap(),
+        target_account.storage().clone(),
+        target_account.code().clone(),
+        Felt::new(2),
+    );
+
+    // Check that the target account has received the filled part of the offered asset
+    assert_eq!(executed_transaction.final_account().hash(), target_account_after.hash());
+
+    // Check that the payback note and the remainder note have been created
+    assert_eq!(executed_transaction.output_notes().num_notes(), 2);
+
+    let output_payback = executed_transaction.output_notes().get_note(0);
+    assert_eq!(output_payback.id(), payback_note.id());
+    assert_eq!(output_payback.metadata(), payback_note.metadata());
+
+    let output_remainder = executed_transaction.output_notes().get_note(1);
+    assert_eq!(output_remainder.id(), remainder.note().id());
+    assert_eq!(output_remainder.metadata(), remainder.note().metadata());
+    assert_eq!(remainder.offered_asset().amount(), 60);
+    assert_eq!(remainder.requested_asset().amount(), 30);
+    assert_eq!(remainder.note().metadata().aux(), ZERO);
+
+    // Prove, serialize/deserialize and verify the transaction
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+}
+
+#[test]
+fn swapp_script_full_fill_and_price_check() {
+    let mut chain = MockChain::new();
+    let offered_faucet = chain.add_existing_faucet(Auth::NoAuth, "POL", 100000u64);
+    let requested_faucet = chain.add_existing_faucet(Auth::NoAuth, "ETH", 100000u64);
+    let offered_asset = offered_faucet.mint(100);
+    let requested_asset = requested_faucet.mint(50);
+
+    let sender_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let target_account =
+        chain.add_existing_wallet(Auth::BasicAuth, vec![requested_faucet.mint(50)]);
+
+    let swapp = SwappNote::new(
+        sender_id,
+        offered_asset,
+        requested_asset,
+        NoteType::Public,
+        ZERO,
+        &mut RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+
+    chain.add_note(swapp.note().clone());
+    chain.seal_block(None);
+
+    let transaction_script =
+        TransactionScript::compile(DEFAULT_AUTH_SCRIPT, vec![], TransactionKernel::assembler())
+            .unwrap();
+
+    // paying 19 units for 40 offered units does not meet the price of the note
+    let executed_transaction = chain
+        .build_tx_context(target_account.id())
+        .tx_script(transaction_script.clone())
+        .note_args(BTreeMap::from([(swapp.note().id(), SwappNote::note_args(40, 19))]))
+        .build()
+        .execute();
+    assert!(executed_transaction.is_err());
+
+    // consuming the note without note args fills it entirely
+    let (payback_note, remainder) = swapp.fill(target_account.id(), 0, 0).unwrap();
+    assert!(remainder.is_none());
+
+    let executed_transaction = chain
+        .build_tx_context(target_account.id())
+        .tx_script(transaction_script)
+        .build()
+        .execute()
+        .unwrap();
+
+    let target_account_after: Account = Account::from_parts(
+        target_account.id(),
+        AssetVault::new(&[offered_asset]).unwrap(),
+        target_account.storage().clone(),
+        target_account.code().clone(),
+        Felt::new(2),
+    );
+    assert_eq!(executed_transaction.final_account().hash(), target_account_after.hash());
+
+    assert_eq!(executed_transaction.output_notes().num_notes(), 1);
+    assert_eq!(executed_transaction.output_notes().get_note(0).id(), payback_note.id());
+}
+
+#[test]
+fn swapp_note_args_are_passed_via_transaction_request() {
+    let mut chain = MockChain::new();
+    let offered_faucet = chain.add_existing_faucet(Auth::NoAuth, "POL", 100000u64);
+    let requested_faucet = chain.add_existing_faucet(Auth::NoAuth, "ETH", 100000u64);
+
+    let sender_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let target_account =
+        chain.add_existing_wallet(Auth::BasicAuth, vec![requested_faucet.mint(50)]);
+
+    let swapp = SwappNote::new(
+        sender_id,
+        offered_faucet.mint(100),
+        requested_faucet.mint(50),
+        NoteType::Public,
+        ZERO,
+        &mut RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+
+    chain.add_note(swapp.note().clone());
+    chain.seal_block(None);
+
+    let note_args = SwappNote::note_args(40, 20);
+    let (payback_note, remainder) = swapp.fill(target_account.id(), 40, 20).unwrap();
+    let remainder = remainder.expect("partial fill creates a remainder note");
+
+    let request = TransactionRequest::new(target_account.id())
+        .with_input_note(swapp.note().id(), Some(note_args))
+        .with_procedure_call(ProcedureCall::new(AUTH_TX_RPO_FALCON512_PROC, []).unwrap());
+    assert_eq!(request.note_args(swapp.note().id()), Some(note_args));
+
+    let mut tx_args = request.build_transaction_args(TransactionKernel::assembler()).unwrap();
+    tx_args.add_expected_output_note(remainder.note());
+    assert_eq!(tx_args.note_args(swapp.note().id()), note_args);
+
+    let mut tx_context = chain.build_tx_context(target_account.id()).build();
+    let tx_args = tx_args.with_advice_inputs(tx_context.tx_args().advice_inputs().clone());
+    tx_context.set_tx_args(tx_args);
+
+    // the note is filled partially according to the note args of the request
+    let executed_transaction = tx_context.execute().unwrap();
+    assert_eq!(executed_transaction.output_notes().num_notes(), 2);
+    assert_eq!(executed_transaction.output_notes().get_note(0).id(), payback_note.id());
+    assert_eq!(executed_transaction.output_notes().get_note(1).id(), remainder.note().id());
+}
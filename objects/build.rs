@@ -0,0 +1,39 @@
+/// Generates the Rust definitions of the protobuf messages in `proto/objects.proto` when the
+/// `proto` feature is enabled.
+fn main() {
+    #[cfg(feature = "proto")]
+    proto::compile_messages().expect("protobuf messages should be generated");
+}
+
+#[cfg(feature = "proto")]
+mod proto {
+    use std::{env, error::Error, fs, path::PathBuf};
+
+    /// Defines whether the build script can write to /src.
+    const CAN_WRITE_TO_SRC: bool = option_env!("DOCS_RS").is_none();
+
+    /// Compiles `proto/objects.proto` into `src/proto/generated/objects.rs`.
+    pub fn compile_messages() -> Result<(), Box<dyn Error>> {
+        println!("cargo:rerun-if-changed=proto");
+
+        // The docs.rs build pipeline has a read-only filesystem, so we want to return early or
+        // otherwise the docs will fail to build there.
+        if !CAN_WRITE_TO_SRC {
+            return Ok(());
+        }
+
+        let crate_root = PathBuf::from(
+            env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR should be set"),
+        );
+        let dst_dir = crate_root.join("src").join("proto").join("generated");
+        let proto_dir = crate_root.join("proto");
+
+        // Remove `objects.rs` if it exists.
+        fs::remove_file(dst_dir.join("objects.rs")).ok();
+
+        let file_descriptors = protox::compile([proto_dir.join("objects.proto")], [proto_dir])?;
+        prost_build::Config::new().out_dir(dst_dir).compile_fds(file_descriptors)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use miden_objects::{accounts::Account, utils::serde::BoundedDeserializable};
+
+fuzz_target!(|data: &[u8]| {
+    let _ = Account::read_from_untrusted_bytes(data);
+});
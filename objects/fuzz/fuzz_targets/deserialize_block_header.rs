@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use miden_objects::{utils::serde::BoundedDeserializable, BlockHeader};
+
+fuzz_target!(|data: &[u8]| {
+    let _ = BlockHeader::read_from_untrusted_bytes(data);
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use miden_objects::{notes::Note, utils::serde::BoundedDeserializable};
+
+fuzz_target!(|data: &[u8]| {
+    let _ = Note::read_from_untrusted_bytes(data);
+});
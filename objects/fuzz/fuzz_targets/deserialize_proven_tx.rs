@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use miden_objects::{transaction::ProvenTransaction, utils::serde::BoundedDeserializable};
+
+fuzz_target!(|data: &[u8]| {
+    let _ = ProvenTransaction::read_from_untrusted_bytes(data);
+});
@@ -5,8 +5,8 @@ use alloc::{
 use core::{fmt, str::FromStr};
 
 use super::{
-    get_account_seed, AccountError, ByteReader, Deserializable, DeserializationError, Digest, Felt,
-    Hasher, Serializable, Word, ZERO,
+    seed::get_account_seed_with_params, AccountError, ByteReader, Deserializable,
+    DeserializationError, Digest, Felt, Hasher, Serializable, Word, ZERO,
 };
 use crate::{crypto::merkle::LeafIndex, utils::hex_to_bytes, ACCOUNT_TREE_DEPTH};
 
@@ -22,6 +22,11 @@ pub const ACCOUNT_TYPE_MASK_SHIFT: u64 = 60;
 pub const ACCOUNT_TYPE_MASK: u64 = 0b11 << ACCOUNT_TYPE_MASK_SHIFT;
 pub const ACCOUNT_ISFAUCET_MASK: u64 = 0b10 << ACCOUNT_TYPE_MASK_SHIFT;
 
+// The lower bit of the storage mode bits is not used by any storage mode and determines the version
+// of the account ID layout
+pub const ACCOUNT_VERSION_MASK_SHIFT: u64 = 62;
+pub const ACCOUNT_VERSION_MASK: u64 = 0b1 << ACCOUNT_VERSION_MASK_SHIFT;
+
 // ACCOUNT TYPES
 // ================================================================================================
 
@@ -51,6 +56,21 @@ impl AccountType {
     }
 }
 
+impl fmt::Display for AccountType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AccountType::FungibleFaucet => write!(f, "fungible faucet"),
+            AccountType::NonFungibleFaucet => write!(f, "non-fungible faucet"),
+            AccountType::RegularAccountImmutableCode => {
+                write!(f, "regular account (immutable code)")
+            },
+            AccountType::RegularAccountUpdatableCode => {
+                write!(f, "regular account (updatable code)")
+            },
+        }
+    }
+}
+
 /// Extracts the [AccountType] encoded in an u64.
 ///
 /// The account id is encoded in the bits `[62,60]` of the u64, see [ACCOUNT_TYPE_MASK].
@@ -134,6 +154,54 @@ impl FromStr for AccountStorageMode {
     }
 }
 
+// ACCOUNT ID VERSION
+// ================================================================================================
+
+pub const ACCOUNT_ID_VERSION_0: u8 = 0;
+
+/// The version of the layout of an [AccountId].
+///
+/// The version is encoded in the bit of the ID selected by [ACCOUNT_VERSION_MASK]. All IDs which
+/// can currently be created use [AccountIdVersion::Version0], and IDs with an unknown version are
+/// rejected when parsed, so that a future change of the layout is detected instead of the ID being
+/// misinterpreted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u8)]
+pub enum AccountIdVersion {
+    Version0 = ACCOUNT_ID_VERSION_0,
+}
+
+impl AccountIdVersion {
+    /// The latest version of the account ID layout, used for all newly created IDs.
+    pub const LATEST: Self = Self::Version0;
+
+    /// Returns the numeric value of this version.
+    pub const fn as_u8(&self) -> u8 {
+        *self as u8
+    }
+}
+
+impl TryFrom<u8> for AccountIdVersion {
+    type Error = AccountError;
+
+    /// Returns the [AccountIdVersion] with the provided numeric value.
+    ///
+    /// # Errors
+    /// Returns an error if the version is unknown.
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            ACCOUNT_ID_VERSION_0 => Ok(Self::Version0),
+            other => Err(AccountError::AccountIdUnknownVersion(other)),
+        }
+    }
+}
+
+impl fmt::Display for AccountIdVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "v{}", self.as_u8())
+    }
+}
+
 // ACCOUNT ID
 // ================================================================================================
 
@@ -193,10 +261,34 @@ impl AccountId {
         seed: Word,
         code_commitment: Digest,
         storage_commitment: Digest,
+    ) -> Result<Self, AccountError> {
+        Self::new_with_params(
+            seed,
+            code_commitment,
+            storage_commitment,
+            SeedGrindingParams::default(),
+        )
+    }
+
+    /// Returns a new account ID derived from the specified seed, code commitment and storage
+    /// commitment, requiring the seed digest to satisfy the proof-of-work difficulty defined by
+    /// the provided parameters instead of the default one.
+    ///
+    /// Note that the transaction kernel always enforces the default difficulty, so IDs which only
+    /// satisfy a lower difficulty are suitable for local development networks only.
+    ///
+    /// # Errors
+    /// Returns an error if the resulting account ID does not comply with account ID rules or if the
+    /// seed digest does not satisfy the difficulty defined by the provided parameters.
+    pub fn new_with_params(
+        seed: Word,
+        code_commitment: Digest,
+        storage_commitment: Digest,
+        params: SeedGrindingParams,
     ) -> Result<Self, AccountError> {
         let seed_digest = compute_digest(seed, code_commitment, storage_commitment);
 
-        Self::validate_seed_digest(&seed_digest)?;
+        Self::validate_seed_digest(&seed_digest, params)?;
         seed_digest[0].try_into()
     }
 
@@ -214,7 +306,7 @@ impl AccountId {
         let code_commitment = Digest::default();
         let storage_commitment = Digest::default();
 
-        let seed = get_account_seed(
+        let seed = Self::get_account_seed(
             init_seed,
             account_type,
             AccountStorageMode::Public,
@@ -303,6 +395,41 @@ impl AccountId {
         self.storage_mode() == AccountStorageMode::Public
     }
 
+    /// Returns the version of the layout of this account ID.
+    pub fn version(&self) -> AccountIdVersion {
+        let version = (self.0.as_int() & ACCOUNT_VERSION_MASK) >> ACCOUNT_VERSION_MASK_SHIFT;
+        AccountIdVersion::try_from(version as u8).expect("account with invalid version created")
+    }
+
+    /// Returns true if this account ID uses the latest version of the account ID layout.
+    pub fn is_latest_version(&self) -> bool {
+        self.version() == AccountIdVersion::LATEST
+    }
+
+    /// Returns the metadata encoded in this account ID, i.e., the type and the storage mode of the
+    /// account, and the version of the ID layout.
+    pub fn to_parts(&self) -> AccountIdParts {
+        AccountIdParts {
+            account_type: self.account_type(),
+            storage_mode: self.storage_mode(),
+            version: self.version(),
+        }
+    }
+
+    /// Returns this account ID encoded in the layout of the specified version.
+    ///
+    /// Since [AccountIdVersion::Version0] is currently the only version, this returns the ID
+    /// unchanged. Once a new version is introduced, this is where the metadata of older IDs is
+    /// re-encoded, so that callers which persist IDs can upgrade them in a single place.
+    ///
+    /// # Errors
+    /// Returns an error if the ID cannot be represented in the specified version.
+    pub fn migrate(self, version: AccountIdVersion) -> Result<Self, AccountError> {
+        match (self.version(), version) {
+            (AccountIdVersion::Version0, AccountIdVersion::Version0) => Ok(self),
+        }
+    }
+
     /// Finds and returns a seed suitable for creating an account ID for the specified account type
     /// using the provided initial seed as a starting point.
     pub fn get_account_seed(
@@ -312,7 +439,35 @@ impl AccountId {
         code_commitment: Digest,
         storage_commitment: Digest,
     ) -> Result<Word, AccountError> {
-        get_account_seed(init_seed, account_type, storage_mode, code_commitment, storage_commitment)
+        Self::get_account_seed_with_params(
+            init_seed,
+            account_type,
+            storage_mode,
+            code_commitment,
+            storage_commitment,
+            SeedGrindingParams::default(),
+        )
+    }
+
+    /// Finds and returns a seed suitable for creating an account ID for the specified account type
+    /// using the provided initial seed as a starting point, where the seed digest satisfies the
+    /// proof-of-work difficulty defined by the provided parameters.
+    pub fn get_account_seed_with_params(
+        init_seed: [u8; 32],
+        account_type: AccountType,
+        storage_mode: AccountStorageMode,
+        code_commitment: Digest,
+        storage_commitment: Digest,
+        params: SeedGrindingParams,
+    ) -> Result<Word, AccountError> {
+        get_account_seed_with_params(
+            init_seed,
+            account_type,
+            storage_mode,
+            code_commitment,
+            storage_commitment,
+            params,
+        )
     }
 
     /// Creates an Account Id from a hex string. Assumes the string starts with "0x" and
@@ -333,19 +488,92 @@ impl AccountId {
         format!("0x{:016x}", self.0.as_int())
     }
 
+    /// Returns a bech32m-encoded string of this account ID using the provided human-readable part
+    /// (e.g. `"mm"` for mainnet).
+    ///
+    /// Unlike the hex encoding, the bech32m encoding contains a checksum which allows detecting
+    /// typos when the ID is entered manually.
+    ///
+    /// # Errors
+    /// Returns an error if the human-readable part is empty, too long, or contains characters
+    /// outside of the printable ASCII range or upper case characters.
+    pub fn to_bech32(&self, hrp: &str) -> Result<String, AccountError> {
+        validate_bech32_hrp(hrp)?;
+
+        let data = bytes_to_u5(&self.0.as_int().to_be_bytes());
+        let checksum = bech32_checksum(hrp, &data);
+
+        let mut encoded = String::with_capacity(hrp.len() + 1 + data.len() + checksum.len());
+        encoded.push_str(hrp);
+        encoded.push(BECH32_SEPARATOR);
+        encoded.extend(
+            data.iter()
+                .chain(checksum.iter())
+                .map(|&value| BECH32_CHARSET[value as usize] as char),
+        );
+
+        Ok(encoded)
+    }
+
+    /// Parses a bech32m-encoded account ID and returns the human-readable part together with the
+    /// decoded account ID.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The string is not a valid bech32m string or its checksum does not match.
+    /// - The encoded data is not a valid account ID.
+    pub fn from_bech32(bech32: &str) -> Result<(String, AccountId), AccountError> {
+        if bech32.chars().any(|c| c.is_ascii_uppercase())
+            && bech32.chars().any(|c| c.is_ascii_lowercase())
+        {
+            return Err(AccountError::Bech32ParseError("string contains mixed case".to_string()));
+        }
+        let bech32 = bech32.to_ascii_lowercase();
+
+        let (hrp, data) = bech32.rsplit_once(BECH32_SEPARATOR).ok_or_else(|| {
+            AccountError::Bech32ParseError("missing human-readable part separator".to_string())
+        })?;
+        validate_bech32_hrp(hrp)?;
+
+        let values = data
+            .bytes()
+            .map(|c| {
+                BECH32_CHARSET.iter().position(|&v| v == c).map(|v| v as u8).ok_or_else(|| {
+                    AccountError::Bech32ParseError(format!("invalid character `{}`", c as char))
+                })
+            })
+            .collect::<Result<Vec<u8>, _>>()?;
+
+        if values.len() < BECH32_CHECKSUM_LENGTH {
+            return Err(AccountError::Bech32ParseError("data part is too short".to_string()));
+        }
+        let (data, checksum) = values.split_at(values.len() - BECH32_CHECKSUM_LENGTH);
+        if bech32_checksum(hrp, data) != checksum {
+            return Err(AccountError::Bech32ParseError("invalid checksum".to_string()));
+        }
+
+        let bytes: [u8; 8] = u5_to_bytes(data)?.try_into().map_err(|bytes: Vec<u8>| {
+            AccountError::Bech32ParseError(format!("expected 8 bytes, found {}", bytes.len()))
+        })?;
+        let account_id = AccountId::try_from(u64::from_be_bytes(bytes))?;
+
+        Ok((hrp.to_string(), account_id))
+    }
+
     // UTILITY METHODS
     // --------------------------------------------------------------------------------------------
 
-    /// Returns an error if:
-    /// - There are fewer then:
-    ///   - 24 trailing ZEROs in the last element of the seed digest for regular accounts.
-    ///   - 32 trailing ZEROs in the last element of the seed digest for faucet accounts.
-    pub(super) fn validate_seed_digest(digest: &Digest) -> Result<(), AccountError> {
+    /// Returns an error if there are fewer trailing ZEROs in the last element of the seed digest
+    /// than required by the provided parameters for the type of the account.
+    pub(super) fn validate_seed_digest(
+        digest: &Digest,
+        params: SeedGrindingParams,
+    ) -> Result<(), AccountError> {
         // check the id satisfies the proof-of-work requirement.
         let required_zeros = if is_regular_account(digest[0].as_int()) {
-            Self::REGULAR_ACCOUNT_SEED_DIGEST_MIN_TRAILING_ZEROS
+            params.regular_account_min_trailing_zeros()
         } else {
-            Self::FAUCET_SEED_DIGEST_MIN_TRAILING_ZEROS
+            params.faucet_min_trailing_zeros()
         };
 
         let trailing_zeros = digest_pow(*digest);
@@ -360,6 +588,71 @@ impl AccountId {
     }
 }
 
+// SEED GRINDING PARAMS
+// ================================================================================================
+
+/// Parameters defining the proof-of-work difficulty required for account ID seeds.
+///
+/// The difficulty is expressed as the minimum number of trailing zeros in the last element of the
+/// seed digest. The default parameters match the difficulty enforced by the transaction kernel,
+/// while lower difficulties can be used to speed up account creation on local development networks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SeedGrindingParams {
+    regular_account_min_trailing_zeros: u32,
+    faucet_min_trailing_zeros: u32,
+}
+
+impl SeedGrindingParams {
+    /// The maximum supported number of trailing zeros.
+    ///
+    /// The transaction kernel checks the proof-of-work using 32-bit moduli, so higher difficulties
+    /// cannot be verified.
+    pub const MAX_TRAILING_ZEROS: u32 = 31;
+
+    /// Returns new [SeedGrindingParams] with the specified minimum number of trailing zeros for
+    /// regular and faucet accounts.
+    ///
+    /// # Errors
+    /// Returns an error if any of the values is greater than [Self::MAX_TRAILING_ZEROS].
+    pub fn new(
+        regular_account_min_trailing_zeros: u32,
+        faucet_min_trailing_zeros: u32,
+    ) -> Result<Self, AccountError> {
+        let max = regular_account_min_trailing_zeros.max(faucet_min_trailing_zeros);
+        if max > Self::MAX_TRAILING_ZEROS {
+            return Err(AccountError::SeedGrindingDifficultyTooHigh {
+                max: Self::MAX_TRAILING_ZEROS,
+                actual: max,
+            });
+        }
+
+        Ok(Self {
+            regular_account_min_trailing_zeros,
+            faucet_min_trailing_zeros,
+        })
+    }
+
+    /// Returns the minimum number of trailing zeros required for regular accounts.
+    pub fn regular_account_min_trailing_zeros(&self) -> u32 {
+        self.regular_account_min_trailing_zeros
+    }
+
+    /// Returns the minimum number of trailing zeros required for faucet accounts.
+    pub fn faucet_min_trailing_zeros(&self) -> u32 {
+        self.faucet_min_trailing_zeros
+    }
+}
+
+impl Default for SeedGrindingParams {
+    fn default() -> Self {
+        Self {
+            regular_account_min_trailing_zeros:
+                AccountId::REGULAR_ACCOUNT_SEED_DIGEST_MIN_TRAILING_ZEROS,
+            faucet_min_trailing_zeros: AccountId::FAUCET_SEED_DIGEST_MIN_TRAILING_ZEROS,
+        }
+    }
+}
+
 impl PartialOrd for AccountId {
     fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         Some(self.cmp(other))
@@ -373,8 +666,38 @@ impl Ord for AccountId {
 }
 
 impl fmt::Display for AccountId {
+    /// Formats the account ID as a hex string, e.g. `0x200000000000001f`.
+    ///
+    /// With the alternate flag (`{:#}`), the decoded metadata of the ID is appended, e.g.
+    /// `0x200000000000001f (fungible faucet, public, v0)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x{:016x}", self.0.as_int())?;
+        if f.alternate() {
+            write!(f, " ({})", self.to_parts())?;
+        }
+        Ok(())
+    }
+}
+
+// ACCOUNT ID PARTS
+// ================================================================================================
+
+/// The metadata encoded in an [AccountId].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccountIdParts {
+    /// The type of the account.
+    pub account_type: AccountType,
+    /// The storage mode of the account.
+    pub storage_mode: AccountStorageMode,
+    /// The version of the account ID layout.
+    pub version: AccountIdVersion,
+}
+
+impl fmt::Display for AccountIdParts {
+    /// Formats the parts as `<account type>, <storage mode>, <version>`, e.g.
+    /// `fungible faucet, public, v0`.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "0x{:016x}", self.0.as_int())
+        write!(f, "{}, {}, {}", self.account_type, self.storage_mode, self.version)
     }
 }
 
@@ -416,10 +739,17 @@ impl From<AccountId> for LeafIndex<ACCOUNT_TREE_DEPTH> {
 /// # Errors
 /// Returns an error if:
 /// - If there are fewer than [AccountId::MIN_ACCOUNT_ONES] in the provided value.
+/// - If the provided value encodes an unknown [AccountIdVersion].
 /// - If the provided value contains invalid account ID metadata (i.e., the first 4 bits).
 pub const fn account_id_from_felt(value: Felt) -> Result<AccountId, AccountError> {
     let int_value = value.as_int();
 
+    // the version is checked first, so that IDs of a future layout are reported as such
+    let version = ((int_value & ACCOUNT_VERSION_MASK) >> ACCOUNT_VERSION_MASK_SHIFT) as u8;
+    if version != ACCOUNT_ID_VERSION_0 {
+        return Err(AccountError::AccountIdUnknownVersion(version));
+    }
+
     let count = int_value.count_ones();
     if count < AccountId::MIN_ACCOUNT_ONES {
         return Err(AccountError::AccountIdTooFewOnes(AccountId::MIN_ACCOUNT_ONES, count));
@@ -442,6 +772,7 @@ impl TryFrom<Felt> for AccountId {
     /// # Errors
     /// Returns an error if:
     /// - If there are fewer than [AccountId::MIN_ACCOUNT_ONES] in the provided value.
+    /// - If the provided value encodes an unknown [AccountIdVersion].
     /// - If the provided value contains invalid account ID metadata (i.e., the first 4 bits).
     fn try_from(value: Felt) -> Result<Self, Self::Error> {
         account_id_from_felt(value)
@@ -488,6 +819,118 @@ impl Deserializable for AccountId {
     }
 }
 
+// BECH32 ENCODING
+// ================================================================================================
+
+/// The character set used for encoding 5-bit values in bech32 strings.
+const BECH32_CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// The separator between the human-readable part and the data part of a bech32 string.
+const BECH32_SEPARATOR: char = '1';
+
+/// The number of 5-bit values in the checksum of a bech32 string.
+const BECH32_CHECKSUM_LENGTH: usize = 6;
+
+/// The constant used for computing bech32m checksums as defined in BIP-350.
+const BECH32M_CONST: u32 = 0x2bc8_30a3;
+
+/// The maximum length of the human-readable part of a bech32 string.
+const BECH32_MAX_HRP_LENGTH: usize = 83;
+
+/// Returns an error if the provided human-readable part is not valid for a bech32 string.
+fn validate_bech32_hrp(hrp: &str) -> Result<(), AccountError> {
+    if hrp.is_empty() || hrp.len() > BECH32_MAX_HRP_LENGTH {
+        return Err(AccountError::Bech32ParseError(format!(
+            "human-readable part must be between 1 and {BECH32_MAX_HRP_LENGTH} characters long"
+        )));
+    }
+
+    if hrp.bytes().any(|c| !(33..=126).contains(&c) || c.is_ascii_uppercase()) {
+        return Err(AccountError::Bech32ParseError(
+            "human-readable part must consist of lower case printable ASCII characters".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Computes the bech32m checksum over the human-readable part and the 5-bit data values.
+fn bech32_checksum(hrp: &str, data: &[u8]) -> [u8; BECH32_CHECKSUM_LENGTH] {
+    let values = hrp
+        .bytes()
+        .map(|c| c >> 5)
+        .chain([0])
+        .chain(hrp.bytes().map(|c| c & 0x1f))
+        .chain(data.iter().copied())
+        .chain([0; BECH32_CHECKSUM_LENGTH]);
+
+    let polymod = bech32_polymod(values) ^ BECH32M_CONST;
+
+    let mut checksum = [0; BECH32_CHECKSUM_LENGTH];
+    for (i, value) in checksum.iter_mut().enumerate() {
+        *value = ((polymod >> (5 * (BECH32_CHECKSUM_LENGTH - 1 - i))) & 0x1f) as u8;
+    }
+    checksum
+}
+
+/// Computes the BCH checksum polynomial over the provided 5-bit values.
+fn bech32_polymod(values: impl Iterator<Item = u8>) -> u32 {
+    const GENERATOR: [u32; 5] = [0x3b6a_57b2, 0x2650_8e6d, 0x1ea1_19fa, 0x3d42_33dd, 0x2a14_62b3];
+
+    let mut chk: u32 = 1;
+    for value in values {
+        let top = chk >> 25;
+        chk = ((chk & 0x01ff_ffff) << 5) ^ value as u32;
+        for (i, generator) in GENERATOR.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                chk ^= generator;
+            }
+        }
+    }
+    chk
+}
+
+/// Converts bytes into 5-bit values, padding the last value with zeros if needed.
+fn bytes_to_u5(bytes: &[u8]) -> Vec<u8> {
+    let mut values = Vec::with_capacity((bytes.len() * 8).div_ceil(5));
+    let mut acc: u32 = 0;
+    let mut bits = 0;
+    for &byte in bytes {
+        acc = (acc << 8) | byte as u32;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            values.push(((acc >> bits) & 0x1f) as u8);
+        }
+    }
+    if bits > 0 {
+        values.push(((acc << (5 - bits)) & 0x1f) as u8);
+    }
+    values
+}
+
+/// Converts 5-bit values back into bytes.
+///
+/// # Errors
+/// Returns an error if the padding of the last value is invalid.
+fn u5_to_bytes(values: &[u8]) -> Result<Vec<u8>, AccountError> {
+    let mut bytes = Vec::with_capacity(values.len() * 5 / 8);
+    let mut acc: u32 = 0;
+    let mut bits = 0;
+    for &value in values {
+        acc = (acc << 5) | value as u32;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push(((acc >> bits) & 0xff) as u8);
+        }
+    }
+    if bits >= 5 || (acc << (8 - bits)) & 0xff != 0 {
+        return Err(AccountError::Bech32ParseError("invalid padding".to_string()));
+    }
+    Ok(bytes)
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 fn parse_felt(bytes: &[u8]) -> Result<Felt, AccountError> {
@@ -620,11 +1063,14 @@ pub mod testing {
 
 #[cfg(test)]
 mod tests {
+    use alloc::string::{String, ToString};
+
     use miden_crypto::utils::{Deserializable, Serializable};
 
     use super::{
-        testing::*, AccountId, AccountStorageMode, AccountType, ACCOUNT_ISFAUCET_MASK,
-        ACCOUNT_TYPE_MASK_SHIFT, FUNGIBLE_FAUCET, NON_FUNGIBLE_FAUCET,
+        testing::*, AccountError, AccountId, AccountIdVersion, AccountStorageMode, AccountType,
+        DeserializationError, ACCOUNT_ISFAUCET_MASK, ACCOUNT_STORAGE_MASK,
+        ACCOUNT_STORAGE_MASK_SHIFT, ACCOUNT_TYPE_MASK_SHIFT, FUNGIBLE_FAUCET, NON_FUNGIBLE_FAUCET,
         REGULAR_ACCOUNT_IMMUTABLE_CODE, REGULAR_ACCOUNT_UPDATABLE_CODE,
     };
 
@@ -659,6 +1105,41 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_account_id_bech32() {
+        for account_id in [
+            ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+        ] {
+            let acc = AccountId::try_from(account_id).expect("Valid account ID");
+            let bech32 = acc.to_bech32("mm").unwrap();
+            assert!(bech32.starts_with("mm1"));
+            assert_eq!(AccountId::from_bech32(&bech32).unwrap(), ("mm".to_string(), acc));
+            assert_eq!(
+                AccountId::from_bech32(&bech32.to_ascii_uppercase()).unwrap(),
+                ("mm".to_string(), acc)
+            );
+
+            // changing a single character invalidates the checksum
+            let mut corrupted = bech32.into_bytes();
+            let last = corrupted.len() - 1;
+            corrupted[last] = if corrupted[last] == b'q' { b'p' } else { b'q' };
+            let corrupted = String::from_utf8(corrupted).unwrap();
+            assert!(matches!(
+                AccountId::from_bech32(&corrupted),
+                Err(AccountError::Bech32ParseError(_))
+            ));
+        }
+
+        let acc = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        assert!(acc.to_bech32("").is_err());
+        assert!(acc.to_bech32("MM").is_err());
+        assert!(AccountId::from_bech32("mm1Qpzry9x8").is_err());
+        assert!(AccountId::from_bech32("mmqpzry9x8").is_err());
+        assert!(AccountId::from_bech32("mm1qpzrb").is_err());
+    }
+
     #[test]
     fn test_account_id_serde() {
         let account_id = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN)
@@ -702,6 +1183,59 @@ mod tests {
         assert_eq!(account_type, account_id.account_type());
     }
 
+    #[test]
+    fn test_account_id_parts() {
+        let account_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let parts = account_id.to_parts();
+        assert_eq!(parts.account_type, AccountType::FungibleFaucet);
+        assert_eq!(parts.storage_mode, AccountStorageMode::Public);
+        assert_eq!(parts.version, AccountIdVersion::Version0);
+        assert_eq!(
+            format!("{account_id:#}"),
+            format!("{account_id} (fungible faucet, public, v0)")
+        );
+
+        let account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+        let parts = account_id.to_parts();
+        assert_eq!(parts.account_type, AccountType::RegularAccountUpdatableCode);
+        assert_eq!(parts.storage_mode, AccountStorageMode::Private);
+        assert_eq!(parts.to_string(), "regular account (updatable code), private, v0");
+
+        // the plain format is the hex representation of the ID
+        assert_eq!(account_id.to_string(), account_id.to_hex());
+    }
+
+    #[test]
+    fn test_account_id_version() {
+        let account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        assert_eq!(account_id.version(), AccountIdVersion::LATEST);
+        assert!(account_id.is_latest_version());
+        assert_eq!(account_id.migrate(AccountIdVersion::Version0).unwrap(), account_id);
+
+        assert_eq!(AccountIdVersion::try_from(0).unwrap(), AccountIdVersion::Version0);
+        assert!(matches!(
+            AccountIdVersion::try_from(1),
+            Err(AccountError::AccountIdUnknownVersion(1))
+        ));
+
+        // IDs of an unknown version are rejected by all parsers, regardless of their storage mode
+        for storage_bits in [0b01_u64, 0b11] {
+            let value = (ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN
+                & !ACCOUNT_STORAGE_MASK)
+                | (storage_bits << ACCOUNT_STORAGE_MASK_SHIFT);
+            assert!(matches!(
+                AccountId::try_from(value),
+                Err(AccountError::AccountIdUnknownVersion(1))
+            ));
+            assert!(matches!(
+                AccountId::read_from_bytes(&value.to_le_bytes()),
+                Err(DeserializationError::InvalidValue(_))
+            ));
+        }
+    }
+
     #[test]
     fn test_account_id_tag_identifiers() {
         let account_id = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN)
@@ -1,10 +1,13 @@
-// AUTH SECRET KEY
-// ================================================================================================
-
-use miden_crypto::dsa::rpo_falcon512::{self, SecretKey};
+use miden_crypto::dsa::rpo_falcon512::{self, PublicKey, SecretKey};
 use vm_core::utils::{ByteReader, ByteWriter, Deserializable, Serializable};
 use vm_processor::DeserializationError;
 
+use super::StorageSlot;
+use crate::Word;
+
+// AUTH SECRET KEY
+// ================================================================================================
+
 /// Types of secret keys used for signing messages
 #[derive(Clone, Debug)]
 #[repr(u8)]
@@ -13,12 +16,33 @@ pub enum AuthSecretKey {
 }
 
 impl AuthSecretKey {
+    /// Returns a new randomly generated RpoFalcon512 secret key.
+    #[cfg(feature = "std")]
+    pub fn new_rpo_falcon512() -> Self {
+        AuthSecretKey::RpoFalcon512(SecretKey::new())
+    }
+
     /// Identifier for the type of authentication key
     pub fn auth_scheme_id(&self) -> u8 {
         match self {
             AuthSecretKey::RpoFalcon512(_) => 0u8,
         }
     }
+
+    /// Returns the public key corresponding to this secret key.
+    pub fn public_key(&self) -> AuthPublicKey {
+        match self {
+            AuthSecretKey::RpoFalcon512(secret_key) => {
+                AuthPublicKey::RpoFalcon512(secret_key.public_key())
+            },
+        }
+    }
+
+    /// Returns the entry of this key in the key list of a transaction authenticator, i.e., the
+    /// word encoding the public key together with this secret key.
+    pub fn to_authenticator_entry(&self) -> (Word, AuthSecretKey) {
+        (self.public_key().into(), self.clone())
+    }
 }
 
 impl Serializable for AuthSecretKey {
@@ -45,3 +69,70 @@ impl Deserializable for AuthSecretKey {
         }
     }
 }
+
+// AUTH PUBLIC KEY
+// ================================================================================================
+
+/// Types of public keys used for verifying signatures.
+///
+/// A public key is stored in account storage as a single word, from which the authentication
+/// components verify the signatures of transactions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AuthPublicKey {
+    RpoFalcon512(rpo_falcon512::PublicKey),
+}
+
+impl AuthPublicKey {
+    /// Returns an RpoFalcon512 public key decoded from the word under which it is stored in
+    /// account storage.
+    pub fn rpo_falcon512_from_word(word: Word) -> Self {
+        AuthPublicKey::RpoFalcon512(PublicKey::new(word))
+    }
+
+    /// Identifier for the type of authentication key
+    pub fn auth_scheme_id(&self) -> u8 {
+        match self {
+            AuthPublicKey::RpoFalcon512(_) => 0u8,
+        }
+    }
+
+    /// Returns the storage slot holding this public key, as expected by the authentication
+    /// components.
+    pub fn to_storage_slot(&self) -> StorageSlot {
+        StorageSlot::Value((*self).into())
+    }
+}
+
+impl From<AuthPublicKey> for Word {
+    fn from(public_key: AuthPublicKey) -> Self {
+        match public_key {
+            AuthPublicKey::RpoFalcon512(public_key) => public_key.into(),
+        }
+    }
+}
+
+impl From<rpo_falcon512::PublicKey> for AuthPublicKey {
+    fn from(public_key: rpo_falcon512::PublicKey) -> Self {
+        AuthPublicKey::RpoFalcon512(public_key)
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_auth_key_storage_encoding() {
+        let secret_key = AuthSecretKey::new_rpo_falcon512();
+        let public_key = secret_key.public_key();
+
+        let word = Word::from(public_key);
+        assert_eq!(AuthPublicKey::rpo_falcon512_from_word(word), public_key);
+        assert_eq!(public_key.to_storage_slot(), StorageSlot::Value(word));
+        assert_eq!(secret_key.to_authenticator_entry().0, word);
+        assert_eq!(public_key.auth_scheme_id(), secret_key.auth_scheme_id());
+    }
+}
@@ -5,7 +5,7 @@ use vm_processor::Digest;
 use crate::{
     accounts::{
         Account, AccountCode, AccountComponent, AccountId, AccountStorage, AccountStorageMode,
-        AccountType,
+        AccountType, SeedGrindingParams,
     },
     assets::{Asset, AssetVault},
     AccountError, Felt, Word, ZERO,
@@ -23,6 +23,7 @@ use crate::{
 /// By default, the builder is initialized with:
 /// - The `account_type` set to [`AccountType::RegularAccountUpdatableCode`].
 /// - The `storage_mode` set to [`AccountStorageMode::Private`].
+/// - The `seed_grinding_params` set to [`SeedGrindingParams::default`].
 ///
 /// The methods that are required to be called are:
 ///
@@ -40,6 +41,7 @@ pub struct AccountBuilder {
     components: Vec<AccountComponent>,
     account_type: AccountType,
     storage_mode: AccountStorageMode,
+    seed_grinding_params: SeedGrindingParams,
     init_seed: Option<[u8; 32]>,
 }
 
@@ -54,6 +56,7 @@ impl AccountBuilder {
             init_seed: None,
             account_type: AccountType::RegularAccountUpdatableCode,
             storage_mode: AccountStorageMode::Private,
+            seed_grinding_params: SeedGrindingParams::default(),
         }
     }
 
@@ -78,6 +81,15 @@ impl AccountBuilder {
         self
     }
 
+    /// Sets the proof-of-work parameters used when grinding the seed of the account.
+    ///
+    /// Note that the transaction kernel enforces the default parameters, so accounts built with a
+    /// lower difficulty can only be used on networks which were configured accordingly.
+    pub fn seed_grinding_params(mut self, seed_grinding_params: SeedGrindingParams) -> Self {
+        self.seed_grinding_params = seed_grinding_params;
+        self
+    }
+
     /// Adds an [`AccountComponent`] to the builder. This method can be called multiple times and
     /// **must be called at least once** since an account must export at least one procedure.
     ///
@@ -132,19 +144,25 @@ impl AccountBuilder {
         code_commitment: Digest,
         storage_commitment: Digest,
     ) -> Result<(AccountId, Word), AccountError> {
-        let seed = AccountId::get_account_seed(
+        let seed = AccountId::get_account_seed_with_params(
             init_seed,
             self.account_type,
             self.storage_mode,
             code_commitment,
             storage_commitment,
+            self.seed_grinding_params,
         )
         .map_err(|err| {
             AccountError::BuildError("account seed generation failed".into(), Some(Box::new(err)))
         })?;
 
-        let account_id = AccountId::new(seed, code_commitment, storage_commitment)
-            .expect("get_account_seed should provide a suitable seed");
+        let account_id = AccountId::new_with_params(
+            seed,
+            code_commitment,
+            storage_commitment,
+            self.seed_grinding_params,
+        )
+        .expect("get_account_seed should provide a suitable seed");
 
         Ok((account_id, seed))
     }
@@ -367,6 +385,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn account_builder_with_seed_grinding_params() {
+        let params = SeedGrindingParams::new(1, 1).unwrap();
+
+        let (account, seed) = Account::builder()
+            .init_seed([7; 32])
+            .account_type(AccountType::RegularAccountImmutableCode)
+            .storage_mode(AccountStorageMode::Public)
+            .seed_grinding_params(params)
+            .with_component(CustomComponent1 { slot0: 1 })
+            .build()
+            .unwrap();
+
+        let computed_id = AccountId::new_with_params(
+            seed,
+            account.code.commitment(),
+            account.storage.commitment(),
+            params,
+        )
+        .unwrap();
+        assert_eq!(account.id(), computed_id);
+        assert_eq!(account.id().account_type(), AccountType::RegularAccountImmutableCode);
+        assert!(account.id().is_public());
+    }
+
     #[test]
     fn account_builder_non_empty_vault_on_new_account() {
         let storage_slot0 = 25;
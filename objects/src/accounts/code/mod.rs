@@ -1,12 +1,17 @@
-use alloc::{collections::BTreeSet, string::ToString, sync::Arc, vec::Vec};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
 
-use vm_core::mast::MastForest;
+use vm_core::mast::{MastForest, MastNodeId};
 
 use super::{
     AccountError, ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Felt,
     Hasher, Serializable,
 };
-use crate::accounts::{AccountComponent, AccountType};
+use crate::accounts::{AccountComponent, AccountType, NoncePolicy};
 
 pub mod procedure;
 use procedure::AccountProcedureInfo;
@@ -19,20 +24,28 @@ use procedure::AccountProcedureInfo;
 /// Account's public interface consists of a set of account procedures, each procedure being a
 /// Miden VM program. Thus, MAST root of each procedure commits to the underlying program.
 ///
-/// Each exported procedure is associated with a storage offset and a storage size.
+/// Each exported procedure is associated with a storage offset, a storage size, a flag
+/// indicating whether the procedure is read-only and the nonce policy of the account (see
+/// [AccountProcedureInfo]).
 ///
 /// We commit to the entire account interface by building a sequential hash of all procedure MAST
-/// roots and associated storage_offset's. Specifically, each procedure contributes exactly 8 field
+/// roots and associated metadata. Specifically, each procedure contributes exactly 8 field
 /// elements to the sequence of elements to be hashed. These elements are defined as follows:
 ///
 /// ```text
-/// [PROCEDURE_MAST_ROOT, storage_offset, 0, 0, storage_size]
+/// [PROCEDURE_MAST_ROOT, storage_offset, storage_size, read_only, strict_nonce]
 /// ```
+///
+/// When built from [AccountComponent]s, the account code additionally retains the fully-qualified
+/// names of the exported procedures (e.g., `basic_wallet::receive_asset`). The names are not part
+/// of the commitment and are not serialized, and thus are unavailable for account code
+/// reconstructed from its parts or deserialized from bytes.
 #[derive(Debug, Clone)]
 pub struct AccountCode {
     mast: Arc<MastForest>,
     procedures: Vec<AccountProcedureInfo>,
     commitment: Digest,
+    procedure_names: BTreeMap<Digest, String>,
 }
 
 impl AccountCode {
@@ -69,6 +82,9 @@ impl AccountCode {
     /// - The number of [`StorageSlot`](crate::accounts::StorageSlot)s of a component or of all
     ///   components exceeds 255.
     /// - [`MastForest::merge`] fails on all libraries.
+    ///
+    /// The nonce policy of the account code is [NoncePolicy::Strict] if any of the components
+    /// requires it, and [NoncePolicy::Relaxed] otherwise.
     pub(super) fn from_components_unchecked(
         components: &[AccountComponent],
         account_type: AccountType,
@@ -77,8 +93,18 @@ impl AccountCode {
             MastForest::merge(components.iter().map(|component| component.mast_forest()))
                 .map_err(|err| AccountError::AccountCodeMergeError(err.to_string()))?;
 
+        let nonce_policy = if components
+            .iter()
+            .any(|component| component.nonce_policy() == NoncePolicy::Strict)
+        {
+            NoncePolicy::Strict
+        } else {
+            NoncePolicy::Relaxed
+        };
+
         let mut procedures = Vec::new();
         let mut proc_root_set = BTreeSet::new();
+        let mut procedure_names = BTreeMap::new();
 
         // Slot 0 is globally reserved for faucet accounts so the accessible slots begin at 1 if
         // there is a faucet component present.
@@ -87,6 +113,13 @@ impl AccountCode {
         for component in components {
             let component_storage_size = component.storage_size();
 
+            let library = component.library();
+            for export in library.exports() {
+                let proc_mast_root =
+                    library.mast_forest()[library.get_export_node_id(export)].digest();
+                procedure_names.insert(proc_mast_root, export.to_string());
+            }
+
             for module in component.library().module_infos() {
                 for proc_mast_root in module.procedure_digests() {
                     // We cannot support procedures from multiple components with the same MAST root
@@ -107,11 +140,11 @@ impl AccountCode {
                     };
 
                     // Note: Offset and size are validated in `AccountProcedureInfo::new`.
-                    procedures.push(AccountProcedureInfo::new(
-                        proc_mast_root,
-                        storage_offset,
-                        storage_size,
-                    )?);
+                    procedures.push(
+                        AccountProcedureInfo::new(proc_mast_root, storage_offset, storage_size)?
+                            .with_read_only(component.is_read_only_procedure(&proc_mast_root))
+                            .with_nonce_policy(nonce_policy),
+                    );
                 }
             }
 
@@ -133,6 +166,7 @@ impl AccountCode {
             commitment: build_procedure_commitment(&procedures),
             procedures,
             mast: Arc::new(merged_mast_forest),
+            procedure_names,
         })
     }
 
@@ -152,14 +186,17 @@ impl AccountCode {
     /// - The number of procedures is smaller than 1 or greater than 256.
     /// - If some any of the provided procedures does not have a corresponding root in the provided
     ///   MAST forest.
+    /// - The provided procedures do not all have the same nonce policy.
     pub fn from_parts(mast: Arc<MastForest>, procedures: Vec<AccountProcedureInfo>) -> Self {
         assert!(!procedures.is_empty(), "no account procedures");
         assert!(procedures.len() <= Self::MAX_NUM_PROCEDURES, "too many account procedures");
+        assert!(has_uniform_nonce_policy(&procedures), "inconsistent account nonce policy");
 
         Self {
             commitment: build_procedure_commitment(&procedures),
             procedures,
             mast,
+            procedure_names: BTreeMap::new(),
         }
     }
 
@@ -191,6 +228,17 @@ impl AccountCode {
         self.procedures.len()
     }
 
+    /// Returns the nonce policy of this account code, which is enforced by the transaction kernel
+    /// for every transaction executed against the account.
+    ///
+    /// All procedures of an account code have the same nonce policy, and thus the policy is read
+    /// from the first procedure. Note that under [NoncePolicy::Strict], every transaction against
+    /// the account must increment its nonce, including transactions which do not change the state
+    /// of the account (e.g., transactions consuming notes without assets).
+    pub fn nonce_policy(&self) -> NoncePolicy {
+        self.procedures[0].nonce_policy()
+    }
+
     /// Returns true if a procedure with the specified MAST root is defined in this account code.
     pub fn has_procedure(&self, mast_root: Digest) -> bool {
         self.procedures.iter().any(|procedure| procedure.mast_root() == &mast_root)
@@ -204,6 +252,64 @@ impl AccountCode {
         &self.procedures[index]
     }
 
+    /// Returns information about the procedure with the specified MAST root or None if such
+    /// procedure is not defined in this [AccountCode].
+    pub fn get_procedure_by_root(&self, root: Digest) -> Option<&AccountProcedureInfo> {
+        self.procedures.iter().find(|procedure| procedure.mast_root() == &root)
+    }
+
+    /// Returns information about the procedure with the specified name or None if no such
+    /// procedure is defined in this [AccountCode] or if procedure names are not available.
+    ///
+    /// The name can either be fully-qualified (e.g., `basic_wallet::receive_asset`) or consist of
+    /// the procedure name only (e.g., `receive_asset`). In the latter case, None is returned if
+    /// more than one exported procedure has the specified name.
+    pub fn get_procedure_by_name(&self, name: &str) -> Option<&AccountProcedureInfo> {
+        let root = match self.procedure_names.iter().find(|(_, path)| path.as_str() == name) {
+            Some((root, _)) => *root,
+            None => {
+                let mut matches = self
+                    .procedure_names
+                    .iter()
+                    .filter(|(_, path)| path.rsplit("::").next() == Some(name));
+                match (matches.next(), matches.next()) {
+                    (Some((root, _)), None) => *root,
+                    _ => return None,
+                }
+            },
+        };
+
+        self.get_procedure_by_root(root)
+    }
+
+    /// Returns the fully-qualified name of the procedure with the specified MAST root, or None if
+    /// such procedure is not defined in this [AccountCode] or if its name is not available.
+    pub fn get_procedure_name(&self, root: Digest) -> Option<&str> {
+        self.procedure_names.get(&root).map(String::as_str)
+    }
+
+    /// Returns an iterator over the procedures of this account code together with their names, if
+    /// available.
+    pub fn named_procedures(
+        &self,
+    ) -> impl Iterator<Item = (Option<&str>, &AccountProcedureInfo)> + '_ {
+        self.procedures
+            .iter()
+            .map(|procedure| (self.get_procedure_name(*procedure.mast_root()), procedure))
+    }
+
+    /// Returns the ID of the node in the [MastForest] of this account code which is the root of
+    /// the exported procedure with the specified MAST root.
+    ///
+    /// Returns None if the procedure is not exported from this account code (even if the
+    /// procedure is present in the MAST forest, e.g., as an internal procedure).
+    pub fn get_procedure_node_id(&self, root: Digest) -> Option<MastNodeId> {
+        if !self.has_procedure(root) {
+            return None;
+        }
+        self.mast.find_procedure_root(root)
+    }
+
     /// Returns the procedure index for the procedure with the specified MAST root or None if such
     /// procedure is not defined in this [AccountCode].
     pub fn get_procedure_index_by_root(&self, root: Digest) -> Option<usize> {
@@ -217,7 +323,7 @@ impl AccountCode {
     ///
     /// This is done by first converting each procedure into 8 field elements as follows:
     /// ```text
-    /// [PROCEDURE_MAST_ROOT, storage_offset, storage_size, 0, 0]
+    /// [PROCEDURE_MAST_ROOT, storage_offset, storage_size, read_only, strict_nonce]
     /// ```
     /// And then concatenating the resulting elements into a single vector.
     pub fn as_elements(&self) -> Vec<Felt> {
@@ -283,6 +389,11 @@ impl Deserializable for AccountCode {
         let module = Arc::new(MastForest::read_from(source)?);
         let num_procedures = (source.read_u8()? as usize) + 1;
         let procedures = source.read_many::<AccountProcedureInfo>(num_procedures)?;
+        if !has_uniform_nonce_policy(&procedures) {
+            return Err(DeserializationError::InvalidValue(
+                "account procedures have different nonce policies".to_string(),
+            ));
+        }
 
         Ok(Self::from_parts(module, procedures))
     }
@@ -299,6 +410,13 @@ fn procedures_as_elements(procedures: &[AccountProcedureInfo]) -> Vec<Felt> {
         .collect()
 }
 
+/// Returns true if all of the given procedures have the same nonce policy.
+fn has_uniform_nonce_policy(procedures: &[AccountProcedureInfo]) -> bool {
+    procedures
+        .windows(2)
+        .all(|pair| pair[0].nonce_policy() == pair[1].nonce_policy())
+}
+
 /// Computes the commitment to the given procedures
 fn build_procedure_commitment(procedures: &[AccountProcedureInfo]) -> Digest {
     let elements = procedures_as_elements(procedures);
@@ -316,7 +434,10 @@ mod tests {
 
     use super::{AccountCode, Deserializable, Serializable};
     use crate::{
-        accounts::{code::build_procedure_commitment, AccountComponent, AccountType, StorageSlot},
+        accounts::{
+            code::build_procedure_commitment, AccountComponent, AccountType, NoncePolicy,
+            StorageSlot,
+        },
         AccountError,
     };
 
@@ -328,6 +449,18 @@ mod tests {
         assert_eq!(deserialized, code)
     }
 
+    #[test]
+    fn test_deserialize_account_code_with_inconsistent_nonce_policy() {
+        let code = AccountCode::mock();
+        assert!(code.num_procedures() > 1);
+
+        let mut procedures = code.procedures().to_vec();
+        procedures[1] = procedures[1].clone().with_nonce_policy(NoncePolicy::Strict);
+        let inconsistent_code = AccountCode { procedures, ..code };
+
+        assert!(AccountCode::read_from_bytes(&inconsistent_code.to_bytes()).is_err());
+    }
+
     #[test]
     fn test_account_code_procedure_commitment() {
         let code = AccountCode::mock();
@@ -369,4 +502,45 @@ mod tests {
 
         assert!(matches!(err, AccountError::StorageOffsetOutOfBounds { actual: 256, .. }))
     }
+
+    #[test]
+    fn test_account_code_procedure_introspection() {
+        let library1 = Assembler::default().assemble_library(["export.foo add end"]).unwrap();
+        let library2 = Assembler::default()
+            .assemble_library(["export.bar sub end export.baz mul end"])
+            .unwrap();
+        let component1 = AccountComponent::new(library1, vec![]).unwrap().with_supports_all_types();
+        let component2 = AccountComponent::new(library2, vec![]).unwrap().with_supports_all_types();
+
+        let code = AccountCode::from_components(
+            &[component1, component2],
+            AccountType::RegularAccountUpdatableCode,
+        )
+        .unwrap();
+
+        let names = code.named_procedures().map(|(name, _)| name.unwrap()).collect::<Vec<_>>();
+        assert_eq!(names.len(), 3);
+
+        for (name, procedure) in code.named_procedures() {
+            let name = name.unwrap();
+            let root = *procedure.mast_root();
+
+            // procedures can be looked up by their fully-qualified and their short name
+            let short_name = name.rsplit("::").next().unwrap();
+            assert_eq!(code.get_procedure_by_name(name), Some(procedure));
+            assert_eq!(code.get_procedure_by_name(short_name), Some(procedure));
+            assert_eq!(code.get_procedure_name(root), Some(name));
+
+            // exported procedures are roots of the MAST forest
+            let node_id = code.get_procedure_node_id(root).unwrap();
+            assert_eq!(code.mast()[node_id].digest(), root);
+        }
+        assert!(code.get_procedure_by_name("qux").is_none());
+
+        // procedure names are not serialized
+        let deserialized = AccountCode::read_from_bytes(&code.to_bytes()).unwrap();
+        assert_eq!(deserialized, code);
+        assert!(deserialized.named_procedures().all(|(name, _)| name.is_none()));
+        assert!(deserialized.get_procedure_by_name("foo").is_none());
+    }
 }
@@ -7,7 +7,10 @@ use vm_core::{
 use vm_processor::DeserializationError;
 
 use super::{Digest, Felt};
-use crate::{accounts::AccountStorage, AccountError};
+use crate::{
+    accounts::{AccountStorage, NoncePolicy},
+    AccountError,
+};
 
 // ACCOUNT PROCEDURE INFO
 // ================================================================================================
@@ -27,11 +30,23 @@ use crate::{accounts::AccountStorage, AccountError};
 /// account's storage. For example, if storage size for a procedure is set to 3, the procedure will
 /// be bounded to access storage slots in the range [storage_offset, storage_offset + 3 - 1].
 /// Furthermore storage_size = 0 indicates that a procedure does not need to access storage.
+///
+/// Procedures can additionally be marked as read-only, in which case the transaction kernel rejects
+/// any attempt of the procedure to modify the account's state, i.e., to modify the account's
+/// storage, nonce, code or vault, to create notes or to mint and burn assets. Reads within the
+/// procedure's storage range remain allowed.
+///
+/// Finally, the info includes the [NoncePolicy] of the account the procedure belongs to. Account
+/// code built from components sets the same policy for all of its procedures, and the transaction
+/// kernel enforces the policy of the first procedure of the account (see
+/// [AccountCode::nonce_policy()](super::AccountCode::nonce_policy)).
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct AccountProcedureInfo {
     mast_root: Digest,
     storage_offset: u8,
     storage_size: u8,
+    read_only: bool,
+    nonce_policy: NoncePolicy,
 }
 
 impl AccountProcedureInfo {
@@ -64,7 +79,26 @@ impl AccountProcedureInfo {
             });
         }
 
-        Ok(Self { mast_root, storage_offset, storage_size })
+        Ok(Self {
+            mast_root,
+            storage_offset,
+            storage_size,
+            read_only: false,
+            nonce_policy: NoncePolicy::Relaxed,
+        })
+    }
+
+    /// Sets whether the procedure is prohibited from modifying the account's state (i.e., its
+    /// storage, vault, nonce and code).
+    pub fn with_read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Sets the nonce policy of the account the procedure belongs to.
+    pub fn with_nonce_policy(mut self, nonce_policy: NoncePolicy) -> Self {
+        self.nonce_policy = nonce_policy;
+        self
     }
 
     // PUBLIC ACCESSORS
@@ -84,6 +118,17 @@ impl AccountProcedureInfo {
     pub fn storage_size(&self) -> u8 {
         self.storage_size
     }
+
+    /// Returns true if the procedure is prohibited from modifying the account's state (i.e., its
+    /// storage, vault, nonce and code).
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Returns the nonce policy of the account the procedure belongs to.
+    pub fn nonce_policy(&self) -> NoncePolicy {
+        self.nonce_policy
+    }
 }
 
 impl From<AccountProcedureInfo> for [Felt; 8] {
@@ -99,6 +144,12 @@ impl From<AccountProcedureInfo> for [Felt; 8] {
         // copy the storage size into value[5]
         result[5] = Felt::from(value.storage_size);
 
+        // copy the read-only flag into value[6]
+        result[6] = Felt::from(value.read_only as u8);
+
+        // copy the nonce policy flag into value[7]
+        result[7] = Felt::from((value.nonce_policy == NoncePolicy::Strict) as u8);
+
         result
     }
 }
@@ -120,12 +171,27 @@ impl TryFrom<[Felt; 8]> for AccountProcedureInfo {
             .try_into()
             .map_err(|_| AccountError::AccountCodeProcedureInvalidStorageSize)?;
 
-        // Check if the remaining values are 0
-        if value[6] != Felt::ZERO || value[7] != Felt::ZERO {
-            return Err(AccountError::AccountCodeProcedureInvalidPadding);
-        }
-
-        Ok(Self { mast_root, storage_offset, storage_size })
+        // get read_only flag from value[6]
+        let read_only = match value[6].as_int() {
+            0 => false,
+            1 => true,
+            _ => return Err(AccountError::AccountCodeProcedureInvalidReadOnlyFlag),
+        };
+
+        // get nonce policy flag from value[7]
+        let nonce_policy = match value[7].as_int() {
+            0 => NoncePolicy::Relaxed,
+            1 => NoncePolicy::Strict,
+            _ => return Err(AccountError::AccountCodeProcedureInvalidNoncePolicy),
+        };
+
+        Ok(Self {
+            mast_root,
+            storage_offset,
+            storage_size,
+            read_only,
+            nonce_policy,
+        })
     }
 }
 
@@ -133,13 +199,17 @@ impl Serializable for AccountProcedureInfo {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         target.write(self.mast_root);
         target.write_u8(self.storage_offset);
-        target.write_u8(self.storage_size)
+        target.write_u8(self.storage_size);
+        target.write_bool(self.read_only);
+        target.write_bool(self.nonce_policy == NoncePolicy::Strict);
     }
 
     fn get_size_hint(&self) -> usize {
         self.mast_root.get_size_hint()
             + self.storage_offset.get_size_hint()
             + self.storage_size.get_size_hint()
+            + self.read_only.get_size_hint()
+            + true.get_size_hint()
     }
 }
 
@@ -148,7 +218,14 @@ impl Deserializable for AccountProcedureInfo {
         let mast_root: Digest = source.read()?;
         let storage_offset = source.read_u8()?;
         let storage_size = source.read_u8()?;
+        let read_only = source.read_bool()?;
+        let nonce_policy = if source.read_bool()? {
+            NoncePolicy::Strict
+        } else {
+            NoncePolicy::Relaxed
+        };
         Self::new(mast_root, storage_offset, storage_size)
+            .map(|procedure| procedure.with_read_only(read_only).with_nonce_policy(nonce_policy))
             .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
     }
 }
@@ -162,7 +239,7 @@ mod tests {
     use miden_crypto::utils::{Deserializable, Serializable};
     use vm_core::Felt;
 
-    use crate::accounts::{AccountCode, AccountProcedureInfo};
+    use crate::accounts::{AccountCode, AccountProcedureInfo, NoncePolicy};
 
     #[test]
     fn test_from_to_account_procedure() {
@@ -188,4 +265,46 @@ mod tests {
 
         assert_eq!(account_code.procedures()[0], deserialized);
     }
+
+    #[test]
+    fn test_read_only_account_procedure() {
+        let procedure = AccountCode::mock().procedures()[0].clone().with_read_only(true);
+        assert!(procedure.is_read_only());
+
+        // the read-only flag survives the conversion to field elements and serialization
+        let felts: [Felt; 8] = procedure.clone().into();
+        assert_eq!(felts[6], Felt::new(1));
+        assert_eq!(AccountProcedureInfo::try_from(felts).unwrap(), procedure);
+        assert_eq!(
+            AccountProcedureInfo::read_from_bytes(&procedure.to_bytes()).unwrap(),
+            procedure
+        );
+
+        // flags other than 0 and 1 are rejected
+        let mut felts = felts;
+        felts[6] = Felt::new(2);
+        assert!(AccountProcedureInfo::try_from(felts).is_err());
+    }
+
+    #[test]
+    fn test_account_procedure_nonce_policy() {
+        let procedure = AccountCode::mock().procedures()[0]
+            .clone()
+            .with_nonce_policy(NoncePolicy::Strict);
+        assert_eq!(procedure.nonce_policy(), NoncePolicy::Strict);
+
+        // the nonce policy survives the conversion to field elements and serialization
+        let felts: [Felt; 8] = procedure.clone().into();
+        assert_eq!(felts[7], Felt::new(1));
+        assert_eq!(AccountProcedureInfo::try_from(felts).unwrap(), procedure);
+        assert_eq!(
+            AccountProcedureInfo::read_from_bytes(&procedure.to_bytes()).unwrap(),
+            procedure
+        );
+
+        // flags other than 0 and 1 are rejected
+        let mut felts = felts;
+        felts[7] = Felt::new(2);
+        assert!(AccountProcedureInfo::try_from(felts).is_err());
+    }
 }
@@ -1,11 +1,15 @@
-use alloc::{collections::BTreeSet, string::ToString, vec::Vec};
+use alloc::{
+    collections::BTreeSet,
+    string::{String, ToString},
+    vec::Vec,
+};
 
 use assembly::{Assembler, Compile, Library};
 use vm_processor::MastForest;
 
 use crate::{
-    accounts::{AccountType, StorageSlot},
-    AccountError,
+    accounts::{AccountType, NoncePolicy, StorageSlot},
+    AccountError, Digest,
 };
 
 /// An [`AccountComponent`] defines a [`Library`] of code and the initial value and types of
@@ -23,11 +27,25 @@ use crate::{
 /// would only specify support for [`AccountType::FungibleFaucet`]. Using it to instantiate a
 /// regular account would fail. By default, the set of supported types is empty, so each component
 /// is forced to explicitly define what it supports.
+///
+/// Components can optionally be given a human-readable name (e.g. `"basic_wallet"`) which makes it
+/// easier to identify them when inspecting the components an account was built from.
+///
+/// Procedures of a component can be declared read-only, in which case the transaction kernel
+/// prevents them from modifying the state of the account (see
+/// [`AccountProcedureInfo`](crate::accounts::AccountProcedureInfo)).
+///
+/// Components can also opt the account into the [`NoncePolicy::Strict`] nonce policy, which the
+/// transaction kernel then enforces for every transaction executed against the account. The
+/// account uses the strict policy if any of its components requires it.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AccountComponent {
+    pub(super) name: Option<String>,
     pub(super) library: Library,
     pub(super) storage_slots: Vec<StorageSlot>,
     pub(super) supported_types: BTreeSet<AccountType>,
+    pub(super) read_only_procedures: BTreeSet<Digest>,
+    pub(super) nonce_policy: NoncePolicy,
 }
 
 impl AccountComponent {
@@ -54,9 +72,12 @@ impl AccountComponent {
             .map_err(|_| AccountError::StorageTooManySlots(storage_slots.len() as u64))?;
 
         Ok(Self {
+            name: None,
             library: code,
             storage_slots,
             supported_types: BTreeSet::new(),
+            read_only_procedures: BTreeSet::new(),
+            nonce_policy: NoncePolicy::Relaxed,
         })
     }
 
@@ -86,6 +107,11 @@ impl AccountComponent {
     // ACCESSORS
     // --------------------------------------------------------------------------------------------
 
+    /// Returns the name of this component, if one was set.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
     /// Returns the number of storage slots accessible from this component.
     pub fn storage_size(&self) -> u8 {
         u8::try_from(self.storage_slots.len())
@@ -117,9 +143,30 @@ impl AccountComponent {
         self.supported_types.contains(&account_type)
     }
 
+    /// Returns the MAST roots of the procedures of this component which are declared read-only.
+    pub fn read_only_procedures(&self) -> &BTreeSet<Digest> {
+        &self.read_only_procedures
+    }
+
+    /// Returns `true` if the procedure with the given MAST root is declared read-only.
+    pub fn is_read_only_procedure(&self, mast_root: &Digest) -> bool {
+        self.read_only_procedures.contains(mast_root)
+    }
+
+    /// Returns the nonce policy this component requires for the account.
+    pub fn nonce_policy(&self) -> NoncePolicy {
+        self.nonce_policy
+    }
+
     // MUTATORS
     // --------------------------------------------------------------------------------------------
 
+    /// Sets the human-readable name of this component.
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
     /// Adds `supported_type` to the set of [`AccountType`]s supported by this component.
     ///
     /// This function has the semantics of [`BTreeSet::insert`], i.e. adding a type twice is fine
@@ -138,6 +185,28 @@ impl AccountComponent {
         self
     }
 
+    /// Declares the procedures with the given MAST roots as read-only, i.e., prohibits them from
+    /// modifying the state of the account.
+    ///
+    /// MAST roots which do not belong to a procedure exported from this component's library are
+    /// ignored when building [`AccountCode`](crate::accounts::AccountCode).
+    pub fn with_read_only_procedures(
+        mut self,
+        mast_roots: impl IntoIterator<Item = Digest>,
+    ) -> Self {
+        self.read_only_procedures.extend(mast_roots);
+        self
+    }
+
+    /// Sets the nonce policy this component requires for the account.
+    ///
+    /// Requiring [NoncePolicy::Strict] forces every transaction against the account to increment
+    /// its nonce, even transactions which do not change the state of the account.
+    pub fn with_nonce_policy(mut self, nonce_policy: NoncePolicy) -> Self {
+        self.nonce_policy = nonce_policy;
+        self
+    }
+
     /// Sets the [`AccountType`]s supported by this component to all account types.
     pub fn with_supports_all_types(mut self) -> Self {
         self.supported_types.extend([
@@ -0,0 +1,491 @@
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::ToString,
+    vec::Vec,
+};
+
+use super::{AccountDelta, NonFungibleDeltaAction};
+use crate::{
+    accounts::{Account, AccountHeader, AccountId, AccountStorageHeader, PartialAccount},
+    assets::{Asset, FungibleAsset},
+    crypto::merkle::{MerklePath, NodeIndex, SmtProof, SMT_DEPTH},
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    AccountDeltaError, Digest, Felt, Hasher, Word, EMPTY_WORD,
+};
+
+// COMPACT ACCOUNT DELTA
+// ================================================================================================
+
+/// An [AccountDelta] together with openings of the changed account state against the state of the
+/// account before the delta was applied.
+///
+/// A compact delta consists of:
+/// - The initial state of the account as a [PartialAccount], i.e., the account header and storage
+///   header together with openings of all vault entries and storage map entries changed by the
+///   delta.
+/// - The [AccountDelta] itself.
+///
+/// This allows computing the commitment to the final state of a public account without access to
+/// its full state (see [CompactAccountDelta::final_header()]), and thus, verifying that an update
+/// of the account is consistent with the state commitments of a transaction or a batch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompactAccountDelta {
+    initial_state: PartialAccount,
+    delta: AccountDelta,
+}
+
+impl CompactAccountDelta {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [CompactAccountDelta] for the provided delta applied to the provided initial
+    /// account state.
+    ///
+    /// # Errors
+    /// Returns an error if the delta updates a storage map in a slot which is not a map slot.
+    pub fn new(initial_state: &Account, delta: AccountDelta) -> Result<Self, AccountDeltaError> {
+        let map_keys = delta.storage().maps().iter().flat_map(|(&index, map_delta)| {
+            map_delta.leaves().keys().map(move |&key| (index, Word::from(key)))
+        });
+        let vault_keys = delta
+            .vault()
+            .fungible()
+            .iter()
+            .map(|(&faucet_id, _)| fungible_vault_key(faucet_id))
+            .chain(delta.vault().non_fungible().iter().map(|(asset, _)| asset.vault_key()));
+
+        let initial_state = PartialAccount::from_account(initial_state, map_keys, vault_keys)
+            .map_err(AccountDeltaError::InvalidInitialStorage)?;
+
+        Ok(Self { initial_state, delta })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the initial state of the account to which this delta applies.
+    pub fn initial_state(&self) -> &PartialAccount {
+        &self.initial_state
+    }
+
+    /// Returns the underlying account delta.
+    pub fn delta(&self) -> &AccountDelta {
+        &self.delta
+    }
+
+    /// Returns the header of the account after this delta is applied to its initial state.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - An updated vault or storage map entry has not been opened.
+    /// - A fungible asset balance resulting from the delta is negative or too large.
+    /// - The delta updates a storage slot which does not exist.
+    pub fn final_header(&self) -> Result<AccountHeader, AccountDeltaError> {
+        let initial_header = self.initial_state.header();
+
+        // compute the new vault root
+        let mut vault_updates = Vec::new();
+        for (&faucet_id, &amount) in self.delta.vault().fungible().iter() {
+            let vault_key = fungible_vault_key(faucet_id);
+            let balance = self
+                .initial_state
+                .get_balance(faucet_id)
+                .map_err(|err| AccountDeltaError::CompactDeltaInvalidOpening(err.to_string()))?
+                .ok_or(AccountDeltaError::CompactDeltaMissingOpening(vault_key))?;
+
+            let value = match (balance as i64).checked_add(amount) {
+                Some(0) => EMPTY_WORD,
+                Some(balance) if balance > 0 => FungibleAsset::new(faucet_id, balance as u64)
+                    .map(|asset| Word::from(Asset::from(asset)))
+                    .map_err(|err| {
+                        AccountDeltaError::CompactDeltaInvalidOpening(err.to_string())
+                    })?,
+                _ => {
+                    return Err(AccountDeltaError::FungibleAssetDeltaOverflow {
+                        faucet_id,
+                        this: balance as i64,
+                        other: amount,
+                    })
+                },
+            };
+            vault_updates.push((Digest::from(vault_key), value));
+        }
+        for (&asset, &action) in self.delta.vault().non_fungible().iter() {
+            let value = match action {
+                NonFungibleDeltaAction::Add => Word::from(Asset::from(asset)),
+                NonFungibleDeltaAction::Remove => EMPTY_WORD,
+            };
+            vault_updates.push((Digest::from(asset.vault_key()), value));
+        }
+        let vault_root = apply_smt_updates(
+            initial_header.vault_root(),
+            self.initial_state.vault_openings(),
+            vault_updates,
+        )?;
+
+        // compute the new storage commitment
+        let mut slots: Vec<_> = self.initial_state.storage_header().slots().cloned().collect();
+        for (&index, &value) in self.delta.storage().values() {
+            self.initial_state
+                .get_item(index)
+                .map_err(AccountDeltaError::InvalidInitialStorage)?;
+            slots[index as usize].1 = value;
+        }
+        for (&index, map_delta) in self.delta.storage().maps() {
+            let root = self
+                .initial_state
+                .get_item(index)
+                .map_err(AccountDeltaError::InvalidInitialStorage)?;
+            let openings = self
+                .initial_state
+                .storage_map_openings()
+                .get(&index)
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+            let root = apply_smt_updates(
+                Digest::from(root),
+                openings,
+                map_delta.leaves().iter().map(|(&key, &value)| (key, value)),
+            )?;
+            slots[index as usize].1 = root.into();
+        }
+        let storage_commitment = AccountStorageHeader::new(slots).compute_commitment();
+
+        Ok(AccountHeader::new(
+            initial_header.id(),
+            self.delta.nonce().unwrap_or(initial_header.nonce()),
+            vault_root,
+            storage_commitment,
+            initial_header.code_commitment(),
+        ))
+    }
+
+    /// Converts this compact delta into its initial account state and the underlying delta.
+    pub fn into_parts(self) -> (PartialAccount, AccountDelta) {
+        (self.initial_state, self.delta)
+    }
+
+    // DATA MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Merges another [CompactAccountDelta] into this one.
+    ///
+    /// The other delta must apply to the final state of this delta. Its openings are re-based onto
+    /// the initial state of this delta, so that the merged delta can be verified against the
+    /// initial state alone.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The initial state of the other delta is not the final state of this delta.
+    /// - The underlying account deltas cannot be merged.
+    pub fn merge(&mut self, other: Self) -> Result<(), AccountDeltaError> {
+        let final_hash = self.final_header()?.hash();
+        if final_hash != other.initial_state.hash() {
+            return Err(AccountDeltaError::CompactDeltaStateMismatch {
+                expected: final_hash,
+                actual: other.initial_state.hash(),
+            });
+        }
+
+        let (other_state, other_delta) = other.into_parts();
+        let mut initial_state = self.initial_state.clone();
+
+        for opening in
+            rebase_openings(self.initial_state.vault_openings(), other_state.vault_openings())?
+        {
+            initial_state
+                .add_vault_opening(opening)
+                .map_err(|err| AccountDeltaError::CompactDeltaInvalidOpening(err.to_string()))?;
+        }
+        for (&index, openings) in other_state.storage_map_openings() {
+            let initial_openings = self
+                .initial_state
+                .storage_map_openings()
+                .get(&index)
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+
+            for opening in rebase_openings(initial_openings, openings)? {
+                initial_state.add_storage_map_opening(index, opening).map_err(|err| {
+                    AccountDeltaError::CompactDeltaInvalidOpening(err.to_string())
+                })?;
+            }
+        }
+
+        self.delta.merge(other_delta)?;
+        self.initial_state = initial_state;
+
+        Ok(())
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for CompactAccountDelta {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.initial_state.write_into(target);
+        self.delta.write_into(target);
+    }
+
+    fn get_size_hint(&self) -> usize {
+        // the size hint is used to enforce the account update size limit, so the size of the
+        // openings is computed exactly
+        self.initial_state.to_bytes().len() + self.delta.get_size_hint()
+    }
+}
+
+impl Deserializable for CompactAccountDelta {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let initial_state = PartialAccount::read_from(source)?;
+        let delta = AccountDelta::read_from(source)?;
+
+        Ok(Self { initial_state, delta })
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the vault key of the fungible asset issued by the specified faucet.
+fn fungible_vault_key(faucet_id: AccountId) -> Word {
+    FungibleAsset::new(faucet_id, 0)
+        .expect("fungible asset delta must contain only fungible faucet IDs")
+        .vault_key()
+}
+
+/// Returns the root of a Sparse Merkle tree after the provided updates are applied to it.
+///
+/// The provided openings must open all updated keys against the provided root of the tree.
+fn apply_smt_updates(
+    root: Digest,
+    openings: &[SmtProof],
+    updates: impl IntoIterator<Item = (Digest, Word)>,
+) -> Result<Digest, AccountDeltaError> {
+    // collect the new entries of all updated leaves
+    let mut leaves: BTreeMap<u64, Vec<(Digest, Word)>> = BTreeMap::new();
+    for (key, value) in updates {
+        let opening = openings
+            .iter()
+            .find(|opening| opening.get(&key).is_some())
+            .ok_or(AccountDeltaError::CompactDeltaMissingOpening(key.into()))?;
+
+        let entries = leaves
+            .entry(opening.leaf().index().value())
+            .or_insert_with(|| opening.leaf().entries().into_iter().copied().collect());
+        entries.retain(|(entry_key, _)| *entry_key != key);
+        if value != EMPTY_WORD {
+            entries.push((key, value));
+        }
+    }
+
+    if leaves.is_empty() {
+        return Ok(root);
+    }
+
+    // recompute the inner nodes on the paths from the updated leaves to the root, level by level
+    let mut nodes = opened_nodes(openings);
+    let mut updated = BTreeSet::new();
+    for (leaf_index, entries) in leaves {
+        let index = NodeIndex::new_unchecked(SMT_DEPTH, leaf_index);
+        nodes.insert(index, hash_leaf(entries));
+        updated.insert(index);
+    }
+
+    for _ in 0..SMT_DEPTH {
+        let mut parents = BTreeSet::new();
+        for mut index in updated {
+            index.move_up();
+            parents.insert(index);
+        }
+
+        for parent in parents.iter() {
+            let left = get_node(&nodes, parent.left_child())?;
+            let right = get_node(&nodes, parent.right_child())?;
+            nodes.insert(*parent, Hasher::merge(&[left, right]));
+        }
+
+        updated = parents;
+    }
+
+    get_node(&nodes, NodeIndex::root())
+}
+
+/// Returns the openings of the other tree state re-based onto the tree state opened by `initial`.
+///
+/// The other tree state must be the result of updating entries opened by `initial`. Openings of
+/// leaves which are already opened by `initial` are skipped, since the openings of `initial`
+/// already prove the initial values of all entries in these leaves. For all other leaves, the
+/// nodes known from `initial` take precedence over the nodes of the other openings, because they
+/// are the only nodes which may have changed between the two tree states.
+fn rebase_openings(
+    initial: &[SmtProof],
+    other: &[SmtProof],
+) -> Result<Vec<SmtProof>, AccountDeltaError> {
+    let nodes = opened_nodes(initial);
+    let opened_leaves: BTreeSet<u64> =
+        initial.iter().map(|opening| opening.leaf().index().value()).collect();
+
+    other
+        .iter()
+        .filter(|opening| !opened_leaves.contains(&opening.leaf().index().value()))
+        .map(|opening| {
+            let mut index = NodeIndex::new_unchecked(SMT_DEPTH, opening.leaf().index().value());
+            let mut path = Vec::with_capacity(SMT_DEPTH as usize);
+            for sibling in opening.path().iter() {
+                path.push(nodes.get(&index.sibling()).copied().unwrap_or(*sibling));
+                index.move_up();
+            }
+
+            SmtProof::new(MerklePath::new(path), opening.leaf().clone())
+                .map_err(|err| AccountDeltaError::CompactDeltaInvalidOpening(err.to_string()))
+        })
+        .collect()
+}
+
+/// Returns all nodes of a Sparse Merkle tree which are known from the provided openings.
+fn opened_nodes(openings: &[SmtProof]) -> BTreeMap<NodeIndex, Digest> {
+    let mut nodes = BTreeMap::new();
+    for opening in openings {
+        let mut index = NodeIndex::new_unchecked(SMT_DEPTH, opening.leaf().index().value());
+        let mut node = opening.leaf().hash();
+        nodes.insert(index, node);
+
+        for &sibling in opening.path().iter() {
+            nodes.insert(index.sibling(), sibling);
+            node = if index.is_value_odd() {
+                Hasher::merge(&[sibling, node])
+            } else {
+                Hasher::merge(&[node, sibling])
+            };
+            index.move_up();
+            nodes.insert(index, node);
+        }
+    }
+
+    nodes
+}
+
+fn get_node(
+    nodes: &BTreeMap<NodeIndex, Digest>,
+    index: NodeIndex,
+) -> Result<Digest, AccountDeltaError> {
+    nodes.get(&index).copied().ok_or_else(|| {
+        AccountDeltaError::CompactDeltaInvalidOpening(format!("node {index:?} has not been opened"))
+    })
+}
+
+/// Returns the hash of a Sparse Merkle tree leaf with the provided entries.
+///
+/// This mirrors the leaf hashing of [crate::crypto::merkle::SmtLeaf].
+pub(crate) fn hash_leaf(mut entries: Vec<(Digest, Word)>) -> Digest {
+    match entries.len() {
+        0 => Digest::from(EMPTY_WORD),
+        1 => {
+            let (key, value) = entries[0];
+            Hasher::merge(&[key, value.into()])
+        },
+        _ => {
+            sort_leaf_entries(&mut entries);
+            let elements: Vec<Felt> = entries
+                .into_iter()
+                .flat_map(|(key, value)| Word::from(key).into_iter().chain(value))
+                .collect();
+            Hasher::hash_elements(&elements)
+        },
+    }
+}
+
+/// Sorts the provided entries of a Sparse Merkle tree leaf in the order in which they are hashed,
+/// i.e., by their keys, starting with the most significant element.
+pub(crate) fn sort_leaf_entries(entries: &mut [(Digest, Word)]) {
+    entries.sort_by(|(key_a, _), (key_b, _)| {
+        let key_a = Word::from(*key_a);
+        let key_b = Word::from(*key_b);
+        key_a.iter().rev().map(Felt::as_int).cmp(key_b.iter().rev().map(Felt::as_int))
+    });
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use vm_core::utils::{Deserializable, Serializable};
+
+    use super::CompactAccountDelta;
+    use crate::{
+        accounts::{
+            account_id::testing::ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, AccountId,
+            AccountStorageDelta, StorageMap, StorageMapDelta, StorageSlot,
+        },
+        assets::{Asset, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails},
+        testing::storage::{build_account, build_account_delta, build_assets},
+        AccountDeltaError, Digest, Felt,
+    };
+
+    #[test]
+    fn compact_delta_computes_final_state() {
+        let (asset_0, asset_1) = build_assets();
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let non_fungible: Asset =
+            NonFungibleAsset::new(&NonFungibleAssetDetails::new(faucet_id, vec![1, 2, 3]).unwrap())
+                .unwrap()
+                .into();
+
+        let key_0 = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let key_1 = [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)];
+        let value = [Felt::new(9), Felt::new(10), Felt::new(11), Felt::new(12)];
+        let map = StorageMap::with_entries([(Digest::from(key_0), value)]).unwrap();
+
+        let mut account = build_account(
+            vec![asset_0, asset_1],
+            Felt::new(1),
+            vec![StorageSlot::Value(value), StorageSlot::Map(map)],
+        );
+
+        // remove part of a fungible asset, add a non-fungible asset and update the storage
+        let spent: Asset = FungibleAsset::new(asset_0.faucet_id(), 100).unwrap().into();
+        let storage_delta = AccountStorageDelta::from_iters(
+            [0],
+            [],
+            [(1, StorageMapDelta::from_iters([key_0], [(key_1, value)]))],
+        );
+        let delta =
+            build_account_delta(vec![non_fungible], vec![spent], Felt::new(2), storage_delta);
+
+        let mut compact_delta = CompactAccountDelta::new(&account, delta.clone()).unwrap();
+        assert_eq!(compact_delta.initial_state().hash(), account.hash());
+
+        account.apply_delta(&delta).unwrap();
+        assert_eq!(compact_delta.final_header().unwrap().hash(), account.hash());
+
+        let bytes = compact_delta.to_bytes();
+        assert_eq!(CompactAccountDelta::read_from_bytes(&bytes).unwrap(), compact_delta);
+
+        // remove the rest of the fungible asset and the other map entry
+        let rest: Asset = FungibleAsset::new(asset_0.faucet_id(), 23).unwrap().into();
+        let storage_delta = AccountStorageDelta::from_iters(
+            [],
+            [],
+            [(1, StorageMapDelta::from_iters([key_1], []))],
+        );
+        let delta = build_account_delta(vec![], vec![rest, asset_1], Felt::new(3), storage_delta);
+
+        let next_delta = CompactAccountDelta::new(&account, delta.clone()).unwrap();
+        account.apply_delta(&delta).unwrap();
+        assert_eq!(next_delta.final_header().unwrap().hash(), account.hash());
+
+        // merged deltas are verified against the initial state of the first delta
+        let initial_hash = compact_delta.initial_state().hash();
+        compact_delta.merge(next_delta.clone()).unwrap();
+        assert_eq!(compact_delta.initial_state().hash(), initial_hash);
+        assert_eq!(compact_delta.final_header().unwrap().hash(), account.hash());
+
+        // a delta cannot be merged into a delta which does not precede it
+        assert!(matches!(
+            next_delta.clone().merge(next_delta),
+            Err(AccountDeltaError::CompactDeltaStateMismatch { .. })
+        ));
+    }
+}
@@ -6,8 +6,12 @@ use super::{
 };
 use crate::AccountDeltaError;
 
+mod compact;
+pub use compact::CompactAccountDelta;
+pub(crate) use compact::{hash_leaf, sort_leaf_entries};
+
 mod storage;
-pub use storage::{AccountStorageDelta, StorageMapDelta};
+pub use storage::{AccountStorageDelta, StorageMapDelta, StorageMapEntryChange};
 
 mod vault;
 pub use vault::{
@@ -67,6 +71,55 @@ impl AccountDelta {
         self.vault.merge(other.vault)
     }
 
+    /// Returns a delta which undoes the changes of this delta.
+    ///
+    /// The provided account must be the state of the account before this delta was applied. The
+    /// nonce of the returned delta, if any, is the nonce of that initial state. Since this
+    /// decreases the nonce, the returned delta must be applied via [Account::revert_delta()]
+    /// rather than [Account::apply_delta()].
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The storage of the provided account is inconsistent with the storage delta.
+    /// - A fungible asset delta cannot be negated.
+    pub fn invert(&self, initial_state: &Account) -> Result<Self, AccountDeltaError> {
+        let storage = self.storage.invert(initial_state.storage())?;
+        let vault = self.vault.invert()?;
+        let nonce = self.nonce.map(|_| initial_state.nonce());
+
+        Ok(Self { storage, vault, nonce })
+    }
+
+    /// Checks that the nonce update of this delta is valid for an account whose nonce was
+    /// `initial_nonce` before the delta, under the provided [NoncePolicy].
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The new nonce is not larger than the initial nonce.
+    /// - The policy is [NoncePolicy::Strict] and the nonce was not incremented by exactly one.
+    pub fn validate_nonce_update(
+        &self,
+        initial_nonce: Felt,
+        policy: NoncePolicy,
+    ) -> Result<(), AccountDeltaError> {
+        match (self.nonce, policy) {
+            (Some(new), _) if new.as_int() <= initial_nonce.as_int() => {
+                Err(AccountDeltaError::InconsistentNonceUpdate(format!(
+                    "New nonce {new} is not larger than the initial nonce {initial_nonce}"
+                )))
+            },
+            (Some(new), NoncePolicy::Strict) if new.as_int() != initial_nonce.as_int() + 1 => {
+                Err(AccountDeltaError::InconsistentNonceUpdate(format!(
+                    "New nonce {new} is not the successor of the initial nonce {initial_nonce}"
+                )))
+            },
+            (None, NoncePolicy::Strict) => Err(AccountDeltaError::InconsistentNonceUpdate(
+                "nonce not updated under the strict nonce policy".to_string(),
+            )),
+            _ => Ok(()),
+        }
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -96,6 +149,25 @@ impl AccountDelta {
     }
 }
 
+// NONCE POLICY
+// ================================================================================================
+
+/// Describes how the nonce of an account is expected to change in a single transaction.
+///
+/// The nonce policy of an account is part of its code (see
+/// [AccountComponent::with_nonce_policy()](crate::accounts::AccountComponent::with_nonce_policy))
+/// and is enforced by the transaction kernel. Executors may additionally apply a stricter policy
+/// to detect replays when chaining local transactions against the same account.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NoncePolicy {
+    /// The nonce must increase if the account state changed, and may be left unchanged otherwise.
+    #[default]
+    Relaxed,
+    /// The nonce must be incremented by exactly one in every transaction, even if the account
+    /// state did not change.
+    Strict,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum AccountUpdateDetails {
     /// Account is private (no on-chain state change).
@@ -106,6 +178,10 @@ pub enum AccountUpdateDetails {
 
     /// For existing accounts, only the delta is needed.
     Delta(AccountDelta),
+
+    /// For existing accounts, the delta together with openings of the changed state against the
+    /// initial state, which allow verifying the update without access to the full account state.
+    CompactDelta(CompactAccountDelta),
 }
 
 impl AccountUpdateDetails {
@@ -132,10 +208,27 @@ impl AccountUpdateDetails {
 
                 AccountUpdateDetails::New(account)
             },
+            (AccountUpdateDetails::New(mut account), AccountUpdateDetails::CompactDelta(delta)) => {
+                account.apply_delta(delta.delta()).map_err(|_| {
+                    AccountDeltaError::IncompatibleAccountUpdates(
+                        AccountUpdateDetails::New(account.clone()),
+                        AccountUpdateDetails::CompactDelta(delta.clone()),
+                    )
+                })?;
+
+                AccountUpdateDetails::New(account)
+            },
             (AccountUpdateDetails::Delta(mut delta), AccountUpdateDetails::Delta(new_delta)) => {
                 delta.merge(new_delta)?;
                 AccountUpdateDetails::Delta(delta)
             },
+            (
+                AccountUpdateDetails::CompactDelta(mut delta),
+                AccountUpdateDetails::CompactDelta(new_delta),
+            ) => {
+                delta.merge(new_delta)?;
+                AccountUpdateDetails::CompactDelta(delta)
+            },
             (left, right) => {
                 return Err(AccountDeltaError::IncompatibleAccountUpdates(left, right))
             },
@@ -187,6 +280,10 @@ impl Serializable for AccountUpdateDetails {
                 2_u8.write_into(target);
                 delta.write_into(target);
             },
+            AccountUpdateDetails::CompactDelta(delta) => {
+                3_u8.write_into(target);
+                delta.write_into(target);
+            },
         }
     }
 
@@ -198,6 +295,7 @@ impl Serializable for AccountUpdateDetails {
             AccountUpdateDetails::Private => u8_size,
             AccountUpdateDetails::New(account) => u8_size + account.get_size_hint(),
             AccountUpdateDetails::Delta(account_delta) => u8_size + account_delta.get_size_hint(),
+            AccountUpdateDetails::CompactDelta(delta) => u8_size + delta.get_size_hint(),
         }
     }
 }
@@ -208,6 +306,7 @@ impl Deserializable for AccountUpdateDetails {
             0 => Ok(Self::Private),
             1 => Ok(Self::New(Account::read_from(source)?)),
             2 => Ok(Self::Delta(AccountDelta::read_from(source)?)),
+            3 => Ok(Self::CompactDelta(CompactAccountDelta::read_from(source)?)),
             v => Err(DeserializationError::InvalidValue(format!(
                 "Unknown variant {v} for AccountDetails"
             ))),
@@ -256,7 +355,7 @@ mod tests {
 
     use vm_core::{utils::Serializable, Felt, FieldElement};
 
-    use super::{AccountDelta, AccountStorageDelta, AccountVaultDelta};
+    use super::{AccountDelta, AccountStorageDelta, AccountVaultDelta, NoncePolicy};
     use crate::{
         accounts::{
             account_id::testing::ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
@@ -284,6 +383,41 @@ mod tests {
         assert!(AccountDelta::new(storage_delta.clone(), vault_delta.clone(), Some(ONE)).is_ok());
     }
 
+    #[test]
+    fn account_delta_nonce_policy() {
+        let initial_nonce = Felt::new(5);
+        let delta_with_nonce = |nonce: u64| {
+            AccountDelta::new(
+                AccountStorageDelta::from_iters([1], [], []),
+                AccountVaultDelta::default(),
+                Some(Felt::new(nonce)),
+            )
+            .unwrap()
+        };
+
+        // unchanged nonce is accepted only under the relaxed policy
+        let delta = AccountDelta::default();
+        assert!(delta.validate_nonce_update(initial_nonce, NoncePolicy::Relaxed).is_ok());
+        assert!(delta.validate_nonce_update(initial_nonce, NoncePolicy::Strict).is_err());
+
+        // increment by one is accepted under both policies
+        let delta = delta_with_nonce(6);
+        assert!(delta.validate_nonce_update(initial_nonce, NoncePolicy::Relaxed).is_ok());
+        assert!(delta.validate_nonce_update(initial_nonce, NoncePolicy::Strict).is_ok());
+
+        // larger increments are accepted only under the relaxed policy
+        let delta = delta_with_nonce(8);
+        assert!(delta.validate_nonce_update(initial_nonce, NoncePolicy::Relaxed).is_ok());
+        assert!(delta.validate_nonce_update(initial_nonce, NoncePolicy::Strict).is_err());
+
+        // regressions are rejected under both policies
+        for nonce in [4, 5] {
+            let delta = delta_with_nonce(nonce);
+            assert!(delta.validate_nonce_update(initial_nonce, NoncePolicy::Relaxed).is_err());
+            assert!(delta.validate_nonce_update(initial_nonce, NoncePolicy::Strict).is_err());
+        }
+    }
+
     #[test]
     fn account_update_details_size_hint() {
         // AccountDelta
@@ -10,7 +10,10 @@ use super::{
     AccountDeltaError, ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
     Word,
 };
-use crate::Digest;
+use crate::{
+    accounts::{AccountStorage, StorageMap, StorageSlot},
+    AccountError, Digest,
+};
 
 // ACCOUNT STORAGE DELTA
 // ================================================================================================
@@ -22,6 +25,10 @@ use crate::Digest;
 ///   updated storage slots and the values are the new values for these slots.
 /// - A map containing updates to storage maps. The keys in this map are indexes of the updated
 ///   storage slots and the values are corresponding storage map delta objects.
+///
+/// Updates to storage maps are tracked per map entry rather than as replacements of the whole map,
+/// so that the size of the delta depends only on the number of changed entries. A minimal delta
+/// between two states of account storage can be computed via [AccountStorageDelta::from_diff()].
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct AccountStorageDelta {
     values: BTreeMap<u8, Word>,
@@ -40,6 +47,66 @@ impl AccountStorageDelta {
         Ok(result)
     }
 
+    /// Returns a minimal delta which transforms the `initial` storage into the `final_storage`.
+    ///
+    /// Value slots are included only if their values differ, and for map slots only the entries
+    /// which differ are included (see [StorageMapDelta::from_diff()]).
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The two storages do not have the same number of slots.
+    /// - A slot has a different type in the two storages.
+    pub fn from_diff(
+        initial: &AccountStorage,
+        final_storage: &AccountStorage,
+    ) -> Result<Self, AccountDeltaError> {
+        let initial_slots = initial.slots();
+        let final_slots = final_storage.slots();
+        if initial_slots.len() != final_slots.len() {
+            return Err(AccountDeltaError::StorageSlotCountMismatch {
+                expected: initial_slots.len(),
+                actual: final_slots.len(),
+            });
+        }
+
+        let mut delta = Self::default();
+        for (index, (initial_slot, final_slot)) in initial_slots.iter().zip(final_slots).enumerate()
+        {
+            let index = index as u8;
+            match (initial_slot, final_slot) {
+                (StorageSlot::Value(initial_value), StorageSlot::Value(final_value)) => {
+                    if initial_value != final_value {
+                        delta.values.insert(index, *final_value);
+                    }
+                },
+                (StorageSlot::Map(initial_map), StorageSlot::Map(final_map)) => {
+                    let map_delta = StorageMapDelta::from_diff(initial_map, final_map);
+                    if !map_delta.is_empty() {
+                        delta.maps.insert(index, map_delta);
+                    }
+                },
+                (StorageSlot::Value(_), _) | (_, StorageSlot::Value(_)) => {
+                    return Err(AccountDeltaError::StorageSlotTypeMismatch(index))
+                },
+                // at least one of the maps is held by a storage backend; since the backend cannot
+                // enumerate the changed entries, both maps are loaded if their roots differ
+                (initial_slot, final_slot) => {
+                    if initial_slot.value() != final_slot.value() {
+                        let initial_map = load_map(initial_slot)
+                            .map_err(AccountDeltaError::InvalidInitialStorage)?;
+                        let final_map =
+                            load_map(final_slot).map_err(AccountDeltaError::InvalidFinalStorage)?;
+                        delta
+                            .maps
+                            .insert(index, StorageMapDelta::from_diff(&initial_map, &final_map));
+                    }
+                },
+            }
+        }
+
+        Ok(delta)
+    }
+
     /// Returns a reference to the updated values in this storage delta.
     pub fn values(&self) -> &BTreeMap<u8, Word> {
         &self.values
@@ -82,6 +149,80 @@ impl AccountStorageDelta {
         self.validate()
     }
 
+    /// Returns a delta which undoes the changes of this delta.
+    ///
+    /// The returned delta restores every slot and map entry updated by this delta to its value in
+    /// the provided storage, which must be the storage this delta was applied to.
+    ///
+    /// # Errors
+    /// Returns an error if a slot updated by this delta does not exist in the provided storage, or
+    /// if a map slot updated by this delta is not a map in the provided storage.
+    pub fn invert(&self, storage: &AccountStorage) -> Result<Self, AccountDeltaError> {
+        let values = self
+            .values
+            .keys()
+            .map(|&slot| storage.get_item(slot).map(|value| (slot, Word::from(value))))
+            .collect::<Result<_, _>>()
+            .map_err(AccountDeltaError::InvalidInitialStorage)?;
+
+        let maps = self
+            .maps
+            .iter()
+            .map(|(&slot, map_delta)| {
+                let leaves = map_delta
+                    .leaves()
+                    .keys()
+                    .map(|key| Ok((*key, storage.get_map_item(slot, Word::from(*key))?)))
+                    .collect::<Result<_, AccountError>>()?;
+                Ok((slot, StorageMapDelta::new(leaves)))
+            })
+            .collect::<Result<_, _>>()
+            .map_err(AccountDeltaError::InvalidInitialStorage)?;
+
+        Ok(Self { values, maps })
+    }
+
+    /// Checks that applying this delta to the provided storage results in the storage committed to
+    /// by `final_commitment`.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - A value slot updated by this delta does not exist in the provided storage or is not a
+    ///   value slot.
+    /// - A map slot updated by this delta does not exist in the provided storage or is not a map
+    ///   slot.
+    /// - The commitment to the provided storage with this delta applied is not
+    ///   `final_commitment`.
+    pub fn validate_against(
+        &self,
+        storage: &AccountStorage,
+        final_commitment: Digest,
+    ) -> Result<(), AccountDeltaError> {
+        for &index in self.values.keys() {
+            match storage.slots().get(index as usize) {
+                Some(StorageSlot::Value(_)) => (),
+                _ => return Err(AccountDeltaError::StorageSlotTypeMismatch(index)),
+            }
+        }
+
+        for &index in self.maps.keys() {
+            storage.get_map_slot(index).map_err(AccountDeltaError::InvalidInitialStorage)?;
+        }
+
+        let mut updated_storage = storage.clone();
+        updated_storage
+            .apply_delta(self)
+            .map_err(AccountDeltaError::InvalidInitialStorage)?;
+        if updated_storage.commitment() != final_commitment {
+            return Err(AccountDeltaError::StorageCommitmentMismatch {
+                expected: final_commitment,
+                actual: updated_storage.commitment(),
+            });
+        }
+
+        Ok(())
+    }
+
     /// Checks whether this storage delta is valid.
     ///
     /// # Errors:
@@ -194,6 +335,9 @@ impl Deserializable for AccountStorageDelta {
 ///
 /// The differences are represented as leaf updates: a map of updated item key ([Digest]) to
 /// value ([Word]). For cleared items the value is [EMPTY_WORD].
+///
+/// Whether an update adds, updates or removes an entry depends on the state of the map the delta
+/// is applied to, and can be determined via [StorageMapDelta::changes()].
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct StorageMapDelta(BTreeMap<Digest, Word>);
 
@@ -203,6 +347,24 @@ impl StorageMapDelta {
         Self(map)
     }
 
+    /// Returns a minimal delta which transforms the `initial` map into the `final_map`, i.e., a
+    /// delta containing exactly the entries whose values differ between the two maps.
+    pub fn from_diff(initial: &StorageMap, final_map: &StorageMap) -> Self {
+        let mut leaves = BTreeMap::new();
+        for (key, value) in final_map.entries() {
+            if initial.get_value(key) != *value {
+                leaves.insert(*key, *value);
+            }
+        }
+        for (key, _) in initial.entries() {
+            if final_map.get_value(key) == EMPTY_WORD {
+                leaves.insert(*key, EMPTY_WORD);
+            }
+        }
+
+        Self(leaves)
+    }
+
     /// Returns a reference to the updated leaves in this storage map delta.
     pub fn leaves(&self) -> &BTreeMap<Digest, Word> {
         &self.0
@@ -218,6 +380,27 @@ impl StorageMapDelta {
         self.0.is_empty()
     }
 
+    /// Returns an iterator over the changes which this delta makes to the provided map, i.e., the
+    /// map the delta is applied to.
+    ///
+    /// Updates which do not change the map (e.g., clearing a key which is not set) are skipped.
+    pub fn changes<'a>(
+        &'a self,
+        map: &'a StorageMap,
+    ) -> impl Iterator<Item = (Digest, StorageMapEntryChange)> + 'a {
+        self.0.iter().filter_map(|(&key, &new)| {
+            let old = map.get_value(&key);
+            let change = match (old == EMPTY_WORD, new == EMPTY_WORD) {
+                _ if old == new => return None,
+                (true, _) => StorageMapEntryChange::Added(new),
+                (false, true) => StorageMapEntryChange::Removed(old),
+                (false, false) => StorageMapEntryChange::Updated { old, new },
+            };
+
+            Some((key, change))
+        })
+    }
+
     /// Merge `other` into this delta, giving precedence to `other`.
     pub fn merge(&mut self, other: Self) {
         // Aggregate the changes into a map such that `other` overwrites self.
@@ -235,6 +418,17 @@ impl StorageMapDelta {
     }
 }
 
+/// A change of a single entry of a storage map made by a [StorageMapDelta].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StorageMapEntryChange {
+    /// An entry with the specified value was added to the map.
+    Added(Word),
+    /// The value of an existing entry was updated.
+    Updated { old: Word, new: Word },
+    /// An entry with the specified value was removed from the map.
+    Removed(Word),
+}
+
 #[cfg(any(feature = "testing", test))]
 impl StorageMapDelta {
     /// Creates a new [StorageMapDelta] from the provided iterators.
@@ -299,14 +493,30 @@ impl Deserializable for StorageMapDelta {
     }
 }
 
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the map of the provided map slot, loading it from its storage backend if necessary.
+fn load_map(slot: &StorageSlot) -> Result<StorageMap, AccountError> {
+    match slot {
+        StorageSlot::Map(map) => Ok(map.clone()),
+        StorageSlot::LazyMap(map) => map.to_storage_map(),
+        StorageSlot::Value(_) => unreachable!("value slots should not be loaded as maps"),
+    }
+}
+
 // TESTS
 // ================================================================================================
 
 #[cfg(test)]
 mod tests {
-    use super::{AccountStorageDelta, Deserializable, Serializable};
+    use super::{
+        AccountStorageDelta, Deserializable, Serializable, StorageMapEntryChange, Vec, EMPTY_WORD,
+    };
     use crate::{
-        accounts::StorageMapDelta, testing::storage::AccountStorageDeltaBuilder, ONE, ZERO,
+        accounts::{AccountStorage, StorageMap, StorageMapDelta, StorageSlot},
+        testing::storage::AccountStorageDeltaBuilder,
+        AccountDeltaError, Digest, Felt, ONE, ZERO,
     };
 
     #[test]
@@ -344,6 +554,79 @@ mod tests {
         assert!(AccountStorageDelta::read_from_bytes(&bytes).is_err());
     }
 
+    #[test]
+    fn storage_delta_tracks_map_entries() {
+        let [key_a, key_b, key_c, key_d] =
+            [1, 2, 3, 4].map(|key| Digest::from([Felt::new(key), ZERO, ZERO, ZERO]));
+        let [one, two] = [[ONE; 4], [Felt::new(2); 4]];
+
+        let initial_map =
+            StorageMap::with_entries([(key_a, one), (key_b, one), (key_c, one)]).unwrap();
+        let final_map =
+            StorageMap::with_entries([(key_a, one), (key_b, two), (key_d, two)]).unwrap();
+        let initial = AccountStorage::new(vec![
+            StorageSlot::Value(one),
+            StorageSlot::Value(one),
+            StorageSlot::Map(initial_map.clone()),
+        ])
+        .unwrap();
+        let final_storage = AccountStorage::new(vec![
+            StorageSlot::Value(one),
+            StorageSlot::Value(two),
+            StorageSlot::Map(final_map.clone()),
+        ])
+        .unwrap();
+
+        // only the changed slots and map entries are included in the delta
+        let delta = AccountStorageDelta::from_diff(&initial, &final_storage).unwrap();
+        assert_eq!(delta.values().keys().collect::<Vec<_>>(), vec![&1]);
+        let map_delta = delta.maps().get(&2).unwrap();
+        assert_eq!(map_delta.leaves().keys().collect::<Vec<_>>(), vec![&key_b, &key_c, &key_d]);
+        assert_eq!(
+            map_delta.changes(&initial_map).collect::<Vec<_>>(),
+            vec![
+                (key_b, StorageMapEntryChange::Updated { old: one, new: two }),
+                (key_c, StorageMapEntryChange::Removed(one)),
+                (key_d, StorageMapEntryChange::Added(two)),
+            ]
+        );
+        assert_eq!(map_delta.changes(&final_map).count(), 0);
+
+        // applying the delta to the initial storage results in the final storage
+        delta.validate_against(&initial, final_storage.commitment()).unwrap();
+        assert!(matches!(
+            delta.validate_against(&initial, initial.commitment()),
+            Err(AccountDeltaError::StorageCommitmentMismatch { .. })
+        ));
+        assert!(matches!(
+            delta.validate_against(
+                &AccountStorage::new(vec![StorageSlot::Value(one)]).unwrap(),
+                final_storage.commitment()
+            ),
+            Err(AccountDeltaError::StorageSlotTypeMismatch(1))
+        ));
+
+        let mut storage = initial.clone();
+        storage.apply_delta(&delta).unwrap();
+        assert_eq!(storage.commitment(), final_storage.commitment());
+
+        // clearing an absent key is not a change
+        let map_delta = StorageMapDelta::from_iters([[Felt::new(5), ZERO, ZERO, ZERO]], []);
+        assert_eq!(map_delta.changes(&initial_map).count(), 0);
+        assert_eq!(
+            StorageMapDelta::from_diff(&initial_map, &initial_map),
+            StorageMapDelta::default()
+        );
+        assert_eq!(map_delta.leaves().values().next(), Some(&EMPTY_WORD));
+
+        // storages with different layouts cannot be diffed
+        let storage = AccountStorage::new(vec![StorageSlot::Value(one)]).unwrap();
+        assert!(matches!(
+            AccountStorageDelta::from_diff(&initial, &storage),
+            Err(AccountDeltaError::StorageSlotCountMismatch { expected: 3, actual: 1 })
+        ));
+    }
+
     #[test]
     fn test_is_empty() {
         let storage_delta = AccountStorageDelta::default();
@@ -10,6 +10,7 @@ use super::{
 use crate::{
     accounts::{AccountId, AccountType},
     assets::{Asset, FungibleAsset, NonFungibleAsset},
+    bounded_serde::read_many_bounded,
 };
 
 // ACCOUNT VAULT DELTA
@@ -78,6 +79,16 @@ impl AccountVaultDelta {
         self.non_fungible.merge(other.non_fungible)?;
         self.fungible.merge(other.fungible)
     }
+
+    /// Returns a delta which undoes the changes of this delta.
+    ///
+    /// Assets added by this delta are removed by the returned delta and vice versa.
+    ///
+    /// # Errors
+    /// Returns an error if a fungible asset delta cannot be negated.
+    pub fn invert(&self) -> Result<Self, AccountDeltaError> {
+        Ok(Self::new(self.fungible.invert()?, self.non_fungible.invert()))
+    }
 }
 
 #[cfg(any(feature = "testing", test))]
@@ -234,6 +245,27 @@ impl FungibleAssetDelta {
         Ok(())
     }
 
+    /// Returns a delta which undoes the changes of this delta, i.e., a delta in which every
+    /// amount is negated.
+    ///
+    /// # Errors
+    /// Returns an error if an amount cannot be negated without overflowing.
+    pub fn invert(&self) -> Result<Self, AccountDeltaError> {
+        self.0
+            .iter()
+            .map(|(&faucet_id, &amount)| {
+                amount.checked_neg().map(|negated| (faucet_id, negated)).ok_or(
+                    AccountDeltaError::FungibleAssetDeltaOverflow {
+                        faucet_id,
+                        this: 0,
+                        other: amount,
+                    },
+                )
+            })
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+
     // HELPER FUNCTIONS
     // ---------------------------------------------------------------------------------------------
 
@@ -303,11 +335,14 @@ impl Deserializable for FungibleAssetDelta {
         // TODO: We save `i64` as `u64` since winter utils only supports unsigned integers for now.
         //   We should update this code (and serialization as well) once it support signeds
         // integers.
-        let map = source
-            .read_many::<(AccountId, u64)>(num_fungible_assets)?
-            .into_iter()
-            .map(|(account_id, delta_as_u64)| (account_id, delta_as_u64 as i64))
-            .collect();
+        let map = read_many_bounded::<_, (AccountId, u64)>(
+            source,
+            num_fungible_assets,
+            FungibleAsset::SERIALIZED_SIZE,
+        )?
+        .into_iter()
+        .map(|(account_id, delta_as_u64)| (account_id, delta_as_u64 as i64))
+        .collect();
 
         Self::new(map).map_err(|err| DeserializationError::InvalidValue(err.to_string()))
     }
@@ -367,6 +402,12 @@ impl NonFungibleAssetDelta {
         Ok(())
     }
 
+    /// Returns a delta which undoes the changes of this delta, i.e., a delta in which every added
+    /// asset is removed and every removed asset is added.
+    pub fn invert(&self) -> Self {
+        Self(self.0.iter().map(|(&asset, &action)| (asset, action.inverse())).collect())
+    }
+
     // HELPER FUNCTIONS
     // ---------------------------------------------------------------------------------------------
 
@@ -459,6 +500,16 @@ pub enum NonFungibleDeltaAction {
     Remove,
 }
 
+impl NonFungibleDeltaAction {
+    /// Returns the action which undoes this action.
+    pub fn inverse(&self) -> Self {
+        match self {
+            NonFungibleDeltaAction::Add => NonFungibleDeltaAction::Remove,
+            NonFungibleDeltaAction::Remove => NonFungibleDeltaAction::Add,
+        }
+    }
+}
+
 // TESTS
 // ================================================================================================
 
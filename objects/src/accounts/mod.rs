@@ -6,13 +6,13 @@ use crate::{
 
 pub mod account_id;
 pub use account_id::{
-    AccountId, AccountStorageMode, AccountType, ACCOUNT_ISFAUCET_MASK, ACCOUNT_STORAGE_MASK_SHIFT,
-    ACCOUNT_TYPE_MASK_SHIFT,
+    AccountId, AccountIdParts, AccountIdVersion, AccountStorageMode, AccountType,
+    SeedGrindingParams, ACCOUNT_ISFAUCET_MASK, ACCOUNT_STORAGE_MASK_SHIFT, ACCOUNT_TYPE_MASK_SHIFT,
 };
 
 pub mod auth;
 
-pub use auth::AuthSecretKey;
+pub use auth::{AuthPublicKey, AuthSecretKey};
 
 mod builder;
 pub use builder::AccountBuilder;
@@ -25,15 +25,20 @@ pub use component::AccountComponent;
 
 pub mod delta;
 pub use delta::{
-    AccountDelta, AccountStorageDelta, AccountVaultDelta, FungibleAssetDelta,
-    NonFungibleAssetDelta, NonFungibleDeltaAction, StorageMapDelta,
+    AccountDelta, AccountStorageDelta, AccountVaultDelta, CompactAccountDelta, FungibleAssetDelta,
+    NonFungibleAssetDelta, NonFungibleDeltaAction, NoncePolicy, StorageMapDelta,
+    StorageMapEntryChange,
 };
 
 mod seed;
-pub use seed::{get_account_seed, get_account_seed_single};
+pub use seed::{get_account_seed, get_account_seed_single, SeedGrinder};
 
 mod storage;
-pub use storage::{AccountStorage, AccountStorageHeader, StorageMap, StorageSlot, StorageSlotType};
+pub use storage::{
+    AccountStorage, AccountStorageHeader, LazyMapSlot, LazyStorageMap, MemoryStorageBackend,
+    SchemaSlotType, StorageArray, StorageBackend, StorageMap, StorageSchema, StorageSlot,
+    StorageSlotType,
+};
 
 mod header;
 pub use header::AccountHeader;
@@ -41,6 +46,11 @@ pub use header::AccountHeader;
 mod data;
 pub use data::AccountData;
 
+mod partial;
+pub use partial::PartialAccount;
+
+mod snapshot;
+
 // ACCOUNT
 // ================================================================================================
 
@@ -272,6 +282,29 @@ impl Account {
         Ok(())
     }
 
+    /// Reverts the changes of a previously applied delta using its inverse.
+    ///
+    /// The inverse must be obtained via [AccountDelta::invert()] from the state of this account
+    /// before the original delta was applied. Unlike [Self::apply_delta()], this method does not
+    /// require the nonce to increase, and sets it to the nonce of the inverse delta, if any.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Applying the vault sub-delta to the vault of this account fails.
+    /// - Applying the storage sub-delta to the storage of this account fails.
+    pub fn revert_delta(&mut self, inverse: &AccountDelta) -> Result<(), AccountError> {
+        self.vault
+            .apply_delta(inverse.vault())
+            .map_err(AccountError::AssetVaultUpdateError)?;
+        self.storage.apply_delta(inverse.storage())?;
+
+        if let Some(nonce) = inverse.nonce() {
+            self.nonce = nonce;
+        }
+
+        Ok(())
+    }
+
     /// Sets the nonce of this account to the specified nonce value.
     ///
     /// # Errors
@@ -291,6 +324,15 @@ impl Account {
         Ok(())
     }
 
+    /// Writes the uncommitted updates of the storage maps of this account which are held by
+    /// storage backends into their backends (see [AccountStorage::commit_lazy_maps()]).
+    ///
+    /// # Errors
+    /// Returns an error if any of the backends fails to write the updates.
+    pub fn commit_lazy_maps(&mut self) -> Result<(), AccountError> {
+        self.storage.commit_lazy_maps()
+    }
+
     // TEST HELPERS
     // --------------------------------------------------------------------------------------------
 
@@ -498,6 +540,57 @@ mod tests {
         assert_eq!(account, final_account);
     }
 
+    #[test]
+    fn account_delta_is_correctly_inverted() {
+        let init_nonce = Felt::new(1);
+        let (asset_0, asset_1) = build_assets();
+        let map_key = Digest::new([Felt::new(101), Felt::new(102), Felt::new(103), Felt::new(104)]);
+        let storage_map = StorageMap::with_entries([(
+            map_key,
+            [Felt::new(1_u64), Felt::new(2_u64), Felt::new(3_u64), Felt::new(4_u64)],
+        )])
+        .unwrap();
+
+        let initial_account = build_account(
+            vec![asset_0],
+            init_nonce,
+            vec![
+                StorageSlot::Value([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+                StorageSlot::Value(Word::default()),
+                StorageSlot::Map(storage_map),
+            ],
+        );
+
+        let new_map_key = [Felt::new(105), Felt::new(106), Felt::new(107), Felt::new(108)];
+        let updated_map = StorageMapDelta::from_iters(
+            [map_key.into()],
+            [(new_map_key, [Felt::new(9), Felt::new(10), Felt::new(11), Felt::new(12)])],
+        );
+        let storage_delta = AccountStorageDeltaBuilder::default()
+            .add_cleared_items([0])
+            .add_updated_values([(1, [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)])])
+            .add_updated_maps([(2, updated_map)])
+            .build()
+            .unwrap();
+        let account_delta =
+            build_account_delta(vec![asset_1], vec![asset_0], Felt::new(2), storage_delta);
+
+        let inverse = account_delta.invert(&initial_account).unwrap();
+        assert_eq!(inverse.nonce(), Some(init_nonce));
+        assert_eq!(inverse.vault(), &account_delta.vault().invert().unwrap());
+
+        let mut account = initial_account.clone();
+        account.apply_delta(&account_delta).unwrap();
+        assert_ne!(account, initial_account);
+
+        account.revert_delta(&inverse).unwrap();
+        assert_eq!(account, initial_account);
+
+        // inverting a delta against storage which does not contain the updated slots fails
+        let small_account = build_account(vec![asset_0], init_nonce, vec![]);
+        assert!(account_delta.invert(&small_account).is_err());
+    }
+
     #[test]
     #[should_panic]
     fn valid_account_delta_with_unchanged_nonce() {
@@ -0,0 +1,324 @@
+use alloc::{collections::BTreeMap, string::ToString, vec::Vec};
+
+use super::{
+    Account, AccountError, AccountHeader, AccountId, AccountStorageHeader, ByteReader, ByteWriter,
+    Deserializable, DeserializationError, Digest, Felt, Serializable, StorageSlotType, Word,
+};
+use crate::{
+    assets::{Asset, FungibleAsset},
+    crypto::merkle::SmtProof,
+};
+
+// PARTIAL ACCOUNT
+// ================================================================================================
+
+/// A pruned representation of an account which holds only the commitments to the account state
+/// together with selectively opened storage map entries and vault assets.
+///
+/// A partial account consists of:
+/// - The [AccountHeader] of the account, i.e., its ID, nonce and the commitments to its vault,
+///   storage and code.
+/// - The [AccountStorageHeader] of the account, which contains the values of all storage slots (for
+///   map slots, the roots of the maps) and is verified against the storage commitment.
+/// - Openings of storage map entries, each verified against the root of its map.
+/// - Openings of vault entries, each verified against the vault root.
+///
+/// This allows light clients to read and prove parts of an account's state without downloading
+/// the full vault and storage maps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartialAccount {
+    header: AccountHeader,
+    storage_header: AccountStorageHeader,
+    storage_map_openings: BTreeMap<u8, Vec<SmtProof>>,
+    vault_openings: Vec<SmtProof>,
+}
+
+impl PartialAccount {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [PartialAccount] without any openings.
+    ///
+    /// # Errors
+    /// Returns an error if the commitment to the storage header does not match the storage
+    /// commitment of the account header.
+    pub fn new(
+        header: AccountHeader,
+        storage_header: AccountStorageHeader,
+    ) -> Result<Self, AccountError> {
+        if storage_header.compute_commitment() != header.storage_commitment() {
+            return Err(AccountError::PartialAccountStorageHeaderMismatch);
+        }
+
+        Ok(Self {
+            header,
+            storage_header,
+            storage_map_openings: BTreeMap::new(),
+            vault_openings: Vec::new(),
+        })
+    }
+
+    /// Returns a [PartialAccount] built from the provided account, opening the specified storage
+    /// map entries and vault keys.
+    ///
+    /// # Errors
+    /// Returns an error if any of the specified storage slots is not a map.
+    pub fn from_account(
+        account: &Account,
+        map_keys: impl IntoIterator<Item = (u8, Word)>,
+        vault_keys: impl IntoIterator<Item = Word>,
+    ) -> Result<Self, AccountError> {
+        let mut partial = Self::new(account.into(), account.storage().get_header())?;
+
+        for (index, key) in map_keys {
+            partial.add_storage_map_opening(index, account.storage().open_map_item(index, key)?)?;
+        }
+
+        for key in vault_keys {
+            partial.add_vault_opening(account.vault().asset_tree().open(&Digest::from(key)))?;
+        }
+
+        Ok(partial)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of this account.
+    pub fn id(&self) -> AccountId {
+        self.header.id()
+    }
+
+    /// Returns the nonce of this account.
+    pub fn nonce(&self) -> Felt {
+        self.header.nonce()
+    }
+
+    /// Returns the hash of this account (see [Account::hash()]).
+    pub fn hash(&self) -> Digest {
+        self.header.hash()
+    }
+
+    /// Returns the header of this account.
+    pub fn header(&self) -> &AccountHeader {
+        &self.header
+    }
+
+    /// Returns the storage header of this account.
+    pub fn storage_header(&self) -> &AccountStorageHeader {
+        &self.storage_header
+    }
+
+    /// Returns the openings of storage map entries, grouped by the index of the map slot.
+    pub fn storage_map_openings(&self) -> &BTreeMap<u8, Vec<SmtProof>> {
+        &self.storage_map_openings
+    }
+
+    /// Returns the openings of vault entries.
+    pub fn vault_openings(&self) -> &[SmtProof] {
+        &self.vault_openings
+    }
+
+    /// Returns the value of the storage slot at the specified index.
+    ///
+    /// For map slots, the root of the map is returned.
+    ///
+    /// # Errors
+    /// Returns an error if the index is out of bounds.
+    pub fn get_item(&self, index: u8) -> Result<Word, AccountError> {
+        self.storage_header.slot(index as usize).map(|(_, value)| *value)
+    }
+
+    /// Returns the value stored under the provided key in the map located in storage at the
+    /// specified index, or `None` if the key has not been opened.
+    pub fn get_map_item(&self, index: u8, key: Word) -> Option<Word> {
+        let key = Digest::from(key);
+        self.storage_map_openings.get(&index)?.iter().find_map(|proof| proof.get(&key))
+    }
+
+    /// Returns the value stored in the vault under the provided vault key, or `None` if the key
+    /// has not been opened.
+    ///
+    /// The returned value is [crate::EMPTY_WORD] if the opening proves that the vault contains no
+    /// asset under the key.
+    pub fn get_vault_item(&self, vault_key: Word) -> Option<Word> {
+        let key = Digest::from(vault_key);
+        self.vault_openings.iter().find_map(|proof| proof.get(&key))
+    }
+
+    /// Returns the balance of the fungible asset issued by the specified faucet, or `None` if the
+    /// corresponding vault entry has not been opened.
+    ///
+    /// # Errors
+    /// Returns an error if the specified account is not a fungible faucet.
+    pub fn get_balance(&self, faucet_id: AccountId) -> Result<Option<u64>, AccountError> {
+        let vault_key = FungibleAsset::new(faucet_id, 0)
+            .map_err(|err| AccountError::PartialAccountInvalidOpening(err.to_string()))?
+            .vault_key();
+
+        Ok(self.get_vault_item(vault_key).map(|value| match Asset::try_from(value) {
+            Ok(Asset::Fungible(asset)) => asset.amount(),
+            _ => 0,
+        }))
+    }
+
+    // DATA MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Adds an opening of a storage map entry located at the specified storage slot.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The slot index is out of bounds or the slot is not a map.
+    /// - The proof does not open the root of the map.
+    pub fn add_storage_map_opening(
+        &mut self,
+        index: u8,
+        proof: SmtProof,
+    ) -> Result<(), AccountError> {
+        let (slot_type, root) = self.storage_header.slot(index as usize)?;
+        if *slot_type != StorageSlotType::Map {
+            return Err(AccountError::StorageSlotNotMap(index));
+        }
+
+        if proof.compute_root() != Digest::from(*root) {
+            return Err(AccountError::PartialAccountInvalidOpening(format!(
+                "opening does not match the root of the map in storage slot {index}"
+            )));
+        }
+
+        self.storage_map_openings.entry(index).or_default().push(proof);
+
+        Ok(())
+    }
+
+    /// Adds an opening of a vault entry.
+    ///
+    /// # Errors
+    /// Returns an error if the proof does not open the vault root.
+    pub fn add_vault_opening(&mut self, proof: SmtProof) -> Result<(), AccountError> {
+        if proof.compute_root() != self.header.vault_root() {
+            return Err(AccountError::PartialAccountInvalidOpening(
+                "opening does not match the vault root".to_string(),
+            ));
+        }
+
+        self.vault_openings.push(proof);
+
+        Ok(())
+    }
+}
+
+impl From<&Account> for PartialAccount {
+    fn from(account: &Account) -> Self {
+        Self::new(account.into(), account.storage().get_header())
+            .expect("storage header of an account must match its storage commitment")
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for PartialAccount {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.header.write_into(target);
+        self.storage_header.write_into(target);
+        target.write_u8(self.storage_map_openings.len() as u8);
+        target.write_many(self.storage_map_openings.iter());
+        self.vault_openings.write_into(target);
+    }
+}
+
+impl Deserializable for PartialAccount {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let header = AccountHeader::read_from(source)?;
+        let storage_header = AccountStorageHeader::read_from(source)?;
+        let num_maps = source.read_u8()? as usize;
+        let storage_map_openings = source.read_many::<(u8, Vec<SmtProof>)>(num_maps)?;
+        let vault_openings = Vec::<SmtProof>::read_from(source)?;
+
+        let mut partial = Self::new(header, storage_header)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+        for (index, proof) in storage_map_openings
+            .into_iter()
+            .flat_map(|(index, proofs)| proofs.into_iter().map(move |proof| (index, proof)))
+        {
+            partial
+                .add_storage_map_opening(index, proof)
+                .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+        }
+        for proof in vault_openings {
+            partial
+                .add_vault_opening(proof)
+                .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+        }
+
+        Ok(partial)
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        accounts::{StorageMap, StorageSlot},
+        testing::storage::{build_account, build_assets},
+        EMPTY_WORD,
+    };
+
+    #[test]
+    fn test_partial_account() {
+        let (asset_0, asset_1) = build_assets();
+        let key = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let value = [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)];
+        let missing_key = [Felt::new(9), Felt::new(10), Felt::new(11), Felt::new(12)];
+        let map = StorageMap::with_entries([(Digest::from(key), value)]).unwrap();
+
+        let account = build_account(
+            vec![asset_0],
+            Felt::new(1),
+            vec![StorageSlot::Value(value), StorageSlot::Map(map)],
+        );
+
+        let partial = PartialAccount::from_account(
+            &account,
+            [(1, key), (1, missing_key)],
+            [asset_0.vault_key(), asset_1.vault_key()],
+        )
+        .unwrap();
+
+        assert_eq!(partial.hash(), account.hash());
+        assert_eq!(partial.get_item(0).unwrap(), value);
+        assert_eq!(partial.get_map_item(1, key), Some(value));
+        assert_eq!(partial.get_map_item(1, missing_key), Some(EMPTY_WORD));
+        assert_eq!(partial.get_map_item(0, key), None);
+
+        assert_eq!(partial.get_vault_item(asset_0.vault_key()), Some(asset_0.into()));
+        assert_eq!(partial.get_vault_item(asset_1.vault_key()), Some(EMPTY_WORD));
+        assert_eq!(
+            partial.get_balance(asset_0.faucet_id()).unwrap(),
+            asset_0.as_fungible().map(FungibleAsset::amount)
+        );
+        assert_eq!(partial.get_balance(asset_1.faucet_id()).unwrap(), Some(0));
+
+        // openings of another account's state are rejected
+        let other_account = build_account(vec![asset_1], Felt::new(1), vec![]);
+        let mut partial = PartialAccount::from(&account);
+        let proof = other_account.vault().asset_tree().open(&Digest::from(asset_1.vault_key()));
+        assert!(matches!(
+            partial.add_vault_opening(proof),
+            Err(AccountError::PartialAccountInvalidOpening(_))
+        ));
+        assert!(matches!(
+            PartialAccount::from_account(&account, [(0, key)], []),
+            Err(AccountError::StorageSlotNotMap(0))
+        ));
+        assert!(matches!(
+            PartialAccount::new(account.clone().into(), other_account.storage().get_header()),
+            Err(AccountError::PartialAccountStorageHeaderMismatch)
+        ));
+    }
+}
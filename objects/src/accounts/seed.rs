@@ -1,4 +1,5 @@
 use alloc::vec::Vec;
+use core::task::Poll;
 #[cfg(feature = "concurrent")]
 use std::{
     sync::{
@@ -10,21 +11,42 @@ use std::{
 
 use super::{
     account_id::compute_digest, AccountError, AccountId, AccountStorageMode, AccountType, Digest,
-    Felt, Word,
+    Felt, SeedGrindingParams, Word,
 };
 
 // SEED GENERATORS
 // --------------------------------------------------------------------------------------------
 
 /// Finds and returns a seed suitable for creating an account ID for the specified account type
-/// using the provided initial seed as a starting point. Using multi-threading.
-#[cfg(feature = "concurrent")]
+/// using the provided initial seed as a starting point.
 pub fn get_account_seed(
     init_seed: [u8; 32],
     account_type: AccountType,
     storage_mode: AccountStorageMode,
     code_commitment: Digest,
     storage_commitment: Digest,
+) -> Result<Word, AccountError> {
+    get_account_seed_with_params(
+        init_seed,
+        account_type,
+        storage_mode,
+        code_commitment,
+        storage_commitment,
+        SeedGrindingParams::default(),
+    )
+}
+
+/// Finds and returns a seed suitable for creating an account ID for the specified account type
+/// using the provided initial seed as a starting point and the provided proof-of-work parameters.
+/// Using multi-threading.
+#[cfg(feature = "concurrent")]
+pub(super) fn get_account_seed_with_params(
+    init_seed: [u8; 32],
+    account_type: AccountType,
+    storage_mode: AccountStorageMode,
+    code_commitment: Digest,
+    storage_commitment: Digest,
+    params: SeedGrindingParams,
 ) -> Result<Word, AccountError> {
     let thread_count = thread::available_parallelism().map_or(1, |v| v.get());
 
@@ -45,6 +67,7 @@ pub fn get_account_seed(
                 storage_mode,
                 code_commitment,
                 storage_commitment,
+                params,
             )
         });
     }
@@ -67,6 +90,7 @@ pub fn get_account_seed(
 }
 
 #[cfg(feature = "concurrent")]
+#[allow(clippy::too_many_arguments)]
 pub fn get_account_seed_inner(
     send: Sender<(Digest, Word)>,
     stop: Arc<RwLock<bool>>,
@@ -75,6 +99,7 @@ pub fn get_account_seed_inner(
     storage_mode: AccountStorageMode,
     code_commitment: Digest,
     storage_commitment: Digest,
+    params: SeedGrindingParams,
 ) {
     let init_seed: Vec<[u8; 8]> =
         init_seed.chunks(8).map(|chunk| chunk.try_into().unwrap()).collect();
@@ -102,7 +127,7 @@ pub fn get_account_seed_inner(
         }
 
         // check if the seed satisfies the specified account type
-        if AccountId::validate_seed_digest(&current_digest).is_ok() {
+        if AccountId::validate_seed_digest(&current_digest, params).is_ok() {
             if let Ok(account_id) = AccountId::try_from(current_digest[0]) {
                 if account_id.account_type() == account_type
                     && account_id.storage_mode() == storage_mode
@@ -121,20 +146,28 @@ pub fn get_account_seed_inner(
 }
 
 #[cfg(not(feature = "concurrent"))]
-pub fn get_account_seed(
+pub(super) fn get_account_seed_with_params(
     init_seed: [u8; 32],
     account_type: AccountType,
     storage_mode: AccountStorageMode,
     code_commitment: Digest,
     storage_commitment: Digest,
+    params: SeedGrindingParams,
 ) -> Result<Word, AccountError> {
-    get_account_seed_single(
+    let mut grinder = SeedGrinder::new(
         init_seed,
         account_type,
         storage_mode,
         code_commitment,
         storage_commitment,
     )
+    .with_params(params);
+
+    loop {
+        if let Poll::Ready(seed) = grinder.poll(u64::MAX) {
+            return Ok(seed);
+        }
+    }
 }
 
 /// Finds and returns a seed suitable for creating an account ID for the specified account type
@@ -146,39 +179,129 @@ pub fn get_account_seed_single(
     code_commitment: Digest,
     storage_commitment: Digest,
 ) -> Result<Word, AccountError> {
-    let init_seed: Vec<[u8; 8]> =
-        init_seed.chunks(8).map(|chunk| chunk.try_into().unwrap()).collect();
-    let mut current_seed: Word = [
-        Felt::new(u64::from_le_bytes(init_seed[0])),
-        Felt::new(u64::from_le_bytes(init_seed[1])),
-        Felt::new(u64::from_le_bytes(init_seed[2])),
-        Felt::new(u64::from_le_bytes(init_seed[3])),
-    ];
-    let mut current_digest = compute_digest(current_seed, code_commitment, storage_commitment);
+    let mut grinder = SeedGrinder::new(
+        init_seed,
+        account_type,
+        storage_mode,
+        code_commitment,
+        storage_commitment,
+    );
+
+    loop {
+        if let Poll::Ready(seed) = grinder.poll(u64::MAX) {
+            return Ok(seed);
+        }
+    }
+}
+
+// SEED GRINDER
+// --------------------------------------------------------------------------------------------
 
+/// An incremental account seed grinder.
+///
+/// Unlike [get_account_seed()], which blocks until a suitable seed is found, the grinder performs
+/// the search in bounded chunks via [SeedGrinder::poll()]. This allows callers running in
+/// single-threaded environments (e.g., in the browser) to yield control between chunks and to
+/// report progress using [SeedGrinder::iterations()].
+///
+/// For the same inputs, the grinder finds the same seed as [get_account_seed_single()].
+pub struct SeedGrinder {
+    account_type: AccountType,
+    storage_mode: AccountStorageMode,
+    code_commitment: Digest,
+    storage_commitment: Digest,
+    params: SeedGrindingParams,
+    current_seed: Word,
+    current_digest: Digest,
+    iterations: u64,
+    result: Option<Word>,
     #[cfg(feature = "log")]
-    let mut log = log::Log::start(current_digest, current_seed, account_type, storage_mode);
+    log: log::Log,
+}
 
-    // loop until we have a seed that satisfies the specified account type.
-    loop {
-        #[cfg(feature = "log")]
-        log.iteration(current_digest, current_seed);
+impl SeedGrinder {
+    /// Returns a new [SeedGrinder] which searches for a seed suitable for creating an account ID
+    /// for the specified account type using the provided initial seed as a starting point.
+    pub fn new(
+        init_seed: [u8; 32],
+        account_type: AccountType,
+        storage_mode: AccountStorageMode,
+        code_commitment: Digest,
+        storage_commitment: Digest,
+    ) -> Self {
+        let init_seed: Vec<[u8; 8]> =
+            init_seed.chunks(8).map(|chunk| chunk.try_into().unwrap()).collect();
+        let current_seed: Word = [
+            Felt::new(u64::from_le_bytes(init_seed[0])),
+            Felt::new(u64::from_le_bytes(init_seed[1])),
+            Felt::new(u64::from_le_bytes(init_seed[2])),
+            Felt::new(u64::from_le_bytes(init_seed[3])),
+        ];
+        let current_digest = compute_digest(current_seed, code_commitment, storage_commitment);
+
+        Self {
+            account_type,
+            storage_mode,
+            code_commitment,
+            storage_commitment,
+            params: SeedGrindingParams::default(),
+            current_seed,
+            current_digest,
+            iterations: 0,
+            result: None,
+            #[cfg(feature = "log")]
+            log: log::Log::start(current_digest, current_seed, account_type, storage_mode),
+        }
+    }
 
-        // check if the seed satisfies the specified account type
-        if AccountId::validate_seed_digest(&current_digest).is_ok() {
-            if let Ok(account_id) = AccountId::try_from(current_digest[0]) {
-                if account_id.account_type() == account_type
-                    && account_id.storage_mode() == storage_mode
-                {
-                    #[cfg(feature = "log")]
-                    log.done(current_digest, current_seed, account_id);
+    /// Sets the proof-of-work parameters the seed digest must satisfy.
+    ///
+    /// By default, [SeedGrindingParams::default()] is used.
+    pub fn with_params(mut self, params: SeedGrindingParams) -> Self {
+        self.params = params;
+        self
+    }
 
-                    return Ok(current_seed);
-                };
+    /// Returns the number of seeds which have been checked so far.
+    pub fn iterations(&self) -> u64 {
+        self.iterations
+    }
+
+    /// Checks at most `max_iterations` seeds and returns [Poll::Ready] with the seed if a suitable
+    /// one was found, or [Poll::Pending] if the search needs to be continued.
+    ///
+    /// Once a seed was found, subsequent calls return the same seed without doing any work.
+    pub fn poll(&mut self, max_iterations: u64) -> Poll<Word> {
+        if let Some(seed) = self.result {
+            return Poll::Ready(seed);
+        }
+
+        for _ in 0..max_iterations {
+            #[cfg(feature = "log")]
+            self.log.iteration(self.current_digest, self.current_seed);
+
+            self.iterations += 1;
+
+            // check if the seed satisfies the specified account type
+            if AccountId::validate_seed_digest(&self.current_digest, self.params).is_ok() {
+                if let Ok(account_id) = AccountId::try_from(self.current_digest[0]) {
+                    if account_id.account_type() == self.account_type
+                        && account_id.storage_mode() == self.storage_mode
+                    {
+                        #[cfg(feature = "log")]
+                        self.log.done(self.current_digest, self.current_seed, account_id);
+
+                        self.result = Some(self.current_seed);
+                        return Poll::Ready(self.current_seed);
+                    };
+                }
             }
+            self.current_seed = self.current_digest.into();
+            self.current_digest =
+                compute_digest(self.current_seed, self.code_commitment, self.storage_commitment);
         }
-        current_seed = current_digest.into();
-        current_digest = compute_digest(current_seed, code_commitment, storage_commitment);
+
+        Poll::Pending
     }
 }
 
@@ -253,7 +376,7 @@ mod log {
             }
         }
 
-        pub fn done(self, digest: Digest, seed: Word, account_id: AccountId) {
+        pub fn done(&self, digest: Digest, seed: Word, account_id: AccountId) {
             log::info!(
                 "Found account seed [pow={}, current_digest={}, current_seed={} type={:?} onchain={}]]",
                 digest_pow(digest),
@@ -265,3 +388,89 @@ mod log {
         }
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seed_grinder() {
+        let init_seed = [7; 32];
+        let account_type = AccountType::RegularAccountImmutableCode;
+        let storage_mode = AccountStorageMode::Public;
+
+        let expected = get_account_seed_single(
+            init_seed,
+            account_type,
+            storage_mode,
+            Digest::default(),
+            Digest::default(),
+        )
+        .unwrap();
+
+        let mut grinder = SeedGrinder::new(
+            init_seed,
+            account_type,
+            storage_mode,
+            Digest::default(),
+            Digest::default(),
+        );
+
+        let mut polls = 0;
+        let seed = loop {
+            polls += 1;
+            if let Poll::Ready(seed) = grinder.poll(1) {
+                break seed;
+            }
+        };
+
+        assert_eq!(seed, expected);
+        assert_eq!(grinder.iterations(), polls);
+        assert_eq!(grinder.poll(1), Poll::Ready(seed));
+        assert_eq!(grinder.iterations(), polls);
+
+        let account_id = AccountId::new(seed, Digest::default(), Digest::default()).unwrap();
+        assert_eq!(account_id.account_type(), account_type);
+        assert_eq!(account_id.storage_mode(), storage_mode);
+    }
+
+    #[test]
+    fn test_seed_grinding_params() {
+        let init_seed = [3; 32];
+        let account_type = AccountType::FungibleFaucet;
+        let storage_mode = AccountStorageMode::Private;
+        let params = SeedGrindingParams::new(2, 3).unwrap();
+
+        let seed = AccountId::get_account_seed_with_params(
+            init_seed,
+            account_type,
+            storage_mode,
+            Digest::default(),
+            Digest::default(),
+            params,
+        )
+        .unwrap();
+
+        let account_id =
+            AccountId::new_with_params(seed, Digest::default(), Digest::default(), params).unwrap();
+        assert_eq!(account_id.account_type(), account_type);
+
+        // a stricter difficulty must be satisfied by the seed as well
+        let strict = SeedGrindingParams::new(2, SeedGrindingParams::MAX_TRAILING_ZEROS).unwrap();
+        let digest = compute_digest(seed, Digest::default(), Digest::default());
+        if digest[3].as_int().trailing_zeros() < SeedGrindingParams::MAX_TRAILING_ZEROS {
+            assert!(matches!(
+                AccountId::new_with_params(seed, Digest::default(), Digest::default(), strict),
+                Err(AccountError::SeedDigestTooFewTrailingZeros { .. })
+            ));
+        }
+
+        assert!(matches!(
+            SeedGrindingParams::new(SeedGrindingParams::MAX_TRAILING_ZEROS + 1, 0),
+            Err(AccountError::SeedGrindingDifficultyTooHigh { .. })
+        ));
+    }
+}
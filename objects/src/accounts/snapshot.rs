@@ -0,0 +1,235 @@
+use alloc::vec::Vec;
+
+use miden_crypto::utils::SliceReader;
+
+use super::{
+    Account, AccountCode, AccountError, AccountId, AccountStorage, AssetVault, ByteReader,
+    ByteWriter, Deserializable, Digest, Felt, Serializable,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Magic bytes identifying an account snapshot.
+const SNAPSHOT_MAGIC: [u8; 4] = *b"MASN";
+
+/// Tags of the sections of an account snapshot.
+const SECTION_ID: u8 = 0;
+const SECTION_CODE: u8 = 1;
+const SECTION_STORAGE: u8 = 2;
+const SECTION_VAULT: u8 = 3;
+const SECTION_NONCE: u8 = 4;
+
+/// Number of sections required to restore an account.
+const NUM_REQUIRED_SECTIONS: usize = 5;
+
+// ACCOUNT SNAPSHOT
+// ================================================================================================
+
+impl Account {
+    /// The current version of the account snapshot format.
+    pub const SNAPSHOT_VERSION: u8 = 1;
+
+    /// Returns a versioned binary snapshot of this account.
+    ///
+    /// The snapshot is laid out as follows:
+    /// - 4 magic bytes identifying the blob as an account snapshot.
+    /// - The format version (see [Self::SNAPSHOT_VERSION]).
+    /// - The commitment to the account state (see [Self::hash()]).
+    /// - The number of sections, followed by the sections themselves. Each section consists of a
+    ///   tag, the length of its payload and the payload. The sections contain the account ID, code,
+    ///   storage, vault and nonce.
+    ///
+    /// Since every section is tagged and length-prefixed, newer versions of the format may append
+    /// sections which older readers skip, while changes which older readers cannot safely ignore
+    /// increment the format version.
+    pub fn to_snapshot(&self) -> Vec<u8> {
+        let mut target = Vec::new();
+        target.write_bytes(&SNAPSHOT_MAGIC);
+        target.write_u8(Self::SNAPSHOT_VERSION);
+        self.hash().write_into(&mut target);
+
+        target.write_usize(NUM_REQUIRED_SECTIONS);
+        write_section(&mut target, SECTION_ID, &self.id);
+        write_section(&mut target, SECTION_CODE, &self.code);
+        write_section(&mut target, SECTION_STORAGE, &self.storage);
+        write_section(&mut target, SECTION_VAULT, &self.vault);
+        write_section(&mut target, SECTION_NONCE, &self.nonce);
+
+        target
+    }
+
+    /// Restores an account from a snapshot produced by [Self::to_snapshot()].
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The snapshot does not start with the expected magic bytes.
+    /// - The snapshot was produced by a newer, unsupported version of the format.
+    /// - A required section is missing, duplicated or cannot be deserialized.
+    /// - The commitment to the restored account does not match the one stored in the snapshot.
+    pub fn from_snapshot(bytes: &[u8]) -> Result<Self, AccountError> {
+        let mut source = SliceReader::new(bytes);
+
+        let magic: [u8; 4] = source.read_array().map_err(AccountError::SnapshotParseError)?;
+        if magic != SNAPSHOT_MAGIC {
+            return Err(AccountError::SnapshotInvalidMagic);
+        }
+
+        let version = source.read_u8().map_err(AccountError::SnapshotParseError)?;
+        if version == 0 || version > Self::SNAPSHOT_VERSION {
+            return Err(AccountError::SnapshotUnsupportedVersion {
+                max: Self::SNAPSHOT_VERSION,
+                actual: version,
+            });
+        }
+
+        let commitment =
+            Digest::read_from(&mut source).map_err(AccountError::SnapshotParseError)?;
+
+        let mut id = None;
+        let mut code = None;
+        let mut storage = None;
+        let mut vault = None;
+        let mut nonce = None;
+
+        let num_sections = source.read_usize().map_err(AccountError::SnapshotParseError)?;
+        for _ in 0..num_sections {
+            let tag = source.read_u8().map_err(AccountError::SnapshotParseError)?;
+            let len = source.read_usize().map_err(AccountError::SnapshotParseError)?;
+            let payload = source.read_slice(len).map_err(AccountError::SnapshotParseError)?;
+
+            match tag {
+                SECTION_ID => read_section(&mut id, tag, payload)?,
+                SECTION_CODE => read_section(&mut code, tag, payload)?,
+                SECTION_STORAGE => read_section(&mut storage, tag, payload)?,
+                SECTION_VAULT => read_section(&mut vault, tag, payload)?,
+                SECTION_NONCE => read_section(&mut nonce, tag, payload)?,
+                // sections unknown to this version are skipped
+                _ => (),
+            }
+        }
+
+        let account = Self::from_parts(
+            require_section::<AccountId>(id, SECTION_ID)?,
+            require_section::<AssetVault>(vault, SECTION_VAULT)?,
+            require_section::<AccountStorage>(storage, SECTION_STORAGE)?,
+            require_section::<AccountCode>(code, SECTION_CODE)?,
+            require_section::<Felt>(nonce, SECTION_NONCE)?,
+        );
+
+        if account.hash() != commitment {
+            return Err(AccountError::SnapshotCommitmentMismatch {
+                expected: commitment,
+                actual: account.hash(),
+            });
+        }
+
+        Ok(account)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Writes the provided value into the target as a tagged, length-prefixed section.
+fn write_section<T: Serializable>(target: &mut Vec<u8>, tag: u8, value: &T) {
+    let payload = value.to_bytes();
+    target.write_u8(tag);
+    target.write_usize(payload.len());
+    target.write_bytes(&payload);
+}
+
+/// Deserializes the payload of the section with the specified tag into `slot`.
+fn read_section<T: Deserializable>(
+    slot: &mut Option<T>,
+    tag: u8,
+    payload: &[u8],
+) -> Result<(), AccountError> {
+    if slot.is_some() {
+        return Err(AccountError::SnapshotDuplicateSection(tag));
+    }
+    *slot = Some(T::read_from_bytes(payload).map_err(AccountError::SnapshotParseError)?);
+
+    Ok(())
+}
+
+/// Returns the value of a required section, or an error if the section was missing.
+fn require_section<T>(value: Option<T>, tag: u8) -> Result<T, AccountError> {
+    value.ok_or(AccountError::SnapshotMissingSection(tag))
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{accounts::StorageSlot, testing::storage::build_account};
+
+    fn account() -> Account {
+        let word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        build_account(vec![], Felt::new(3), vec![StorageSlot::Value(word)])
+    }
+
+    #[test]
+    fn test_account_snapshot_roundtrip() {
+        let account = account();
+        let snapshot = account.to_snapshot();
+
+        assert_eq!(&snapshot[..4], b"MASN");
+        assert_eq!(snapshot[4], Account::SNAPSHOT_VERSION);
+        assert_eq!(Account::from_snapshot(&snapshot).unwrap(), account);
+    }
+
+    #[test]
+    fn test_account_snapshot_validation() {
+        let snapshot = account().to_snapshot();
+
+        let mut invalid_magic = snapshot.clone();
+        invalid_magic[0] = b'X';
+        assert!(matches!(
+            Account::from_snapshot(&invalid_magic),
+            Err(AccountError::SnapshotInvalidMagic)
+        ));
+
+        let mut newer_version = snapshot.clone();
+        newer_version[4] = Account::SNAPSHOT_VERSION + 1;
+        assert!(matches!(
+            Account::from_snapshot(&newer_version),
+            Err(AccountError::SnapshotUnsupportedVersion { .. })
+        ));
+
+        let mut invalid_commitment = snapshot.clone();
+        invalid_commitment[5] ^= 1;
+        assert!(Account::from_snapshot(&invalid_commitment).is_err());
+
+        assert!(matches!(
+            Account::from_snapshot(&snapshot[..snapshot.len() - 1]),
+            Err(AccountError::SnapshotParseError(_))
+        ));
+    }
+
+    #[test]
+    fn test_account_snapshot_skips_unknown_sections() {
+        let account = account();
+        let header_len = 4 + 1 + Digest::SERIALIZED_SIZE;
+        let snapshot = account.to_snapshot();
+
+        // rebuild the snapshot with an additional section appended
+        let mut extended = snapshot[..header_len].to_vec();
+        extended.write_usize(NUM_REQUIRED_SECTIONS + 1);
+        extended.extend_from_slice(&snapshot[header_len + 1..]);
+        write_section(&mut extended, 42, &Felt::new(7));
+
+        assert_eq!(Account::from_snapshot(&extended).unwrap(), account);
+
+        // a snapshot missing a required section is rejected
+        let mut truncated = snapshot[..header_len].to_vec();
+        truncated.write_usize(1);
+        write_section(&mut truncated, SECTION_ID, &account.id());
+        assert!(matches!(
+            Account::from_snapshot(&truncated),
+            Err(AccountError::SnapshotMissingSection(_))
+        ));
+    }
+}
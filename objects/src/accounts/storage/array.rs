@@ -0,0 +1,218 @@
+use alloc::vec::Vec;
+
+use vm_core::{Felt, ONE, ZERO};
+
+use super::{AccountError, Digest, StorageMap, StorageSlot, Word};
+
+// STORAGE ARRAY
+// ================================================================================================
+
+/// A fixed-size array of [Word]s stored in a single [StorageSlot::Map] slot.
+///
+/// Arrays are emulated on top of storage maps as follows:
+/// - The length of the array is stored as `[len, 1, 0, 0]` under [StorageArray::LENGTH_KEY]. The
+///   second element marks the map as an array, such that empty arrays are distinguishable from
+///   maps which do not hold an array.
+/// - The element at position `i` is stored under the key `[i, 0, 0, 0]` (see
+///   [StorageArray::element_key()]).
+///
+/// Since the length is an entry of the map, the root of the map commits both to the length and to
+/// the elements of the array. The transaction kernel treats the slot as a regular map. Account
+/// code accesses the elements of an array with bounds checks via the `get_item` and `set_item`
+/// procedures of the `miden::array` module, and Rust code via
+/// [AccountStorage::get_array_item()](super::AccountStorage::get_array_item) and
+/// [AccountStorage::set_array_item()](super::AccountStorage::set_array_item).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StorageArray {
+    len: u32,
+    map: StorageMap,
+}
+
+impl StorageArray {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The key under which the length of the array is stored.
+    pub const LENGTH_KEY: Word = [ZERO, ZERO, ZERO, ONE];
+
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [StorageArray] of the specified length with all elements set to
+    /// [StorageMap::EMPTY_VALUE].
+    pub fn new(len: u32) -> Self {
+        let mut map = StorageMap::new();
+        map.insert(Self::LENGTH_KEY.into(), [Felt::from(len), ONE, ZERO, ZERO]);
+
+        Self { len, map }
+    }
+
+    /// Returns a new [StorageArray] containing the provided elements.
+    ///
+    /// # Errors
+    /// Returns an error if more than [u32::MAX] elements are provided.
+    pub fn with_items(items: impl IntoIterator<Item = Word>) -> Result<Self, AccountError> {
+        let items: Vec<Word> = items.into_iter().collect();
+        let len = u32::try_from(items.len())
+            .map_err(|_| AccountError::StorageArrayTooLong(items.len()))?;
+
+        let mut array = Self::new(len);
+        for (position, item) in items.into_iter().enumerate() {
+            array.map.insert(Self::element_key(position as u32).into(), item);
+        }
+
+        Ok(array)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the key under which the element at the specified position is stored.
+    pub fn element_key(position: u32) -> Word {
+        [Felt::from(position), ZERO, ZERO, ZERO]
+    }
+
+    /// Returns the number of elements of this array.
+    pub fn len(&self) -> u32 {
+        self.len
+    }
+
+    /// Returns true if this array has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the element at the specified position.
+    ///
+    /// # Errors
+    /// Returns an error if the position is out of bounds.
+    pub fn get(&self, position: u32) -> Result<Word, AccountError> {
+        check_bounds(self.len, position)?;
+        Ok(self.map.get_value(&Self::element_key(position).into()))
+    }
+
+    /// Returns the commitment to this array, i.e., the root of the underlying map.
+    pub fn commitment(&self) -> Digest {
+        self.map.root()
+    }
+
+    /// Returns the map in which this array is stored.
+    pub fn into_map(self) -> StorageMap {
+        self.map
+    }
+
+    // DATA MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Updates the element at the specified position and returns the old element.
+    ///
+    /// # Errors
+    /// Returns an error if the position is out of bounds.
+    pub fn set(&mut self, position: u32, value: Word) -> Result<Word, AccountError> {
+        check_bounds(self.len, position)?;
+        Ok(self.map.insert(Self::element_key(position).into(), value))
+    }
+}
+
+impl From<StorageArray> for StorageSlot {
+    fn from(array: StorageArray) -> Self {
+        StorageSlot::Map(array.into_map())
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the array length encoded by the provided value of the length entry of a map, or `None`
+/// if the value is not a valid array length entry.
+pub(super) fn array_len(length_entry: Word) -> Option<u32> {
+    let [len, flag, rest @ ..] = length_entry;
+    if flag != ONE || rest.iter().any(|element| *element != ZERO) {
+        return None;
+    }
+
+    u32::try_from(len.as_int()).ok()
+}
+
+/// Returns an error if the position is out of bounds for an array of the specified length.
+pub(super) fn check_bounds(len: u32, position: u32) -> Result<(), AccountError> {
+    if position >= len {
+        return Err(AccountError::StorageArrayIndexOutOfBounds { len, actual: position });
+    }
+
+    Ok(())
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use vm_core::{Felt, ONE};
+
+    use super::{array_len, StorageArray};
+    use crate::accounts::{AccountError, AccountStorage, StorageMap, StorageSlot};
+
+    #[test]
+    fn storage_array_is_bounds_checked() {
+        let items = [[ONE; 4], [Felt::new(2); 4], [Felt::new(3); 4]];
+        let array = StorageArray::with_items(items).unwrap();
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.get(1).unwrap(), items[1]);
+        assert!(matches!(
+            array.get(3),
+            Err(AccountError::StorageArrayIndexOutOfBounds { len: 3, actual: 3 })
+        ));
+
+        // the commitment of the array is the value of its storage slot
+        let commitment = array.commitment();
+        let mut storage = AccountStorage::new(vec![
+            StorageSlot::empty_value(),
+            array.into(),
+            StorageSlot::Map(StorageMap::new()),
+        ])
+        .unwrap();
+        assert_eq!(storage.get_item(1).unwrap(), commitment);
+
+        assert_eq!(storage.get_array_len(1).unwrap(), 3);
+        assert_eq!(storage.get_array_item(1, 2).unwrap(), items[2]);
+        assert_eq!(storage.set_array_item(1, 0, items[2]).unwrap(), (commitment.into(), items[0]));
+        assert_eq!(storage.get_array_item(1, 0).unwrap(), items[2]);
+        assert!(matches!(
+            storage.set_array_item(1, 3, items[0]),
+            Err(AccountError::StorageArrayIndexOutOfBounds { len: 3, actual: 3 })
+        ));
+
+        // updating an element changes the commitment in the same way as updating the array
+        let mut expected = StorageArray::with_items(items).unwrap();
+        expected.set(0, items[2]).unwrap();
+        assert_eq!(storage.get_item(1).unwrap(), expected.commitment());
+
+        // maps without a length entry or with an invalid length entry are not arrays
+        assert!(array_len(storage.get_map_item(2, StorageArray::LENGTH_KEY).unwrap()).is_none());
+        assert!(matches!(
+            storage.get_array_item(2, 0),
+            Err(AccountError::StorageSlotNotArray(2))
+        ));
+        storage.set_map_item(2, StorageArray::LENGTH_KEY, [ONE; 4]).unwrap();
+        assert!(array_len(storage.get_map_item(2, StorageArray::LENGTH_KEY).unwrap()).is_none());
+        assert!(matches!(
+            storage.get_array_item(2, 0),
+            Err(AccountError::StorageSlotNotArray(2))
+        ));
+        assert!(matches!(storage.get_array_item(0, 0), Err(AccountError::StorageSlotNotMap(0))));
+
+        // empty arrays are distinguishable from maps which do not hold an array
+        let mut storage = AccountStorage::new(vec![
+            StorageArray::new(0).into(),
+            StorageSlot::Map(StorageMap::new()),
+        ])
+        .unwrap();
+        assert_eq!(storage.get_array_len(0).unwrap(), 0);
+        assert_ne!(storage.get_item(0).unwrap(), storage.get_item(1).unwrap());
+        assert!(matches!(
+            storage.set_array_item(0, 0, items[0]),
+            Err(AccountError::StorageArrayIndexOutOfBounds { len: 0, actual: 0 })
+        ));
+    }
+}
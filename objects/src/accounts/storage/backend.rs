@@ -0,0 +1,706 @@
+use alloc::{boxed::Box, collections::BTreeMap, string::ToString, vec::Vec};
+use core::{convert::Infallible, fmt};
+
+use miden_crypto::merkle::EmptySubtreeRoots;
+
+use super::{AccountError, Digest, StorageMap, Word};
+use crate::{
+    accounts::{
+        delta::{hash_leaf, sort_leaf_entries},
+        StorageMapDelta,
+    },
+    crypto::merkle::{LeafIndex, MerklePath, NodeIndex, SmtLeaf, SmtProof, SMT_DEPTH},
+    Hasher,
+};
+
+// STORAGE BACKEND
+// ================================================================================================
+
+/// A key-value store holding the nodes of a [LazyStorageMap].
+///
+/// The store holds two kinds of entries:
+/// - Leaves, which are stored under their index and consist of the key-value pairs of the map which
+///   are mapped to the leaf.
+/// - Nodes, which are stored under their [NodeIndex] and consist of the hash of the node. This
+///   includes the hashes of the leaves (at depth 64) and the root of the tree (at depth 0).
+///
+/// Leaves and nodes which are not stored are assumed to be empty, i.e., the store only needs to
+/// hold the non-empty part of the tree. This makes it possible to keep large storage maps in a
+/// persistent database (e.g., RocksDB or sled) instead of in memory.
+pub trait StorageBackend {
+    /// The error returned when the store fails to read or write entries.
+    type Error;
+
+    /// Returns the entries of the leaf at the specified index, or None if the leaf is empty.
+    ///
+    /// # Errors
+    /// Returns an error if the store fails to read the leaf.
+    fn get_leaf(&self, index: u64) -> Result<Option<Vec<(Digest, Word)>>, Self::Error>;
+
+    /// Returns the hash of the node at the specified index, or None if the node is the root of an
+    /// empty subtree.
+    ///
+    /// # Errors
+    /// Returns an error if the store fails to read the node.
+    fn get_node(&self, index: NodeIndex) -> Result<Option<Digest>, Self::Error>;
+
+    /// Writes the provided leaves and nodes into the store, replacing the existing ones.
+    ///
+    /// Empty leaves and roots of empty subtrees are included as well, and can be removed from the
+    /// store. The write should be atomic, i.e., the store should not be left with only some of the
+    /// provided entries written.
+    ///
+    /// # Errors
+    /// Returns an error if the store fails to write the entries.
+    fn write_batch(
+        &mut self,
+        leaves: BTreeMap<u64, Vec<(Digest, Word)>>,
+        nodes: BTreeMap<NodeIndex, Digest>,
+    ) -> Result<(), Self::Error>;
+}
+
+// MEMORY STORAGE BACKEND
+// ================================================================================================
+
+/// A [StorageBackend] which keeps all leaves and nodes in memory.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MemoryStorageBackend {
+    leaves: BTreeMap<u64, Vec<(Digest, Word)>>,
+    nodes: BTreeMap<NodeIndex, Digest>,
+}
+
+impl MemoryStorageBackend {
+    /// Returns a new, empty [MemoryStorageBackend].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of non-empty leaves in this store.
+    pub fn num_leaves(&self) -> usize {
+        self.leaves.len()
+    }
+}
+
+impl StorageBackend for MemoryStorageBackend {
+    type Error = Infallible;
+
+    fn get_leaf(&self, index: u64) -> Result<Option<Vec<(Digest, Word)>>, Self::Error> {
+        Ok(self.leaves.get(&index).cloned())
+    }
+
+    fn get_node(&self, index: NodeIndex) -> Result<Option<Digest>, Self::Error> {
+        Ok(self.nodes.get(&index).copied())
+    }
+
+    fn write_batch(
+        &mut self,
+        leaves: BTreeMap<u64, Vec<(Digest, Word)>>,
+        nodes: BTreeMap<NodeIndex, Digest>,
+    ) -> Result<(), Self::Error> {
+        for (index, entries) in leaves {
+            if entries.is_empty() {
+                self.leaves.remove(&index);
+            } else {
+                self.leaves.insert(index, entries);
+            }
+        }
+
+        for (index, node) in nodes {
+            if node == empty_subtree_root(index) {
+                self.nodes.remove(&index);
+            } else {
+                self.nodes.insert(index, node);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// LAZY STORAGE MAP
+// ================================================================================================
+
+/// A storage map whose Sparse Merkle tree is held by a [StorageBackend] and loaded on demand.
+///
+/// The map computes the same root as a [StorageMap] with the same entries, and thus can be used
+/// in place of a [StorageMap] for maintaining the commitment of a large map of an account, without
+/// holding the entire map in memory: reading an entry loads a single leaf, and updating an entry
+/// loads the siblings of the nodes on the path from its leaf to the root.
+///
+/// Updated leaves and nodes are tracked in memory until they are written into the backend via
+/// [LazyStorageMap::commit()], or dropped via [LazyStorageMap::discard()].
+///
+/// A lazy map can be held by an [AccountStorage](super::AccountStorage) as a
+/// [StorageSlot::LazyMap](super::StorageSlot::LazyMap) slot (see [LazyMapSlot]).
+#[derive(Debug, Clone)]
+pub struct LazyStorageMap<B: StorageBackend = MemoryStorageBackend> {
+    backend: B,
+    root: Digest,
+    dirty_leaves: BTreeMap<u64, Vec<(Digest, Word)>>,
+    dirty_nodes: BTreeMap<NodeIndex, Digest>,
+}
+
+impl<B: StorageBackend> LazyStorageMap<B> {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [LazyStorageMap] holding the map stored in the provided backend.
+    ///
+    /// # Errors
+    /// Returns an error if the root of the map cannot be read from the backend.
+    pub fn new(backend: B) -> Result<Self, B::Error> {
+        let root = backend
+            .get_node(NodeIndex::root())?
+            .unwrap_or_else(|| empty_subtree_root(NodeIndex::root()));
+
+        Ok(Self {
+            backend,
+            root,
+            dirty_leaves: BTreeMap::new(),
+            dirty_nodes: BTreeMap::new(),
+        })
+    }
+
+    /// Returns a new [LazyStorageMap] holding the entries of the provided [StorageMap], which are
+    /// written into the provided (empty) backend.
+    ///
+    /// # Errors
+    /// Returns an error if the backend fails to read or write the nodes of the map.
+    pub fn from_storage_map(map: &StorageMap, backend: B) -> Result<Self, B::Error> {
+        let mut lazy_map = Self::new(backend)?;
+        for &(key, value) in map.entries() {
+            lazy_map.insert(key, value)?;
+        }
+        lazy_map.commit()?;
+
+        Ok(lazy_map)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the root of this map, including any uncommitted updates.
+    pub fn root(&self) -> Digest {
+        self.root
+    }
+
+    /// Returns the value stored under the provided key, or [StorageMap::EMPTY_VALUE] if the key
+    /// is not in the map.
+    ///
+    /// # Errors
+    /// Returns an error if the leaf of the key cannot be read from the backend.
+    pub fn get_value(&self, key: &Digest) -> Result<Word, B::Error> {
+        let entries = self.get_leaf(leaf_index(key))?;
+        Ok(entries
+            .iter()
+            .find(|(entry_key, _)| entry_key == key)
+            .map(|(_, value)| *value)
+            .unwrap_or(StorageMap::EMPTY_VALUE))
+    }
+
+    /// Returns an opening of the provided key, which proves either the value stored under the key
+    /// or that the key is not in the map against the root of this map.
+    ///
+    /// # Errors
+    /// Returns an error if the leaf of the key or the siblings of the nodes on the path from the
+    /// leaf to the root cannot be read from the backend.
+    pub fn open(&self, key: &Digest) -> Result<SmtProof, B::Error> {
+        let leaf_index = leaf_index(key);
+        let mut entries = self.get_leaf(leaf_index)?;
+        sort_leaf_entries(&mut entries);
+
+        let mut index = NodeIndex::new_unchecked(SMT_DEPTH, leaf_index);
+        let mut path = Vec::with_capacity(SMT_DEPTH as usize);
+        for _ in 0..SMT_DEPTH {
+            path.push(self.get_node(index.sibling())?);
+            index.move_up();
+        }
+
+        let leaf = SmtLeaf::new(entries, LeafIndex::new_max_depth(leaf_index))
+            .expect("entries of a leaf should be mapped to the leaf");
+        Ok(SmtProof::new(MerklePath::new(path), leaf)
+            .expect("path should have the depth of the storage map tree"))
+    }
+
+    /// Returns a [StorageMap] holding all entries of this map, including uncommitted updates.
+    ///
+    /// This loads the entire map into memory. Only the non-empty subtrees of the tree are visited.
+    ///
+    /// # Errors
+    /// Returns an error if the nodes or leaves of the map cannot be read from the backend.
+    pub fn to_storage_map(&self) -> Result<StorageMap, B::Error> {
+        let mut entries = Vec::new();
+        let mut pending = vec![NodeIndex::root()];
+        while let Some(index) = pending.pop() {
+            if self.get_node(index)? == empty_subtree_root(index) {
+                continue;
+            }
+
+            if index.depth() == SMT_DEPTH {
+                entries.extend(self.get_leaf(index.value())?);
+            } else {
+                pending.push(index.left_child());
+                pending.push(index.right_child());
+            }
+        }
+
+        Ok(StorageMap::with_entries(entries).expect("entries of a storage map should be valid"))
+    }
+
+    /// Returns true if this map contains updates which have not been written into the backend.
+    pub fn has_uncommitted_changes(&self) -> bool {
+        !self.dirty_nodes.is_empty()
+    }
+
+    /// Returns a reference to the backend of this map.
+    ///
+    /// Note that the backend does not reflect uncommitted updates of this map.
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+
+    // DATA MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Inserts the provided value under the provided key, and returns the value previously stored
+    /// under the key. Inserting [StorageMap::EMPTY_VALUE] removes the key from the map.
+    ///
+    /// # Errors
+    /// Returns an error if the nodes required to update the root cannot be read from the backend.
+    pub fn insert(&mut self, key: Digest, value: Word) -> Result<Word, B::Error> {
+        let leaf_index = leaf_index(&key);
+        let mut entries = self.get_leaf(leaf_index)?;
+
+        let old_value = match entries.iter().position(|(entry_key, _)| *entry_key == key) {
+            Some(position) => entries.remove(position).1,
+            None => StorageMap::EMPTY_VALUE,
+        };
+        if old_value == value {
+            return Ok(old_value);
+        }
+        if value != StorageMap::EMPTY_VALUE {
+            entries.push((key, value));
+        }
+
+        // recompute the nodes on the path from the updated leaf to the root
+        let mut index = NodeIndex::new_unchecked(SMT_DEPTH, leaf_index);
+        let mut node = hash_leaf(entries.clone());
+        self.dirty_leaves.insert(leaf_index, entries);
+        self.dirty_nodes.insert(index, node);
+
+        for _ in 0..SMT_DEPTH {
+            let sibling = self.get_node(index.sibling())?;
+            node = if index.is_value_odd() {
+                Hasher::merge(&[sibling, node])
+            } else {
+                Hasher::merge(&[node, sibling])
+            };
+            index.move_up();
+            self.dirty_nodes.insert(index, node);
+        }
+        self.root = node;
+
+        Ok(old_value)
+    }
+
+    /// Applies the provided delta to this map, and returns the new root of the map.
+    ///
+    /// # Errors
+    /// Returns an error if the nodes required to update the root cannot be read from the backend.
+    pub fn apply_delta(&mut self, delta: &StorageMapDelta) -> Result<Digest, B::Error> {
+        for (&key, &value) in delta.leaves().iter() {
+            self.insert(key, value)?;
+        }
+
+        Ok(self.root)
+    }
+
+    /// Writes all updated leaves and nodes into the backend.
+    ///
+    /// # Errors
+    /// Returns an error if the backend fails to write the updates, in which case the updates are
+    /// kept in this map.
+    pub fn commit(&mut self) -> Result<(), B::Error> {
+        if !self.has_uncommitted_changes() {
+            return Ok(());
+        }
+
+        self.backend.write_batch(self.dirty_leaves.clone(), self.dirty_nodes.clone())?;
+        self.dirty_leaves.clear();
+        self.dirty_nodes.clear();
+
+        Ok(())
+    }
+
+    /// Drops all updates which have not been written into the backend, restoring the map stored in
+    /// the backend.
+    ///
+    /// # Errors
+    /// Returns an error if the root of the map cannot be read from the backend.
+    pub fn discard(&mut self) -> Result<(), B::Error> {
+        self.dirty_leaves.clear();
+        self.dirty_nodes.clear();
+        self.root = self.get_node(NodeIndex::root())?;
+
+        Ok(())
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    fn get_leaf(&self, index: u64) -> Result<Vec<(Digest, Word)>, B::Error> {
+        match self.dirty_leaves.get(&index) {
+            Some(entries) => Ok(entries.clone()),
+            None => Ok(self.backend.get_leaf(index)?.unwrap_or_default()),
+        }
+    }
+
+    fn get_node(&self, index: NodeIndex) -> Result<Digest, B::Error> {
+        match self.dirty_nodes.get(&index) {
+            Some(node) => Ok(*node),
+            None => Ok(self.backend.get_node(index)?.unwrap_or_else(|| empty_subtree_root(index))),
+        }
+    }
+}
+
+impl<B: StorageBackend + Default> Default for LazyStorageMap<B> {
+    fn default() -> Self {
+        Self {
+            backend: B::default(),
+            root: empty_subtree_root(NodeIndex::root()),
+            dirty_leaves: BTreeMap::new(),
+            dirty_nodes: BTreeMap::new(),
+        }
+    }
+}
+
+// LAZY MAP SLOT
+// ================================================================================================
+
+/// A [LazyStorageMap] held by a [StorageSlot::LazyMap](super::StorageSlot::LazyMap) slot of an
+/// [AccountStorage](super::AccountStorage).
+///
+/// The slot hides the type of the backend of the map, so that maps held by different backends can
+/// be part of the same account storage. Errors of the backend are returned as
+/// [AccountError::StorageBackendError].
+///
+/// Cloning a slot clones the backend of its map (e.g., a handle to a database) together with the
+/// uncommitted updates of the map. Two slots are equal if their maps have the same root.
+pub struct LazyMapSlot(Box<dyn LazyMap>);
+
+impl LazyMapSlot {
+    /// Returns a new [LazyMapSlot] holding the provided map.
+    pub fn new<B>(map: LazyStorageMap<B>) -> Self
+    where
+        B: StorageBackend + Clone + Send + Sync + 'static,
+        B::Error: fmt::Display,
+    {
+        Self(Box::new(map))
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the root of the map of this slot, including any uncommitted updates.
+    pub fn root(&self) -> Digest {
+        self.0.root()
+    }
+
+    /// Returns the value stored under the provided key, or [StorageMap::EMPTY_VALUE] if the key
+    /// is not in the map.
+    ///
+    /// # Errors
+    /// Returns an error if the leaf of the key cannot be read from the backend.
+    pub fn get_value(&self, key: &Digest) -> Result<Word, AccountError> {
+        self.0.get_value(key)
+    }
+
+    /// Returns an opening of the provided key against the root of the map of this slot (see
+    /// [LazyStorageMap::open()]).
+    ///
+    /// # Errors
+    /// Returns an error if the opening cannot be read from the backend.
+    pub fn open(&self, key: &Digest) -> Result<SmtProof, AccountError> {
+        self.0.open(key)
+    }
+
+    /// Returns a [StorageMap] holding all entries of the map of this slot (see
+    /// [LazyStorageMap::to_storage_map()]).
+    ///
+    /// # Errors
+    /// Returns an error if the map cannot be read from the backend.
+    pub fn to_storage_map(&self) -> Result<StorageMap, AccountError> {
+        self.0.to_storage_map()
+    }
+
+    /// Returns true if the map of this slot contains updates which have not been written into the
+    /// backend.
+    pub fn has_uncommitted_changes(&self) -> bool {
+        self.0.has_uncommitted_changes()
+    }
+
+    // DATA MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Inserts the provided value under the provided key, and returns the value previously stored
+    /// under the key.
+    ///
+    /// # Errors
+    /// Returns an error if the nodes required to update the root cannot be read from the backend.
+    pub fn insert(&mut self, key: Digest, value: Word) -> Result<Word, AccountError> {
+        self.0.insert(key, value)
+    }
+
+    /// Applies the provided delta to the map of this slot, and returns the new root of the map.
+    ///
+    /// # Errors
+    /// Returns an error if the nodes required to update the root cannot be read from the backend.
+    pub fn apply_delta(&mut self, delta: &StorageMapDelta) -> Result<Digest, AccountError> {
+        for (&key, &value) in delta.leaves().iter() {
+            self.insert(key, value)?;
+        }
+
+        Ok(self.root())
+    }
+
+    /// Writes all updates of the map of this slot into the backend.
+    ///
+    /// # Errors
+    /// Returns an error if the backend fails to write the updates.
+    pub fn commit(&mut self) -> Result<(), AccountError> {
+        self.0.commit()
+    }
+
+    /// Drops all updates of the map of this slot which have not been written into the backend.
+    ///
+    /// # Errors
+    /// Returns an error if the root of the map cannot be read from the backend.
+    pub fn discard(&mut self) -> Result<(), AccountError> {
+        self.0.discard()
+    }
+}
+
+impl Clone for LazyMapSlot {
+    fn clone(&self) -> Self {
+        Self(self.0.clone_box())
+    }
+}
+
+impl fmt::Debug for LazyMapSlot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LazyMapSlot")
+            .field("root", &self.root())
+            .field("has_uncommitted_changes", &self.has_uncommitted_changes())
+            .finish()
+    }
+}
+
+impl PartialEq for LazyMapSlot {
+    fn eq(&self, other: &Self) -> bool {
+        self.root() == other.root()
+    }
+}
+
+impl Eq for LazyMapSlot {}
+
+/// The operations of a [LazyStorageMap] used by a [LazyMapSlot], independent of the backend of
+/// the map.
+trait LazyMap: Send + Sync {
+    fn root(&self) -> Digest;
+    fn get_value(&self, key: &Digest) -> Result<Word, AccountError>;
+    fn open(&self, key: &Digest) -> Result<SmtProof, AccountError>;
+    fn to_storage_map(&self) -> Result<StorageMap, AccountError>;
+    fn has_uncommitted_changes(&self) -> bool;
+    fn insert(&mut self, key: Digest, value: Word) -> Result<Word, AccountError>;
+    fn commit(&mut self) -> Result<(), AccountError>;
+    fn discard(&mut self) -> Result<(), AccountError>;
+    fn clone_box(&self) -> Box<dyn LazyMap>;
+}
+
+impl<B> LazyMap for LazyStorageMap<B>
+where
+    B: StorageBackend + Clone + Send + Sync + 'static,
+    B::Error: fmt::Display,
+{
+    fn root(&self) -> Digest {
+        LazyStorageMap::root(self)
+    }
+
+    fn get_value(&self, key: &Digest) -> Result<Word, AccountError> {
+        LazyStorageMap::get_value(self, key).map_err(backend_error)
+    }
+
+    fn open(&self, key: &Digest) -> Result<SmtProof, AccountError> {
+        LazyStorageMap::open(self, key).map_err(backend_error)
+    }
+
+    fn to_storage_map(&self) -> Result<StorageMap, AccountError> {
+        LazyStorageMap::to_storage_map(self).map_err(backend_error)
+    }
+
+    fn has_uncommitted_changes(&self) -> bool {
+        LazyStorageMap::has_uncommitted_changes(self)
+    }
+
+    fn insert(&mut self, key: Digest, value: Word) -> Result<Word, AccountError> {
+        LazyStorageMap::insert(self, key, value).map_err(backend_error)
+    }
+
+    fn commit(&mut self) -> Result<(), AccountError> {
+        LazyStorageMap::commit(self).map_err(backend_error)
+    }
+
+    fn discard(&mut self) -> Result<(), AccountError> {
+        LazyStorageMap::discard(self).map_err(backend_error)
+    }
+
+    fn clone_box(&self) -> Box<dyn LazyMap> {
+        Box::new(self.clone())
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns an [AccountError] describing the provided error of a [StorageBackend].
+fn backend_error(err: impl fmt::Display) -> AccountError {
+    AccountError::StorageBackendError(err.to_string())
+}
+
+/// Returns the index of the leaf to which the provided key is mapped, i.e., the value of its most
+/// significant element.
+fn leaf_index(key: &Digest) -> u64 {
+    Word::from(*key)[3].as_int()
+}
+
+/// Returns the root of an empty subtree whose root is at the provided index.
+fn empty_subtree_root(index: NodeIndex) -> Digest {
+    *EmptySubtreeRoots::entry(SMT_DEPTH, index.depth())
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{leaf_index, LazyMapSlot, LazyStorageMap, MemoryStorageBackend, StorageBackend};
+    use crate::{
+        accounts::{AccountStorage, AccountStorageDelta, StorageMap, StorageMapDelta, StorageSlot},
+        utils::{Deserializable, Serializable},
+        Digest, Felt, Word, EMPTY_WORD,
+    };
+
+    fn key(elements: [u64; 4]) -> Digest {
+        Digest::new(elements.map(Felt::new))
+    }
+
+    fn value(element: u64) -> Word {
+        [Felt::new(element); 4]
+    }
+
+    #[test]
+    fn lazy_storage_map_matches_storage_map() {
+        // the first two keys are mapped to the same leaf
+        let entries = [
+            (key([1, 2, 3, 4]), value(1)),
+            (key([5, 6, 7, 4]), value(2)),
+            (key([9, 10, 11, 12]), value(3)),
+        ];
+        let storage_map = StorageMap::with_entries(entries).unwrap();
+
+        let mut lazy_map = LazyStorageMap::<MemoryStorageBackend>::default();
+        assert_eq!(lazy_map.root(), StorageMap::new().root());
+        for (key, value) in entries {
+            assert_eq!(lazy_map.insert(key, value).unwrap(), EMPTY_WORD);
+        }
+        assert_eq!(lazy_map.root(), storage_map.root());
+        assert_eq!(lazy_map.get_value(&entries[1].0).unwrap(), entries[1].1);
+        assert_eq!(lazy_map.get_value(&key([0, 0, 0, 4])).unwrap(), EMPTY_WORD);
+
+        // removing an entry of a multi-entry leaf, and updating an entry
+        let delta =
+            StorageMapDelta::from_iters([entries[0].0.into()], [(entries[2].0.into(), value(4))]);
+        let mut updated_map = storage_map.clone();
+        updated_map.apply_delta(&delta);
+        assert_eq!(lazy_map.apply_delta(&delta).unwrap(), updated_map.root());
+    }
+
+    #[test]
+    fn lazy_storage_map_commit_and_discard() {
+        let entries = [(key([1, 2, 3, 4]), value(1)), (key([5, 6, 7, 8]), value(2))];
+        let storage_map = StorageMap::with_entries(entries).unwrap();
+
+        let mut lazy_map =
+            LazyStorageMap::from_storage_map(&storage_map, MemoryStorageBackend::new()).unwrap();
+        assert!(!lazy_map.has_uncommitted_changes());
+        assert_eq!(lazy_map.backend().num_leaves(), 2);
+
+        // uncommitted updates are dropped on discard
+        lazy_map.insert(entries[0].0, value(3)).unwrap();
+        assert!(lazy_map.has_uncommitted_changes());
+        lazy_map.discard().unwrap();
+        assert_eq!(lazy_map.root(), storage_map.root());
+        assert_eq!(lazy_map.get_value(&entries[0].0).unwrap(), entries[0].1);
+
+        // committed updates are visible to a map reopened from the backend
+        lazy_map.insert(entries[0].0, EMPTY_WORD).unwrap();
+        lazy_map.commit().unwrap();
+        assert_eq!(lazy_map.backend().num_leaves(), 1);
+
+        let reopened = LazyStorageMap::new(lazy_map.backend().clone()).unwrap();
+        assert_eq!(reopened.root(), StorageMap::with_entries([entries[1]]).unwrap().root());
+        assert_eq!(
+            reopened.backend().get_leaf(leaf_index(&entries[1].0)).unwrap(),
+            Some(vec![entries[1]])
+        );
+    }
+
+    #[test]
+    fn account_storage_with_lazy_map_slot() {
+        let entries = [(key([1, 2, 3, 4]), value(1)), (key([5, 6, 7, 8]), value(2))];
+        let storage_map = StorageMap::with_entries(entries).unwrap();
+        let lazy_map =
+            LazyStorageMap::from_storage_map(&storage_map, MemoryStorageBackend::new()).unwrap();
+
+        let mut storage =
+            AccountStorage::new(vec![StorageSlot::Value(value(7)), StorageSlot::Map(storage_map)])
+                .unwrap();
+        let mut lazy_storage = AccountStorage::new(vec![
+            StorageSlot::Value(value(7)),
+            StorageSlot::LazyMap(LazyMapSlot::new(lazy_map)),
+        ])
+        .unwrap();
+        assert_eq!(lazy_storage.commitment(), storage.commitment());
+        assert_eq!(
+            lazy_storage.get_map_item(1, entries[1].0.into()).unwrap(),
+            storage.get_map_item(1, entries[1].0.into()).unwrap()
+        );
+        assert_eq!(
+            lazy_storage.open_map_item(1, entries[0].0.into()).unwrap(),
+            storage.open_map_item(1, entries[0].0.into()).unwrap()
+        );
+
+        // updates are applied to the lazy map slot in the same way as to an in-memory map slot
+        storage.set_map_item(1, entries[0].0.into(), value(3)).unwrap();
+        lazy_storage.set_map_item(1, entries[0].0.into(), value(3)).unwrap();
+        let delta = AccountStorageDelta::from_iters(
+            [],
+            [],
+            [(1, StorageMapDelta::from_iters([], [(key([9, 10, 11, 12]).into(), value(4))]))],
+        );
+        storage.apply_delta(&delta).unwrap();
+        lazy_storage.apply_delta(&delta).unwrap();
+        assert_eq!(lazy_storage.commitment(), storage.commitment());
+        assert_eq!(
+            AccountStorageDelta::from_diff(&storage, &lazy_storage).unwrap(),
+            Default::default()
+        );
+
+        lazy_storage.commit_lazy_maps().unwrap();
+        assert_eq!(lazy_storage.commitment(), storage.commitment());
+
+        // a lazy map slot is serialized as an in-memory map slot
+        let deserialized = AccountStorage::read_from_bytes(&lazy_storage.to_bytes()).unwrap();
+        assert_eq!(deserialized, storage);
+    }
+}
@@ -3,8 +3,8 @@ use alloc::vec::Vec;
 use vm_core::utils::{ByteReader, ByteWriter, Deserializable, Serializable};
 use vm_processor::DeserializationError;
 
-use super::{AccountStorage, StorageSlotType, Word};
-use crate::AccountError;
+use super::{AccountStorage, Felt, Hasher, StorageSlotType, Word};
+use crate::{AccountError, Digest};
 
 // ACCOUNT STORAGE HEADER
 // ================================================================================================
@@ -55,6 +55,25 @@ impl AccountStorageHeader {
             actual: index as u8,
         })
     }
+
+    /// Computes the commitment to the storage described by this header.
+    ///
+    /// The commitment is computed in the same way as [AccountStorage::commitment()], and thus
+    /// matches the commitment to the storage from which this header was derived.
+    pub fn compute_commitment(&self) -> Digest {
+        Hasher::hash_elements(&self.as_elements())
+    }
+
+    /// Converts storage slots of this header into a vector of field elements.
+    ///
+    /// The elements are laid out in the same way as in [AccountStorage::as_elements()], i.e., each
+    /// slot is encoded as `[STORAGE_SLOT_VALUE, storage_slot_type, 0, 0, 0]`.
+    pub fn as_elements(&self) -> Vec<Felt> {
+        self.slots
+            .iter()
+            .flat_map(|(slot_type, value)| value.iter().chain(slot_type.as_word().iter()).copied())
+            .collect()
+    }
 }
 
 impl From<AccountStorage> for AccountStorageHeader {
@@ -119,6 +138,12 @@ mod tests {
         assert_eq!(expected_header, AccountStorageHeader::from(account_storage))
     }
 
+    #[test]
+    fn test_storage_header_commitment() {
+        let storage = AccountStorage::mock();
+        assert_eq!(storage.get_header().compute_commitment(), storage.commitment());
+    }
+
     #[test]
     fn test_serde_account_storage_header() {
         // create new storage header
@@ -1,10 +1,14 @@
 use alloc::{string::ToString, vec::Vec};
+use core::fmt;
 
 use super::{
     AccountError, AccountStorageDelta, ByteReader, ByteWriter, Deserializable,
     DeserializationError, Digest, Felt, Hasher, Serializable, Word,
 };
-use crate::accounts::{AccountComponent, AccountType};
+use crate::{
+    accounts::{AccountComponent, AccountType},
+    crypto::merkle::SmtProof,
+};
 
 mod slot;
 pub use slot::{StorageSlot, StorageSlotType};
@@ -12,9 +16,18 @@ pub use slot::{StorageSlot, StorageSlotType};
 mod map;
 pub use map::StorageMap;
 
+mod array;
+pub use array::StorageArray;
+
+mod backend;
+pub use backend::{LazyMapSlot, LazyStorageMap, MemoryStorageBackend, StorageBackend};
+
 mod header;
 pub use header::AccountStorageHeader;
 
+mod schema;
+pub use schema::{SchemaSlotType, StorageSchema};
+
 // ACCOUNT STORAGE
 // ================================================================================================
 
@@ -27,6 +40,16 @@ pub use header::AccountStorageHeader;
 /// - [StorageSlot::Map]: contains a [StorageMap] which is a key-value map where both keys and
 ///   values are [Word]s. The value of a storage slot containing a map is the commitment to the
 ///   underlying map.
+///
+/// The map of a map slot can also be held by a [StorageBackend] instead of in memory (see
+/// [StorageSlot::LazyMap]). Such maps are loaded on demand, i.e., reading or updating an entry only
+/// loads the nodes of the tree required for it. Accessing the map of such a slot via
+/// [AccountStorage::get_map()] or [AccountStorage::map_entries()] is not supported, since it
+/// requires the entire map to be in memory.
+///
+/// Fixed-size arrays of [Word]s are emulated on top of map slots (see [StorageArray]) and can be
+/// accessed with bounds checks via [AccountStorage::get_array_item()] and
+/// [AccountStorage::set_array_item()].
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AccountStorage {
     slots: Vec<StorageSlot>,
@@ -127,16 +150,98 @@ impl AccountStorage {
     /// # Errors:
     /// - If the index is out of bounds
     /// - If the [StorageSlot] is not [StorageSlotType::Map]
+    /// - If the map is held by a storage backend which fails to read the item
     pub fn get_map_item(&self, index: u8, key: Word) -> Result<Word, AccountError> {
+        match self.get_map_slot(index)? {
+            StorageSlot::Map(map) => Ok(map.get_value(&Digest::from(key))),
+            StorageSlot::LazyMap(map) => map.get_value(&Digest::from(key)),
+            StorageSlot::Value(_) => unreachable!("map slots should not be value slots"),
+        }
+    }
+
+    /// Returns a reference to the [StorageMap] located in storage at the specified index.
+    ///
+    /// # Errors:
+    /// - If the index is out of bounds
+    /// - If the [StorageSlot] is not [StorageSlotType::Map]
+    /// - If the map is held by a storage backend (see [StorageSlot::LazyMap])
+    pub fn get_map(&self, index: u8) -> Result<&StorageMap, AccountError> {
+        match self.get_map_slot(index)? {
+            StorageSlot::Map(ref map) => Ok(map),
+            _ => Err(AccountError::StorageSlotNotInMemory(index)),
+        }
+    }
+
+    /// Returns the map slot (i.e., either a [StorageSlot::Map] or a [StorageSlot::LazyMap] slot)
+    /// at the specified index.
+    ///
+    /// # Errors:
+    /// - If the index is out of bounds
+    /// - If the [StorageSlot] is not [StorageSlotType::Map]
+    pub(crate) fn get_map_slot(&self, index: u8) -> Result<&StorageSlot, AccountError> {
         match self.slots.get(index as usize).ok_or(AccountError::StorageIndexOutOfBounds {
             max: self.slots.len() as u8,
             actual: index,
         })? {
-            StorageSlot::Map(ref map) => Ok(map.get_value(&Digest::from(key))),
-            _ => Err(AccountError::StorageSlotNotMap(index)),
+            StorageSlot::Value(_) => Err(AccountError::StorageSlotNotMap(index)),
+            slot => Ok(slot),
         }
     }
 
+    /// Returns an opening of the specified key in a map located in storage at the specified index.
+    ///
+    /// The returned proof can be verified against the value of the storage slot (i.e., the root
+    /// of the map) and proves either the value stored under the key or that the key is not set.
+    ///
+    /// # Errors:
+    /// - If the index is out of bounds
+    /// - If the [StorageSlot] is not [StorageSlotType::Map]
+    /// - If the map is held by a storage backend which fails to read the opening
+    pub fn open_map_item(&self, index: u8, key: Word) -> Result<SmtProof, AccountError> {
+        match self.get_map_slot(index)? {
+            StorageSlot::Map(map) => Ok(map.open(&Digest::from(key))),
+            StorageSlot::LazyMap(map) => map.open(&Digest::from(key)),
+            StorageSlot::Value(_) => unreachable!("map slots should not be value slots"),
+        }
+    }
+
+    /// Returns an iterator over the key-value pairs of a map located in storage at the specified
+    /// index.
+    ///
+    /// # Errors:
+    /// - If the index is out of bounds
+    /// - If the [StorageSlot] is not [StorageSlotType::Map]
+    /// - If the map is held by a storage backend (see [StorageSlot::LazyMap])
+    pub fn map_entries(
+        &self,
+        index: u8,
+    ) -> Result<impl Iterator<Item = &(Digest, Word)>, AccountError> {
+        self.get_map(index).map(StorageMap::entries)
+    }
+
+    /// Returns the length of the array located in storage at the specified index.
+    ///
+    /// # Errors:
+    /// - If the index is out of bounds
+    /// - If the [StorageSlot] is not [StorageSlotType::Map]
+    /// - If the map does not contain a valid array length (see [StorageArray])
+    pub fn get_array_len(&self, index: u8) -> Result<u32, AccountError> {
+        array::array_len(self.get_map_item(index, StorageArray::LENGTH_KEY)?)
+            .ok_or(AccountError::StorageSlotNotArray(index))
+    }
+
+    /// Returns the element at the specified position of the array located in storage at the
+    /// specified index.
+    ///
+    /// # Errors:
+    /// - If the index is out of bounds
+    /// - If the [StorageSlot] is not [StorageSlotType::Map] or does not contain an array
+    /// - If the position is out of bounds for the array
+    pub fn get_array_item(&self, index: u8, position: u32) -> Result<Word, AccountError> {
+        array::check_bounds(self.get_array_len(index)?, position)?;
+        self.get_map_item(index, StorageArray::element_key(position))
+    }
+
     /// Returns an [AccountStorageHeader] for this account storage.
     pub fn get_header(&self) -> AccountStorageHeader {
         AccountStorageHeader::new(
@@ -144,6 +249,67 @@ impl AccountStorage {
         )
     }
 
+    // TYPED ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the value of the value slot with the specified name in the provided schema,
+    /// converted into `T`.
+    ///
+    /// # Errors:
+    /// - If the schema does not contain a value slot with the specified name.
+    /// - If the slot index is out of bounds for this storage.
+    /// - If the value cannot be converted into `T`.
+    pub fn get_typed<T>(&self, schema: &StorageSchema, name: &str) -> Result<T, AccountError>
+    where
+        T: TryFrom<Word>,
+        T::Error: fmt::Display,
+    {
+        let index = schema.value_index(name)?;
+        convert_word(self.get_item(index)?.into())
+    }
+
+    /// Returns the value at the specified position of the array with the specified name in the
+    /// provided schema, converted into `T`.
+    ///
+    /// # Errors:
+    /// - If the schema does not contain an array with the specified name.
+    /// - If the position is out of bounds for the array or the storage.
+    /// - If the value cannot be converted into `T`.
+    pub fn get_typed_element<T>(
+        &self,
+        schema: &StorageSchema,
+        name: &str,
+        position: u8,
+    ) -> Result<T, AccountError>
+    where
+        T: TryFrom<Word>,
+        T::Error: fmt::Display,
+    {
+        let index = schema.array_index(name, position)?;
+        convert_word(self.get_item(index)?.into())
+    }
+
+    /// Returns the value stored under the provided key in the map with the specified name in the
+    /// provided schema, converted into `T`.
+    ///
+    /// # Errors:
+    /// - If the schema does not contain a map with the specified name.
+    /// - If the slot index is out of bounds for this storage or the slot is not a map.
+    /// - If the value cannot be converted into `T`.
+    pub fn get_typed_map_item<T>(
+        &self,
+        schema: &StorageSchema,
+        name: &str,
+        key: Word,
+    ) -> Result<T, AccountError>
+    where
+        T: TryFrom<Word>,
+        T::Error: fmt::Display,
+    {
+        let index = schema.map_index(name)?;
+        convert_word(self.get_map_item(index, key)?)
+    }
+
     // DATA MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -161,12 +327,15 @@ impl AccountStorage {
                 .get_mut(idx as usize)
                 .ok_or(AccountError::StorageIndexOutOfBounds { max: len, actual: idx })?;
 
-            let storage_map = match storage_slot {
-                StorageSlot::Map(map) => map,
-                _ => return Err(AccountError::StorageSlotNotMap(idx)),
-            };
-
-            storage_map.apply_delta(map);
+            match storage_slot {
+                StorageSlot::Map(storage_map) => {
+                    storage_map.apply_delta(map);
+                },
+                StorageSlot::LazyMap(storage_map) => {
+                    storage_map.apply_delta(map)?;
+                },
+                StorageSlot::Value(_) => return Err(AccountError::StorageSlotNotMap(idx)),
+            }
         }
 
         // update storage values
@@ -216,6 +385,8 @@ impl AccountStorage {
     /// # Errors:
     /// - If the index is out of bounds
     /// - If the [StorageSlot] is not [StorageSlotType::Map]
+    /// - If the map is held by a storage backend which fails to read the nodes required for the
+    ///   update
     pub fn set_map_item(
         &mut self,
         index: u8,
@@ -232,18 +403,94 @@ impl AccountStorage {
             });
         }
 
-        let storage_map = match self.slots[index as usize] {
-            StorageSlot::Map(ref mut map) => map,
-            _ => return Err(AccountError::StorageSlotNotMap(index)),
-        };
+        match self.slots[index as usize] {
+            StorageSlot::Map(ref mut map) => {
+                // get old map root to return
+                let old_root = map.root();
+
+                // update the key-value pair in the map
+                let old_value = map.insert(key.into(), value);
+
+                Ok((old_root.into(), old_value))
+            },
+            StorageSlot::LazyMap(ref mut map) => {
+                let old_root = map.root();
+                let old_value = map.insert(key.into(), value)?;
+
+                Ok((old_root.into(), old_value))
+            },
+            StorageSlot::Value(_) => Err(AccountError::StorageSlotNotMap(index)),
+        }
+    }
+
+    /// Updates the element at the specified position of the array located in storage at the
+    /// specified index.
+    ///
+    /// Returns the old commitment to the array (i.e., the old root of the underlying map) and the
+    /// old element.
+    ///
+    /// # Errors:
+    /// - If the index is out of bounds
+    /// - If the [StorageSlot] is not [StorageSlotType::Map] or does not contain an array
+    /// - If the position is out of bounds for the array
+    pub fn set_array_item(
+        &mut self,
+        index: u8,
+        position: u32,
+        value: Word,
+    ) -> Result<(Word, Word), AccountError> {
+        array::check_bounds(self.get_array_len(index)?, position)?;
+        self.set_map_item(index, StorageArray::element_key(position), value)
+    }
 
-        // get old map root to return
-        let old_root = storage_map.root();
+    /// Writes the uncommitted updates of all maps held by storage backends (see
+    /// [StorageSlot::LazyMap]) into their backends.
+    ///
+    /// # Errors:
+    /// - If any of the backends fails to write the updates; the updates of the maps which were not
+    ///   written are kept.
+    pub fn commit_lazy_maps(&mut self) -> Result<(), AccountError> {
+        for slot in self.slots.iter_mut() {
+            if let StorageSlot::LazyMap(map) = slot {
+                map.commit()?;
+            }
+        }
 
-        // update the key-value pair in the map
-        let old_value = storage_map.insert(key.into(), value);
+        Ok(())
+    }
 
-        Ok((old_root.into(), old_value))
+    /// Updates the value slot with the specified name in the provided schema and returns the old
+    /// value.
+    ///
+    /// # Errors:
+    /// - If the schema does not contain a value slot with the specified name.
+    /// - If the slot index is out of bounds for this storage or the slot is not a value slot.
+    pub fn set_typed(
+        &mut self,
+        schema: &StorageSchema,
+        name: &str,
+        value: impl Into<Word>,
+    ) -> Result<Word, AccountError> {
+        let index = schema.value_index(name)?;
+        self.set_item(index, value.into())
+    }
+
+    /// Updates the value at the specified position of the array with the specified name in the
+    /// provided schema and returns the old value.
+    ///
+    /// # Errors:
+    /// - If the schema does not contain an array with the specified name.
+    /// - If the position is out of bounds for the array or the storage.
+    /// - If the slot is not a value slot.
+    pub fn set_typed_element(
+        &mut self,
+        schema: &StorageSchema,
+        name: &str,
+        position: u8,
+        value: impl Into<Word>,
+    ) -> Result<Word, AccountError> {
+        let index = schema.array_index(name, position)?;
+        self.set_item(index, value.into())
     }
 }
 
@@ -255,6 +502,15 @@ fn slots_as_elements(slots: &[StorageSlot]) -> Vec<Felt> {
     slots.iter().flat_map(|slot| slot.as_elements()).collect()
 }
 
+/// Converts the given word into `T`, mapping conversion failures into an [AccountError].
+fn convert_word<T>(word: Word) -> Result<T, AccountError>
+where
+    T: TryFrom<Word>,
+    T::Error: fmt::Display,
+{
+    T::try_from(word).map_err(|err| AccountError::StorageSlotValueConversionError(err.to_string()))
+}
+
 /// Computes the commitment to the given slots
 fn build_slots_commitment(slots: &[StorageSlot]) -> Digest {
     let elements = slots_as_elements(slots);
@@ -297,8 +553,11 @@ impl Deserializable for AccountStorage {
 
 #[cfg(test)]
 mod tests {
+    use alloc::vec::Vec;
+
     use super::{
-        build_slots_commitment, AccountStorage, Deserializable, Serializable, StorageMap, Word,
+        build_slots_commitment, AccountError, AccountStorage, Deserializable, Digest, Felt,
+        Serializable, StorageMap, Word,
     };
     use crate::accounts::StorageSlot;
 
@@ -319,6 +578,43 @@ mod tests {
         assert_eq!(storage, AccountStorage::read_from_bytes(&bytes).unwrap());
     }
 
+    #[test]
+    fn test_account_storage_map_api() {
+        let key = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let value = [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)];
+        let missing_key = [Felt::new(9), Felt::new(10), Felt::new(11), Felt::new(12)];
+
+        let mut storage = AccountStorage::new(vec![
+            StorageSlot::empty_value(),
+            StorageSlot::Map(StorageMap::default()),
+        ])
+        .unwrap();
+        storage.set_map_item(1, key, value).unwrap();
+
+        assert_eq!(storage.get_map_item(1, key).unwrap(), value);
+        assert_eq!(
+            storage.map_entries(1).unwrap().collect::<Vec<_>>(),
+            vec![&(Digest::from(key), value)]
+        );
+
+        let root = storage.get_item(1).unwrap();
+        let proof = storage.open_map_item(1, key).unwrap();
+        assert!(proof.verify_membership(&Digest::from(key), &value, &root));
+
+        let proof = storage.open_map_item(1, missing_key).unwrap();
+        assert!(proof.verify_membership(
+            &Digest::from(missing_key),
+            &StorageMap::EMPTY_VALUE,
+            &root
+        ));
+
+        assert!(matches!(storage.open_map_item(0, key), Err(AccountError::StorageSlotNotMap(0))));
+        assert!(matches!(
+            storage.map_entries(2).map(|entries| entries.count()),
+            Err(AccountError::StorageIndexOutOfBounds { max: 2, actual: 2 })
+        ));
+    }
+
     #[test]
     fn test_account_storage_slots_commitment() {
         let storage = AccountStorage::mock();
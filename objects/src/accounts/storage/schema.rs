@@ -0,0 +1,304 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use super::{AccountError, AccountStorage, StorageSlot};
+
+// STORAGE SCHEMA SLOT TYPE
+// ================================================================================================
+
+/// The type of a named entry in a [StorageSchema].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchemaSlotType {
+    /// A single [StorageSlot::Value] slot.
+    Value,
+    /// A single [StorageSlot::Map] slot.
+    Map,
+    /// A contiguous range of the specified number of [StorageSlot::Value] slots.
+    Array(u8),
+}
+
+impl SchemaSlotType {
+    /// Returns the number of storage slots occupied by an entry of this type.
+    pub fn num_slots(&self) -> usize {
+        match self {
+            SchemaSlotType::Value | SchemaSlotType::Map => 1,
+            SchemaSlotType::Array(len) => *len as usize,
+        }
+    }
+}
+
+// STORAGE SCHEMA
+// ================================================================================================
+
+/// A layout of account storage which assigns names and types to storage slots.
+///
+/// Entries are laid out sequentially in the order in which they were declared, starting at slot
+/// index 0. For example, a schema declared as `[("owner", Value), ("balances", Map),
+/// ("limits", Array(3))]` places `owner` in slot 0, `balances` in slot 1 and `limits` in slots
+/// 2 through 4.
+///
+/// The schema allows reading and writing storage by name (see [AccountStorage::get_typed()] and
+/// [AccountStorage::set_typed()]) instead of hard-coding slot indices. The schema itself is not
+/// part of the account state and does not affect the storage commitment.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StorageSchema {
+    entries: Vec<SchemaEntry>,
+}
+
+/// A single named entry of a [StorageSchema].
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct SchemaEntry {
+    name: String,
+    slot_type: SchemaSlotType,
+    offset: u8,
+}
+
+impl StorageSchema {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [StorageSchema] instantiated from the provided named entries.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Two entries have the same name.
+    /// - The entries occupy more than [AccountStorage::MAX_NUM_STORAGE_SLOTS] slots.
+    pub fn new<N: Into<String>>(
+        entries: impl IntoIterator<Item = (N, SchemaSlotType)>,
+    ) -> Result<Self, AccountError> {
+        let mut schema = Self::default();
+        for (name, slot_type) in entries {
+            schema = schema.with_entry(name, slot_type)?;
+        }
+
+        Ok(schema)
+    }
+
+    /// Appends an entry with the provided name and type to this schema.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - An entry with the same name already exists.
+    /// - The schema would occupy more than [AccountStorage::MAX_NUM_STORAGE_SLOTS] slots.
+    pub fn with_entry(
+        mut self,
+        name: impl Into<String>,
+        slot_type: SchemaSlotType,
+    ) -> Result<Self, AccountError> {
+        let name = name.into();
+        if self.entries.iter().any(|entry| entry.name == name) {
+            return Err(AccountError::StorageSchemaDuplicateSlotName(name));
+        }
+
+        let offset = self.num_slots();
+        let num_slots = offset + slot_type.num_slots();
+        if num_slots > AccountStorage::MAX_NUM_STORAGE_SLOTS {
+            return Err(AccountError::StorageTooManySlots(num_slots as u64));
+        }
+
+        self.entries.push(SchemaEntry { name, slot_type, offset: offset as u8 });
+
+        Ok(self)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the total number of storage slots occupied by this schema.
+    pub fn num_slots(&self) -> usize {
+        self.entries.iter().map(|entry| entry.slot_type.num_slots()).sum()
+    }
+
+    /// Returns an iterator over the names, types and first slot indices of the schema entries.
+    pub fn entries(&self) -> impl Iterator<Item = (&str, SchemaSlotType, u8)> {
+        self.entries
+            .iter()
+            .map(|entry| (entry.name.as_str(), entry.slot_type, entry.offset))
+    }
+
+    /// Returns the type and the first slot index of the entry with the specified name.
+    ///
+    /// # Errors
+    /// Returns an error if the schema does not contain an entry with the specified name.
+    pub fn get(&self, name: &str) -> Result<(SchemaSlotType, u8), AccountError> {
+        self.entries
+            .iter()
+            .find(|entry| entry.name == name)
+            .map(|entry| (entry.slot_type, entry.offset))
+            .ok_or_else(|| AccountError::StorageSchemaSlotNotFound(name.to_string()))
+    }
+
+    /// Returns the slot index of the value entry with the specified name.
+    ///
+    /// # Errors
+    /// Returns an error if the entry does not exist or is not a [SchemaSlotType::Value].
+    pub fn value_index(&self, name: &str) -> Result<u8, AccountError> {
+        match self.get(name)? {
+            (SchemaSlotType::Value, index) => Ok(index),
+            _ => Err(AccountError::StorageSchemaSlotTypeMismatch(name.to_string())),
+        }
+    }
+
+    /// Returns the slot index of the map entry with the specified name.
+    ///
+    /// # Errors
+    /// Returns an error if the entry does not exist or is not a [SchemaSlotType::Map].
+    pub fn map_index(&self, name: &str) -> Result<u8, AccountError> {
+        match self.get(name)? {
+            (SchemaSlotType::Map, index) => Ok(index),
+            _ => Err(AccountError::StorageSchemaSlotTypeMismatch(name.to_string())),
+        }
+    }
+
+    /// Returns the slot index of the element at the specified position of the array entry with
+    /// the specified name.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The entry does not exist or is not a [SchemaSlotType::Array].
+    /// - The position is out of bounds for the array.
+    pub fn array_index(&self, name: &str, position: u8) -> Result<u8, AccountError> {
+        match self.get(name)? {
+            (SchemaSlotType::Array(len), offset) => {
+                if position >= len {
+                    return Err(AccountError::StorageIndexOutOfBounds {
+                        max: len,
+                        actual: position,
+                    });
+                }
+                Ok(offset + position)
+            },
+            _ => Err(AccountError::StorageSchemaSlotTypeMismatch(name.to_string())),
+        }
+    }
+
+    /// Checks that the provided storage matches this schema.
+    ///
+    /// Storage matches the schema if it has exactly the number of slots declared by the schema and
+    /// every slot has the declared type.
+    ///
+    /// # Errors
+    /// Returns an error if the storage does not match this schema.
+    pub fn validate(&self, storage: &AccountStorage) -> Result<(), AccountError> {
+        let num_slots = self.num_slots();
+        if storage.slots().len() != num_slots {
+            return Err(AccountError::StorageSchemaNumSlotsMismatch {
+                expected: num_slots,
+                actual: storage.slots().len(),
+            });
+        }
+
+        for entry in self.entries.iter() {
+            let start = entry.offset as usize;
+            let slots = &storage.slots()[start..start + entry.slot_type.num_slots()];
+            let matches = match entry.slot_type {
+                SchemaSlotType::Map => {
+                    matches!(slots[0], StorageSlot::Map(_) | StorageSlot::LazyMap(_))
+                },
+                SchemaSlotType::Value | SchemaSlotType::Array(_) => {
+                    slots.iter().all(|slot| matches!(slot, StorageSlot::Value(_)))
+                },
+            };
+
+            if !matches {
+                return Err(AccountError::StorageSchemaSlotTypeMismatch(entry.name.clone()));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        accounts::StorageMap,
+        assets::{TokenMetadata, TokenSymbol},
+        Felt, Word, ZERO,
+    };
+
+    fn schema() -> StorageSchema {
+        StorageSchema::new([
+            ("metadata", SchemaSlotType::Value),
+            ("balances", SchemaSlotType::Map),
+            ("limits", SchemaSlotType::Array(3)),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_storage_schema_layout() {
+        let schema = schema();
+        assert_eq!(schema.num_slots(), 5);
+        assert_eq!(schema.value_index("metadata").unwrap(), 0);
+        assert_eq!(schema.map_index("balances").unwrap(), 1);
+        assert_eq!(schema.array_index("limits", 2).unwrap(), 4);
+
+        assert!(matches!(
+            schema.array_index("limits", 3),
+            Err(AccountError::StorageIndexOutOfBounds { max: 3, actual: 3 })
+        ));
+        assert!(matches!(
+            schema.map_index("metadata"),
+            Err(AccountError::StorageSchemaSlotTypeMismatch(_))
+        ));
+        assert!(matches!(schema.get("missing"), Err(AccountError::StorageSchemaSlotNotFound(_))));
+        assert!(matches!(
+            schema.clone().with_entry("limits", SchemaSlotType::Value),
+            Err(AccountError::StorageSchemaDuplicateSlotName(_))
+        ));
+        assert!(matches!(
+            schema.with_entry("big", SchemaSlotType::Array(255)),
+            Err(AccountError::StorageTooManySlots(260))
+        ));
+    }
+
+    #[test]
+    fn test_storage_schema_typed_access() {
+        let schema = schema();
+        let mut storage = AccountStorage::new(vec![
+            StorageSlot::empty_value(),
+            StorageSlot::Map(StorageMap::default()),
+            StorageSlot::empty_value(),
+            StorageSlot::empty_value(),
+            StorageSlot::empty_value(),
+        ])
+        .unwrap();
+        schema.validate(&storage).unwrap();
+
+        let metadata = TokenMetadata::new(TokenSymbol::new("POL").unwrap(), 8, 1_000).unwrap();
+        storage.set_typed(&schema, "metadata", metadata).unwrap();
+        assert_eq!(storage.get_typed::<TokenMetadata>(&schema, "metadata").unwrap(), metadata);
+        assert_eq!(storage.get_item(0).unwrap(), Word::from(metadata).into());
+
+        let limit = [Felt::new(7), ZERO, ZERO, ZERO];
+        storage.set_typed_element(&schema, "limits", 1, limit).unwrap();
+        assert_eq!(storage.get_typed_element::<Word>(&schema, "limits", 1).unwrap(), limit);
+        assert_eq!(storage.get_item(3).unwrap(), limit.into());
+
+        let key = [Felt::new(1), ZERO, ZERO, ZERO];
+        storage.set_map_item(1, key, limit).unwrap();
+        assert_eq!(storage.get_typed_map_item::<Word>(&schema, "balances", key).unwrap(), limit);
+
+        // a word with non-zero padding cannot be decoded as token metadata
+        let invalid_metadata = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        storage.set_typed_element(&schema, "limits", 0, invalid_metadata).unwrap();
+        assert!(matches!(
+            storage.get_typed_element::<TokenMetadata>(&schema, "limits", 0),
+            Err(AccountError::StorageSlotValueConversionError(_))
+        ));
+        assert!(matches!(
+            storage.get_typed::<Word>(&schema, "balances"),
+            Err(AccountError::StorageSchemaSlotTypeMismatch(_))
+        ));
+
+        let mismatched = AccountStorage::new(vec![StorageSlot::empty_value()]).unwrap();
+        assert!(schema.validate(&mismatched).is_err());
+    }
+}
@@ -4,7 +4,7 @@ use vm_core::{
 };
 use vm_processor::DeserializationError;
 
-use super::{map::EMPTY_STORAGE_MAP_ROOT, Felt, StorageMap, Word};
+use super::{map::EMPTY_STORAGE_MAP_ROOT, Felt, LazyMapSlot, StorageMap, Word};
 
 mod r#type;
 pub use r#type::StorageSlotType;
@@ -13,10 +13,20 @@ pub use r#type::StorageSlotType;
 // ================================================================================================
 
 /// An object that represents the type of a storage slot.
+///
+/// Map slots hold their map either in memory ([StorageSlot::Map]) or in a
+/// [StorageBackend](super::StorageBackend) from which the map is loaded on demand
+/// ([StorageSlot::LazyMap]). Both are slots of type [StorageSlotType::Map], and the transaction
+/// kernel does not distinguish between them.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum StorageSlot {
     Value(Word),
     Map(StorageMap),
+    /// A map slot whose map is held by a storage backend.
+    ///
+    /// Serializing a lazy map slot loads the entire map from the backend and writes it as a
+    /// [StorageSlot::Map] slot, and panics if the backend fails to read the map.
+    LazyMap(LazyMapSlot),
 }
 
 impl StorageSlot {
@@ -28,6 +38,7 @@ impl StorageSlot {
         match self {
             StorageSlot::Value(value) => *value == EMPTY_WORD,
             StorageSlot::Map(map) => map.root() == EMPTY_STORAGE_MAP_ROOT,
+            StorageSlot::LazyMap(map) => map.root() == EMPTY_STORAGE_MAP_ROOT,
         }
     }
 
@@ -35,7 +46,7 @@ impl StorageSlot {
     pub fn default_word(&self) -> Word {
         match self {
             StorageSlot::Value(_) => EMPTY_WORD,
-            StorageSlot::Map(_) => EMPTY_STORAGE_MAP_ROOT.into(),
+            StorageSlot::Map(_) | StorageSlot::LazyMap(_) => EMPTY_STORAGE_MAP_ROOT.into(),
         }
     }
 
@@ -67,10 +78,12 @@ impl StorageSlot {
     /// Returns:
     /// - For [StorageSlot::Value] the value
     /// - For [StorageSlot::Map] the root of the [StorageMap]
+    /// - For [StorageSlot::LazyMap] the root of the map held by the storage backend
     pub fn value(&self) -> Word {
         match self {
             Self::Value(value) => *value,
             Self::Map(map) => map.root().into(),
+            Self::LazyMap(map) => map.root().into(),
         }
     }
 
@@ -78,7 +91,7 @@ impl StorageSlot {
     pub fn slot_type(&self) -> StorageSlotType {
         match self {
             StorageSlot::Value(_) => StorageSlotType::Value,
-            StorageSlot::Map(_) => StorageSlotType::Map,
+            StorageSlot::Map(_) | StorageSlot::LazyMap(_) => StorageSlotType::Map,
         }
     }
 }
@@ -93,6 +106,8 @@ impl Serializable for StorageSlot {
         match self {
             Self::Value(value) => target.write(value),
             Self::Map(map) => target.write(map),
+            Self::LazyMap(map) => target
+                .write(map.to_storage_map().expect("failed to load storage map from its backend")),
         }
     }
 
@@ -102,6 +117,8 @@ impl Serializable for StorageSlot {
         size += match self {
             StorageSlot::Value(word) => word.get_size_hint(),
             StorageSlot::Map(storage_map) => storage_map.get_size_hint(),
+            // the size of a lazy map is not known without loading it from its backend
+            StorageSlot::LazyMap(_) => 0,
         };
 
         size
@@ -1,5 +1,5 @@
-use alloc::string::ToString;
-use core::fmt;
+use alloc::string::{String, ToString};
+use core::{fmt, str::FromStr};
 
 use vm_core::{
     utils::{ByteReader, ByteWriter, Deserializable, Serializable},
@@ -8,7 +8,8 @@ use vm_core::{
 use vm_processor::DeserializationError;
 
 use super::{
-    is_not_a_non_fungible_asset, AccountId, AccountType, Asset, AssetError, Felt, Word, ZERO,
+    is_not_a_non_fungible_asset, split_asset_str, AccountId, AccountType, Asset, AssetError, Felt,
+    Word, ZERO,
 };
 
 // FUNGIBLE ASSET
@@ -47,6 +48,57 @@ impl FungibleAsset {
         asset.validate()
     }
 
+    /// Returns a fungible asset whose amount is parsed from a decimal string expressed in whole
+    /// token units, e.g., `"1.25"` with 2 decimals results in an amount of 125 base units.
+    ///
+    /// The string must consist of an integer part optionally followed by a `.` and a fractional
+    /// part, both containing only ASCII digits. The conversion is exact, i.e., the fractional part
+    /// can have at most `decimals` digits.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The string is not a valid decimal number, or its fractional part has more than `decimals`
+    ///   digits.
+    /// - The resulting amount does not fit into a u64, or is greater than 2^63 - 1.
+    /// - The faucet_id is not a valid fungible faucet ID.
+    pub fn from_units_str(
+        units: &str,
+        decimals: u8,
+        faucet_id: AccountId,
+    ) -> Result<Self, AssetError> {
+        let (integer, fraction) = units.split_once('.').unwrap_or((units, ""));
+        let is_digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+        if !is_digits(integer) || (units.contains('.') && !is_digits(fraction)) {
+            return Err(AssetError::AssetParseError(format!(
+                "invalid fungible asset amount `{units}`"
+            )));
+        }
+        if fraction.len() > decimals as usize {
+            return Err(AssetError::AssetParseError(format!(
+                "fungible asset amount `{units}` has more than {decimals} decimals"
+            )));
+        }
+
+        let too_big =
+            || AssetError::AssetParseError(format!("fungible asset amount `{units}` is too big"));
+
+        // integer * 10^decimals + fraction * 10^(decimals - fraction.len())
+        let integer_scale = 10_u64.checked_pow(decimals as u32).ok_or_else(too_big)?;
+        let fraction_scale = 10_u64.pow((decimals as usize - fraction.len()) as u32);
+        let integer = integer.parse::<u64>().map_err(|_| too_big())?;
+        let fraction = if fraction.is_empty() {
+            0
+        } else {
+            fraction.parse::<u64>().map_err(|_| too_big())?
+        };
+        let amount = integer
+            .checked_mul(integer_scale)
+            .and_then(|amount| amount.checked_add(fraction * fraction_scale))
+            .ok_or_else(too_big)?;
+
+        Self::new(faucet_id, amount)
+    }
+
     /// Creates a new [FungibleAsset] without checking its validity.
     pub(crate) fn new_unchecked(value: Word) -> FungibleAsset {
         FungibleAsset {
@@ -68,6 +120,26 @@ impl FungibleAsset {
         self.amount
     }
 
+    /// Returns the amount of this asset formatted as a decimal string in whole token units, e.g.,
+    /// an amount of 125 base units with 2 decimals is formatted as `"1.25"`.
+    ///
+    /// Trailing zeros of the fractional part are omitted, and so is the `.` if the amount is a
+    /// whole number of units.
+    pub fn format_units(&self, decimals: u8) -> String {
+        let digits = self.amount.to_string();
+        let decimals = decimals as usize;
+        // left-pad the digits with zeros so that the integer part contains at least one digit
+        let digits = format!("{digits:0>width$}", width = decimals + 1);
+        let (integer, fraction) = digits.split_at(digits.len() - decimals);
+
+        let fraction = fraction.trim_end_matches('0');
+        if fraction.is_empty() {
+            integer.to_string()
+        } else {
+            format!("{integer}.{fraction}")
+        }
+    }
+
     /// Returns true if this and the other assets were issued from the same faucet.
     pub fn is_from_same_faucet(&self, other: &Self) -> bool {
         self.faucet_id == other.faucet_id
@@ -116,6 +188,81 @@ impl FungibleAsset {
         Ok(FungibleAsset { faucet_id: self.faucet_id, amount })
     }
 
+    /// Adds two fungible assets together and returns the result.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The assets were not issued by the same faucet.
+    /// - The total amount of the assets is greater than [Self::MAX_AMOUNT].
+    pub fn checked_add(self, other: Self) -> Result<Self, AssetError> {
+        if self.faucet_id != other.faucet_id {
+            return Err(AssetError::InconsistentFaucetIds(self.faucet_id, other.faucet_id));
+        }
+
+        // both amounts are at most 2^63 - 1, so their sum cannot overflow a u64
+        let amount = self.amount + other.amount;
+        if amount > Self::MAX_AMOUNT {
+            return Err(AssetError::AmountTooBig(amount));
+        }
+
+        Ok(Self { faucet_id: self.faucet_id, amount })
+    }
+
+    /// Subtracts the other asset from this asset and returns the result.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The assets were not issued by the same faucet.
+    /// - The amount of the other asset is greater than the amount of this asset.
+    pub fn checked_sub(self, other: Self) -> Result<Self, AssetError> {
+        if self.faucet_id != other.faucet_id {
+            return Err(AssetError::InconsistentFaucetIds(self.faucet_id, other.faucet_id));
+        }
+
+        let amount = self
+            .amount
+            .checked_sub(other.amount)
+            .ok_or(AssetError::AssetAmountNotSufficient(self.amount, other.amount))?;
+
+        Ok(Self { faucet_id: self.faucet_id, amount })
+    }
+
+    /// Adds two fungible assets together and returns the result, capping the resulting amount at
+    /// [Self::MAX_AMOUNT].
+    ///
+    /// # Errors
+    /// Returns an error if the assets were not issued by the same faucet.
+    pub fn saturating_add(self, other: Self) -> Result<Self, AssetError> {
+        if self.faucet_id != other.faucet_id {
+            return Err(AssetError::InconsistentFaucetIds(self.faucet_id, other.faucet_id));
+        }
+
+        // both amounts are at most 2^63 - 1, so their sum cannot overflow a u64
+        let amount = (self.amount + other.amount).min(Self::MAX_AMOUNT);
+
+        Ok(Self { faucet_id: self.faucet_id, amount })
+    }
+
+    /// Splits this asset into two assets issued by the same faucet, where the first one contains
+    /// the specified amount and the second one contains the remainder.
+    ///
+    /// # Errors
+    /// Returns an error if this asset's amount is smaller than the requested amount.
+    pub fn try_split(self, amount: u64) -> Result<(Self, Self), AssetError> {
+        let remainder = self
+            .amount
+            .checked_sub(amount)
+            .ok_or(AssetError::AssetAmountNotSufficient(self.amount, amount))?;
+
+        Ok((
+            Self { faucet_id: self.faucet_id, amount },
+            Self {
+                faucet_id: self.faucet_id,
+                amount: remainder,
+            },
+        ))
+    }
+
     // HELPER FUNCTIONS
     // --------------------------------------------------------------------------------------------
 
@@ -154,6 +301,17 @@ impl From<FungibleAsset> for Asset {
     }
 }
 
+impl TryFrom<Asset> for FungibleAsset {
+    type Error = AssetError;
+
+    fn try_from(value: Asset) -> Result<Self, Self::Error> {
+        match value {
+            Asset::Fungible(asset) => Ok(asset),
+            Asset::NonFungible(_) => Err(AssetError::NotAFungibleAsset(value)),
+        }
+    }
+}
+
 impl TryFrom<Word> for FungibleAsset {
     type Error = AssetError;
 
@@ -169,8 +327,23 @@ impl TryFrom<Word> for FungibleAsset {
 }
 
 impl fmt::Display for FungibleAsset {
+    /// Formats the asset as `<faucet_id>:<amount>`, e.g. `0x200000000000001f:1000`.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self)
+        write!(f, "{}:{}", self.faucet_id, self.amount)
+    }
+}
+
+impl FromStr for FungibleAsset {
+    type Err = AssetError;
+
+    /// Parses a fungible asset from the `<faucet_id>:<amount>` format produced by [fmt::Display].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (faucet_id, amount) = split_asset_str(s)?;
+        let amount = amount.parse::<u64>().map_err(|err| {
+            AssetError::AssetParseError(format!("invalid fungible asset amount `{amount}`: {err}"))
+        })?;
+
+        Self::new(faucet_id, amount)
     }
 }
 
@@ -247,4 +420,109 @@ mod tests {
         let err = FungibleAsset::read_from_bytes(&asset_bytes).unwrap_err();
         assert!(matches!(err, DeserializationError::InvalidValue(_)));
     }
+
+    #[test]
+    fn test_fungible_asset_units() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let asset = |amount| FungibleAsset::new(faucet_id, amount).unwrap();
+
+        assert_eq!(asset(125).format_units(2), "1.25");
+        assert_eq!(asset(100).format_units(2), "1");
+        assert_eq!(asset(5).format_units(3), "0.005");
+        assert_eq!(asset(0).format_units(8), "0");
+        assert_eq!(asset(1230).format_units(0), "1230");
+        assert_eq!(asset(FungibleAsset::MAX_AMOUNT).format_units(30).len(), 32);
+
+        for (units, decimals, amount) in [
+            ("1.25", 2, 125),
+            ("1.2", 2, 120),
+            ("1", 2, 100),
+            ("0.005", 3, 5),
+            ("007", 0, 7),
+            ("9223372036854775807", 0, FungibleAsset::MAX_AMOUNT),
+            ("9.223372036854775807", 18, FungibleAsset::MAX_AMOUNT),
+        ] {
+            let parsed = FungibleAsset::from_units_str(units, decimals, faucet_id).unwrap();
+            assert_eq!(parsed, asset(amount));
+            assert_eq!(
+                FungibleAsset::from_units_str(&parsed.format_units(decimals), decimals, faucet_id),
+                Ok(parsed)
+            );
+        }
+
+        for (units, decimals) in [
+            ("", 2),
+            (".5", 2),
+            ("1.", 2),
+            ("-1", 2),
+            ("+1", 2),
+            ("1.2.3", 2),
+            ("1,5", 2),
+            (" 1", 2),
+            ("1.255", 2),
+            ("1.5", 0),
+            ("18446744073709551616", 0),
+            ("18446744073709551615", 1),
+            ("1", 20),
+        ] {
+            assert!(matches!(
+                FungibleAsset::from_units_str(units, decimals, faucet_id),
+                Err(AssetError::AssetParseError(_))
+            ));
+        }
+
+        // the amount must not exceed the maximum amount of a fungible asset
+        assert!(matches!(
+            FungibleAsset::from_units_str("9.223372036854775808", 18, faucet_id),
+            Err(AssetError::AmountTooBig(_))
+        ));
+
+        let non_fungible_faucet_id =
+            AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap();
+        assert!(matches!(
+            FungibleAsset::from_units_str("1", 2, non_fungible_faucet_id),
+            Err(AssetError::NotAFungibleFaucetId(..))
+        ));
+    }
+
+    #[test]
+    fn test_fungible_asset_checked_math() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let other_faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1).unwrap();
+
+        let asset = FungibleAsset::new(faucet_id, 100).unwrap();
+        let max_asset = FungibleAsset::new(faucet_id, FungibleAsset::MAX_AMOUNT).unwrap();
+        let other_asset = FungibleAsset::new(other_faucet_id, 100).unwrap();
+
+        assert_eq!(asset.checked_add(asset).unwrap().amount(), 200);
+        assert!(matches!(asset.checked_add(max_asset), Err(AssetError::AmountTooBig(_))));
+        assert!(matches!(
+            asset.checked_add(other_asset),
+            Err(AssetError::InconsistentFaucetIds(..))
+        ));
+
+        assert_eq!(asset.checked_sub(asset).unwrap().amount(), 0);
+        assert!(matches!(
+            asset.checked_sub(max_asset),
+            Err(AssetError::AssetAmountNotSufficient(100, FungibleAsset::MAX_AMOUNT))
+        ));
+        assert!(matches!(
+            asset.checked_sub(other_asset),
+            Err(AssetError::InconsistentFaucetIds(..))
+        ));
+
+        assert_eq!(asset.saturating_add(max_asset).unwrap(), max_asset);
+        assert!(matches!(
+            asset.saturating_add(other_asset),
+            Err(AssetError::InconsistentFaucetIds(..))
+        ));
+
+        let (split, remainder) = asset.try_split(30).unwrap();
+        assert_eq!(split, FungibleAsset::new(faucet_id, 30).unwrap());
+        assert_eq!(remainder, FungibleAsset::new(faucet_id, 70).unwrap());
+        assert!(matches!(
+            asset.try_split(101),
+            Err(AssetError::AssetAmountNotSufficient(100, 101))
+        ));
+    }
 }
@@ -1,3 +1,6 @@
+use alloc::string::ToString;
+use core::{fmt, str::FromStr};
+
 use super::{
     accounts::{AccountId, AccountType, ACCOUNT_ISFAUCET_MASK},
     utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
@@ -10,12 +13,21 @@ pub use fungible::FungibleAsset;
 mod nonfungible;
 pub use nonfungible::{NonFungibleAsset, NonFungibleAssetDetails};
 
+mod registry;
+pub use registry::NonFungibleAssetRegistry;
+
+mod token_metadata;
+pub use token_metadata::TokenMetadata;
+
 mod token_symbol;
 pub use token_symbol::TokenSymbol;
 
 mod vault;
 pub use vault::AssetVault;
 
+mod witness;
+pub use witness::{verify_asset_inclusion, AssetWitness};
+
 // ASSET
 // ================================================================================================
 
@@ -112,7 +124,27 @@ impl Asset {
         }
     }
 
+    /// Returns a reference to the inner fungible asset, or `None` if the asset is not fungible.
+    pub fn as_fungible(&self) -> Option<&FungibleAsset> {
+        match self {
+            Asset::Fungible(asset) => Some(asset),
+            Asset::NonFungible(_) => None,
+        }
+    }
+
+    /// Returns a reference to the inner non-fungible asset, or `None` if the asset is fungible.
+    pub fn as_non_fungible(&self) -> Option<&NonFungibleAsset> {
+        match self {
+            Asset::Fungible(_) => None,
+            Asset::NonFungible(asset) => Some(asset),
+        }
+    }
+
     /// Returns the inner fungible asset, or panics if the asset is not fungible.
+    #[deprecated(
+        since = "0.7.0",
+        note = "use `as_fungible()` or `FungibleAsset::try_from()` instead"
+    )]
     pub fn unwrap_fungible(&self) -> FungibleAsset {
         match self {
             Asset::Fungible(asset) => *asset,
@@ -121,6 +153,10 @@ impl Asset {
     }
 
     /// Returns the inner non-fungible asset, or panics if the asset is fungible.
+    #[deprecated(
+        since = "0.7.0",
+        note = "use `as_non_fungible()` or `NonFungibleAsset::try_from()` instead"
+    )]
     pub fn unwrap_non_fungible(&mut self) -> NonFungibleAsset {
         match self {
             Asset::Fungible(_) => panic!("the asset is fungible"),
@@ -164,6 +200,32 @@ impl TryFrom<Word> for Asset {
     }
 }
 
+impl fmt::Display for Asset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Asset::Fungible(asset) => asset.fmt(f),
+            Asset::NonFungible(asset) => asset.fmt(f),
+        }
+    }
+}
+
+impl FromStr for Asset {
+    type Err = AssetError;
+
+    /// Parses an asset from the `<faucet_id>:<value>` format, where the type of the asset is
+    /// determined by the type of the faucet.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (faucet_id, _) = split_asset_str(s)?;
+        match faucet_id.account_type() {
+            AccountType::FungibleFaucet => FungibleAsset::from_str(s).map(Asset::from),
+            AccountType::NonFungibleFaucet => NonFungibleAsset::from_str(s).map(Asset::from),
+            other_type => Err(AssetError::AssetParseError(format!(
+                "expected an account ID of type faucet, found {other_type:?}"
+            ))),
+        }
+    }
+}
+
 // SERIALIZATION
 // ================================================================================================
 
@@ -209,6 +271,17 @@ impl Deserializable for Asset {
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Splits a string in the `<faucet_id>:<value>` format into the parsed faucet ID and the value.
+fn split_asset_str(s: &str) -> Result<(AccountId, &str), AssetError> {
+    let (faucet_id, value) = s.split_once(':').ok_or_else(|| {
+        AssetError::AssetParseError(format!("expected `<faucet_id>:<value>`, found `{s}`"))
+    })?;
+    let faucet_id = AccountId::from_hex(faucet_id)
+        .map_err(|err| AssetError::InvalidAccountId(err.to_string()))?;
+
+    Ok((faucet_id, value))
+}
+
 /// Returns `true` if asset in [Word] is not a non-fungible asset.
 ///
 /// Note: this does not mean that the word is a fungible asset as the word may contain an value
@@ -224,13 +297,15 @@ fn is_not_a_non_fungible_asset(asset: Word) -> bool {
 
 #[cfg(test)]
 mod tests {
+    use alloc::string::ToString;
+    use core::str::FromStr;
 
     use miden_crypto::{
         utils::{Deserializable, Serializable},
         Word,
     };
 
-    use super::{Asset, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails};
+    use super::{Asset, AssetError, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails};
     use crate::accounts::{
         account_id::testing::{
             ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
@@ -295,4 +370,57 @@ mod tests {
             assert_eq!(non_fungible_asset, Asset::new_unchecked(Word::from(non_fungible_asset)));
         }
     }
+
+    #[test]
+    fn test_asset_display_from_str() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let fungible_asset: Asset = FungibleAsset::new(faucet_id, 1000).unwrap().into();
+        let fungible_str = fungible_asset.to_string();
+        assert_eq!(fungible_str, format!("{faucet_id}:1000"));
+        assert_eq!(Asset::from_str(&fungible_str).unwrap(), fungible_asset);
+
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let details = NonFungibleAssetDetails::new(faucet_id, vec![1, 2, 3]).unwrap();
+        let non_fungible_asset: Asset = NonFungibleAsset::new(&details).unwrap().into();
+        let non_fungible_str = non_fungible_asset.to_string();
+        assert_eq!(Asset::from_str(&non_fungible_str).unwrap(), non_fungible_asset);
+
+        // the faucet ID must match the one encoded in the non-fungible asset
+        let other_faucet_id =
+            AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN_1).unwrap();
+        let (_, asset_word) = non_fungible_str.split_once(':').unwrap();
+        assert!(Asset::from_str(&format!("{other_faucet_id}:{asset_word}")).is_err());
+
+        assert!(Asset::from_str("1000").is_err());
+        assert!(Asset::from_str(&format!("{faucet_id}:abc")).is_err());
+        let fungible_faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        assert!(Asset::from_str(&format!("{fungible_faucet_id}:-1")).is_err());
+        assert!(Asset::from_str(&format!("{fungible_faucet_id}:{}", u64::MAX)).is_err());
+    }
+
+    #[test]
+    fn test_asset_accessors() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let fungible = FungibleAsset::new(faucet_id, 100).unwrap();
+        let fungible_asset = Asset::from(fungible);
+        assert_eq!(fungible_asset.as_fungible(), Some(&fungible));
+        assert_eq!(fungible_asset.as_non_fungible(), None);
+        assert_eq!(FungibleAsset::try_from(fungible_asset), Ok(fungible));
+        assert_eq!(
+            NonFungibleAsset::try_from(fungible_asset),
+            Err(AssetError::NotANonFungibleAsset(fungible_asset))
+        );
+
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let details = NonFungibleAssetDetails::new(faucet_id, vec![1, 2, 3]).unwrap();
+        let non_fungible = NonFungibleAsset::new(&details).unwrap();
+        let non_fungible_asset = Asset::from(non_fungible);
+        assert_eq!(non_fungible_asset.as_fungible(), None);
+        assert_eq!(non_fungible_asset.as_non_fungible(), Some(&non_fungible));
+        assert_eq!(NonFungibleAsset::try_from(non_fungible_asset), Ok(non_fungible));
+        assert_eq!(
+            FungibleAsset::try_from(non_fungible_asset),
+            Err(AssetError::NotAFungibleAsset(non_fungible_asset))
+        );
+    }
 }
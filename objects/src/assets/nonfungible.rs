@@ -1,9 +1,12 @@
 use alloc::{string::ToString, vec::Vec};
-use core::fmt;
+use core::{fmt, str::FromStr};
 
 use vm_core::{FieldElement, WORD_SIZE};
 
-use super::{AccountId, AccountType, Asset, AssetError, Felt, Hasher, Word, ACCOUNT_ISFAUCET_MASK};
+use super::{
+    split_asset_str, AccountId, AccountType, Asset, AssetError, Felt, Hasher, Word,
+    ACCOUNT_ISFAUCET_MASK,
+};
 use crate::{
     utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
     Digest,
@@ -152,6 +155,17 @@ impl From<NonFungibleAsset> for Asset {
     }
 }
 
+impl TryFrom<Asset> for NonFungibleAsset {
+    type Error = AssetError;
+
+    fn try_from(value: Asset) -> Result<Self, Self::Error> {
+        match value {
+            Asset::NonFungible(asset) => Ok(asset),
+            Asset::Fungible(_) => Err(AssetError::NotANonFungibleAsset(value)),
+        }
+    }
+}
+
 impl TryFrom<Word> for NonFungibleAsset {
     type Error = AssetError;
 
@@ -163,8 +177,32 @@ impl TryFrom<Word> for NonFungibleAsset {
 }
 
 impl fmt::Display for NonFungibleAsset {
+    /// Formats the asset as `<faucet_id>:<asset_word>`, where the asset word is hex-encoded.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self)
+        write!(f, "{}:{}", self.faucet_id(), Digest::from(self.0).to_hex())
+    }
+}
+
+impl FromStr for NonFungibleAsset {
+    type Err = AssetError;
+
+    /// Parses a non-fungible asset from the `<faucet_id>:<asset_word>` format produced by
+    /// [fmt::Display].
+    ///
+    /// # Errors
+    /// Returns an error if the faucet ID does not match the faucet ID encoded in the asset word.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (faucet_id, asset) = split_asset_str(s)?;
+        let asset = Digest::try_from(asset).map_err(|err| {
+            AssetError::AssetParseError(format!("invalid non-fungible asset `{asset}`: {err}"))
+        })?;
+
+        let asset = Self::try_from(Word::from(asset))?;
+        if asset.faucet_id() != faucet_id {
+            return Err(AssetError::InconsistentFaucetIds(faucet_id, asset.faucet_id()));
+        }
+
+        Ok(asset)
     }
 }
 
@@ -249,6 +287,31 @@ impl NonFungibleAssetDetails {
     }
 }
 
+impl Serializable for NonFungibleAssetDetails {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write(self.faucet_id);
+        target.write_usize(self.asset_data.len());
+        target.write_bytes(&self.asset_data);
+    }
+
+    fn get_size_hint(&self) -> usize {
+        self.faucet_id.get_size_hint()
+            + self.asset_data.len().get_size_hint()
+            + self.asset_data.len()
+    }
+}
+
+impl Deserializable for NonFungibleAssetDetails {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let faucet_id: AccountId = source.read()?;
+        let data_len = source.read_usize()?;
+        let asset_data = source.read_vec(data_len)?;
+
+        Self::new(faucet_id, asset_data)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
+
 // TESTS
 // ================================================================================================
 
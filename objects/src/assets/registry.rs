@@ -0,0 +1,182 @@
+use alloc::{collections::BTreeMap, string::ToString};
+
+use super::{
+    AssetError, ByteReader, ByteWriter, Deserializable, DeserializationError, NonFungibleAsset,
+    NonFungibleAssetDetails, Serializable,
+};
+use crate::bounded_serde::read_many_bounded;
+
+// NON-FUNGIBLE ASSET REGISTRY
+// ================================================================================================
+
+/// A registry which maps non-fungible assets back to their [NonFungibleAssetDetails].
+///
+/// A [NonFungibleAsset] is a commitment to the asset data and thus cannot be used to recover the
+/// original data on its own. The registry keeps track of this association so that off-chain
+/// services can resolve assets found in vaults and notes back to their full details.
+///
+/// Entries are always keyed by the asset derived from the stored details, so a registry can never
+/// contain details which do not match their key.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NonFungibleAssetRegistry {
+    assets: BTreeMap<NonFungibleAsset, NonFungibleAssetDetails>,
+}
+
+impl NonFungibleAssetRegistry {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [NonFungibleAssetRegistry] initialized with the provided asset details.
+    ///
+    /// # Errors
+    /// Returns an error if any of the details were not issued by a non-fungible faucet.
+    pub fn new(
+        details: impl IntoIterator<Item = NonFungibleAssetDetails>,
+    ) -> Result<Self, AssetError> {
+        let mut registry = Self::default();
+        for details in details {
+            registry.insert(details)?;
+        }
+
+        Ok(registry)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the details of the specified non-fungible asset, or `None` if the asset is not
+    /// registered.
+    pub fn get(&self, asset: &NonFungibleAsset) -> Option<&NonFungibleAssetDetails> {
+        self.assets.get(asset)
+    }
+
+    /// Returns true if the specified non-fungible asset is registered.
+    pub fn contains(&self, asset: &NonFungibleAsset) -> bool {
+        self.assets.contains_key(asset)
+    }
+
+    /// Returns the number of registered assets.
+    pub fn num_assets(&self) -> usize {
+        self.assets.len()
+    }
+
+    /// Returns true if the registry contains no assets.
+    pub fn is_empty(&self) -> bool {
+        self.assets.is_empty()
+    }
+
+    /// Returns an iterator over the registered assets and their details.
+    pub fn iter(&self) -> impl Iterator<Item = (&NonFungibleAsset, &NonFungibleAssetDetails)> {
+        self.assets.iter()
+    }
+
+    // PUBLIC MODIFIERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Registers the specified asset details and returns the non-fungible asset derived from them.
+    ///
+    /// Registering the same details more than once has no effect.
+    ///
+    /// # Errors
+    /// Returns an error if the details were not issued by a non-fungible faucet.
+    pub fn insert(
+        &mut self,
+        details: NonFungibleAssetDetails,
+    ) -> Result<NonFungibleAsset, AssetError> {
+        let asset = NonFungibleAsset::new(&details)?;
+        self.assets.insert(asset, details);
+
+        Ok(asset)
+    }
+
+    /// Removes the specified non-fungible asset from the registry and returns its details, or
+    /// `None` if the asset was not registered.
+    pub fn remove(&mut self, asset: &NonFungibleAsset) -> Option<NonFungibleAssetDetails> {
+        self.assets.remove(asset)
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for NonFungibleAssetRegistry {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        // the assets are derived from the details, so only the details need to be serialized
+        target.write_usize(self.assets.len());
+        target.write_many(self.assets.values());
+    }
+
+    fn get_size_hint(&self) -> usize {
+        self.assets.len().get_size_hint()
+            + self.assets.values().map(Serializable::get_size_hint).sum::<usize>()
+    }
+}
+
+impl Deserializable for NonFungibleAssetRegistry {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_assets = source.read_usize()?;
+        // each entry consists of at least a faucet ID and a single-byte length of the asset data
+        let details = read_many_bounded::<_, NonFungibleAssetDetails>(source, num_assets, 9)?;
+
+        Self::new(details).map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::accounts::{
+        account_id::testing::{
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+            ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN_1,
+        },
+        AccountId,
+    };
+
+    #[test]
+    fn test_non_fungible_asset_registry() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let other_faucet_id =
+            AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN_1).unwrap();
+
+        let details_1 = NonFungibleAssetDetails::new(faucet_id, vec![1, 2, 3]).unwrap();
+        let details_2 = NonFungibleAssetDetails::new(other_faucet_id, vec![4, 5]).unwrap();
+
+        let mut registry = NonFungibleAssetRegistry::new([details_1.clone()]).unwrap();
+        let asset_1 = NonFungibleAsset::new(&details_1).unwrap();
+        let asset_2 = registry.insert(details_2.clone()).unwrap();
+        registry.insert(details_2.clone()).unwrap();
+
+        assert_eq!(registry.num_assets(), 2);
+        assert_eq!(registry.get(&asset_1), Some(&details_1));
+        assert_eq!(registry.get(&asset_2), Some(&details_2));
+
+        let bytes = registry.to_bytes();
+        assert_eq!(bytes.len(), registry.get_size_hint());
+        assert_eq!(NonFungibleAssetRegistry::read_from_bytes(&bytes).unwrap(), registry);
+
+        assert_eq!(registry.remove(&asset_1), Some(details_1));
+        assert!(!registry.contains(&asset_1));
+        assert_eq!(registry.num_assets(), 1);
+    }
+
+    #[test]
+    fn test_non_fungible_asset_registry_rejects_fungible_faucet() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let mut bytes = Vec::new();
+        bytes.extend(1_usize.to_bytes());
+        bytes.extend(faucet_id.to_bytes());
+        bytes.extend(1_usize.to_bytes());
+        bytes.push(7);
+
+        assert!(matches!(
+            NonFungibleAssetRegistry::read_from_bytes(&bytes),
+            Err(DeserializationError::InvalidValue(_))
+        ));
+    }
+}
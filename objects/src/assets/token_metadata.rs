@@ -0,0 +1,179 @@
+use alloc::string::ToString;
+
+use super::{
+    AssetError, ByteReader, ByteWriter, Deserializable, DeserializationError, Felt, FungibleAsset,
+    Serializable, TokenSymbol, Word, ZERO,
+};
+
+// TOKEN METADATA
+// ================================================================================================
+
+/// Metadata of a token issued by a fungible faucet.
+///
+/// The metadata consists of the token symbol, the number of decimals used to display token amounts,
+/// and the maximum supply of the token. It is encoded into a single word as follows:
+///
+/// `[max_supply, decimals, token_symbol, 0]`
+///
+/// This matches the layout of the metadata slot of the basic fungible faucet, so wallets can
+/// decode the metadata directly from the faucet's storage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TokenMetadata {
+    symbol: TokenSymbol,
+    decimals: u8,
+    max_supply: u64,
+}
+
+impl TokenMetadata {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The maximum number of decimals a token can have.
+    pub const MAX_DECIMALS: u8 = 12;
+
+    /// The maximum supply a token can have.
+    pub const MAX_SUPPLY: u64 = FungibleAsset::MAX_AMOUNT;
+
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns new [TokenMetadata] instantiated from the provided symbol, decimals and max supply.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The number of decimals is greater than [Self::MAX_DECIMALS].
+    /// - The max supply is greater than [Self::MAX_SUPPLY].
+    pub fn new(symbol: TokenSymbol, decimals: u8, max_supply: u64) -> Result<Self, AssetError> {
+        if decimals > Self::MAX_DECIMALS {
+            return Err(AssetError::TokenMetadataError(format!(
+                "decimals must be at most {}, but was {decimals}",
+                Self::MAX_DECIMALS
+            )));
+        }
+
+        if max_supply > Self::MAX_SUPPLY {
+            return Err(AssetError::TokenMetadataError(format!(
+                "max supply must be at most {}, but was {max_supply}",
+                Self::MAX_SUPPLY
+            )));
+        }
+
+        Ok(Self { symbol, decimals, max_supply })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the symbol of the token.
+    pub fn symbol(&self) -> TokenSymbol {
+        self.symbol
+    }
+
+    /// Returns the number of decimals of the token.
+    pub fn decimals(&self) -> u8 {
+        self.decimals
+    }
+
+    /// Returns the maximum supply of the token.
+    pub fn max_supply(&self) -> u64 {
+        self.max_supply
+    }
+}
+
+// CONVERSIONS
+// ================================================================================================
+
+impl From<TokenMetadata> for Word {
+    fn from(metadata: TokenMetadata) -> Self {
+        [
+            Felt::new(metadata.max_supply),
+            Felt::from(metadata.decimals),
+            metadata.symbol.into(),
+            ZERO,
+        ]
+    }
+}
+
+impl TryFrom<Word> for TokenMetadata {
+    type Error = AssetError;
+
+    fn try_from(word: Word) -> Result<Self, Self::Error> {
+        if word[3] != ZERO {
+            return Err(AssetError::TokenMetadataError(
+                "the most significant element of the metadata must be zero".to_string(),
+            ));
+        }
+
+        let decimals = u8::try_from(word[1].as_int()).map_err(|_| {
+            AssetError::TokenMetadataError(format!(
+                "decimals must fit into a u8, but was {}",
+                word[1].as_int()
+            ))
+        })?;
+        let symbol = TokenSymbol::try_from(word[2])?;
+
+        Self::new(symbol, decimals, word[0].as_int())
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for TokenMetadata {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write(Felt::from(self.symbol));
+        target.write_u8(self.decimals);
+        target.write_u64(self.max_supply);
+    }
+}
+
+impl Deserializable for TokenMetadata {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let symbol: Felt = source.read()?;
+        let decimals = source.read_u8()?;
+        let max_supply = source.read_u64()?;
+
+        let symbol = TokenSymbol::try_from(symbol)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+
+        Self::new(symbol, decimals, max_supply)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_metadata_encoding() {
+        let symbol = TokenSymbol::new("POL").unwrap();
+        let metadata = TokenMetadata::new(symbol, 8, 1_000_000).unwrap();
+
+        let word: Word = metadata.into();
+        assert_eq!(word, [Felt::new(1_000_000), Felt::new(8), symbol.into(), ZERO]);
+        assert_eq!(TokenMetadata::try_from(word).unwrap(), metadata);
+
+        assert_eq!(TokenMetadata::read_from_bytes(&metadata.to_bytes()).unwrap(), metadata);
+    }
+
+    #[test]
+    fn test_token_metadata_validation() {
+        let symbol = TokenSymbol::new("POL").unwrap();
+
+        assert!(TokenMetadata::new(symbol, TokenMetadata::MAX_DECIMALS + 1, 100).is_err());
+        assert!(TokenMetadata::new(symbol, 2, TokenMetadata::MAX_SUPPLY + 1).is_err());
+
+        let invalid_decimals = [Felt::new(100), Felt::new(256), symbol.into(), ZERO];
+        assert!(TokenMetadata::try_from(invalid_decimals).is_err());
+
+        let invalid_symbol = [Felt::new(100), Felt::new(2), Felt::new(u32::MAX as u64), ZERO];
+        assert!(TokenMetadata::try_from(invalid_symbol).is_err());
+
+        let invalid_padding = [Felt::new(100), Felt::new(2), symbol.into(), Felt::new(1)];
+        assert!(TokenMetadata::try_from(invalid_padding).is_err());
+    }
+}
@@ -2,7 +2,7 @@ use alloc::string::{String, ToString};
 
 use super::{AssetError, Felt};
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct TokenSymbol(Felt);
 
 impl TokenSymbol {
@@ -1,11 +1,14 @@
-use alloc::{string::ToString, vec::Vec};
+use alloc::collections::{BTreeMap, BTreeSet};
 
 use super::{
-    AccountId, AccountType, Asset, ByteReader, ByteWriter, Deserializable, DeserializationError,
-    FungibleAsset, NonFungibleAsset, Serializable, ZERO,
+    AccountId, AccountType, Asset, AssetWitness, ByteReader, ByteWriter, Deserializable,
+    DeserializationError, FungibleAsset, NonFungibleAsset, Serializable, Word, ZERO,
 };
 use crate::{
-    accounts::{AccountVaultDelta, NonFungibleDeltaAction},
+    accounts::{
+        AccountVaultDelta, FungibleAssetDelta, NonFungibleAssetDelta, NonFungibleDeltaAction,
+    },
+    bounded_serde::read_many_bounded,
     crypto::merkle::Smt,
     AssetVaultError, Digest,
 };
@@ -49,6 +52,15 @@ impl AssetVault {
         self.asset_tree.root()
     }
 
+    /// Returns a witness proving the content of this vault under the specified vault key.
+    ///
+    /// If the vault contains an asset under the key, the witness proves the inclusion of that
+    /// asset (see [verify_asset_inclusion()](super::verify_asset_inclusion)). Otherwise, the
+    /// witness proves that no asset is stored under the key.
+    pub fn open(&self, vault_key: Word) -> AssetWitness {
+        AssetWitness::new(vault_key, self.asset_tree.open(&Digest::from(vault_key)))
+    }
+
     /// Returns true if the specified non-fungible asset is stored in this vault.
     pub fn has_non_fungible_asset(&self, asset: Asset) -> Result<bool, AssetVaultError> {
         if asset.is_fungible() {
@@ -62,6 +74,23 @@ impl AssetVault {
         }
     }
 
+    /// Returns true if this vault contains the specified asset.
+    ///
+    /// For a fungible asset, this is the case if the vault holds at least the amount of the asset
+    /// issued by the same faucet. For a non-fungible asset, the asset itself must be stored in the
+    /// vault.
+    pub fn contains(&self, asset: &Asset) -> bool {
+        match self.asset_tree.get_value(&asset.vault_key().into()) {
+            value if value == Smt::EMPTY_VALUE => false,
+            value => match asset {
+                Asset::Fungible(asset) => {
+                    FungibleAsset::new_unchecked(value).amount() >= asset.amount()
+                },
+                Asset::NonFungible(_) => value == Word::from(asset),
+            },
+        }
+    }
+
     /// Returns the balance of the asset issued by the specified faucet. If the vault does not
     /// contain such an asset, 0 is returned.
     ///
@@ -84,6 +113,54 @@ impl AssetVault {
         self.asset_tree.entries().map(|x| Asset::new_unchecked(x.1))
     }
 
+    /// Returns an iterator over the assets stored in the vault.
+    ///
+    /// This is equivalent to [AssetVault::assets()].
+    pub fn iter(&self) -> impl Iterator<Item = Asset> + '_ {
+        self.assets()
+    }
+
+    /// Returns an iterator over the fungible assets stored in the vault.
+    pub fn fungible_assets(&self) -> impl Iterator<Item = FungibleAsset> + '_ {
+        self.assets().filter_map(|asset| match asset {
+            Asset::Fungible(asset) => Some(asset),
+            Asset::NonFungible(_) => None,
+        })
+    }
+
+    /// Returns an iterator over the non-fungible assets stored in the vault.
+    pub fn non_fungible_assets(&self) -> impl Iterator<Item = NonFungibleAsset> + '_ {
+        self.assets().filter_map(|asset| match asset {
+            Asset::Fungible(_) => None,
+            Asset::NonFungible(asset) => Some(asset),
+        })
+    }
+
+    /// Returns the number of assets stored in the vault.
+    ///
+    /// Fungible assets issued by the same faucet are stored as a single asset, while every
+    /// non-fungible asset is counted individually.
+    pub fn asset_count(&self) -> usize {
+        self.asset_tree.entries().count()
+    }
+
+    /// Returns a map from the IDs of the fungible faucets whose assets are stored in the vault to
+    /// the balance of the respective asset.
+    pub fn fungible_balance_map(&self) -> BTreeMap<AccountId, u64> {
+        self.fungible_assets()
+            .map(|asset| (asset.faucet_id(), asset.amount()))
+            .collect()
+    }
+
+    /// Returns an iterator over the assets stored in the vault which were issued by the specified
+    /// faucet.
+    ///
+    /// For a fungible faucet the iterator yields at most one asset, while for a non-fungible faucet
+    /// it yields all non-fungible assets issued by that faucet.
+    pub fn assets_by_faucet(&self, faucet_id: AccountId) -> impl Iterator<Item = Asset> + '_ {
+        self.assets().filter(move |asset| asset.faucet_id() == faucet_id)
+    }
+
     /// Returns a reference to the Sparse Merkle Tree underling this asset vault.
     pub fn asset_tree(&self) -> &Smt {
         &self.asset_tree
@@ -94,6 +171,60 @@ impl AssetVault {
         self.asset_tree.is_empty()
     }
 
+    /// Returns an [AccountVaultDelta] describing the changes needed to transform this vault into
+    /// the `other` vault.
+    ///
+    /// Applying the returned delta to this vault via [AssetVault::apply_delta()] results in a vault
+    /// with the same commitment as `other`.
+    pub fn diff(&self, other: &AssetVault) -> AccountVaultDelta {
+        let mut fungible = BTreeMap::new();
+        let mut non_fungible = BTreeMap::new();
+
+        // assets which are present in the other vault were either added or had their balance
+        // changed
+        for (key, new_value) in other.asset_tree.entries() {
+            let old_value = self.asset_tree.get_value(key);
+            if old_value == *new_value {
+                continue;
+            }
+
+            match Asset::new_unchecked(*new_value) {
+                Asset::Fungible(new) => {
+                    let old_amount = match old_value {
+                        value if value == Smt::EMPTY_VALUE => 0,
+                        value => FungibleAsset::new_unchecked(value).amount(),
+                    };
+                    // both amounts are at most 2^63 - 1, so the difference always fits into i64
+                    fungible.insert(new.faucet_id(), new.amount() as i64 - old_amount as i64);
+                },
+                Asset::NonFungible(asset) => {
+                    non_fungible.insert(asset, NonFungibleDeltaAction::Add);
+                },
+            }
+        }
+
+        // assets which are present only in this vault were removed
+        for (key, old_value) in self.asset_tree.entries() {
+            if other.asset_tree.get_value(key) != Smt::EMPTY_VALUE {
+                continue;
+            }
+
+            match Asset::new_unchecked(*old_value) {
+                Asset::Fungible(old) => {
+                    fungible.insert(old.faucet_id(), -(old.amount() as i64));
+                },
+                Asset::NonFungible(asset) => {
+                    non_fungible.insert(asset, NonFungibleDeltaAction::Remove);
+                },
+            }
+        }
+
+        AccountVaultDelta::new(
+            FungibleAssetDelta::new(fungible).expect("vault contains only valid fungible assets"),
+            NonFungibleAssetDelta::new(non_fungible),
+        )
+    }
+
     // PUBLIC MODIFIERS
     // --------------------------------------------------------------------------------------------
 
@@ -126,6 +257,67 @@ impl AssetVault {
         Ok(())
     }
 
+    /// Adds all of the specified assets to the vault.
+    ///
+    /// All assets are validated and fungible amounts are aggregated per faucet before the vault is
+    /// modified. The underlying Sparse Merkle tree is then updated in a single batch, which is
+    /// considerably faster than calling [AssetVault::add_asset()] for each asset individually.
+    ///
+    /// If an error is returned, the vault is left unchanged.
+    ///
+    /// # Errors
+    /// - If the total value of fungible assets issued by the same faucet is greater than or equal
+    ///   to 2^63.
+    /// - If the same non-fungible asset appears more than once or is already stored in the vault.
+    pub fn extend(
+        &mut self,
+        assets: impl IntoIterator<Item = Asset>,
+    ) -> Result<(), AssetVaultError> {
+        let mut fungible: BTreeMap<AccountId, FungibleAsset> = BTreeMap::new();
+        let mut non_fungible: BTreeSet<NonFungibleAsset> = BTreeSet::new();
+
+        for asset in assets {
+            match asset {
+                Asset::Fungible(asset) => {
+                    let current = match fungible.get(&asset.faucet_id()) {
+                        Some(current) => *current,
+                        None => match self.asset_tree.get_value(&asset.vault_key().into()) {
+                            current if current == Smt::EMPTY_VALUE => {
+                                FungibleAsset::new(asset.faucet_id(), 0)
+                                    .expect("fungible asset has a valid faucet ID")
+                            },
+                            current => FungibleAsset::new_unchecked(current),
+                        },
+                    };
+                    let new = current
+                        .add(asset)
+                        .map_err(AssetVaultError::AddFungibleAssetBalanceError)?;
+                    fungible.insert(new.faucet_id(), new);
+                },
+                Asset::NonFungible(asset) => {
+                    let in_vault =
+                        self.asset_tree.get_value(&asset.vault_key().into()) != Smt::EMPTY_VALUE;
+                    if in_vault || !non_fungible.insert(asset) {
+                        return Err(AssetVaultError::DuplicateNonFungibleAsset(asset));
+                    }
+                },
+            }
+        }
+
+        let entries = fungible
+            .into_values()
+            .map(Asset::Fungible)
+            .chain(non_fungible.into_iter().map(Asset::NonFungible))
+            .map(|asset| (asset.vault_key().into(), asset.into()));
+
+        let mutations = self.asset_tree.compute_mutations(entries);
+        self.asset_tree
+            .apply_mutations(mutations)
+            .expect("mutations were computed against the current tree");
+
+        Ok(())
+    }
+
     // ADD ASSET
     // --------------------------------------------------------------------------------------------
     /// Add the specified asset to the vault.
@@ -262,11 +454,12 @@ impl AssetVault {
 
 impl Serializable for AssetVault {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
-        // TODO: determine total number of assets in the vault without allocating the vector
-        let assets = self.assets().collect::<Vec<_>>();
-
-        target.write_usize(assets.len());
-        target.write_many(&assets);
+        // the assets are written one by one so that serializing a large vault does not require
+        // materializing all of its assets in memory
+        target.write_usize(self.asset_count());
+        for asset in self.assets() {
+            asset.write_into(target);
+        }
     }
 
     fn get_size_hint(&self) -> usize {
@@ -284,10 +477,205 @@ impl Serializable for AssetVault {
     }
 }
 
+impl AssetVault {
+    /// The maximum number of assets read from a serialized vault at once.
+    const DESERIALIZATION_CHUNK_SIZE: usize = 1024;
+}
+
 impl Deserializable for AssetVault {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         let num_assets = source.read_usize()?;
-        let assets = source.read_many::<Asset>(num_assets)?;
-        Self::new(&assets).map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+
+        // the assets are read in chunks of bounded size so that deserializing a large vault does
+        // not require materializing all of its assets in memory before inserting them
+        let mut vault = Self::default();
+        let mut num_remaining = num_assets;
+        while num_remaining > 0 {
+            let chunk_size = num_remaining.min(Self::DESERIALIZATION_CHUNK_SIZE);
+            // fungible assets are the smallest assets when serialized
+            let assets =
+                read_many_bounded::<_, Asset>(source, chunk_size, FungibleAsset::SERIALIZED_SIZE)?;
+
+            for asset in assets {
+                let old_value = vault.asset_tree.insert(asset.vault_key().into(), asset.into());
+                if old_value != Smt::EMPTY_VALUE {
+                    return Err(DeserializationError::InvalidValue(format!(
+                        "vault contains duplicate asset {asset:?}"
+                    )));
+                }
+            }
+
+            num_remaining -= chunk_size;
+        }
+
+        Ok(vault)
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::accounts::account_id::testing::{
+        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1,
+        ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+    };
+
+    fn fungible_asset(faucet_id: u64, amount: u64) -> Asset {
+        FungibleAsset::new(AccountId::try_from(faucet_id).unwrap(), amount)
+            .unwrap()
+            .into()
+    }
+
+    #[test]
+    fn test_asset_vault_iterators() {
+        let fungible_1 = fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, 100);
+        let fungible_2 = fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1, 50);
+        let non_fungible_1 = NonFungibleAsset::mock(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, &[1]);
+        let non_fungible_2 = NonFungibleAsset::mock(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, &[2]);
+
+        let vault =
+            AssetVault::new(&[fungible_1, fungible_2, non_fungible_1, non_fungible_2]).unwrap();
+
+        assert_eq!(vault.iter().count(), 4);
+        assert_eq!(vault.fungible_assets().count(), 2);
+        assert_eq!(vault.non_fungible_assets().count(), 2);
+
+        let faucet_assets: Vec<_> = vault.assets_by_faucet(fungible_1.faucet_id()).collect();
+        assert_eq!(faucet_assets, vec![fungible_1]);
+
+        let faucet_assets: Vec<_> = vault.assets_by_faucet(non_fungible_1.faucet_id()).collect();
+        assert_eq!(faucet_assets.len(), 2);
+        assert!(faucet_assets.contains(&non_fungible_1));
+        assert!(faucet_assets.contains(&non_fungible_2));
+    }
+
+    #[test]
+    fn test_asset_vault_summary() {
+        let fungible_1 = fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, 100);
+        let fungible_2 = fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1, 50);
+        let non_fungible_1 = NonFungibleAsset::mock(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, &[1]);
+        let non_fungible_2 = NonFungibleAsset::mock(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, &[2]);
+
+        let vault = AssetVault::new(&[fungible_1, fungible_2, non_fungible_1]).unwrap();
+
+        assert_eq!(vault.asset_count(), 3);
+        assert_eq!(AssetVault::default().asset_count(), 0);
+
+        assert_eq!(
+            vault.fungible_balance_map(),
+            BTreeMap::from([(fungible_1.faucet_id(), 100), (fungible_2.faucet_id(), 50)])
+        );
+        assert!(AssetVault::default().fungible_balance_map().is_empty());
+
+        assert!(vault.contains(&fungible_1));
+        assert!(vault.contains(&fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, 99)));
+        assert!(!vault.contains(&fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, 101)));
+        assert!(vault.contains(&non_fungible_1));
+        assert!(!vault.contains(&non_fungible_2));
+        assert!(!AssetVault::default().contains(&fungible_1));
+    }
+
+    #[test]
+    fn test_asset_vault_diff() {
+        let fungible_1 = fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, 100);
+        let fungible_2 = fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1, 50);
+        let non_fungible_1 = NonFungibleAsset::mock(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, &[1]);
+        let non_fungible_2 = NonFungibleAsset::mock(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, &[2]);
+
+        let vault = AssetVault::new(&[fungible_1, fungible_2, non_fungible_1]).unwrap();
+        let other = AssetVault::new(&[
+            fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, 30),
+            non_fungible_2,
+        ])
+        .unwrap();
+
+        let delta = vault.diff(&other);
+        assert_eq!(
+            delta,
+            AccountVaultDelta::from_iters(
+                [non_fungible_2],
+                [
+                    fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, 70),
+                    fungible_2,
+                    non_fungible_1
+                ]
+            )
+        );
+
+        let mut updated = vault.clone();
+        updated.apply_delta(&delta).unwrap();
+        assert_eq!(updated.commitment(), other.commitment());
+
+        assert!(vault.diff(&vault).is_empty());
+        assert!(AssetVault::default().diff(&AssetVault::default()).is_empty());
+    }
+
+    #[test]
+    fn test_asset_vault_extend() {
+        let fungible_1 = fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, 100);
+        let fungible_2 = fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1, 50);
+        let non_fungible_1 = NonFungibleAsset::mock(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, &[1]);
+        let non_fungible_2 = NonFungibleAsset::mock(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, &[2]);
+
+        let mut vault = AssetVault::new(&[fungible_1, non_fungible_1]).unwrap();
+        vault.extend([fungible_1, fungible_2, fungible_2, non_fungible_2]).unwrap();
+
+        let expected = AssetVault::new(&[
+            fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, 200),
+            fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1, 100),
+            non_fungible_1,
+            non_fungible_2,
+        ])
+        .unwrap();
+        assert_eq!(vault.commitment(), expected.commitment());
+
+        // duplicate non-fungible assets leave the vault unchanged
+        let non_fungible_3 = NonFungibleAsset::mock(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, &[3]);
+        assert!(matches!(
+            vault.extend([fungible_1, non_fungible_3, non_fungible_3]),
+            Err(AssetVaultError::DuplicateNonFungibleAsset(_))
+        ));
+        assert!(matches!(
+            vault.extend([non_fungible_1]),
+            Err(AssetVaultError::DuplicateNonFungibleAsset(_))
+        ));
+        assert_eq!(vault.commitment(), expected.commitment());
+
+        // fungible overflow is detected across the aggregated amounts
+        let max = fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1, FungibleAsset::MAX_AMOUNT);
+        assert!(matches!(
+            vault.extend([max]),
+            Err(AssetVaultError::AddFungibleAssetBalanceError(_))
+        ));
+        assert_eq!(vault.commitment(), expected.commitment());
+    }
+
+    #[test]
+    fn test_asset_vault_chunked_serialization() {
+        // a vault which spans multiple deserialization chunks
+        let num_assets = AssetVault::DESERIALIZATION_CHUNK_SIZE * 2 + 1;
+        let assets: Vec<Asset> = (0..num_assets as u32)
+            .map(|i| {
+                NonFungibleAsset::mock(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, &i.to_le_bytes())
+            })
+            .collect();
+        let vault = AssetVault::new(&assets).unwrap();
+
+        let bytes = vault.to_bytes();
+        assert_eq!(bytes.len(), vault.get_size_hint());
+        assert_eq!(AssetVault::read_from_bytes(&bytes).unwrap(), vault);
+
+        // duplicate assets are rejected, even if they are in different chunks
+        let mut bytes = Vec::new();
+        bytes.write_usize(num_assets + 1);
+        for asset in vault.assets().chain([assets[0]]) {
+            asset.write_into(&mut bytes);
+        }
+        assert!(AssetVault::read_from_bytes(&bytes).is_err());
     }
 }
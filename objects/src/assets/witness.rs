@@ -0,0 +1,157 @@
+use super::{
+    Asset, ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, Word,
+};
+use crate::{crypto::merkle::SmtProof, AssetVaultError, Digest, EMPTY_WORD};
+
+// ASSET WITNESS
+// ================================================================================================
+
+/// A witness proving the content of a single entry of an [super::AssetVault].
+///
+/// The witness consists of a vault key and an opening of the vault's Sparse Merkle tree at that
+/// key. It proves either that the vault contains a specific asset under the key, or that the vault
+/// contains no asset under the key, without revealing any other assets of the vault.
+///
+/// Witnesses are created via [super::AssetVault::open()] and verified against a vault root via
+/// [verify_asset_inclusion()].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssetWitness {
+    vault_key: Word,
+    proof: SmtProof,
+}
+
+impl AssetWitness {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [AssetWitness] for the provided vault key and opening.
+    pub fn new(vault_key: Word, proof: SmtProof) -> Self {
+        Self { vault_key, proof }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the vault key opened by this witness.
+    pub fn vault_key(&self) -> Word {
+        self.vault_key
+    }
+
+    /// Returns the opening of the vault key.
+    pub fn proof(&self) -> &SmtProof {
+        &self.proof
+    }
+
+    /// Returns the root of the vault computed from this witness.
+    pub fn compute_vault_root(&self) -> Digest {
+        self.proof.compute_root()
+    }
+
+    /// Returns the asset stored under the vault key according to this witness, or `None` if the
+    /// witness proves that no asset is stored under the key.
+    ///
+    /// # Errors
+    /// Returns an error if the opening does not contain the vault key or the stored value is not
+    /// a valid asset.
+    pub fn asset(&self) -> Result<Option<Asset>, AssetVaultError> {
+        let value = self
+            .proof
+            .get(&Digest::from(self.vault_key))
+            .ok_or(AssetVaultError::AssetWitnessKeyMismatch(self.vault_key))?;
+
+        if value == EMPTY_WORD {
+            return Ok(None);
+        }
+
+        Asset::try_from(value)
+            .map(Some)
+            .map_err(AssetVaultError::AssetWitnessInvalidAsset)
+    }
+
+    /// Consumes this witness and returns its vault key and opening.
+    pub fn into_parts(self) -> (Word, SmtProof) {
+        (self.vault_key, self.proof)
+    }
+}
+
+// VERIFICATION
+// ================================================================================================
+
+/// Verifies that the vault with the specified root contains the asset proven by the provided
+/// witness and returns that asset.
+///
+/// # Errors
+/// Returns an error if:
+/// - The witness does not open the specified vault root.
+/// - The witness does not prove an asset under its vault key, including when it proves that no
+///   asset is stored under the key.
+pub fn verify_asset_inclusion(
+    vault_root: Digest,
+    witness: &AssetWitness,
+) -> Result<Asset, AssetVaultError> {
+    let computed_root = witness.compute_vault_root();
+    if computed_root != vault_root {
+        return Err(AssetVaultError::AssetWitnessRootMismatch {
+            expected: vault_root,
+            actual: computed_root,
+        });
+    }
+
+    witness
+        .asset()?
+        .ok_or(AssetVaultError::AssetWitnessAssetNotFound(witness.vault_key()))
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for AssetWitness {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.vault_key.write_into(target);
+        self.proof.write_into(target);
+    }
+}
+
+impl Deserializable for AssetWitness {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let vault_key = Word::read_from(source)?;
+        let proof = SmtProof::read_from(source)?;
+
+        Ok(Self::new(vault_key, proof))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assets::AssetVault, testing::storage::build_assets};
+
+    #[test]
+    fn test_asset_witness() {
+        let (asset_0, asset_1) = build_assets();
+        let vault = AssetVault::new(&[asset_0]).unwrap();
+
+        let witness = vault.open(asset_0.vault_key());
+        assert_eq!(verify_asset_inclusion(vault.commitment(), &witness).unwrap(), asset_0);
+        assert_eq!(AssetWitness::read_from_bytes(&witness.to_bytes()).unwrap(), witness);
+
+        // a witness for a missing asset proves its absence, but not its inclusion
+        let witness = vault.open(asset_1.vault_key());
+        assert_eq!(witness.asset().unwrap(), None);
+        assert!(matches!(
+            verify_asset_inclusion(vault.commitment(), &witness),
+            Err(AssetVaultError::AssetWitnessAssetNotFound(_))
+        ));
+
+        // a witness does not verify against another vault
+        let other_vault = AssetVault::new(&[asset_1]).unwrap();
+        let witness = other_vault.open(asset_1.vault_key());
+        assert!(matches!(
+            verify_asset_inclusion(vault.commitment(), &witness),
+            Err(AssetVaultError::AssetWitnessRootMismatch { .. })
+        ));
+    }
+}
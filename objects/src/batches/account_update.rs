@@ -0,0 +1,182 @@
+use alloc::vec::Vec;
+
+use crate::{
+    accounts::{delta::AccountUpdateDetails, AccountId},
+    transaction::{ProvenTransaction, TransactionId},
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    BatchError, Digest,
+};
+
+// BATCH ACCOUNT UPDATE
+// ================================================================================================
+
+/// Describes the changes made to an account by all transactions in a batch against this account.
+///
+/// The transactions against the same account must form a chain of account states, i.e., the
+/// initial state of every transaction must be the final state of the previous transaction against
+/// the account in the batch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchAccountUpdate {
+    /// ID of the updated account.
+    account_id: AccountId,
+
+    /// The hash of the account state before the first transaction in the batch.
+    init_state_hash: Digest,
+
+    /// The hash of the account state after the last transaction in the batch.
+    final_state_hash: Digest,
+
+    /// The IDs of the transactions which updated the account, in the order of execution.
+    transactions: Vec<TransactionId>,
+
+    /// The merged details of the account updates of all transactions. For private accounts this is
+    /// set to [AccountUpdateDetails::Private].
+    details: AccountUpdateDetails,
+}
+
+impl BatchAccountUpdate {
+    /// Returns a new [BatchAccountUpdate] instantiated from the account update of the provided
+    /// transaction.
+    pub fn from_transaction(transaction: &ProvenTransaction) -> Self {
+        let update = transaction.account_update();
+
+        Self {
+            account_id: transaction.account_id(),
+            init_state_hash: update.init_state_hash(),
+            final_state_hash: update.final_state_hash(),
+            transactions: vec![transaction.id()],
+            details: update.details().clone(),
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of the updated account.
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the hash of the account state before the first transaction in the batch.
+    pub fn init_state_hash(&self) -> Digest {
+        self.init_state_hash
+    }
+
+    /// Returns the hash of the account state after the last transaction in the batch.
+    pub fn final_state_hash(&self) -> Digest {
+        self.final_state_hash
+    }
+
+    /// Returns the IDs of the transactions which updated the account.
+    pub fn transactions(&self) -> &[TransactionId] {
+        &self.transactions
+    }
+
+    /// Returns the merged details of the account updates of all transactions.
+    pub fn details(&self) -> &AccountUpdateDetails {
+        &self.details
+    }
+
+    /// Returns `true` if the account update details are for a private account.
+    pub fn is_private(&self) -> bool {
+        self.details.is_private()
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Merges the account update of the provided transaction into this update.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The initial account state of the transaction is not the final state of this update.
+    /// - The account update details of the transaction cannot be merged into this update.
+    ///
+    /// # Panics
+    /// Panics if the transaction is not against the account of this update.
+    pub fn merge_proven_tx(&mut self, transaction: &ProvenTransaction) -> Result<(), BatchError> {
+        assert_eq!(self.account_id, transaction.account_id(), "account ID mismatch");
+
+        let update = transaction.account_update();
+        self.merge(
+            update.init_state_hash(),
+            update.final_state_hash(),
+            update.details().clone(),
+            [transaction.id()],
+        )
+    }
+
+    /// Merges the provided update, describing the changes made to the account by a later batch,
+    /// into this update.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The initial account state of the provided update is not the final state of this update.
+    /// - The account update details of the provided update cannot be merged into this update.
+    ///
+    /// # Panics
+    /// Panics if the provided update is not for the account of this update.
+    pub fn merge_batch_update(&mut self, update: BatchAccountUpdate) -> Result<(), BatchError> {
+        assert_eq!(self.account_id, update.account_id, "account ID mismatch");
+
+        self.merge(
+            update.init_state_hash,
+            update.final_state_hash,
+            update.details,
+            update.transactions,
+        )
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    fn merge(
+        &mut self,
+        init_state_hash: Digest,
+        final_state_hash: Digest,
+        details: AccountUpdateDetails,
+        transactions: impl IntoIterator<Item = TransactionId>,
+    ) -> Result<(), BatchError> {
+        if init_state_hash != self.final_state_hash {
+            return Err(BatchError::InconsistentAccountStateTransition {
+                account_id: self.account_id,
+                expected: self.final_state_hash,
+                actual: init_state_hash,
+            });
+        }
+
+        let current_details = core::mem::replace(&mut self.details, AccountUpdateDetails::Private);
+        self.details = current_details
+            .merge(details)
+            .map_err(|err| BatchError::AccountUpdateMergeError(self.account_id, err))?;
+        self.final_state_hash = final_state_hash;
+        self.transactions.extend(transactions);
+
+        Ok(())
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for BatchAccountUpdate {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.account_id.write_into(target);
+        self.init_state_hash.write_into(target);
+        self.final_state_hash.write_into(target);
+        self.transactions.write_into(target);
+        self.details.write_into(target);
+    }
+}
+
+impl Deserializable for BatchAccountUpdate {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            account_id: AccountId::read_from(source)?,
+            init_state_hash: Digest::read_from(source)?,
+            final_state_hash: Digest::read_from(source)?,
+            transactions: <Vec<TransactionId>>::read_from(source)?,
+            details: AccountUpdateDetails::read_from(source)?,
+        })
+    }
+}
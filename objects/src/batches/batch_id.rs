@@ -0,0 +1,71 @@
+use alloc::{string::String, vec::Vec};
+use core::fmt::{Debug, Display};
+
+use crate::{
+    transaction::TransactionId,
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    Digest, Felt, Hasher,
+};
+
+// BATCH ID
+// ================================================================================================
+
+/// A unique identifier of a batch of transactions.
+///
+/// The batch ID is computed as a sequential hash of the IDs of the transactions in the batch, in
+/// the order in which the transactions are included in the batch.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct BatchId(Digest);
+
+impl BatchId {
+    /// Returns the [BatchId] of a batch consisting of the transactions with the provided IDs.
+    pub fn compute<'a>(tx_ids: impl IntoIterator<Item = &'a TransactionId>) -> Self {
+        let elements: Vec<Felt> =
+            tx_ids.into_iter().flat_map(|tx_id| tx_id.as_elements().to_vec()).collect();
+
+        Self(Hasher::hash_elements(&elements))
+    }
+
+    /// Returns the elements representation of this batch ID.
+    pub fn as_elements(&self) -> &[Felt] {
+        self.0.as_elements()
+    }
+
+    /// Returns a big-endian, hex-encoded string.
+    pub fn to_hex(&self) -> String {
+        self.0.to_hex()
+    }
+
+    /// Returns the digest defining this batch ID.
+    pub fn inner(&self) -> Digest {
+        self.0
+    }
+}
+
+impl Debug for BatchId {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.to_hex())
+    }
+}
+
+impl Display for BatchId {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.to_hex())
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for BatchId {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_bytes(&self.0.to_bytes());
+    }
+}
+
+impl Deserializable for BatchId {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let id = Digest::read_from(source)?;
+        Ok(Self(id))
+    }
+}
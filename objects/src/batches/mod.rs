@@ -1,2 +1,14 @@
+mod account_update;
+pub use account_update::BatchAccountUpdate;
+
+mod batch_id;
+pub use batch_id::BatchId;
+
 mod note_tree;
 pub use note_tree::BatchNoteTree;
+
+mod proposed_batch;
+pub use proposed_batch::ProposedBatch;
+
+mod proven_batch;
+pub use proven_batch::ProvenBatch;
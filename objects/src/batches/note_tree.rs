@@ -35,4 +35,9 @@ impl BatchNoteTree {
     pub fn root(&self) -> RpoDigest {
         self.0.root()
     }
+
+    /// Consumes this tree and returns the underlying [SimpleSmt<BATCH_NOTE_TREE_DEPTH>].
+    pub fn into_smt(self) -> SimpleSmt<BATCH_NOTE_TREE_DEPTH> {
+        self.0
+    }
 }
@@ -0,0 +1,258 @@
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
+
+use super::{BatchAccountUpdate, BatchId, BatchNoteTree};
+use crate::{
+    accounts::AccountId,
+    notes::{NoteHeader, NoteId},
+    transaction::{InputNoteCommitment, OutputNote, ProvenTransaction, TransactionId},
+    BatchError, Digest, MAX_ACCOUNTS_PER_BATCH, MAX_INPUT_NOTES_PER_BATCH,
+    MAX_OUTPUT_NOTES_PER_BATCH,
+};
+
+// PROPOSED BATCH
+// ================================================================================================
+
+/// A validated set of proven transactions which is ready to be proven as a batch.
+///
+/// Creating a [ProposedBatch] computes the batch-level state changes of its transactions:
+/// - The account updates of all transactions against the same account are merged into a single
+///   [BatchAccountUpdate].
+/// - Unauthenticated input notes which are created by an earlier transaction in the batch are
+///   consumed within the batch, and are therefore removed from both the input and the output notes
+///   of the batch (note erasure).
+/// - The remaining output notes are committed to by a [BatchNoteTree].
+///
+/// Unauthenticated input notes which are not created within the batch remain input notes of the
+/// batch, and must be authenticated by the block producer (see
+/// [Self::unauthenticated_input_notes()]).
+#[derive(Debug, Clone)]
+pub struct ProposedBatch {
+    id: BatchId,
+    transactions: Vec<ProvenTransaction>,
+    account_updates: BTreeMap<AccountId, BatchAccountUpdate>,
+    input_notes: Vec<InputNoteCommitment>,
+    output_notes: Vec<OutputNote>,
+    output_notes_tree: BatchNoteTree,
+    expiration_block_num: u32,
+}
+
+impl ProposedBatch {
+    /// Returns a new [ProposedBatch] built from the provided transactions.
+    ///
+    /// The transactions are included in the batch in the provided order. Transactions against the
+    /// same account must be provided in the order in which they were executed.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - No transactions are provided or the same transaction is provided more than once.
+    /// - The transactions against the same account do not form a chain of account states, or their
+    ///   account updates cannot be merged.
+    /// - Two transactions consume a note with the same nullifier.
+    /// - Two transactions create a note with the same ID.
+    /// - An unauthenticated input note has the ID of a note created in the batch, but its header
+    ///   does not match the header of the created note.
+    /// - Two unauthenticated input notes with different nullifiers have the same ID.
+    /// - A transaction creates a note which was consumed as an unauthenticated note by an earlier
+    ///   transaction in the batch.
+    /// - The number of updated accounts, input notes or output notes of the batch exceeds the
+    ///   respective batch limits.
+    pub fn new(transactions: Vec<ProvenTransaction>) -> Result<Self, BatchError> {
+        if transactions.is_empty() {
+            return Err(BatchError::EmptyBatch);
+        }
+
+        let mut tx_ids = BTreeSet::new();
+        let mut account_updates = BTreeMap::<AccountId, BatchAccountUpdate>::new();
+        let mut nullifiers = BTreeSet::new();
+        let mut input_notes = Vec::new();
+        // output notes are kept in the order of their creation; notes consumed within the batch
+        // are set to `None`
+        let mut output_notes: Vec<Option<OutputNote>> = Vec::new();
+        let mut output_note_indices = BTreeMap::<NoteId, usize>::new();
+        // IDs of the unauthenticated input notes which were not created within the batch
+        let mut unauthenticated_note_ids = BTreeSet::new();
+
+        for tx in transactions.iter() {
+            if !tx_ids.insert(tx.id()) {
+                return Err(BatchError::DuplicateTransaction(tx.id()));
+            }
+
+            match account_updates.get_mut(&tx.account_id()) {
+                Some(update) => update.merge_proven_tx(tx)?,
+                None => {
+                    account_updates
+                        .insert(tx.account_id(), BatchAccountUpdate::from_transaction(tx));
+                },
+            }
+
+            for input_note in tx.input_notes().iter() {
+                if !nullifiers.insert(input_note.nullifier()) {
+                    return Err(BatchError::DuplicateInputNote(input_note.nullifier()));
+                }
+
+                // erase unauthenticated notes created by an earlier transaction in the batch
+                let Some(header) = input_note.header() else {
+                    input_notes.push(input_note.clone());
+                    continue;
+                };
+                match output_note_indices.get(&header.id()) {
+                    Some(idx) => {
+                        // notes with the same ID but different nullifiers can only be provided by
+                        // invalid transactions
+                        let output_note = output_notes[*idx]
+                            .take()
+                            .ok_or(BatchError::DuplicateUnauthenticatedNote(header.id()))?;
+                        if output_note.hash() != header.hash() {
+                            return Err(BatchError::UnauthenticatedNoteMismatch {
+                                note_id: header.id(),
+                                expected: output_note.hash(),
+                                actual: header.hash(),
+                            });
+                        }
+                    },
+                    None => {
+                        unauthenticated_note_ids.insert(header.id());
+                        input_notes.push(input_note.clone());
+                    },
+                }
+            }
+
+            for output_note in tx.output_notes().iter() {
+                if unauthenticated_note_ids.contains(&output_note.id()) {
+                    return Err(BatchError::NoteConsumedBeforeCreation(output_note.id()));
+                }
+                if output_note_indices.insert(output_note.id(), output_notes.len()).is_some() {
+                    return Err(BatchError::DuplicateOutputNote(output_note.id()));
+                }
+                output_notes.push(Some(output_note.clone()));
+            }
+        }
+
+        let output_notes: Vec<OutputNote> = output_notes.into_iter().flatten().collect();
+
+        if account_updates.len() > MAX_ACCOUNTS_PER_BATCH {
+            return Err(BatchError::TooManyAccountUpdates(account_updates.len()));
+        }
+        if input_notes.len() > MAX_INPUT_NOTES_PER_BATCH {
+            return Err(BatchError::TooManyInputNotes(input_notes.len()));
+        }
+        if output_notes.len() > MAX_OUTPUT_NOTES_PER_BATCH {
+            return Err(BatchError::TooManyOutputNotes(output_notes.len()));
+        }
+
+        let output_notes_tree = BatchNoteTree::with_contiguous_leaves(
+            output_notes.iter().map(|note| (note.id(), note.metadata())),
+        )
+        .map_err(BatchError::NoteTreeError)?;
+
+        let id = BatchId::compute(tx_ids_in_order(&transactions).iter());
+        let expiration_block_num = transactions
+            .iter()
+            .map(ProvenTransaction::expiration_block_num)
+            .min()
+            .expect("the batch contains at least one transaction");
+
+        Ok(Self {
+            id,
+            transactions,
+            account_updates,
+            input_notes,
+            output_notes,
+            output_notes_tree,
+            expiration_block_num,
+        })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of this batch.
+    pub fn id(&self) -> BatchId {
+        self.id
+    }
+
+    /// Returns the transactions of this batch.
+    pub fn transactions(&self) -> &[ProvenTransaction] {
+        &self.transactions
+    }
+
+    /// Returns the updates of the accounts changed by the transactions of this batch.
+    pub fn account_updates(&self) -> impl Iterator<Item = &BatchAccountUpdate> {
+        self.account_updates.values()
+    }
+
+    /// Returns the notes consumed by this batch, excluding the notes created within the batch.
+    pub fn input_notes(&self) -> &[InputNoteCommitment] {
+        &self.input_notes
+    }
+
+    /// Returns the headers of the unauthenticated input notes of this batch which were not created
+    /// within the batch.
+    ///
+    /// The block producer must match these notes against the notes created in the block or
+    /// authenticate them against the chain.
+    pub fn unauthenticated_input_notes(&self) -> impl Iterator<Item = &NoteHeader> {
+        self.input_notes.iter().filter_map(InputNoteCommitment::header)
+    }
+
+    /// Checks that all unauthenticated input notes of this batch were created within the batch,
+    /// i.e., that the batch does not depend on notes which must be authenticated by the block
+    /// producer.
+    ///
+    /// # Errors
+    /// Returns an error if an unauthenticated input note of this batch was not created by an
+    /// earlier transaction in the batch.
+    pub fn validate_note_erasure(&self) -> Result<(), BatchError> {
+        match self.unauthenticated_input_notes().next() {
+            Some(header) => Err(BatchError::UnauthenticatedNoteNotFound(header.id())),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the notes created by this batch, excluding the notes consumed within the batch.
+    pub fn output_notes(&self) -> &[OutputNote] {
+        &self.output_notes
+    }
+
+    /// Returns the root of the tree of the output notes of this batch.
+    pub fn output_notes_root(&self) -> Digest {
+        self.output_notes_tree.root()
+    }
+
+    /// Returns the block number at which this batch expires, i.e., the earliest expiration block
+    /// number of its transactions.
+    pub fn expiration_block_num(&self) -> u32 {
+        self.expiration_block_num
+    }
+
+    /// Consumes this batch and returns its transactions and its batch-level state changes, i.e.,
+    /// the account updates, the input notes, the output notes and the output notes tree.
+    pub fn into_parts(
+        self,
+    ) -> (
+        Vec<ProvenTransaction>,
+        Vec<BatchAccountUpdate>,
+        Vec<InputNoteCommitment>,
+        Vec<OutputNote>,
+        BatchNoteTree,
+    ) {
+        (
+            self.transactions,
+            self.account_updates.into_values().collect(),
+            self.input_notes,
+            self.output_notes,
+            self.output_notes_tree,
+        )
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Returns the IDs of the provided transactions in the order of the transactions.
+fn tx_ids_in_order(transactions: &[ProvenTransaction]) -> Vec<TransactionId> {
+    transactions.iter().map(ProvenTransaction::id).collect()
+}
@@ -0,0 +1,133 @@
+use alloc::vec::Vec;
+
+use super::{BatchAccountUpdate, BatchId, ProposedBatch};
+use crate::{
+    transaction::{InputNoteCommitment, OutputNote, ProvenTransaction},
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    Digest,
+};
+
+// PROVEN BATCH
+// ================================================================================================
+
+/// A batch of proven transactions together with their batch-level state changes.
+///
+/// A proven batch describes the batch-level state changes of its transactions: the updates of the
+/// accounts changed by the batch, the notes consumed by the batch and the notes created by the
+/// batch. Notes which are created and consumed within the batch are not part of the proven batch.
+///
+/// A proven batch does not carry an aggregated batch proof yet; aggregating the transaction proofs
+/// requires recursive verification of transaction proofs in the VM, which is not supported yet.
+/// Instead, the batch keeps its transactions including their proofs, so that consumers which do
+/// not trust the creator of the batch can verify the transaction proofs themselves (e.g., using
+/// `TransactionVerifier::verify_batch()` with the transactions returned by
+/// [ProvenBatch::transactions()]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProvenBatch {
+    id: BatchId,
+    transactions: Vec<ProvenTransaction>,
+    account_updates: Vec<BatchAccountUpdate>,
+    input_notes: Vec<InputNoteCommitment>,
+    output_notes: Vec<OutputNote>,
+    output_notes_root: Digest,
+    expiration_block_num: u32,
+}
+
+impl ProvenBatch {
+    /// Returns a new [ProvenBatch] built from the provided proposed batch.
+    ///
+    /// This does not verify the proofs of the transactions in the proposed batch. Batches should
+    /// be proven using `LocalBatchProver`, which verifies the transaction proofs before creating
+    /// the proven batch; the proofs are kept in the batch, so consumers can verify them again.
+    pub fn new(batch: ProposedBatch) -> Self {
+        let id = batch.id();
+        let expiration_block_num = batch.expiration_block_num();
+        let (transactions, account_updates, input_notes, output_notes, output_notes_tree) =
+            batch.into_parts();
+
+        Self {
+            id,
+            transactions,
+            account_updates,
+            input_notes,
+            output_notes,
+            output_notes_root: output_notes_tree.root(),
+            expiration_block_num,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of this batch.
+    pub fn id(&self) -> BatchId {
+        self.id
+    }
+
+    /// Returns the transactions of this batch, including their proofs.
+    pub fn transactions(&self) -> &[ProvenTransaction] {
+        &self.transactions
+    }
+
+    /// Returns the updates of the accounts changed by this batch.
+    pub fn account_updates(&self) -> &[BatchAccountUpdate] {
+        &self.account_updates
+    }
+
+    /// Returns the notes consumed by this batch.
+    pub fn input_notes(&self) -> &[InputNoteCommitment] {
+        &self.input_notes
+    }
+
+    /// Returns the notes created by this batch.
+    pub fn output_notes(&self) -> &[OutputNote] {
+        &self.output_notes
+    }
+
+    /// Returns the root of the tree of the output notes of this batch.
+    pub fn output_notes_root(&self) -> Digest {
+        self.output_notes_root
+    }
+
+    /// Returns the block number at which this batch expires.
+    pub fn expiration_block_num(&self) -> u32 {
+        self.expiration_block_num
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for ProvenBatch {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.id.write_into(target);
+        self.transactions.write_into(target);
+        self.account_updates.write_into(target);
+        self.input_notes.write_into(target);
+        self.output_notes.write_into(target);
+        self.output_notes_root.write_into(target);
+        self.expiration_block_num.write_into(target);
+    }
+}
+
+impl Deserializable for ProvenBatch {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let id = BatchId::read_from(source)?;
+        let transactions = <Vec<ProvenTransaction>>::read_from(source)?;
+        let account_updates = <Vec<BatchAccountUpdate>>::read_from(source)?;
+        let input_notes = <Vec<InputNoteCommitment>>::read_from(source)?;
+        let output_notes = <Vec<OutputNote>>::read_from(source)?;
+        let output_notes_root = Digest::read_from(source)?;
+        let expiration_block_num = u32::read_from(source)?;
+
+        Ok(Self {
+            id,
+            transactions,
+            account_updates,
+            input_notes,
+            output_notes,
+            output_notes_root,
+            expiration_block_num,
+        })
+    }
+}
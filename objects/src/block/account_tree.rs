@@ -0,0 +1,311 @@
+use alloc::{collections::BTreeSet, vec::Vec};
+
+use crate::{
+    accounts::AccountId,
+    crypto::merkle::{LeafIndex, MerklePath, MutationSet, SimpleSmt},
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    AccountTreeError, Digest, Felt, Word, ACCOUNT_TREE_DEPTH, EMPTY_WORD,
+};
+
+/// A set of mutations of an [AccountTree] which can be applied to the tree it was computed
+/// against.
+pub type AccountTreeMutations =
+    MutationSet<ACCOUNT_TREE_DEPTH, LeafIndex<ACCOUNT_TREE_DEPTH>, Word>;
+
+// ACCOUNT TREE
+// ================================================================================================
+
+/// Wrapper over [SimpleSmt<ACCOUNT_TREE_DEPTH>] for the tree of the latest state hashes of all
+/// accounts on chain.
+///
+/// Account IDs are used as leaf indexes of the tree, and the value of each leaf is the hash of the
+/// latest state of the corresponding account. Leaves of accounts which are not on chain are set
+/// to [EMPTY_WORD]. The root of the tree is committed to by every block header (see
+/// [crate::BlockHeader::account_root()]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountTree(SimpleSmt<ACCOUNT_TREE_DEPTH>);
+
+impl AccountTree {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new empty [AccountTree].
+    pub fn new() -> Self {
+        Self(SimpleSmt::new().expect("depth too big for SimpleSmt"))
+    }
+
+    /// Returns a new [AccountTree] containing the provided accounts with the provided state
+    /// hashes.
+    ///
+    /// # Errors
+    /// Returns an error if the provided entries contain the same account ID more than once.
+    pub fn with_entries(
+        entries: impl IntoIterator<Item = (AccountId, Digest)>,
+    ) -> Result<Self, AccountTreeError> {
+        let mut tree = Self::new();
+        let mutations = tree.compute_mutations(entries)?;
+        tree.apply_mutations(mutations)?;
+
+        Ok(tree)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the root of the tree.
+    pub fn root(&self) -> Digest {
+        self.0.root()
+    }
+
+    /// Returns the number of accounts in the tree.
+    pub fn num_accounts(&self) -> usize {
+        self.0.leaves().count()
+    }
+
+    /// Returns the state hash of the specified account, or `None` if the account is not in the
+    /// tree.
+    pub fn get(&self, account_id: AccountId) -> Option<Digest> {
+        let state_hash = self.0.get_leaf(&account_id.into());
+        (state_hash != EMPTY_WORD).then_some(state_hash.into())
+    }
+
+    /// Returns an iterator over the accounts in the tree together with their state hashes.
+    pub fn entries(&self) -> impl Iterator<Item = (AccountId, Digest)> + '_ {
+        self.0.leaves().map(|(index, state_hash)| {
+            (AccountId::new_unchecked(Felt::new(index)), Digest::from(*state_hash))
+        })
+    }
+
+    /// Returns a witness for the specified account.
+    ///
+    /// The witness proves either that the account has a specific state hash, or that the account
+    /// is not in the tree.
+    pub fn open(&self, account_id: AccountId) -> AccountWitness {
+        let value_path = self.0.open(&account_id.into());
+        AccountWitness::new(account_id, value_path.value.into(), value_path.path)
+    }
+
+    /// Computes the mutations required to set the state hashes of the provided accounts, without
+    /// modifying the tree.
+    ///
+    /// # Errors
+    /// Returns an error if the provided updates contain the same account ID more than once.
+    pub fn compute_mutations(
+        &self,
+        updates: impl IntoIterator<Item = (AccountId, Digest)>,
+    ) -> Result<AccountTreeMutations, AccountTreeError> {
+        let mut account_ids = BTreeSet::new();
+        let mut leaves = Vec::new();
+        for (account_id, state_hash) in updates {
+            if !account_ids.insert(account_id) {
+                return Err(AccountTreeError::DuplicateAccountId(account_id));
+            }
+            leaves.push((LeafIndex::from(account_id), Word::from(state_hash)));
+        }
+
+        Ok(self.0.compute_mutations(leaves))
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Sets the state hash of the specified account and returns its previous state hash, or
+    /// `None` if the account was not in the tree.
+    pub fn insert(&mut self, account_id: AccountId, state_hash: Digest) -> Option<Digest> {
+        let prev_state_hash = self.0.insert(account_id.into(), state_hash.into());
+        (prev_state_hash != EMPTY_WORD).then_some(prev_state_hash.into())
+    }
+
+    /// Applies the provided mutations to the tree.
+    ///
+    /// # Errors
+    /// Returns an error if the mutations were not computed against the current state of the tree.
+    pub fn apply_mutations(
+        &mut self,
+        mutations: AccountTreeMutations,
+    ) -> Result<(), AccountTreeError> {
+        self.0
+            .apply_mutations(mutations)
+            .map_err(AccountTreeError::ApplyMutationsFailed)
+    }
+}
+
+impl Default for AccountTree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ACCOUNT WITNESS
+// ================================================================================================
+
+/// A witness proving the state hash of a single account in an [AccountTree].
+///
+/// The witness consists of an account ID, the state hash of the account, and the Merkle path from
+/// the leaf of the account to the root of the tree. If the account is not in the tree, the state
+/// hash is set to [Digest::default()], which proves that the account is not on chain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountWitness {
+    account_id: AccountId,
+    state_hash: Digest,
+    path: MerklePath,
+}
+
+impl AccountWitness {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [AccountWitness] for the provided account ID, state hash and Merkle path.
+    pub fn new(account_id: AccountId, state_hash: Digest, path: MerklePath) -> Self {
+        Self { account_id, state_hash, path }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of the account opened by this witness.
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the state hash of the account, or [Digest::default()] if the witness proves that
+    /// the account is not in the tree.
+    pub fn state_hash(&self) -> Digest {
+        self.state_hash
+    }
+
+    /// Returns the Merkle path from the leaf of the account to the root of the tree.
+    pub fn path(&self) -> &MerklePath {
+        &self.path
+    }
+
+    /// Verifies this witness against the specified account root.
+    ///
+    /// # Errors
+    /// Returns an error if the witness does not open the specified account root.
+    pub fn verify(&self, account_root: Digest) -> Result<(), AccountTreeError> {
+        self.path
+            .verify(self.account_id.into(), self.state_hash, &account_root)
+            .map_err(|_| AccountTreeError::WitnessVerificationFailed {
+                account_id: self.account_id,
+                account_root,
+            })
+    }
+
+    /// Consumes this witness and returns its account ID, state hash and Merkle path.
+    pub fn into_parts(self) -> (AccountId, Digest, MerklePath) {
+        (self.account_id, self.state_hash, self.path)
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for AccountWitness {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.account_id.write_into(target);
+        self.state_hash.write_into(target);
+        self.path.write_into(target);
+    }
+}
+
+impl Deserializable for AccountWitness {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let account_id = AccountId::read_from(source)?;
+        let state_hash = Digest::read_from(source)?;
+        let path = MerklePath::read_from(source)?;
+
+        Ok(Self::new(account_id, state_hash, path))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use vm_core::utils::{Deserializable, Serializable};
+
+    use super::{AccountTree, AccountWitness};
+    use crate::{
+        accounts::{
+            account_id::testing::{
+                ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+                ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+                ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+            },
+            AccountId,
+        },
+        AccountTreeError, Digest, Felt,
+    };
+
+    fn state_hash(value: u64) -> Digest {
+        Digest::from([Felt::new(value); 4])
+    }
+
+    #[test]
+    fn account_tree_mutations() {
+        let account_0 =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let account_1 =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+        let account_2 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+
+        let mut tree =
+            AccountTree::with_entries([(account_0, state_hash(1)), (account_1, state_hash(2))])
+                .unwrap();
+        assert_eq!(tree.num_accounts(), 2);
+        assert_eq!(tree.get(account_0), Some(state_hash(1)));
+        assert_eq!(tree.get(account_2), None);
+
+        // mutations are computed without modifying the tree
+        let root = tree.root();
+        let mutations = tree
+            .compute_mutations([(account_0, state_hash(3)), (account_2, state_hash(4))])
+            .unwrap();
+        assert_eq!(tree.root(), root);
+
+        tree.apply_mutations(mutations).unwrap();
+        assert_eq!(tree.get(account_0), Some(state_hash(3)));
+        assert_eq!(tree.get(account_2), Some(state_hash(4)));
+
+        let mut expected = AccountTree::new();
+        assert_eq!(expected.insert(account_1, state_hash(2)), None);
+        assert_eq!(expected.insert(account_2, state_hash(4)), None);
+        assert_eq!(expected.insert(account_0, state_hash(3)), None);
+        assert_eq!(tree.root(), expected.root());
+
+        assert!(matches!(
+            tree.compute_mutations([(account_1, state_hash(5)), (account_1, state_hash(6))]),
+            Err(AccountTreeError::DuplicateAccountId(account_id)) if account_id == account_1
+        ));
+        assert!(matches!(
+            AccountTree::with_entries([(account_0, state_hash(1)), (account_0, state_hash(1))]),
+            Err(AccountTreeError::DuplicateAccountId(_))
+        ));
+    }
+
+    #[test]
+    fn account_tree_witnesses() {
+        let account_0 =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let account_1 =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+        let tree = AccountTree::with_entries([(account_0, state_hash(1))]).unwrap();
+
+        // inclusion
+        let witness = tree.open(account_0);
+        assert_eq!(witness.state_hash(), state_hash(1));
+        assert!(witness.verify(tree.root()).is_ok());
+        assert_eq!(AccountWitness::read_from_bytes(&witness.to_bytes()).unwrap(), witness);
+
+        // non-inclusion
+        let witness = tree.open(account_1);
+        assert_eq!(witness.state_hash(), Digest::default());
+        assert!(witness.verify(tree.root()).is_ok());
+        assert!(matches!(
+            witness.verify(Digest::default()),
+            Err(AccountTreeError::WitnessVerificationFailed { .. })
+        ));
+    }
+}
@@ -5,10 +5,16 @@ use super::{
     MAX_OUTPUT_NOTES_PER_BATCH, MAX_OUTPUT_NOTES_PER_BLOCK, ZERO,
 };
 
+mod account_tree;
+pub use account_tree::{AccountTree, AccountTreeMutations, AccountWitness};
 mod header;
 pub use header::BlockHeader;
 mod note_tree;
 pub use note_tree::{BlockNoteIndex, BlockNoteTree};
+mod nullifier_tree;
+pub use nullifier_tree::{NullifierTree, NullifierWitness};
+mod proposed_block;
+pub use proposed_block::ProposedBlock;
 
 use crate::{
     accounts::{delta::AccountUpdateDetails, AccountId},
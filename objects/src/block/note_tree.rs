@@ -6,7 +6,8 @@ use miden_crypto::{
 };
 
 use crate::{
-    notes::{compute_note_hash, NoteId, NoteMetadata},
+    batches::BatchNoteTree,
+    notes::{compute_note_hash, NoteId, NoteInclusionProof, NoteMetadata},
     utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
     BlockError, BLOCK_NOTE_TREE_DEPTH, MAX_BATCHES_PER_BLOCK, MAX_OUTPUT_NOTES_PER_BATCH,
     MAX_OUTPUT_NOTES_PER_BLOCK,
@@ -14,9 +15,10 @@ use crate::{
 
 /// Wrapper over [SimpleSmt<BLOCK_NOTE_TREE_DEPTH>] for notes tree.
 ///
-/// Each note is stored as two adjacent leaves: odd leaf for id, even leaf for metadata hash.
-/// ID's leaf index is calculated as [(batch_idx * MAX_NOTES_PER_BATCH + note_idx_in_batch) * 2].
-/// Metadata hash leaf is stored the next after id leaf: [id_index + 1].
+/// Each note is stored in a single leaf with the value `hash(note_id || note_metadata)`. The
+/// leaf index of a note is calculated as `batch_idx * MAX_OUTPUT_NOTES_PER_BATCH +
+/// note_idx_in_batch`, so that the notes of each batch form a subtree of the block note tree
+/// whose root is the root of the [BatchNoteTree] of that batch.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct BlockNoteTree(SimpleSmt<BLOCK_NOTE_TREE_DEPTH>);
 
@@ -53,6 +55,35 @@ impl BlockNoteTree {
         // get the path to the leaf containing the note (path len = 16)
         self.0.open(&index.leaf_index()).path
     }
+
+    /// Returns a proof of inclusion of the note with specified batch/note indexes in the block with
+    /// the specified number, assuming that this tree is the note tree of that block.
+    pub fn open(&self, block_num: u32, index: BlockNoteIndex) -> NoteInclusionProof {
+        NoteInclusionProof::new(block_num, index.leaf_index_value(), self.get_note_path(index))
+            .expect("Unreachable: note index must be within the block note tree")
+    }
+
+    /// Inserts the note tree of the batch with the specified index as a subtree of this tree.
+    ///
+    /// Any notes previously stored in the subtree of the batch are replaced.
+    ///
+    /// # Errors
+    /// Returns an error if the batch index is greater than or equal to [MAX_BATCHES_PER_BLOCK].
+    pub fn insert_batch_note_subtree(
+        &mut self,
+        batch_idx: usize,
+        batch_note_tree: BatchNoteTree,
+    ) -> Result<(), BlockError> {
+        if batch_idx >= MAX_BATCHES_PER_BLOCK {
+            return Err(BlockError::TooManyTransactionBatches(batch_idx));
+        }
+
+        self.0
+            .set_subtree(batch_idx as u64, batch_note_tree.into_smt())
+            .expect("Unreachable: batch index must be within the block note tree");
+
+        Ok(())
+    }
 }
 
 impl Default for BlockNoteTree {
@@ -142,7 +173,58 @@ mod tests {
         Felt, ONE, ZERO,
     };
 
-    use super::BlockNoteTree;
+    use super::{BlockNoteIndex, BlockNoteTree};
+    use crate::{
+        accounts::{
+            account_id::testing::ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN, AccountId,
+        },
+        batches::BatchNoteTree,
+        notes::{NoteExecutionHint, NoteId, NoteMetadata, NoteTag, NoteType},
+        BlockHeader, Digest,
+    };
+
+    #[test]
+    fn test_batch_note_subtrees() {
+        let sender =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let metadata = NoteMetadata::new(
+            sender,
+            NoteType::Public,
+            NoteTag::from(0),
+            NoteExecutionHint::always(),
+            ZERO,
+        )
+        .unwrap();
+        let batches: Vec<Vec<NoteId>> = vec![
+            (1..4).map(|i| NoteId::from([Felt::new(i), ZERO, ZERO, ZERO])).collect(),
+            vec![],
+            (4..6).map(|i| NoteId::from([Felt::new(i), ZERO, ZERO, ZERO])).collect(),
+        ];
+
+        let mut tree = BlockNoteTree::default();
+        for (batch_idx, notes) in batches.iter().enumerate() {
+            let batch_tree = BatchNoteTree::with_contiguous_leaves(
+                notes.iter().map(|note_id| (*note_id, &metadata)),
+            )
+            .unwrap();
+            tree.insert_batch_note_subtree(batch_idx, batch_tree).unwrap();
+        }
+
+        let entries = batches.iter().enumerate().flat_map(|(batch_idx, notes)| {
+            notes.iter().enumerate().map(move |(note_idx, note_id)| {
+                (BlockNoteIndex::new(batch_idx, note_idx).unwrap(), *note_id, metadata)
+            })
+        });
+        assert_eq!(tree.root(), BlockNoteTree::with_entries(entries).unwrap().root());
+
+        // openings of the tree are valid note inclusion proofs
+        let zero = Digest::default();
+        let header =
+            BlockHeader::new(0, zero, 7, zero, zero, zero, tree.root(), zero, zero, zero, 0);
+        let proof = tree.open(7, BlockNoteIndex::new(2, 1).unwrap());
+        assert!(proof.verify(batches[2][1], &metadata, &header).is_ok());
+        assert!(proof.verify(batches[0][1], &metadata, &header).is_err());
+    }
 
     #[test]
     fn test_serialization() {
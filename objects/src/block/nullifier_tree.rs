@@ -0,0 +1,372 @@
+use alloc::{collections::BTreeSet, vec::Vec};
+
+use crate::{
+    crypto::merkle::{Smt, SmtProof},
+    notes::Nullifier,
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    Digest, Felt, NullifierTreeError, Word, EMPTY_WORD, ONE, ZERO,
+};
+
+// NULLIFIER TREE
+// ================================================================================================
+
+/// Wrapper over [Smt] for the tree of nullifiers of all notes consumed on chain.
+///
+/// The tree maps the nullifier of each consumed note to the number of the block in which the note
+/// was consumed, stored as `[block_num, 1, 0, 0]`. The second element marks the nullifier as
+/// spent, such that notes consumed in the genesis block are distinguishable from notes which have
+/// not been consumed. Nullifiers of notes which have not been consumed map to [EMPTY_WORD]. The root of the tree is committed to by every block header (see
+/// [crate::BlockHeader::nullifier_root()]).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NullifierTree(Smt);
+
+impl NullifierTree {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new empty [NullifierTree].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a new [NullifierTree] containing the provided nullifiers, each of which is marked
+    /// as consumed in the corresponding block.
+    ///
+    /// # Errors
+    /// Returns an error if the provided entries contain the same nullifier more than once.
+    pub fn with_entries(
+        entries: impl IntoIterator<Item = (Nullifier, u32)>,
+    ) -> Result<Self, NullifierTreeError> {
+        let mut tree = Self::new();
+        for (nullifier, block_num) in entries {
+            if tree.is_spent(&nullifier) {
+                return Err(NullifierTreeError::DuplicateNullifier(nullifier));
+            }
+            tree.0.insert(nullifier.inner(), block_num_to_value(block_num));
+        }
+
+        Ok(tree)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the root of the tree.
+    pub fn root(&self) -> Digest {
+        self.0.root()
+    }
+
+    /// Returns the number of nullifiers in the tree.
+    pub fn num_nullifiers(&self) -> usize {
+        self.0.entries().count()
+    }
+
+    /// Returns the number of the block in which the note with the specified nullifier was
+    /// consumed, or `None` if the note has not been consumed.
+    pub fn get_block_num(&self, nullifier: &Nullifier) -> Option<u32> {
+        value_to_block_num(self.0.get_value(&nullifier.inner()))
+            .expect("nullifier tree must contain only valid block numbers")
+    }
+
+    /// Returns true if the note with the specified nullifier has been consumed.
+    pub fn is_spent(&self, nullifier: &Nullifier) -> bool {
+        self.get_block_num(nullifier).is_some()
+    }
+
+    /// Returns an iterator over the nullifiers in the tree together with the numbers of the blocks
+    /// in which the corresponding notes were consumed.
+    pub fn entries(&self) -> impl Iterator<Item = (Nullifier, u32)> + '_ {
+        self.0.entries().map(|(nullifier, value)| {
+            let block_num = value_to_block_num(*value)
+                .ok()
+                .flatten()
+                .expect("nullifier tree must contain only valid block numbers");
+            (Nullifier::from(*nullifier), block_num)
+        })
+    }
+
+    /// Returns a witness for the specified nullifier.
+    ///
+    /// The witness proves either that the note with the specified nullifier was consumed in a
+    /// specific block, or that the note has not been consumed.
+    pub fn open(&self, nullifier: &Nullifier) -> NullifierWitness {
+        NullifierWitness::new(*nullifier, self.0.open(&nullifier.inner()))
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Marks the note with the specified nullifier as consumed in the specified block.
+    ///
+    /// # Errors
+    /// Returns an error if the nullifier is already in the tree.
+    pub fn insert(
+        &mut self,
+        nullifier: Nullifier,
+        block_num: u32,
+    ) -> Result<(), NullifierTreeError> {
+        self.insert_batch([nullifier], block_num)
+    }
+
+    /// Marks the notes with the specified nullifiers as consumed in the specified block.
+    ///
+    /// The tree is updated only if all nullifiers can be inserted, i.e., if an error is returned,
+    /// the tree is left unchanged.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Any of the nullifiers is already in the tree.
+    /// - The same nullifier is provided more than once.
+    pub fn insert_batch(
+        &mut self,
+        nullifiers: impl IntoIterator<Item = Nullifier>,
+        block_num: u32,
+    ) -> Result<(), NullifierTreeError> {
+        let mut batch = BTreeSet::new();
+        let nullifiers = nullifiers.into_iter().collect::<Vec<_>>();
+
+        for nullifier in nullifiers.iter() {
+            if let Some(block_num) = self.get_block_num(nullifier) {
+                return Err(NullifierTreeError::NullifierAlreadySpent {
+                    nullifier: *nullifier,
+                    block_num,
+                });
+            }
+            if !batch.insert(*nullifier) {
+                return Err(NullifierTreeError::DuplicateNullifier(*nullifier));
+            }
+        }
+
+        for nullifier in nullifiers {
+            self.0.insert(nullifier.inner(), block_num_to_value(block_num));
+        }
+
+        Ok(())
+    }
+}
+
+// NULLIFIER WITNESS
+// ================================================================================================
+
+/// A witness proving the state of a single nullifier in a [NullifierTree].
+///
+/// The witness consists of a nullifier and an opening of the nullifier tree at that nullifier. It
+/// proves either that the corresponding note was consumed in a specific block (inclusion), or that
+/// the note has not been consumed (non-inclusion).
+///
+/// Witnesses are created via [NullifierTree::open()] and verified against a nullifier root via
+/// [NullifierWitness::verify()].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NullifierWitness {
+    nullifier: Nullifier,
+    proof: SmtProof,
+}
+
+impl NullifierWitness {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [NullifierWitness] for the provided nullifier and opening.
+    pub fn new(nullifier: Nullifier, proof: SmtProof) -> Self {
+        Self { nullifier, proof }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the nullifier opened by this witness.
+    pub fn nullifier(&self) -> Nullifier {
+        self.nullifier
+    }
+
+    /// Returns the opening of the nullifier.
+    pub fn proof(&self) -> &SmtProof {
+        &self.proof
+    }
+
+    /// Returns the root of the nullifier tree computed from this witness.
+    pub fn compute_root(&self) -> Digest {
+        self.proof.compute_root()
+    }
+
+    /// Returns the number of the block in which the note was consumed according to this witness,
+    /// or `None` if the witness proves that the note has not been consumed.
+    ///
+    /// # Errors
+    /// Returns an error if the opening does not contain the nullifier or the stored value is not a
+    /// valid block number.
+    pub fn block_num(&self) -> Result<Option<u32>, NullifierTreeError> {
+        let value = self
+            .proof
+            .get(&self.nullifier.inner())
+            .ok_or(NullifierTreeError::WitnessKeyMismatch(self.nullifier))?;
+
+        value_to_block_num(value)
+    }
+
+    /// Verifies this witness against the specified nullifier root, and returns the number of the
+    /// block in which the note was consumed, or `None` if the note has not been consumed.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The witness does not open the specified nullifier root.
+    /// - The opening does not contain the nullifier or the stored value is not a valid block
+    ///   number.
+    pub fn verify(&self, nullifier_root: Digest) -> Result<Option<u32>, NullifierTreeError> {
+        let computed_root = self.compute_root();
+        if computed_root != nullifier_root {
+            return Err(NullifierTreeError::WitnessRootMismatch {
+                expected: nullifier_root,
+                actual: computed_root,
+            });
+        }
+
+        self.block_num()
+    }
+
+    /// Consumes this witness and returns its nullifier and opening.
+    pub fn into_parts(self) -> (Nullifier, SmtProof) {
+        (self.nullifier, self.proof)
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for NullifierWitness {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.nullifier.write_into(target);
+        self.proof.write_into(target);
+    }
+}
+
+impl Deserializable for NullifierWitness {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let nullifier = Nullifier::read_from(source)?;
+        let proof = SmtProof::read_from(source)?;
+
+        Ok(Self::new(nullifier, proof))
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the value under which a nullifier consumed in the specified block is stored.
+fn block_num_to_value(block_num: u32) -> Word {
+    [Felt::from(block_num), ONE, ZERO, ZERO]
+}
+
+/// Returns the block number stored in the provided nullifier tree value, or `None` if the value
+/// is [EMPTY_WORD].
+fn value_to_block_num(value: Word) -> Result<Option<u32>, NullifierTreeError> {
+    if value == EMPTY_WORD {
+        return Ok(None);
+    }
+
+    if value[1] != ONE || value[2..] != [ZERO; 2] {
+        return Err(NullifierTreeError::InvalidNullifierValue(value));
+    }
+
+    u32::try_from(value[0].as_int())
+        .map(Some)
+        .map_err(|_| NullifierTreeError::InvalidNullifierValue(value))
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use vm_core::utils::{Deserializable, Serializable};
+
+    use super::{NullifierTree, NullifierWitness};
+    use crate::{notes::Nullifier, Digest, Felt, NullifierTreeError, ZERO};
+
+    fn nullifier(value: u64) -> Nullifier {
+        Nullifier::from([ZERO, ZERO, ZERO, Felt::new(value)])
+    }
+
+    #[test]
+    fn nullifier_tree_insert() {
+        let mut tree = NullifierTree::new();
+        tree.insert(nullifier(1), 3).unwrap();
+        tree.insert_batch([nullifier(2), nullifier(3)], 5).unwrap();
+
+        assert_eq!(tree.num_nullifiers(), 3);
+        assert_eq!(tree.get_block_num(&nullifier(1)), Some(3));
+        assert_eq!(tree.get_block_num(&nullifier(3)), Some(5));
+        assert!(!tree.is_spent(&nullifier(4)));
+        assert_eq!(
+            tree,
+            NullifierTree::with_entries([(nullifier(3), 5), (nullifier(1), 3), (nullifier(2), 5)])
+                .unwrap()
+        );
+
+        // a batch containing a spent nullifier is rejected as a whole
+        let root = tree.root();
+        assert_eq!(
+            tree.insert_batch([nullifier(4), nullifier(2)], 6),
+            Err(NullifierTreeError::NullifierAlreadySpent {
+                nullifier: nullifier(2),
+                block_num: 5
+            })
+        );
+        assert_eq!(
+            tree.insert_batch([nullifier(4), nullifier(4)], 6),
+            Err(NullifierTreeError::DuplicateNullifier(nullifier(4)))
+        );
+        assert_eq!(tree.root(), root);
+        assert!(!tree.is_spent(&nullifier(4)));
+    }
+
+    #[test]
+    fn nullifier_tree_genesis_block() {
+        let mut tree = NullifierTree::new();
+        tree.insert(nullifier(1), 0).unwrap();
+
+        assert!(tree.is_spent(&nullifier(1)));
+        assert_eq!(tree.get_block_num(&nullifier(1)), Some(0));
+        assert_eq!(tree.entries().collect::<Vec<_>>(), vec![(nullifier(1), 0)]);
+        assert_eq!(tree.open(&nullifier(1)).verify(tree.root()), Ok(Some(0)));
+
+        // a note consumed in the genesis block cannot be consumed again
+        assert_eq!(
+            tree.insert(nullifier(1), 1),
+            Err(NullifierTreeError::NullifierAlreadySpent {
+                nullifier: nullifier(1),
+                block_num: 0
+            })
+        );
+        assert_eq!(
+            NullifierTree::with_entries([(nullifier(1), 0), (nullifier(1), 0)]),
+            Err(NullifierTreeError::DuplicateNullifier(nullifier(1)))
+        );
+    }
+
+    #[test]
+    fn nullifier_tree_witnesses() {
+        let tree = NullifierTree::with_entries([(nullifier(1), 7)]).unwrap();
+
+        // inclusion
+        let witness = tree.open(&nullifier(1));
+        assert_eq!(witness.verify(tree.root()), Ok(Some(7)));
+        assert_eq!(NullifierWitness::read_from_bytes(&witness.to_bytes()).unwrap(), witness);
+
+        // non-inclusion
+        let witness = tree.open(&nullifier(2));
+        assert_eq!(witness.verify(tree.root()), Ok(None));
+
+        assert!(matches!(
+            witness.verify(Digest::default()),
+            Err(NullifierTreeError::WitnessRootMismatch { .. })
+        ));
+        let (_, proof) = witness.into_parts();
+        assert_eq!(
+            NullifierWitness::new(nullifier(1), proof).block_num(),
+            Err(NullifierTreeError::WitnessKeyMismatch(nullifier(1)))
+        );
+    }
+}
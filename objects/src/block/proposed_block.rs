@@ -0,0 +1,252 @@
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
+
+use super::{
+    compute_tx_hash, Block, BlockAccountUpdate, BlockHeader, BlockNoteIndex, BlockNoteTree,
+    NoteBatch,
+};
+use crate::{
+    accounts::AccountId,
+    batches::{BatchAccountUpdate, BatchId, ProvenBatch},
+    errors::BlockError,
+    notes::{NoteHeader, NoteId, Nullifier},
+    transaction::{OutputNote, TransactionId},
+    Digest, MAX_ACCOUNTS_PER_BLOCK, MAX_BATCHES_PER_BLOCK, MAX_INPUT_NOTES_PER_BLOCK,
+    MAX_OUTPUT_NOTES_PER_BLOCK,
+};
+
+// PROPOSED BLOCK
+// ================================================================================================
+
+/// A set of proven batches which is ready to be proven as a block.
+///
+/// Creating a [ProposedBlock] computes the block-level state changes of its batches:
+/// - The account updates of all batches against the same account are merged into a single update.
+/// - Unauthenticated input notes which are created by an earlier batch in the block are consumed
+///   within the block, and are therefore removed from both the nullifiers and the output notes of
+///   the block.
+/// - The remaining output notes of every batch form a note batch of the block.
+///
+/// The proposed block does not depend on the state of the chain. It is the responsibility of the
+/// block prover to check the proposed block against the chain state (including authenticating the
+/// remaining unauthenticated input notes against the notes created in the chain) and to compute
+/// the header of the new block.
+#[derive(Debug, Clone)]
+pub struct ProposedBlock {
+    batch_ids: Vec<BatchId>,
+    account_updates: BTreeMap<AccountId, BatchAccountUpdate>,
+    output_note_batches: Vec<NoteBatch>,
+    nullifiers: Vec<Nullifier>,
+    unauthenticated_notes: Vec<NoteHeader>,
+    expiration_block_num: u32,
+}
+
+impl ProposedBlock {
+    /// Returns a new [ProposedBlock] built from the provided batches.
+    ///
+    /// The batches are included in the block in the provided order. Batches updating the same
+    /// account must be provided in the order in which they were built.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The number of batches exceeds [MAX_BATCHES_PER_BLOCK] or the same batch is provided more
+    ///   than once.
+    /// - The updates of the same account do not form a chain of account states, or they cannot be
+    ///   merged.
+    /// - Two batches consume a note with the same nullifier.
+    /// - Two batches create a note with the same ID.
+    /// - An unauthenticated input note created within the block does not match the created note.
+    /// - The number of updated accounts, nullifiers or output notes of the block exceeds the
+    ///   respective block limits.
+    pub fn new(batches: Vec<ProvenBatch>) -> Result<Self, BlockError> {
+        if batches.len() > MAX_BATCHES_PER_BLOCK {
+            return Err(BlockError::TooManyTransactionBatches(batches.len()));
+        }
+
+        let mut batch_ids = Vec::with_capacity(batches.len());
+        let mut account_updates = BTreeMap::<AccountId, BatchAccountUpdate>::new();
+        let mut nullifiers = BTreeSet::new();
+        let mut block_nullifiers = Vec::new();
+        let mut unauthenticated_notes = Vec::new();
+        // output notes consumed within the block are set to `None`
+        let mut output_note_batches: Vec<Vec<Option<OutputNote>>> = Vec::new();
+        let mut output_note_indices = BTreeMap::<NoteId, (usize, usize)>::new();
+        let mut expiration_block_num = u32::MAX;
+
+        for batch in batches {
+            if batch_ids.contains(&batch.id()) {
+                return Err(BlockError::DuplicateBatch(batch.id()));
+            }
+            batch_ids.push(batch.id());
+            expiration_block_num = expiration_block_num.min(batch.expiration_block_num());
+
+            for update in batch.account_updates() {
+                match account_updates.get_mut(&update.account_id()) {
+                    Some(current) => current
+                        .merge_batch_update(update.clone())
+                        .map_err(BlockError::InvalidAccountUpdate)?,
+                    None => {
+                        account_updates.insert(update.account_id(), update.clone());
+                    },
+                }
+            }
+
+            for input_note in batch.input_notes() {
+                if !nullifiers.insert(input_note.nullifier()) {
+                    return Err(BlockError::DuplicateNullifier(input_note.nullifier()));
+                }
+
+                // erase unauthenticated notes created by an earlier batch in the block
+                let Some(header) = input_note.header() else {
+                    block_nullifiers.push(input_note.nullifier());
+                    continue;
+                };
+                match output_note_indices.get(&header.id()) {
+                    Some((batch_idx, note_idx)) => {
+                        let output_note = output_note_batches[*batch_idx][*note_idx]
+                            .take()
+                            .expect("notes consumed within the block have distinct nullifiers");
+                        if output_note.hash() != header.hash() {
+                            return Err(BlockError::UnauthenticatedNoteMismatch {
+                                note_id: header.id(),
+                                expected: output_note.hash(),
+                                actual: header.hash(),
+                            });
+                        }
+                    },
+                    None => {
+                        block_nullifiers.push(input_note.nullifier());
+                        unauthenticated_notes.push(*header);
+                    },
+                }
+            }
+
+            let batch_idx = output_note_batches.len();
+            for (note_idx, output_note) in batch.output_notes().iter().enumerate() {
+                if output_note_indices.insert(output_note.id(), (batch_idx, note_idx)).is_some() {
+                    return Err(BlockError::DuplicateNoteFound(output_note.id()));
+                }
+            }
+            output_note_batches.push(batch.output_notes().iter().cloned().map(Some).collect());
+        }
+
+        let output_note_batches: Vec<NoteBatch> = output_note_batches
+            .into_iter()
+            .map(|batch| batch.into_iter().flatten().collect())
+            .collect();
+
+        if account_updates.len() > MAX_ACCOUNTS_PER_BLOCK {
+            return Err(BlockError::TooManyAccountUpdates(account_updates.len()));
+        }
+        if block_nullifiers.len() > MAX_INPUT_NOTES_PER_BLOCK {
+            return Err(BlockError::TooManyNullifiersInBlock(block_nullifiers.len()));
+        }
+        let output_note_count: usize = output_note_batches.iter().map(Vec::len).sum();
+        if output_note_count > MAX_OUTPUT_NOTES_PER_BLOCK {
+            return Err(BlockError::TooManyNotesInBlock(output_note_count));
+        }
+
+        Ok(Self {
+            batch_ids,
+            account_updates,
+            output_note_batches,
+            nullifiers: block_nullifiers,
+            unauthenticated_notes,
+            expiration_block_num,
+        })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the IDs of the batches in this block.
+    pub fn batch_ids(&self) -> &[BatchId] {
+        &self.batch_ids
+    }
+
+    /// Returns the updates of the accounts changed by the batches of this block.
+    ///
+    /// Each update contains the state of the account before the first batch and after the last
+    /// batch updating the account in this block.
+    pub fn account_updates(&self) -> impl Iterator<Item = &BatchAccountUpdate> {
+        self.account_updates.values()
+    }
+
+    /// Returns the note batches containing all notes created in this block.
+    pub fn output_note_batches(&self) -> &[NoteBatch] {
+        &self.output_note_batches
+    }
+
+    /// Returns the nullifiers of all notes consumed in this block.
+    pub fn nullifiers(&self) -> &[Nullifier] {
+        &self.nullifiers
+    }
+
+    /// Returns the headers of the unauthenticated input notes of this block which are not created
+    /// within the block, and thus need to be authenticated against the notes created in the chain.
+    pub fn unauthenticated_notes(&self) -> &[NoteHeader] {
+        &self.unauthenticated_notes
+    }
+
+    /// Returns the block number at which this block expires, i.e., the earliest expiration block
+    /// number of its batches.
+    pub fn expiration_block_num(&self) -> u32 {
+        self.expiration_block_num
+    }
+
+    /// Returns a note tree containing all notes created in this block.
+    pub fn build_note_tree(&self) -> BlockNoteTree {
+        let entries = self.output_note_batches.iter().enumerate().flat_map(|(batch_idx, notes)| {
+            notes.iter().enumerate().map(move |(note_idx_in_batch, note)| {
+                (
+                    BlockNoteIndex::new(batch_idx, note_idx_in_batch)
+                        .expect("number of batches and notes is within the block limits"),
+                    note.id(),
+                    *note.metadata(),
+                )
+            })
+        });
+
+        BlockNoteTree::with_entries(entries)
+            .expect("number of batches and notes is within the block limits")
+    }
+
+    /// Returns an iterator over all transactions which affected accounts in this block with
+    /// corresponding account IDs.
+    pub fn transactions(&self) -> impl Iterator<Item = (TransactionId, AccountId)> + '_ {
+        self.account_updates.values().flat_map(|update| {
+            update.transactions().iter().map(|tx_id| (*tx_id, update.account_id()))
+        })
+    }
+
+    /// Computes a commitment to the transactions included in this block.
+    pub fn compute_tx_hash(&self) -> Digest {
+        compute_tx_hash(self.transactions())
+    }
+
+    // CONVERSIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Consumes this proposed block and returns a [Block] with the provided header.
+    ///
+    /// # Errors
+    /// Returns an error if the resulting block is invalid.
+    pub fn into_block(self, header: BlockHeader) -> Result<Block, BlockError> {
+        let updated_accounts = self
+            .account_updates
+            .into_values()
+            .map(|update| {
+                BlockAccountUpdate::new(
+                    update.account_id(),
+                    update.final_state_hash(),
+                    update.details().clone(),
+                    update.transactions().to_vec(),
+                )
+            })
+            .collect();
+
+        Block::new(header, updated_accounts, self.output_note_batches, self.nullifiers)
+    }
+}
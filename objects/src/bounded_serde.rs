@@ -0,0 +1,132 @@
+use alloc::vec::Vec;
+
+use crate::{
+    accounts::Account,
+    notes::Note,
+    transaction::ProvenTransaction,
+    utils::serde::{ByteReader, Deserializable, DeserializationError},
+    BlockHeader, ACCOUNT_MAX_SIZE, ACCOUNT_UPDATE_MAX_SIZE, EXECUTION_PROOF_MAX_SIZE,
+    MAX_INPUT_NOTES_PER_TX, MAX_OUTPUT_NOTES_PER_TX, NOTE_MAX_SIZE,
+};
+
+// BOUNDED DESERIALIZABLE
+// ================================================================================================
+
+/// A type which can be safely deserialized from bytes received from an untrusted source.
+///
+/// Every serialized value of such a type is at most [Self::MAX_SERIALIZED_SIZE] bytes long, and the
+/// deserialization of the type never allocates memory for more elements than the provided bytes
+/// can actually contain. Thus, the memory used to deserialize a value is bounded by the size of the
+/// input, regardless of the length prefixes it contains.
+pub trait BoundedDeserializable: Deserializable {
+    /// The maximum size of a serialized value of this type, in bytes.
+    const MAX_SERIALIZED_SIZE: usize;
+
+    /// Deserializes a value of this type from bytes received from an untrusted source.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The provided bytes are longer than [Self::MAX_SERIALIZED_SIZE].
+    /// - The provided bytes are not a valid serialization of a value of this type, or contain
+    ///   trailing bytes.
+    fn read_from_untrusted_bytes(bytes: &[u8]) -> Result<Self, DeserializationError> {
+        if bytes.len() > Self::MAX_SERIALIZED_SIZE {
+            return Err(DeserializationError::InvalidValue(format!(
+                "serialized value of {} bytes exceeds the maximum size of {} bytes",
+                bytes.len(),
+                Self::MAX_SERIALIZED_SIZE
+            )));
+        }
+
+        Self::read_from_bytes(bytes)
+    }
+}
+
+impl BoundedDeserializable for Account {
+    const MAX_SERIALIZED_SIZE: usize = ACCOUNT_MAX_SIZE as usize;
+}
+
+impl BoundedDeserializable for Note {
+    const MAX_SERIALIZED_SIZE: usize = NOTE_MAX_SIZE as usize;
+}
+
+impl BoundedDeserializable for ProvenTransaction {
+    /// The account update, the input note commitments (a nullifier and an optional note header
+    /// each), the output notes, the proof, and up to 256 bytes of fixed-size fields.
+    const MAX_SERIALIZED_SIZE: usize = ACCOUNT_UPDATE_MAX_SIZE as usize
+        + MAX_INPUT_NOTES_PER_TX * 128
+        + MAX_OUTPUT_NOTES_PER_TX * NOTE_MAX_SIZE as usize
+        + EXECUTION_PROOF_MAX_SIZE as usize
+        + 256;
+}
+
+impl BoundedDeserializable for BlockHeader {
+    /// The version, block number and timestamp (4 bytes each), and 8 digests (32 bytes each).
+    const MAX_SERIALIZED_SIZE: usize = 3 * 4 + 8 * 32;
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Reads the specified number of elements from the source, after checking that the source
+/// contains at least `min_element_size` bytes for each of them.
+///
+/// Unlike [ByteReader::read_many()], this never allocates memory for more elements than the
+/// source can contain, and should be used whenever the number of elements is read from the source
+/// as a `usize`.
+pub(crate) fn read_many_bounded<R: ByteReader, D: Deserializable>(
+    source: &mut R,
+    num_elements: usize,
+    min_element_size: usize,
+) -> Result<Vec<D>, DeserializationError> {
+    source.check_eor(num_elements.saturating_mul(min_element_size))?;
+    source.read_many(num_elements)
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use vm_core::utils::{ByteWriter, Deserializable, Serializable};
+
+    use super::BoundedDeserializable;
+    use crate::{
+        accounts::{Account, AccountDelta},
+        assets::AssetVault,
+        notes::Note,
+        BlockHeader, Digest,
+    };
+
+    #[test]
+    fn oversized_values_are_rejected() {
+        let bytes = vec![0; Note::MAX_SERIALIZED_SIZE + 1];
+        assert!(Note::read_from_untrusted_bytes(&bytes).is_err());
+
+        let bytes = vec![0; Account::MAX_SERIALIZED_SIZE + 1];
+        assert!(Account::read_from_untrusted_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn block_header_max_size_is_exact() {
+        let header = BlockHeader::mock(7, None, None, &[], Digest::default());
+        let bytes = header.to_bytes();
+        assert_eq!(bytes.len(), BlockHeader::MAX_SERIALIZED_SIZE);
+        assert_eq!(BlockHeader::read_from_untrusted_bytes(&bytes).unwrap(), header);
+    }
+
+    #[test]
+    fn huge_length_prefixes_are_rejected() {
+        // a vault claiming to contain an enormous number of assets
+        let mut bytes = Vec::new();
+        bytes.write_usize(usize::MAX / 2);
+        assert!(AssetVault::read_from_bytes(&bytes).is_err());
+
+        // a delta whose fungible vault delta claims to contain an enormous number of assets
+        let mut bytes = AccountDelta::default().storage().to_bytes();
+        bytes.write_usize(usize::MAX / 2);
+        assert!(AccountDelta::read_from_bytes(&bytes).is_err());
+    }
+}
@@ -4,6 +4,15 @@ pub const ACCOUNT_TREE_DEPTH: u8 = 64;
 /// The maximum allowed size of an account update is 32 KiB.
 pub const ACCOUNT_UPDATE_MAX_SIZE: u16 = 2u16.pow(15);
 
+/// The maximum allowed size of a serialized account is 1 MiB.
+pub const ACCOUNT_MAX_SIZE: u32 = 2u32.pow(20);
+
+/// The maximum allowed size of a serialized note is 64 KiB.
+pub const NOTE_MAX_SIZE: u32 = 2u32.pow(16);
+
+/// The maximum allowed size of a serialized transaction execution proof is 1 MiB.
+pub const EXECUTION_PROOF_MAX_SIZE: u32 = 2u32.pow(20);
+
 /// The maximum number of assets that can be stored in a single note.
 pub const MAX_ASSETS_PER_NOTE: usize = 255;
 
@@ -19,6 +28,9 @@ pub const MAX_INPUT_NOTES_PER_TX: usize = 1024;
 /// The maximum number of new notes created by a single transaction.
 pub const MAX_OUTPUT_NOTES_PER_TX: usize = MAX_INPUT_NOTES_PER_TX;
 
+/// The version of the transaction kernel used to execute transactions.
+pub const TX_KERNEL_VERSION: u8 = 0;
+
 /// The minimum proof security level used by the Miden prover & verifier.
 pub const MIN_PROOF_SECURITY_LEVEL: u32 = 96;
 
@@ -0,0 +1,175 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::utils::serde::{
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
+};
+
+// SCRIPT DEBUG INFO
+// ================================================================================================
+
+/// Debug information of a note script or a transaction script.
+///
+/// The debug information retains the MASM source code from which a script was compiled, together
+/// with a map from byte offsets in the source code to line and column numbers. Scripts compiled
+/// with debug information are compiled in debug mode, so that the source locations recorded in
+/// their MAST can be mapped to lines of the retained source code via [Self::line_column()].
+///
+/// Debug information does not affect the MAST root of the script. It is never part of the
+/// regular serialized form of a script, and thus, is stripped whenever a script is serialized as
+/// part of another object (e.g., a note, a transaction or a block). It is only retained in the
+/// debug section written by
+/// [NoteScript::to_bytes_with_debug_info()](crate::notes::NoteScript::to_bytes_with_debug_info)
+/// and [TransactionScript::to_bytes_with_debug_info()](crate::transaction::TransactionScript::to_bytes_with_debug_info).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptDebugInfo {
+    source: String,
+    /// Byte offsets of the first character of every line of the source code.
+    line_starts: Vec<usize>,
+}
+
+impl ScriptDebugInfo {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The version of the encoding of the debug section of serialized scripts.
+    pub const DEBUG_SECTION_VERSION: u8 = 1;
+
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns new [ScriptDebugInfo] for the provided source code.
+    pub fn new(source: impl Into<String>) -> Self {
+        let source = source.into();
+        let line_starts = core::iter::once(0)
+            .chain(source.match_indices('\n').map(|(idx, _)| idx + 1))
+            .collect();
+
+        Self { source, line_starts }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the source code of the script.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns the number of lines of the source code.
+    pub fn num_lines(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// Returns the line with the specified 1-based number, without the line terminator, or `None`
+    /// if the source code does not contain such a line.
+    pub fn line(&self, line_num: usize) -> Option<&str> {
+        let start = *self.line_starts.get(line_num.checked_sub(1)?)?;
+        let end = self.line_starts.get(line_num).map_or(self.source.len(), |end| end - 1);
+
+        Some(self.source[start..end].trim_end_matches('\r'))
+    }
+
+    /// Returns the 1-based line and column numbers of the specified byte offset in the source
+    /// code, or `None` if the offset is outside of the source code.
+    pub fn line_column(&self, offset: usize) -> Option<(usize, usize)> {
+        if offset > self.source.len() {
+            return None;
+        }
+
+        let line_idx = self.line_starts.partition_point(|&start| start <= offset) - 1;
+        Some((line_idx + 1, offset - self.line_starts[line_idx] + 1))
+    }
+}
+
+// DEBUG SECTION
+// ================================================================================================
+
+/// Writes the debug section of a serialized script, which consists of the version of its encoding
+/// followed by the optional debug info of the script.
+pub(crate) fn write_debug_section<W: ByteWriter>(
+    debug_info: Option<&ScriptDebugInfo>,
+    target: &mut W,
+) {
+    target.write_u8(ScriptDebugInfo::DEBUG_SECTION_VERSION);
+    debug_info.cloned().write_into(target);
+}
+
+/// Reads the debug section of a serialized script written by [write_debug_section()].
+///
+/// # Errors
+/// Returns an error if the version of the debug section is not supported or the debug info cannot
+/// be deserialized.
+pub(crate) fn read_debug_section<R: ByteReader>(
+    source: &mut R,
+) -> Result<Option<ScriptDebugInfo>, DeserializationError> {
+    let version = source.read_u8()?;
+    if version != ScriptDebugInfo::DEBUG_SECTION_VERSION {
+        return Err(DeserializationError::InvalidValue(format!(
+            "unsupported debug section version {version}"
+        )));
+    }
+
+    Option::<ScriptDebugInfo>::read_from(source)
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for ScriptDebugInfo {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_usize(self.source.len());
+        target.write_bytes(self.source.as_bytes());
+    }
+}
+
+impl Deserializable for ScriptDebugInfo {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let len = source.read_usize()?;
+        let source = String::from_utf8(source.read_vec(len)?)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+
+        Ok(Self::new(source))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use miden_crypto::utils::SliceReader;
+
+    use super::{read_debug_section, write_debug_section, ScriptDebugInfo};
+    use crate::utils::serde::{Deserializable, Serializable};
+
+    #[test]
+    fn script_debug_info_maps_offsets_to_lines() {
+        let debug_info = ScriptDebugInfo::new("begin\n    push.1\r\n    drop\nend");
+
+        assert_eq!(debug_info.num_lines(), 4);
+        assert_eq!(debug_info.line(1), Some("begin"));
+        assert_eq!(debug_info.line(2), Some("    push.1"));
+        assert_eq!(debug_info.line(4), Some("end"));
+        assert_eq!(debug_info.line(0), None);
+        assert_eq!(debug_info.line(5), None);
+
+        assert_eq!(debug_info.line_column(0), Some((1, 1)));
+        assert_eq!(debug_info.line_column(10), Some((2, 5)));
+        assert_eq!(debug_info.line_column(27), Some((4, 1)));
+        assert_eq!(debug_info.line_column(30), Some((4, 4)));
+        assert_eq!(debug_info.line_column(31), None);
+
+        let bytes = debug_info.to_bytes();
+        assert_eq!(ScriptDebugInfo::read_from_bytes(&bytes).unwrap(), debug_info);
+
+        // debug sections with an unknown version are rejected
+        let mut bytes = vec![];
+        write_debug_section(Some(&debug_info), &mut bytes);
+        assert_eq!(read_debug_section(&mut SliceReader::new(&bytes)).unwrap(), Some(debug_info));
+        bytes[0] = ScriptDebugInfo::DEBUG_SECTION_VERSION + 1;
+        assert!(read_debug_section(&mut SliceReader::new(&bytes)).is_err());
+    }
+}
@@ -7,14 +7,15 @@ use super::{
     accounts::{AccountId, StorageSlotType},
     assets::{Asset, FungibleAsset, NonFungibleAsset},
     crypto::merkle::MerkleError,
-    notes::NoteId,
+    notes::{NoteId, Nullifier},
     Digest, Word, MAX_ACCOUNTS_PER_BLOCK, MAX_BATCHES_PER_BLOCK, MAX_INPUT_NOTES_PER_BLOCK,
     MAX_OUTPUT_NOTES_PER_BATCH, MAX_OUTPUT_NOTES_PER_BLOCK,
 };
 use crate::{
     accounts::{delta::AccountUpdateDetails, AccountType},
+    batches::BatchId,
     notes::NoteType,
-    ACCOUNT_UPDATE_MAX_SIZE,
+    transaction::TransactionId,
 };
 
 // ACCOUNT ERROR
@@ -32,10 +33,13 @@ pub enum AccountError {
     },
     AccountCodeProcedureInvalidStorageOffset,
     AccountCodeProcedureInvalidStorageSize,
-    AccountCodeProcedureInvalidPadding,
+    AccountCodeProcedureInvalidNoncePolicy,
+    AccountCodeProcedureInvalidReadOnlyFlag,
     AccountIdInvalidFieldElement(String),
     AccountIdTooFewOnes(u32, u32),
+    AccountIdUnknownVersion(u8),
     AssetVaultUpdateError(AssetVaultError),
+    Bech32ParseError(String),
     BuildError(String, Option<Box<AccountError>>),
     DuplicateStorageItems(MerkleError),
     FungibleFaucetIdInvalidFirstBit,
@@ -44,6 +48,10 @@ pub enum AccountError {
     HexParseError(String),
     InvalidAccountStorageMode,
     MapsUpdateToNonMapsSlot(u8, StorageSlotType),
+    MultisigInvalidThreshold {
+        threshold: u32,
+        num_keys: usize,
+    },
     NonceNotMonotonicallyIncreasing {
         current: u64,
         new: u64,
@@ -52,8 +60,41 @@ pub enum AccountError {
         expected: u32,
         actual: u32,
     },
+    SeedGrindingDifficultyTooHigh {
+        max: u32,
+        actual: u32,
+    },
+    SnapshotCommitmentMismatch {
+        expected: Digest,
+        actual: Digest,
+    },
+    SnapshotDuplicateSection(u8),
+    SnapshotInvalidMagic,
+    SnapshotMissingSection(u8),
+    SnapshotParseError(DeserializationError),
+    SnapshotUnsupportedVersion {
+        max: u8,
+        actual: u8,
+    },
+    SpendingLimitInvalidConfig(String),
+    StorageArrayIndexOutOfBounds {
+        len: u32,
+        actual: u32,
+    },
+    StorageArrayTooLong(usize),
+    StorageBackendError(String),
+    StorageSchemaDuplicateSlotName(String),
+    StorageSchemaNumSlotsMismatch {
+        expected: usize,
+        actual: usize,
+    },
+    StorageSchemaSlotNotFound(String),
+    StorageSchemaSlotTypeMismatch(String),
+    StorageSlotNotArray(u8),
+    StorageSlotNotInMemory(u8),
     StorageSlotNotMap(u8),
     StorageSlotNotValue(u8),
+    StorageSlotValueConversionError(String),
     StorageIndexOutOfBounds {
         max: u8,
         actual: u8,
@@ -63,6 +104,8 @@ pub enum AccountError {
         max: u8,
         actual: u16,
     },
+    PartialAccountInvalidOpening(String),
+    PartialAccountStorageHeaderMismatch,
     PureProcedureWithStorageOffset,
     UnsupportedComponentForAccountType {
         account_type: AccountType,
@@ -93,6 +136,12 @@ impl std::error::Error for AccountError {}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AccountDeltaError {
+    CompactDeltaInvalidOpening(String),
+    CompactDeltaMissingOpening(Word),
+    CompactDeltaStateMismatch {
+        expected: Digest,
+        actual: Digest,
+    },
     DuplicateStorageItemUpdate(usize),
     DuplicateNonFungibleVaultUpdate(NonFungibleAsset),
     FungibleAssetDeltaOverflow {
@@ -102,7 +151,18 @@ pub enum AccountDeltaError {
     },
     IncompatibleAccountUpdates(AccountUpdateDetails, AccountUpdateDetails),
     InconsistentNonceUpdate(String),
+    InvalidFinalStorage(AccountError),
+    InvalidInitialStorage(AccountError),
     NotAFungibleFaucetId(AccountId),
+    StorageCommitmentMismatch {
+        expected: Digest,
+        actual: Digest,
+    },
+    StorageSlotCountMismatch {
+        expected: usize,
+        actual: usize,
+    },
+    StorageSlotTypeMismatch(u8),
 }
 
 #[cfg(feature = "std")]
@@ -121,15 +181,19 @@ impl fmt::Display for AccountDeltaError {
 pub enum AssetError {
     AmountTooBig(u64),
     AssetAmountNotSufficient(u64, u64),
+    AssetParseError(String),
     FungibleAssetInvalidTag(u32),
     FungibleAssetInvalidWord(Word),
     InconsistentFaucetIds(AccountId, AccountId),
     InvalidAccountId(String),
     InvalidFieldElement(String),
     NonFungibleAssetInvalidTag(u32),
+    NotAFungibleAsset(Asset),
     NotAFungibleFaucetId(AccountId, AccountType),
+    NotANonFungibleAsset(Asset),
     NotANonFungibleFaucetId(AccountId),
     NotAnAsset(Word),
+    TokenMetadataError(String),
     TokenSymbolError(String),
 }
 
@@ -148,6 +212,10 @@ impl std::error::Error for AssetError {}
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AssetVaultError {
     AddFungibleAssetBalanceError(AssetError),
+    AssetWitnessAssetNotFound(Word),
+    AssetWitnessInvalidAsset(AssetError),
+    AssetWitnessKeyMismatch(Word),
+    AssetWitnessRootMismatch { expected: Digest, actual: Digest },
     DuplicateAsset(MerkleError),
     DuplicateNonFungibleAsset(NonFungibleAsset),
     FungibleAssetNotFound(FungibleAsset),
@@ -173,24 +241,40 @@ impl std::error::Error for AssetVaultError {}
 pub enum NoteError {
     DuplicateFungibleAsset(AccountId),
     DuplicateNonFungibleAsset(NonFungibleAsset),
+    EncryptedNoteIdMismatch { expected: NoteId, actual: NoteId },
+    InclusionProofBlockMismatch { expected: u32, actual: u32 },
     InconsistentNoteTag(NoteType, u64),
     InvalidAssetData(AssetError),
     InvalidNoteSender(AccountError),
     InvalidNoteTagUseCase(u16),
     InvalidNoteExecutionHintTag(u8),
     InvalidNoteExecutionHintPayload(u8, u32),
+    InvalidNoteExecutionMode(u8),
+    InvalidNoteInputs(String),
+    InvalidNullifierProofValue(Word),
     InvalidNoteType(NoteType),
     InvalidNoteTypeValue(u64),
     InvalidLocationIndex(String),
     InvalidStubDataLen(usize),
+    InvalidSwapFill { offered_fill: u64, requested_fill: u64 },
     NetworkExecutionRequiresOnChainAccount,
     NetworkExecutionRequiresPublicNote(NoteType),
+    NoteDecryptionFailed,
     NoteDeserializationError(DeserializationError),
+    NoteInputsSchemaDuplicateField(String),
+    NoteInputsSchemaFieldNotFound(String),
+    NoteInputsSchemaFieldTypeMismatch(String),
+    NoteNotInBlock(NoteId, u32),
     NoteScriptAssemblyError(String), // TODO: use Report
     NoteScriptDeserializationError(DeserializationError),
+    NullifierProofKeyMismatch(Nullifier),
+    NullifierProofRootMismatch { expected: Digest, actual: Digest },
     PublicUseCaseRequiresPublicNote(NoteType),
+    RecipientDigestMismatch { expected: Digest, actual: Digest },
     TooManyAssets(usize),
     TooManyInputs(usize),
+    TooManyNoteArgs(usize),
+    UnknownNoteScript(Digest),
 }
 
 impl NoteError {
@@ -224,25 +308,83 @@ impl fmt::Display for NoteError {
 #[cfg(feature = "std")]
 impl std::error::Error for NoteError {}
 
+// ACCOUNT TREE ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccountTreeError {
+    ApplyMutationsFailed(MerkleError),
+    DuplicateAccountId(AccountId),
+    WitnessVerificationFailed {
+        account_id: AccountId,
+        account_root: Digest,
+    },
+}
+
+impl fmt::Display for AccountTreeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AccountTreeError {}
+
+// NULLIFIER TREE ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NullifierTreeError {
+    DuplicateNullifier(Nullifier),
+    InvalidNullifierValue(Word),
+    NullifierAlreadySpent { nullifier: Nullifier, block_num: u32 },
+    WitnessKeyMismatch(Nullifier),
+    WitnessRootMismatch { expected: Digest, actual: Digest },
+}
+
+impl fmt::Display for NullifierTreeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NullifierTreeError {}
+
 // CHAIN MMR ERROR
 // ================================================================================================
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ChainMmrError {
+    BlockNumMismatch { expected: u32, actual: u32 },
     BlockNumTooBig { chain_length: usize, block_num: u32 },
+    ChainRootMismatch { block_num: u32 },
     DuplicateBlock { block_num: u32 },
+    InvalidBlockPath { block_num: u32 },
     UntrackedBlock { block_num: u32 },
 }
 
 impl ChainMmrError {
+    pub fn block_num_mismatch(expected: u32, actual: u32) -> Self {
+        Self::BlockNumMismatch { expected, actual }
+    }
+
     pub fn block_num_too_big(chain_length: usize, block_num: u32) -> Self {
         Self::BlockNumTooBig { chain_length, block_num }
     }
 
+    pub fn chain_root_mismatch(block_num: u32) -> Self {
+        Self::ChainRootMismatch { block_num }
+    }
+
     pub fn duplicate_block(block_num: u32) -> Self {
         Self::DuplicateBlock { block_num }
     }
 
+    pub fn invalid_block_path(block_num: u32) -> Self {
+        Self::InvalidBlockPath { block_num }
+    }
+
     pub fn untracked_block(block_num: u32) -> Self {
         Self::UntrackedBlock { block_num }
     }
@@ -257,6 +399,28 @@ impl fmt::Display for ChainMmrError {
 #[cfg(feature = "std")]
 impl std::error::Error for ChainMmrError {}
 
+// PROTO CONVERSION ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProtoConversionError {
+    AccountError(AccountError),
+    DeserializationError(DeserializationError),
+    InconsistentField(&'static str),
+    InvalidFieldElement(u64),
+    MissingField(&'static str),
+    NoteError(NoteError),
+}
+
+impl fmt::Display for ProtoConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ProtoConversionError {}
+
 // TRANSACTION SCRIPT ERROR
 // ================================================================================================
 
@@ -282,6 +446,10 @@ pub enum TransactionInputError {
     AccountSeedNotProvidedForNewAccount,
     AccountSeedProvidedForExistingAccount,
     DuplicateInputNote(Digest),
+    ForeignAccountCodeMismatch(AccountId),
+    ForeignAccountInvalidStorageProof(AccountId),
+    ForeignAccountNotInBlock(AccountId, u32),
+    ForeignAccountStorageMismatch(AccountId),
     InconsistentAccountSeed { expected: AccountId, actual: AccountId },
     InconsistentChainLength { expected: u32, actual: u32 },
     InconsistentChainRoot { expected: Digest, actual: Digest },
@@ -310,6 +478,8 @@ pub enum TransactionOutputError {
     FinalAccountHeaderDataInvalid(AccountError),
     OutputNoteDataNotFound,
     OutputNoteDataInvalid(NoteError),
+    OutputNoteNotFound(NoteId),
+    OutputNoteNotPartial(NoteId),
     OutputNotesCommitmentInconsistent(Digest, Digest),
     OutputStackInvalid(String),
     TooManyOutputNotes(usize),
@@ -324,6 +494,25 @@ impl fmt::Display for TransactionOutputError {
 #[cfg(feature = "std")]
 impl std::error::Error for TransactionOutputError {}
 
+// EXECUTED TRANSACTION ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExecutedTransactionError {
+    AccountDeltaApplicationFailed(AccountError),
+    AccountFinalHashMismatch(Digest, Digest),
+    AccountStorageDeltaMismatch(AccountDeltaError),
+}
+
+impl fmt::Display for ExecutedTransactionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ExecutedTransactionError {}
+
 // PROVEN TRANSACTION ERROR
 // ================================================================================================
 
@@ -331,7 +520,9 @@ impl std::error::Error for TransactionOutputError {}
 pub enum ProvenTransactionError {
     AccountFinalHashMismatch(Digest, Digest),
     AccountIdMismatch(AccountId, AccountId),
+    AccountInitialHashMismatch(Digest, Digest),
     InputNotesError(TransactionInputError),
+    InvalidCompactAccountDelta(AccountDeltaError),
     NoteDetailsForUnknownNotes(Vec<NoteId>),
     OffChainAccountWithDetails(AccountId),
     OnChainAccountMissingDetails(AccountId),
@@ -339,6 +530,10 @@ pub enum ProvenTransactionError {
     ExistingOnChainAccountRequiresDeltaDetails(AccountId),
     OutputNotesError(TransactionOutputError),
     AccountUpdateSizeLimitExceeded(AccountId, usize),
+    InvalidExpirationBlockNum(u32),
+    NoteAssetLimitExceeded(NoteId, usize),
+    TooManyInputNotes(usize),
+    TooManyOutputNotes(usize),
 }
 
 impl fmt::Display for ProvenTransactionError {
@@ -353,9 +548,15 @@ impl fmt::Display for ProvenTransactionError {
                     "Proven transaction account_id {tx_id} and account_details.id must match {details_id}.",
                 )
             },
+            ProvenTransactionError::AccountInitialHashMismatch(account_init_hash, details_hash) => {
+                write!(f, "Proven transaction account_init_hash {account_init_hash} and the initial hash of the account details must match {details_hash}.")
+            },
             ProvenTransactionError::InputNotesError(inner) => {
                 write!(f, "Invalid input notes: {inner}")
             },
+            ProvenTransactionError::InvalidCompactAccountDelta(inner) => {
+                write!(f, "Invalid compact account delta: {inner}")
+            },
             ProvenTransactionError::NoteDetailsForUnknownNotes(note_ids) => {
                 write!(f, "Note details for unknown note ids: {note_ids:?}")
             },
@@ -375,7 +576,19 @@ impl fmt::Display for ProvenTransactionError {
                 write!(f, "Existing on-chain account {account_id} should only provide deltas")
             },
             ProvenTransactionError::AccountUpdateSizeLimitExceeded(account_id, size) => {
-                write!(f, "Update on account {account_id} of size {size} exceeds the allowed limit of {ACCOUNT_UPDATE_MAX_SIZE}")
+                write!(f, "Update on account {account_id} of size {size} exceeds the allowed limit")
+            },
+            ProvenTransactionError::InvalidExpirationBlockNum(block_num) => {
+                write!(f, "Transaction expiration block number {block_num} must be greater than the genesis block number")
+            },
+            ProvenTransactionError::NoteAssetLimitExceeded(note_id, num_assets) => {
+                write!(f, "Output note {note_id} with {num_assets} assets exceeds the allowed number of assets")
+            },
+            ProvenTransactionError::TooManyInputNotes(num_notes) => {
+                write!(f, "Transaction consumes {num_notes} notes, which exceeds the allowed number of input notes")
+            },
+            ProvenTransactionError::TooManyOutputNotes(num_notes) => {
+                write!(f, "Transaction creates {num_notes} notes, which exceeds the allowed number of output notes")
             },
         }
     }
@@ -384,25 +597,80 @@ impl fmt::Display for ProvenTransactionError {
 #[cfg(feature = "std")]
 impl std::error::Error for ProvenTransactionError {}
 
+// BATCH ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BatchError {
+    AccountUpdateMergeError(AccountId, AccountDeltaError),
+    DuplicateInputNote(Nullifier),
+    DuplicateOutputNote(NoteId),
+    DuplicateTransaction(TransactionId),
+    DuplicateUnauthenticatedNote(NoteId),
+    EmptyBatch,
+    InconsistentAccountStateTransition {
+        account_id: AccountId,
+        expected: Digest,
+        actual: Digest,
+    },
+    NoteConsumedBeforeCreation(NoteId),
+    NoteTreeError(MerkleError),
+    TooManyAccountUpdates(usize),
+    TooManyInputNotes(usize),
+    TooManyOutputNotes(usize),
+    UnauthenticatedNoteMismatch {
+        note_id: NoteId,
+        expected: Digest,
+        actual: Digest,
+    },
+    UnauthenticatedNoteNotFound(NoteId),
+}
+
+impl fmt::Display for BatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BatchError {}
+
 // BLOCK VALIDATION ERROR
 // ================================================================================================
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum BlockError {
+    DuplicateBatch(BatchId),
     DuplicateNoteFound(NoteId),
+    DuplicateNullifier(Nullifier),
+    InvalidAccountUpdate(BatchError),
     TooManyAccountUpdates(usize),
     TooManyNotesInBatch(usize),
     TooManyNotesInBlock(usize),
     TooManyNullifiersInBlock(usize),
     TooManyTransactionBatches(usize),
+    UnauthenticatedNoteMismatch {
+        note_id: NoteId,
+        expected: Digest,
+        actual: Digest,
+    },
 }
 
 impl fmt::Display for BlockError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            BlockError::DuplicateBatch(id) => {
+                write!(f, "Duplicate batch {id} found in the block")
+            },
             BlockError::DuplicateNoteFound(id) => {
                 write!(f, "Duplicate note {id} found in the block")
             },
+            BlockError::DuplicateNullifier(nullifier) => {
+                write!(f, "Duplicate nullifier {nullifier} found in the block")
+            },
+            BlockError::InvalidAccountUpdate(err) => {
+                write!(f, "Invalid account update in the block: {err}")
+            },
             BlockError::TooManyAccountUpdates(actual) => {
                 write!(f, "Too many accounts updated in a block. Max: {MAX_ACCOUNTS_PER_BLOCK}, actual: {actual}")
             },
@@ -424,6 +692,12 @@ impl fmt::Display for BlockError {
                     "Too many transaction batches. Max: {MAX_BATCHES_PER_BLOCK}, actual: {actual}"
                 )
             },
+            BlockError::UnauthenticatedNoteMismatch { note_id, expected, actual } => {
+                write!(
+                    f,
+                    "Unauthenticated note {note_id} does not match the note created in the block. Expected hash: {expected}, actual: {actual}"
+                )
+            },
         }
     }
 }
@@ -16,19 +16,34 @@ pub mod transaction;
 #[cfg(any(feature = "testing", test))]
 pub mod testing;
 
+mod bounded_serde;
 mod constants;
+mod debug_info;
 mod errors;
+mod limits;
+
+#[cfg(feature = "std")]
+mod stream_serde;
+
+#[cfg(feature = "proto")]
+pub mod proto;
+
+#[cfg(feature = "serde")]
+mod serde_impls;
 
 // RE-EXPORTS
 // ================================================================================================
 
 pub use block::BlockHeader;
 pub use constants::*;
+pub use debug_info::ScriptDebugInfo;
 pub use errors::{
-    AccountDeltaError, AccountError, AssetError, AssetVaultError, BlockError, ChainMmrError,
-    NoteError, ProvenTransactionError, TransactionInputError, TransactionOutputError,
+    AccountDeltaError, AccountError, AccountTreeError, AssetError, AssetVaultError, BatchError,
+    BlockError, ChainMmrError, ExecutedTransactionError, NoteError, NullifierTreeError,
+    ProtoConversionError, ProvenTransactionError, TransactionInputError, TransactionOutputError,
     TransactionScriptError,
 };
+pub use limits::{ProtocolLimits, ProtocolParams};
 pub use miden_crypto::hash::rpo::{Rpo256 as Hasher, RpoDigest as Digest};
 pub use vm_core::{Felt, FieldElement, StarkField, Word, EMPTY_WORD, ONE, WORD_SIZE, ZERO};
 
@@ -51,6 +66,10 @@ pub mod utils {
         pub use miden_crypto::utils::{
             ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
         };
+
+        pub use crate::bounded_serde::BoundedDeserializable;
+        #[cfg(feature = "std")]
+        pub use crate::stream_serde::{StreamDeserializable, StreamSerializable};
     }
 
     /// Construct a new `Digest` from four `u64` values.
@@ -0,0 +1,181 @@
+use crate::{
+    accounts::AccountStorage,
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    ACCOUNT_UPDATE_MAX_SIZE, MAX_ACCOUNTS_PER_BATCH, MAX_ASSETS_PER_NOTE, MAX_BATCHES_PER_BLOCK,
+    MAX_INPUTS_PER_NOTE, MAX_INPUT_NOTES_PER_BATCH, MAX_INPUT_NOTES_PER_TX,
+    MAX_NUM_FOREIGN_ACCOUNTS, MAX_OUTPUT_NOTES_PER_BATCH, MAX_OUTPUT_NOTES_PER_TX,
+    MAX_TX_EXECUTION_CYCLES, MIN_PROOF_SECURITY_LEVEL, TX_KERNEL_VERSION,
+};
+
+// PROTOCOL LIMITS
+// ================================================================================================
+
+/// Structural limits which a transaction must respect in order to be accepted by the network.
+///
+/// The [Default] limits are the limits enforced by the protocol. Operators can use stricter limits
+/// to bound the resources spent on a single transaction, e.g., when admitting transactions into a
+/// mempool via [ProvenTransaction::validate()](crate::transaction::ProvenTransaction::validate).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProtocolLimits {
+    /// The maximum number of notes consumed by a single transaction.
+    pub max_input_notes_per_tx: usize,
+    /// The maximum number of notes created by a single transaction.
+    pub max_output_notes_per_tx: usize,
+    /// The maximum number of assets in a single note.
+    pub max_assets_per_note: usize,
+    /// The maximum size of the serialized update of a public account, in bytes.
+    pub max_account_update_size: usize,
+}
+
+impl Default for ProtocolLimits {
+    fn default() -> Self {
+        Self {
+            max_input_notes_per_tx: MAX_INPUT_NOTES_PER_TX,
+            max_output_notes_per_tx: MAX_OUTPUT_NOTES_PER_TX,
+            max_assets_per_note: MAX_ASSETS_PER_NOTE,
+            max_account_update_size: ACCOUNT_UPDATE_MAX_SIZE as usize,
+        }
+    }
+}
+
+// PROTOCOL PARAMETERS
+// ================================================================================================
+
+/// The parameters of the protocol, i.e., the version of the transaction kernel and the limits
+/// enforced on transactions, notes, accounts, batches and blocks.
+///
+/// The [Default] parameters are the parameters of the protocol implemented by this crate, and are
+/// derived from the constants defined in this crate. The parameters can be serialized, so that a
+/// node can advertise the parameters it enforces and clients can check that they are compatible
+/// with it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProtocolParams {
+    /// The version of the transaction kernel used to execute transactions.
+    pub tx_kernel_version: u8,
+    /// The minimum security level of transaction proofs.
+    pub min_proof_security_level: u32,
+    /// The maximum number of VM cycles a transaction can take.
+    pub max_tx_execution_cycles: u32,
+    /// The maximum number of notes consumed by a single transaction.
+    pub max_input_notes_per_tx: usize,
+    /// The maximum number of notes created by a single transaction.
+    pub max_output_notes_per_tx: usize,
+    /// The maximum number of foreign accounts a single transaction can load.
+    pub max_foreign_accounts_per_tx: u8,
+    /// The maximum number of assets in a single note.
+    pub max_assets_per_note: usize,
+    /// The maximum number of inputs of a single note.
+    pub max_inputs_per_note: usize,
+    /// The maximum number of storage slots of an account.
+    pub max_account_storage_slots: usize,
+    /// The maximum size of the serialized update of a public account, in bytes.
+    pub max_account_update_size: usize,
+    /// The maximum number of accounts updated in a single batch.
+    pub max_accounts_per_batch: usize,
+    /// The maximum number of notes consumed in a single batch.
+    pub max_input_notes_per_batch: usize,
+    /// The maximum number of notes created in a single batch.
+    pub max_output_notes_per_batch: usize,
+    /// The maximum number of batches in a single block.
+    pub max_batches_per_block: usize,
+}
+
+impl ProtocolParams {
+    /// Returns the limits on transactions defined by these parameters.
+    pub fn limits(&self) -> ProtocolLimits {
+        ProtocolLimits {
+            max_input_notes_per_tx: self.max_input_notes_per_tx,
+            max_output_notes_per_tx: self.max_output_notes_per_tx,
+            max_assets_per_note: self.max_assets_per_note,
+            max_account_update_size: self.max_account_update_size,
+        }
+    }
+}
+
+impl Default for ProtocolParams {
+    fn default() -> Self {
+        Self {
+            tx_kernel_version: TX_KERNEL_VERSION,
+            min_proof_security_level: MIN_PROOF_SECURITY_LEVEL,
+            max_tx_execution_cycles: MAX_TX_EXECUTION_CYCLES,
+            max_input_notes_per_tx: MAX_INPUT_NOTES_PER_TX,
+            max_output_notes_per_tx: MAX_OUTPUT_NOTES_PER_TX,
+            max_foreign_accounts_per_tx: MAX_NUM_FOREIGN_ACCOUNTS,
+            max_assets_per_note: MAX_ASSETS_PER_NOTE,
+            max_inputs_per_note: MAX_INPUTS_PER_NOTE,
+            max_account_storage_slots: AccountStorage::MAX_NUM_STORAGE_SLOTS,
+            max_account_update_size: ACCOUNT_UPDATE_MAX_SIZE as usize,
+            max_accounts_per_batch: MAX_ACCOUNTS_PER_BATCH,
+            max_input_notes_per_batch: MAX_INPUT_NOTES_PER_BATCH,
+            max_output_notes_per_batch: MAX_OUTPUT_NOTES_PER_BATCH,
+            max_batches_per_block: MAX_BATCHES_PER_BLOCK,
+        }
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for ProtocolParams {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(self.tx_kernel_version);
+        target.write_u32(self.min_proof_security_level);
+        target.write_u32(self.max_tx_execution_cycles);
+        target.write_usize(self.max_input_notes_per_tx);
+        target.write_usize(self.max_output_notes_per_tx);
+        target.write_u8(self.max_foreign_accounts_per_tx);
+        target.write_usize(self.max_assets_per_note);
+        target.write_usize(self.max_inputs_per_note);
+        target.write_usize(self.max_account_storage_slots);
+        target.write_usize(self.max_account_update_size);
+        target.write_usize(self.max_accounts_per_batch);
+        target.write_usize(self.max_input_notes_per_batch);
+        target.write_usize(self.max_output_notes_per_batch);
+        target.write_usize(self.max_batches_per_block);
+    }
+}
+
+impl Deserializable for ProtocolParams {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            tx_kernel_version: source.read_u8()?,
+            min_proof_security_level: source.read_u32()?,
+            max_tx_execution_cycles: source.read_u32()?,
+            max_input_notes_per_tx: source.read_usize()?,
+            max_output_notes_per_tx: source.read_usize()?,
+            max_foreign_accounts_per_tx: source.read_u8()?,
+            max_assets_per_note: source.read_usize()?,
+            max_inputs_per_note: source.read_usize()?,
+            max_account_storage_slots: source.read_usize()?,
+            max_account_update_size: source.read_usize()?,
+            max_accounts_per_batch: source.read_usize()?,
+            max_input_notes_per_batch: source.read_usize()?,
+            max_output_notes_per_batch: source.read_usize()?,
+            max_batches_per_block: source.read_usize()?,
+        })
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{ProtocolLimits, ProtocolParams};
+    use crate::utils::serde::{Deserializable, Serializable};
+
+    #[test]
+    fn test_protocol_params() {
+        let params = ProtocolParams::default();
+        assert_eq!(params.limits(), ProtocolLimits::default());
+        assert_eq!(ProtocolParams::read_from_bytes(&params.to_bytes()).unwrap(), params);
+
+        let params = ProtocolParams {
+            max_input_notes_per_tx: 16,
+            max_batches_per_block: 8,
+            ..params
+        };
+        assert_eq!(params.limits().max_input_notes_per_tx, 16);
+        assert_eq!(ProtocolParams::read_from_bytes(&params.to_bytes()).unwrap(), params);
+    }
+}
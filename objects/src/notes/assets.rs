@@ -1,10 +1,13 @@
-use alloc::vec::Vec;
+use alloc::{
+    collections::{btree_map::Entry, BTreeMap},
+    vec::Vec,
+};
 
 use super::{
     Asset, ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Felt, Hasher,
     NoteError, Serializable, Word, WORD_SIZE, ZERO,
 };
-use crate::MAX_ASSETS_PER_NOTE;
+use crate::{assets::FungibleAsset, MAX_ASSETS_PER_NOTE};
 
 // NOTE ASSETS
 // ================================================================================================
@@ -15,7 +18,8 @@ use crate::MAX_ASSETS_PER_NOTE;
 ///
 /// All the assets in a note can be reduced to a single commitment which is computed by
 /// sequentially hashing the assets. Note that the same list of assets can result in two different
-/// commitments if the asset ordering is different.
+/// commitments if the asset ordering is different. To obtain a commitment which is independent of
+/// the order in which assets are provided, use [NoteAssets::from_assets()].
 #[derive(Debug, Default, Clone)]
 pub struct NoteAssets {
     assets: Vec<Asset>,
@@ -59,6 +63,51 @@ impl NoteAssets {
         Ok(Self { assets, hash })
     }
 
+    /// Returns new [NoteAssets] constructed from the provided assets in canonical form.
+    ///
+    /// Fungible assets issued by the same faucet are aggregated into a single asset, and the
+    /// resulting assets are sorted by their vault keys. Thus, the same logical set of assets always
+    /// results in the same asset commitment, regardless of the order in which the assets are
+    /// provided.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The same non-fungible asset is provided more than once.
+    /// - Aggregating fungible assets issued by the same faucet results in an invalid asset.
+    /// - The aggregated assets contain more than [Self::MAX_NUM_ASSETS] assets.
+    pub fn from_assets(assets: impl IntoIterator<Item = Asset>) -> Result<Self, NoteError> {
+        let mut aggregated = BTreeMap::<Digest, Asset>::new();
+
+        for asset in assets {
+            match aggregated.entry(asset.vault_key().into()) {
+                Entry::Vacant(entry) => {
+                    entry.insert(asset);
+                },
+                // assets with the same vault key are either fungible assets issued by the same
+                // faucet, or the same non-fungible asset
+                Entry::Occupied(mut entry) => match asset {
+                    Asset::Fungible(other_asset) => {
+                        let new_asset = FungibleAsset::try_from(*entry.get())
+                            .and_then(|own_asset| own_asset.add(other_asset))
+                            .map_err(NoteError::InvalidAssetData)?;
+                        entry.insert(Asset::Fungible(new_asset));
+                    },
+                    Asset::NonFungible(nf_asset) => {
+                        return Err(NoteError::duplicate_non_fungible_asset(nf_asset));
+                    },
+                },
+            }
+        }
+
+        if aggregated.len() > Self::MAX_NUM_ASSETS {
+            return Err(NoteError::too_many_assets(aggregated.len()));
+        }
+
+        let assets: Vec<Asset> = aggregated.into_values().collect();
+        let hash = compute_asset_commitment(&assets);
+        Ok(Self { assets, hash })
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -125,8 +174,8 @@ impl NoteAssets {
                 Asset::Fungible(f_own_asset) => {
                     // if a fungible asset issued by the same faucet is found, try to add the
                     // the provided asset to it
-                    let new_asset = f_own_asset
-                        .add(asset.unwrap_fungible())
+                    let new_asset = FungibleAsset::try_from(asset)
+                        .and_then(|asset| f_own_asset.add(asset))
                         .map_err(NoteError::InvalidAssetData)?;
                     *own_asset = Asset::Fungible(new_asset);
                 },
@@ -221,9 +270,15 @@ impl Deserializable for NoteAssets {
 mod tests {
     use super::{compute_asset_commitment, NoteAssets};
     use crate::{
-        accounts::account_id::{testing::ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN, AccountId},
-        assets::{Asset, FungibleAsset},
-        Digest, Felt,
+        accounts::account_id::{
+            testing::{
+                ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+                ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+            },
+            AccountId,
+        },
+        assets::{Asset, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails},
+        Digest, Felt, NoteError,
     };
 
     #[test]
@@ -248,4 +303,39 @@ mod tests {
         assert_eq!(assets.assets, vec![expected_asset]);
         assert_eq!(assets.hash, compute_asset_commitment(&[expected_asset]));
     }
+
+    #[test]
+    fn from_assets_is_canonical() {
+        let faucet_id = AccountId::new_unchecked(Felt::new(ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN));
+        let other_faucet_id =
+            AccountId::new_unchecked(Felt::new(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN));
+        let nft_faucet_id = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+
+        let fungible_1 = Asset::Fungible(FungibleAsset::new(faucet_id, 100).unwrap());
+        let fungible_2 = Asset::Fungible(FungibleAsset::new(faucet_id, 50).unwrap());
+        let other_fungible = Asset::Fungible(FungibleAsset::new(other_faucet_id, 10).unwrap());
+        let details = NonFungibleAssetDetails::new(nft_faucet_id, vec![1, 2, 3]).unwrap();
+        let nft = Asset::NonFungible(NonFungibleAsset::new(&details).unwrap());
+
+        let assets =
+            NoteAssets::from_assets([fungible_1, nft, other_fungible, fungible_2]).unwrap();
+        let reordered =
+            NoteAssets::from_assets([other_fungible, fungible_2, nft, fungible_1]).unwrap();
+
+        // fungible assets from the same faucet are aggregated
+        assert_eq!(assets.num_assets(), 3);
+        assert!(assets
+            .iter()
+            .any(|asset| *asset == Asset::Fungible(FungibleAsset::new(faucet_id, 150).unwrap())));
+
+        // the commitment does not depend on the order of the provided assets
+        assert_eq!(assets, reordered);
+        assert_eq!(assets.commitment(), reordered.commitment());
+
+        // duplicate non-fungible assets are rejected
+        assert!(matches!(
+            NoteAssets::from_assets([nft, fungible_1, nft]),
+            Err(NoteError::DuplicateNonFungibleAsset(_))
+        ));
+    }
 }
@@ -0,0 +1,201 @@
+use alloc::vec::Vec;
+
+use super::{
+    Note, NoteAssets, NoteExecutionHint, NoteInputs, NoteMetadata, NoteRecipient, NoteScript,
+    NoteTag, NoteType,
+};
+use crate::{
+    accounts::AccountId, assets::Asset, crypto::rand::FeltRng, Felt, NoteError, Word, ZERO,
+};
+
+// NOTE BUILDER
+// ================================================================================================
+
+/// A builder for assembling a [Note] from its script, inputs, assets and metadata.
+///
+/// By default, the built note is public, has a tag of `0`, an execution hint of
+/// [NoteExecutionHint::always()], no inputs, no assets and an `aux` value of `0`. Unless a serial
+/// number is provided explicitly via [NoteBuilder::serial_num()], it is drawn from the random
+/// number generator passed to [NoteBuilder::build()].
+///
+/// All constraints on the note (e.g., the maximum number of assets and inputs, duplicate assets
+/// and the compatibility of the tag with the note type) are validated when the note is built.
+#[derive(Debug, Clone)]
+pub struct NoteBuilder {
+    sender: AccountId,
+    script: NoteScript,
+    inputs: Vec<Felt>,
+    assets: Vec<Asset>,
+    note_type: NoteType,
+    tag: NoteTag,
+    execution_hint: NoteExecutionHint,
+    aux: Felt,
+    serial_num: Option<Word>,
+}
+
+impl NoteBuilder {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [NoteBuilder] for a note created by the specified sender and executing the
+    /// provided script.
+    pub fn new(sender: AccountId, script: NoteScript) -> Self {
+        Self {
+            sender,
+            script,
+            inputs: Vec::new(),
+            assets: Vec::new(),
+            note_type: NoteType::Public,
+            tag: NoteTag::from(0),
+            execution_hint: NoteExecutionHint::always(),
+            aux: ZERO,
+            serial_num: None,
+        }
+    }
+
+    // BUILDER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Sets the script of the note, replacing the previous one.
+    pub fn script(mut self, script: NoteScript) -> Self {
+        self.script = script;
+        self
+    }
+
+    /// Sets the inputs of the note, replacing any previously set inputs.
+    pub fn inputs(mut self, inputs: impl IntoIterator<Item = Felt>) -> Self {
+        self.inputs = inputs.into_iter().collect();
+        self
+    }
+
+    /// Adds an asset to the note.
+    pub fn add_asset(mut self, asset: Asset) -> Self {
+        self.assets.push(asset);
+        self
+    }
+
+    /// Adds the provided assets to the note.
+    pub fn add_assets(mut self, assets: impl IntoIterator<Item = Asset>) -> Self {
+        self.assets.extend(assets);
+        self
+    }
+
+    /// Sets the type of the note.
+    pub fn note_type(mut self, note_type: NoteType) -> Self {
+        self.note_type = note_type;
+        self
+    }
+
+    /// Sets the tag of the note.
+    pub fn tag(mut self, tag: NoteTag) -> Self {
+        self.tag = tag;
+        self
+    }
+
+    /// Sets the execution hint of the note.
+    pub fn execution_hint(mut self, execution_hint: NoteExecutionHint) -> Self {
+        self.execution_hint = execution_hint;
+        self
+    }
+
+    /// Sets the `aux` value of the note metadata.
+    pub fn aux(mut self, aux: Felt) -> Self {
+        self.aux = aux;
+        self
+    }
+
+    /// Sets the serial number of the note instead of drawing it from the random number generator.
+    pub fn serial_num(mut self, serial_num: Word) -> Self {
+        self.serial_num = Some(serial_num);
+        self
+    }
+
+    /// Builds the [Note], drawing its serial number from the provided random number generator
+    /// unless one was set explicitly.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The note has more than [NoteAssets::MAX_NUM_ASSETS] assets or contains duplicate assets.
+    /// - The note has more than [crate::MAX_INPUTS_PER_NOTE] inputs.
+    /// - The tag is not compatible with the note type.
+    pub fn build<R: FeltRng>(self, rng: &mut R) -> Result<Note, NoteError> {
+        let assets = NoteAssets::new(self.assets)?;
+        let inputs = NoteInputs::new(self.inputs)?;
+        let metadata = NoteMetadata::new(
+            self.sender,
+            self.note_type,
+            self.tag,
+            self.execution_hint,
+            self.aux,
+        )?;
+        let serial_num = self.serial_num.unwrap_or_else(|| rng.draw_word());
+        let recipient = NoteRecipient::new(serial_num, self.script, inputs);
+
+        Ok(Note::new(assets, metadata, recipient))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        accounts::account_id::testing::{
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+        },
+        assets::FungibleAsset,
+        crypto::rand::RpoRandomCoin,
+        notes::NoteExecutionMode,
+        ONE,
+    };
+
+    #[test]
+    fn test_note_builder() {
+        let sender =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+        let tag = NoteTag::from_account_id(sender, NoteExecutionMode::Local).unwrap();
+        let mut rng = RpoRandomCoin::new([ONE; 4]);
+
+        let note = NoteBuilder::new(sender, NoteScript::mock())
+            .add_asset(asset)
+            .inputs([ONE, ONE])
+            .tag(tag)
+            .aux(ONE)
+            .build(&mut rng)
+            .unwrap();
+
+        assert_eq!(note.assets().iter().collect::<Vec<_>>(), vec![&asset]);
+        assert_eq!(note.inputs().values(), &[ONE, ONE]);
+        assert_eq!(note.metadata().sender(), sender);
+        assert_eq!(note.metadata().tag(), tag);
+        assert_eq!(note.metadata().aux(), ONE);
+
+        // serial numbers are drawn from the rng unless set explicitly
+        let other_note = NoteBuilder::new(sender, NoteScript::mock()).build(&mut rng).unwrap();
+        assert_ne!(other_note.serial_num(), note.serial_num());
+
+        let note = NoteBuilder::new(sender, NoteScript::mock())
+            .serial_num([ONE; 4])
+            .build(&mut rng)
+            .unwrap();
+        assert_eq!(note.serial_num(), [ONE; 4]);
+
+        // duplicate assets and too many inputs are rejected
+        assert!(matches!(
+            NoteBuilder::new(sender, NoteScript::mock())
+                .add_assets([asset, asset])
+                .build(&mut rng),
+            Err(NoteError::DuplicateFungibleAsset(_))
+        ));
+        assert!(matches!(
+            NoteBuilder::new(sender, NoteScript::mock())
+                .inputs(vec![ONE; crate::MAX_INPUTS_PER_NOTE + 1])
+                .build(&mut rng),
+            Err(NoteError::TooManyInputs(_))
+        ));
+    }
+}
@@ -0,0 +1,286 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use chacha20poly1305::{
+    aead::{Aead, KeyInit, Payload},
+    ChaCha20Poly1305, Key, Nonce,
+};
+use miden_crypto::hash::blake::Blake3_256;
+use rand::{CryptoRng, RngCore};
+use x25519_dalek::{EphemeralSecret, PublicKey, StaticSecret};
+
+use super::{
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Note, NoteError, NoteId,
+    Serializable,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Domain separator used when deriving the symmetric key of an encrypted note.
+const KEY_DERIVATION_DOMAIN: &[u8] = b"miden-note-envelope-v1";
+
+// ENCRYPTION KEYS
+// ================================================================================================
+
+/// An X25519 public key to which note details can be encrypted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoteEncryptionPublicKey([u8; 32]);
+
+impl NoteEncryptionPublicKey {
+    /// Returns the byte representation of this public key.
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl From<[u8; 32]> for NoteEncryptionPublicKey {
+    fn from(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+}
+
+/// An X25519 secret key used to decrypt notes encrypted to the corresponding
+/// [NoteEncryptionPublicKey].
+#[derive(Clone)]
+pub struct NoteEncryptionSecretKey(StaticSecret);
+
+impl NoteEncryptionSecretKey {
+    /// Returns a new secret key generated using the provided random number generator.
+    pub fn with_rng<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        Self(StaticSecret::random_from_rng(rng))
+    }
+
+    /// Returns the public key corresponding to this secret key.
+    pub fn public_key(&self) -> NoteEncryptionPublicKey {
+        NoteEncryptionPublicKey(PublicKey::from(&self.0).to_bytes())
+    }
+
+    /// Returns the byte representation of this secret key.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0.to_bytes()
+    }
+}
+
+impl From<[u8; 32]> for NoteEncryptionSecretKey {
+    fn from(bytes: [u8; 32]) -> Self {
+        Self(StaticSecret::from(bytes))
+    }
+}
+
+impl fmt::Debug for NoteEncryptionSecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("NoteEncryptionSecretKey").field(&self.public_key()).finish()
+    }
+}
+
+// ENCRYPTED NOTE
+// ================================================================================================
+
+/// A note whose details are encrypted to the public key of its recipient.
+///
+/// Encrypted notes allow delivering the full details of a private note (i.e., its assets, script,
+/// inputs, serial number and metadata) over an untrusted channel, while only the [NoteId] of the
+/// note is recorded on chain.
+///
+/// The note is encrypted using an ephemeral X25519 key exchange with the recipient's public key.
+/// The shared secret is hashed into a ChaCha20-Poly1305 key, and the ID of the note is used as
+/// associated data. Thus, the note ID is visible to anyone relaying the note, but it cannot be
+/// changed without invalidating the ciphertext.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EncryptedNote {
+    note_id: NoteId,
+    ephemeral_key: NoteEncryptionPublicKey,
+    ciphertext: Vec<u8>,
+}
+
+impl EncryptedNote {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Encrypts the provided note to the specified recipient public key.
+    pub fn encrypt_for<R: RngCore + CryptoRng>(
+        note: &Note,
+        recipient_key: &NoteEncryptionPublicKey,
+        rng: &mut R,
+    ) -> Self {
+        let ephemeral_secret = EphemeralSecret::random_from_rng(rng);
+        let ephemeral_key = NoteEncryptionPublicKey(PublicKey::from(&ephemeral_secret).to_bytes());
+        let shared_secret = ephemeral_secret.diffie_hellman(&PublicKey::from(recipient_key.0));
+
+        let cipher = build_cipher(shared_secret.as_bytes(), &ephemeral_key, recipient_key);
+        let note_id = note.id();
+        let payload = Payload {
+            msg: &note.to_bytes(),
+            aad: &note_id.to_bytes(),
+        };
+        let ciphertext = cipher
+            .encrypt(&Nonce::default(), payload)
+            .expect("encryption of a note should not fail");
+
+        Self { note_id, ephemeral_key, ciphertext }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of the encrypted note.
+    pub fn note_id(&self) -> NoteId {
+        self.note_id
+    }
+
+    /// Returns the ephemeral public key used to encrypt the note.
+    pub fn ephemeral_key(&self) -> &NoteEncryptionPublicKey {
+        &self.ephemeral_key
+    }
+
+    /// Returns the encrypted note details.
+    pub fn ciphertext(&self) -> &[u8] {
+        &self.ciphertext
+    }
+
+    // DECRYPTION
+    // --------------------------------------------------------------------------------------------
+
+    /// Decrypts the note using the provided secret key of the recipient.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The note was not encrypted to the public key of the provided secret key, or the ciphertext
+    ///   or note ID were tampered with.
+    /// - The decrypted note cannot be deserialized.
+    /// - The ID of the decrypted note does not match the ID of this encrypted note.
+    pub fn decrypt(&self, secret_key: &NoteEncryptionSecretKey) -> Result<Note, NoteError> {
+        let shared_secret = secret_key.0.diffie_hellman(&PublicKey::from(self.ephemeral_key.0));
+
+        let cipher =
+            build_cipher(shared_secret.as_bytes(), &self.ephemeral_key, &secret_key.public_key());
+        let payload = Payload {
+            msg: &self.ciphertext,
+            aad: &self.note_id.to_bytes(),
+        };
+        let plaintext = cipher
+            .decrypt(&Nonce::default(), payload)
+            .map_err(|_| NoteError::NoteDecryptionFailed)?;
+
+        let note =
+            Note::read_from_bytes(&plaintext).map_err(NoteError::NoteDeserializationError)?;
+        if note.id() != self.note_id {
+            return Err(NoteError::EncryptedNoteIdMismatch {
+                expected: self.note_id,
+                actual: note.id(),
+            });
+        }
+
+        Ok(note)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the cipher keyed with the hash of the shared secret and both public keys of the key
+/// exchange.
+///
+/// Since a fresh ephemeral key is used for every encrypted note, every key is used only once and
+/// a constant nonce can be used.
+fn build_cipher(
+    shared_secret: &[u8; 32],
+    ephemeral_key: &NoteEncryptionPublicKey,
+    recipient_key: &NoteEncryptionPublicKey,
+) -> ChaCha20Poly1305 {
+    let mut key_material = Vec::with_capacity(KEY_DERIVATION_DOMAIN.len() + 3 * 32);
+    key_material.extend_from_slice(KEY_DERIVATION_DOMAIN);
+    key_material.extend_from_slice(shared_secret);
+    key_material.extend_from_slice(&ephemeral_key.0);
+    key_material.extend_from_slice(&recipient_key.0);
+
+    let key: [u8; 32] = Blake3_256::hash(&key_material).into();
+    ChaCha20Poly1305::new(Key::from_slice(&key))
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for NoteEncryptionPublicKey {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_bytes(&self.0);
+    }
+}
+
+impl Deserializable for NoteEncryptionPublicKey {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self(source.read_array()?))
+    }
+}
+
+impl Serializable for EncryptedNote {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.note_id.write_into(target);
+        self.ephemeral_key.write_into(target);
+        target.write_usize(self.ciphertext.len());
+        target.write_bytes(&self.ciphertext);
+    }
+}
+
+impl Deserializable for EncryptedNote {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let note_id = NoteId::read_from(source)?;
+        let ephemeral_key = NoteEncryptionPublicKey::read_from(source)?;
+        let len = source.read_usize()?;
+        let ciphertext = source.read_vec(len)?;
+
+        Ok(Self { note_id, ephemeral_key, ciphertext })
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    use super::*;
+    use crate::{
+        accounts::{
+            account_id::testing::ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN, AccountId,
+        },
+        crypto::rand::RpoRandomCoin,
+        notes::{NoteBuilder, NoteScript, NoteType},
+        ONE,
+    };
+
+    #[test]
+    fn test_encrypted_note_roundtrip() {
+        let sender =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let note = NoteBuilder::new(sender, NoteScript::mock())
+            .note_type(NoteType::Private)
+            .inputs([ONE, ONE])
+            .build(&mut RpoRandomCoin::new([ONE; 4]))
+            .unwrap();
+
+        let mut rng = ChaCha20Rng::from_seed([0; 32]);
+        let secret_key = NoteEncryptionSecretKey::with_rng(&mut rng);
+        let encrypted = EncryptedNote::encrypt_for(&note, &secret_key.public_key(), &mut rng);
+
+        assert_eq!(encrypted.note_id(), note.id());
+        assert_eq!(EncryptedNote::read_from_bytes(&encrypted.to_bytes()).unwrap(), encrypted);
+        assert_eq!(encrypted.decrypt(&secret_key).unwrap(), note);
+
+        // the note cannot be decrypted with another key
+        let other_key = NoteEncryptionSecretKey::with_rng(&mut rng);
+        assert!(matches!(encrypted.decrypt(&other_key), Err(NoteError::NoteDecryptionFailed)));
+
+        // tampering with the ciphertext or the note ID is detected
+        let mut tampered = encrypted.clone();
+        tampered.ciphertext[0] ^= 1;
+        assert!(matches!(tampered.decrypt(&secret_key), Err(NoteError::NoteDecryptionFailed)));
+
+        let mut tampered = encrypted;
+        tampered.note_id = NoteId::from(crate::Digest::default());
+        assert!(matches!(tampered.decrypt(&secret_key), Err(NoteError::NoteDecryptionFailed)));
+    }
+}
@@ -3,10 +3,31 @@ use vm_processor::DeserializationError;
 
 use super::{Note, NoteDetails, NoteId, NoteInclusionProof, NoteTag};
 
+// CONSTANTS
+// ================================================================================================
+
+/// Magic bytes identifying a serialized note file.
+const NOTE_FILE_MAGIC: &str = "NOTE";
+
+/// Magic bytes of note files written before the format was versioned. In these files, the magic
+/// bytes are directly followed by the variant of the note file.
+const LEGACY_NOTE_FILE_MAGIC: &str = "note";
+
 // NOTE FILE
 // ================================================================================================
 
 /// A serialized representation of a note.
+///
+/// Note files are used to share notes between clients (e.g., exporting a note from one wallet and
+/// importing it into another). Depending on how much is known about the note, a note file contains
+/// either only the ID of the note, the details of the note without a proof of its inclusion in the
+/// chain, or the full note together with its inclusion proof.
+///
+/// Serialized note files start with the magic bytes `NOTE`, followed by the version of the format
+/// (see [NoteFile::VERSION]) and the variant of the note file. Note files written before the format
+/// was versioned start with the magic bytes `note` followed directly by the variant, and can still
+/// be read.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum NoteFile {
     /// The note's details aren't known.
     NoteId(NoteId),
@@ -27,6 +48,20 @@ pub enum NoteFile {
     NoteWithProof(Note, NoteInclusionProof),
 }
 
+impl NoteFile {
+    /// The current version of the note file format.
+    pub const VERSION: u8 = 1;
+
+    /// Returns the ID of the note contained in this note file.
+    pub fn note_id(&self) -> NoteId {
+        match self {
+            NoteFile::NoteId(note_id) => *note_id,
+            NoteFile::NoteDetails { details, .. } => details.id(),
+            NoteFile::NoteWithProof(note, _) => note.id(),
+        }
+    }
+}
+
 impl From<NoteDetails> for NoteFile {
     fn from(details: NoteDetails) -> Self {
         NoteFile::NoteDetails { details, after_block_num: 0, tag: None }
@@ -39,12 +74,19 @@ impl From<NoteId> for NoteFile {
     }
 }
 
+impl From<(Note, NoteInclusionProof)> for NoteFile {
+    fn from((note, proof): (Note, NoteInclusionProof)) -> Self {
+        NoteFile::NoteWithProof(note, proof)
+    }
+}
+
 // SERIALIZATION
 // ================================================================================================
 
 impl Serializable for NoteFile {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
-        target.write_bytes("note".as_bytes());
+        target.write_bytes(NOTE_FILE_MAGIC.as_bytes());
+        target.write_u8(Self::VERSION);
         match self {
             NoteFile::NoteId(note_id) => {
                 target.write_u8(0);
@@ -68,10 +110,23 @@ impl Serializable for NoteFile {
 impl Deserializable for NoteFile {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         let magic_value = source.read_string(4)?;
-        if magic_value != "note" {
-            return Err(DeserializationError::InvalidValue(format!(
-                "Invalid note file marker: {magic_value}"
-            )));
+        match magic_value.as_str() {
+            NOTE_FILE_MAGIC => {
+                let version = source.read_u8()?;
+                if version != Self::VERSION {
+                    return Err(DeserializationError::InvalidValue(format!(
+                        "Unsupported note file version {version}, expected {}",
+                        Self::VERSION
+                    )));
+                }
+            },
+            // the variants of legacy note files are encoded in the same way as in version 1
+            LEGACY_NOTE_FILE_MAGIC => (),
+            _ => {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "Invalid note file marker: {magic_value}"
+                )))
+            },
         }
         match source.read_u8()? {
             0 => Ok(NoteFile::NoteId(NoteId::read_from(source)?)),
@@ -152,7 +207,42 @@ mod tests {
         file.write_into(&mut buffer);
 
         let magic_value = &buffer[..4];
-        assert_eq!(magic_value, b"note");
+        assert_eq!(magic_value, b"NOTE");
+        assert_eq!(buffer[4], NoteFile::VERSION);
+    }
+
+    #[test]
+    fn legacy_note_files_are_readable() {
+        let note = create_example_note();
+        let files = [
+            NoteFile::NoteId(note.id()),
+            NoteFile::NoteDetails {
+                details: note.details.clone(),
+                after_block_num: 456,
+                tag: Some(NoteTag::from(123)),
+            },
+            NoteFile::NoteWithProof(
+                note.clone(),
+                NoteInclusionProof::new(0, 0, Default::default()).unwrap(),
+            ),
+        ];
+
+        for file in files {
+            // legacy files lack the version byte following the magic bytes
+            let mut buffer = b"note".to_vec();
+            buffer.extend_from_slice(&file.to_bytes()[5..]);
+
+            assert_eq!(NoteFile::read_from_bytes(&buffer).unwrap(), file);
+        }
+    }
+
+    #[test]
+    fn unsupported_version_is_rejected() {
+        let note = create_example_note();
+        let mut buffer = NoteFile::NoteId(note.id()).to_bytes();
+        buffer[4] = NoteFile::VERSION + 1;
+
+        assert!(NoteFile::read_from_bytes(&buffer).is_err());
     }
 
     #[test]
@@ -205,6 +295,7 @@ mod tests {
 
         let file_copy = NoteFile::read_from_bytes(&buffer).unwrap();
 
+        assert_eq!(file_copy.note_id(), note.id());
         match file_copy {
             NoteFile::NoteWithProof(note_copy, inclusion_proof_copy) => {
                 assert_eq!(note, note_copy);
@@ -0,0 +1,467 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use super::{NoteError, NoteInputs};
+use crate::{accounts::AccountId, assets::FungibleAsset, Felt, Word, MAX_INPUTS_PER_NOTE};
+
+// NOTE INPUT TYPE
+// ================================================================================================
+
+/// The type of a named field in a [NoteInputsSchema].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoteInputType {
+    /// An [AccountId], encoded as a single element.
+    AccountId,
+    /// An arbitrary field element.
+    Felt,
+    /// A `u32` value, encoded as a single element.
+    U32,
+    /// An amount of a fungible asset, i.e., a `u64` value of at most [FungibleAsset::MAX_AMOUNT],
+    /// encoded as a single element.
+    Amount,
+    /// A word, encoded as four elements.
+    Word,
+    /// A byte blob of at most the specified number of bytes.
+    ///
+    /// The blob is encoded as its length followed by the bytes packed into `u32` elements in
+    /// little-endian order, 4 bytes per element, and padded with zeros to the maximum length.
+    Bytes(u8),
+}
+
+impl NoteInputType {
+    /// Returns the number of note inputs occupied by a field of this type.
+    pub fn num_values(&self) -> usize {
+        match self {
+            Self::AccountId | Self::Felt | Self::U32 | Self::Amount => 1,
+            Self::Word => 4,
+            Self::Bytes(max_len) => 1 + (*max_len as usize).div_ceil(4),
+        }
+    }
+}
+
+// NOTE INPUT VALUE
+// ================================================================================================
+
+/// A typed value of a field in a [NoteInputsSchema].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NoteInputValue {
+    AccountId(AccountId),
+    Felt(Felt),
+    U32(u32),
+    Amount(u64),
+    Word(Word),
+    Bytes(Vec<u8>),
+}
+
+impl NoteInputValue {
+    /// Appends the encoding of this value as a field of the specified type to `values`.
+    fn encode_into(
+        &self,
+        name: &str,
+        input_type: NoteInputType,
+        values: &mut Vec<Felt>,
+    ) -> Result<(), NoteError> {
+        match (self, input_type) {
+            (Self::AccountId(id), NoteInputType::AccountId) => values.push((*id).into()),
+            (Self::Felt(felt), NoteInputType::Felt) => values.push(*felt),
+            (Self::U32(value), NoteInputType::U32) => values.push((*value).into()),
+            (Self::Amount(amount), NoteInputType::Amount) => {
+                if *amount > FungibleAsset::MAX_AMOUNT {
+                    return Err(invalid_field(name, "amount exceeds the maximum fungible amount"));
+                }
+                values.push(Felt::new(*amount));
+            },
+            (Self::Word(word), NoteInputType::Word) => values.extend_from_slice(word),
+            (Self::Bytes(bytes), NoteInputType::Bytes(max_len)) => {
+                if bytes.len() > max_len as usize {
+                    return Err(invalid_field(name, "byte blob exceeds the maximum length"));
+                }
+
+                values.push(Felt::from(bytes.len() as u32));
+                let num_chunks = (max_len as usize).div_ceil(4);
+                for chunk_idx in 0..num_chunks {
+                    let mut chunk = [0_u8; 4];
+                    for (i, byte) in bytes.iter().skip(chunk_idx * 4).take(4).enumerate() {
+                        chunk[i] = *byte;
+                    }
+                    values.push(Felt::from(u32::from_le_bytes(chunk)));
+                }
+            },
+            _ => return Err(NoteError::NoteInputsSchemaFieldTypeMismatch(name.to_string())),
+        }
+
+        Ok(())
+    }
+
+    /// Decodes a value of the specified type from the provided note inputs, which must have
+    /// exactly the length of the type's encoding.
+    fn decode(name: &str, input_type: NoteInputType, values: &[Felt]) -> Result<Self, NoteError> {
+        match input_type {
+            NoteInputType::AccountId => AccountId::try_from(values[0])
+                .map(Self::AccountId)
+                .map_err(|err| invalid_field(name, &err.to_string())),
+            NoteInputType::Felt => Ok(Self::Felt(values[0])),
+            NoteInputType::U32 => decode_u32(name, values[0]).map(Self::U32),
+            NoteInputType::Amount => {
+                let amount = values[0].as_int();
+                if amount > FungibleAsset::MAX_AMOUNT {
+                    return Err(invalid_field(name, "amount exceeds the maximum fungible amount"));
+                }
+                Ok(Self::Amount(amount))
+            },
+            NoteInputType::Word => {
+                Ok(Self::Word(values.try_into().expect("word field should have 4 values")))
+            },
+            NoteInputType::Bytes(max_len) => {
+                let len = decode_u32(name, values[0])? as usize;
+                if len > max_len as usize {
+                    return Err(invalid_field(name, "byte blob exceeds the maximum length"));
+                }
+
+                let mut bytes = Vec::with_capacity(values.len() * 4);
+                for value in &values[1..] {
+                    bytes.extend_from_slice(&decode_u32(name, *value)?.to_le_bytes());
+                }
+                if bytes[len..].iter().any(|byte| *byte != 0) {
+                    return Err(invalid_field(name, "byte blob padding must be zero"));
+                }
+                bytes.truncate(len);
+
+                Ok(Self::Bytes(bytes))
+            },
+        }
+    }
+}
+
+impl From<AccountId> for NoteInputValue {
+    fn from(id: AccountId) -> Self {
+        Self::AccountId(id)
+    }
+}
+
+impl From<Felt> for NoteInputValue {
+    fn from(felt: Felt) -> Self {
+        Self::Felt(felt)
+    }
+}
+
+impl From<u32> for NoteInputValue {
+    fn from(value: u32) -> Self {
+        Self::U32(value)
+    }
+}
+
+impl From<Word> for NoteInputValue {
+    fn from(word: Word) -> Self {
+        Self::Word(word)
+    }
+}
+
+impl From<Vec<u8>> for NoteInputValue {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self::Bytes(bytes)
+    }
+}
+
+// NOTE INPUTS SCHEMA
+// ================================================================================================
+
+/// A layout of note inputs which assigns names and types to the input values expected by a note
+/// script.
+///
+/// Fields are laid out sequentially in the order in which they were declared, starting at input
+/// index 0. For example, a schema declared as `[("target", AccountId), ("recipient", Word)]`
+/// places `target` at index 0 and `recipient` at indices 1 through 4.
+///
+/// The schema allows encoding typed values into [NoteInputs] via [NoteInputsSchema::encode()]
+/// and decoding them back via [NoteInputsSchema::decode()], validating the number of inputs and
+/// the values of every field, instead of indexing the input values by position. The schema itself
+/// is not part of the note and does not affect the inputs commitment.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NoteInputsSchema {
+    fields: Vec<SchemaField>,
+}
+
+/// A single named field of a [NoteInputsSchema].
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct SchemaField {
+    name: String,
+    input_type: NoteInputType,
+    offset: usize,
+}
+
+impl NoteInputsSchema {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [NoteInputsSchema] instantiated from the provided named fields.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Two fields have the same name.
+    /// - The fields occupy more than [MAX_INPUTS_PER_NOTE] inputs.
+    pub fn new<N: Into<String>>(
+        fields: impl IntoIterator<Item = (N, NoteInputType)>,
+    ) -> Result<Self, NoteError> {
+        let mut schema = Self::default();
+        for (name, input_type) in fields {
+            schema = schema.with_field(name, input_type)?;
+        }
+
+        Ok(schema)
+    }
+
+    /// Appends a field with the provided name and type to this schema.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - A field with the same name already exists.
+    /// - The schema would occupy more than [MAX_INPUTS_PER_NOTE] inputs.
+    pub fn with_field(
+        mut self,
+        name: impl Into<String>,
+        input_type: NoteInputType,
+    ) -> Result<Self, NoteError> {
+        let name = name.into();
+        if self.fields.iter().any(|field| field.name == name) {
+            return Err(NoteError::NoteInputsSchemaDuplicateField(name));
+        }
+
+        let offset = self.num_values();
+        let num_values = offset + input_type.num_values();
+        if num_values > MAX_INPUTS_PER_NOTE {
+            return Err(NoteError::too_many_inputs(num_values));
+        }
+
+        self.fields.push(SchemaField { name, input_type, offset });
+
+        Ok(self)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the total number of note inputs occupied by this schema.
+    pub fn num_values(&self) -> usize {
+        self.fields.iter().map(|field| field.input_type.num_values()).sum()
+    }
+
+    /// Returns an iterator over the names, types and first input indices of the schema fields.
+    pub fn fields(&self) -> impl Iterator<Item = (&str, NoteInputType, usize)> {
+        self.fields
+            .iter()
+            .map(|field| (field.name.as_str(), field.input_type, field.offset))
+    }
+
+    /// Returns the type and the first input index of the field with the specified name.
+    ///
+    /// # Errors
+    /// Returns an error if the schema does not contain a field with the specified name.
+    pub fn get(&self, name: &str) -> Result<(NoteInputType, usize), NoteError> {
+        self.find(name).map(|field| (field.input_type, field.offset))
+    }
+
+    // ENCODING / DECODING
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns [NoteInputs] encoding the provided values, one per field of this schema in the
+    /// order in which the fields were declared.
+    ///
+    /// # Errors
+    /// Returns an error if the number of values does not match the number of fields, or if a
+    /// value does not have the type of its field or cannot be encoded as such (e.g., a byte blob
+    /// longer than the maximum length).
+    pub fn encode(&self, values: &[NoteInputValue]) -> Result<NoteInputs, NoteError> {
+        if values.len() != self.fields.len() {
+            return Err(NoteError::InvalidNoteInputs(format!(
+                "expected {} note input fields, but found {}",
+                self.fields.len(),
+                values.len()
+            )));
+        }
+
+        let mut inputs = Vec::with_capacity(self.num_values());
+        for (field, value) in self.fields.iter().zip(values) {
+            value.encode_into(&field.name, field.input_type, &mut inputs)?;
+        }
+
+        NoteInputs::new(inputs)
+    }
+
+    /// Returns the values of all fields of this schema decoded from the provided note inputs, in
+    /// the order in which the fields were declared.
+    ///
+    /// # Errors
+    /// Returns an error if the number of inputs does not match this schema, or if the inputs of a
+    /// field are not a valid encoding of its type.
+    pub fn decode(&self, inputs: &NoteInputs) -> Result<Vec<NoteInputValue>, NoteError> {
+        self.check_num_values(inputs)?;
+
+        self.fields
+            .iter()
+            .map(|field| Self::decode_field_values(field, inputs.values()))
+            .collect()
+    }
+
+    /// Returns the value of the field with the specified name decoded from the provided note
+    /// inputs.
+    ///
+    /// # Errors
+    /// Returns an error if the field does not exist, if the number of inputs does not match this
+    /// schema, or if the inputs of the field are not a valid encoding of its type.
+    pub fn decode_field(
+        &self,
+        inputs: &NoteInputs,
+        name: &str,
+    ) -> Result<NoteInputValue, NoteError> {
+        let field = self.find(name)?;
+        self.check_num_values(inputs)?;
+
+        Self::decode_field_values(field, inputs.values())
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    fn find(&self, name: &str) -> Result<&SchemaField, NoteError> {
+        self.fields
+            .iter()
+            .find(|field| field.name == name)
+            .ok_or_else(|| NoteError::NoteInputsSchemaFieldNotFound(name.to_string()))
+    }
+
+    fn check_num_values(&self, inputs: &NoteInputs) -> Result<(), NoteError> {
+        let num_values = self.num_values();
+        if inputs.values().len() != num_values {
+            return Err(NoteError::InvalidNoteInputs(format!(
+                "expected {num_values} note inputs, but found {}",
+                inputs.num_values()
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn decode_field_values(
+        field: &SchemaField,
+        values: &[Felt],
+    ) -> Result<NoteInputValue, NoteError> {
+        let values = &values[field.offset..field.offset + field.input_type.num_values()];
+        NoteInputValue::decode(&field.name, field.input_type, values)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn invalid_field(name: &str, reason: &str) -> NoteError {
+    NoteError::InvalidNoteInputs(format!("invalid value of note input field {name}: {reason}"))
+}
+
+fn decode_u32(name: &str, value: Felt) -> Result<u32, NoteError> {
+    u32::try_from(value.as_int()).map_err(|_| invalid_field(name, "value must fit into u32"))
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        accounts::account_id::testing::ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN, ZERO,
+    };
+
+    fn schema() -> NoteInputsSchema {
+        NoteInputsSchema::new([
+            ("target", NoteInputType::AccountId),
+            ("amount", NoteInputType::Amount),
+            ("recipient", NoteInputType::Word),
+            ("memo", NoteInputType::Bytes(6)),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_note_inputs_schema_round_trip() {
+        let schema = schema();
+        assert_eq!(schema.num_values(), 9);
+        assert_eq!(schema.get("recipient").unwrap(), (NoteInputType::Word, 2));
+        assert_eq!(schema.get("memo").unwrap(), (NoteInputType::Bytes(6), 6));
+
+        let target =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+        let recipient = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let values = vec![
+            NoteInputValue::AccountId(target),
+            NoteInputValue::Amount(100),
+            NoteInputValue::Word(recipient),
+            NoteInputValue::Bytes(b"hello".to_vec()),
+        ];
+
+        let inputs = schema.encode(&values).unwrap();
+        assert_eq!(
+            inputs.values()[6..],
+            [Felt::new(5), Felt::from(u32::from_le_bytes(*b"hell")), Felt::new(b'o' as u64)]
+        );
+        assert_eq!(schema.decode(&inputs).unwrap(), values);
+        assert_eq!(schema.decode_field(&inputs, "amount").unwrap(), NoteInputValue::Amount(100));
+    }
+
+    #[test]
+    fn test_note_inputs_schema_validation() {
+        let schema = schema();
+        let target =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+
+        assert!(matches!(
+            schema.clone().with_field("memo", NoteInputType::Felt),
+            Err(NoteError::NoteInputsSchemaDuplicateField(_))
+        ));
+        assert!(matches!(
+            schema
+                .clone()
+                .with_field("big", NoteInputType::Bytes(255))
+                .unwrap()
+                .with_field("bigger", NoteInputType::Bytes(255)),
+            Err(NoteError::TooManyInputs(139))
+        ));
+        assert!(matches!(
+            schema.get("missing"),
+            Err(NoteError::NoteInputsSchemaFieldNotFound(_))
+        ));
+
+        // values must match the types of the fields
+        let mismatched = [
+            NoteInputValue::Felt(ZERO),
+            NoteInputValue::Amount(100),
+            NoteInputValue::Word([ZERO; 4]),
+            NoteInputValue::Bytes(vec![]),
+        ];
+        assert!(matches!(
+            schema.encode(&mismatched),
+            Err(NoteError::NoteInputsSchemaFieldTypeMismatch(_))
+        ));
+
+        let too_long = [
+            NoteInputValue::AccountId(target),
+            NoteInputValue::Amount(100),
+            NoteInputValue::Word([ZERO; 4]),
+            NoteInputValue::Bytes(vec![1; 7]),
+        ];
+        assert!(matches!(schema.encode(&too_long), Err(NoteError::InvalidNoteInputs(_))));
+
+        // inputs must have the length of the schema and valid field encodings
+        let short_inputs = NoteInputs::new(vec![target.into()]).unwrap();
+        assert!(matches!(schema.decode(&short_inputs), Err(NoteError::InvalidNoteInputs(_))));
+
+        let amount = Felt::new(FungibleAsset::MAX_AMOUNT + 1);
+        let mut values = vec![target.into(), amount, ZERO, ZERO, ZERO, ZERO];
+        values.extend([Felt::new(1), ZERO, ZERO]);
+        let invalid_amount = NoteInputs::new(values).unwrap();
+        assert!(matches!(schema.decode(&invalid_amount), Err(NoteError::InvalidNoteInputs(_))));
+        assert!(schema.decode_field(&invalid_amount, "target").is_ok());
+    }
+}
@@ -1,7 +1,10 @@
 use super::{
-    ByteReader, ByteWriter, Deserializable, DeserializationError, NoteError, Serializable,
+    compute_note_hash, ByteReader, ByteWriter, Deserializable, DeserializationError, NoteError,
+    NoteId, NoteMetadata, Serializable,
+};
+use crate::{
+    crypto::merkle::MerklePath, BlockHeader, MAX_BATCHES_PER_BLOCK, MAX_OUTPUT_NOTES_PER_BATCH,
 };
-use crate::{crypto::merkle::MerklePath, MAX_BATCHES_PER_BLOCK, MAX_OUTPUT_NOTES_PER_BATCH};
 
 /// Contains information about the location of a note.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -71,6 +74,38 @@ impl NoteInclusionProof {
     pub fn note_path(&self) -> &MerklePath {
         &self.note_path
     }
+
+    // VERIFICATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Verifies that the note with the specified ID and metadata is included in the note tree of
+    /// the block with the provided header.
+    ///
+    /// The leaves of a block's note tree commit to both the ID and the metadata of a note (see
+    /// [compute_note_hash()]), and thus the metadata is required to verify the inclusion.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The block number of this proof does not match the number of the provided block.
+    /// - The note path does not authenticate the note against the note root of the block.
+    pub fn verify(
+        &self,
+        note_id: NoteId,
+        metadata: &NoteMetadata,
+        block_header: &BlockHeader,
+    ) -> Result<(), NoteError> {
+        if self.location.block_num != block_header.block_num() {
+            return Err(NoteError::InclusionProofBlockMismatch {
+                expected: block_header.block_num(),
+                actual: self.location.block_num,
+            });
+        }
+
+        let note_hash = compute_note_hash(note_id, metadata);
+        self.note_path
+            .verify(self.location.node_index_in_block.into(), note_hash, &block_header.note_root())
+            .map_err(|_| NoteError::NoteNotInBlock(note_id, self.location.block_num))
+    }
 }
 
 // SERIALIZATION
@@ -107,3 +142,58 @@ impl Deserializable for NoteInclusionProof {
         Ok(Self { location, note_path })
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        accounts::{
+            account_id::testing::ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN, AccountId,
+        },
+        block::{BlockNoteIndex, BlockNoteTree},
+        notes::{NoteExecutionHint, NoteTag, NoteType},
+        Digest, Felt, ZERO,
+    };
+
+    fn block_header(block_num: u32, note_root: Digest) -> BlockHeader {
+        let zero = Digest::default();
+        BlockHeader::new(0, zero, block_num, zero, zero, zero, note_root, zero, zero, zero, 0)
+    }
+
+    #[test]
+    fn test_note_inclusion_proof_verification() {
+        let sender =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let metadata = NoteMetadata::new(
+            sender,
+            NoteType::Public,
+            NoteTag::from(0),
+            NoteExecutionHint::always(),
+            ZERO,
+        )
+        .unwrap();
+        let note_id = NoteId::from([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+
+        let index = BlockNoteIndex::new(1, 3).unwrap();
+        let note_tree = BlockNoteTree::with_entries([(index, note_id, metadata)]).unwrap();
+        let header = block_header(7, note_tree.root());
+        let proof =
+            NoteInclusionProof::new(7, index.leaf_index_value(), note_tree.get_note_path(index))
+                .unwrap();
+
+        assert!(proof.verify(note_id, &metadata, &header).is_ok());
+
+        let other_note_id = NoteId::from([Felt::new(5), ZERO, ZERO, ZERO]);
+        assert!(matches!(
+            proof.verify(other_note_id, &metadata, &header),
+            Err(NoteError::NoteNotInBlock(..))
+        ));
+        assert!(matches!(
+            proof.verify(note_id, &metadata, &block_header(8, note_tree.root())),
+            Err(NoteError::InclusionProofBlockMismatch { expected: 8, actual: 7 })
+        ));
+    }
+}
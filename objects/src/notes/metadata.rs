@@ -2,7 +2,8 @@ use alloc::string::ToString;
 
 use super::{
     execution_hint::NoteExecutionHint, AccountId, ByteReader, ByteWriter, Deserializable,
-    DeserializationError, Felt, NoteError, NoteTag, NoteType, Serializable, Word,
+    DeserializationError, Felt, NoteError, NoteExecutionMode, NoteTag, NoteType, Serializable,
+    Word,
 };
 
 // NOTE METADATA
@@ -84,6 +85,19 @@ impl NoteMetadata {
     pub fn is_private(&self) -> bool {
         self.note_type == NoteType::Private
     }
+
+    /// Returns the execution mode of the note, as defined by its tag.
+    pub fn execution_mode(&self) -> NoteExecutionMode {
+        self.tag.execution_mode()
+    }
+
+    /// Returns `true` if the note is intended to be executed by the network.
+    ///
+    /// Such notes are always public, since the tag of a note with [NoteExecutionMode::Network]
+    /// can only be combined with [NoteType::Public].
+    pub fn is_network_note(&self) -> bool {
+        self.execution_mode().is_network()
+    }
 }
 
 impl From<NoteMetadata> for Word {
@@ -179,6 +193,52 @@ fn unmerge_type_and_hint(value: u64) -> Result<(NoteType, NoteExecutionHint), No
 mod tests {
 
     use super::*;
+    use crate::accounts::account_id::testing::{
+        ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN, ACCOUNT_ID_SENDER,
+    };
+
+    #[test]
+    fn test_network_note() {
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let on_chain =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let network_tag = NoteTag::from_account_id(on_chain, NoteExecutionMode::Network).unwrap();
+        let local_tag = NoteTag::from_account_id(on_chain, NoteExecutionMode::Local).unwrap();
+
+        let metadata = NoteMetadata::new(
+            sender,
+            NoteType::Public,
+            network_tag,
+            NoteExecutionHint::always(),
+            Felt::default(),
+        )
+        .unwrap();
+        assert_eq!(metadata.execution_mode(), NoteExecutionMode::Network);
+        assert!(metadata.is_network_note());
+
+        let metadata = NoteMetadata::new(
+            sender,
+            NoteType::Private,
+            local_tag,
+            NoteExecutionHint::always(),
+            Felt::default(),
+        )
+        .unwrap();
+        assert_eq!(metadata.execution_mode(), NoteExecutionMode::Local);
+        assert!(!metadata.is_network_note());
+
+        // network execution cannot be combined with private notes
+        assert_eq!(
+            NoteMetadata::new(
+                sender,
+                NoteType::Private,
+                network_tag,
+                NoteExecutionHint::always(),
+                Felt::default(),
+            ),
+            Err(NoteError::NetworkExecutionRequiresPublicNote(NoteType::Private))
+        );
+    }
 
     #[test]
     fn test_merge_and_unmerge() {
@@ -11,6 +11,9 @@ use crate::{accounts::AccountId, assets::Asset, Digest, Felt, Hasher, NoteError,
 mod assets;
 pub use assets::NoteAssets;
 
+mod builder;
+pub use builder::NoteBuilder;
+
 mod details;
 pub use details::NoteDetails;
 
@@ -20,17 +23,28 @@ pub use header::{compute_note_hash, NoteHeader};
 mod inputs;
 pub use inputs::NoteInputs;
 
+mod inputs_schema;
+pub use inputs_schema::{NoteInputType, NoteInputValue, NoteInputsSchema};
+
 mod metadata;
 pub use metadata::NoteMetadata;
 
+#[cfg(feature = "encryption")]
+mod envelope;
+#[cfg(feature = "encryption")]
+pub use envelope::{EncryptedNote, NoteEncryptionPublicKey, NoteEncryptionSecretKey};
+
 mod execution_hint;
 pub use execution_hint::NoteExecutionHint;
 
+mod note_args;
+pub use note_args::NoteArgs;
+
 mod note_id;
 pub use note_id::NoteId;
 
 mod note_tag;
-pub use note_tag::{NoteExecutionMode, NoteTag};
+pub use note_tag::{NoteExecutionMode, NoteTag, NoteTagTarget};
 
 mod note_type;
 pub use note_type::NoteType;
@@ -38,6 +52,9 @@ pub use note_type::NoteType;
 mod nullifier;
 pub use nullifier::Nullifier;
 
+mod nullifier_filter;
+pub use nullifier_filter::NullifierFilter;
+
 mod location;
 pub use location::{NoteInclusionProof, NoteLocation};
 
@@ -0,0 +1,129 @@
+use super::{
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Felt, NoteError, Serializable,
+    Word, WORD_SIZE, ZERO,
+};
+use crate::EMPTY_WORD;
+
+// NOTE ARGS
+// ================================================================================================
+
+/// Arguments passed to a note script by the account consuming the note.
+///
+/// Unlike note inputs, which are fixed by the creator of the note and committed to by its
+/// recipient, note args are chosen by the consumer of the note for every execution of the note
+/// script (e.g., the fill amounts of a partially fillable swap note). Note args are not committed
+/// to by the note, and thus note scripts must treat them as untrusted input.
+///
+/// Note args are a single word which is placed on top of the stack right before the note script is
+/// executed, such that the note script starts with the stack `[NOTE_ARGS]`. Following the usual
+/// word layout, the last element of the word ends up on top of the stack, i.e., note args
+/// `[a, b, c, d]` result in the stack `[d, c, b, a, ...]`. Notes consumed without explicit args
+/// receive [NoteArgs::EMPTY].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct NoteArgs(Word);
+
+impl NoteArgs {
+    /// Note args consisting of four zero elements, which are passed to notes consumed without
+    /// explicit args.
+    pub const EMPTY: Self = Self(EMPTY_WORD);
+
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns new [NoteArgs] instantiated from the provided word.
+    pub const fn new(args: Word) -> Self {
+        Self(args)
+    }
+
+    /// Returns new [NoteArgs] instantiated from the provided elements, padded with zeros to a
+    /// full word.
+    ///
+    /// # Errors
+    /// Returns an error if more than [WORD_SIZE] elements are provided.
+    pub fn from_elements(elements: &[Felt]) -> Result<Self, NoteError> {
+        if elements.len() > WORD_SIZE {
+            return Err(NoteError::TooManyNoteArgs(elements.len()));
+        }
+
+        let mut args = [ZERO; WORD_SIZE];
+        args[..elements.len()].copy_from_slice(elements);
+        Ok(Self(args))
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the note args as a word.
+    pub fn as_word(&self) -> Word {
+        self.0
+    }
+
+    /// Returns the elements of the note args.
+    pub fn as_elements(&self) -> &[Felt] {
+        &self.0
+    }
+
+    /// Returns true if all elements of the note args are zero.
+    pub fn is_empty(&self) -> bool {
+        self.0 == EMPTY_WORD
+    }
+}
+
+// CONVERSIONS
+// ================================================================================================
+
+impl From<Word> for NoteArgs {
+    fn from(args: Word) -> Self {
+        Self(args)
+    }
+}
+
+impl AsRef<Word> for NoteArgs {
+    fn as_ref(&self) -> &Word {
+        &self.0
+    }
+}
+
+impl From<NoteArgs> for Word {
+    fn from(args: NoteArgs) -> Self {
+        args.0
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for NoteArgs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.0.write_into(target);
+    }
+}
+
+impl Deserializable for NoteArgs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Word::read_from(source).map(Self)
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{Felt, NoteArgs, NoteError, ZERO};
+
+    #[test]
+    fn note_args_are_padded_to_a_word() {
+        let args = NoteArgs::from_elements(&[Felt::new(40), Felt::new(20)]).unwrap();
+        assert_eq!(args.as_word(), [Felt::new(40), Felt::new(20), ZERO, ZERO]);
+        assert!(!args.is_empty());
+
+        assert_eq!(NoteArgs::from_elements(&[]).unwrap(), NoteArgs::EMPTY);
+        assert!(NoteArgs::EMPTY.is_empty());
+
+        assert!(matches!(
+            NoteArgs::from_elements(&[ZERO; 5]),
+            Err(NoteError::TooManyNoteArgs(5))
+        ));
+    }
+}
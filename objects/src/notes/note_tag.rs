@@ -33,6 +33,46 @@ pub enum NoteExecutionMode {
     Local = LOCAL_EXECUTION,
 }
 
+impl NoteExecutionMode {
+    /// Returns true if this execution mode is [NoteExecutionMode::Network].
+    pub fn is_network(&self) -> bool {
+        *self == NoteExecutionMode::Network
+    }
+}
+
+impl From<NoteExecutionMode> for u8 {
+    fn from(mode: NoteExecutionMode) -> Self {
+        mode as u8
+    }
+}
+
+impl TryFrom<u8> for NoteExecutionMode {
+    type Error = NoteError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            NETWORK_EXECUTION => Ok(NoteExecutionMode::Network),
+            LOCAL_EXECUTION => Ok(NoteExecutionMode::Local),
+            _ => Err(NoteError::InvalidNoteExecutionMode(value)),
+        }
+    }
+}
+
+// NOTE TAG TARGET
+// ================================================================================================
+
+/// The decoded target of a [NoteTag], as returned by [NoteTag::target()].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoteTagTarget {
+    /// The note targets a specific public account for network execution. The value contains the
+    /// 31 most significant bits of the account ID.
+    NetworkAccount(u32),
+    /// The note targets a use case. For tags with the `0b11` prefix, this also covers tags
+    /// created for an account with [NoteExecutionMode::Local], in which case `use_case_id` holds
+    /// the 14 most significant bits of the account ID and `payload` is 0.
+    UseCase { use_case_id: u16, payload: u16 },
+}
+
 // NOTE TAG
 // ================================================================================================
 
@@ -163,6 +203,15 @@ impl NoteTag {
         Ok(Self(execution_bits | use_case_bits | payload_bits))
     }
 
+    /// Returns a new [NoteTag] for a note intended to be consumed locally by the specified account.
+    ///
+    /// This is equivalent to [NoteTag::from_account_id()] with [NoteExecutionMode::Local], which
+    /// never fails.
+    pub fn for_account(account_id: AccountId) -> Self {
+        Self::from_account_id(account_id, NoteExecutionMode::Local)
+            .expect("local execution tags can be created for any account")
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -176,10 +225,9 @@ impl NoteTag {
 
     /// Returns note execution mode defined by this tag.
     ///
-    /// If the most significant bit of the tag is 0 or the 3 most significant bits are equal to
-    /// 0b101, the note is intended for local execution; otherwise, the note is intended for
-    /// network execution.
-    pub fn execution_hint(&self) -> NoteExecutionMode {
+    /// If the most significant bit of the tag is 1, the note is intended for local execution;
+    /// otherwise, the note is intended for network execution.
+    pub fn execution_mode(&self) -> NoteExecutionMode {
         let first_bit = self.0 >> 31;
 
         if first_bit == (LOCAL_EXECUTION as u32) {
@@ -189,18 +237,51 @@ impl NoteTag {
         }
     }
 
+    /// Returns note execution mode defined by this tag.
+    #[deprecated(note = "use `execution_mode()` instead")]
+    pub fn execution_hint(&self) -> NoteExecutionMode {
+        self.execution_mode()
+    }
+
     /// Returns the inner u32 value of this tag.
     pub fn inner(&self) -> u32 {
         self.0
     }
 
+    /// Decodes the target of this tag.
+    ///
+    /// Together with [NoteTag::execution_mode()], this inverts the constructors of [NoteTag]:
+    /// - Tags created via [NoteTag::from_account_id()] with [NoteExecutionMode::Network] decode
+    ///   into [NoteTagTarget::NetworkAccount].
+    /// - Tags created via [NoteTag::for_public_use_case()] and [NoteTag::for_local_use_case()]
+    ///   decode into [NoteTagTarget::UseCase] with the original use case ID and payload.
+    /// - Tags created via [NoteTag::from_account_id()] with [NoteExecutionMode::Local] decode into
+    ///   [NoteTagTarget::UseCase], since they share their layout with local use case tags. Use
+    ///   [NoteTag::matches_account()] to check such tags against a specific account.
+    pub fn target(&self) -> NoteTagTarget {
+        if self.is_single_target() {
+            NoteTagTarget::NetworkAccount(self.0)
+        } else {
+            NoteTagTarget::UseCase {
+                use_case_id: ((self.0 >> 16) & 0x3fff) as u16,
+                payload: self.0 as u16,
+            }
+        }
+    }
+
+    /// Returns true if this tag is the tag derived from the specified account ID via
+    /// [NoteTag::from_account_id()] with the execution mode of this tag.
+    pub fn matches_account(&self, account_id: AccountId) -> bool {
+        NoteTag::from_account_id(account_id, self.execution_mode()).is_ok_and(|tag| tag == *self)
+    }
+
     // UTILITY METHODS
     // --------------------------------------------------------------------------------------------
 
     /// Returns an error if this tag is not consistent with the specified note type, and self
     /// otherwise.
     pub fn validate(&self, note_type: NoteType) -> Result<Self, NoteError> {
-        if self.execution_hint() == NoteExecutionMode::Network && note_type != NoteType::Public {
+        if self.execution_mode() == NoteExecutionMode::Network && note_type != NoteType::Public {
             return Err(NoteError::NetworkExecutionRequiresPublicNote(note_type));
         }
 
@@ -286,7 +367,7 @@ impl Deserializable for NoteTag {
 
 #[cfg(test)]
 mod tests {
-    use super::{NoteExecutionMode, NoteTag};
+    use super::{NoteExecutionMode, NoteTag, NoteTagTarget};
     use crate::{
         accounts::{
             account_id::testing::{
@@ -339,7 +420,7 @@ mod tests {
             let tag = NoteTag::from_account_id(on_chain, NoteExecutionMode::Network)
                 .expect("Tag generation must work with network exeuction and on-chain accounts");
             assert!(tag.is_single_target());
-            assert_eq!(tag.execution_hint(), NoteExecutionMode::Network);
+            assert_eq!(tag.execution_mode(), NoteExecutionMode::Network);
 
             assert_eq!(
                 tag.validate(NoteType::Public),
@@ -360,7 +441,7 @@ mod tests {
             let tag = NoteTag::from_account_id(off_chain, NoteExecutionMode::Local)
                 .expect("Tag generation must work with network execution and off-chain account id");
             assert!(!tag.is_single_target());
-            assert_eq!(tag.execution_hint(), NoteExecutionMode::Local);
+            assert_eq!(tag.execution_mode(), NoteExecutionMode::Local);
 
             assert_eq!(
                 tag.validate(NoteType::Public),
@@ -383,7 +464,7 @@ mod tests {
             let tag = NoteTag::from_account_id(on_chain, NoteExecutionMode::Local)
                 .expect("Tag generation must work with network exeuction and on-chain accounts");
             assert!(!tag.is_single_target());
-            assert_eq!(tag.execution_hint(), NoteExecutionMode::Local);
+            assert_eq!(tag.execution_mode(), NoteExecutionMode::Local);
 
             assert_eq!(
                 tag.validate(NoteType::Public),
@@ -403,6 +484,16 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_execution_mode_conversions() {
+        for mode in [NoteExecutionMode::Network, NoteExecutionMode::Local] {
+            assert_eq!(NoteExecutionMode::try_from(u8::from(mode)), Ok(mode));
+        }
+        assert!(NoteExecutionMode::Network.is_network());
+        assert!(!NoteExecutionMode::Local.is_network());
+        assert_eq!(NoteExecutionMode::try_from(2), Err(NoteError::InvalidNoteExecutionMode(2)));
+    }
+
     #[test]
     fn test_from_account_id_values() {
         let off_chain =
@@ -426,6 +517,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_note_tag_target_roundtrip() {
+        let on_chain =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let off_chain = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+
+        let tag = NoteTag::from_account_id(on_chain, NoteExecutionMode::Network).unwrap();
+        assert_eq!(tag.target(), NoteTagTarget::NetworkAccount((u64::from(on_chain) >> 33) as u32));
+        assert!(tag.matches_account(on_chain));
+        assert!(!tag.matches_account(off_chain));
+
+        let tag = NoteTag::for_account(off_chain);
+        assert_eq!(tag, NoteTag::from_account_id(off_chain, NoteExecutionMode::Local).unwrap());
+        assert_eq!(tag.execution_mode(), NoteExecutionMode::Local);
+        assert!(tag.matches_account(off_chain));
+        assert!(!tag.matches_account(on_chain));
+
+        for execution in [NoteExecutionMode::Local, NoteExecutionMode::Network] {
+            let tag = NoteTag::for_public_use_case(0x2abc, 0x1234, execution).unwrap();
+            assert_eq!(tag.execution_mode(), execution);
+            assert_eq!(
+                tag.target(),
+                NoteTagTarget::UseCase { use_case_id: 0x2abc, payload: 0x1234 }
+            );
+        }
+
+        let tag = NoteTag::for_local_use_case(0x3fff, 0xffff).unwrap();
+        assert_eq!(tag.execution_mode(), NoteExecutionMode::Local);
+        assert_eq!(tag.target(), NoteTagTarget::UseCase { use_case_id: 0x3fff, payload: 0xffff });
+    }
+
     #[test]
     fn test_for_public_use_case() {
         // NETWORK
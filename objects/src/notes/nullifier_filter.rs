@@ -0,0 +1,279 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use super::{
+    ByteReader, ByteWriter, Deserializable, DeserializationError, NoteError, Nullifier,
+    Serializable,
+};
+use crate::{block::Block, crypto::merkle::SmtProof, BlockHeader, EMPTY_WORD, ONE, ZERO};
+
+// NULLIFIER FILTER
+// ================================================================================================
+
+/// A watch-list of nullifiers used to detect when tracked notes are consumed.
+///
+/// The filter keeps track of a set of nullifiers together with the number of the block in which
+/// each of them was consumed (if any). It can be updated either from the list of nullifiers
+/// produced in a block, or from openings of the nullifier tree committed to by a block header.
+///
+/// The filter also records the number of the last block it was updated with, so that wallets can
+/// persist the filter and resume syncing from that block.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NullifierFilter {
+    nullifiers: BTreeMap<Nullifier, Option<u32>>,
+    last_block_num: u32,
+}
+
+impl NullifierFilter {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [NullifierFilter] tracking the provided nullifiers, none of which is known to
+    /// be consumed.
+    pub fn new(nullifiers: impl IntoIterator<Item = Nullifier>) -> Self {
+        Self {
+            nullifiers: nullifiers.into_iter().map(|nullifier| (nullifier, None)).collect(),
+            last_block_num: 0,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of tracked nullifiers.
+    pub fn num_tracked(&self) -> usize {
+        self.nullifiers.len()
+    }
+
+    /// Returns true if the specified nullifier is tracked by this filter.
+    pub fn contains(&self, nullifier: &Nullifier) -> bool {
+        self.nullifiers.contains_key(nullifier)
+    }
+
+    /// Returns the number of the block in which the specified nullifier was consumed, or `None`
+    /// if the nullifier is not tracked or not known to be consumed.
+    pub fn consumed_at(&self, nullifier: &Nullifier) -> Option<u32> {
+        self.nullifiers.get(nullifier).copied().flatten()
+    }
+
+    /// Returns the number of the last block this filter was updated with.
+    pub fn last_block_num(&self) -> u32 {
+        self.last_block_num
+    }
+
+    /// Returns an iterator over the tracked nullifiers which are not known to be consumed.
+    pub fn unconsumed(&self) -> impl Iterator<Item = &Nullifier> {
+        self.nullifiers
+            .iter()
+            .filter_map(|(nullifier, block_num)| block_num.is_none().then_some(nullifier))
+    }
+
+    /// Returns an iterator over the consumed nullifiers together with the numbers of the blocks
+    /// in which they were consumed.
+    pub fn consumed(&self) -> impl Iterator<Item = (&Nullifier, u32)> {
+        self.nullifiers
+            .iter()
+            .filter_map(|(nullifier, block_num)| block_num.map(|block_num| (nullifier, block_num)))
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Starts tracking the specified nullifier.
+    ///
+    /// If the nullifier is already tracked, its state is left unchanged.
+    pub fn track(&mut self, nullifier: Nullifier) {
+        self.nullifiers.entry(nullifier).or_insert(None);
+    }
+
+    /// Stops tracking the specified nullifier and returns true if it was tracked.
+    pub fn untrack(&mut self, nullifier: &Nullifier) -> bool {
+        self.nullifiers.remove(nullifier).is_some()
+    }
+
+    /// Updates this filter with the nullifiers produced in the block with the provided header,
+    /// and returns the tracked nullifiers which were consumed in that block.
+    pub fn apply_block_nullifiers<'a>(
+        &mut self,
+        header: &BlockHeader,
+        nullifiers: impl IntoIterator<Item = &'a Nullifier>,
+    ) -> Vec<Nullifier> {
+        let block_num = header.block_num();
+        let mut consumed = Vec::new();
+
+        for nullifier in nullifiers {
+            if let Some(state) = self.nullifiers.get_mut(nullifier) {
+                if state.is_none() {
+                    *state = Some(block_num);
+                    consumed.push(*nullifier);
+                }
+            }
+        }
+
+        self.last_block_num = self.last_block_num.max(block_num);
+
+        consumed
+    }
+
+    /// Updates this filter with the nullifiers produced in the provided block, and returns the
+    /// tracked nullifiers which were consumed in that block.
+    pub fn apply_block(&mut self, block: &Block) -> Vec<Nullifier> {
+        self.apply_block_nullifiers(&block.header(), block.nullifiers())
+    }
+
+    /// Updates the state of the specified nullifier from an opening of the nullifier tree
+    /// committed to by the provided block header.
+    ///
+    /// Returns the number of the block in which the nullifier was consumed, or `None` if the
+    /// opening proves that the nullifier has not been consumed as of the provided block. If the
+    /// nullifier is tracked, its state is updated accordingly.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The opening does not match the nullifier root of the block header.
+    /// - The opening does not contain the specified nullifier.
+    /// - The value stored for the nullifier is not a valid block number.
+    pub fn apply_nullifier_proof(
+        &mut self,
+        header: &BlockHeader,
+        nullifier: Nullifier,
+        proof: &SmtProof,
+    ) -> Result<Option<u32>, NoteError> {
+        let root = proof.compute_root();
+        if root != header.nullifier_root() {
+            return Err(NoteError::NullifierProofRootMismatch {
+                expected: header.nullifier_root(),
+                actual: root,
+            });
+        }
+
+        let value = proof
+            .get(&nullifier.inner())
+            .ok_or(NoteError::NullifierProofKeyMismatch(nullifier))?;
+
+        // consumed nullifiers are stored in the nullifier tree as [block_num, 1, 0, 0]
+        let block_num = if value == EMPTY_WORD {
+            None
+        } else {
+            if value[1] != ONE || value[2..] != [ZERO; 2] {
+                return Err(NoteError::InvalidNullifierProofValue(value));
+            }
+            let block_num = u32::try_from(value[0].as_int())
+                .map_err(|_| NoteError::InvalidNullifierProofValue(value))?;
+            Some(block_num)
+        };
+
+        if let Some(state) = self.nullifiers.get_mut(&nullifier) {
+            if state.is_none() {
+                *state = block_num;
+            }
+        }
+
+        Ok(block_num)
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for NullifierFilter {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u32(self.last_block_num);
+        target.write_usize(self.nullifiers.len());
+        for (nullifier, block_num) in self.nullifiers.iter() {
+            nullifier.write_into(target);
+            block_num.write_into(target);
+        }
+    }
+}
+
+impl Deserializable for NullifierFilter {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let last_block_num = source.read_u32()?;
+        let num_nullifiers = source.read_usize()?;
+
+        let mut nullifiers = BTreeMap::new();
+        for _ in 0..num_nullifiers {
+            let nullifier = Nullifier::read_from(source)?;
+            let block_num = Option::<u32>::read_from(source)?;
+            if nullifiers.insert(nullifier, block_num).is_some() {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "duplicate nullifier {nullifier} in nullifier filter"
+                )));
+            }
+        }
+
+        Ok(Self { nullifiers, last_block_num })
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{crypto::merkle::Smt, Digest, Felt};
+
+    fn nullifier(value: u64) -> Nullifier {
+        Nullifier::from([ZERO, ZERO, ZERO, Felt::new(value)])
+    }
+
+    fn block_header(block_num: u32, nullifier_root: Digest) -> BlockHeader {
+        let zero = Digest::default();
+        BlockHeader::new(0, zero, block_num, zero, zero, nullifier_root, zero, zero, zero, zero, 0)
+    }
+
+    #[test]
+    fn test_nullifier_filter_apply_block() {
+        let mut filter = NullifierFilter::new([nullifier(1), nullifier(2)]);
+        filter.track(nullifier(3));
+
+        let header = block_header(5, Digest::default());
+        let consumed = filter.apply_block_nullifiers(&header, &[nullifier(2), nullifier(4)]);
+
+        assert_eq!(consumed, vec![nullifier(2)]);
+        assert_eq!(filter.consumed_at(&nullifier(2)), Some(5));
+        assert_eq!(filter.consumed_at(&nullifier(1)), None);
+        assert_eq!(filter.consumed_at(&nullifier(4)), None);
+        assert_eq!(filter.unconsumed().collect::<Vec<_>>(), vec![&nullifier(1), &nullifier(3)]);
+        assert_eq!(filter.last_block_num(), 5);
+
+        // a nullifier is reported as consumed only once
+        let header = block_header(6, Digest::default());
+        assert!(filter.apply_block_nullifiers(&header, &[nullifier(2)]).is_empty());
+        assert_eq!(filter.consumed().collect::<Vec<_>>(), vec![(&nullifier(2), 5)]);
+
+        assert!(filter.untrack(&nullifier(3)));
+        assert!(!filter.contains(&nullifier(3)));
+
+        assert_eq!(NullifierFilter::read_from_bytes(&filter.to_bytes()).unwrap(), filter);
+    }
+
+    #[test]
+    fn test_nullifier_filter_apply_proof() {
+        let block_num_word = [Felt::new(7), ONE, ZERO, ZERO];
+        let tree = Smt::with_entries([(nullifier(1).inner(), block_num_word)]).unwrap();
+        let header = block_header(8, tree.root());
+
+        let mut filter = NullifierFilter::new([nullifier(1), nullifier(2)]);
+
+        let proof = tree.open(&nullifier(1).inner());
+        assert_eq!(filter.apply_nullifier_proof(&header, nullifier(1), &proof).unwrap(), Some(7));
+        assert_eq!(filter.consumed_at(&nullifier(1)), Some(7));
+
+        let proof = tree.open(&nullifier(2).inner());
+        assert_eq!(filter.apply_nullifier_proof(&header, nullifier(2), &proof).unwrap(), None);
+        assert_eq!(filter.consumed_at(&nullifier(2)), None);
+
+        // an opening for another nullifier or against another root is rejected
+        assert!(matches!(
+            filter.apply_nullifier_proof(&header, nullifier(3), &proof),
+            Err(NoteError::NullifierProofKeyMismatch(_))
+        ));
+        let other_header = block_header(8, Digest::default());
+        assert!(matches!(
+            filter.apply_nullifier_proof(&other_header, nullifier(2), &proof),
+            Err(NoteError::NullifierProofRootMismatch { .. })
+        ));
+    }
+}
@@ -1,6 +1,6 @@
 use super::{
-    ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, NoteAssets, NoteHeader,
-    NoteId, NoteMetadata, Serializable,
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Note, NoteAssets,
+    NoteError, NoteHeader, NoteId, NoteMetadata, NoteRecipient, Serializable,
 };
 
 // PARTIAL NOTE
@@ -47,6 +47,25 @@ impl PartialNote {
     pub fn assets(&self) -> &NoteAssets {
         &self.assets
     }
+
+    /// Returns the full [Note] built from this partial note and the provided recipient.
+    ///
+    /// This is used when the details of the recipient are not known when the note is created
+    /// (e.g., because they are kept private by the counterparty), but are supplied later.
+    ///
+    /// # Errors
+    /// Returns an error if the digest of the provided recipient does not match the recipient
+    /// digest of this note.
+    pub fn complete(self, recipient: NoteRecipient) -> Result<Note, NoteError> {
+        if recipient.digest() != self.recipient_digest {
+            return Err(NoteError::RecipientDigestMismatch {
+                expected: self.recipient_digest,
+                actual: recipient.digest(),
+            });
+        }
+
+        Ok(Note::new(self.assets, self.metadata, recipient))
+    }
 }
 
 impl From<&PartialNote> for NoteHeader {
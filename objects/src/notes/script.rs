@@ -1,7 +1,12 @@
-use alloc::{string::ToString, sync::Arc, vec::Vec};
+use alloc::{
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
 use core::fmt::Display;
 
 use assembly::{Assembler, Compile};
+use miden_crypto::utils::SliceReader;
 use vm_core::{
     mast::{MastForest, MastNodeId},
     prettier::PrettyPrint,
@@ -10,8 +15,9 @@ use vm_core::{
 
 use super::{Digest, Felt};
 use crate::{
+    debug_info::{read_debug_section, write_debug_section},
     utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
-    NoteError,
+    NoteError, ScriptDebugInfo,
 };
 
 // NOTE SCRIPT
@@ -21,10 +27,17 @@ use crate::{
 ///
 /// A note's script represents a program which must be executed for a note to be consumed. As such
 /// it defines the rules and side effects of consuming a given note.
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// A note script may carry [ScriptDebugInfo] retaining the source code it was compiled from (see
+/// [NoteScript::compile_with_debug_info()]). The debug info does not affect the hash of the script
+/// nor its equality, and it is not provided to the transaction kernel. It is only serialized via
+/// [NoteScript::to_bytes_with_debug_info()], and thus, is stripped from notes and all other
+/// objects containing the script.
+#[derive(Debug, Clone)]
 pub struct NoteScript {
     mast: Arc<MastForest>,
     entrypoint: MastNodeId,
+    debug_info: Option<ScriptDebugInfo>,
 }
 
 impl NoteScript {
@@ -36,6 +49,7 @@ impl NoteScript {
         Self {
             entrypoint: code.entrypoint(),
             mast: code.mast_forest().clone(),
+            debug_info: None,
         }
     }
 
@@ -51,6 +65,25 @@ impl NoteScript {
         Ok(Self::new(program))
     }
 
+    /// Returns a new [NoteScript] compiled in debug mode from the provided source code using the
+    /// specified assembler, which retains the source code in its [ScriptDebugInfo].
+    ///
+    /// Compiling in debug mode records the source locations of the instructions in the MAST of the
+    /// script, which can be mapped to lines of the retained source code via
+    /// [ScriptDebugInfo::line_column()]. The hash of the script is the same as if it was compiled
+    /// via [Self::compile()].
+    ///
+    /// # Errors
+    /// Returns an error if the compilation of the provided source code fails.
+    pub fn compile_with_debug_info(
+        source_code: impl Into<String>,
+        assembler: Assembler,
+    ) -> Result<Self, NoteError> {
+        let source_code = source_code.into();
+        let script = Self::compile(source_code.as_str(), assembler.with_debug_mode(true))?;
+        Ok(script.with_debug_info(ScriptDebugInfo::new(source_code)))
+    }
+
     /// Returns a new [NoteScript] deserialized from the provided bytes.
     ///
     /// # Errors
@@ -59,13 +92,49 @@ impl NoteScript {
         Self::read_from_bytes(bytes).map_err(NoteError::NoteScriptDeserializationError)
     }
 
+    /// Returns a new [NoteScript] deserialized from the provided bytes written by
+    /// [Self::to_bytes_with_debug_info()], retaining the debug info of the script.
+    ///
+    /// # Errors
+    /// Returns an error if note script deserialization fails.
+    pub fn from_bytes_with_debug_info(bytes: &[u8]) -> Result<Self, NoteError> {
+        let mut source = SliceReader::new(bytes);
+        let script = Self::read_from(&mut source)
+            .and_then(|script| {
+                let debug_info = read_debug_section(&mut source)?;
+                Ok(Self { debug_info, ..script })
+            })
+            .map_err(NoteError::NoteScriptDeserializationError)?;
+
+        if source.has_more_bytes() {
+            return Err(NoteError::NoteScriptDeserializationError(
+                DeserializationError::UnconsumedBytes,
+            ));
+        }
+
+        Ok(script)
+    }
+
     /// Returns a new [NoteScript] instantiated from the provided components.
     ///
     /// # Panics
     /// Panics if the specified entrypoint is not in the provided MAST forest.
     pub fn from_parts(mast: Arc<MastForest>, entrypoint: MastNodeId) -> Self {
         assert!(mast.get_node_by_id(entrypoint).is_some());
-        Self { mast, entrypoint }
+        Self { mast, entrypoint, debug_info: None }
+    }
+
+    /// Returns this note script with the provided debug info attached.
+    pub fn with_debug_info(mut self, debug_info: ScriptDebugInfo) -> Self {
+        self.debug_info = Some(debug_info);
+        self
+    }
+
+    /// Returns this note script without its debug info, e.g., for distributing it in release
+    /// builds.
+    pub fn without_debug_info(mut self) -> Self {
+        self.debug_info = None;
+        self
     }
 
     // PUBLIC ACCESSORS
@@ -80,8 +149,32 @@ impl NoteScript {
     pub fn mast(&self) -> Arc<MastForest> {
         self.mast.clone()
     }
+
+    /// Returns the debug info of this note script, if it was retained.
+    pub fn debug_info(&self) -> Option<&ScriptDebugInfo> {
+        self.debug_info.as_ref()
+    }
+
+    /// Serializes this note script into bytes, followed by a debug section containing its debug
+    /// info.
+    ///
+    /// Unlike [Serializable::to_bytes()], which never includes debug info, the returned bytes
+    /// must be deserialized via [Self::from_bytes_with_debug_info()].
+    pub fn to_bytes_with_debug_info(&self) -> Vec<u8> {
+        let mut bytes = self.to_bytes();
+        write_debug_section(self.debug_info.as_ref(), &mut bytes);
+        bytes
+    }
 }
 
+impl PartialEq for NoteScript {
+    fn eq(&self, other: &Self) -> bool {
+        self.mast == other.mast && self.entrypoint == other.entrypoint
+    }
+}
+
+impl Eq for NoteScript {}
+
 // CONVERSIONS INTO NOTE SCRIPT
 // ================================================================================================
 
@@ -200,7 +293,10 @@ impl Display for NoteScript {
 #[cfg(test)]
 mod tests {
     use super::{Assembler, Felt, NoteScript, Vec};
-    use crate::testing::notes::DEFAULT_NOTE_CODE;
+    use crate::{
+        testing::notes::DEFAULT_NOTE_CODE,
+        utils::serde::{Deserializable, Serializable},
+    };
 
     #[test]
     fn test_note_script_to_from_felt() {
@@ -213,4 +309,34 @@ mod tests {
 
         assert_eq!(note_script, decoded);
     }
+
+    #[test]
+    fn test_note_script_debug_info_round_trip() {
+        let source = "begin\n    push.1\n    drop\nend";
+        let script = NoteScript::compile_with_debug_info(source, Assembler::default()).unwrap();
+        let debug_info = script.debug_info().unwrap();
+        assert_eq!(debug_info.source(), source);
+        assert_eq!(debug_info.line(2), Some("    push.1"));
+
+        // debug info affects neither the hash nor the equality of scripts
+        let release_script = NoteScript::compile(source, Assembler::default()).unwrap();
+        assert_eq!(script.hash(), release_script.hash());
+        assert_eq!(script.clone().without_debug_info(), script);
+
+        // debug info is retained only in the debug section
+        let decoded =
+            NoteScript::from_bytes_with_debug_info(&script.to_bytes_with_debug_info()).unwrap();
+        assert_eq!(decoded.debug_info(), script.debug_info());
+        assert_eq!(decoded, script);
+
+        let decoded = NoteScript::read_from_bytes(&script.to_bytes()).unwrap();
+        assert!(decoded.debug_info().is_none());
+        assert_eq!(decoded, script);
+
+        let decoded =
+            NoteScript::from_bytes_with_debug_info(&release_script.to_bytes_with_debug_info())
+                .unwrap();
+        assert!(decoded.debug_info().is_none());
+        assert!(NoteScript::from_bytes_with_debug_info(&script.to_bytes()).is_err());
+    }
 }
@@ -0,0 +1,94 @@
+// This file is @generated by prost-build.
+/// A digest consisting of 4 field elements, in canonical form.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct Digest {
+    #[prost(fixed64, tag = "1")]
+    pub d0: u64,
+    #[prost(fixed64, tag = "2")]
+    pub d1: u64,
+    #[prost(fixed64, tag = "3")]
+    pub d2: u64,
+    #[prost(fixed64, tag = "4")]
+    pub d3: u64,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct AccountId {
+    #[prost(fixed64, tag = "1")]
+    pub id: u64,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct BlockHeader {
+    #[prost(uint32, tag = "1")]
+    pub version: u32,
+    #[prost(message, optional, tag = "2")]
+    pub prev_hash: ::core::option::Option<Digest>,
+    #[prost(fixed32, tag = "3")]
+    pub block_num: u32,
+    #[prost(message, optional, tag = "4")]
+    pub chain_root: ::core::option::Option<Digest>,
+    #[prost(message, optional, tag = "5")]
+    pub account_root: ::core::option::Option<Digest>,
+    #[prost(message, optional, tag = "6")]
+    pub nullifier_root: ::core::option::Option<Digest>,
+    #[prost(message, optional, tag = "7")]
+    pub note_root: ::core::option::Option<Digest>,
+    #[prost(message, optional, tag = "8")]
+    pub tx_hash: ::core::option::Option<Digest>,
+    #[prost(message, optional, tag = "9")]
+    pub kernel_root: ::core::option::Option<Digest>,
+    #[prost(message, optional, tag = "10")]
+    pub proof_hash: ::core::option::Option<Digest>,
+    #[prost(fixed32, tag = "11")]
+    pub timestamp: u32,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct NoteMetadata {
+    #[prost(message, optional, tag = "1")]
+    pub sender: ::core::option::Option<AccountId>,
+    #[prost(uint32, tag = "2")]
+    pub note_type: u32,
+    #[prost(fixed32, tag = "3")]
+    pub tag: u32,
+    #[prost(fixed64, tag = "4")]
+    pub execution_hint: u64,
+    #[prost(fixed64, tag = "5")]
+    pub aux: u64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Note {
+    #[prost(message, optional, tag = "1")]
+    pub metadata: ::core::option::Option<NoteMetadata>,
+    /// Canonical encoding of the note details, i.e., the assets and the recipient of the note.
+    #[prost(bytes = "vec", tag = "2")]
+    pub details: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Account {
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<AccountId>,
+    #[prost(message, optional, tag = "2")]
+    pub hash: ::core::option::Option<Digest>,
+    /// Canonical encoding of the account.
+    #[prost(bytes = "vec", tag = "3")]
+    pub details: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProvenTransaction {
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<Digest>,
+    #[prost(message, optional, tag = "2")]
+    pub account_id: ::core::option::Option<AccountId>,
+    /// Canonical encoding of the proven transaction.
+    #[prost(bytes = "vec", tag = "3")]
+    pub transaction: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionWitness {
+    #[prost(message, optional, tag = "1")]
+    pub account_id: ::core::option::Option<AccountId>,
+    #[prost(fixed32, tag = "2")]
+    pub block_num: u32,
+    /// Canonical encoding of the transaction witness.
+    #[prost(bytes = "vec", tag = "3")]
+    pub witness: ::prost::alloc::vec::Vec<u8>,
+}
@@ -0,0 +1,389 @@
+//! Protobuf messages for the core object types and conversions between the messages and the
+//! objects.
+//!
+//! The messages are specified in `proto/objects.proto`, and their Rust definitions are generated
+//! from it by the build script. Simple objects, such as block headers and note metadata, are
+//! represented field by field. Complex objects are carried as their canonical binary encoding
+//! (i.e., [Serializable] and [Deserializable]), together with the fields which are commonly needed
+//! to index them without decoding the full object. When converting a message back
+//! into an object, these fields are checked against the decoded object.
+
+use crate::{
+    accounts::{Account, AccountId},
+    notes::{Note, NoteDetails, NoteExecutionHint, NoteMetadata, NoteTag, NoteType},
+    transaction::{ProvenTransaction, TransactionWitness},
+    utils::serde::{Deserializable, Serializable},
+    BlockHeader, Digest, Felt, ProtoConversionError, StarkField,
+};
+
+mod generated;
+pub use generated::objects as proto;
+
+/// Contains the protobuf definitions of the messages.
+pub const PROTO_MESSAGES: &str = include_str!("../../proto/objects.proto");
+
+// DIGEST
+// ================================================================================================
+
+impl From<Digest> for proto::Digest {
+    fn from(digest: Digest) -> Self {
+        let [d0, d1, d2, d3] = digest.as_elements() else {
+            unreachable!("digest consists of 4 elements")
+        };
+
+        Self {
+            d0: d0.as_int(),
+            d1: d1.as_int(),
+            d2: d2.as_int(),
+            d3: d3.as_int(),
+        }
+    }
+}
+
+impl TryFrom<proto::Digest> for Digest {
+    type Error = ProtoConversionError;
+
+    fn try_from(digest: proto::Digest) -> Result<Self, Self::Error> {
+        Ok(Digest::new([
+            felt_from_u64(digest.d0)?,
+            felt_from_u64(digest.d1)?,
+            felt_from_u64(digest.d2)?,
+            felt_from_u64(digest.d3)?,
+        ]))
+    }
+}
+
+// ACCOUNT ID
+// ================================================================================================
+
+impl From<AccountId> for proto::AccountId {
+    fn from(account_id: AccountId) -> Self {
+        Self { id: account_id.into() }
+    }
+}
+
+impl TryFrom<proto::AccountId> for AccountId {
+    type Error = ProtoConversionError;
+
+    fn try_from(account_id: proto::AccountId) -> Result<Self, Self::Error> {
+        AccountId::try_from(account_id.id).map_err(ProtoConversionError::AccountError)
+    }
+}
+
+// BLOCK HEADER
+// ================================================================================================
+
+impl From<BlockHeader> for proto::BlockHeader {
+    fn from(header: BlockHeader) -> Self {
+        Self {
+            version: header.version(),
+            prev_hash: Some(header.prev_hash().into()),
+            block_num: header.block_num(),
+            chain_root: Some(header.chain_root().into()),
+            account_root: Some(header.account_root().into()),
+            nullifier_root: Some(header.nullifier_root().into()),
+            note_root: Some(header.note_root().into()),
+            tx_hash: Some(header.tx_hash().into()),
+            kernel_root: Some(header.kernel_root().into()),
+            proof_hash: Some(header.proof_hash().into()),
+            timestamp: header.timestamp(),
+        }
+    }
+}
+
+impl TryFrom<proto::BlockHeader> for BlockHeader {
+    type Error = ProtoConversionError;
+
+    fn try_from(header: proto::BlockHeader) -> Result<Self, Self::Error> {
+        Ok(BlockHeader::new(
+            header.version,
+            required(header.prev_hash, "prev_hash")?.try_into()?,
+            header.block_num,
+            required(header.chain_root, "chain_root")?.try_into()?,
+            required(header.account_root, "account_root")?.try_into()?,
+            required(header.nullifier_root, "nullifier_root")?.try_into()?,
+            required(header.note_root, "note_root")?.try_into()?,
+            required(header.tx_hash, "tx_hash")?.try_into()?,
+            required(header.kernel_root, "kernel_root")?.try_into()?,
+            required(header.proof_hash, "proof_hash")?.try_into()?,
+            header.timestamp,
+        ))
+    }
+}
+
+// NOTE METADATA
+// ================================================================================================
+
+impl From<NoteMetadata> for proto::NoteMetadata {
+    fn from(metadata: NoteMetadata) -> Self {
+        Self {
+            sender: Some(metadata.sender().into()),
+            note_type: metadata.note_type() as u32,
+            tag: metadata.tag().into(),
+            execution_hint: metadata.execution_hint().into(),
+            aux: metadata.aux().as_int(),
+        }
+    }
+}
+
+impl TryFrom<proto::NoteMetadata> for NoteMetadata {
+    type Error = ProtoConversionError;
+
+    fn try_from(metadata: proto::NoteMetadata) -> Result<Self, Self::Error> {
+        let sender = required(metadata.sender, "sender")?.try_into()?;
+        let note_type =
+            NoteType::try_from(metadata.note_type).map_err(ProtoConversionError::NoteError)?;
+        let execution_hint = NoteExecutionHint::try_from(metadata.execution_hint)
+            .map_err(ProtoConversionError::NoteError)?;
+        let aux = felt_from_u64(metadata.aux)?;
+
+        NoteMetadata::new(sender, note_type, NoteTag::from(metadata.tag), execution_hint, aux)
+            .map_err(ProtoConversionError::NoteError)
+    }
+}
+
+// NOTE
+// ================================================================================================
+
+impl From<&Note> for proto::Note {
+    fn from(note: &Note) -> Self {
+        Self {
+            metadata: Some((*note.metadata()).into()),
+            details: NoteDetails::from(note).to_bytes(),
+        }
+    }
+}
+
+impl TryFrom<proto::Note> for Note {
+    type Error = ProtoConversionError;
+
+    fn try_from(note: proto::Note) -> Result<Self, Self::Error> {
+        let metadata = required(note.metadata, "metadata")?.try_into()?;
+        let (assets, recipient) = NoteDetails::read_from_bytes(&note.details)
+            .map_err(ProtoConversionError::DeserializationError)?
+            .into_parts();
+
+        Ok(Note::new(assets, metadata, recipient))
+    }
+}
+
+// ACCOUNT
+// ================================================================================================
+
+impl From<&Account> for proto::Account {
+    fn from(account: &Account) -> Self {
+        Self {
+            id: Some(account.id().into()),
+            hash: Some(account.hash().into()),
+            details: account.to_bytes(),
+        }
+    }
+}
+
+impl TryFrom<proto::Account> for Account {
+    type Error = ProtoConversionError;
+
+    fn try_from(account: proto::Account) -> Result<Self, Self::Error> {
+        let account_id: AccountId = required(account.id, "id")?.try_into()?;
+        let hash: Digest = required(account.hash, "hash")?.try_into()?;
+        let details = Account::read_from_bytes(&account.details)
+            .map_err(ProtoConversionError::DeserializationError)?;
+
+        if details.id() != account_id {
+            return Err(ProtoConversionError::InconsistentField("id"));
+        }
+        if details.hash() != hash {
+            return Err(ProtoConversionError::InconsistentField("hash"));
+        }
+
+        Ok(details)
+    }
+}
+
+// PROVEN TRANSACTION
+// ================================================================================================
+
+impl From<&ProvenTransaction> for proto::ProvenTransaction {
+    fn from(transaction: &ProvenTransaction) -> Self {
+        Self {
+            id: Some(Digest::from(transaction.id()).into()),
+            account_id: Some(transaction.account_id().into()),
+            transaction: transaction.to_bytes(),
+        }
+    }
+}
+
+impl TryFrom<proto::ProvenTransaction> for ProvenTransaction {
+    type Error = ProtoConversionError;
+
+    fn try_from(transaction: proto::ProvenTransaction) -> Result<Self, Self::Error> {
+        let id: Digest = required(transaction.id, "id")?.try_into()?;
+        let account_id: AccountId = required(transaction.account_id, "account_id")?.try_into()?;
+        let details = ProvenTransaction::read_from_bytes(&transaction.transaction)
+            .map_err(ProtoConversionError::DeserializationError)?;
+
+        if Digest::from(details.id()) != id {
+            return Err(ProtoConversionError::InconsistentField("id"));
+        }
+        if details.account_id() != account_id {
+            return Err(ProtoConversionError::InconsistentField("account_id"));
+        }
+
+        Ok(details)
+    }
+}
+
+// TRANSACTION WITNESS
+// ================================================================================================
+
+impl From<&TransactionWitness> for proto::TransactionWitness {
+    fn from(witness: &TransactionWitness) -> Self {
+        Self {
+            account_id: Some(witness.account().id().into()),
+            block_num: witness.block_header().block_num(),
+            witness: witness.to_bytes(),
+        }
+    }
+}
+
+impl TryFrom<proto::TransactionWitness> for TransactionWitness {
+    type Error = ProtoConversionError;
+
+    fn try_from(witness: proto::TransactionWitness) -> Result<Self, Self::Error> {
+        let account_id: AccountId = required(witness.account_id, "account_id")?.try_into()?;
+        let details = TransactionWitness::read_from_bytes(&witness.witness)
+            .map_err(ProtoConversionError::DeserializationError)?;
+
+        if details.account().id() != account_id {
+            return Err(ProtoConversionError::InconsistentField("account_id"));
+        }
+        if details.block_header().block_num() != witness.block_num {
+            return Err(ProtoConversionError::InconsistentField("block_num"));
+        }
+
+        Ok(details)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the value of the specified message field, or an error if the field is not set.
+fn required<T>(value: Option<T>, field: &'static str) -> Result<T, ProtoConversionError> {
+    value.ok_or(ProtoConversionError::MissingField(field))
+}
+
+/// Returns a field element with the provided value, or an error if the value is not a canonical
+/// field element.
+fn felt_from_u64(value: u64) -> Result<Felt, ProtoConversionError> {
+    if value >= Felt::MODULUS {
+        return Err(ProtoConversionError::InvalidFieldElement(value));
+    }
+
+    Ok(Felt::new(value))
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use prost::Message;
+
+    use super::proto;
+    use crate::{
+        accounts::{
+            account_id::testing::{
+                ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+                ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN, ACCOUNT_ID_SENDER,
+            },
+            Account, AccountCode, AccountId, AccountStorage,
+        },
+        assets::{Asset, AssetVault, FungibleAsset},
+        notes::{Note, NoteExecutionHint, NoteMetadata, NoteTag, NoteType},
+        testing::notes::MockNoteBuilder,
+        BlockHeader, Digest, Felt, ProtoConversionError, StarkField,
+    };
+
+    #[test]
+    fn block_header_roundtrip() {
+        let header = BlockHeader::mock(3, None, None, &[], Digest::default());
+
+        let bytes = proto::BlockHeader::from(header).encode_to_vec();
+        let decoded = proto::BlockHeader::decode(bytes.as_slice()).unwrap();
+
+        assert_eq!(BlockHeader::try_from(decoded).unwrap(), header);
+
+        let missing_root = proto::BlockHeader { note_root: None, ..decoded };
+        assert_eq!(
+            BlockHeader::try_from(missing_root),
+            Err(ProtoConversionError::MissingField("note_root"))
+        );
+    }
+
+    #[test]
+    fn digest_must_be_canonical() {
+        let digest = proto::Digest { d0: 0, d1: Felt::MODULUS, d2: 0, d3: 0 };
+        assert_eq!(
+            Digest::try_from(digest),
+            Err(ProtoConversionError::InvalidFieldElement(Felt::MODULUS))
+        );
+    }
+
+    #[test]
+    fn note_roundtrip() {
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+        let note = MockNoteBuilder::new(sender, rand::rngs::mock::StepRng::new(0, 1))
+            .add_assets([asset])
+            .build(&crate::assembly::Assembler::default())
+            .unwrap();
+
+        let bytes = proto::Note::from(&note).encode_to_vec();
+        let decoded = proto::Note::decode(bytes.as_slice()).unwrap();
+        assert_eq!(Note::try_from(decoded).unwrap(), note);
+
+        let metadata = NoteMetadata::new(
+            sender,
+            NoteType::Public,
+            NoteTag::from(1234),
+            NoteExecutionHint::after_block(100),
+            Felt::new(27),
+        )
+        .unwrap();
+        assert_eq!(NoteMetadata::try_from(proto::NoteMetadata::from(metadata)).unwrap(), metadata);
+
+        // the note type must be valid
+        let invalid_metadata = proto::NoteMetadata { note_type: 0, ..metadata.into() };
+        assert!(matches!(
+            NoteMetadata::try_from(invalid_metadata),
+            Err(ProtoConversionError::NoteError(_))
+        ));
+    }
+
+    #[test]
+    fn account_roundtrip() {
+        let account = Account::from_parts(
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap(),
+            AssetVault::default(),
+            AccountStorage::mock(),
+            AccountCode::mock(),
+            Felt::new(1),
+        );
+
+        let message = proto::Account::from(&account);
+        let decoded = proto::Account::decode(message.encode_to_vec().as_slice()).unwrap();
+        assert_eq!(Account::try_from(decoded).unwrap(), account);
+
+        // the account hash must match the account details
+        let inconsistent = proto::Account {
+            hash: Some(Digest::default().into()),
+            ..message
+        };
+        assert_eq!(
+            Account::try_from(inconsistent),
+            Err(ProtoConversionError::InconsistentField("hash"))
+        );
+    }
+}
@@ -0,0 +1,232 @@
+//! Implementations of [serde::Serialize] and [serde::Deserialize] for the core object types.
+//!
+//! Rather than mirroring the internal structure of each type, the objects are serialized using
+//! their canonical binary encoding (i.e., [Serializable] and [Deserializable]). For
+//! human-readable formats such as JSON, the bytes are hex-encoded into a single string, while for
+//! binary formats they are written as a byte array. This guarantees that the serde representation
+//! is always consistent with the one used by the rest of the protocol.
+//!
+//! Identifiers and assets which have a canonical textual form are serialized using that form in
+//! human-readable formats.
+
+use alloc::{string::String, vec::Vec};
+use core::{fmt, fmt::Write, marker::PhantomData, str::FromStr};
+
+use serde::{
+    de::{self, SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{
+    accounts::{Account, AccountCode, AccountDelta, AccountId, AccountStorage},
+    assets::{Asset, AssetVault, FungibleAsset, NonFungibleAsset},
+    notes::{Note, NoteDetails, NoteHeader, NoteId, NoteInclusionProof, NoteMetadata, Nullifier},
+    transaction::{
+        InputNote, OutputNote, ProvenTransaction, TransactionArgs, TransactionId,
+        TransactionInputs, TransactionScript, TransactionWitness,
+    },
+    utils::serde::{Deserializable, Serializable},
+    BlockHeader,
+};
+
+// BYTE-ENCODED TYPES
+// ================================================================================================
+
+/// Implements [Serialize] and [Deserialize] for types implementing [Serializable] and
+/// [Deserializable] by encoding them as hex strings or raw bytes.
+macro_rules! impl_serde_via_bytes {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl Serialize for $ty {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serialize_bytes(&self.to_bytes(), serializer)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $ty {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let bytes = deserialize_bytes(deserializer)?;
+                    <$ty>::read_from_bytes(&bytes).map_err(de::Error::custom)
+                }
+            }
+        )+
+    };
+}
+
+impl_serde_via_bytes!(
+    Account,
+    AccountCode,
+    AccountDelta,
+    AccountStorage,
+    AssetVault,
+    BlockHeader,
+    InputNote,
+    Note,
+    NoteDetails,
+    NoteHeader,
+    NoteId,
+    NoteInclusionProof,
+    NoteMetadata,
+    Nullifier,
+    OutputNote,
+    ProvenTransaction,
+    TransactionArgs,
+    TransactionId,
+    TransactionInputs,
+    TransactionScript,
+    TransactionWitness,
+);
+
+// STRING-ENCODED TYPES
+// ================================================================================================
+
+/// Implements [Serialize] and [Deserialize] for types which have a canonical textual form. The
+/// textual form is used for human-readable formats and the binary encoding for all other formats.
+macro_rules! impl_serde_via_str {
+    ($($ty:ty => $parse:expr),+ $(,)?) => {
+        $(
+            impl Serialize for $ty {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    if serializer.is_human_readable() {
+                        serializer.collect_str(self)
+                    } else {
+                        serializer.serialize_bytes(&self.to_bytes())
+                    }
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $ty {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    if deserializer.is_human_readable() {
+                        let value = String::deserialize(deserializer)?;
+                        $parse(value.as_str()).map_err(de::Error::custom)
+                    } else {
+                        let bytes = deserialize_bytes(deserializer)?;
+                        <$ty>::read_from_bytes(&bytes).map_err(de::Error::custom)
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_serde_via_str!(
+    AccountId => AccountId::from_hex,
+    Asset => Asset::from_str,
+    FungibleAsset => FungibleAsset::from_str,
+    NonFungibleAsset => NonFungibleAsset::from_str,
+);
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Serializes the provided bytes as a `0x`-prefixed hex string for human-readable formats, and as
+/// a byte array otherwise.
+fn serialize_bytes<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        let mut hex = String::with_capacity(2 + bytes.len() * 2);
+        hex.push_str("0x");
+        for byte in bytes {
+            write!(hex, "{byte:02x}").expect("writing to a string cannot fail");
+        }
+        serializer.serialize_str(&hex)
+    } else {
+        serializer.serialize_bytes(bytes)
+    }
+}
+
+/// Deserializes bytes which were serialized via [serialize_bytes()].
+fn deserialize_bytes<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(BytesVisitor(PhantomData))
+    } else {
+        deserializer.deserialize_byte_buf(BytesVisitor(PhantomData))
+    }
+}
+
+/// A visitor accepting either a `0x`-prefixed hex string, a byte array, or a sequence of bytes.
+struct BytesVisitor<'de>(PhantomData<&'de ()>);
+
+impl<'de> Visitor<'de> for BytesVisitor<'de> {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a hex string or a byte array")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        let hex = value
+            .strip_prefix("0x")
+            .ok_or_else(|| E::custom("hex string must start with `0x`"))?;
+        if !hex.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+            return Err(E::custom("hex string contains invalid characters"));
+        }
+        if hex.len() % 2 != 0 {
+            return Err(E::custom("hex string must have an even number of characters"));
+        }
+
+        Ok((0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).expect("hex digits were validated"))
+            .collect())
+    }
+
+    fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Self::Value, E> {
+        Ok(value.to_vec())
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, value: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(value)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+
+        Ok(bytes)
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        accounts::account_id::testing::{
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+        },
+        testing::notes::MockNoteBuilder,
+    };
+
+    #[test]
+    fn test_serde_json_roundtrip() {
+        let account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let json = serde_json::to_string(&account_id).unwrap();
+        assert_eq!(json, format!("\"{account_id}\""));
+        assert_eq!(serde_json::from_str::<AccountId>(&json).unwrap(), account_id);
+
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+        let json = serde_json::to_string(&asset).unwrap();
+        assert_eq!(json, format!("\"{asset}\""));
+        assert_eq!(serde_json::from_str::<Asset>(&json).unwrap(), asset);
+
+        let note = MockNoteBuilder::new(account_id, rand::rngs::mock::StepRng::new(0, 1))
+            .add_assets([asset])
+            .build(&crate::assembly::Assembler::default())
+            .unwrap();
+        let json = serde_json::to_string(&note).unwrap();
+        assert_eq!(serde_json::from_str::<Note>(&json).unwrap(), note);
+
+        let json = serde_json::to_string(note.metadata()).unwrap();
+        assert_eq!(&serde_json::from_str::<NoteMetadata>(&json).unwrap(), note.metadata());
+
+        assert!(serde_json::from_str::<NoteMetadata>("\"0x0\"").is_err());
+        assert!(serde_json::from_str::<NoteMetadata>("\"1234\"").is_err());
+    }
+}
@@ -0,0 +1,147 @@
+use std::io::{self, Read, Write};
+
+use vm_core::utils::ReadAdapter;
+
+use crate::utils::serde::{ByteWriter, Deserializable, DeserializationError, Serializable};
+
+// STREAM SERIALIZABLE
+// ================================================================================================
+
+/// An extension of [Serializable] which writes values directly into a [std::io::Write].
+///
+/// Unlike [Serializable::to_bytes()], this never materializes the serialized value in memory, and
+/// thus should be preferred for large values, e.g., accounts with many assets. Since values are
+/// written in many small pieces, unbuffered writers (e.g., files) should be wrapped into a
+/// [std::io::BufWriter].
+pub trait StreamSerializable: Serializable {
+    /// Serializes this value into the provided writer.
+    ///
+    /// # Errors
+    /// Returns the first error returned by the writer; nothing is written after such an error.
+    fn write_into_stream<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        let mut target = IoWriter { writer, error: None };
+        self.write_into(&mut target);
+
+        match target.error {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T: Serializable> StreamSerializable for T {}
+
+// STREAM DESERIALIZABLE
+// ================================================================================================
+
+/// An extension of [Deserializable] which reads values directly from a [std::io::Read].
+///
+/// Unlike [Deserializable::read_from_bytes()], this does not require the serialized value to be
+/// loaded into memory before deserializing it. Note that the reader is read in chunks, and thus
+/// bytes following the serialized value may be consumed from it as well.
+pub trait StreamDeserializable: Deserializable {
+    /// Deserializes a value of this type from the provided reader.
+    ///
+    /// # Errors
+    /// Returns an error if the reader fails, or if the bytes it provides are not a valid
+    /// serialization of a value of this type.
+    fn read_from_stream<R: Read>(reader: &mut R) -> Result<Self, DeserializationError> {
+        Self::read_from(&mut ReadAdapter::new(reader))
+    }
+}
+
+impl<T: Deserializable> StreamDeserializable for T {}
+
+// IO WRITER
+// ================================================================================================
+
+/// A [ByteWriter] which forwards all bytes to a [std::io::Write], keeping track of the first error
+/// returned by it.
+struct IoWriter<'a, W: Write> {
+    writer: &'a mut W,
+    error: Option<io::Error>,
+}
+
+impl<W: Write> ByteWriter for IoWriter<'_, W> {
+    fn write_u8(&mut self, value: u8) {
+        self.write_bytes(&[value]);
+    }
+
+    fn write_bytes(&mut self, values: &[u8]) {
+        if self.error.is_none() {
+            if let Err(err) = self.writer.write_all(values) {
+                self.error = Some(err);
+            }
+        }
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use std::io::{self, Write};
+
+    use assembly::Assembler;
+    use vm_core::FieldElement;
+
+    use super::{StreamDeserializable, StreamSerializable};
+    use crate::{
+        accounts::{
+            account_id::testing::ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN, Account,
+        },
+        assets::{AssetVault, FungibleAsset},
+        utils::serde::Serializable,
+        Felt,
+    };
+
+    /// A writer which accepts a limited number of bytes.
+    struct LimitedWriter(usize);
+
+    impl Write for LimitedWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            if buf.len() > self.0 {
+                return Err(io::Error::new(io::ErrorKind::WriteZero, "writer is full"));
+            }
+            self.0 -= buf.len();
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn stream_serialization_round_trip() {
+        let account = Account::mock(
+            ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+            Felt::ONE,
+            Assembler::default(),
+        );
+        let vault = AssetVault::new(&[FungibleAsset::mock(100)]).unwrap();
+
+        let mut bytes = Vec::new();
+        account.write_into_stream(&mut bytes).unwrap();
+        assert_eq!(bytes, account.to_bytes());
+        assert_eq!(Account::read_from_stream(&mut bytes.as_slice()).unwrap(), account);
+
+        let mut bytes = Vec::new();
+        vault.write_into_stream(&mut bytes).unwrap();
+        assert_eq!(bytes, vault.to_bytes());
+        assert_eq!(AssetVault::read_from_stream(&mut bytes.as_slice()).unwrap(), vault);
+    }
+
+    #[test]
+    fn stream_serialization_reports_writer_errors() {
+        let vault = AssetVault::mock();
+
+        let mut writer = LimitedWriter(vault.to_bytes().len() - 1);
+        assert!(vault.write_into_stream(&mut writer).is_err());
+
+        let mut writer = LimitedWriter(vault.to_bytes().len());
+        assert!(vault.write_into_stream(&mut writer).is_ok());
+    }
+}
@@ -0,0 +1,382 @@
+//! [proptest] strategies for generating random but valid Miden objects.
+//!
+//! The [Arbitrary] implementations in this module allow using `any::<T>()` for [AccountId],
+//! [Asset], [NoteAssets], [AccountStorage] and [AccountDelta]. Strategies which require
+//! parameters, or which generate objects that must be consistent with each other (e.g., an account
+//! together with a delta which can be applied to it), are provided as functions.
+
+use alloc::vec::Vec;
+
+use proptest::{collection::vec, prelude::*};
+use vm_core::{Felt, Word};
+use vm_processor::Digest;
+
+use crate::{
+    accounts::{
+        Account, AccountCode, AccountDelta, AccountId, AccountStorage, AccountStorageDelta,
+        AccountStorageMode, AccountType, AccountVaultDelta, StorageMap, StorageMapDelta,
+        StorageSlot,
+    },
+    assets::{Asset, AssetVault, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails},
+    notes::NoteAssets,
+};
+
+/// The maximum number of storage slots in generated account storage.
+const MAX_STORAGE_SLOTS: usize = 8;
+
+/// The maximum number of entries in generated storage maps.
+const MAX_MAP_ENTRIES: usize = 8;
+
+/// The maximum number of assets in generated vaults, notes, and deltas.
+const MAX_ASSETS: usize = 8;
+
+// ACCOUNT IDS
+// ================================================================================================
+
+impl Arbitrary for AccountType {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            Just(AccountType::FungibleFaucet),
+            Just(AccountType::NonFungibleFaucet),
+            Just(AccountType::RegularAccountImmutableCode),
+            Just(AccountType::RegularAccountUpdatableCode),
+        ]
+        .boxed()
+    }
+}
+
+impl Arbitrary for AccountStorageMode {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        prop_oneof![Just(AccountStorageMode::Public), Just(AccountStorageMode::Private)].boxed()
+    }
+}
+
+impl Arbitrary for AccountId {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        (any::<AccountType>(), any::<AccountStorageMode>())
+            .prop_flat_map(|(account_type, storage_mode)| {
+                account_id_strategy(account_type, storage_mode)
+            })
+            .boxed()
+    }
+}
+
+/// Returns a strategy generating account IDs of the specified type and storage mode.
+pub fn account_id_strategy(
+    account_type: AccountType,
+    storage_mode: AccountStorageMode,
+) -> impl Strategy<Value = AccountId> {
+    any::<[u8; 8]>()
+        .prop_map(move |bytes| AccountId::new_with_type_and_mode(bytes, account_type, storage_mode))
+}
+
+/// Returns a strategy generating faucet IDs of the specified type with any storage mode.
+fn faucet_id_strategy(account_type: AccountType) -> impl Strategy<Value = AccountId> {
+    any::<AccountStorageMode>()
+        .prop_flat_map(move |storage_mode| account_id_strategy(account_type, storage_mode))
+}
+
+// ASSETS
+// ================================================================================================
+
+impl Arbitrary for Asset {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            fungible_asset_strategy(1..=FungibleAsset::MAX_AMOUNT),
+            non_fungible_asset_strategy(),
+        ]
+        .boxed()
+    }
+}
+
+/// Returns a strategy generating fungible assets with amounts generated by the provided strategy.
+pub fn fungible_asset_strategy(amount: impl Strategy<Value = u64>) -> impl Strategy<Value = Asset> {
+    (faucet_id_strategy(AccountType::FungibleFaucet), amount).prop_map(|(faucet_id, amount)| {
+        FungibleAsset::new(faucet_id, amount)
+            .expect("amount must not exceed the maximum fungible asset amount")
+            .into()
+    })
+}
+
+/// Returns a strategy generating non-fungible assets.
+pub fn non_fungible_asset_strategy() -> impl Strategy<Value = Asset> {
+    (faucet_id_strategy(AccountType::NonFungibleFaucet), vec(any::<u8>(), 1..64)).prop_map(
+        |(faucet_id, data)| {
+            let details = NonFungibleAssetDetails::new(faucet_id, data)
+                .expect("faucet ID must be a non-fungible faucet ID");
+            NonFungibleAsset::new(&details).expect("asset details must be valid").into()
+        },
+    )
+}
+
+/// Returns a strategy generating lists of distinct assets, i.e., lists which contain at most one
+/// fungible asset per faucet and no duplicate non-fungible assets.
+///
+/// Fungible asset amounts are bounded by `u32::MAX`, so that adding two generated assets never
+/// exceeds the maximum fungible asset amount.
+pub fn distinct_assets_strategy(max_assets: usize) -> impl Strategy<Value = Vec<Asset>> {
+    let asset =
+        prop_oneof![fungible_asset_strategy(1..=u32::MAX as u64), non_fungible_asset_strategy()];
+    vec(asset, 0..=max_assets).prop_map(dedup_assets)
+}
+
+impl Arbitrary for NoteAssets {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        distinct_assets_strategy(MAX_ASSETS)
+            .prop_map(|assets| NoteAssets::new(assets).expect("assets must be distinct"))
+            .boxed()
+    }
+}
+
+// ACCOUNT STORAGE
+// ================================================================================================
+
+impl Arbitrary for AccountStorage {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        let slot = prop_oneof![
+            word_strategy().prop_map(StorageSlot::Value),
+            vec((word_strategy(), word_strategy()), 0..=MAX_MAP_ENTRIES).prop_map(|entries| {
+                let entries = entries.into_iter().map(|(key, value)| (Digest::from(key), value));
+                StorageSlot::Map(StorageMap::with_entries(entries).expect("entries must be valid"))
+            }),
+        ];
+
+        vec(slot, 0..=MAX_STORAGE_SLOTS)
+            .prop_map(|slots| AccountStorage::new(slots).expect("number of slots must be valid"))
+            .boxed()
+    }
+}
+
+/// Returns a strategy generating arbitrary words.
+pub fn word_strategy() -> impl Strategy<Value = Word> {
+    any::<[u64; 4]>().prop_map(|values| values.map(Felt::new))
+}
+
+// ACCOUNT DELTAS
+// ================================================================================================
+
+impl Arbitrary for AccountDelta {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        // value updates and map updates use disjoint slot ranges, since a slot cannot be updated
+        // both as a value and as a map
+        let values = vec((0..MAX_STORAGE_SLOTS as u8, word_strategy()), 0..=MAX_STORAGE_SLOTS);
+        let maps = vec(
+            (
+                MAX_STORAGE_SLOTS as u8..2 * MAX_STORAGE_SLOTS as u8,
+                vec((word_strategy(), word_strategy()), 1..=MAX_MAP_ENTRIES),
+            ),
+            0..=MAX_STORAGE_SLOTS,
+        );
+
+        (
+            values,
+            maps,
+            distinct_assets_strategy(MAX_ASSETS),
+            distinct_assets_strategy(MAX_ASSETS),
+            1..u32::MAX,
+        )
+            .prop_map(|(values, maps, added_assets, removed_assets, nonce)| {
+                let maps = maps
+                    .into_iter()
+                    .map(|(index, entries)| (index, StorageMapDelta::from_iters([], entries)));
+                let storage = AccountStorageDelta::from_iters([], values, maps);
+
+                // a non-fungible asset cannot be both added and removed by the same delta
+                let removed_assets = removed_assets.into_iter().filter(|removed| {
+                    !added_assets.iter().any(|added| !added.is_fungible() && added.is_same(removed))
+                });
+                let vault = AccountVaultDelta::from_iters(added_assets.clone(), removed_assets);
+
+                AccountDelta::new(storage, vault, Some(Felt::from(nonce)))
+                    .expect("nonce must be non-zero")
+            })
+            .boxed()
+    }
+}
+
+/// Returns a strategy generating accounts together with deltas which can be applied to them.
+///
+/// The generated deltas update a subset of the storage slots of the account (respecting the types
+/// of the slots), remove a subset of the assets in the vault of the account, add new assets to the
+/// vault, and increment the nonce of the account.
+pub fn account_with_delta_strategy() -> impl Strategy<Value = (Account, AccountDelta)> {
+    (
+        any::<[u8; 8]>(),
+        any::<AccountStorage>(),
+        distinct_assets_strategy(MAX_ASSETS),
+        distinct_assets_strategy(MAX_ASSETS),
+        vec((any::<bool>(), word_strategy(), word_strategy()), MAX_STORAGE_SLOTS),
+        vec(any::<bool>(), MAX_ASSETS),
+    )
+        .prop_map(|(id_bytes, storage, assets, new_assets, slot_updates, removals)| {
+            let account_id = AccountId::new_with_type_and_mode(
+                id_bytes,
+                AccountType::RegularAccountUpdatableCode,
+                AccountStorageMode::Public,
+            );
+
+            let mut storage_delta = AccountStorageDelta::default();
+            for (index, (slot, (update, key, value))) in
+                storage.slots().iter().zip(slot_updates).enumerate()
+            {
+                if !update {
+                    continue;
+                }
+                match slot {
+                    StorageSlot::Value(_) => storage_delta.set_item(index as u8, value),
+                    StorageSlot::Map(_) | StorageSlot::LazyMap(_) => {
+                        storage_delta.set_map_item(index as u8, Digest::from(key), value)
+                    },
+                }
+            }
+
+            let removed_assets: Vec<Asset> = assets
+                .iter()
+                .zip(removals)
+                .filter_map(|(asset, remove)| remove.then_some(*asset))
+                .collect();
+            let added_assets: Vec<Asset> = new_assets
+                .into_iter()
+                .filter(|new_asset| !assets.iter().any(|asset| asset.is_same(new_asset)))
+                .collect();
+            let vault_delta = AccountVaultDelta::from_iters(added_assets, removed_assets);
+
+            let vault = AssetVault::new(&assets).expect("assets must be distinct");
+            let account =
+                Account::from_parts(account_id, vault, storage, AccountCode::mock(), Felt::new(1));
+            let delta = AccountDelta::new(storage_delta, vault_delta, Some(Felt::new(2)))
+                .expect("nonce must be incremented");
+
+            (account, delta)
+        })
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Removes all assets which are the same as a preceding asset in the list (see [Asset::is_same()]).
+fn dedup_assets(assets: Vec<Asset>) -> Vec<Asset> {
+    let mut distinct: Vec<Asset> = Vec::with_capacity(assets.len());
+    for asset in assets {
+        if !distinct.iter().any(|existing| existing.is_same(&asset)) {
+            distinct.push(asset);
+        }
+    }
+    distinct
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use vm_core::{
+        utils::{Deserializable, Serializable},
+        Word,
+    };
+    use vm_processor::Digest;
+
+    use super::account_with_delta_strategy;
+    use crate::{
+        accounts::{AccountDelta, AccountId, AccountStorage},
+        assets::Asset,
+        notes::NoteAssets,
+    };
+
+    proptest! {
+        #[test]
+        fn serialization_round_trips(
+            account_id in any::<AccountId>(),
+            asset in any::<Asset>(),
+            note_assets in any::<NoteAssets>(),
+            storage in any::<AccountStorage>(),
+            delta in any::<AccountDelta>(),
+        ) {
+            prop_assert_eq!(AccountId::read_from_bytes(&account_id.to_bytes()).unwrap(), account_id);
+            prop_assert_eq!(Asset::read_from_bytes(&asset.to_bytes()).unwrap(), asset);
+            prop_assert_eq!(
+                NoteAssets::read_from_bytes(&note_assets.to_bytes()).unwrap(),
+                note_assets
+            );
+            prop_assert_eq!(AccountStorage::read_from_bytes(&storage.to_bytes()).unwrap(), storage);
+            prop_assert_eq!(AccountDelta::read_from_bytes(&delta.to_bytes()).unwrap(), delta);
+        }
+
+        #[test]
+        fn applied_delta_is_reflected_in_account_state(
+            (account, delta) in account_with_delta_strategy()
+        ) {
+            let mut final_account = account.clone();
+            final_account.apply_delta(&delta).unwrap();
+            prop_assert_eq!(final_account.nonce(), delta.nonce().unwrap());
+
+            for (index, value) in delta.storage().values() {
+                prop_assert_eq!(final_account.storage().get_item(*index).unwrap(), Digest::from(*value));
+            }
+            for (index, map_delta) in delta.storage().maps() {
+                for (key, value) in map_delta.leaves() {
+                    let key = Word::from(*key);
+                    prop_assert_eq!(final_account.storage().get_map_item(*index, key).unwrap(), *value);
+                }
+            }
+            for (index, slot) in account.storage().slots().iter().enumerate() {
+                let index = index as u8;
+                if !delta.storage().values().contains_key(&index)
+                    && !delta.storage().maps().contains_key(&index)
+                {
+                    prop_assert_eq!(&final_account.storage().slots()[index as usize], slot);
+                }
+            }
+
+            for asset in delta.vault().added_assets() {
+                match asset {
+                    Asset::Fungible(asset) => prop_assert_eq!(
+                        final_account.vault().get_balance(asset.faucet_id()).unwrap(),
+                        asset.amount()
+                    ),
+                    Asset::NonFungible(_) => {
+                        prop_assert!(final_account.vault().has_non_fungible_asset(asset).unwrap())
+                    },
+                }
+            }
+            for asset in delta.vault().removed_assets() {
+                match asset {
+                    Asset::Fungible(asset) => prop_assert_eq!(
+                        final_account.vault().get_balance(asset.faucet_id()).unwrap(),
+                        0
+                    ),
+                    Asset::NonFungible(_) => {
+                        prop_assert!(!final_account.vault().has_non_fungible_asset(asset).unwrap())
+                    },
+                }
+            }
+
+            // the inverse of the delta restores the initial state of the account
+            let inverse = delta.invert(&account).unwrap();
+            final_account.revert_delta(&inverse).unwrap();
+            prop_assert_eq!(final_account.hash(), account.hash());
+        }
+    }
+}
@@ -18,11 +18,11 @@ use crate::{
 
 pub const DEFAULT_NOTE_CODE: &str = "begin nop end";
 
-// NOTE BUILDER
+// MOCK NOTE BUILDER
 // ================================================================================================
 
 #[derive(Debug, Clone)]
-pub struct NoteBuilder {
+pub struct MockNoteBuilder {
     sender: AccountId,
     inputs: Vec<Felt>,
     assets: Vec<Asset>,
@@ -34,7 +34,7 @@ pub struct NoteBuilder {
     aux: Felt,
 }
 
-impl NoteBuilder {
+impl MockNoteBuilder {
     pub fn new<T: Rng>(sender: AccountId, mut rng: T) -> Self {
         let serial_num = [
             Felt::new(rng.gen()),
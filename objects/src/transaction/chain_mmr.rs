@@ -1,9 +1,9 @@
-use alloc::{collections::BTreeMap, vec::Vec};
+use alloc::{collections::BTreeMap, string::ToString, vec::Vec};
 
 use vm_core::utils::{Deserializable, Serializable};
 
 use crate::{
-    crypto::merkle::{InnerNodeInfo, MmrPeaks, PartialMmr},
+    crypto::merkle::{InnerNodeInfo, MerklePath, MmrPeaks, MmrProof, PartialMmr},
     BlockHeader, ChainMmrError,
 };
 
@@ -86,6 +86,24 @@ impl ChainMmr {
         self.blocks.get(&block_num)
     }
 
+    /// Returns an iterator over the block headers of all blocks present in this chain MMR, in
+    /// ascending order of block numbers.
+    pub fn block_headers(&self) -> impl Iterator<Item = &BlockHeader> {
+        self.blocks.values()
+    }
+
+    /// Returns an inclusion proof of the specified block against the peaks of this chain MMR, or
+    /// None if the block is not present in this chain MMR.
+    pub fn open(&self, block_num: u32) -> Option<MmrProof> {
+        if !self.contains_block(block_num) {
+            return None;
+        }
+
+        self.mmr
+            .open(block_num as usize)
+            .expect("block number is within the chain length")
+    }
+
     // DATA MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -101,6 +119,92 @@ impl ChainMmr {
     pub fn add_block(&mut self, block_header: BlockHeader, track: bool) {
         assert_eq!(block_header.block_num(), self.chain_length() as u32);
         self.mmr.add(block_header.hash(), track);
+        if track {
+            self.blocks.insert(block_header.block_num(), block_header);
+        }
+    }
+
+    /// Appends the provided block header to this chain MMR after checking that it is the header of
+    /// the next block in the chain.
+    ///
+    /// The header is authenticated by checking that its chain root commits to the current peaks
+    /// of this chain MMR. If `track` parameter is set to true, the authentication path for the
+    /// provided block header will be added to this chain MMR.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The block number of the provided block header is not equal to the current chain length.
+    /// - The chain root of the provided block header does not match the peaks of this chain MMR.
+    pub fn append_block(
+        &mut self,
+        block_header: BlockHeader,
+        track: bool,
+    ) -> Result<(), ChainMmrError> {
+        let chain_length = self.chain_length() as u32;
+        if block_header.block_num() != chain_length {
+            return Err(ChainMmrError::block_num_mismatch(chain_length, block_header.block_num()));
+        }
+
+        if block_header.chain_root() != self.peaks().hash_peaks() {
+            return Err(ChainMmrError::chain_root_mismatch(block_header.block_num()));
+        }
+
+        self.add_block(block_header, track);
+
+        Ok(())
+    }
+
+    /// Adds the provided header of a block which is already part of the chain to this chain MMR,
+    /// together with the authentication path for the block.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The block number of the provided block header is not smaller than the chain length.
+    /// - The provided path is not a valid authentication path for the block header against the
+    ///   peaks of this chain MMR.
+    pub fn track_block(
+        &mut self,
+        block_header: BlockHeader,
+        path: &MerklePath,
+    ) -> Result<(), ChainMmrError> {
+        let chain_length = self.chain_length();
+        if block_header.block_num() as usize >= chain_length {
+            return Err(ChainMmrError::block_num_too_big(chain_length, block_header.block_num()));
+        }
+
+        self.mmr
+            .track(block_header.block_num() as usize, block_header.hash(), path)
+            .map_err(|_| ChainMmrError::invalid_block_path(block_header.block_num()))?;
+        self.blocks.insert(block_header.block_num(), block_header);
+
+        Ok(())
+    }
+
+    /// Removes the specified block and its authentication path from this chain MMR, and returns
+    /// the header of the removed block.
+    ///
+    /// Nodes of the authentication path which are still needed to authenticate other blocks are
+    /// retained. Returns None if the block is not present in this chain MMR.
+    pub fn untrack_block(&mut self, block_num: u32) -> Option<BlockHeader> {
+        let block_header = self.blocks.remove(&block_num)?;
+        self.mmr.untrack(block_num as usize);
+
+        Some(block_header)
+    }
+
+    /// Removes all blocks which do not satisfy the provided predicate, together with their
+    /// authentication paths, from this chain MMR.
+    pub fn retain_blocks(&mut self, mut predicate: impl FnMut(&BlockHeader) -> bool) {
+        let removed_blocks: Vec<u32> = self
+            .blocks
+            .values()
+            .filter(|block| !predicate(block))
+            .map(BlockHeader::block_num)
+            .collect();
+
+        for block_num in removed_blocks {
+            self.untrack_block(block_num);
+        }
     }
 
     // ITERATORS
@@ -131,12 +235,10 @@ impl Deserializable for ChainMmr {
     ) -> Result<Self, miden_crypto::utils::DeserializationError> {
         let mmr = PartialMmr::read_from(source)?;
         let block_count = usize::read_from(source)?;
-        let mut blocks = BTreeMap::new();
-        for _ in 0..block_count {
-            let block = BlockHeader::read_from(source)?;
-            blocks.insert(block.block_num(), block);
-        }
-        Ok(Self { mmr, blocks })
+        let blocks = source.read_many::<BlockHeader>(block_count)?;
+
+        Self::new(mmr, blocks)
+            .map_err(|err| miden_crypto::utils::DeserializationError::InvalidValue(err.to_string()))
     }
 }
 // TESTS
@@ -150,7 +252,7 @@ mod tests {
     use crate::{
         alloc::vec::Vec,
         crypto::merkle::{Mmr, PartialMmr},
-        BlockHeader, Digest,
+        BlockHeader, ChainMmrError, Digest,
     };
 
     #[test]
@@ -215,12 +317,80 @@ mod tests {
         assert_eq!(chain_mmr, deserialized);
     }
 
+    #[test]
+    fn test_chain_mmr_append_and_track() {
+        // create a chain of 3 blocks and a chain MMR which does not track any of the blocks
+        let mut mmr = Mmr::default();
+        let mut block_headers = Vec::new();
+        for i in 0..3 {
+            let block_header = chain_block_header(i, &mmr);
+            mmr.add(block_header.hash());
+            block_headers.push(block_header);
+        }
+        let partial_mmr: PartialMmr = mmr.peaks().into();
+        let mut chain_mmr = ChainMmr::new(partial_mmr, Vec::new()).unwrap();
+
+        // only the header of the next block with the correct chain root can be appended
+        assert_eq!(
+            chain_mmr.append_block(int_to_block_header(4), true),
+            Err(ChainMmrError::block_num_mismatch(3, 4))
+        );
+        assert_eq!(
+            chain_mmr.append_block(int_to_block_header(3), true),
+            Err(ChainMmrError::chain_root_mismatch(3))
+        );
+
+        let block_header = chain_block_header(3, &mmr);
+        mmr.add(block_header.hash());
+        chain_mmr.append_block(block_header, true).unwrap();
+
+        assert_eq!(chain_mmr.get_block(3), Some(&block_header));
+        assert_eq!(chain_mmr.open(3), Some(mmr.open(3).unwrap()));
+
+        // an old block can be tracked with a valid authentication path only
+        assert_eq!(chain_mmr.open(1), None);
+        assert_eq!(
+            chain_mmr.track_block(block_headers[1], &mmr.open(0).unwrap().merkle_path),
+            Err(ChainMmrError::invalid_block_path(1))
+        );
+        assert_eq!(
+            chain_mmr.track_block(int_to_block_header(4), &mmr.open(0).unwrap().merkle_path),
+            Err(ChainMmrError::block_num_too_big(4, 4))
+        );
+
+        chain_mmr
+            .track_block(block_headers[1], &mmr.open(1).unwrap().merkle_path)
+            .unwrap();
+        assert_eq!(chain_mmr.open(1), Some(mmr.open(1).unwrap()));
+        assert_eq!(chain_mmr.block_headers().count(), 2);
+
+        // the tracked blocks survive serialization
+        let deserialized = ChainMmr::read_from_bytes(&chain_mmr.to_bytes()).unwrap();
+        assert_eq!(chain_mmr, deserialized);
+
+        // untracked blocks are removed from the chain MMR
+        chain_mmr.retain_blocks(|block| block.block_num() != 1);
+        assert!(!chain_mmr.contains_block(1));
+        assert_eq!(chain_mmr.open(1), None);
+        assert_eq!(chain_mmr.untrack_block(3), Some(block_header));
+        assert_eq!(chain_mmr.block_headers().count(), 0);
+        assert_eq!(chain_mmr.peaks(), mmr.peaks());
+    }
+
+    fn chain_block_header(block_num: u32, mmr: &Mmr) -> BlockHeader {
+        block_header(block_num, mmr.peaks().hash_peaks())
+    }
+
     fn int_to_block_header(block_num: u32) -> BlockHeader {
+        block_header(block_num, Digest::default())
+    }
+
+    fn block_header(block_num: u32, chain_root: Digest) -> BlockHeader {
         BlockHeader::new(
             0,
             Digest::default(),
             block_num,
-            Digest::default(),
+            chain_root,
             Digest::default(),
             Digest::default(),
             Digest::default(),
@@ -3,10 +3,15 @@ use core::cell::OnceCell;
 
 use super::{
     Account, AccountDelta, AccountHeader, AccountId, AdviceInputs, BlockHeader, InputNote,
-    InputNotes, NoteId, OutputNotes, TransactionArgs, TransactionId, TransactionInputs,
-    TransactionOutputs, TransactionWitness,
+    InputNotes, KernelEvent, NoteId, OutputNotes, TransactionArgs, TransactionId,
+    TransactionInputs, TransactionOutputs, TransactionWitness,
+};
+use crate::{
+    accounts::AccountCode,
+    assets::FungibleAsset,
+    notes::{Note, NoteRecipient},
+    ExecutedTransactionError, TransactionOutputError,
 };
-use crate::accounts::AccountCode;
 
 // EXECUTED TRANSACTION
 // ================================================================================================
@@ -31,6 +36,7 @@ pub struct ExecutedTransaction {
     tx_args: TransactionArgs,
     advice_witness: AdviceInputs,
     tx_measurements: TransactionMeasurements,
+    events: Vec<KernelEvent>,
 }
 
 impl ExecutedTransaction {
@@ -62,9 +68,17 @@ impl ExecutedTransaction {
             tx_args,
             advice_witness,
             tx_measurements,
+            events: Vec::new(),
         }
     }
 
+    /// Sets the log of the events emitted by the transaction kernel while executing this
+    /// transaction.
+    pub fn with_events(mut self, events: Vec<KernelEvent>) -> Self {
+        self.events = events;
+        self
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -98,6 +112,17 @@ impl ExecutedTransaction {
         &self.tx_outputs.output_notes
     }
 
+    /// Returns the block number at which this transaction expires, i.e., the transaction can only
+    /// be included into blocks with a smaller number.
+    pub fn expiration_block_num(&self) -> u32 {
+        self.tx_outputs.expiration_block_num
+    }
+
+    /// Returns the fee paid by this transaction, if any.
+    pub fn fee(&self) -> Option<FungibleAsset> {
+        self.tx_outputs.fee
+    }
+
     /// Returns a reference to the transaction args.
     pub fn tx_args(&self) -> &TransactionArgs {
         &self.tx_args
@@ -130,6 +155,74 @@ impl ExecutedTransaction {
         &self.tx_measurements
     }
 
+    /// Returns the events emitted by the transaction kernel while executing this transaction, in
+    /// the order in which they were emitted.
+    pub fn events(&self) -> &[KernelEvent] {
+        &self.events
+    }
+
+    // VALIDATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks that the account delta of this transaction is consistent with its initial and final
+    /// account states.
+    ///
+    /// Specifically, this re-derives the final account state from the initial account and the
+    /// account delta. A mismatch indicates a bookkeeping error in the host which executed the
+    /// transaction, and thus, that the account delta would not describe the state transition
+    /// attested to by the proof. This is much cheaper than proving, and should be used to catch
+    /// such errors before the transaction is proven.
+    ///
+    /// Input and output notes are not checked here: their commitments are computed on
+    /// construction, and the commitment to the output notes is checked against the one reported
+    /// by the transaction kernel when the transaction outputs are extracted.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The storage delta does not transform the initial account storage into the storage
+    ///   committed to by the final account.
+    /// - The account delta cannot be applied to the initial account.
+    /// - The hash of the initial account with the account delta applied differs from the hash of
+    ///   the final account.
+    pub fn validate(&self) -> Result<(), ExecutedTransactionError> {
+        self.account_delta
+            .storage()
+            .validate_against(
+                self.initial_account().storage(),
+                self.final_account().storage_commitment(),
+            )
+            .map_err(ExecutedTransactionError::AccountStorageDeltaMismatch)?;
+
+        let mut account = self.initial_account().clone();
+        account
+            .apply_delta(&self.account_delta)
+            .map_err(ExecutedTransactionError::AccountDeltaApplicationFailed)?;
+        if account.hash() != self.final_account().hash() {
+            return Err(ExecutedTransactionError::AccountFinalHashMismatch(
+                self.final_account().hash(),
+                account.hash(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Supplies the recipient details of a partial output note created by this transaction,
+    /// converting it into a full note.
+    ///
+    /// This does not affect the ID of this transaction. See [OutputNotes::complete_note()] for
+    /// more details.
+    pub fn complete_output_note(
+        &mut self,
+        note_id: NoteId,
+        recipient: NoteRecipient,
+    ) -> Result<&Note, TransactionOutputError> {
+        self.tx_outputs.output_notes.complete_note(note_id, recipient)
+    }
+
     // CONVERSIONS
     // --------------------------------------------------------------------------------------------
 
@@ -166,7 +259,7 @@ impl From<ExecutedTransaction> for TransactionMeasurements {
 
 /// Stores the resulting number of cycles for each transaction execution stage obtained from the
 /// `TransactionProgress` struct.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TransactionMeasurements {
     pub prologue: usize,
     pub notes_processing: usize,
@@ -0,0 +1,107 @@
+use super::{AccountId, TransactionMeasurements};
+use crate::{assets::FungibleAsset, AssetError};
+
+// FEE MODEL
+// ================================================================================================
+
+/// Defines how the fee of a transaction is derived from the cost of executing it.
+///
+/// Fee models are deterministic: the same measurements always result in the same fee. Thus,
+/// clients can quote the fee of a transaction by executing (or simulating) it locally and applying
+/// the fee model to the resulting [TransactionMeasurements].
+pub trait FeeModel {
+    /// Returns the fee for a transaction with the provided measurements.
+    ///
+    /// # Errors
+    /// Returns an error if the fee cannot be represented as a fungible asset.
+    fn compute_fee(
+        &self,
+        measurements: &TransactionMeasurements,
+    ) -> Result<FungibleAsset, AssetError>;
+}
+
+// CYCLE FEE MODEL
+// ================================================================================================
+
+/// A [FeeModel] which charges a base fee plus a fixed fee per cycle of the transaction trace.
+///
+/// The fee is computed over the trace length of the transaction (i.e., the total number of cycles
+/// rounded up to the next power of two), since this is what determines the cost of proving the
+/// transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CycleFeeModel {
+    faucet_id: AccountId,
+    base_fee: u64,
+    fee_per_cycle: u64,
+}
+
+impl CycleFeeModel {
+    /// Returns a new [CycleFeeModel] charging fees in the assets of the specified faucet.
+    pub fn new(faucet_id: AccountId, base_fee: u64, fee_per_cycle: u64) -> Self {
+        Self { faucet_id, base_fee, fee_per_cycle }
+    }
+
+    /// Returns the ID of the faucet issuing the fee asset.
+    pub fn faucet_id(&self) -> AccountId {
+        self.faucet_id
+    }
+
+    /// Returns the fee charged for every transaction regardless of its cost.
+    pub fn base_fee(&self) -> u64 {
+        self.base_fee
+    }
+
+    /// Returns the fee charged per cycle of the transaction trace.
+    pub fn fee_per_cycle(&self) -> u64 {
+        self.fee_per_cycle
+    }
+}
+
+impl FeeModel for CycleFeeModel {
+    fn compute_fee(
+        &self,
+        measurements: &TransactionMeasurements,
+    ) -> Result<FungibleAsset, AssetError> {
+        let amount = (measurements.trace_length() as u64)
+            .checked_mul(self.fee_per_cycle)
+            .and_then(|cycles_fee| cycles_fee.checked_add(self.base_fee))
+            .ok_or(AssetError::AmountTooBig(u64::MAX))?;
+
+        FungibleAsset::new(self.faucet_id, amount)
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::accounts::account_id::testing::ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN;
+
+    fn measurements(prologue: usize, epilogue: usize) -> TransactionMeasurements {
+        TransactionMeasurements {
+            prologue,
+            notes_processing: 0,
+            note_execution: vec![],
+            tx_script_processing: 0,
+            epilogue,
+        }
+    }
+
+    #[test]
+    fn test_cycle_fee_model() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let fee_model = CycleFeeModel::new(faucet_id, 100, 2);
+
+        // 1000 cycles are rounded up to a trace of 1024 cycles
+        let fee = fee_model.compute_fee(&measurements(600, 400)).unwrap();
+        assert_eq!(fee, FungibleAsset::new(faucet_id, 100 + 2 * 1024).unwrap());
+
+        let fee_model = CycleFeeModel::new(faucet_id, 0, FungibleAsset::MAX_AMOUNT);
+        assert!(matches!(
+            fee_model.compute_fee(&measurements(600, 400)),
+            Err(AssetError::AmountTooBig(_))
+        ));
+    }
+}
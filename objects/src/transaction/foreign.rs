@@ -0,0 +1,193 @@
+use alloc::vec::Vec;
+
+use miden_crypto::merkle::{MerklePath, SmtProof};
+use vm_core::utils::{ByteReader, ByteWriter, Deserializable, Serializable};
+use vm_processor::DeserializationError;
+
+use super::{Account, AccountHeader, AccountId, BlockHeader, Digest};
+use crate::{
+    accounts::{AccountCode, AccountStorageHeader, StorageSlot, StorageSlotType},
+    TransactionInputError,
+};
+
+// FOREIGN ACCOUNT INPUTS
+// ================================================================================================
+
+/// Data required to read the state of a foreign account during transaction execution (i.e., to
+/// execute procedures of the foreign account via foreign procedure invocation).
+///
+/// The inputs consist of:
+/// - The header of the foreign account, i.e., its ID, nonce and the commitments to its vault,
+///   storage and code.
+/// - The storage header of the foreign account, which contains the values of all storage slots.
+/// - The code of the foreign account.
+/// - Openings of the storage map entries which may be read during execution.
+/// - The Merkle path of the account in the account tree of the reference block. The path is used to
+///   authenticate the account state against the account root of the block header.
+///
+/// For public accounts, these inputs can be built from the account state and the state proofs
+/// provided by the network. For private accounts, the inputs must be built from the account state
+/// known to the user, together with a witness of the account hash in the account tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForeignAccountInputs {
+    account_header: AccountHeader,
+    storage_header: AccountStorageHeader,
+    account_code: AccountCode,
+    storage_map_proofs: Vec<SmtProof>,
+    account_path: MerklePath,
+}
+
+impl ForeignAccountInputs {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns new [ForeignAccountInputs] instantiated from the provided data.
+    pub fn new(
+        account_header: AccountHeader,
+        storage_header: AccountStorageHeader,
+        account_code: AccountCode,
+        storage_map_proofs: Vec<SmtProof>,
+        account_path: MerklePath,
+    ) -> Self {
+        Self {
+            account_header,
+            storage_header,
+            account_code,
+            storage_map_proofs,
+            account_path,
+        }
+    }
+
+    /// Returns [ForeignAccountInputs] built from the provided account and its Merkle path in the
+    /// account tree.
+    ///
+    /// All entries of the account's in-memory storage maps are opened, and thus all of them can be
+    /// read during execution. Entries of map slots held by a storage backend are not opened.
+    pub fn from_account(account: &Account, account_path: MerklePath) -> Self {
+        let storage_map_proofs = account
+            .storage()
+            .slots()
+            .iter()
+            .filter_map(|slot| match slot {
+                StorageSlot::Map(map) => Some(map),
+                StorageSlot::Value(_) | StorageSlot::LazyMap(_) => None,
+            })
+            .flat_map(|map| map.entries().map(|(key, _)| map.open(key)))
+            .collect();
+
+        Self::new(
+            account.into(),
+            account.storage().get_header(),
+            account.code().clone(),
+            storage_map_proofs,
+            account_path,
+        )
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of the foreign account.
+    pub fn account_id(&self) -> AccountId {
+        self.account_header.id()
+    }
+
+    /// Returns the header of the foreign account.
+    pub fn account_header(&self) -> &AccountHeader {
+        &self.account_header
+    }
+
+    /// Returns the storage header of the foreign account.
+    pub fn storage_header(&self) -> &AccountStorageHeader {
+        &self.storage_header
+    }
+
+    /// Returns the code of the foreign account.
+    pub fn account_code(&self) -> &AccountCode {
+        &self.account_code
+    }
+
+    /// Returns the openings of the storage map entries of the foreign account.
+    pub fn storage_map_proofs(&self) -> &[SmtProof] {
+        &self.storage_map_proofs
+    }
+
+    /// Returns the Merkle path of the foreign account in the account tree.
+    pub fn account_path(&self) -> &MerklePath {
+        &self.account_path
+    }
+
+    // VALIDATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Validates these inputs against the provided reference block header.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The storage header or the code do not match the commitments in the account header.
+    /// - Any of the storage map openings does not open a storage map of the account.
+    /// - The account hash is not authenticated by the account root of the block header.
+    pub fn validate(&self, block_header: &BlockHeader) -> Result<(), TransactionInputError> {
+        let account_id = self.account_id();
+
+        if self.storage_header.compute_commitment() != self.account_header.storage_commitment() {
+            return Err(TransactionInputError::ForeignAccountStorageMismatch(account_id));
+        }
+
+        if self.account_code.commitment() != self.account_header.code_commitment() {
+            return Err(TransactionInputError::ForeignAccountCodeMismatch(account_id));
+        }
+
+        let map_roots: Vec<Digest> = self
+            .storage_header
+            .slots()
+            .filter(|(slot_type, _)| *slot_type == StorageSlotType::Map)
+            .map(|(_, root)| Digest::from(*root))
+            .collect();
+        for proof in self.storage_map_proofs.iter() {
+            if !map_roots.contains(&proof.compute_root()) {
+                return Err(TransactionInputError::ForeignAccountInvalidStorageProof(account_id));
+            }
+        }
+
+        self.account_path
+            .verify(account_id.into(), self.account_header.hash(), &block_header.account_root())
+            .map_err(|_| {
+                TransactionInputError::ForeignAccountNotInBlock(
+                    account_id,
+                    block_header.block_num(),
+                )
+            })
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for ForeignAccountInputs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.account_header.write_into(target);
+        self.storage_header.write_into(target);
+        self.account_code.write_into(target);
+        self.storage_map_proofs.write_into(target);
+        self.account_path.write_into(target);
+    }
+}
+
+impl Deserializable for ForeignAccountInputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let account_header = AccountHeader::read_from(source)?;
+        let storage_header = AccountStorageHeader::read_from(source)?;
+        let account_code = AccountCode::read_from(source)?;
+        let storage_map_proofs = Vec::<SmtProof>::read_from(source)?;
+        let account_path = MerklePath::read_from(source)?;
+
+        Ok(Self::new(
+            account_header,
+            storage_header,
+            account_code,
+            storage_map_proofs,
+            account_path,
+        ))
+    }
+}
@@ -31,6 +31,12 @@ impl TransactionInputs {
     /// Returns an error if:
     /// - For a new account, account seed is not provided or the provided seed is invalid.
     /// - For an existing account, account seed was provided.
+    /// - The chain MMR is inconsistent with the block header.
+    /// - An authenticated input note was not created in the block referenced by its inclusion
+    ///   proof, or this block is not tracked by the chain MMR.
+    ///
+    /// When the `concurrent` feature is enabled, the inclusion proofs of the input notes are
+    /// verified in parallel.
     pub fn new(
         account: Account,
         account_seed: Option<Word>,
@@ -42,7 +48,6 @@ impl TransactionInputs {
         validate_account_seed(&account, account_seed)?;
 
         // check the block_chain and block_header are consistent
-        let block_num = block_header.block_num();
         if block_chain.chain_length() != block_header.block_num() as usize {
             return Err(TransactionInputError::InconsistentChainLength {
                 expected: block_header.block_num(),
@@ -57,20 +62,22 @@ impl TransactionInputs {
             });
         }
 
-        // check the authentication paths of the input notes.
-        for note in input_notes.iter() {
-            if let InputNote::Authenticated { note, proof } = note {
-                let note_block_num = proof.location().block_num();
-
-                let block_header = if note_block_num == block_num {
-                    &block_header
-                } else {
-                    block_chain
-                        .get_block(note_block_num)
-                        .ok_or(TransactionInputError::InputNoteBlockNotInChainMmr(note.id()))?
-                };
-
-                validate_is_in_block(note, proof, block_header)?;
+        // check the authentication paths of the input notes; when the `concurrent` feature is
+        // enabled, the notes are checked in parallel. in both cases, the error of the first
+        // invalid note is returned.
+        let validate_note =
+            |note: &InputNote| validate_input_note(note, &block_header, &block_chain);
+
+        #[cfg(not(feature = "concurrent"))]
+        input_notes.iter().try_for_each(validate_note)?;
+
+        #[cfg(feature = "concurrent")]
+        {
+            use rayon::prelude::*;
+            if let Some(err) =
+                input_notes.notes.par_iter().find_map_first(|note| validate_note(note).err())
+            {
+                return Err(err);
             }
         }
 
@@ -390,6 +397,40 @@ impl InputNote {
     pub fn location(&self) -> Option<&NoteLocation> {
         self.proof().map(|proof| proof.location())
     }
+
+    /// Returns true if the existence of this note in the chain is verified by the transaction
+    /// kernel.
+    ///
+    /// The existence of unauthenticated notes must be verified by the batch or block producer,
+    /// which matches them against the notes created in the same batch or block.
+    pub fn is_authenticated(&self) -> bool {
+        matches!(self, Self::Authenticated { .. })
+    }
+}
+
+/// Validates that the provided input note, if it is authenticated, was created in the block
+/// referenced by its inclusion proof, and that this block is either the reference block of the
+/// transaction or a block tracked by the provided chain MMR.
+fn validate_input_note(
+    note: &InputNote,
+    block_header: &BlockHeader,
+    block_chain: &ChainMmr,
+) -> Result<(), TransactionInputError> {
+    if let InputNote::Authenticated { note, proof } = note {
+        let note_block_num = proof.location().block_num();
+
+        let block_header = if note_block_num == block_header.block_num() {
+            block_header
+        } else {
+            block_chain
+                .get_block(note_block_num)
+                .ok_or(TransactionInputError::InputNoteBlockNotInChainMmr(note.id()))?
+        };
+
+        validate_is_in_block(note, proof, block_header)?;
+    }
+
+    Ok(())
 }
 
 /// Validates whether the provided note belongs to the note tree of the specified block.
@@ -398,14 +439,9 @@ fn validate_is_in_block(
     proof: &NoteInclusionProof,
     block_header: &BlockHeader,
 ) -> Result<(), TransactionInputError> {
-    let note_index = proof.location().node_index_in_block().into();
-    let note_hash = note.hash();
-    proof
-        .note_path()
-        .verify(note_index, note_hash, &block_header.note_root())
-        .map_err(|_| {
-            TransactionInputError::InputNoteNotInBlock(note.id(), proof.location().block_num())
-        })
+    proof.verify(note.id(), note.metadata(), block_header).map_err(|_| {
+        TransactionInputError::InputNoteNotInBlock(note.id(), proof.location().block_num())
+    })
 }
 
 impl ToInputNoteCommitments for InputNote {
@@ -491,3 +527,114 @@ pub fn validate_account_seed(
         (false, None) => Ok(()),
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use assembly::Assembler;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+    use vm_core::ONE;
+
+    use super::*;
+    use crate::{
+        accounts::account_id::testing::ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+        block::{BlockNoteIndex, BlockNoteTree},
+        crypto::merkle::{MmrPeaks, PartialMmr},
+        testing::notes::MockNoteBuilder,
+    };
+
+    /// Checks that the error of the first invalid input note is returned, also when the inclusion
+    /// proofs are verified in parallel with the `concurrent` feature.
+    #[test]
+    fn transaction_inputs_report_first_invalid_note() {
+        let sender =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let notes: Vec<Note> = (0..32u8)
+            .map(|seed| {
+                MockNoteBuilder::new(sender, ChaCha20Rng::from_seed([seed; 32]))
+                    .build(&Assembler::default())
+                    .unwrap()
+            })
+            .collect();
+
+        let indices: Vec<BlockNoteIndex> =
+            (0..notes.len()).map(|i| BlockNoteIndex::new(0, i).unwrap()).collect();
+        let note_tree = BlockNoteTree::with_entries(
+            notes
+                .iter()
+                .zip(&indices)
+                .map(|(note, index)| (*index, note.id(), *note.metadata())),
+        )
+        .unwrap();
+
+        let block_chain =
+            ChainMmr::new(PartialMmr::from_peaks(MmrPeaks::new(0, Vec::new()).unwrap()), vec![])
+                .unwrap();
+        let zero = Digest::default();
+        let block_header = BlockHeader::new(
+            0,
+            zero,
+            0,
+            block_chain.peaks().hash_peaks(),
+            zero,
+            zero,
+            note_tree.root(),
+            zero,
+            zero,
+            zero,
+            0,
+        );
+
+        // notes 9 and 23 are given the inclusion proof of another note
+        let input_notes: Vec<InputNote> = notes
+            .iter()
+            .enumerate()
+            .map(|(i, note)| {
+                let proof_index = if i == 9 || i == 23 { indices[i + 1] } else { indices[i] };
+                let proof = NoteInclusionProof::new(
+                    0,
+                    proof_index.leaf_index_value(),
+                    note_tree.get_note_path(proof_index),
+                )
+                .unwrap();
+                InputNote::authenticated(note.clone(), proof)
+            })
+            .collect();
+
+        let account = Account::mock(
+            ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+            ONE,
+            Assembler::default(),
+        );
+        let result = TransactionInputs::new(
+            account.clone(),
+            None,
+            block_header.clone(),
+            block_chain.clone(),
+            InputNotes::new(input_notes.clone()).unwrap(),
+        );
+        assert!(matches!(
+            result,
+            Err(TransactionInputError::InputNoteNotInBlock(note_id, 0)) if note_id == notes[9].id()
+        ));
+
+        // without the invalid notes, the inputs are valid
+        let valid_notes = input_notes
+            .into_iter()
+            .enumerate()
+            .filter(|(i, _)| *i != 9 && *i != 23)
+            .map(|(_, note)| note)
+            .collect();
+        assert!(TransactionInputs::new(
+            account,
+            None,
+            block_header,
+            block_chain,
+            InputNotes::new(valid_notes).unwrap(),
+        )
+        .is_ok());
+    }
+}
@@ -0,0 +1,38 @@
+use super::{Digest, Felt, Word};
+use crate::{assets::Asset, notes::NoteMetadata};
+
+// KERNEL EVENT
+// ================================================================================================
+
+/// A typed record of an event emitted by the transaction kernel while executing a transaction.
+///
+/// The transaction host decodes the raw events emitted by the kernel (together with the relevant
+/// data from the operand stack) into kernel events, and records them in the order in which they
+/// occurred. Unlike the account delta, which only describes the net effect of a transaction, the
+/// event log describes every individual action, and thus allows indexers to react to on-chain
+/// activity without re-deriving it from deltas.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KernelEvent {
+    /// An asset was added to the vault of the native account.
+    AssetAddedToVault(Asset),
+    /// An asset was removed from the vault of the native account.
+    AssetRemovedFromVault(Asset),
+    /// A value storage slot of the native account was updated.
+    StorageItemUpdated { slot_index: u8, value: Word },
+    /// An entry of a map storage slot of the native account was updated.
+    StorageMapItemUpdated { slot_index: u8, key: Digest, value: Word },
+    /// The nonce of the native account was incremented by the specified value.
+    NonceIncremented(Felt),
+    /// A procedure of an account with the specified MAST root invoked the transaction kernel.
+    AccountProcedureInvoked(Digest),
+    /// An output note with the specified index was created.
+    NoteCreated {
+        note_idx: usize,
+        metadata: NoteMetadata,
+        recipient: Digest,
+    },
+    /// An asset was added to the output note with the specified index.
+    AssetAddedToNote { note_idx: usize, asset: Asset },
+    /// A signature was requested for the specified message against the specified public key.
+    SignatureRequested { pub_key: Word, message: Word },
+}
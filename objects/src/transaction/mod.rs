@@ -7,7 +7,10 @@ use super::{
 
 mod chain_mmr;
 mod executed_tx;
+mod fee;
+mod foreign;
 mod inputs;
+mod kernel_event;
 mod outputs;
 mod proven_tx;
 mod transaction_id;
@@ -16,7 +19,10 @@ mod tx_witness;
 
 pub use chain_mmr::ChainMmr;
 pub use executed_tx::{ExecutedTransaction, TransactionMeasurements};
+pub use fee::{CycleFeeModel, FeeModel};
+pub use foreign::ForeignAccountInputs;
 pub use inputs::{InputNote, InputNotes, ToInputNoteCommitments, TransactionInputs};
+pub use kernel_event::KernelEvent;
 pub use outputs::{OutputNote, OutputNotes, TransactionOutputs};
 pub use proven_tx::{
     InputNoteCommitment, ProvenTransaction, ProvenTransactionBuilder, TxAccountUpdate,
@@ -6,7 +6,11 @@ use vm_processor::DeserializationError;
 
 use crate::{
     accounts::AccountHeader,
-    notes::{compute_note_hash, Note, NoteAssets, NoteHeader, NoteId, NoteMetadata, PartialNote},
+    assets::FungibleAsset,
+    notes::{
+        compute_note_hash, Note, NoteAssets, NoteHeader, NoteId, NoteMetadata, NoteRecipient,
+        PartialNote,
+    },
     Digest, Felt, Hasher, TransactionOutputError, Word, MAX_OUTPUT_NOTES_PER_TX,
 };
 // TRANSACTION OUTPUTS
@@ -21,6 +25,8 @@ pub struct TransactionOutputs {
     pub output_notes: OutputNotes,
     /// Defines up to which block the transaction is considered valid.
     pub expiration_block_num: u32,
+    /// The fee paid by the transaction, if any.
+    pub fee: Option<FungibleAsset>,
 }
 
 // OUTPUT NOTES
@@ -92,6 +98,47 @@ impl OutputNotes {
     pub fn iter(&self) -> impl Iterator<Item = &OutputNote> {
         self.notes.iter()
     }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Replaces the partial output note with the specified ID by the full note built from the
+    /// provided recipient.
+    ///
+    /// Since the ID and metadata of the note do not change, the commitment to the output notes
+    /// remains the same.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - There is no output note with the specified ID.
+    /// - The note with the specified ID is not an [OutputNote::Partial] note.
+    /// - The digest of the provided recipient does not match the recipient digest of the note.
+    pub fn complete_note(
+        &mut self,
+        note_id: NoteId,
+        recipient: NoteRecipient,
+    ) -> Result<&Note, TransactionOutputError> {
+        let note = self
+            .notes
+            .iter_mut()
+            .find(|note| note.id() == note_id)
+            .ok_or(TransactionOutputError::OutputNoteNotFound(note_id))?;
+
+        let OutputNote::Partial(partial_note) = note else {
+            return Err(TransactionOutputError::OutputNoteNotPartial(note_id));
+        };
+
+        let full_note = partial_note
+            .clone()
+            .complete(recipient)
+            .map_err(TransactionOutputError::OutputNoteDataInvalid)?;
+        *note = OutputNote::Full(full_note);
+
+        match note {
+            OutputNote::Full(note) => Ok(note),
+            _ => unreachable!("the note was just replaced by a full note"),
+        }
+    }
 }
 
 // SERIALIZATION
@@ -265,3 +312,58 @@ impl Deserializable for OutputNote {
         }
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        accounts::{
+            account_id::testing::ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN, AccountId,
+        },
+        crypto::rand::RpoRandomCoin,
+        notes::{NoteBuilder, NoteScript, NoteType},
+        NoteError, ONE,
+    };
+
+    #[test]
+    fn test_complete_partial_output_note() {
+        let sender =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let mut rng = RpoRandomCoin::new([ONE; 4]);
+        let note = NoteBuilder::new(sender, NoteScript::mock())
+            .note_type(NoteType::Private)
+            .build(&mut rng)
+            .unwrap();
+        let other_note = NoteBuilder::new(sender, NoteScript::mock()).build(&mut rng).unwrap();
+
+        let partial_note =
+            PartialNote::new(*note.metadata(), note.recipient().digest(), note.assets().clone());
+        let mut output_notes = OutputNotes::new(vec![
+            OutputNote::Partial(partial_note),
+            OutputNote::Full(other_note.clone()),
+        ])
+        .unwrap();
+        let commitment = output_notes.commitment();
+
+        // a recipient which does not match the recipient digest is rejected
+        assert!(matches!(
+            output_notes.complete_note(note.id(), other_note.recipient().clone()),
+            Err(TransactionOutputError::OutputNoteDataInvalid(
+                NoteError::RecipientDigestMismatch { .. }
+            ))
+        ));
+
+        let completed = output_notes.complete_note(note.id(), note.recipient().clone()).unwrap();
+        assert_eq!(completed, &note);
+        assert_eq!(output_notes.get_note(0), &OutputNote::Full(note.clone()));
+        assert_eq!(output_notes.commitment(), commitment);
+
+        assert!(matches!(
+            output_notes.complete_note(other_note.id(), other_note.recipient().clone()),
+            Err(TransactionOutputError::OutputNoteNotPartial(_))
+        ));
+    }
+}
@@ -5,12 +5,13 @@ use miden_verifier::ExecutionProof;
 use super::{InputNote, ToInputNoteCommitments};
 use crate::{
     accounts::delta::AccountUpdateDetails,
+    assets::FungibleAsset,
     notes::NoteHeader,
     transaction::{
         AccountId, Digest, InputNotes, Nullifier, OutputNote, OutputNotes, TransactionId,
     },
     utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
-    ProvenTransactionError, ACCOUNT_UPDATE_MAX_SIZE,
+    ProtocolLimits, ProvenTransactionError, ACCOUNT_UPDATE_MAX_SIZE, GENESIS_BLOCK,
 };
 
 // PROVEN TRANSACTION
@@ -39,6 +40,9 @@ pub struct ProvenTransaction {
     /// The block number by which the transaction will expire, as defined by the executed scripts.
     expiration_block_num: u32,
 
+    /// The fee paid by the transaction, if any.
+    fee: Option<FungibleAsset>,
+
     /// A STARK proof that attests to the correct execution of the transaction.
     proof: ExecutionProof,
 }
@@ -74,6 +78,17 @@ impl ProvenTransaction {
         &self.proof
     }
 
+    /// Returns the conjectured security level of the transaction proof, in bits.
+    ///
+    /// The security level is determined by the options the proof was generated with (i.e., the
+    /// number of queries, the blowup factor, the grinding factor and the hash function), which are
+    /// recorded in the proof itself. Thus, it can be checked before the proof is verified; since
+    /// the options are bound to the proof, a proof whose options are misrepresented fails
+    /// verification.
+    pub fn proof_security_level(&self) -> u32 {
+        self.proof.security_level()
+    }
+
     /// Returns the block reference the transaction was executed against.
     pub fn block_ref(&self) -> Digest {
         self.block_ref
@@ -89,6 +104,14 @@ impl ProvenTransaction {
         self.expiration_block_num
     }
 
+    /// Returns the fee paid by the transaction, if any.
+    ///
+    /// The fee is a public output of the transaction, and thus it is attested to by the proof of
+    /// the transaction. Block producers can use it to prioritize transactions.
+    pub fn fee(&self) -> Option<FungibleAsset> {
+        self.fee
+    }
+
     /// Returns an iterator over the nullifiers of all input notes in this transaction.
     ///
     /// This includes both authenticated and unauthenticated notes.
@@ -96,10 +119,69 @@ impl ProvenTransaction {
         self.input_notes.iter().map(InputNoteCommitment::nullifier)
     }
 
+    // VALIDATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks that this transaction respects the provided limits and is otherwise well-formed.
+    ///
+    /// These checks are considerably cheaper than verifying the proof of the transaction, and thus
+    /// can be used to reject malformed transactions before the proof is verified.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The transaction consumes more than `max_input_notes_per_tx` notes.
+    /// - The transaction creates more than `max_output_notes_per_tx` notes.
+    /// - Any of the public output notes contains more than `max_assets_per_note` assets.
+    /// - The update of a public account is larger than `max_account_update_size` bytes, or the
+    ///   account update details are inconsistent with the account.
+    /// - The expiration block number is not greater than the genesis block number, i.e., the
+    ///   transaction could never be included into the chain.
+    pub fn validate(&self, limits: &ProtocolLimits) -> Result<(), ProvenTransactionError> {
+        if self.input_notes.num_notes() > limits.max_input_notes_per_tx {
+            return Err(ProvenTransactionError::TooManyInputNotes(self.input_notes.num_notes()));
+        }
+
+        if self.output_notes.num_notes() > limits.max_output_notes_per_tx {
+            return Err(ProvenTransactionError::TooManyOutputNotes(self.output_notes.num_notes()));
+        }
+
+        for note in self.output_notes.iter() {
+            if let Some(assets) = note.assets() {
+                if assets.num_assets() > limits.max_assets_per_note {
+                    return Err(ProvenTransactionError::NoteAssetLimitExceeded(
+                        note.id(),
+                        assets.num_assets(),
+                    ));
+                }
+            }
+        }
+
+        if self.account_id().is_public() {
+            let account_update_size = self.account_update.details().get_size_hint();
+            if account_update_size > limits.max_account_update_size {
+                return Err(ProvenTransactionError::AccountUpdateSizeLimitExceeded(
+                    self.account_id(),
+                    account_update_size,
+                ));
+            }
+        }
+        self.validate_account_update()?;
+
+        if self.expiration_block_num <= GENESIS_BLOCK {
+            return Err(ProvenTransactionError::InvalidExpirationBlockNum(
+                self.expiration_block_num,
+            ));
+        }
+
+        Ok(())
+    }
+
     // HELPER METHODS
     // --------------------------------------------------------------------------------------------
 
-    fn validate(self) -> Result<Self, ProvenTransactionError> {
+    /// Checks that the account update details are consistent with the account of this
+    /// transaction.
+    fn validate_account_update(&self) -> Result<(), ProvenTransactionError> {
         if self.account_id().is_public() {
             self.account_update.validate()?;
 
@@ -138,12 +220,42 @@ impl ProvenTransaction {
                         ));
                     }
                 },
+                AccountUpdateDetails::CompactDelta(ref delta) => {
+                    if is_new_account {
+                        return Err(ProvenTransactionError::NewOnChainAccountRequiresFullDetails(
+                            self.account_id(),
+                        ));
+                    }
+                    if delta.initial_state().id() != self.account_id() {
+                        return Err(ProvenTransactionError::AccountIdMismatch(
+                            self.account_id(),
+                            delta.initial_state().id(),
+                        ));
+                    }
+                    if delta.initial_state().hash() != self.account_update.init_state_hash() {
+                        return Err(ProvenTransactionError::AccountInitialHashMismatch(
+                            self.account_update.init_state_hash(),
+                            delta.initial_state().hash(),
+                        ));
+                    }
+
+                    let final_hash = delta
+                        .final_header()
+                        .map_err(ProvenTransactionError::InvalidCompactAccountDelta)?
+                        .hash();
+                    if final_hash != self.account_update.final_state_hash() {
+                        return Err(ProvenTransactionError::AccountFinalHashMismatch(
+                            self.account_update.final_state_hash(),
+                            final_hash,
+                        ));
+                    }
+                },
             }
         } else if !self.account_update.is_private() {
             return Err(ProvenTransactionError::OffChainAccountWithDetails(self.account_id()));
         }
 
-        Ok(self)
+        Ok(())
     }
 }
 
@@ -154,6 +266,7 @@ impl Serializable for ProvenTransaction {
         self.output_notes.write_into(target);
         self.block_ref.write_into(target);
         self.expiration_block_num.write_into(target);
+        self.fee.write_into(target);
         self.proof.write_into(target);
     }
 }
@@ -167,6 +280,7 @@ impl Deserializable for ProvenTransaction {
 
         let block_ref = Digest::read_from(source)?;
         let expiration_block_num = u32::read_from(source)?;
+        let fee = Option::<FungibleAsset>::read_from(source)?;
         let proof = ExecutionProof::read_from(source)?;
 
         let id = TransactionId::new(
@@ -183,12 +297,15 @@ impl Deserializable for ProvenTransaction {
             output_notes,
             block_ref,
             expiration_block_num,
+            fee,
             proof,
         };
 
         proven_transaction
-            .validate()
-            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+            .validate_account_update()
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+
+        Ok(proven_transaction)
     }
 }
 
@@ -222,6 +339,9 @@ pub struct ProvenTransactionBuilder {
     /// The block number by which the transaction will expire, as defined by the executed scripts.
     expiration_block_num: u32,
 
+    /// The fee paid by the transaction, if any.
+    fee: Option<FungibleAsset>,
+
     /// A STARK proof that attests to the correct execution of the transaction.
     proof: ExecutionProof,
 }
@@ -248,6 +368,7 @@ impl ProvenTransactionBuilder {
             output_notes: Vec::new(),
             block_ref,
             expiration_block_num,
+            fee: None,
             proof,
         }
     }
@@ -261,6 +382,12 @@ impl ProvenTransactionBuilder {
         self
     }
 
+    /// Sets the fee paid by the transaction.
+    pub fn fee(mut self, fee: Option<FungibleAsset>) -> Self {
+        self.fee = fee;
+        self
+    }
+
     /// Add notes consumed by the transaction.
     pub fn add_input_notes<I, T>(mut self, notes: I) -> Self
     where
@@ -311,10 +438,13 @@ impl ProvenTransactionBuilder {
             output_notes,
             block_ref: self.block_ref,
             expiration_block_num: self.expiration_block_num,
+            fee: self.fee,
             proof: self.proof,
         };
 
-        proven_transaction.validate()
+        proven_transaction.validate_account_update()?;
+
+        Ok(proven_transaction)
     }
 }
 
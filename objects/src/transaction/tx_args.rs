@@ -1,8 +1,13 @@
-use alloc::{collections::BTreeMap, string::ToString, sync::Arc, vec::Vec};
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
 use core::ops::Deref;
 
 use assembly::{Assembler, Compile};
-use miden_crypto::merkle::InnerNodeInfo;
+use miden_crypto::{merkle::InnerNodeInfo, utils::SliceReader};
 use vm_core::{
     mast::{MastForest, MastNodeId},
     utils::{ByteReader, ByteWriter, Deserializable, Serializable},
@@ -10,10 +15,12 @@ use vm_core::{
 };
 use vm_processor::{AdviceInputs, AdviceMap, DeserializationError};
 
-use super::{Digest, Felt, Word};
+use super::{Digest, Felt, ForeignAccountInputs, Word, ZERO};
 use crate::{
-    notes::{NoteDetails, NoteId},
-    TransactionScriptError,
+    assets::FungibleAsset,
+    debug_info::{read_debug_section, write_debug_section},
+    notes::{NoteArgs, NoteDetails, NoteId},
+    ScriptDebugInfo, TransactionScriptError,
 };
 
 // TRANSACTION ARGS
@@ -25,13 +32,16 @@ use crate::{
 ///   have been executed.
 /// - Note arguments: data put onto the stack right before a note script is executed. These are
 ///   different from note inputs, as the user executing the transaction can specify arbitrary note
-///   args.
+///   args (see [NoteArgs]).
 /// - Advice inputs: Provides data needed by the runtime, like the details of public output notes.
+/// - Fee: the fungible asset which is deducted from the account vault in the epilogue and paid as
+///   the fee of the transaction.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct TransactionArgs {
     tx_script: Option<TransactionScript>,
-    note_args: BTreeMap<NoteId, Word>,
+    note_args: BTreeMap<NoteId, NoteArgs>,
     advice_inputs: AdviceInputs,
+    fee: Option<FungibleAsset>,
 }
 
 impl TransactionArgs {
@@ -45,7 +55,7 @@ impl TransactionArgs {
     /// to the advice inputs' map.
     pub fn new(
         tx_script: Option<TransactionScript>,
-        note_args: Option<BTreeMap<NoteId, Word>>,
+        note_args: Option<BTreeMap<NoteId, NoteArgs>>,
         advice_map: AdviceMap,
     ) -> Self {
         let mut advice_inputs = AdviceInputs::default().with_map(advice_map);
@@ -59,6 +69,7 @@ impl TransactionArgs {
             tx_script,
             note_args: note_args.unwrap_or_default(),
             advice_inputs,
+            fee: None,
         }
     }
 
@@ -68,7 +79,7 @@ impl TransactionArgs {
     }
 
     /// Returns new [TransactionArgs] instantiated with the provided note arguments.
-    pub fn with_note_args(note_args: BTreeMap<NoteId, Word>) -> Self {
+    pub fn with_note_args(note_args: BTreeMap<NoteId, NoteArgs>) -> Self {
         Self::new(None, Some(note_args), AdviceMap::default())
     }
 
@@ -79,6 +90,16 @@ impl TransactionArgs {
         self
     }
 
+    /// Returns the provided [TransactionArgs] with the fee of the transaction set to `fee`.
+    ///
+    /// The fee is deducted from the vault of the account in the epilogue of the transaction, and
+    /// is exposed as a public output of the transaction. Since this changes the account state, the
+    /// nonce of the account must be incremented by the transaction.
+    pub fn with_fee(mut self, fee: FungibleAsset) -> Self {
+        self.fee = Some(fee);
+        self
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -88,8 +109,15 @@ impl TransactionArgs {
     }
 
     /// Returns a reference to a specific note argument.
+    #[deprecated(note = "use `note_args()` instead")]
     pub fn get_note_args(&self, note_id: NoteId) -> Option<&Word> {
-        self.note_args.get(&note_id)
+        self.note_args.get(&note_id).map(AsRef::as_ref)
+    }
+
+    /// Returns the [NoteArgs] with which the specified note is consumed, or [NoteArgs::EMPTY] if
+    /// no args were provided for the note.
+    pub fn note_args(&self, note_id: NoteId) -> NoteArgs {
+        self.note_args.get(&note_id).copied().unwrap_or(NoteArgs::EMPTY)
     }
 
     /// Returns a reference to the args [AdviceInputs].
@@ -97,9 +125,28 @@ impl TransactionArgs {
         &self.advice_inputs
     }
 
+    /// Returns the fee paid by the transaction, if set.
+    pub fn fee(&self) -> Option<FungibleAsset> {
+        self.fee
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
+    /// Sets the transaction script, replacing any script previously set, and adds all mappings
+    /// from the inputs of the script to the advice inputs' map.
+    pub fn set_tx_script(&mut self, tx_script: TransactionScript) {
+        self.advice_inputs
+            .extend_map(tx_script.inputs().iter().map(|(hash, input)| (*hash, input.clone())));
+        self.tx_script = Some(tx_script);
+    }
+
+    /// Sets the args with which the specified note is consumed, replacing any args previously set
+    /// for the note.
+    pub fn add_note_args(&mut self, note_id: NoteId, note_args: NoteArgs) {
+        self.note_args.insert(note_id, note_args);
+    }
+
     /// Populates the advice inputs with the specified note details.
     ///
     /// The advice inputs' map is extended with the following keys:
@@ -141,6 +188,54 @@ impl TransactionArgs {
         }
     }
 
+    /// Populates the advice inputs with the data of the specified foreign account.
+    ///
+    /// The advice inputs' map is extended with the following keys:
+    ///
+    /// - [account_id, 0, 0, 0] |-> [ID_AND_NONCE, VAULT_ROOT, STORAGE_COMMITMENT, CODE_COMMITMENT]
+    /// - storage_commitment |-> storage slots
+    /// - code_commitment |-> account procedures
+    /// - storage map leaf hash |-> storage map leaf, for every provided storage map opening
+    ///
+    /// The advice inputs' Merkle store is extended with the nodes of the account path in the
+    /// account tree, and the nodes of the provided storage map openings.
+    pub fn add_foreign_account(&mut self, inputs: &ForeignAccountInputs) {
+        let header = inputs.account_header();
+        let account_id: Felt = header.id().into();
+
+        let account_data = [
+            [account_id, ZERO, ZERO, header.nonce()].as_slice(),
+            header.vault_root().as_elements(),
+            header.storage_commitment().as_elements(),
+            header.code_commitment().as_elements(),
+        ]
+        .concat();
+
+        self.advice_inputs.extend_map([
+            (Digest::from([account_id, ZERO, ZERO, ZERO]), account_data),
+            (header.storage_commitment(), inputs.storage_header().as_elements()),
+            (header.code_commitment(), inputs.account_code().as_elements()),
+        ]);
+
+        self.advice_inputs.extend_merkle_store(
+            inputs
+                .account_path()
+                .inner_nodes(header.id().into(), header.hash())
+                .expect("account path depth should be valid"),
+        );
+
+        for proof in inputs.storage_map_proofs() {
+            let leaf = proof.leaf();
+            self.advice_inputs.extend_merkle_store(
+                proof
+                    .path()
+                    .inner_nodes(leaf.index().value(), leaf.hash())
+                    .expect("storage map path depth should be valid"),
+            );
+            self.advice_inputs.extend_map([(leaf.hash(), leaf.to_elements())]);
+        }
+    }
+
     /// Extends the internal advice inputs' map with the provided key-value pairs.
     pub fn extend_advice_map<T: IntoIterator<Item = (Digest, Vec<Felt>)>>(&mut self, iter: T) {
         self.advice_inputs.extend_map(iter)
@@ -157,16 +252,18 @@ impl Serializable for TransactionArgs {
         self.tx_script.write_into(target);
         self.note_args.write_into(target);
         self.advice_inputs.write_into(target);
+        self.fee.write_into(target);
     }
 }
 
 impl Deserializable for TransactionArgs {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         let tx_script = Option::<TransactionScript>::read_from(source)?;
-        let note_args = BTreeMap::<NoteId, Word>::read_from(source)?;
+        let note_args = BTreeMap::<NoteId, NoteArgs>::read_from(source)?;
         let advice_inputs = AdviceInputs::read_from(source)?;
+        let fee = Option::<FungibleAsset>::read_from(source)?;
 
-        Ok(Self { tx_script, note_args, advice_inputs })
+        Ok(Self { tx_script, note_args, advice_inputs, fee })
     }
 }
 
@@ -182,11 +279,16 @@ impl Deserializable for TransactionArgs {
 /// - An executable program defined by a [MastForest] and an associated entrypoint.
 /// - A set of transaction script inputs defined by a map of key-value inputs that are loaded into
 ///   the advice inputs' map such that the transaction script can access them.
-#[derive(Clone, Debug, PartialEq, Eq)]
+/// - Optional [ScriptDebugInfo] retaining the source code of the script, which affects neither
+///   the hash nor the equality of the script. It is only serialized via
+///   [TransactionScript::to_bytes_with_debug_info()], and thus, is stripped from transaction
+///   arguments and all other objects containing the script.
+#[derive(Clone, Debug)]
 pub struct TransactionScript {
     mast: Arc<MastForest>,
     entrypoint: MastNodeId,
     inputs: BTreeMap<Digest, Vec<Felt>>,
+    debug_info: Option<ScriptDebugInfo>,
 }
 
 impl TransactionScript {
@@ -199,6 +301,7 @@ impl TransactionScript {
             entrypoint: code.entrypoint(),
             mast: code.mast_forest().clone(),
             inputs: inputs.into_iter().map(|(k, v)| (k.into(), v)).collect(),
+            debug_info: None,
         }
     }
 
@@ -218,6 +321,40 @@ impl TransactionScript {
         Ok(Self::new(program, inputs))
     }
 
+    /// Returns a new [TransactionScript] compiled in debug mode from the provided source code and
+    /// inputs using the specified assembler, which retains the source code in its
+    /// [ScriptDebugInfo].
+    ///
+    /// The hash of the script is the same as if it was compiled via [Self::compile()].
+    ///
+    /// # Errors
+    /// Returns an error if the compilation of the provided source code fails.
+    pub fn compile_with_debug_info(
+        source_code: impl Into<String>,
+        inputs: impl IntoIterator<Item = (Word, Vec<Felt>)>,
+        assembler: Assembler,
+    ) -> Result<Self, TransactionScriptError> {
+        let source_code = source_code.into();
+        let script = Self::compile(source_code.as_str(), inputs, assembler.with_debug_mode(true))?;
+        Ok(script.with_debug_info(ScriptDebugInfo::new(source_code)))
+    }
+
+    /// Returns a new [TransactionScript] deserialized from the provided bytes written by
+    /// [Self::to_bytes_with_debug_info()], retaining the debug info of the script.
+    ///
+    /// # Errors
+    /// Returns an error if transaction script deserialization fails.
+    pub fn from_bytes_with_debug_info(bytes: &[u8]) -> Result<Self, DeserializationError> {
+        let mut source = SliceReader::new(bytes);
+        let script = Self::read_from(&mut source)?;
+        let debug_info = read_debug_section(&mut source)?;
+        if source.has_more_bytes() {
+            return Err(DeserializationError::UnconsumedBytes);
+        }
+
+        Ok(Self { debug_info, ..script })
+    }
+
     /// Returns a new [TransactionScript] instantiated from the provided components.
     ///
     /// # Panics
@@ -228,7 +365,24 @@ impl TransactionScript {
         inputs: BTreeMap<Digest, Vec<Felt>>,
     ) -> Self {
         assert!(mast.get_node_by_id(entrypoint).is_some());
-        Self { mast, entrypoint, inputs }
+        Self {
+            mast,
+            entrypoint,
+            inputs,
+            debug_info: None,
+        }
+    }
+
+    /// Returns this transaction script with the provided debug info attached.
+    pub fn with_debug_info(mut self, debug_info: ScriptDebugInfo) -> Self {
+        self.debug_info = Some(debug_info);
+        self
+    }
+
+    /// Returns this transaction script without its debug info.
+    pub fn without_debug_info(mut self) -> Self {
+        self.debug_info = None;
+        self
     }
 
     // PUBLIC ACCESSORS
@@ -248,8 +402,34 @@ impl TransactionScript {
     pub fn inputs(&self) -> &BTreeMap<Digest, Vec<Felt>> {
         &self.inputs
     }
+
+    /// Returns the debug info of this transaction script, if it was retained.
+    pub fn debug_info(&self) -> Option<&ScriptDebugInfo> {
+        self.debug_info.as_ref()
+    }
+
+    /// Serializes this transaction script into bytes, followed by a debug section containing its
+    /// debug info.
+    ///
+    /// Unlike [Serializable::to_bytes()], which never includes debug info, the returned bytes
+    /// must be deserialized via [Self::from_bytes_with_debug_info()].
+    pub fn to_bytes_with_debug_info(&self) -> Vec<u8> {
+        let mut bytes = self.to_bytes();
+        write_debug_section(self.debug_info.as_ref(), &mut bytes);
+        bytes
+    }
 }
 
+impl PartialEq for TransactionScript {
+    fn eq(&self, other: &Self) -> bool {
+        self.mast == other.mast
+            && self.entrypoint == other.entrypoint
+            && self.inputs == other.inputs
+    }
+}
+
+impl Eq for TransactionScript {}
+
 // SERIALIZATION
 // ================================================================================================
 
@@ -273,10 +453,11 @@ impl Deserializable for TransactionScript {
 
 #[cfg(test)]
 mod tests {
+    use assembly::Assembler;
     use vm_core::utils::{Deserializable, Serializable};
     use vm_processor::AdviceMap;
 
-    use crate::transaction::TransactionArgs;
+    use crate::transaction::{TransactionArgs, TransactionScript};
 
     #[test]
     fn test_tx_args_serialization() {
@@ -286,4 +467,24 @@ mod tests {
 
         assert_eq!(args, decoded);
     }
+
+    #[test]
+    fn test_tx_script_debug_info_serialization() {
+        let source = "begin\n    push.1 drop\nend";
+        let tx_script =
+            TransactionScript::compile_with_debug_info(source, [], Assembler::default()).unwrap();
+        assert_eq!(tx_script.debug_info().unwrap().line(2), Some("    push.1 drop"));
+
+        let decoded =
+            TransactionScript::from_bytes_with_debug_info(&tx_script.to_bytes_with_debug_info())
+                .unwrap();
+        assert_eq!(decoded.debug_info(), tx_script.debug_info());
+        assert_eq!(decoded, tx_script);
+
+        // debug info is stripped when the script is serialized as part of the transaction args
+        let tx_args = TransactionArgs::new(Some(tx_script.clone()), None, AdviceMap::default());
+        let decoded = TransactionArgs::read_from_bytes(&tx_args.to_bytes()).unwrap();
+        assert!(decoded.tx_script().unwrap().debug_info().is_none());
+        assert_eq!(decoded.tx_script(), Some(&tx_script));
+    }
 }
@@ -1,10 +1,23 @@
 use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use alloc::{borrow::ToOwned, string::ToString};
+#[cfg(feature = "std")]
+use std::{
+    fs::{self, File},
+    io::{self, BufReader, BufWriter, Read, Write},
+    path::Path,
+};
 
 use vm_core::utils::{ByteReader, Deserializable, Serializable};
 use vm_processor::DeserializationError;
 
 use super::{AdviceInputs, TransactionArgs, TransactionInputs};
 use crate::accounts::AccountCode;
+#[cfg(feature = "std")]
+use crate::{
+    utils::serde::{StreamDeserializable, StreamSerializable},
+    TX_KERNEL_VERSION,
+};
 
 // TRANSACTION WITNESS
 // ================================================================================================
@@ -35,6 +48,73 @@ pub struct TransactionWitness {
     pub account_codes: Vec<AccountCode>,
 }
 
+impl TransactionWitness {
+    /// The magic bytes at the start of a transaction witness file.
+    pub const FILE_MAGIC: [u8; 4] = *b"mtxw";
+
+    // PERSISTENCE
+    // --------------------------------------------------------------------------------------------
+
+    /// Writes this witness into the specified file, so that the transaction can be proven later
+    /// (e.g., after a restart of the process, or on another machine).
+    ///
+    /// The file starts with [Self::FILE_MAGIC] and the version of the transaction kernel the
+    /// witness was produced by, followed by the serialized witness. The witness is first written
+    /// into a temporary file next to the specified file, which is then renamed, so that an
+    /// interrupted write never leaves a partially written witness behind.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be written.
+    #[cfg(feature = "std")]
+    pub fn write(&self, filepath: impl AsRef<Path>) -> io::Result<()> {
+        let filepath = filepath.as_ref();
+        let mut tmp_path = filepath.as_os_str().to_owned();
+        tmp_path.push(".tmp");
+
+        let mut writer = BufWriter::new(File::create(&tmp_path)?);
+        writer.write_all(&Self::FILE_MAGIC)?;
+        writer.write_all(&[TX_KERNEL_VERSION])?;
+        self.write_into_stream(&mut writer)?;
+        writer.into_inner().map_err(io::IntoInnerError::into_error)?.sync_all()?;
+
+        fs::rename(tmp_path, filepath)
+    }
+
+    /// Reads a witness previously written via [Self::write()] from the specified file.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The file cannot be read.
+    /// - The file is not a transaction witness file, or was written for a different version of the
+    ///   transaction kernel.
+    /// - The file does not contain a valid serialized witness.
+    #[cfg(feature = "std")]
+    pub fn read(filepath: impl AsRef<Path>) -> io::Result<Self> {
+        let mut reader = BufReader::new(File::open(filepath)?);
+
+        let mut header = [0; 5];
+        reader.read_exact(&mut header)?;
+        if header[..4] != Self::FILE_MAGIC {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "file is not a transaction witness file",
+            ));
+        }
+        if header[4] != TX_KERNEL_VERSION {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "transaction witness was written for kernel version {}, expected version {}",
+                    header[4], TX_KERNEL_VERSION
+                ),
+            ));
+        }
+
+        Self::read_from_stream(&mut reader)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))
+    }
+}
+
 // SERIALIZATION
 // ================================================================================================
 